@@ -22,7 +22,7 @@ use std::io::{self, Read};
 use std::{env, process};
 
 use clap::{Arg, Command};
-use hime_sdk::errors::{Error, Errors};
+use hime_sdk::errors::{codes, Error, Errors, WARNING_NAMES};
 use hime_sdk::{CompilationTask, Input, Mode, Modifier, ParsingMethod, Runtime};
 use miette::{EyreContext, MietteHandler};
 
@@ -121,10 +121,26 @@ pub fn main() -> miette::Result<()> {
                     "lr0",
                     "lr1",
                     "lalr1",
+                    "ielr1",
                     "rnglr1",
-                    "rnglalr1"
+                    "rnglalr1",
+                    "hlr1",
+                    "hlalr1"
                 ])
         )
+        .arg(
+            Arg::new("lookahead")
+                .value_name("K")
+                .short('k')
+                .long("lookahead")
+                .help("The number of tokens of lookahead for the parser (default to 1). More than 1 is only supported by the lr1, lalr1 and ielr1 methods.")
+                .takes_value(true)
+                .required(false)
+                .validator(|value| match value.parse::<usize>() {
+                    Ok(k) if k > 0 => Ok(()),
+                    _ => Err(String::from("expected a positive integer")),
+                })
+        )
         .arg(
             Arg::new("debug")
                 .long("debug")
@@ -132,6 +148,37 @@ pub fn main() -> miette::Result<()> {
                 .takes_value(false)
                 .required(false)
         )
+        .arg(
+            Arg::new("report")
+                .long("report")
+                .help("Print the conflicts resolved by the precedence declarations.")
+                .takes_value(false)
+                .required(false)
+        )
+        .arg(
+            Arg::new("ambiguity")
+                .long("ambiguity")
+                .help("Analyze the grammars for ambiguities and print the definitely and possibly ambiguous rules.")
+                .takes_value(false)
+                .required(false)
+        )
+        .arg(
+            Arg::new("deny_warnings")
+                .long("deny-warnings")
+                .help("Treat the warnings as errors, the compilation fails if there is any warning.")
+                .takes_value(false)
+                .required(false)
+        )
+        .arg(
+            Arg::new("allow")
+                .value_name("WARNING")
+                .long("allow")
+                .help("Do not report the specified warning, can be repeated.")
+                .takes_value(true)
+                .required(false)
+                .multiple_occurrences(true)
+                .possible_values(WARNING_NAMES)
+        )
         .arg(
             Arg::new("rust_no_std")
                 .long("--no-std")
@@ -153,6 +200,13 @@ pub fn main() -> miette::Result<()> {
                 .takes_value(false)
                 .required(false)
         )
+        .arg(
+            Arg::new("rust_char_lexer")
+                .long("--char-lexer")
+                .help("Rust-only, indicates whether the lexer consumes Unicode scalar values instead of UTF-16 code units (default to false)")
+                .takes_value(false)
+                .required(false)
+        )
         .arg(
             Arg::new("grammar_name")
                 .value_name("GRAMMAR")
@@ -168,16 +222,33 @@ pub fn main() -> miette::Result<()> {
                 .help("Compiles the target grammar in-memory and test it against an input read from std::in and output the AST or parse errors")
                 .required(false)
         )
+        .arg(
+            Arg::new("explain")
+                .value_name("CODE")
+                .long("explain")
+                .help("Print the long-form explanation of an error code, e.g. H0008.")
+                .takes_value(true)
+                .required(false)
+        )
         .arg(
             Arg::new("inputs")
                 .value_name("INPUTS")
                 .help("The file names of the input grammars")
                 .takes_value(true)
-                .required(true)
+                .required_unless_present("explain")
                 .multiple_values(true)
         )
         .get_matches();
 
+    if let Some(code) = matches.value_of("explain") {
+        if let Some(explanation) = codes::get_explanation(code) {
+            print!("{explanation}");
+            process::exit(0);
+        }
+        println!("Unknown error code `{code}`");
+        process::exit(1);
+    }
+
     let mut task = CompilationTask::default();
     match matches.value_of("output_mode") {
         Some("sources") => task.mode = Some(Mode::Sources),
@@ -209,13 +280,31 @@ pub fn main() -> miette::Result<()> {
         Some("lr0") => task.method = Some(ParsingMethod::LR0),
         Some("lr1") => task.method = Some(ParsingMethod::LR1),
         Some("lalr1") => task.method = Some(ParsingMethod::LALR1),
+        Some("ielr1") => task.method = Some(ParsingMethod::IELR1),
         Some("rnglr1") => task.method = Some(ParsingMethod::RNGLR1),
         Some("rnglalr1") => task.method = Some(ParsingMethod::RNGLALR1),
+        Some("hlr1") => task.method = Some(ParsingMethod::HLR1),
+        Some("hlalr1") => task.method = Some(ParsingMethod::HLALR1),
         _ => {}
     }
+    task.lookahead = matches
+        .value_of("lookahead")
+        .and_then(|value| value.parse::<usize>().ok());
     if matches.is_present("debug") {
         task.print_debug_data = Some(true);
     }
+    if matches.is_present("report") {
+        task.print_resolved_conflicts = Some(true);
+    }
+    if matches.is_present("ambiguity") {
+        task.print_ambiguities = Some(true);
+    }
+    if matches.is_present("deny_warnings") {
+        task.deny_warnings = Some(true);
+    }
+    if let Some(allowed) = matches.values_of("allow") {
+        task.allowed_warnings = allowed.map(std::string::ToString::to_string).collect();
+    }
     if matches.is_present("rust_no_std") {
         task.rust_use_std = Some(false);
     }
@@ -225,6 +314,9 @@ pub fn main() -> miette::Result<()> {
     if matches.is_present("rust_compress_automata") {
         task.rust_compress_automata = Some(true);
     }
+    if matches.is_present("rust_char_lexer") {
+        task.rust_char_lexer = Some(true);
+    }
     task.grammar_name = matches
         .value_of("grammar_name")
         .map(std::string::ToString::to_string);
@@ -248,7 +340,11 @@ pub fn main() -> miette::Result<()> {
 
 /// Executes the normal operation of the compiler
 fn execute_normal<'a>(task: &CompilationTask<'a>) -> Result<(), Errors<'a>> {
-    task.execute()?;
+    let mut data = task.execute()?;
+    if !data.warnings.is_empty() {
+        let warnings = std::mem::take(&mut data.warnings);
+        println!("{}", HimeCcErrors(Errors::from(data, warnings)));
+    }
     Ok(())
 }
 
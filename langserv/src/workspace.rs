@@ -21,18 +21,20 @@ use std::fs::File;
 use std::io::{self, BufReader, ErrorKind, Read};
 use std::path::{Path, PathBuf};
 
-use hime_redist::text::TextPosition;
+use hime_redist::text::{TextPosition, TextUnit};
 use hime_sdk::errors::Error;
 use hime_sdk::grammars::{
     Grammar, RuleBodyElement, Symbol, SymbolRef, OPTION_AXIOM, OPTION_SEPARATOR,
 };
+use hime_sdk::lints;
 use hime_sdk::{CompilationTask, Input, InputReference, LoadedData, LoadedInput};
 use serde_json::Value;
 use tower_lsp::jsonrpc::Error as JsonRpcError;
 use tower_lsp::lsp_types::{
-    CodeLens, Command, Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity,
+    CodeLens, Command, Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, DiagnosticTag,
     DidChangeTextDocumentParams, FileChangeType, FileEvent, GotoDefinitionResponse, Hover,
-    HoverContents, Location, MarkedString, Position, Range, SymbolInformation, SymbolKind, Url,
+    HoverContents, Location, MarkedString, NumberOrString, Position, Range, SymbolInformation,
+    SymbolKind, Url,
 };
 
 use crate::symbols::{SymbolRegistry, SymbolRegistryElement};
@@ -83,20 +85,36 @@ impl WorkspaceData {
     }
 
     /// Translate an input reference to a LSP range
+    /// LSP positions use columns in UTF-16 code units.
     #[allow(clippy::cast_possible_truncation)]
     fn to_range(inputs: &[LoadedInput], input_reference: InputReference) -> Range {
-        let end = inputs[input_reference.input_index]
-            .content
-            .get_position_for(input_reference.position, input_reference.length);
+        let content = &inputs[input_reference.input_index].content;
+        let index = content.get_index_at(input_reference.position);
+        let start = content.get_position_in(index, TextUnit::Utf16);
+        let end = content.get_position_in(index + input_reference.length, TextUnit::Utf16);
         Range::new(
-            Position::new(
-                (input_reference.position.line - 1) as u32,
-                (input_reference.position.column - 1) as u32,
-            ),
+            Position::new((start.line - 1) as u32, (start.column - 1) as u32),
             Position::new((end.line - 1) as u32, (end.column - 1) as u32),
         )
     }
 
+    /// Translate a LSP position in an input to an input reference
+    fn get_reference_at(&self, input_index: usize, line: u32, character: u32) -> InputReference {
+        let content = &self.inputs[input_index].content;
+        let index = content.get_index_at_in(
+            TextPosition {
+                line: line as usize + 1,
+                column: character as usize + 1,
+            },
+            TextUnit::Utf16,
+        );
+        InputReference {
+            input_index,
+            position: content.get_position_at(index),
+            length: 0,
+        }
+    }
+
     /// Gets the symbol at a location in an input
     fn find_symbol_at(&self, location: InputReference) -> Option<&SymbolRegistryElement> {
         for symbols in &self.symbols.grammars {
@@ -247,14 +265,18 @@ impl Workspace {
         match task.load() {
             Ok(data) => {
                 let mut data = data.into_static();
-                let mut errors = Vec::new();
+                let mut errors = std::mem::take(&mut data.warnings);
                 for (index, grammar) in data.grammars.iter_mut().enumerate() {
                     if let Err(mut errs) = task.generate_in_memory(grammar, index) {
                         errors.append(&mut errs);
                     }
+                    errors.append(&mut lints::lint(grammar, index));
                 }
                 for error in &errors {
-                    if let Some((index, diag)) = to_diagnostic(&mut self.documents, &data, error) {
+                    if let Some((index, mut diag)) =
+                        to_diagnostic(&mut self.documents, &data, error)
+                    {
+                        diag.code = Some(NumberOrString::String(error.get_code().to_string()));
                         self.documents[index].diagnostics.push(diag);
                     }
                 }
@@ -268,9 +290,10 @@ impl Workspace {
             Err(errors) => {
                 let errors = errors.into_static();
                 for error in &errors.errors {
-                    if let Some((index, diag)) =
+                    if let Some((index, mut diag)) =
                         to_diagnostic(&mut self.documents, &errors.context, error)
                     {
+                        diag.code = Some(NumberOrString::String(error.get_code().to_string()));
                         self.documents[index].diagnostics.push(diag);
                     }
                 }
@@ -375,15 +398,8 @@ impl Workspace {
             .enumerate()
             .find(|(_, doc)| doc.url.as_str() == doc_uri)?
             .0;
-        let input_ref = InputReference {
-            input_index: doc_index,
-            position: TextPosition {
-                line: line as usize + 1,
-                column: character as usize + 1,
-            },
-            length: 0,
-        };
         let data = self.data.as_ref()?;
+        let input_ref = data.get_reference_at(doc_index, line, character);
         let symbol = data.find_symbol_at(input_ref)?;
         if symbol.definitions.is_empty() {
             None
@@ -416,15 +432,8 @@ impl Workspace {
             .enumerate()
             .find(|(_, doc)| doc.url.as_str() == doc_uri)?
             .0;
-        let input_ref = InputReference {
-            input_index: doc_index,
-            position: TextPosition {
-                line: line as usize + 1,
-                column: character as usize + 1,
-            },
-            length: 0,
-        };
         let data = self.data.as_ref()?;
+        let input_ref = data.get_reference_at(doc_index, line, character);
         let symbol = data.find_symbol_at(input_ref)?;
         let mut references = Vec::new();
         for input_ref in &symbol.definitions {
@@ -454,15 +463,8 @@ impl Workspace {
             .enumerate()
             .find(|(_, doc)| doc.url.as_str() == doc_uri)?
             .0;
-        let input_ref = InputReference {
-            input_index: doc_index,
-            position: TextPosition {
-                line: line as usize + 1,
-                column: character as usize + 1,
-            },
-            length: 0,
-        };
         let data = self.data.as_ref()?;
+        let input_ref = data.get_reference_at(doc_index, line, character);
         let symbol = data.find_symbol_at(input_ref)?;
         let content = match symbol.symbol_ref {
             SymbolRef::Dummy => String::from("<dummy>"),
@@ -842,6 +844,37 @@ fn to_diagnostic(
                 data: None,
             },
         )),
+        Error::UnknownUnicodeProperty(input_reference, name) => Some((
+            input_reference.input_index,
+            Diagnostic {
+                range: WorkspaceData::to_range(&data.inputs, *input_reference),
+                severity: Some(DiagnosticSeverity::ERROR),
+                code: None,
+                code_description: None,
+                source: Some(super::CRATE_NAME.to_string()),
+                message: format!("Unknown unicode script or property `{name}`"),
+                related_information: None,
+                tags: None,
+                data: None,
+            },
+        )),
+        Error::UnsupportedUnicodeVersion(input_reference, version, supported) => Some((
+            input_reference.input_index,
+            Diagnostic {
+                range: WorkspaceData::to_range(&data.inputs, *input_reference),
+                severity: Some(DiagnosticSeverity::ERROR),
+                code: None,
+                code_description: None,
+                source: Some(super::CRATE_NAME.to_string()),
+                message: format!(
+                    "Unsupported unicode version `{version}`, supported versions: {}",
+                    supported.join(", ")
+                ),
+                related_information: None,
+                tags: None,
+                data: None,
+            },
+        )),
         Error::UnsupportedNonPlane0InCharacterClass(input_reference, c) => Some((
             input_reference.input_index,
             Diagnostic {
@@ -872,7 +905,7 @@ fn to_diagnostic(
                 data: None,
             },
         )),
-        Error::OverridingPreviousTerminal(input_reference, name, _previous) => Some((
+        Error::UnicodeInByteGrammar(input_reference) => Some((
             input_reference.input_index,
             Diagnostic {
                 range: WorkspaceData::to_range(&data.inputs, *input_reference),
@@ -880,12 +913,54 @@ fn to_diagnostic(
                 code: None,
                 code_description: None,
                 source: Some(super::CRATE_NAME.to_string()),
+                message: "Unicode characters and classes cannot be used when the input is made of bytes".to_string(),
+                related_information: None,
+                tags: None,
+                data: None,
+            },
+        )),
+        Error::ByteInTextGrammar(input_reference) => Some((
+            input_reference.input_index,
+            Diagnostic {
+                range: WorkspaceData::to_range(&data.inputs, *input_reference),
+                severity: Some(DiagnosticSeverity::ERROR),
+                code: None,
+                code_description: None,
+                source: Some(super::CRATE_NAME.to_string()),
+                message: "Byte values can only be used when the input is made of bytes (option Input = \"bytes\")".to_string(),
+                related_information: None,
+                tags: None,
+                data: None,
+            },
+        )),
+        Error::OverridingPreviousTerminal(input_reference, name, _previous) => Some((
+            input_reference.input_index,
+            Diagnostic {
+                range: WorkspaceData::to_range(&data.inputs, *input_reference),
+                severity: Some(DiagnosticSeverity::WARNING),
+                code: None,
+                code_description: None,
+                source: Some(super::CRATE_NAME.to_string()),
                 message: format!("Overriding the previous definition of `{name}`"),
                 related_information: None,
                 tags: None,
                 data: None,
             },
         )),
+        Error::PrecedenceRedefined(input_reference, name, _previous) => Some((
+            input_reference.input_index,
+            Diagnostic {
+                range: WorkspaceData::to_range(&data.inputs, *input_reference),
+                severity: Some(DiagnosticSeverity::ERROR),
+                code: None,
+                code_description: None,
+                source: Some(super::CRATE_NAME.to_string()),
+                message: format!("The precedence of `{name}` is already declared"),
+                related_information: None,
+                tags: None,
+                data: None,
+            },
+        )),
         Error::GrammarNotDefined(input_reference, name) => Some((
             input_reference.input_index,
             Diagnostic {
@@ -967,6 +1042,91 @@ fn to_diagnostic(
                 },
             ))
         }
+        Error::UnreachableVariable(input_reference, _)
+        | Error::UnproductiveVariable(input_reference, _)
+        | Error::UnusedTerminal(input_reference, _)
+        | Error::UnusedFragment(input_reference, _)
+        | Error::EmptyContext(input_reference, _) => Some((
+            input_reference.input_index,
+            Diagnostic {
+                range: WorkspaceData::to_range(&data.inputs, *input_reference),
+                severity: Some(DiagnosticSeverity::WARNING),
+                code: None,
+                code_description: None,
+                source: Some(super::CRATE_NAME.to_string()),
+                message: error.to_string(),
+                related_information: None,
+                tags: if matches!(error, Error::UnproductiveVariable(..)) {
+                    None
+                } else {
+                    Some(vec![DiagnosticTag::UNNECESSARY])
+                },
+                data: None,
+            },
+        )),
+        Error::DuplicateRuleBody(input_reference, _name, previous) => Some((
+            input_reference.input_index,
+            Diagnostic {
+                range: WorkspaceData::to_range(&data.inputs, *input_reference),
+                severity: Some(DiagnosticSeverity::WARNING),
+                code: None,
+                code_description: None,
+                source: Some(super::CRATE_NAME.to_string()),
+                message: error.to_string(),
+                related_information: Some(vec![DiagnosticRelatedInformation {
+                    location: Location {
+                        uri: documents[previous.input_index].url.clone(),
+                        range: WorkspaceData::to_range(&data.inputs, *previous),
+                    },
+                    message: String::from("Previous rule with the same body"),
+                }]),
+                tags: Some(vec![DiagnosticTag::UNNECESSARY]),
+                data: None,
+            },
+        )),
+        Error::ShadowedTerminal(grammar_index, error) => {
+            let grammar = &data.grammars[*grammar_index];
+            let terminal = grammar.get_terminal(error.terminal.sid()).unwrap();
+            let input_reference = terminal.input_ref;
+            Some((
+                input_reference.input_index,
+                Diagnostic {
+                    range: WorkspaceData::to_range(&data.inputs, input_reference),
+                    severity: Some(DiagnosticSeverity::WARNING),
+                    code: None,
+                    code_description: None,
+                    source: Some(super::CRATE_NAME.to_string()),
+                    message: format!(
+                        "Terminal `{}` is never used and can never be matched",
+                        &terminal.name
+                    ),
+                    related_information: Some(
+                        error
+                            .overriders
+                            .iter()
+                            .map(|overrider| {
+                                let other = grammar.get_terminal(overrider.sid()).unwrap();
+                                DiagnosticRelatedInformation {
+                                    location: Location {
+                                        uri: documents[other.input_ref.input_index].url.clone(),
+                                        range: WorkspaceData::to_range(
+                                            &data.inputs,
+                                            other.input_ref,
+                                        ),
+                                    },
+                                    message: format!(
+                                        "{} overrides {}",
+                                        other.value, terminal.value
+                                    ),
+                                }
+                            })
+                            .collect(),
+                    ),
+                    tags: Some(vec![DiagnosticTag::UNNECESSARY]),
+                    data: None,
+                },
+            ))
+        }
         _ => None,
     }
 }
@@ -17,11 +17,13 @@
 
 //! Module for Abstract-Syntax Trees
 
+use alloc::vec::Vec;
 use core::fmt::{Display, Error, Formatter};
 use core::iter::FusedIterator;
 
 use serde::ser::{Serialize, SerializeSeq, SerializeStruct, Serializer};
 
+use crate::result::InjectionOffsets;
 use crate::symbols::{SemanticElementTrait, Symbol};
 use crate::text::{TextContext, TextPosition, TextSpan};
 use crate::tokens::{Token, TokenRepository};
@@ -140,7 +142,7 @@ impl AstCell {
 #[derive(Debug, Default, Clone)]
 pub struct AstImpl {
     /// The nodes' labels
-    nodes: BigList<AstCell>,
+    pub(crate) nodes: BigList<AstCell>,
     /// The index of the tree's root node
     root: Option<usize>,
 }
@@ -170,6 +172,59 @@ impl AstImpl {
             result
         }
     }
+    /// Grafts the tree of another AST, e.g. the tree of an injected language, under the specified leaf node
+    /// The tokens of the other AST are expected to start at index `tokens`,
+    /// and its variables and virtuals to be shifted by the specified offsets.
+    /// Returns whether the tree has been grafted
+    pub(crate) fn graft(
+        &mut self,
+        node: usize,
+        other: &AstImpl,
+        tokens: usize,
+        offsets: &InjectionOffsets,
+    ) -> bool {
+        let Some(root) = other.root else {
+            return false;
+        };
+        if self.nodes[node].count != 0 {
+            return false;
+        }
+        let cell = self.graft_cell(other, other.nodes[root], tokens, offsets);
+        self.nodes[node].count = 1;
+        self.nodes[node].first = self.nodes.push(cell) as u32;
+        true
+    }
+
+    /// Copies a cell of another AST with its descendants
+    fn graft_cell(
+        &mut self,
+        other: &AstImpl,
+        cell: AstCell,
+        tokens: usize,
+        offsets: &InjectionOffsets,
+    ) -> AstCell {
+        let children: Vec<AstCell> = (0..cell.count)
+            .map(|i| {
+                self.graft_cell(
+                    other,
+                    other.nodes[(cell.first + i) as usize],
+                    tokens,
+                    offsets,
+                )
+            })
+            .collect();
+        let first = self.store(&children, 0, children.len());
+        let index = cell.label.index();
+        let label = match cell.label.table_type() {
+            TableType::Token => TableElemRef::new(TableType::Token, index + tokens),
+            TableType::Variable => {
+                TableElemRef::new(TableType::Variable, index + offsets.variables)
+            }
+            TableType::Virtual => TableElemRef::new(TableType::Virtual, index + offsets.virtuals),
+            TableType::None => cell.label,
+        };
+        AstCell::new(label, cell.count, first as u32)
+    }
 }
 
 /// Represents a simple AST with a tree structure
@@ -329,7 +384,7 @@ impl<'s, 't, 'a> Ast<'s, 't, 'a> {
     }
 
     /// Traverses the AST from the specified node
-    fn traverse<F: FnMut(usize)>(&self, from: usize, mut action: F) {
+    pub(crate) fn traverse<F: FnMut(usize)>(&self, from: usize, mut action: F) {
         let mut stack = alloc::vec![from];
         while let Some(current) = stack.pop() {
             let cell = self.data.nodes[current];
@@ -24,7 +24,7 @@ use core::fmt::{Display, Formatter};
 use serde::Serialize;
 
 use crate::symbols::Symbol;
-use crate::text::{TextPosition, Utf16C};
+use crate::text::{Text, TextPosition, Utf16C};
 
 /// Common trait for data about an error
 pub trait ParseErrorDataTrait: Display {
@@ -77,6 +77,8 @@ pub struct ParseErrorUnexpectedChar {
     position: TextPosition,
     /// The unexpected character
     unexpected: char,
+    /// Whether the unexpected character is a byte of a binary input
+    is_byte: bool,
 }
 
 impl ParseErrorDataTrait for ParseErrorUnexpectedChar {
@@ -87,17 +89,25 @@ impl ParseErrorDataTrait for ParseErrorUnexpectedChar {
 
     /// Gets the error's length in the input (in number of characters)
     fn get_length(&self) -> usize {
-        self.unexpected.len_utf8()
+        if self.is_byte {
+            1
+        } else {
+            self.unexpected.len_utf8()
+        }
     }
 }
 
 impl Display for ParseErrorUnexpectedChar {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        write!(
-            f,
-            "Unexpected character '{}' (U+{:X})",
-            self.unexpected, self.unexpected as u32
-        )
+        if self.is_byte {
+            write!(f, "Unexpected byte 0x{:02X}", self.unexpected as u32)
+        } else {
+            write!(
+                f,
+                "Unexpected character '{}' (U+{:X})",
+                self.unexpected, self.unexpected as u32
+            )
+        }
     }
 }
 
@@ -108,6 +118,29 @@ impl ParseErrorUnexpectedChar {
         ParseErrorUnexpectedChar {
             position,
             unexpected,
+            is_byte: false,
+        }
+    }
+
+    /// Creates a new error for an unexpected byte in a binary input
+    #[must_use]
+    pub fn new_byte(position: TextPosition, unexpected: u8) -> ParseErrorUnexpectedChar {
+        ParseErrorUnexpectedChar {
+            position,
+            unexpected: char::from(unexpected),
+            is_byte: true,
+        }
+    }
+
+    /// Creates a new error for the character at the specified index in a text,
+    /// or the byte for a binary input
+    #[must_use]
+    pub fn new_at(text: &Text, index: usize) -> ParseErrorUnexpectedChar {
+        let position = text.get_position_at(index);
+        if text.is_binary() {
+            ParseErrorUnexpectedChar::new_byte(position, text.at(index) as u8)
+        } else {
+            ParseErrorUnexpectedChar::new(position, text.at(index))
         }
     }
 }
@@ -166,6 +199,92 @@ impl ParseErrorIncorrectEncodingSequence {
     }
 }
 
+/// Represents an inconsistent indentation error in the input of an indentation-sensitive lexer
+/// This occurs when the indentation of a line decreases to a column that does not match an enclosing level
+#[derive(Debug, Clone, Serialize)]
+pub struct ParseErrorInconsistentIndentation {
+    /// The error's position in the input text
+    position: TextPosition,
+    /// The column of the line's indentation
+    column: usize,
+}
+
+impl ParseErrorDataTrait for ParseErrorInconsistentIndentation {
+    /// Gets the error's position in the input
+    fn get_position(&self) -> TextPosition {
+        self.position
+    }
+
+    /// Gets the error's length in the input (in number of characters)
+    fn get_length(&self) -> usize {
+        1
+    }
+}
+
+impl Display for ParseErrorInconsistentIndentation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Inconsistent indentation: column {} does not match any enclosing level",
+            self.column
+        )
+    }
+}
+
+impl ParseErrorInconsistentIndentation {
+    /// Creates a new error
+    #[must_use]
+    pub fn new(position: TextPosition, column: usize) -> ParseErrorInconsistentIndentation {
+        ParseErrorInconsistentIndentation { position, column }
+    }
+}
+
+/// Represents an unknown terminal error in the input of a parser
+/// This occurs when an external source of tokens, e.g. a user-supplied scanner, produces a terminal that is not in the grammar
+#[derive(Debug, Clone, Serialize)]
+pub struct ParseErrorUnknownTerminal {
+    /// The error's position in the input text
+    position: TextPosition,
+    /// The error's length in the input
+    length: usize,
+    /// The identifier of the unknown terminal
+    terminal_id: u32,
+}
+
+impl ParseErrorDataTrait for ParseErrorUnknownTerminal {
+    /// Gets the error's position in the input
+    fn get_position(&self) -> TextPosition {
+        self.position
+    }
+
+    /// Gets the error's length in the input (in number of characters)
+    fn get_length(&self) -> usize {
+        self.length
+    }
+}
+
+impl Display for ParseErrorUnknownTerminal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Unknown terminal 0x{:04X}", self.terminal_id)
+    }
+}
+
+impl ParseErrorUnknownTerminal {
+    /// Creates a new error
+    #[must_use]
+    pub fn new(
+        position: TextPosition,
+        length: usize,
+        terminal_id: u32,
+    ) -> ParseErrorUnknownTerminal {
+        ParseErrorUnknownTerminal {
+            position,
+            length,
+            terminal_id,
+        }
+    }
+}
+
 /// Represents an unexpected token error in a parser
 #[derive(Debug, Clone, Serialize)]
 pub struct ParseErrorUnexpectedToken<'s> {
@@ -250,6 +369,7 @@ impl<'s> ParseErrorUnexpectedToken<'s> {
 /// Represents a lexical or syntactic error
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "type")]
+#[non_exhaustive]
 pub enum ParseError<'s> {
     /// Lexical error occurring when the end of input has been encountered while more characters were expected
     UnexpectedEndOfInput(ParseErrorEndOfInput),
@@ -261,6 +381,10 @@ pub enum ParseError<'s> {
     IncorrectUTF16NoLowSurrogate(ParseErrorIncorrectEncodingSequence),
     /// Lexical error occurring when the high surrogate encoding point is missing in a UTF-16 encoding sequence with an expected high and low surrogate pair
     IncorrectUTF16NoHighSurrogate(ParseErrorIncorrectEncodingSequence),
+    /// Lexical error occurring when the indentation of a line does not match an enclosing level
+    InconsistentIndentation(ParseErrorInconsistentIndentation),
+    /// Error occurring when an external source of tokens produces a terminal that is not in the grammar
+    UnknownTerminal(ParseErrorUnknownTerminal),
 }
 
 impl<'s> ParseErrorDataTrait for ParseError<'s> {
@@ -272,6 +396,8 @@ impl<'s> ParseErrorDataTrait for ParseError<'s> {
             ParseError::UnexpectedToken(x) => x.get_position(),
             ParseError::IncorrectUTF16NoLowSurrogate(x)
             | ParseError::IncorrectUTF16NoHighSurrogate(x) => x.get_position(),
+            ParseError::InconsistentIndentation(x) => x.get_position(),
+            ParseError::UnknownTerminal(x) => x.get_position(),
         }
     }
 
@@ -283,6 +409,23 @@ impl<'s> ParseErrorDataTrait for ParseError<'s> {
             ParseError::UnexpectedToken(x) => x.get_length(),
             ParseError::IncorrectUTF16NoLowSurrogate(x)
             | ParseError::IncorrectUTF16NoHighSurrogate(x) => x.get_length(),
+            ParseError::InconsistentIndentation(x) => x.get_length(),
+            ParseError::UnknownTerminal(x) => x.get_length(),
+        }
+    }
+}
+
+impl ParseError<'_> {
+    /// Moves this error to the specified position in the input
+    pub(crate) fn set_position(&mut self, position: TextPosition) {
+        match self {
+            ParseError::UnexpectedEndOfInput(x) => x.position = position,
+            ParseError::UnexpectedChar(x) => x.position = position,
+            ParseError::UnexpectedToken(x) => x.position = position,
+            ParseError::IncorrectUTF16NoLowSurrogate(x)
+            | ParseError::IncorrectUTF16NoHighSurrogate(x) => x.position = position,
+            ParseError::InconsistentIndentation(x) => x.position = position,
+            ParseError::UnknownTerminal(x) => x.position = position,
         }
     }
 }
@@ -296,6 +439,8 @@ impl<'s> Display for ParseError<'s> {
             ParseError::UnexpectedToken(x) => x.fmt(f),
             ParseError::IncorrectUTF16NoLowSurrogate(x)
             | ParseError::IncorrectUTF16NoHighSurrogate(x) => x.fmt(f),
+            ParseError::InconsistentIndentation(x) => x.fmt(f),
+            ParseError::UnknownTerminal(x) => x.fmt(f),
         }
     }
 }
@@ -343,4 +488,17 @@ impl<'s> ParseErrors<'s> {
         self.errors
             .push(ParseError::IncorrectUTF16NoHighSurrogate(error));
     }
+
+    /// Handles the inconsistent indentation error
+    pub fn push_error_inconsistent_indentation(
+        &mut self,
+        error: ParseErrorInconsistentIndentation,
+    ) {
+        self.errors.push(ParseError::InconsistentIndentation(error));
+    }
+
+    /// Handles the unknown terminal error
+    pub fn push_error_unknown_terminal(&mut self, error: ParseErrorUnknownTerminal) {
+        self.errors.push(ParseError::UnknownTerminal(error));
+    }
 }
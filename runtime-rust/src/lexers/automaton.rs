@@ -18,8 +18,10 @@
 //! Module for lexers' automata
 
 use alloc::vec::Vec;
+use core::slice::Iter;
+use core::str::Chars;
 
-use crate::text::{Text, Utf16C};
+use crate::text::{Text, Utf16Iter};
 use crate::utils::bin::{read_table_u16, read_table_u32, read_u32};
 
 /// Identifier of an invalid state in an automaton
@@ -33,23 +35,37 @@ pub struct MatchedTerminal {
     pub index: u16,
 }
 
+/// The unit of the input consumed by the automaton of a lexer
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum AutomatonUnit {
+    /// The automaton consumes UTF-16 code units,
+    /// characters outside plane 0 are consumed as surrogate pairs
+    #[default]
+    Utf16,
+    /// The automaton consumes Unicode scalar values (`char`)
+    Char,
+    /// The automaton consumes the bytes of a binary input
+    Byte,
+}
+
 /// Represents a transition in the automaton of a lexer
-/// A transition is matched by a range of UTF-16 code points
+/// A transition is matched by a range of values in the automaton's unit,
+/// i.e. UTF-16 code units or Unicode scalar values
 /// Its target is a state in the automaton
 #[derive(Copy, Clone)]
 pub struct AutomatonTransition {
     /// Start of the range
-    pub start: Utf16C,
+    pub start: u32,
     /// End of the range
-    pub end: Utf16C,
+    pub end: u32,
     /// The transition's target
     pub target: u32,
 }
 
 impl AutomatonTransition {
-    /// Get whether this transition matches the specified character
+    /// Get whether this transition matches the specified value
     #[must_use]
-    pub fn matches(self, c: Utf16C) -> bool {
+    pub fn matches(self, c: u32) -> bool {
         c >= self.start && c <= self.end
     }
 }
@@ -69,10 +85,11 @@ impl AutomatonTransition {
 /// u16: start of the range
 /// u16: end of the range
 /// u16: next state's index
+/// For automata on Unicode scalar values, all the fields are u32.
 #[derive(Copy, Clone)]
 pub struct AutomatonState<'a> {
     /// The automaton table
-    table: &'a [u16],
+    table: &'a StatesTable,
     /// The offset of this state within the table
     offset: usize,
 }
@@ -81,15 +98,15 @@ impl<'a> AutomatonState<'a> {
     /// Gets the number of matched terminals in this state
     #[must_use]
     pub fn get_terminals_count(&self) -> usize {
-        self.table[self.offset] as usize
+        self.table.get(self.offset) as usize
     }
 
     /// Gets the i-th matched terminal in this state
     #[must_use]
     pub fn get_terminal(&self, index: usize) -> MatchedTerminal {
         MatchedTerminal {
-            context: self.table[self.offset + index * 2 + 3],
-            index: self.table[self.offset + index * 2 + 4],
+            context: self.table.get(self.offset + index * 2 + 3) as u16,
+            index: self.table.get(self.offset + index * 2 + 4) as u16,
         }
     }
 
@@ -102,25 +119,24 @@ impl<'a> AutomatonState<'a> {
     /// Gets whether this state is a dead end (no more transition)
     #[must_use]
     pub fn is_dead_end(&self) -> bool {
-        self.table[self.offset + 1] == 0
+        self.table.get(self.offset + 1) == 0
     }
 
     /// Gets the number of non-cached transitions in this state
     #[must_use]
     pub fn get_bulk_transitions_count(&self) -> usize {
-        self.table[self.offset + 2] as usize
+        self.table.get(self.offset + 2) as usize
     }
 
     /// Gets the target of the cached transition for the specified value
     #[must_use]
-    pub fn get_cached_transition(&self, value: Utf16C) -> u32 {
-        u32::from(
-            self.table[self.offset + 3 + self.table[self.offset] as usize * 2 + value as usize],
-        )
+    pub fn get_cached_transition(&self, value: u32) -> u32 {
+        self.table
+            .get(self.offset + 3 + self.table.get(self.offset) as usize * 2 + value as usize)
     }
 
     /// Gets an iterator over all the cached transitions
-    pub fn get_cached_transitions(&self) -> impl Iterator<Item = (u16, u32)> + '_ {
+    pub fn get_cached_transitions(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
         (0..256)
             .map(|c| (c, self.get_cached_transition(c)))
             .filter(|(_, s)| *s != DEAD_STATE)
@@ -129,11 +145,11 @@ impl<'a> AutomatonState<'a> {
     /// Gets the i-th non-cached transition in this state
     #[must_use]
     pub fn get_bulk_transition(&self, index: usize) -> AutomatonTransition {
-        let offset = self.offset + 3 + self.table[self.offset] as usize * 2 + 256 + index * 3;
+        let offset = self.offset + 3 + self.table.get(self.offset) as usize * 2 + 256 + index * 3;
         AutomatonTransition {
-            start: self.table[offset],
-            end: self.table[offset + 1],
-            target: u32::from(self.table[offset + 2]),
+            start: self.table.get(offset),
+            end: self.table.get(offset + 1),
+            target: self.table.get(offset + 2),
         }
     }
 
@@ -145,7 +161,7 @@ impl<'a> AutomatonState<'a> {
 
     /// Gets the target of a transition from this state on the specified value
     #[must_use]
-    pub fn get_target_by(&self, value: Utf16C) -> u32 {
+    pub fn get_target_by(&self, value: u32) -> u32 {
         if value <= 255 {
             return self.get_cached_transition(value);
         }
@@ -159,6 +175,33 @@ impl<'a> AutomatonState<'a> {
     }
 }
 
+/// The table of states of a lexer's automaton
+/// The table is stored in the width of the automaton's binary format,
+/// so that automata on UTF-16 code units and bytes keep their compact u16 storage.
+#[derive(Clone)]
+enum StatesTable {
+    /// A table of u16, for automata on UTF-16 code units and bytes
+    U16(Vec<u16>),
+    /// A table of u32, for automata on Unicode scalar values
+    U32(Vec<u32>),
+}
+
+impl Default for StatesTable {
+    fn default() -> Self {
+        StatesTable::U16(Vec::new())
+    }
+}
+
+impl StatesTable {
+    /// Gets the value at the specified index
+    fn get(&self, index: usize) -> u32 {
+        match self {
+            StatesTable::U16(table) => u32::from(table[index]),
+            StatesTable::U32(table) => table[index],
+        }
+    }
+}
+
 /// Represents the automaton of a lexer
 /// Binary data structure of lexers:
 /// u32: number of entries in the states index table
@@ -166,31 +209,78 @@ impl<'a> AutomatonState<'a> {
 /// each entry is of the form:
 /// u32: offset of the state from the beginning of the states table in number of u16
 /// -- states table
+/// For automata on Unicode scalar values, the offsets are in number of u32 and the states table is made of u32.
 #[derive(Clone, Default)]
 pub struct Automaton {
+    /// The unit of the input consumed by this automaton
+    unit: AutomatonUnit,
     /// Table of indices in the states table
     table: Vec<u32>,
     /// Lexer's DFA table of states
-    states: Vec<u16>,
+    states: StatesTable,
     /// The number of states in the automaton
     states_count: usize,
 }
 
 impl Automaton {
-    /// Initializes a new automaton from the given binary data
+    /// Initializes a new automaton on UTF-16 code units from the given binary data
     #[must_use]
     pub fn new(data: &[u8]) -> Automaton {
         let states_count = read_u32(data, 0) as usize;
         let table = read_table_u32(data, 4, states_count);
         let rest = (data.len() - 4 - states_count * 4) / 2;
-        let states = read_table_u16(data, 4 + states_count * 4, rest);
+        let states = StatesTable::U16(read_table_u16(data, 4 + states_count * 4, rest));
+        Automaton {
+            unit: AutomatonUnit::Utf16,
+            table,
+            states,
+            states_count,
+        }
+    }
+
+    /// Initializes a new automaton on bytes from the given binary data
+    /// The format is the same as for UTF-16 code units.
+    #[must_use]
+    pub fn new_bytes(data: &[u8]) -> Automaton {
+        Automaton {
+            unit: AutomatonUnit::Byte,
+            ..Automaton::new(data)
+        }
+    }
+
+    /// Initializes a new automaton on Unicode scalar values from the given binary data
+    #[must_use]
+    pub fn new_chars(data: &[u8]) -> Automaton {
+        let states_count = read_u32(data, 0) as usize;
+        let table = read_table_u32(data, 4, states_count);
+        let rest = (data.len() - 4 - states_count * 4) / 4;
+        let states = StatesTable::U32(read_table_u32(data, 4 + states_count * 4, rest));
         Automaton {
+            unit: AutomatonUnit::Char,
             table,
             states,
             states_count,
         }
     }
 
+    /// Gets the unit of the input consumed by this automaton
+    #[must_use]
+    pub fn get_unit(&self) -> AutomatonUnit {
+        self.unit
+    }
+
+    /// Gets an iterator over the input from the specified index, in the unit consumed by this automaton
+    #[must_use]
+    pub fn iter_input<'t>(&self, input: &'t Text, from: usize) -> AutomatonInput<'t> {
+        match self.unit {
+            AutomatonUnit::Utf16 => AutomatonInput::Utf16(input.iter_utf16_from(from)),
+            AutomatonUnit::Char => {
+                AutomatonInput::Char(input.get_value(from, input.len() - from).chars())
+            }
+            AutomatonUnit::Byte => AutomatonInput::Byte(input.iter_bytes_from(from)),
+        }
+    }
+
     /// Gets the number of states in the automaton
     #[must_use]
     pub fn get_states_count(&self) -> usize {
@@ -212,6 +302,31 @@ impl Automaton {
     }
 }
 
+/// An iterator over the input of a lexer's automaton
+/// This iterator yields a tuple (value, length), where:
+/// * value is a UTF-16 code unit, a Unicode scalar value or a byte, depending on the automaton
+/// * length is the length of the value in the input
+pub enum AutomatonInput<'t> {
+    /// Iterates over UTF-16 code units
+    Utf16(Utf16Iter<'t>),
+    /// Iterates over Unicode scalar values
+    Char(Chars<'t>),
+    /// Iterates over bytes
+    Byte(Iter<'t, u8>),
+}
+
+impl Iterator for AutomatonInput<'_> {
+    type Item = (u32, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            AutomatonInput::Utf16(inner) => inner.next().map(|(c, l)| (u32::from(c), l)),
+            AutomatonInput::Char(inner) => inner.next().map(|c| (u32::from(c), c.len_utf8())),
+            AutomatonInput::Byte(inner) => inner.next().map(|b| (u32::from(*b), 1)),
+        }
+    }
+}
+
 /// Represents a match in the input
 pub struct TokenMatch {
     /// The matching DFA state
@@ -223,6 +338,18 @@ pub struct TokenMatch {
 /// Runs the lexer's DFA to match a terminal in the input ahead
 #[must_use]
 pub fn run_dfa(automaton: &Automaton, input: &Text, index: usize) -> Option<TokenMatch> {
+    run_dfa_in_contexts(automaton, input, index, |_context| true)
+}
+
+/// Runs the lexer's DFA to match a terminal in the input ahead,
+/// only considering the terminals in the contexts accepted by the specified filter
+#[must_use]
+pub fn run_dfa_in_contexts<F: Fn(u16) -> bool>(
+    automaton: &Automaton,
+    input: &Text,
+    index: usize,
+    accepts: F,
+) -> Option<TokenMatch> {
     if input.is_end(index) {
         return Some(TokenMatch {
             state: 0,
@@ -233,12 +360,14 @@ pub fn run_dfa(automaton: &Automaton, input: &Text, index: usize) -> Option<Toke
     let mut result = None;
     let mut state = 0;
     let mut position = index;
-    let mut input_iter = input.iter_utf16_from(index);
+    let mut input_iter = automaton.iter_input(input, index);
 
     while state != DEAD_STATE {
         let state_data = automaton.get_state(state);
         // Is this state a matching state ?
-        if state_data.get_terminals_count() > 0 {
+        if (0..state_data.get_terminals_count())
+            .any(|i| accepts(state_data.get_terminal(i).context))
+        {
             result = Some(TokenMatch {
                 state,
                 length: (position - index) as u32,
@@ -0,0 +1,250 @@
+/*******************************************************************************
+ * Copyright (c) 2017 Association Cénotélie (cenotelie.fr)
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Lesser General Public License as
+ * published by the Free Software Foundation, either version 3
+ * of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU Lesser General Public License for more details.
+ *
+ * You should have received a copy of the GNU Lesser General
+ * Public License along with this program.
+ * If not, see <http://www.gnu.org/licenses/>.
+ ******************************************************************************/
+
+//! Module for filters of tokens between a source of tokens and a parser
+//!
+//! Filters can drop, insert and replace tokens, e.g. for implementing a preprocessor.
+//! Filters are chained by wrapping a `FilteredSource` into another.
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+use super::{ContextProvider, TokenKernel, TokenSource};
+use crate::errors::ParseErrors;
+use crate::symbols::SID_DOLLAR;
+use crate::text::TextSpan;
+use crate::tokens::TokenRepository;
+
+/// A synthetic token produced by a filter
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SyntheticToken {
+    /// The identifier of the token's terminal
+    pub terminal_id: u32,
+    /// The span in the input text of the token's value
+    pub value: TextSpan,
+    /// The span in the input text to which the token is attributed, e.g. for reporting errors
+    pub origin: TextSpan,
+}
+
+impl SyntheticToken {
+    /// Creates a new synthetic token
+    #[must_use]
+    pub fn new(terminal_id: u32, value: TextSpan, origin: TextSpan) -> SyntheticToken {
+        SyntheticToken {
+            terminal_id,
+            value,
+            origin,
+        }
+    }
+}
+
+/// The action of a filter on a token
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenFilterAction {
+    /// Passes the token to the parser
+    Keep,
+    /// Drops the token
+    Drop,
+    /// Replaces the token with synthetic tokens
+    Replace(Vec<SyntheticToken>),
+    /// Inserts synthetic tokens before the token
+    InsertBefore(Vec<SyntheticToken>),
+    /// Inserts synthetic tokens after the token
+    InsertAfter(Vec<SyntheticToken>),
+}
+
+/// A filter of the tokens produced by a source, before they are passed to the parser
+pub trait TokenFilter {
+    /// Filters the specified token, already registered in the repository
+    /// The last token of the input is the dollar terminal (`SID_DOLLAR`) which cannot be removed:
+    /// dropping it has no effect and replacing it inserts the synthetic tokens before it.
+    fn on_token(&mut self, repository: &TokenRepository, token: TokenKernel) -> TokenFilterAction;
+}
+
+impl<F: TokenFilter + ?Sized> TokenFilter for &mut F {
+    fn on_token(&mut self, repository: &TokenRepository, token: TokenKernel) -> TokenFilterAction {
+        (**self).on_token(repository, token)
+    }
+}
+
+/// A source of tokens that applies a filter to the tokens of another source
+pub struct FilteredSource<S, F> {
+    /// The filtered source
+    source: S,
+    /// The filter to apply
+    filter: F,
+    /// The tokens that are pending to be passed to the parser
+    pending: VecDeque<TokenKernel>,
+}
+
+impl<S, F> FilteredSource<S, F> {
+    /// Creates a new source applying the specified filter
+    pub fn new(source: S, filter: F) -> FilteredSource<S, F> {
+        FilteredSource {
+            source,
+            filter,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Gets the filtered source
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<'s: 'a, 't: 'a, 'a, S, F> TokenSource<'s, 't, 'a> for FilteredSource<S, F>
+where
+    S: TokenSource<'s, 't, 'a>,
+    F: TokenFilter,
+{
+    fn get_repository(&self) -> &TokenRepository<'s, 't, 'a> {
+        self.source.get_repository()
+    }
+
+    fn get_repository_mut(&mut self) -> &mut TokenRepository<'s, 't, 'a> {
+        self.source.get_repository_mut()
+    }
+
+    fn get_errors_mut(&mut self) -> &mut ParseErrors<'s> {
+        self.source.get_errors_mut()
+    }
+
+    fn get_next_token(&mut self, contexts: &dyn ContextProvider) -> Option<TokenKernel> {
+        while self.pending.is_empty() {
+            let token = self.source.get_next_token(contexts)?;
+            let action = self.filter.on_token(self.source.get_repository(), token);
+            match action {
+                TokenFilterAction::Keep => self.pending.push_back(token),
+                TokenFilterAction::Drop => {
+                    if token.terminal_id == SID_DOLLAR {
+                        self.pending.push_back(token);
+                    }
+                }
+                TokenFilterAction::Replace(tokens) => {
+                    push_synthetics(self.source.get_repository_mut(), &mut self.pending, tokens);
+                    if token.terminal_id == SID_DOLLAR {
+                        self.pending.push_back(token);
+                    }
+                }
+                TokenFilterAction::InsertBefore(tokens) => {
+                    push_synthetics(self.source.get_repository_mut(), &mut self.pending, tokens);
+                    self.pending.push_back(token);
+                }
+                TokenFilterAction::InsertAfter(tokens) => {
+                    self.pending.push_back(token);
+                    push_synthetics(self.source.get_repository_mut(), &mut self.pending, tokens);
+                }
+            }
+        }
+        self.pending.pop_front()
+    }
+}
+
+/// Registers the synthetic tokens in the repository and queues them
+/// Tokens for unknown terminals are ignored.
+#[allow(clippy::cast_possible_truncation)]
+fn push_synthetics(
+    repository: &mut TokenRepository,
+    pending: &mut VecDeque<TokenKernel>,
+    tokens: Vec<SyntheticToken>,
+) {
+    for token in tokens {
+        let Some(terminal) = repository
+            .terminals
+            .iter()
+            .position(|terminal| terminal.id == token.terminal_id)
+        else {
+            continue;
+        };
+        let index = repository.add_synthetic(terminal, token.value, token.origin);
+        pending.push_back(TokenKernel {
+            terminal_id: token.terminal_id,
+            index: index as u32,
+        });
+    }
+}
+
+#[test]
+fn test_filter_tokens() {
+    use super::stream::TokenStreamSource;
+    use super::DefaultContextProvider;
+    use crate::symbols::Symbol;
+    use crate::text::Text;
+    use crate::tokens::TokenRepositoryImpl;
+
+    /// Expands the macro `N` to the value of its definition `#N v`
+    struct Expander {
+        definition: Option<TextSpan>,
+    }
+
+    impl TokenFilter for Expander {
+        fn on_token(
+            &mut self,
+            repository: &TokenRepository,
+            token: TokenKernel,
+        ) -> TokenFilterAction {
+            let index = token.index as usize;
+            match (token.terminal_id, repository.get_value_for(index)) {
+                (3, _) => TokenFilterAction::Drop,
+                (4, "N") if self.definition.is_none() => TokenFilterAction::Drop,
+                (4, "N") => TokenFilterAction::Replace(alloc::vec![SyntheticToken::new(
+                    4,
+                    self.definition.unwrap(),
+                    repository.get_span_for(index)
+                )]),
+                (4, _) if self.definition.is_none() => {
+                    self.definition = Some(repository.get_span_for(index));
+                    TokenFilterAction::Drop
+                }
+                _ => TokenFilterAction::Keep,
+            }
+        }
+    }
+
+    const TERMINALS: &[Symbol] = &[
+        Symbol { id: 1, name: "ε" },
+        Symbol { id: 2, name: "$" },
+        Symbol { id: 3, name: "#" },
+        Symbol { id: 4, name: "ID" },
+    ];
+    let text = Text::from_str("#N v a N");
+    let mut tokens = TokenRepositoryImpl::default();
+    let mut errors = ParseErrors::default();
+    let stream = [(3, 0, 1), (4, 1, 1), (4, 3, 1), (4, 5, 1), (4, 7, 1)]
+        .into_iter()
+        .map(|(id, index, length)| (id, TextSpan { index, length }));
+    let source = TokenStreamSource::new(
+        TokenRepository::new_mut(TERMINALS, &text, &mut tokens),
+        &mut errors,
+        stream,
+    );
+    let mut source = FilteredSource::new(source, Expander { definition: None });
+    let mut values = Vec::new();
+    while let Some(token) = source.get_next_token(&DefaultContextProvider {}) {
+        let repository = source.get_repository();
+        values.push((
+            repository.get_value_for(token.index as usize),
+            repository.get_span_for(token.index as usize).index,
+            repository.is_synthetic(token.index as usize),
+        ));
+    }
+    assert_eq!(
+        values,
+        alloc::vec![("a", 5, false), ("v", 7, true), ("", 8, false)]
+    );
+}
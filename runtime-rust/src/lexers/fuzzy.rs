@@ -22,7 +22,7 @@ use core::mem::take;
 
 use super::automaton::{Automaton, AutomatonState, TokenMatch, DEAD_STATE};
 use crate::errors::{ParseErrorEndOfInput, ParseErrorUnexpectedChar, ParseErrors};
-use crate::text::{Text, Utf16C};
+use crate::text::Text;
 
 /// Represents a DFA stack head
 #[derive(Clone)]
@@ -180,7 +180,7 @@ impl<'s, 't, 'a> FuzzyMatcher<'s, 't, 'a> {
 
     /// Runs this matcher
     pub fn run(&mut self) -> Option<TokenMatch> {
-        let mut iter = self.text.iter_utf16_from(self.origin_index);
+        let mut iter = self.automaton.iter_input(self.text, self.origin_index);
         let (current, length) = match iter.next() {
             None => (None, 0),
             Some((current, length)) => (Some(current), length),
@@ -247,19 +247,16 @@ impl<'s, 't, 'a> FuzzyMatcher<'s, 't, 'a> {
         } else {
             // a simple unexpected character
             self.errors
-                .push_error_unexpected_char(ParseErrorUnexpectedChar::new(
-                    self.text.get_position_at(index),
-                    self.text.at(index),
-                ));
+                .push_error_unexpected_char(ParseErrorUnexpectedChar::new_at(self.text, index));
         }
     }
 
     /// Constructs the solution when failed to fix the error
     fn on_failure(&mut self) -> Option<TokenMatch> {
         self.errors
-            .push_error_unexpected_char(ParseErrorUnexpectedChar::new(
-                self.text.get_position_at(self.origin_index),
-                self.text.at(self.origin_index),
+            .push_error_unexpected_char(ParseErrorUnexpectedChar::new_at(
+                self.text,
+                self.origin_index,
             ));
         None
     }
@@ -291,7 +288,7 @@ impl<'s, 't, 'a> FuzzyMatcher<'s, 't, 'a> {
         result: &mut FuzzyMatcherResult,
         head: &FuzzyMatcherHead,
         offset: usize,
-        current: Utf16C,
+        current: u32,
     ) {
         let state_data = self.automaton.get_state(head.state);
         // is it a matching state
@@ -374,7 +371,7 @@ impl<'s, 't, 'a> FuzzyMatcher<'s, 't, 'a> {
         head: &FuzzyMatcherHead,
         offset: usize,
         at_end: bool,
-        current: Utf16C,
+        current: u32,
     ) {
         let mut distance = head.get_distance() + 1;
         let mut end = result.insertions.len();
@@ -402,7 +399,7 @@ impl<'s, 't, 'a> FuzzyMatcher<'s, 't, 'a> {
         head: &FuzzyMatcherHead,
         offset: usize,
         at_end: bool,
-        current: Utf16C,
+        current: u32,
         state: u32,
         distance: usize,
     ) {
@@ -17,10 +17,19 @@
 
 //! Module for lexers' implementation
 
-use super::automaton::{run_dfa, Automaton, TokenMatch};
+use alloc::vec::Vec;
+
+use super::automaton::{run_dfa, run_dfa_in_contexts, Automaton, TokenMatch};
 use super::fuzzy::FuzzyMatcher;
-use super::{ContextProvider, LexerData, TokenKernel};
-use crate::errors::{ParseErrorUnexpectedChar, ParseErrors};
+use super::indentation::{IndentationConfig, IndentationTracker};
+use super::{
+    ContextProvider, ExternalMatch, ExternalScanner, LexerData, LexerModeAction, TokenKernel,
+    TokenSource, UserContextProvider, DEFAULT_CONTEXT,
+};
+use crate::errors::{
+    ParseErrorInconsistentIndentation, ParseErrorUnexpectedChar, ParseErrorUnknownTerminal,
+    ParseErrors,
+};
 use crate::symbols::SID_DOLLAR;
 use crate::tokens::TokenRepository;
 
@@ -38,9 +47,9 @@ fn run_fuzzy_matcher<'s, 't, 'a>(
     origin_index: usize,
 ) -> Option<TokenMatch> {
     if recovery == 0 {
-        errors.push_error_unexpected_char(ParseErrorUnexpectedChar::new(
-            repository.text.get_position_at(origin_index),
-            repository.text.at(origin_index),
+        errors.push_error_unexpected_char(ParseErrorUnexpectedChar::new_at(
+            repository.text,
+            origin_index,
         ));
         None
     } else {
@@ -64,10 +73,144 @@ fn run_fuzzy_matcher<'s, 't, 'a>(
     }
 }
 
+impl<'a> LexerData<'_, '_, 'a> {
+    /// Activates indentation-sensitive lexing with the specified configuration
+    pub fn set_indentation(&mut self, config: IndentationConfig<'a>) {
+        self.indentation = IndentationTracker::new(config, self.repository.terminals);
+    }
+
+    /// Sets the user-supplied scanner for external tokens
+    pub fn set_external_scanner(&mut self, scanner: &'a mut dyn ExternalScanner) {
+        self.external = Some(scanner);
+        self.terminal_indices = self
+            .repository
+            .terminals
+            .iter()
+            .enumerate()
+            .map(|(index, terminal)| (terminal.id, index))
+            .collect();
+        self.terminal_indices.sort_unstable();
+    }
+
+    /// Resolves a match of the external scanner at the specified index
+    /// Returns the index of the matched terminal and the length of the token
+    /// Empty matches before the end of the input are ignored, so that the lexer always progresses.
+    /// Matches of unknown terminals are reported as errors and ignored.
+    fn resolve_external(
+        &mut self,
+        index: usize,
+        the_match: ExternalMatch,
+    ) -> Option<(usize, usize)> {
+        let text = self.repository.text;
+        if the_match.length == 0 && !text.is_end(index) {
+            return None;
+        }
+        if let Ok(position) = self
+            .terminal_indices
+            .binary_search_by_key(&the_match.terminal_id, |(id, _)| *id)
+        {
+            return Some((self.terminal_indices[position].1, the_match.length));
+        }
+        self.errors
+            .push_error_unknown_terminal(ParseErrorUnknownTerminal::new(
+                text.get_position_at(index),
+                the_match.length,
+                the_match.terminal_id,
+            ));
+        None
+    }
+
+    /// Runs the external scanner, if any, before the automaton
+    /// Returns the index of the matched terminal and the length of the token
+    fn scan_external_before(&mut self, index: usize) -> Option<(usize, usize)> {
+        let text = self.repository.text;
+        let the_match = self.external.as_mut()?.scan_before(text, index)?;
+        self.resolve_external(index, the_match)
+    }
+
+    /// Runs the external scanner, if any, after the automaton
+    /// Returns the index of the matched terminal and the length of the token
+    fn scan_external_after(
+        &mut self,
+        index: usize,
+        matched: Option<(usize, usize)>,
+    ) -> Option<(usize, usize)> {
+        let text = self.repository.text;
+        let terminals = self.repository.terminals;
+        let Some(external) = self.external.as_mut() else {
+            return matched;
+        };
+        let the_match = external.scan_after(
+            text,
+            index,
+            matched.map(|(terminal, length)| ExternalMatch {
+                terminal_id: terminals[terminal].id,
+                length,
+            }),
+        )?;
+        self.resolve_external(index, the_match)
+    }
+
+    /// Skips the layout from the specified index when the lexing is indentation-sensitive
+    /// Returns the index of the next character to match
+    fn skip_layout(&mut self, index: usize) -> usize {
+        let text = self.repository.text;
+        match self.indentation.as_mut() {
+            Some(tracker) => tracker.skip_layout(text, index),
+            None => index,
+        }
+    }
+
+    /// Notifies the indentation tracker, if any, of a matched separator
+    fn on_separator(&mut self, index: usize, length: usize) {
+        let text = self.repository.text;
+        if let Some(tracker) = self.indentation.as_mut() {
+            tracker.on_separator(text, index, length);
+        }
+    }
+
+    /// Notifies the indentation tracker, if any, of a matched token
+    /// Returns whether the token has been queued by the tracker
+    fn on_token(&mut self, terminal: usize, index: usize, length: usize) -> bool {
+        let text = self.repository.text;
+        let Some(tracker) = self.indentation.as_mut() else {
+            return false;
+        };
+        if let Some(column) =
+            tracker.on_token(terminal, index, length, text.get_value(index, length))
+        {
+            self.errors.push_error_inconsistent_indentation(
+                ParseErrorInconsistentIndentation::new(text.get_position_at(index), column),
+            );
+        }
+        true
+    }
+
+    /// Notifies the indentation tracker, if any, of the end of the input
+    fn on_end(&mut self, index: usize) {
+        if let Some(tracker) = self.indentation.as_mut() {
+            tracker.on_end(index);
+        }
+    }
+
+    /// Adds the next token queued by the indentation tracker, if any, to the repository
+    fn next_pending(&mut self) -> Option<TokenKernel> {
+        let (terminal, index, length) = self.indentation.as_mut()?.next_pending()?;
+        let token_index = self.repository.add(terminal, index, length);
+        Some(TokenKernel {
+            terminal_id: self.repository.terminals[terminal].id,
+            index: token_index as u32,
+        })
+    }
+}
+
 /// Represents a context-free lexer (lexing rules do not depend on the context)
 pub struct ContextFreeLexer<'s, 't, 'a> {
     /// The lexer's innner data
     data: LexerData<'s, 't, 'a>,
+    /// The number of tokens found in the input
+    /// Tokens added afterwards, e.g. synthetic tokens, are not produced by the lexer
+    count: usize,
 }
 
 impl<'s, 't, 'a> ContextFreeLexer<'s, 't, 'a> {
@@ -87,7 +230,11 @@ impl<'s, 't, 'a> ContextFreeLexer<'s, 't, 'a> {
                 separator_id,
                 index: 0,
                 recovery: DEFAULT_RECOVERY_MATCHING_DISTANCE,
+                external: None,
+                terminal_indices: Vec::new(),
+                indentation: None,
             },
+            count: 0,
         }
     }
 
@@ -97,8 +244,9 @@ impl<'s, 't, 'a> ContextFreeLexer<'s, 't, 'a> {
             // lex all tokens now
             self.find_tokens();
             self.data.has_run = true;
+            self.count = self.data.repository.get_tokens_count();
         }
-        if self.data.index >= self.data.repository.get_tokens_count() {
+        if self.data.index >= self.count {
             return None;
         }
         let id = self.data.repository.get_symbol_id_for(self.data.index);
@@ -114,7 +262,26 @@ impl<'s, 't, 'a> ContextFreeLexer<'s, 't, 'a> {
     fn find_tokens(&mut self) {
         let mut index = 0;
         loop {
-            let mut result = run_dfa(&self.data.automaton, self.data.repository.text, index);
+            index = self.data.skip_layout(index);
+            while self.data.next_pending().is_some() {}
+            let mut result = self.data.scan_external_before(index);
+            if result.is_none() {
+                result = match run_dfa(&self.data.automaton, self.data.repository.text, index) {
+                    Some(the_match) if the_match.state == 0 => {
+                        // this is the dollar terminal, at the end of the input
+                        self.data.on_end(index);
+                        while self.data.next_pending().is_some() {}
+                        // the index of the $ symbol is always 1
+                        self.data.repository.add(1, index, 0);
+                        // exit here
+                        return;
+                    }
+                    result => self.data.scan_external_after(
+                        index,
+                        result.map(|the_match| self.get_token(&the_match)),
+                    ),
+                };
+            }
             if result.is_none() {
                 // failed to match, retry with error handling
                 result = run_fuzzy_matcher(
@@ -124,35 +291,46 @@ impl<'s, 't, 'a> ContextFreeLexer<'s, 't, 'a> {
                     self.data.recovery,
                     self.data.errors,
                     index,
-                );
+                )
+                .map(|the_match| self.get_token(&the_match));
             }
-            if let Some(the_match) = result {
-                if the_match.state == 0 {
-                    // this is the dollar terminal, at the end of the input
-                    // the index of the $ symbol is always 1
-                    self.data.repository.add(1, index, 0);
-                    // exit here
-                    return;
-                }
+            if let Some((terminal, length)) = result {
                 // matched something
-                let terminal = self
-                    .data
-                    .automaton
-                    .get_state(the_match.state)
-                    .get_terminal(0)
-                    .index as usize;
-                if self.data.repository.terminals[terminal].id != self.data.separator_id {
-                    self.data
-                        .repository
-                        .add(terminal, index, the_match.length as usize);
+                if self.data.repository.terminals[terminal].id == self.data.separator_id {
+                    self.data.on_separator(index, length);
+                } else if self.data.on_token(terminal, index, length) {
+                    while self.data.next_pending().is_some() {}
+                } else {
+                    self.data.repository.add(terminal, index, length);
                 }
-                index += the_match.length as usize;
+                index += length;
             } else {
                 // skip this character
-                index += self.data.repository.text.at(index).len_utf8();
+                index += self.data.repository.text.get_length_at(index);
             }
         }
     }
+
+    /// Gets the index of the matched terminal and the length of the token for a match of the automaton
+    fn get_token(&self, the_match: &TokenMatch) -> (usize, usize) {
+        let terminal = self
+            .data
+            .automaton
+            .get_state(the_match.state)
+            .get_terminal(0)
+            .index as usize;
+        (terminal, the_match.length as usize)
+    }
+
+    /// Sets the user-supplied scanner for external tokens
+    pub fn set_external_scanner(&mut self, scanner: &'a mut dyn ExternalScanner) {
+        self.data.set_external_scanner(scanner);
+    }
+
+    /// Activates indentation-sensitive lexing with the specified configuration
+    pub fn set_indentation(&mut self, config: IndentationConfig<'a>) {
+        self.data.set_indentation(config);
+    }
 }
 
 /// Represents a context-sensitive lexer (lexing rules do not depend on the context)
@@ -161,6 +339,14 @@ pub struct ContextSensitiveLexer<'s, 't, 'a> {
     data: LexerData<'s, 't, 'a>,
     /// The current index in the input
     input_index: usize,
+    /// The user-defined context provider, if any
+    user_contexts: Option<&'a dyn UserContextProvider>,
+    /// The actions on the stack of modes for each terminal, by terminal index
+    mode_actions: &'a [LexerModeAction],
+    /// The contexts that are used as modes, i.e. pushed by a terminal
+    mode_contexts: Vec<u16>,
+    /// The stack of modes (contexts) pushed by the matched terminals
+    modes: Vec<u16>,
 }
 
 impl<'s, 't, 'a> ContextSensitiveLexer<'s, 't, 'a> {
@@ -180,22 +366,75 @@ impl<'s, 't, 'a> ContextSensitiveLexer<'s, 't, 'a> {
                 separator_id,
                 index: 0,
                 recovery: DEFAULT_RECOVERY_MATCHING_DISTANCE,
+                external: None,
+                terminal_indices: Vec::new(),
+                indentation: None,
             },
             input_index: 0,
+            user_contexts: None,
+            mode_actions: &[],
+            mode_contexts: Vec::new(),
+            modes: Vec::new(),
         }
     }
 
+    /// Sets the actions on the stack of modes for each terminal, by terminal index
+    pub fn set_mode_actions(&mut self, actions: &'a [LexerModeAction]) {
+        self.mode_actions = actions;
+        self.mode_contexts.clear();
+        for action in actions {
+            if let LexerModeAction::Push(context) = *action {
+                if context != DEFAULT_CONTEXT && !self.mode_contexts.contains(&context) {
+                    self.mode_contexts.push(context);
+                }
+            }
+        }
+    }
+
+    /// Gets the current mode, i.e. the context on top of the stack of modes, if any
+    #[must_use]
+    pub fn get_current_mode(&self) -> Option<u16> {
+        self.modes.last().copied()
+    }
+
+    /// Sets the user-defined context provider to consult when choosing a terminal
+    pub fn set_user_context_provider(&mut self, provider: &'a dyn UserContextProvider) {
+        self.user_contexts = Some(provider);
+    }
+
     /// Gets the next token in the input
     fn get_next_token(&mut self, contexts: &dyn ContextProvider) -> Option<TokenKernel> {
         if self.data.has_run {
             return None;
         }
         loop {
-            let mut result = run_dfa(
-                &self.data.automaton,
-                self.data.repository.text,
-                self.input_index,
-            );
+            self.input_index = self.data.skip_layout(self.input_index);
+            if let Some(token) = self.data.next_pending() {
+                return Some(token);
+            }
+            let mut result = self.data.scan_external_before(self.input_index);
+            if result.is_none() {
+                result = match self.run_dfa() {
+                    Some(the_match) if the_match.state == 0 => {
+                        // this is the dollar terminal, at the end of the input
+                        self.data.on_end(self.input_index);
+                        if let Some(token) = self.data.next_pending() {
+                            return Some(token);
+                        }
+                        // the index of the $ symbol is always 1
+                        let token_index = self.data.repository.add(1, self.input_index, 0);
+                        self.data.has_run = true;
+                        return Some(TokenKernel {
+                            terminal_id: SID_DOLLAR,
+                            index: token_index as u32,
+                        });
+                    }
+                    result => {
+                        let matched = result.map(|the_match| self.get_token(&the_match, contexts));
+                        self.data.scan_external_after(self.input_index, matched)
+                    }
+                };
+            }
             if result.is_none() {
                 // failed to match, retry with error handling
                 result = run_fuzzy_matcher(
@@ -205,75 +444,146 @@ impl<'s, 't, 'a> ContextSensitiveLexer<'s, 't, 'a> {
                     self.data.recovery,
                     self.data.errors,
                     self.input_index,
-                );
+                )
+                .map(|the_match| self.get_token(&the_match, contexts));
             }
-            if let Some(the_match) = result {
-                if the_match.state == 0 {
-                    // this is the dollar terminal, at the end of the input
-                    // the index of the $ symbol is always 1
-                    let token_index = self.data.repository.add(1, self.input_index, 0);
-                    self.data.has_run = true;
-                    return Some(TokenKernel {
-                        terminal_id: SID_DOLLAR,
-                        index: token_index as u32,
-                    });
-                }
+            if let Some((terminal_index, length)) = result {
                 // matched something
-                let terminal_index = self.get_terminal_for(the_match.state, contexts);
-                let terminal_id = self.data.repository.terminals[terminal_index as usize].id;
-                if terminal_id != self.data.separator_id {
-                    let token_index = self.data.repository.add(
-                        terminal_index as usize,
-                        self.input_index,
-                        the_match.length as usize,
-                    );
-                    self.input_index += the_match.length as usize;
+                self.apply_mode_action(terminal_index);
+                let terminal_id = self.data.repository.terminals[terminal_index].id;
+                if terminal_id == self.data.separator_id {
+                    self.data.on_separator(self.input_index, length);
+                } else if self.data.on_token(terminal_index, self.input_index, length) {
+                    self.input_index += length;
+                    // the token is returned after the indentation tokens that precede it
+                    return self.data.next_pending();
+                } else {
+                    let token_index =
+                        self.data
+                            .repository
+                            .add(terminal_index, self.input_index, length);
+                    self.input_index += length;
                     return Some(TokenKernel {
                         terminal_id,
                         index: token_index as u32,
                     });
                 }
-                self.input_index += the_match.length as usize;
+                self.input_index += length;
             } else {
                 // skip this character
-                self.input_index += self.data.repository.text.at(self.input_index).len_utf8();
+                self.input_index += self.data.repository.text.get_length_at(self.input_index);
             }
         }
     }
 
+    /// Runs the lexer's DFA at the current index, taking into account the current mode
+    fn run_dfa(&self) -> Option<TokenMatch> {
+        if self.mode_contexts.is_empty() {
+            run_dfa(
+                &self.data.automaton,
+                self.data.repository.text,
+                self.input_index,
+            )
+        } else {
+            run_dfa_in_contexts(
+                &self.data.automaton,
+                self.data.repository.text,
+                self.input_index,
+                |context| self.is_in_mode(context),
+            )
+        }
+    }
+
+    /// Gets the index of the matched terminal and the length of the token for a match of the automaton
+    fn get_token(&self, the_match: &TokenMatch, contexts: &dyn ContextProvider) -> (usize, usize) {
+        let length = the_match.length as usize;
+        let terminal = self.get_terminal_for(the_match.state, length, contexts);
+        (terminal as usize, length)
+    }
+
+    /// Sets the user-supplied scanner for external tokens
+    pub fn set_external_scanner(&mut self, scanner: &'a mut dyn ExternalScanner) {
+        self.data.set_external_scanner(scanner);
+    }
+
+    /// Activates indentation-sensitive lexing with the specified configuration
+    pub fn set_indentation(&mut self, config: IndentationConfig<'a>) {
+        self.data.set_indentation(config);
+    }
+
+    /// Applies the action on the stack of modes for the matched terminal
+    fn apply_mode_action(&mut self, terminal_index: usize) {
+        match self.mode_actions.get(terminal_index) {
+            Some(LexerModeAction::Push(context)) => self.modes.push(*context),
+            Some(LexerModeAction::Pop) => {
+                self.modes.pop();
+            }
+            _ => {}
+        }
+    }
+
+    /// Gets whether the terminals in the specified context can be matched in the current mode
+    /// When the current mode is not the default context, only its terminals can be matched.
+    /// Otherwise, terminals in contexts that are used as modes cannot be matched.
+    fn is_in_mode(&self, context: u16) -> bool {
+        match self.modes.last() {
+            Some(&mode) if mode != DEFAULT_CONTEXT => context == mode,
+            _ => !self.mode_contexts.contains(&context),
+        }
+    }
+
     /// Gets the index of the terminal with the highest priority that is possible in the contexts
-    fn get_terminal_for(&self, state: u32, contexts: &dyn ContextProvider) -> u16 {
+    fn get_terminal_for(&self, state: u32, length: usize, contexts: &dyn ContextProvider) -> u16 {
         let state_data = self.data.automaton.get_state(state);
-        let mut matched = state_data.get_terminal(0);
-        let mut result = matched.index;
-        let mut id = self.data.repository.terminals[result as usize].id;
-        if id == self.data.separator_id {
-            // the separator trumps all
-            return result;
-        }
-        let mut priority =
-            contexts.get_context_priority(self.data.repository.get_count(), matched.context, id);
-        for i in 1..state_data.get_terminals_count() {
-            matched = state_data.get_terminal(i);
-            id = self.data.repository.terminals[matched.index as usize].id;
+        let mut result: Option<(u16, Option<usize>)> = None;
+        for i in 0..state_data.get_terminals_count() {
+            let matched = state_data.get_terminal(i);
+            if !self.is_in_mode(matched.context) {
+                continue;
+            }
+            let id = self.data.repository.terminals[matched.index as usize].id;
             if id == self.data.separator_id {
                 // the separator trumps all
                 return matched.index;
             }
-            let priority_candidate = contexts.get_context_priority(
-                self.data.repository.get_count(),
-                matched.context,
-                id,
-            );
-            if priority_candidate.is_none() {
-                continue;
+            let priority_candidate =
+                self.get_context_priority(length, matched.context, id, contexts);
+            match result {
+                None => result = Some((matched.index, priority_candidate)),
+                Some((_, priority)) => {
+                    if priority_candidate.is_some()
+                        && (priority.is_none() || priority_candidate < priority)
+                    {
+                        result = Some((matched.index, priority_candidate));
+                    }
+                }
             }
-            if priority.is_none() || priority_candidate.unwrap() < priority.unwrap() {
-                result = matched.index;
-                priority = priority_candidate;
+        }
+        result.map_or(state_data.get_terminal(0).index, |(index, _)| index)
+    }
+
+    /// Gets the priority of a context for a candidate terminal,
+    /// first from the parser, then from the user-defined provider if any
+    fn get_context_priority(
+        &self,
+        length: usize,
+        context: u16,
+        terminal_id: u32,
+        contexts: &dyn ContextProvider,
+    ) -> Option<usize> {
+        let priority =
+            contexts.get_context_priority(self.data.repository.get_count(), context, terminal_id);
+        match self.user_contexts {
+            None => priority,
+            Some(user_contexts) => {
+                let value = self
+                    .data
+                    .repository
+                    .text
+                    .get_value(self.input_index, length);
+                user_contexts.get_context_priority(value, context, terminal_id, priority)
             }
         }
-        result
     }
 }
 
@@ -303,6 +613,27 @@ impl<'s, 't, 'a> Lexer<'s, 't, 'a> {
         }
     }
 
+    /// Sets the user-defined context provider to consult when choosing a terminal
+    /// This has no effect on context-free lexers
+    pub fn set_user_context_provider(&mut self, provider: &'a dyn UserContextProvider) {
+        if let Lexer::ContextSensitive(ref mut lexer) = self {
+            lexer.set_user_context_provider(provider);
+        }
+    }
+
+    /// Sets the user-supplied scanner for external tokens
+    pub fn set_external_scanner(&mut self, scanner: &'a mut dyn ExternalScanner) {
+        match self {
+            Lexer::ContextFree(ref mut lexer) => lexer.set_external_scanner(scanner),
+            Lexer::ContextSensitive(ref mut lexer) => lexer.set_external_scanner(scanner),
+        }
+    }
+
+    /// Activates indentation-sensitive lexing with the specified configuration
+    pub fn set_indentation(&mut self, config: IndentationConfig<'a>) {
+        self.get_data_mut().set_indentation(config);
+    }
+
     /// Gets the next token in the input
     pub fn get_next_token(&mut self, contexts: &dyn ContextProvider) -> Option<TokenKernel> {
         match self {
@@ -311,3 +642,21 @@ impl<'s, 't, 'a> Lexer<'s, 't, 'a> {
         }
     }
 }
+
+impl<'s, 't, 'a> TokenSource<'s, 't, 'a> for Lexer<'s, 't, 'a> {
+    fn get_repository(&self) -> &TokenRepository<'s, 't, 'a> {
+        &self.get_data().repository
+    }
+
+    fn get_repository_mut(&mut self) -> &mut TokenRepository<'s, 't, 'a> {
+        &mut self.get_data_mut().repository
+    }
+
+    fn get_errors_mut(&mut self) -> &mut ParseErrors<'s> {
+        self.get_data_mut().errors
+    }
+
+    fn get_next_token(&mut self, contexts: &dyn ContextProvider) -> Option<TokenKernel> {
+        Lexer::get_next_token(self, contexts)
+    }
+}
@@ -0,0 +1,303 @@
+/*******************************************************************************
+ * Copyright (c) 2017 Association Cénotélie (cenotelie.fr)
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Lesser General Public License as
+ * published by the Free Software Foundation, either version 3
+ * of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU Lesser General Public License for more details.
+ *
+ * You should have received a copy of the GNU Lesser General
+ * Public License along with this program.
+ * If not, see <http://www.gnu.org/licenses/>.
+ ******************************************************************************/
+
+//! Module for indentation-sensitive lexing (off-side rule)
+
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::symbols::Symbol;
+use crate::text::Text;
+
+/// The default width of a tab when computing the indentation of a line
+pub const DEFAULT_TAB_WIDTH: usize = 8;
+
+/// The default pairs of brackets within which line breaks and indentation are ignored
+pub const DEFAULT_BRACKETS: &str = "()[]{}";
+
+/// The configuration of indentation-sensitive lexing
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IndentationConfig<'a> {
+    /// The identifier of the terminal produced when the indentation increases
+    pub indent_id: u32,
+    /// The identifier of the terminal produced when the indentation decreases
+    pub dedent_id: u32,
+    /// The identifier of the terminal produced at the end of a logical line
+    pub newline_id: u32,
+    /// The width of a tab, i.e. a tab moves the column to the next multiple of this width
+    pub tab_width: usize,
+    /// Whether blank lines produce a newline token
+    pub blank_lines: bool,
+    /// The pairs of brackets, each opening character followed by its closing one,
+    /// within which line breaks and indentation are ignored
+    pub brackets: &'a str,
+}
+
+/// Tracks the indentation of the lines in the input and synthesizes the corresponding tokens
+///
+/// The tracker consumes the layout (spaces, tabs and line breaks) between tokens.
+/// The indentation of a line is the column of its first token,
+/// so that lines with only separators (e.g. comments) do not change the indentation.
+pub struct IndentationTracker<'a> {
+    /// The configuration
+    config: IndentationConfig<'a>,
+    /// The index of the terminal produced when the indentation increases
+    indent: usize,
+    /// The index of the terminal produced when the indentation decreases
+    dedent: usize,
+    /// The index of the terminal produced at the end of a logical line
+    newline: usize,
+    /// The stack of indentation levels (columns)
+    levels: Vec<usize>,
+    /// The current nesting of brackets
+    depth: usize,
+    /// The column in the leading whitespace of the current line
+    column: usize,
+    /// Whether the leading whitespace of the current line is still being read
+    in_leading: bool,
+    /// Whether no token has been produced yet on the current logical line
+    at_line_start: bool,
+    /// The tokens that are pending to be added to the repository (terminal index, index, length)
+    pending: VecDeque<(usize, usize, usize)>,
+}
+
+impl<'a> IndentationTracker<'a> {
+    /// Creates a new tracker for the specified terminals
+    /// Returns `None` when the synthesized terminals are not found
+    #[must_use]
+    pub fn new(
+        config: IndentationConfig<'a>,
+        terminals: &[Symbol],
+    ) -> Option<IndentationTracker<'a>> {
+        let find = |id: u32| terminals.iter().position(|terminal| terminal.id == id);
+        Some(IndentationTracker {
+            config,
+            indent: find(config.indent_id)?,
+            dedent: find(config.dedent_id)?,
+            newline: find(config.newline_id)?,
+            levels: vec![0],
+            depth: 0,
+            column: 0,
+            in_leading: true,
+            at_line_start: true,
+            pending: VecDeque::new(),
+        })
+    }
+
+    /// Gets the next pending token (terminal index, index, length), if any
+    pub fn next_pending(&mut self) -> Option<(usize, usize, usize)> {
+        self.pending.pop_front()
+    }
+
+    /// Skips the layout (spaces, tabs and line breaks) from the specified index
+    /// Returns the index of the first character that is not part of the layout
+    pub fn skip_layout(&mut self, text: &Text, index: usize) -> usize {
+        let mut index = index;
+        while !text.is_end(index) {
+            let Some(length) = self.on_layout(text, index) else {
+                break;
+            };
+            index += length;
+        }
+        index
+    }
+
+    /// Notifies the tracker that a separator has been matched
+    /// The line breaks within the separator are handled as if they were not part of it.
+    pub fn on_separator(&mut self, text: &Text, index: usize, length: usize) {
+        let end = index + length;
+        let mut index = index;
+        while index < end {
+            if let Some(layout) = self.on_layout(text, index) {
+                index += layout;
+            } else {
+                self.in_leading = false;
+                index += text.get_length_at(index);
+            }
+        }
+    }
+
+    /// Notifies the tracker that a token has been matched
+    /// The token is queued after the indentation tokens that precede it.
+    /// Returns the column of the token when its indentation does not match an enclosing level
+    pub fn on_token(
+        &mut self,
+        terminal: usize,
+        index: usize,
+        length: usize,
+        value: &str,
+    ) -> Option<usize> {
+        let mut inconsistent = None;
+        if self.at_line_start && self.depth == 0 {
+            let column = self.column;
+            if column > self.current_level() {
+                self.levels.push(column);
+                self.pending.push_back((self.indent, index, 0));
+            } else {
+                while column < self.current_level() {
+                    self.levels.pop();
+                    self.pending.push_back((self.dedent, index, 0));
+                }
+                if column != self.current_level() {
+                    // the dedentation does not match an enclosing level
+                    // the levels are left as is so that INDENT and DEDENT tokens remain balanced
+                    inconsistent = Some(column);
+                }
+            }
+        }
+        self.at_line_start = false;
+        self.in_leading = false;
+        let mut chars = value.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            if let Some(position) = self.config.brackets.chars().position(|b| b == c) {
+                if position % 2 == 0 {
+                    self.depth += 1;
+                } else {
+                    self.depth = self.depth.saturating_sub(1);
+                }
+            }
+        }
+        self.pending.push_back((terminal, index, length));
+        inconsistent
+    }
+
+    /// Notifies the tracker that the end of the input has been reached
+    /// This closes the current logical line and all the indentation levels.
+    pub fn on_end(&mut self, index: usize) {
+        if !self.at_line_start {
+            self.pending.push_back((self.newline, index, 0));
+            self.at_line_start = true;
+        }
+        while self.levels.len() > 1 {
+            self.levels.pop();
+            self.pending.push_back((self.dedent, index, 0));
+        }
+    }
+
+    /// Gets the current indentation level
+    fn current_level(&self) -> usize {
+        self.levels.last().copied().unwrap_or_default()
+    }
+
+    /// Handles the layout character at the specified index
+    /// Returns the length of the layout, or `None` if the character is not part of the layout
+    fn on_layout(&mut self, text: &Text, index: usize) -> Option<usize> {
+        match text.at(index) {
+            '\r' => {
+                let length = if !text.is_end(index + 1) && text.at(index + 1) == '\n' {
+                    2
+                } else {
+                    1
+                };
+                self.on_line_break(index, length);
+                Some(length)
+            }
+            '\n' => {
+                self.on_line_break(index, 1);
+                Some(1)
+            }
+            ' ' => {
+                if self.in_leading {
+                    self.column += 1;
+                }
+                Some(1)
+            }
+            '\t' => {
+                if self.in_leading {
+                    let width = self.config.tab_width.max(1);
+                    self.column = (self.column / width + 1) * width;
+                }
+                Some(1)
+            }
+            '\u{000C}' => {
+                // form feed resets the indentation
+                if self.in_leading {
+                    self.column = 0;
+                }
+                Some(1)
+            }
+            _ => None,
+        }
+    }
+
+    /// Handles a line break
+    fn on_line_break(&mut self, index: usize, length: usize) {
+        if self.depth == 0 {
+            if !self.at_line_start || self.config.blank_lines {
+                self.pending.push_back((self.newline, index, length));
+            }
+            self.at_line_start = true;
+        }
+        self.column = 0;
+        self.in_leading = true;
+    }
+}
+
+#[test]
+fn test_indentation_tokens() {
+    const TERMINALS: &[Symbol] = &[
+        Symbol { id: 1, name: "ε" },
+        Symbol { id: 2, name: "$" },
+        Symbol {
+            id: 3,
+            name: "INDENT",
+        },
+        Symbol {
+            id: 4,
+            name: "DEDENT",
+        },
+        Symbol {
+            id: 5,
+            name: "NEWLINE",
+        },
+        Symbol { id: 6, name: "ID" },
+    ];
+    let config = IndentationConfig {
+        indent_id: 3,
+        dedent_id: 4,
+        newline_id: 5,
+        tab_width: 4,
+        blank_lines: false,
+        brackets: DEFAULT_BRACKETS,
+    };
+    let text = Text::from_str("a\n\tb\n\n    c\nd");
+    let mut tracker = IndentationTracker::new(config, TERMINALS).unwrap();
+    let mut index = 0;
+    loop {
+        index = tracker.skip_layout(&text, index);
+        if text.is_end(index) {
+            break;
+        }
+        assert_eq!(
+            tracker.on_token(5, index, 1, text.get_value(index, 1)),
+            None
+        );
+        index += 1;
+    }
+    tracker.on_end(index);
+    let mut terminals = Vec::new();
+    while let Some((terminal, _, _)) = tracker.next_pending() {
+        terminals.push(TERMINALS[terminal].name);
+    }
+    assert_eq!(
+        terminals,
+        vec![
+            "ID", "NEWLINE", "INDENT", "ID", "NEWLINE", "ID", "NEWLINE", "DEDENT", "ID", "NEWLINE"
+        ]
+    );
+}
@@ -18,11 +18,19 @@
 //! Module for lexers API
 
 pub mod automaton;
+pub mod filter;
 pub mod fuzzy;
 pub mod impls;
+pub mod indentation;
+pub mod stream;
+
+use alloc::vec::Vec;
 
 use crate::errors::ParseErrors;
 use crate::lexers::automaton::Automaton;
+use crate::lexers::filter::TokenFilter;
+use crate::lexers::indentation::IndentationTracker;
+use crate::text::Text;
 use crate::tokens::TokenRepository;
 
 /// Identifier of the default context
@@ -41,6 +49,127 @@ pub trait ContextProvider {
     ) -> Option<usize>;
 }
 
+/// Provides user-defined context information to a context-sensitive lexer
+///
+/// The user provider is consulted after the parser for each candidate terminal.
+/// This enables semantic information (e.g. a table of type names) to decide
+/// which terminal is produced for an ambiguous lexeme.
+pub trait UserContextProvider {
+    /// Gets the priority of the specified context required by the specified terminal
+    /// The `value` is the text of the candidate token and `parser_priority` is the priority given by the parser.
+    /// The priority is an unsigned integer. The lesser the value the higher the priority.
+    /// The absence of value represents the unavailability of the required context.
+    fn get_context_priority(
+        &self,
+        value: &str,
+        context: u16,
+        terminal_id: u32,
+        parser_priority: Option<usize>,
+    ) -> Option<usize>;
+}
+
+/// Represents a token matched by an external scanner
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ExternalMatch {
+    /// The identifier of the matched terminal
+    pub terminal_id: u32,
+    /// The length of the token in the input (in bytes)
+    /// An empty match is ignored, as if there were no match, except at the end of the input.
+    pub length: usize,
+}
+
+/// A user-supplied scanner for the tokens that cannot be matched by the lexer's automaton,
+/// typically the terminals declared as `external` in the grammar
+///
+/// The matches of terminals that are not in the grammar are reported as errors and ignored.
+pub trait ExternalScanner {
+    /// Tries to match a token at the specified index in the input, before running the lexer's automaton
+    /// This is also called at the end of the input, so that pending tokens can be produced.
+    #[allow(unused_variables)]
+    fn scan_before(&mut self, text: &Text, index: usize) -> Option<ExternalMatch> {
+        None
+    }
+
+    /// Tries to match a token at the specified index in the input, after running the lexer's automaton
+    /// The `matched` token is the one matched by the automaton, if any.
+    /// The returned token replaces it; `None` falls back to the lexer's error recovery.
+    #[allow(unused_variables)]
+    fn scan_after(
+        &mut self,
+        text: &Text,
+        index: usize,
+        matched: Option<ExternalMatch>,
+    ) -> Option<ExternalMatch> {
+        matched
+    }
+}
+
+/// The hooks of a parser into the production of its tokens
+///
+/// The hooks are combined with the builder methods, for example
+/// `ParseHooks::new().with_contexts(&provider).with_filter(&mut filter)`.
+/// The hooks that do not apply to a lexer are ignored,
+/// e.g. a user-defined context provider for a context-free lexer.
+#[derive(Default)]
+pub struct ParseHooks<'h> {
+    /// The user-defined context provider to consult when choosing a terminal, if any
+    pub contexts: Option<&'h dyn UserContextProvider>,
+    /// The user-supplied scanner for external tokens, if any
+    pub scanner: Option<&'h mut dyn ExternalScanner>,
+    /// The filter of the tokens between the lexer and the parser, if any
+    pub filter: Option<&'h mut dyn TokenFilter>,
+}
+
+impl<'h> ParseHooks<'h> {
+    /// Creates an empty set of hooks
+    #[must_use]
+    pub fn new() -> ParseHooks<'h> {
+        ParseHooks::default()
+    }
+
+    /// Sets the user-defined context provider to consult when choosing a terminal
+    #[must_use]
+    pub fn with_contexts(mut self, contexts: &'h dyn UserContextProvider) -> ParseHooks<'h> {
+        self.contexts = Some(contexts);
+        self
+    }
+
+    /// Sets the user-supplied scanner for external tokens
+    #[must_use]
+    pub fn with_scanner(mut self, scanner: &'h mut dyn ExternalScanner) -> ParseHooks<'h> {
+        self.scanner = Some(scanner);
+        self
+    }
+
+    /// Sets the filter of the tokens between the lexer and the parser
+    #[must_use]
+    pub fn with_filter(mut self, filter: &'h mut dyn TokenFilter) -> ParseHooks<'h> {
+        self.filter = Some(filter);
+        self
+    }
+}
+
+/// A source of tokens for a parser
+///
+/// The lexers generated by Hime are sources of tokens.
+/// Implementing this trait enables feeding a parser with tokens from another lexer,
+/// a preprocessor or a pre-tokenized stream.
+/// The tokens must be added to the repository before they are returned to the parser.
+pub trait TokenSource<'s, 't, 'a> {
+    /// Gets the repository of the tokens produced by this source
+    fn get_repository(&self) -> &TokenRepository<'s, 't, 'a>;
+
+    /// Gets the repository of the tokens produced by this source, for adding tokens
+    fn get_repository_mut(&mut self) -> &mut TokenRepository<'s, 't, 'a>;
+
+    /// Gets the repository for errors
+    fn get_errors_mut(&mut self) -> &mut ParseErrors<'s>;
+
+    /// Gets the next token in the input
+    /// The last token must be the dollar terminal (`SID_DOLLAR`) at the end of the input.
+    fn get_next_token(&mut self, contexts: &dyn ContextProvider) -> Option<TokenKernel>;
+}
+
 /// Implementation of the default context provider
 pub struct DefaultContextProvider {}
 
@@ -59,6 +188,18 @@ impl ContextProvider for DefaultContextProvider {
     }
 }
 
+/// An action on the lexer's stack of contexts (modes) triggered by matching a terminal
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum LexerModeAction {
+    /// No action
+    #[default]
+    None,
+    /// Pushes the context with the specified identifier
+    Push(u16),
+    /// Pops the context on top of the stack
+    Pop,
+}
+
 /// Represents the kernel of a token, i.e. the identifying information of a token
 #[derive(Debug, Default, Copy, Clone)]
 pub struct TokenKernel {
@@ -85,6 +226,12 @@ pub struct LexerData<'s, 't, 'a> {
     /// The maximum Levenshtein distance to go to for the recovery of a matching failure.
     /// A distance of 0 indicates no recovery.
     pub recovery: usize,
+    /// The user-supplied scanner for external tokens, if any
+    pub external: Option<&'a mut dyn ExternalScanner>,
+    /// The indices of the terminals sorted by identifier, for the matches of the external scanner
+    pub terminal_indices: Vec<(u32, usize)>,
+    /// The tracker of the indentation for indentation-sensitive lexing, if any
+    pub indentation: Option<IndentationTracker<'a>>,
 }
 
 pub use impls::Lexer;
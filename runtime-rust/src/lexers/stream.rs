@@ -0,0 +1,124 @@
+/*******************************************************************************
+ * Copyright (c) 2017 Association Cénotélie (cenotelie.fr)
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Lesser General Public License as
+ * published by the Free Software Foundation, either version 3
+ * of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU Lesser General Public License for more details.
+ *
+ * You should have received a copy of the GNU Lesser General
+ * Public License along with this program.
+ * If not, see <http://www.gnu.org/licenses/>.
+ ******************************************************************************/
+
+//! Module for sources of tokens from pre-tokenized streams
+
+use alloc::vec::Vec;
+
+use super::{ContextProvider, TokenKernel, TokenSource};
+use crate::errors::{ParseErrorUnknownTerminal, ParseErrors};
+use crate::symbols::SID_DOLLAR;
+use crate::text::TextSpan;
+use crate::tokens::TokenRepository;
+
+/// A source of tokens over a pre-tokenized stream
+/// Each token in the stream is given as the identifier of its terminal and its span in the input text.
+/// Tokens for unknown terminals are reported as errors and ignored.
+/// The dollar terminal is produced at the end of the input after the last token in the stream.
+pub struct TokenStreamSource<'s, 't, 'a, I>
+where
+    I: Iterator<Item = (u32, TextSpan)>,
+{
+    /// The token repository for this source
+    repository: TokenRepository<'s, 't, 'a>,
+    /// The repository for errors
+    errors: &'a mut ParseErrors<'s>,
+    /// The stream of tokens
+    tokens: I,
+    /// The indices of the terminals sorted by identifier
+    terminal_indices: Vec<(u32, usize)>,
+    /// Whether the end of the stream has been reached
+    has_run: bool,
+}
+
+impl<'s, 't, 'a, I> TokenStreamSource<'s, 't, 'a, I>
+where
+    I: Iterator<Item = (u32, TextSpan)>,
+{
+    /// Creates a new source of tokens over the specified stream
+    pub fn new(
+        repository: TokenRepository<'s, 't, 'a>,
+        errors: &'a mut ParseErrors<'s>,
+        tokens: I,
+    ) -> TokenStreamSource<'s, 't, 'a, I> {
+        let mut terminal_indices: Vec<(u32, usize)> = repository
+            .terminals
+            .iter()
+            .enumerate()
+            .map(|(index, terminal)| (terminal.id, index))
+            .collect();
+        terminal_indices.sort_unstable();
+        TokenStreamSource {
+            repository,
+            errors,
+            tokens,
+            terminal_indices,
+            has_run: false,
+        }
+    }
+}
+
+impl<'s, 't, 'a, I> TokenSource<'s, 't, 'a> for TokenStreamSource<'s, 't, 'a, I>
+where
+    I: Iterator<Item = (u32, TextSpan)>,
+{
+    fn get_repository(&self) -> &TokenRepository<'s, 't, 'a> {
+        &self.repository
+    }
+
+    fn get_repository_mut(&mut self) -> &mut TokenRepository<'s, 't, 'a> {
+        &mut self.repository
+    }
+
+    fn get_errors_mut(&mut self) -> &mut ParseErrors<'s> {
+        self.errors
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn get_next_token(&mut self, _contexts: &dyn ContextProvider) -> Option<TokenKernel> {
+        if self.has_run {
+            return None;
+        }
+        for (terminal_id, span) in self.tokens.by_ref() {
+            let Ok(position) = self
+                .terminal_indices
+                .binary_search_by_key(&terminal_id, |(id, _)| *id)
+            else {
+                self.errors
+                    .push_error_unknown_terminal(ParseErrorUnknownTerminal::new(
+                        self.repository.text.get_position_at(span.index),
+                        span.length,
+                        terminal_id,
+                    ));
+                continue;
+            };
+            let terminal = self.terminal_indices[position].1;
+            let index = self.repository.add(terminal, span.index, span.length);
+            return Some(TokenKernel {
+                terminal_id,
+                index: index as u32,
+            });
+        }
+        // the index of the $ symbol is always 1
+        self.has_run = true;
+        let index = self.repository.add(1, self.repository.text.len(), 0);
+        Some(TokenKernel {
+            terminal_id: SID_DOLLAR,
+            index: index as u32,
+        })
+    }
+}
@@ -17,6 +17,7 @@
 
 //! Module for LR(k) parsers
 
+use alloc::collections::VecDeque;
 use alloc::string::ToString;
 use alloc::vec::Vec;
 
@@ -24,15 +25,15 @@ use super::subtree::SubTree;
 use super::{
     get_op_code_base, get_op_code_tree_action, read_table_u16, read_u16, ContextProvider, LRAction,
     LRActionCode, LRColumnMap, LRContexts, LRExpected, LRProduction, Parser, Symbol, TreeAction,
-    LR_ACTION_CODE_ACCEPT, LR_ACTION_CODE_NONE, LR_ACTION_CODE_REDUCE, LR_ACTION_CODE_SHIFT,
-    LR_OP_CODE_BASE_ADD_VIRTUAL, LR_OP_CODE_BASE_SEMANTIC_ACTION, TREE_ACTION_DROP,
-    TREE_ACTION_NONE, TREE_ACTION_PROMOTE, TREE_ACTION_REPLACE_BY_CHILDREN,
+    LR_ACTION_CODE_ACCEPT, LR_ACTION_CODE_LOOKAHEAD, LR_ACTION_CODE_NONE, LR_ACTION_CODE_REDUCE,
+    LR_ACTION_CODE_SHIFT, LR_OP_CODE_BASE_ADD_VIRTUAL, LR_OP_CODE_BASE_SEMANTIC_ACTION,
+    TREE_ACTION_DROP, TREE_ACTION_NONE, TREE_ACTION_PROMOTE, TREE_ACTION_REPLACE_BY_CHILDREN,
     TREE_ACTION_REPLACE_BY_EPSILON,
 };
 use crate::ast::{AstImpl, TableElemRef, TableType};
 use crate::errors::ParseErrorUnexpectedToken;
-use crate::lexers::{Lexer, TokenKernel, DEFAULT_CONTEXT};
-use crate::symbols::{SemanticBody, SemanticElement, SemanticElementTrait};
+use crate::lexers::{TokenKernel, TokenSource, DEFAULT_CONTEXT};
+use crate::symbols::{SemanticBody, SemanticElement, SemanticElementTrait, SID_EPSILON};
 
 /// Represents the LR(k) parsing table and productions
 #[derive(Clone)]
@@ -49,6 +50,10 @@ pub struct LRkAutomaton {
     table: Vec<u16>,
     /// The table of LR productions
     productions: Vec<LRProduction>,
+    /// The nodes for the decisions on the next tokens, as lists of (terminal sid, offset in the lookahead table)
+    lookaheads: Vec<Vec<(u32, usize)>>,
+    /// The actions for the lookahead nodes, the first action is always the error
+    lookahead_table: Vec<u16>,
 }
 
 impl LRkAutomaton {
@@ -78,6 +83,24 @@ impl LRkAutomaton {
             let production = LRProduction::new(data, &mut index);
             productions.push(production);
         }
+        let mut lookaheads = Vec::new();
+        let mut lookahead_table = alloc::vec![LR_ACTION_CODE_NONE, LR_ACTION_CODE_NONE];
+        if index < data.len() {
+            let nodes_count = read_u16(data, index) as usize;
+            index += 2;
+            for _i in 0..nodes_count {
+                let count = read_u16(data, index) as usize;
+                index += 2;
+                let mut node = Vec::with_capacity(count);
+                for _j in 0..count {
+                    node.push((u32::from(read_u16(data, index)), lookahead_table.len()));
+                    lookahead_table.push(read_u16(data, index + 2));
+                    lookahead_table.push(read_u16(data, index + 4));
+                    index += 6;
+                }
+                lookaheads.push(node);
+            }
+        }
         LRkAutomaton {
             columns_count,
             states_count,
@@ -85,6 +108,8 @@ impl LRkAutomaton {
             contexts,
             table,
             productions,
+            lookaheads,
+            lookahead_table,
         }
     }
 
@@ -128,6 +153,20 @@ impl LRkAutomaton {
         }
     }
 
+    /// Gets the action in a lookahead node for the next token with the given sid
+    /// The data of a `LR_ACTION_CODE_LOOKAHEAD` action is the index of the node.
+    #[must_use]
+    pub fn get_lookahead_action(&self, node: u16, identifier: u32) -> LRAction<'_> {
+        let offset = self.lookaheads[node as usize]
+            .iter()
+            .find(|(sid, _)| *sid == identifier)
+            .map_or(0, |(_, offset)| *offset);
+        LRAction {
+            table: &self.lookahead_table,
+            offset,
+        }
+    }
+
     /// Gets the i-th production
     #[must_use]
     pub fn get_production(&self, index: usize) -> &LRProduction {
@@ -141,7 +180,7 @@ impl LRkAutomaton {
         let mut offset = self.columns_count * state as usize * 2;
         for terminal in terminals {
             let action = self.table[offset];
-            if action == LR_ACTION_CODE_SHIFT {
+            if action == LR_ACTION_CODE_SHIFT || action == LR_ACTION_CODE_LOOKAHEAD {
                 expected.shifts.push(*terminal);
             } else if action == LR_ACTION_CODE_REDUCE {
                 expected.reductions.push(*terminal);
@@ -172,9 +211,9 @@ impl LRkAstReduction {
 }
 
 /// Represents the builder of Parse Trees for LR(k) parsers
-struct LRkAstBuilder<'s, 't, 'a> {
-    /// Lexer associated to this parser
-    lexer: &'a mut Lexer<'s, 't, 'a>,
+struct LRkAstBuilder<'s, 't, 'a, 'l> {
+    /// Source of tokens (usually the lexer) associated to this parser
+    lexer: &'l mut dyn TokenSource<'s, 't, 'a>,
     /// The table of variables
     variables: &'a [Symbol<'s>],
     /// The table of virtuals
@@ -189,20 +228,20 @@ struct LRkAstBuilder<'s, 't, 'a> {
     reduction: Option<LRkAstReduction>,
 }
 
-impl<'s, 't, 'a> SemanticBody for LRkAstBuilder<'s, 't, 'a> {
+impl<'t: 'a, 'a> SemanticBody for LRkAstBuilder<'_, 't, 'a, '_> {
     fn get_element_at(&self, index: usize) -> SemanticElement {
         match &self.reduction {
             None => panic!("Not in a reduction"),
             Some(data) => {
                 let label = data.cache.get_label_at(self.handle[index]);
                 match label.table_type() {
-                    TableType::Token => SemanticElement::Token(
-                        self.lexer.get_data().repository.get_token(label.index()),
-                    ),
+                    TableType::Token => {
+                        SemanticElement::Token(self.lexer.get_repository().get_token(label.index()))
+                    }
                     TableType::Variable => SemanticElement::Variable(self.variables[label.index()]),
                     TableType::Virtual => SemanticElement::Virtual(self.virtuals[label.index()]),
                     TableType::None => {
-                        SemanticElement::Terminal(self.lexer.get_data().repository.terminals[0])
+                        SemanticElement::Terminal(self.lexer.get_repository().terminals[0])
                     }
                 }
             }
@@ -214,14 +253,14 @@ impl<'s, 't, 'a> SemanticBody for LRkAstBuilder<'s, 't, 'a> {
     }
 }
 
-impl<'s, 't, 'a> LRkAstBuilder<'s, 't, 'a> {
+impl<'s, 't, 'a, 'l> LRkAstBuilder<'s, 't, 'a, 'l> {
     /// Initializes the builder with the given stack size
     pub fn new(
-        lexer: &'a mut Lexer<'s, 't, 'a>,
+        lexer: &'l mut dyn TokenSource<'s, 't, 'a>,
         variables: &'a [Symbol<'s>],
         virtuals: &'a [Symbol<'s>],
         result: &'a mut AstImpl,
-    ) -> LRkAstBuilder<'s, 't, 'a> {
+    ) -> LRkAstBuilder<'s, 't, 'a, 'l> {
         LRkAstBuilder {
             lexer,
             variables,
@@ -405,6 +444,8 @@ struct LRkParserData<'s, 'a> {
     automaton: LRkAutomaton,
     /// The parser's stack
     stack: Vec<LRkHead>,
+    /// The tokens read ahead of the current one
+    lookaheads: VecDeque<TokenKernel>,
     /// The grammar variables
     variables: &'a [Symbol<'s>],
     /// The semantic actions
@@ -520,7 +561,7 @@ impl<'s, 'a> ContextProvider for LRkParserData<'s, 'a> {
     }
 }
 
-impl<'s, 't, 'a> LRkParserData<'s, 'a> {
+impl<'s, 't: 'a, 'a, 'l> LRkParserData<'s, 'a> {
     /// Checks whether the specified terminal is indeed expected for a reduction
     /// This check is required because in the case of a base LALR graph,
     /// some terminals expected for reduction in the automaton are coming from other paths.
@@ -531,7 +572,9 @@ impl<'s, 't, 'a> LRkParserData<'s, 'a> {
             .automaton
             .get_action(my_stack[my_stack.len() - 1].state, terminal.id);
         while action.get_code() != LR_ACTION_CODE_NONE {
-            if action.get_code() == LR_ACTION_CODE_SHIFT {
+            if action.get_code() == LR_ACTION_CODE_SHIFT
+                || action.get_code() == LR_ACTION_CODE_LOOKAHEAD
+            {
                 // yep, the terminal was expected
                 return true;
             }
@@ -559,26 +602,65 @@ impl<'s, 't, 'a> LRkParserData<'s, 'a> {
         false
     }
 
-    /// Parses on the specified token kernel
-    fn parse_on_token(&mut self, kernel: TokenKernel, builder: &mut LRkAstBuilder) -> LRActionCode {
-        let stack = &mut self.stack;
+    /// Gets the token at the specified depth after the current one, reading it ahead when necessary
+    /// The tokens read ahead are lexed with the contexts for the current token.
+    fn peek_token(
+        &mut self,
+        depth: usize,
+        builder: &mut LRkAstBuilder<'s, 't, 'a, 'l>,
+    ) -> Option<TokenKernel> {
+        while self.lookaheads.len() <= depth {
+            let kernel = builder.lexer.get_next_token(&*self)?;
+            self.lookaheads.push_back(kernel);
+        }
+        Some(self.lookaheads[depth])
+    }
+
+    /// Gets the action for the specified terminal on the current head
+    /// When the action depends on the next tokens, they are read ahead to resolve it.
+    fn get_action_for(
+        &mut self,
+        terminal_id: u32,
+        builder: &mut LRkAstBuilder<'s, 't, 'a, 'l>,
+    ) -> (LRActionCode, u16) {
+        let state = self.stack[self.stack.len() - 1].state;
+        let action = self.automaton.get_action(state, terminal_id);
+        let (mut code, mut data) = (action.get_code(), action.get_data());
+        let mut depth = 0;
+        while code == LR_ACTION_CODE_LOOKAHEAD {
+            let next = self
+                .peek_token(depth, builder)
+                .map_or(SID_EPSILON, |kernel| kernel.terminal_id);
+            let action = self.automaton.get_lookahead_action(data, next);
+            code = action.get_code();
+            data = action.get_data();
+            depth += 1;
+        }
+        (code, data)
+    }
 
+    /// Parses on the specified token kernel
+    fn parse_on_token(
+        &mut self,
+        kernel: TokenKernel,
+        builder: &mut LRkAstBuilder<'s, 't, 'a, 'l>,
+    ) -> LRActionCode {
         loop {
-            let head = stack[stack.len() - 1];
-            let action = self.automaton.get_action(head.state, kernel.terminal_id);
-            if action.get_code() == LR_ACTION_CODE_SHIFT {
-                stack.push(LRkHead {
-                    state: u32::from(action.get_data()),
+            let (code, data) = self.get_action_for(kernel.terminal_id, builder);
+            if code == LR_ACTION_CODE_SHIFT {
+                self.stack.push(LRkHead {
+                    state: u32::from(data),
                     identifier: kernel.terminal_id,
                 });
                 builder.push_token(kernel.index as usize);
-                return action.get_code();
+                return code;
             }
-            if action.get_code() != LR_ACTION_CODE_REDUCE {
-                return action.get_code();
+            if code != LR_ACTION_CODE_REDUCE {
+                return code;
             }
             // now reduce
-            let production = self.automaton.get_production(action.get_data() as usize);
+            let stack = &mut self.stack;
+            let production = self.automaton.get_production(data as usize);
             let variable = LRkParserData::reduce(production, builder, &mut self.actions);
             let length = stack.len();
             stack.truncate(length - production.reduction_length);
@@ -596,7 +678,7 @@ impl<'s, 't, 'a> LRkParserData<'s, 'a> {
     /// Executes the given LR reduction
     fn reduce(
         production: &LRProduction,
-        builder: &mut LRkAstBuilder<'s, 't, 'a>,
+        builder: &mut LRkAstBuilder<'s, 't, 'a, 'l>,
         actions: &mut dyn FnMut(usize, Symbol, &dyn SemanticBody),
     ) -> Symbol<'s> {
         let variable = builder.variables[production.head];
@@ -631,23 +713,23 @@ impl<'s, 't, 'a> LRkParserData<'s, 'a> {
 }
 
 /// Represents a base for all LR(k) parsers
-pub struct LRkParser<'s, 't, 'a> {
+pub struct LRkParser<'s, 't, 'a, 'l> {
     /// The parser's data
     data: LRkParserData<'s, 'a>,
     /// The AST builder
-    builder: LRkAstBuilder<'s, 't, 'a>,
+    builder: LRkAstBuilder<'s, 't, 'a, 'l>,
 }
 
-impl<'s, 't, 'a> LRkParser<'s, 't, 'a> {
+impl<'s, 't: 'a, 'a, 'l> LRkParser<'s, 't, 'a, 'l> {
     /// Initializes a new instance of the parser
     pub fn new(
-        lexer: &'a mut Lexer<'s, 't, 'a>,
+        lexer: &'l mut dyn TokenSource<'s, 't, 'a>,
         variables: &'a [Symbol<'s>],
         virtuals: &'a [Symbol<'s>],
         automaton: LRkAutomaton,
         ast: &'a mut AstImpl,
         actions: &'a mut dyn FnMut(usize, Symbol, &dyn SemanticBody),
-    ) -> LRkParser<'s, 't, 'a> {
+    ) -> LRkParser<'s, 't, 'a, 'l> {
         LRkParser {
             data: LRkParserData {
                 automaton,
@@ -655,15 +737,25 @@ impl<'s, 't, 'a> LRkParser<'s, 't, 'a> {
                     state: 0,
                     identifier: 0
                 }],
+                lookaheads: VecDeque::new(),
                 variables,
                 actions,
             },
-            builder: LRkAstBuilder::<'s, 't, 'a>::new(lexer, variables, virtuals, ast),
+            builder: LRkAstBuilder::new(lexer, variables, virtuals, ast),
         }
     }
 
+    /// Sets the entry state in the automaton, i.e. the state for the axiom to parse
+    /// By default, the parser starts in state 0, the entry state for the first axiom.
+    pub fn set_entry_state(&mut self, state: u32) {
+        self.data.stack[0].state = state;
+    }
+
     /// Gets the next token in the kernel
     fn get_next_token(&mut self) -> Option<TokenKernel> {
+        if let Some(kernel) = self.data.lookaheads.pop_front() {
+            return Some(kernel);
+        }
         let data = &self.data;
         self.builder.lexer.get_next_token(data)
     }
@@ -673,14 +765,13 @@ impl<'s, 't, 'a> LRkParser<'s, 't, 'a> {
         let token = self
             .builder
             .lexer
-            .get_data()
-            .repository
+            .get_repository()
             .get_token(kernel.index as usize);
         let state = self.data.stack[self.data.stack.len() - 1].state;
         let expected_on_head = self
             .data
             .automaton
-            .get_expected(state, self.builder.lexer.get_data().repository.terminals);
+            .get_expected(state, self.builder.lexer.get_repository().terminals);
         let mut my_expected = Vec::new();
         for x in &expected_on_head.shifts {
             my_expected.push(*x);
@@ -702,7 +793,7 @@ impl<'s, 't, 'a> LRkParser<'s, 't, 'a> {
     }
 }
 
-impl<'s, 't, 'a> Parser for LRkParser<'s, 't, 'a> {
+impl<'t: 'a, 'a> Parser for LRkParser<'_, 't, 'a, '_> {
     fn parse(&mut self) {
         let mut kernel_maybe = self.get_next_token();
         loop {
@@ -726,8 +817,7 @@ impl<'s, 't, 'a> Parser for LRkParser<'s, 't, 'a> {
                             let error = self.build_error(kernel);
                             self.builder
                                 .lexer
-                                .get_data_mut()
-                                .errors
+                                .get_errors_mut()
                                 .push_error_unexpected_token(error);
                             // TODO: try to recover here
                             return;
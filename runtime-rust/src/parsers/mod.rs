@@ -81,6 +81,8 @@ pub const LR_ACTION_CODE_REDUCE: LRActionCode = 1;
 pub const LR_ACTION_CODE_SHIFT: LRActionCode = 2;
 /// Accept the input
 pub const LR_ACTION_CODE_ACCEPT: LRActionCode = 3;
+/// Decide by looking at the next tokens, the data is the index of the lookahead node
+pub const LR_ACTION_CODE_LOOKAHEAD: LRActionCode = 4;
 
 /// A cell in a column map for non-cached identifiers
 #[derive(Copy, Clone)]
@@ -31,7 +31,7 @@ use super::{
 };
 use crate::ast::{AstCell, AstImpl, TableElemRef, TableType};
 use crate::errors::ParseErrorUnexpectedToken;
-use crate::lexers::{Lexer, TokenKernel, DEFAULT_CONTEXT};
+use crate::lexers::{TokenKernel, TokenSource, DEFAULT_CONTEXT};
 use crate::sppf::{
     SppfImpl, SppfImplNodeRef, SppfImplNodeReplaceable, SppfImplNodeReplaceableVersion,
     SppfImplNodeVersions,
@@ -577,8 +577,8 @@ impl SPPFReduction {
 /// Represents a structure that helps build a Shared Packed Parse Forest (SPPF)
 /// A SPPF is a compact representation of multiple variants of an AST at once.
 struct SPPFBuilder<'s, 't, 'a, 'l> {
-    /// Lexer associated to this parser
-    lexer: &'l mut Lexer<'s, 't, 'a>,
+    /// Source of tokens (usually the lexer) associated to this parser
+    lexer: &'l mut dyn TokenSource<'s, 't, 'a>,
     /// The table of variables
     variables: &'a [Symbol<'s>],
     /// The table of virtuals
@@ -594,20 +594,18 @@ struct SPPFBuilder<'s, 't, 'a, 'l> {
     ast: Option<&'a mut AstImpl>,
 }
 
-impl<'s, 't, 'a, 'l> SemanticBody for SPPFBuilder<'s, 't, 'a, 'l> {
+impl<'t: 'a, 'a> SemanticBody for SPPFBuilder<'_, 't, 'a, '_> {
     fn get_element_at(&self, index: usize) -> SemanticElement {
         let reduction = self.reduction.as_ref().expect("Not in a reduction");
         let reference = reduction.versions[0].nodes[index];
         let label = self.sppf.get_node(reference).first_version().label;
         match label.table_type() {
             TableType::Token => {
-                SemanticElement::Token(self.lexer.get_data().repository.get_token(label.index()))
+                SemanticElement::Token(self.lexer.get_repository().get_token(label.index()))
             }
             TableType::Variable => SemanticElement::Variable(self.variables[label.index()]),
             TableType::Virtual => SemanticElement::Virtual(self.virtuals[label.index()]),
-            TableType::None => {
-                SemanticElement::Terminal(self.lexer.get_data().repository.terminals[0])
-            }
+            TableType::None => SemanticElement::Terminal(self.lexer.get_repository().terminals[0]),
         }
     }
 
@@ -624,7 +622,7 @@ impl<'s, 't, 'a, 'l> SemanticBody for SPPFBuilder<'s, 't, 'a, 'l> {
 impl<'s, 't, 'a, 'l> SPPFBuilder<'s, 't, 'a, 'l> {
     /// Initializes the builder targeting an AST
     pub fn new_ast(
-        lexer: &'l mut Lexer<'s, 't, 'a>,
+        lexer: &'l mut dyn TokenSource<'s, 't, 'a>,
         variables: &'a [Symbol<'s>],
         virtuals: &'a [Symbol<'s>],
         ast: &'a mut AstImpl,
@@ -642,7 +640,7 @@ impl<'s, 't, 'a, 'l> SPPFBuilder<'s, 't, 'a, 'l> {
 
     /// Initializes the builder targeting an SPPF
     pub fn new_sppf(
-        lexer: &'l mut Lexer<'s, 't, 'a>,
+        lexer: &'l mut dyn TokenSource<'s, 't, 'a>,
         variables: &'a [Symbol<'s>],
         virtuals: &'a [Symbol<'s>],
         sppf: &'a mut SppfImpl,
@@ -853,8 +851,7 @@ impl<'s, 't, 'a, 'l> SPPFBuilder<'s, 't, 'a, 'l> {
         match label.table_type() {
             TableType::Token => {
                 self.lexer
-                    .get_data()
-                    .repository
+                    .get_repository()
                     .get_token(label.index())
                     .get_symbol()
                     .name
@@ -1491,12 +1488,16 @@ pub struct RNGLRParser<'s, 't, 'a, 'l> {
     builder: SPPFBuilder<'s, 't, 'a, 'l>,
     /// The sub-trees for the constant nullable variables
     nullables: Vec<usize>,
+    /// The entry state in the automaton
+    entry: u32,
+    /// Whether to run on a linear LR stack while the actions are deterministic
+    hybrid: bool,
 }
 
-impl<'s, 't, 'a, 'l> RNGLRParser<'s, 't, 'a, 'l> {
+impl<'s, 't: 'a, 'a, 'l> RNGLRParser<'s, 't, 'a, 'l> {
     /// Initializes a new instance of the parser
     pub fn new_with_ast(
-        lexer: &'l mut Lexer<'s, 't, 'a>,
+        lexer: &'l mut dyn TokenSource<'s, 't, 'a>,
         variables: &'a [Symbol<'s>],
         virtuals: &'a [Symbol<'s>],
         automaton: RNGLRAutomaton,
@@ -1515,6 +1516,8 @@ impl<'s, 't, 'a, 'l> RNGLRParser<'s, 't, 'a, 'l> {
             },
             builder: SPPFBuilder::new_ast(lexer, variables, virtuals, ast),
             nullables: alloc::vec![0xFFFF_FFFF ; variables.len()],
+            entry: 0,
+            hybrid: false,
         };
         RNGLRParser::build_nullables(
             &mut parser.builder,
@@ -1528,7 +1531,7 @@ impl<'s, 't, 'a, 'l> RNGLRParser<'s, 't, 'a, 'l> {
 
     /// Initializes a new instance of the parser
     pub fn new_with_sppf(
-        lexer: &'l mut Lexer<'s, 't, 'a>,
+        lexer: &'l mut dyn TokenSource<'s, 't, 'a>,
         variables: &'a [Symbol<'s>],
         virtuals: &'a [Symbol<'s>],
         automaton: RNGLRAutomaton,
@@ -1547,6 +1550,8 @@ impl<'s, 't, 'a, 'l> RNGLRParser<'s, 't, 'a, 'l> {
             },
             builder: SPPFBuilder::new_sppf(lexer, variables, virtuals, sppf),
             nullables: alloc::vec![0xFFFF_FFFF ; variables.len()],
+            entry: 0,
+            hybrid: false,
         };
         RNGLRParser::build_nullables(
             &mut parser.builder,
@@ -1558,6 +1563,20 @@ impl<'s, 't, 'a, 'l> RNGLRParser<'s, 't, 'a, 'l> {
         parser
     }
 
+    /// Sets the entry state in the automaton, i.e. the state for the axiom to parse
+    /// By default, the parser starts in state 0, the entry state for the first axiom.
+    pub fn set_entry_state(&mut self, state: u32) {
+        self.entry = state;
+    }
+
+    /// Sets whether the parser runs as a hybrid deterministic LR and GLR parser
+    /// When set, the parser stays on a linear LR stack as long as the automaton has a single action,
+    /// switches to the GSS for the conflicting cells and back when the GSS has a single head again.
+    /// The produced trees are the same as with the GLR algorithm.
+    pub fn set_hybrid(&mut self, hybrid: bool) {
+        self.hybrid = hybrid;
+    }
+
     /// Builds the constant sub-trees of nullable variables
     fn build_nullables(
         builder: &mut SPPFBuilder<'s, 't, 'a, 'l>,
@@ -1847,6 +1866,154 @@ impl<'s, 't, 'a, 'l> RNGLRParser<'s, 't, 'a, 'l> {
         }
     }
 
+    /// Executes the deterministic actions from the single head of the current generation on a linear LR stack
+    /// The GSS is kept up-to-date so that the parser can switch back to the GLR algorithm at any point,
+    /// i.e. when a cell does not have a single action or when a reduction goes beyond the linear stack.
+    /// Returns the current generation.
+    fn parse_deterministic(&mut self, mut generation: usize) -> usize {
+        let base = self.data.gss.get_generation(generation).start;
+        // the nodes on the linear stack above the base, with the label of the edge to the node below
+        let mut stack: Vec<(usize, GSSLabel)> = Vec::new();
+        loop {
+            let token_id = self.data.get_next_token_id();
+            if token_id == SID_EPSILON {
+                break;
+            }
+            let head = stack.last().map_or(base, |(node, _)| *node);
+            let state = self.data.gss.get_represented_state(head);
+            if self.data.automaton.get_actions_count(state, token_id) != 1 {
+                break;
+            }
+            let action = self.data.automaton.get_action(state, token_id, 0);
+            let (code, data) = (action.get_code(), action.get_data());
+            if code == LR_ACTION_CODE_SHIFT {
+                // the pending shift is visible to the lexer when looking for the next token
+                self.data.reductions.clear();
+                self.data.shifts.clear();
+                self.data.shifts.push_back(RNGLRShift {
+                    from: head,
+                    to: data as usize,
+                });
+                let old_token = self.data.next_token.unwrap();
+                self.get_next_token();
+                self.data.shifts.clear();
+                generation = self.data.gss.create_generation();
+                let symbol = TableElemRef::new(TableType::Token, old_token.index as usize);
+                let label = GSSLabel {
+                    sppf_node: self.builder.get_single_node(symbol),
+                    symbol_id: old_token.terminal_id,
+                };
+                let node = self.data.gss.create_node(u32::from(data));
+                self.data.gss.create_edge(node, head, label);
+                stack.push((node, label));
+            } else if code == LR_ACTION_CODE_REDUCE {
+                let index = data as usize;
+                let production = self.data.automaton.get_production(index);
+                let length = production.reduction_length;
+                if length > stack.len() {
+                    // the reduction goes into the GSS
+                    break;
+                }
+                let last_node = if length == stack.len() {
+                    base
+                } else {
+                    stack[stack.len() - 1 - length].0
+                };
+                let head = self.data.variables[production.head];
+                let to = self
+                    .data
+                    .get_next_by_var(self.data.gss.get_represented_state(last_node), head.id)
+                    .unwrap();
+                if self.data.gss.find_node(generation, to).is_some() {
+                    // the GLR algorithm shall merge with the existing node
+                    break;
+                }
+                self.data.reductions.clear();
+                self.data.shifts.clear();
+                let sppf_node = if self.data.automaton.nullables[production.head] as usize == index
+                {
+                    // nullable production, use the nullable node
+                    SppfImplNodeRef::new_usize(self.nullables[production.head])
+                } else {
+                    let first = if length == 0 {
+                        EPSILON
+                    } else {
+                        stack[stack.len() - 1].1
+                    };
+                    let mut path = GSSPath::new(last_node, generation, length);
+                    for i in 1..length {
+                        path.labels.push(stack[stack.len() - 1 - i].1);
+                    }
+                    RNGLRParser::build_sppf(
+                        &mut self.builder,
+                        &mut self.data.actions,
+                        &self.nullables,
+                        production,
+                        first,
+                        &path,
+                        None,
+                    )
+                };
+                let label = GSSLabel {
+                    sppf_node,
+                    symbol_id: head.id,
+                };
+                let node = self.data.gss.create_node(to);
+                self.data.gss.create_edge(node, last_node, label);
+                stack.truncate(stack.len() - length);
+                stack.push((node, label));
+            } else {
+                break;
+            }
+        }
+        if let Some(&(head, label)) = stack.last() {
+            let below = if stack.len() > 1 {
+                stack[stack.len() - 2].0
+            } else {
+                base
+            };
+            self.parse_deterministic_schedule(head, below, label);
+        }
+        generation
+    }
+
+    /// Schedules the actions on the head of the linear stack for the GLR algorithm
+    /// The head has a single edge to the node below, with the specified label.
+    fn parse_deterministic_schedule(&mut self, head: usize, below: usize, label: GSSLabel) {
+        let state = self.data.gss.get_represented_state(head);
+        let token_id = self.data.get_next_token_id();
+        let count = self.data.automaton.get_actions_count(state, token_id);
+        for i in 0..count {
+            let action = self.data.automaton.get_action(state, token_id, i);
+            if action.get_code() == LR_ACTION_CODE_SHIFT {
+                self.data.shifts.push_back(RNGLRShift {
+                    from: head,
+                    to: action.get_data() as usize,
+                });
+            } else if action.get_code() == LR_ACTION_CODE_REDUCE {
+                let production = self
+                    .data
+                    .automaton
+                    .get_production(action.get_data() as usize);
+                self.data
+                    .reductions
+                    .push_back(if production.reduction_length == 0 {
+                        RNGLRReduction {
+                            node: head,
+                            production: action.get_data() as usize,
+                            first: EPSILON,
+                        }
+                    } else {
+                        RNGLRReduction {
+                            node: below,
+                            production: action.get_data() as usize,
+                            first: label,
+                        }
+                    });
+            }
+        }
+    }
+
     /// Executes the shift operations for the given token
     fn parse_shifts(&mut self, old_token: TokenKernel) -> usize {
         // Create next generation
@@ -1872,8 +2039,7 @@ impl<'s, 't, 'a, 'l> RNGLRParser<'s, 't, 'a, 'l> {
         let token = self
             .builder
             .lexer
-            .get_data()
-            .repository
+            .get_repository()
             .get_token(kernel.index as usize);
         let mut my_states = Vec::new();
         let mut my_expected = Vec::new();
@@ -1887,7 +2053,7 @@ impl<'s, 't, 'a, 'l> RNGLRParser<'s, 't, 'a, 'l> {
             let expected_on_head = self
                 .data
                 .automaton
-                .get_expected(state, self.builder.lexer.get_data().repository.terminals);
+                .get_expected(state, self.builder.lexer.get_repository().terminals);
             // register the terminals for shift actions
             for symbol in &expected_on_head.shifts {
                 if !my_expected.contains(symbol) {
@@ -1919,10 +2085,10 @@ impl<'s, 't, 'a, 'l> RNGLRParser<'s, 't, 'a, 'l> {
     }
 }
 
-impl<'s, 't, 'a, 'l> Parser for RNGLRParser<'s, 't, 'a, 'l> {
+impl<'t: 'a, 'a> Parser for RNGLRParser<'_, 't, 'a, '_> {
     fn parse(&mut self) {
         let mut generation = self.data.gss.create_generation();
-        let state0 = self.data.gss.create_node(0);
+        let state0 = self.data.gss.create_node(self.entry);
         self.get_next_token();
 
         // bootstrap the shifts and reductions queues
@@ -1930,12 +2096,12 @@ impl<'s, 't, 'a, 'l> Parser for RNGLRParser<'s, 't, 'a, 'l> {
             let count = self
                 .data
                 .automaton
-                .get_actions_count(0, self.data.get_next_token_id());
+                .get_actions_count(self.entry, self.data.get_next_token_id());
             for i in 0..count {
-                let action = self
-                    .data
-                    .automaton
-                    .get_action(0, self.data.get_next_token_id(), i);
+                let action =
+                    self.data
+                        .automaton
+                        .get_action(self.entry, self.data.get_next_token_id(), i);
                 if action.get_code() == LR_ACTION_CODE_SHIFT {
                     self.data.shifts.push_back(RNGLRShift {
                         from: state0,
@@ -1953,6 +2119,13 @@ impl<'s, 't, 'a, 'l> Parser for RNGLRParser<'s, 't, 'a, 'l> {
 
         // Wait for ε token
         while self.data.get_next_token_id() != SID_EPSILON {
+            if self.hybrid && self.data.gss.get_generation(generation).count == 1 {
+                // a single head, use the linear stack
+                generation = self.parse_deterministic(generation);
+                if self.data.get_next_token_id() == SID_EPSILON {
+                    break;
+                }
+            }
             // the stem length (initial number of nodes in the generation before reductions)
             let stem = self.data.gss.get_generation(generation).count;
             // apply all reduction actions
@@ -1963,8 +2136,7 @@ impl<'s, 't, 'a, 'l> Parser for RNGLRParser<'s, 't, 'a, 'l> {
                 let error = self.build_error(self.data.next_token.unwrap(), stem);
                 self.builder
                     .lexer
-                    .get_data_mut()
-                    .errors
+                    .get_errors_mut()
                     .push_error_unexpected_token(error);
                 // TODO: try to recover here
                 return;
@@ -17,15 +17,30 @@
 
 //! Module for the definition of a parse result
 
+use alloc::string::String;
+use alloc::vec::Vec;
+
 use serde::ser::{Serialize, SerializeStruct, Serializer};
 
-use crate::ast::{Ast, AstImpl};
-use crate::errors::ParseErrors;
+use crate::ast::{Ast, AstImpl, TableType};
+use crate::errors::{ParseErrorDataTrait, ParseErrors};
 use crate::sppf::{Sppf, SppfImpl};
-use crate::symbols::Symbol;
+use crate::symbols::{SemanticElementTrait, Symbol};
 use crate::text::Text;
 use crate::tokens::{TokenRepository, TokenRepositoryImpl};
 
+/// The offsets of the symbols of an injected language in the symbol tables of a host result
+/// The symbol tables of the host are expected to contain the ones of the injected language at these offsets.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct InjectionOffsets {
+    /// The offset of the terminals of the injected language
+    pub terminals: usize,
+    /// The offset of the variables of the injected language
+    pub variables: usize,
+    /// The offset of the virtuals of the injected language
+    pub virtuals: usize,
+}
+
 /// Represents the output of a parser
 pub struct ParseResult<'s, 't, 'a, T> {
     /// The table of grammar terminals
@@ -89,6 +104,65 @@ impl<'s, 't, 'a> ParseResult<'s, 't, 'a, AstImpl> {
         )
     }
 
+    /// Grafts the result of parsing the value of a token with the parser of an injected language
+    /// The tree of the injected result becomes the single child of the token's node,
+    /// which is expected to be a leaf.
+    /// The tokens and errors of the injected result are mapped back to the input text of this result.
+    pub fn graft<'s2: 's>(
+        &mut self,
+        node: usize,
+        injected: &ParseResult<'s2, '_, '_, AstImpl>,
+        offsets: &InjectionOffsets,
+    ) {
+        let label = self.parse_tree.nodes[node].label;
+        if label.table_type() != TableType::Token {
+            return;
+        }
+        let offset = self.get_tokens().get_span_for(label.index()).index;
+        for error in &injected.errors.errors {
+            let index = injected.text.get_index_at(error.get_position());
+            let mut error = error.clone();
+            error.set_position(self.text.get_position_at(offset + index));
+            self.errors.errors.push(error);
+        }
+        if injected.is_success() {
+            let tokens = self
+                .tokens
+                .graft(&injected.tokens, offsets.terminals, offset);
+            self.parse_tree
+                .graft(node, &injected.parse_tree, tokens, offsets);
+        }
+    }
+
+    /// Parses the values of the tokens for the specified terminal with the parser of an injected language
+    /// and grafts the results onto the nodes of the tokens
+    pub fn inject<'s2: 's, F>(&mut self, terminal_id: u32, offsets: &InjectionOffsets, mut parse: F)
+    where
+        F: for<'x> FnMut(&'x str) -> ParseResult<'s2, 'x, 's2, AstImpl>,
+    {
+        let targets: Vec<(usize, String)> = {
+            let ast = self.get_ast();
+            let mut targets = Vec::new();
+            if ast.has_root() {
+                let root = ast.get_root();
+                ast.traverse(root.id(), |node| {
+                    let node = ast.get_node(node);
+                    if node.get_token_index().is_some() && node.get_symbol().id == terminal_id {
+                        targets.push((
+                            node.id(),
+                            String::from(node.get_value().unwrap_or_default()),
+                        ));
+                    }
+                });
+            }
+            targets
+        };
+        for (node, value) in targets {
+            let injected = parse(&value);
+            self.graft(node, &injected, offsets);
+        }
+    }
+
     /// Gets the mutable data required for parsing
     #[must_use]
     pub fn get_parsing_data<'x>(
@@ -182,3 +256,103 @@ pub type ParseResultAst = ParseResult<'static, 'static, 'static, AstImpl>;
 
 /// A parse result with a SPPF
 pub type ParseResultSppf = ParseResult<'static, 'static, 'static, SppfImpl>;
+
+#[test]
+fn test_graft_injected_result() {
+    use crate::ast::{AstCell, TableElemRef};
+    use crate::errors::ParseErrorUnexpectedChar;
+    use crate::text::TextPosition;
+
+    const TERMINALS: &[Symbol] = &[
+        Symbol { id: 1, name: "ε" },
+        Symbol { id: 2, name: "$" },
+        Symbol {
+            id: 3,
+            name: "CODE",
+        },
+        Symbol { id: 1, name: "ε" },
+        Symbol { id: 2, name: "$" },
+        Symbol { id: 3, name: "ID" },
+    ];
+    const VARIABLES: &[Symbol] = &[
+        Symbol {
+            id: 4,
+            name: "unit",
+        },
+        Symbol {
+            id: 4,
+            name: "expr",
+        },
+    ];
+    let mut host =
+        ParseResult::<AstImpl>::new(TERMINALS, VARIABLES, &[], Text::from_str("x\n<a b>"));
+    let node = {
+        let (mut tokens, _, ast) = host.get_parsing_data();
+        tokens.add(2, 2, 5);
+        let token = AstCell::new_empty(TableElemRef::new(TableType::Token, 0));
+        let node = ast.store(&[token], 0, 1);
+        ast.store_root(AstCell::new(
+            TableElemRef::new(TableType::Variable, 0),
+            1,
+            node as u32,
+        ));
+        node
+    };
+    let mut injected = ParseResult::<AstImpl>::new(
+        &TERMINALS[3..],
+        &VARIABLES[1..],
+        &[],
+        Text::from_str("<a b>"),
+    );
+    {
+        let (mut tokens, errors, ast) = injected.get_parsing_data();
+        let a = tokens.add(2, 1, 1);
+        let b = tokens.add(2, 3, 1);
+        let children = [
+            AstCell::new_empty(TableElemRef::new(TableType::Token, a)),
+            AstCell::new_empty(TableElemRef::new(TableType::Token, b)),
+        ];
+        let first = ast.store(&children, 0, 2);
+        ast.store_root(AstCell::new(
+            TableElemRef::new(TableType::Variable, 0),
+            2,
+            first as u32,
+        ));
+        errors.push_error_unexpected_char(ParseErrorUnexpectedChar::new(
+            TextPosition { line: 1, column: 4 },
+            ' ',
+        ));
+    }
+    let offsets = InjectionOffsets {
+        terminals: 3,
+        variables: 1,
+        virtuals: 0,
+    };
+    host.graft(node, &injected, &offsets);
+
+    assert_eq!(
+        host.errors.errors[0].get_position(),
+        TextPosition { line: 2, column: 4 }
+    );
+    let ast = host.get_ast();
+    let expr = ast.get_root().child(0).child(0);
+    assert_eq!(expr.get_symbol().name, "expr");
+    let values: Vec<_> = expr
+        .children()
+        .iter()
+        .map(|child| {
+            (
+                child.get_symbol().name,
+                child.get_value(),
+                child.get_position(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        values,
+        alloc::vec![
+            ("ID", Some("a"), Some(TextPosition { line: 2, column: 2 })),
+            ("ID", Some("b"), Some(TextPosition { line: 2, column: 4 }))
+        ]
+    );
+}
@@ -51,6 +51,33 @@ pub struct Symbol<'a> {
     pub name: &'a str,
 }
 
+/// Concatenates tables of symbols into a single table of `N` symbols
+/// This is used to build the symbol tables of a parser including the ones of the injected languages.
+///
+/// # Panics
+///
+/// Raise a panic when the tables do not contain exactly `N` symbols
+#[must_use]
+pub const fn concat_symbols<'a, const N: usize>(tables: &[&[Symbol<'a>]]) -> [Symbol<'a>; N] {
+    let mut result = [Symbol { id: 0, name: "" }; N];
+    let mut count = 0;
+    let mut t = 0;
+    while t < tables.len() {
+        let mut i = 0;
+        while i < tables[t].len() {
+            result[count] = tables[t][i];
+            count += 1;
+            i += 1;
+        }
+        t += 1;
+    }
+    assert!(
+        count == N,
+        "the tables do not contain the expected number of symbols"
+    );
+    result
+}
+
 /// Implementation of `Display` for `Symbol`
 impl<'a> Display for Symbol<'a> {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
@@ -18,15 +18,19 @@
 //! Module for text-handling APIs
 
 use alloc::borrow::Cow;
-use alloc::string::{String, ToString};
+use alloc::string::String;
+use alloc::vec;
 use alloc::vec::Vec;
 use core::cmp::{Ord, Ordering};
 use core::fmt::{Display, Error, Formatter};
 // use alloc::io::Read;
+use core::slice::Iter;
 use core::str::Chars;
 
 use serde::{Deserialize, Serialize};
 
+use crate::utils::graphemes::first_grapheme_len;
+
 /// `Utf16C` represents a single UTF-16 code unit.
 /// A UTF-16 code unit is always represented as a 16 bits unsigned integer.
 /// UTF-16 code units may not represent by themselves valid Unicode code points (characters).
@@ -38,7 +42,107 @@ use serde::{Deserialize, Serialize};
 /// See [UTF-16](https://en.wikipedia.org/wiki/UTF-16) for more details.
 pub type Utf16C = u16;
 
+/// A unit for the offsets and the columns in a text
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TextUnit {
+    /// UTF-8 bytes, this is the unit of the indices and spans in a text
+    Byte,
+    /// Unicode scalar values (`char`), this is the unit of the columns in a `TextPosition`
+    Char,
+    /// UTF-16 code units, as used by the Language Server Protocol
+    Utf16,
+}
+
+/// A unit for the columns of the positions in a text
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ColumnUnit {
+    /// Unicode scalar values (`char`)
+    #[default]
+    Char,
+    /// UTF-16 code units
+    Utf16,
+    /// Extended grapheme clusters, so that combining characters do not count as columns
+    Grapheme,
+}
+
+/// The configuration for the computation of the columns in a text
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TextConfig {
+    /// The width of a tab
+    /// A tab goes to the next column that follows a multiple of this width.
+    /// With a width of 1, a tab counts as a single column.
+    pub tab_width: usize,
+    /// The unit for the columns
+    pub column_unit: ColumnUnit,
+}
+
+impl Default for TextConfig {
+    fn default() -> Self {
+        TextConfig {
+            tab_width: 1,
+            column_unit: ColumnUnit::Char,
+        }
+    }
+}
+
+impl TextConfig {
+    /// Gets the length in bytes and the width in columns of the next element in a line,
+    /// given the current column (0-based)
+    fn next_column(&self, value: &str, column: usize) -> (usize, usize) {
+        if value.starts_with('\t') {
+            return (1, self.tab_width.max(1) - column % self.tab_width.max(1));
+        }
+        match self.column_unit {
+            ColumnUnit::Char => (value.chars().next().map_or(0, char::len_utf8), 1),
+            ColumnUnit::Utf16 => value
+                .chars()
+                .next()
+                .map_or((0, 0), |c| (c.len_utf8(), c.len_utf16())),
+            ColumnUnit::Grapheme => (first_grapheme_len(value), 1),
+        }
+    }
+
+    /// Gets the length in bytes of the next element for a context pointer
+    /// A pointer has one symbol for each character, or for each grapheme when columns are graphemes.
+    fn next_pointed(&self, value: &str) -> usize {
+        if self.column_unit == ColumnUnit::Grapheme {
+            first_grapheme_len(value)
+        } else {
+            value.chars().next().map_or(0, char::len_utf8)
+        }
+    }
+
+    /// Gets the number of columns in a piece of a line
+    fn count_columns(&self, line: &str) -> usize {
+        let mut column = 0;
+        let mut rest = line;
+        while !rest.is_empty() {
+            let (length, width) = self.next_column(rest, column);
+            column += width;
+            rest = &rest[length..];
+        }
+        column
+    }
+
+    /// Gets the number of bytes in a line to go over the specified number of columns
+    /// When the columns end within an element, the element is not included.
+    fn advance_columns(&self, line: &str, columns: usize) -> usize {
+        let mut column = 0;
+        let mut offset = 0;
+        while offset < line.len() {
+            let (length, width) = self.next_column(&line[offset..], column);
+            if column + width > columns {
+                break;
+            }
+            column += width;
+            offset += length;
+        }
+        offset
+    }
+}
+
 /// Represents a span of text in an input as a starting index and length
+/// Both are expressed in bytes, so that a span can directly slice the original `&str`.
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TextSpan {
     /// The starting index
@@ -75,6 +179,7 @@ pub struct TextPosition {
     /// The 1-base line number
     pub line: usize,
     /// The 1-base column number
+    /// By default, this is in number of characters, see `TextConfig` for other units.
     pub column: usize,
 }
 
@@ -122,15 +227,29 @@ pub struct TextContext<'a> {
     pub pointer: String,
 }
 
+/// The content of the input of a parser
+#[derive(Debug, Clone)]
+enum TextContent<'a> {
+    /// A piece of text
+    Chars(Cow<'a, str>),
+    /// Binary data
+    Bytes(Cow<'a, [u8]>),
+}
+
 /// Represents the input of parser with some metadata for line endings
 /// All line numbers and column numbers are 1-based.
 /// Indices in the content are 0-based.
+/// A binary input is made of a single line and its columns are counted in bytes.
 #[derive(Debug, Clone)]
 pub struct Text<'a> {
     /// The full content of the input
-    content: Cow<'a, str>,
+    content: TextContent<'a>,
     /// Cache of the starting indices of each line within the text
     lines: Vec<usize>,
+    /// Cache of the starting offsets of each line in characters and in UTF-16 code units
+    line_offsets: Vec<(usize, usize)>,
+    /// The configuration for the computation of columns
+    config: TextConfig,
 }
 
 impl<'a> Text<'a> {
@@ -138,8 +257,13 @@ impl<'a> Text<'a> {
     #[must_use]
     pub fn into_static(self) -> Text<'static> {
         Text {
-            content: Cow::Owned(self.content.to_string()),
+            content: match self.content {
+                TextContent::Chars(content) => TextContent::Chars(Cow::Owned(content.into_owned())),
+                TextContent::Bytes(content) => TextContent::Bytes(Cow::Owned(content.into_owned())),
+            },
             lines: self.lines,
+            line_offsets: self.line_offsets,
+            config: self.config,
         }
     }
 
@@ -147,21 +271,13 @@ impl<'a> Text<'a> {
     #[allow(clippy::should_implement_trait)]
     #[must_use]
     pub fn from_str(content: &'a str) -> Text<'a> {
-        let lines = find_lines_in(content.char_indices());
-        Text {
-            content: Cow::Borrowed(content),
-            lines,
-        }
+        Text::from_cow(Cow::Borrowed(content))
     }
 
     /// Initializes this text
     #[must_use]
     pub fn from_string(content: String) -> Text<'static> {
-        let lines = find_lines_in(content.char_indices());
-        Text {
-            content: Cow::Owned(content),
-            lines,
-        }
+        Text::from_cow(Cow::Owned(content))
     }
 
     /// Initializes this text from a UTF-8 stream
@@ -175,11 +291,76 @@ impl<'a> Text<'a> {
     ) -> Result<Text<'static>, std::io::Error> {
         let mut content = String::new();
         input.read_to_string(&mut content)?;
+        Ok(Text::from_cow(Cow::Owned(content)))
+    }
+
+    /// Initializes this text and its caches for the lines
+    fn from_cow(content: Cow<'a, str>) -> Text<'a> {
         let lines = find_lines_in(content.char_indices());
-        Ok(Text {
-            content: Cow::Owned(content),
+        let line_offsets = find_line_offsets(&content, &lines);
+        Text {
+            content: TextContent::Chars(content),
             lines,
-        })
+            line_offsets,
+            config: TextConfig::default(),
+        }
+    }
+
+    /// Initializes this text from binary data
+    #[must_use]
+    pub fn from_bytes(content: &'a [u8]) -> Text<'a> {
+        Text {
+            content: TextContent::Bytes(Cow::Borrowed(content)),
+            lines: vec![0],
+            line_offsets: vec![(0, 0)],
+            config: TextConfig::default(),
+        }
+    }
+
+    /// Initializes this text from binary data
+    #[must_use]
+    pub fn from_byte_vec(content: Vec<u8>) -> Text<'static> {
+        Text {
+            content: TextContent::Bytes(Cow::Owned(content)),
+            lines: vec![0],
+            line_offsets: vec![(0, 0)],
+            config: TextConfig::default(),
+        }
+    }
+
+    /// Sets the configuration for the computation of columns
+    #[must_use]
+    pub fn with_config(mut self, config: TextConfig) -> Text<'a> {
+        self.config = config;
+        self
+    }
+
+    /// Gets the configuration for the computation of columns
+    #[must_use]
+    pub fn get_config(&self) -> TextConfig {
+        self.config
+    }
+
+    /// Gets whether this is a binary input
+    #[must_use]
+    pub fn is_binary(&self) -> bool {
+        matches!(self.content, TextContent::Bytes(_))
+    }
+
+    /// Gets the textual content, this is empty for a binary input
+    fn as_str(&self) -> &str {
+        match &self.content {
+            TextContent::Chars(content) => content,
+            TextContent::Bytes(_) => "",
+        }
+    }
+
+    /// Gets the raw content
+    fn as_bytes(&self) -> &[u8] {
+        match &self.content {
+            TextContent::Chars(content) => content.as_bytes(),
+            TextContent::Bytes(content) => content,
+        }
     }
 
     /// Gets the number of lines
@@ -191,35 +372,72 @@ impl<'a> Text<'a> {
     /// Gets whether the text is empty
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.content.is_empty()
+        self.as_bytes().is_empty()
     }
 
-    /// Gets the size in number of characters
+    /// Gets the size in number of bytes
     #[must_use]
     pub fn len(&self) -> usize {
-        self.content.len()
+        self.as_bytes().len()
     }
 
     /// Gets whether the specified index is after the end of the text represented by this object
     #[must_use]
     pub fn is_end(&self, index: usize) -> bool {
-        index >= self.content.len()
+        index >= self.len()
     }
 
     /// Gets the character at the specified index
+    /// For a binary input, this is the character with the same value as the byte.
     ///
     /// # Panics
     ///
     /// Panic when index is at or beyond the end of the content
     #[must_use]
     pub fn at(&self, index: usize) -> char {
-        self.content[index..].chars().next().unwrap()
+        match &self.content {
+            TextContent::Chars(content) => content[index..].chars().next().unwrap(),
+            TextContent::Bytes(content) => char::from(content[index]),
+        }
+    }
+
+    /// Gets the length in the content of the character at the specified index
+    /// For a binary input, this is the length of a byte.
+    ///
+    /// # Panics
+    ///
+    /// Panic when index is at or beyond the end of the content
+    #[must_use]
+    pub fn get_length_at(&self, index: usize) -> usize {
+        if self.is_binary() {
+            1
+        } else {
+            self.at(index).len_utf8()
+        }
     }
 
     /// Gets the substring beginning at the given index with the given length
+    /// For a binary input, this is empty when the bytes are not valid UTF-8.
     #[must_use]
     pub fn get_value(&self, index: usize, length: usize) -> &str {
-        &self.content[index..(index + length)]
+        match &self.content {
+            TextContent::Chars(content) => &content[index..(index + length)],
+            TextContent::Bytes(content) => {
+                core::str::from_utf8(&content[index..(index + length)]).unwrap_or_default()
+            }
+        }
+    }
+
+    /// Gets the bytes beginning at the given index with the given length
+    #[must_use]
+    pub fn get_bytes(&self, index: usize, length: usize) -> &[u8] {
+        &self.as_bytes()[index..(index + length)]
+    }
+
+    /// Get the bytes corresponding to the specified span
+    #[must_use]
+    pub fn get_bytes_for(&self, span: TextSpan) -> &[u8] {
+        self.get_bytes(span.index, span.length)
     }
 
     /// Get the substring corresponding to the specified span
@@ -232,20 +450,98 @@ impl<'a> Text<'a> {
     #[must_use]
     pub fn get_value_at(&self, position: TextPosition, length: usize) -> &str {
         let start = self.get_index_at(position);
-        &self.content[start..(start + length)]
+        self.get_value(start, length)
     }
 
     /// Gets the index within the content of the specified position
+    /// The column of the position is interpreted according to the configuration of this text.
     #[must_use]
     pub fn get_index_at(&self, position: TextPosition) -> usize {
-        let from_line = &self.content[self.lines[position.line - 1]..];
-        let in_line_offset = from_line
-            .char_indices()
-            .take(position.column - 1)
-            .last()
-            .map(|(offset, c)| offset + c.len_utf8())
-            .unwrap_or_default();
-        self.lines[position.line - 1] + in_line_offset
+        if self.is_binary() || self.config == TextConfig::default() {
+            return self.get_index_at_in(position, TextUnit::Char);
+        }
+        let start = self.lines[position.line - 1];
+        let line = &self.as_str()[start..start + self.get_line_length(position.line)];
+        start + self.config.advance_columns(line, position.column - 1)
+    }
+
+    /// Gets the index within the content of the specified position, whose column is in the specified unit
+    /// Tabs count as a single unit, regardless of the configuration.
+    #[must_use]
+    pub fn get_index_at_in(&self, position: TextPosition, unit: TextUnit) -> usize {
+        if self.is_binary() {
+            return position.column - 1;
+        }
+        let start = self.lines[position.line - 1];
+        start + self.advance(start, position.column - 1, unit)
+    }
+
+    /// Gets the number of bytes from an index to go over the specified count in the specified unit
+    /// When the count ends within a character, the character is not included.
+    fn advance(&self, from: usize, count: usize, unit: TextUnit) -> usize {
+        if unit == TextUnit::Byte {
+            return count;
+        }
+        let mut measured = 0;
+        let mut length = 0;
+        for c in self.as_str()[from..].chars() {
+            measured += if unit == TextUnit::Char {
+                1
+            } else {
+                c.len_utf16()
+            };
+            if measured > count {
+                break;
+            }
+            length += c.len_utf8();
+        }
+        length
+    }
+
+    /// Gets the offset of the specified index in the specified unit, from the beginning of the text
+    /// The cost is proportional to the length of the line containing the index.
+    #[must_use]
+    pub fn get_offset_in(&self, index: usize, unit: TextUnit) -> usize {
+        if self.is_binary() || unit == TextUnit::Byte {
+            return index;
+        }
+        let line = find_line_at(&self.lines, index);
+        let (chars, utf16) = self.line_offsets[line];
+        let start = if unit == TextUnit::Char { chars } else { utf16 };
+        start + measure(&self.as_str()[self.lines[line]..index], unit)
+    }
+
+    /// Gets the index within the content for an offset in the specified unit, from the beginning of the text
+    /// The cost is proportional to the length of the line containing the offset.
+    #[must_use]
+    pub fn get_index_from(&self, offset: usize, unit: TextUnit) -> usize {
+        if self.is_binary() || unit == TextUnit::Byte {
+            return offset;
+        }
+        let get = |(chars, utf16): &(usize, usize)| {
+            if unit == TextUnit::Char {
+                *chars
+            } else {
+                *utf16
+            }
+        };
+        let line = self
+            .line_offsets
+            .partition_point(|offsets| get(offsets) <= offset)
+            - 1;
+        let start = self.lines[line];
+        start + self.advance(start, offset - get(&self.line_offsets[line]), unit)
+    }
+
+    /// Gets the span in the specified unit corresponding to a span in the content
+    #[must_use]
+    pub fn get_span_in(&self, span: TextSpan, unit: TextUnit) -> TextSpan {
+        let index = self.get_offset_in(span.index, unit);
+        let end = self.get_offset_in(span.index + span.length, unit);
+        TextSpan {
+            index,
+            length: end - index,
+        }
     }
 
     /// Gets the starting index of the i-th line
@@ -258,7 +554,7 @@ impl<'a> Text<'a> {
     #[must_use]
     pub fn get_line_length(&self, line: usize) -> usize {
         if line == self.lines.len() {
-            self.content.len() - self.lines[line - 1]
+            self.len() - self.lines[line - 1]
         } else {
             self.lines[line] - self.lines[line - 1]
         }
@@ -271,21 +567,43 @@ impl<'a> Text<'a> {
     }
 
     /// Gets the position at the given index
+    /// The column is computed according to the configuration of this text.
     #[must_use]
     pub fn get_position_at(&self, index: usize) -> TextPosition {
+        if self.is_binary() || self.config == TextConfig::default() {
+            return self.get_position_in(index, TextUnit::Char);
+        }
         let line = find_line_at(&self.lines, index);
-        let nb_chars = self.content[self.lines[line]..index].chars().count();
         TextPosition {
             line: line + 1,
-            column: nb_chars + 1,
+            column: self
+                .config
+                .count_columns(&self.as_str()[self.lines[line]..index])
+                + 1,
+        }
+    }
+
+    /// Gets the position at the given index, with a column in the specified unit
+    /// Tabs count as a single unit, regardless of the configuration.
+    #[must_use]
+    pub fn get_position_in(&self, index: usize, unit: TextUnit) -> TextPosition {
+        if self.is_binary() {
+            return TextPosition {
+                line: 1,
+                column: index + 1,
+            };
+        }
+        let line = find_line_at(&self.lines, index);
+        TextPosition {
+            line: line + 1,
+            column: measure(&self.as_str()[self.lines[line]..index], unit) + 1,
         }
     }
 
     /// Gets the position for a starting position and a length
     #[must_use]
     pub fn get_position_for(&self, position: TextPosition, length: usize) -> TextPosition {
-        let index = self.lines[position.line - 1] + position.column - 1 + length;
-        self.get_position_at(index)
+        self.get_position_at(self.get_index_at(position) + length)
     }
 
     /// Gets the context description for the current text at the specified position
@@ -295,45 +613,40 @@ impl<'a> Text<'a> {
     }
 
     /// Gets the context description for the current text at the specified position
+    /// A binary input has no textual context.
     #[must_use]
     pub fn get_context_for(&self, position: TextPosition, length: usize) -> TextContext {
+        if self.is_binary() {
+            return TextContext {
+                content: "",
+                pointer: String::new(),
+            };
+        }
+        let index = self.get_index_at(position);
+        let line_start = self.lines[position.line - 1];
         // gather the data for the line
         let mut line_content = self.get_line_content(position.line);
         // remove the line ending
         line_content = line_content.trim_end_matches(is_line_ending_char);
         // remove the heading white space
-        let mut removed_heading = 0;
-        loop {
-            match line_content.chars().next() {
-                None => break,
-                Some(c) => {
-                    if c.is_whitespace() {
-                        line_content = &line_content[c.len_utf8()..];
-                        removed_heading += 1;
-                    } else {
-                        break;
-                    }
-                }
-            }
-        }
-        let in_line_offset = line_content
-            .char_indices()
-            .take(position.column - 1 - removed_heading)
-            .last()
-            .map(|(offset, c)| offset + c.len_utf8())
-            .unwrap_or_default();
-        let pointer_count = line_content[in_line_offset..]
-            .char_indices()
-            .take_while(|&(offset, _)| offset < length)
-            .count()
-            .max(1);
-        let pointer_blank_count = position.column - 1 - removed_heading;
+        let trimmed = line_content.trim_start();
+        let in_line_offset =
+            (index - line_start).saturating_sub(line_content.len() - trimmed.len());
+        line_content = trimmed;
         // build the pointer
-        let mut pointer = String::with_capacity(pointer_count + pointer_blank_count);
-        for c in line_content.chars().take(pointer_blank_count) {
-            pointer.push(if c == '\t' { '\t' } else { ' ' });
+        let mut pointer = String::new();
+        let mut offset = 0;
+        while offset < in_line_offset.min(line_content.len()) {
+            let rest = &line_content[offset..];
+            pointer.push(if rest.starts_with('\t') { '\t' } else { ' ' });
+            offset += self.config.next_pointed(rest);
         }
-        for _ in 0..pointer_count {
+        let mut pointer_count = 0;
+        while offset < line_content.len() && offset < in_line_offset + length {
+            offset += self.config.next_pointed(&line_content[offset..]);
+            pointer_count += 1;
+        }
+        for _ in 0..pointer_count.max(1) {
             pointer.push('^');
         }
         // return the output
@@ -351,13 +664,19 @@ impl<'a> Text<'a> {
     }
 
     /// Gets an iterator over the UTF-16 codepoints starting at a location
+    /// This is empty for a binary input.
     #[must_use]
     pub fn iter_utf16_from(&self, from: usize) -> Utf16Iter {
         Utf16Iter {
-            inner: self.content[from..].chars(),
+            inner: self.as_str().get(from..).unwrap_or_default().chars(),
             next_cp: None,
         }
     }
+
+    /// Gets an iterator over the bytes starting at a location
+    pub fn iter_bytes_from(&self, from: usize) -> Iter<'_, u8> {
+        self.as_bytes()[from..].iter()
+    }
 }
 
 /// An iterator over UTF-16 code points in the input text
@@ -439,14 +758,33 @@ fn find_lines_in<T: Iterator<Item = (usize, char)>>(iterator: T) -> Vec<usize> {
     result
 }
 
+/// Finds the starting offsets of the lines in characters and in UTF-16 code units
+fn find_line_offsets(content: &str, lines: &[usize]) -> Vec<(usize, usize)> {
+    let mut result = Vec::with_capacity(lines.len());
+    let mut offsets = (0, 0);
+    let mut previous = 0;
+    for &line in lines {
+        let value = &content[previous..line];
+        offsets.0 += measure(value, TextUnit::Char);
+        offsets.1 += measure(value, TextUnit::Utf16);
+        result.push(offsets);
+        previous = line;
+    }
+    result
+}
+
+/// Gets the length of a piece of text in the specified unit
+fn measure(value: &str, unit: TextUnit) -> usize {
+    match unit {
+        TextUnit::Byte => value.len(),
+        TextUnit::Char => value.chars().count(),
+        TextUnit::Utf16 => value.chars().map(char::len_utf16).sum(),
+    }
+}
+
 /// Finds the index of the line at the given input index in the content
 fn find_line_at(lines: &[usize], index: usize) -> usize {
-    for (i, line) in lines.iter().enumerate().skip(1) {
-        if index < *line {
-            return i - 1;
-        }
-    }
-    lines.len() - 1
+    lines.partition_point(|&line| line <= index) - 1
 }
 
 #[test]
@@ -530,7 +868,7 @@ fn test_text_get_position_at() {
             }
         );
     }
-    for i in 8..text.content.len() {
+    for i in 8..text.len() {
         assert_eq!(
             text.get_position_at(i),
             TextPosition {
@@ -607,3 +945,114 @@ fn test_text_get_context_for_no_leading_tab() {
         }
     );
 }
+
+#[test]
+fn test_text_from_bytes() {
+    let text = Text::from_bytes(b"\x01AB\xFF\n\x02");
+    assert!(text.is_binary());
+    assert_eq!(text.len(), 6);
+    assert_eq!(text.get_line_count(), 1);
+    assert_eq!(text.at(3), '\u{FF}');
+    assert_eq!(text.get_bytes(1, 3), b"AB\xFF");
+    assert_eq!(text.get_value(1, 2), "AB");
+    assert_eq!(text.get_value(1, 3), "");
+    assert_eq!(text.get_position_at(5), TextPosition { line: 1, column: 6 });
+    assert_eq!(text.get_index_at(TextPosition { line: 1, column: 6 }), 5);
+    assert_eq!(
+        text.iter_bytes_from(4).copied().collect::<Vec<_>>(),
+        b"\n\x02"
+    );
+    assert_eq!(text.iter_utf16_from(0).count(), 0);
+}
+
+#[test]
+fn test_text_offsets_in_units() {
+    let text = Text::from_str("aé\n𐐀b\nc");
+    // 'b' is at byte 8, char 4, UTF-16 offset 5
+    assert_eq!(text.get_offset_in(8, TextUnit::Char), 4);
+    assert_eq!(text.get_offset_in(8, TextUnit::Utf16), 5);
+    assert_eq!(text.get_index_from(4, TextUnit::Char), 8);
+    assert_eq!(text.get_index_from(5, TextUnit::Utf16), 8);
+    // the middle of a surrogate pair is the beginning of the character
+    assert_eq!(text.get_index_from(4, TextUnit::Utf16), 4);
+    for index in [0, 1, 3, 4, 8, 9, 10] {
+        for unit in [TextUnit::Byte, TextUnit::Char, TextUnit::Utf16] {
+            let offset = text.get_offset_in(index, unit);
+            assert_eq!(text.get_index_from(offset, unit), index);
+            let position = text.get_position_in(index, unit);
+            assert_eq!(text.get_index_at_in(position, unit), index);
+        }
+    }
+    assert_eq!(
+        text.get_position_in(8, TextUnit::Utf16),
+        TextPosition { line: 2, column: 3 }
+    );
+    assert_eq!(
+        text.get_position_in(8, TextUnit::Byte),
+        TextPosition { line: 2, column: 5 }
+    );
+    assert_eq!(
+        text.get_span_in(
+            TextSpan {
+                index: 4,
+                length: 5
+            },
+            TextUnit::Utf16
+        ),
+        TextSpan {
+            index: 3,
+            length: 3
+        }
+    );
+}
+
+#[test]
+fn test_text_get_position_for() {
+    let text = Text::from_str("éé x");
+    assert_eq!(
+        text.get_position_for(TextPosition { line: 1, column: 2 }, 2),
+        TextPosition { line: 1, column: 3 }
+    );
+}
+
+#[test]
+fn test_text_config_tabs() {
+    let text = Text::from_str("\tx\ty\n  z").with_config(TextConfig {
+        tab_width: 4,
+        column_unit: ColumnUnit::Char,
+    });
+    assert_eq!(text.get_position_at(1), TextPosition { line: 1, column: 5 });
+    assert_eq!(text.get_position_at(3), TextPosition { line: 1, column: 9 });
+    assert_eq!(text.get_index_at(TextPosition { line: 1, column: 9 }), 3);
+    assert_eq!(text.get_index_at(TextPosition { line: 1, column: 6 }), 2);
+    assert_eq!(text.get_position_at(7), TextPosition { line: 2, column: 3 });
+    assert_eq!(
+        text.get_context_for(TextPosition { line: 1, column: 9 }, 1),
+        TextContext {
+            content: "x\ty",
+            pointer: String::from(" \t^")
+        }
+    );
+}
+
+#[test]
+fn test_text_config_graphemes() {
+    let text = Text::from_str("e\u{301}e\u{301} x").with_config(TextConfig {
+        tab_width: 1,
+        column_unit: ColumnUnit::Grapheme,
+    });
+    assert_eq!(text.get_position_at(7), TextPosition { line: 1, column: 4 });
+    assert_eq!(text.get_index_at(TextPosition { line: 1, column: 4 }), 7);
+    assert_eq!(
+        text.get_context_for(TextPosition { line: 1, column: 4 }, 1),
+        TextContext {
+            content: "e\u{301}e\u{301} x",
+            pointer: String::from("   ^")
+        }
+    );
+    let text = Text::from_str("\u{1F600}x").with_config(TextConfig {
+        tab_width: 1,
+        column_unit: ColumnUnit::Utf16,
+    });
+    assert_eq!(text.get_position_at(4), TextPosition { line: 1, column: 3 });
+}
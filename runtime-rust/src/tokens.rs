@@ -17,6 +17,8 @@
 
 //! Module for the definition of lexical tokens
 
+use alloc::vec::Vec;
+
 use crate::symbols::{SemanticElementTrait, Symbol};
 use crate::text::{Text, TextContext, TextPosition, TextSpan};
 use crate::utils::biglist::BigList;
@@ -36,6 +38,79 @@ struct TokenRepositoryCell {
 pub struct TokenRepositoryImpl {
     /// The token data in this content
     cells: BigList<TokenRepositoryCell>,
+    /// The spans of the values of the synthetic tokens, sorted by token index
+    synthetics: Vec<(usize, TextSpan)>,
+    /// The indices of the tokens sorted by their position in the input text,
+    /// with the greatest end of the tokens up to each one
+    /// This is empty as long as the tokens are added in the order of the input text,
+    /// i.e. until synthetic tokens are added or tokens from an injected language are grafted.
+    order: Vec<(usize, usize)>,
+}
+
+impl TokenRepositoryImpl {
+    /// Appends the tokens of another repository, e.g. the tokens of an injected language
+    /// The terminal indices are shifted by `terminals` and the spans by `offset`.
+    /// Returns the index of the first appended token
+    pub(crate) fn graft(
+        &mut self,
+        other: &TokenRepositoryImpl,
+        terminals: usize,
+        offset: usize,
+    ) -> usize {
+        let first = self.cells.len();
+        for cell in other.cells.iter() {
+            let index = self.cells.push(TokenRepositoryCell {
+                terminal: cell.terminal + terminals,
+                span: TextSpan {
+                    index: cell.span.index + offset,
+                    length: cell.span.length,
+                },
+            });
+            self.insert_ordered(index);
+        }
+        for (index, span) in &other.synthetics {
+            self.synthetics.push((
+                index + first,
+                TextSpan {
+                    index: span.index + offset,
+                    length: span.length,
+                },
+            ));
+        }
+        first
+    }
+
+    /// Inserts a token in the tokens sorted by their position in the input text
+    /// Tokens at the same position are sorted in the order they are added,
+    /// so that tokens grafted on another one come after it.
+    fn insert_ordered(&mut self, token: usize) {
+        if self.order.is_empty() {
+            // the tokens up to now are in the order of the input text
+            let mut end = 0;
+            for (index, cell) in self.cells.iter().enumerate().take(token) {
+                end = end.max(cell.span.index + cell.span.length);
+                self.order.push((index, end));
+            }
+        }
+        let span = self.cells[token].span;
+        let cells = &self.cells;
+        let position = self
+            .order
+            .partition_point(|(index, _)| cells[*index].span.index <= span.index);
+        let previous = if position == 0 {
+            0
+        } else {
+            self.order[position - 1].1
+        };
+        let end = span.index + span.length;
+        self.order.insert(position, (token, previous.max(end)));
+        for entry in &mut self.order[position + 1..] {
+            if entry.1 >= end {
+                break;
+            }
+            entry.1 = end;
+        }
+    }
 }
 
 /// The proxy structure for a repository of matched tokens
@@ -122,10 +197,37 @@ impl<'s, 't, 'a> TokenRepository<'s, 't, 'a> {
 
     /// Registers a new token in this repository
     pub fn add(&mut self, terminal: usize, index: usize, length: usize) -> usize {
-        self.data.cells.push(TokenRepositoryCell {
+        let token = self.data.cells.push(TokenRepositoryCell {
             terminal,
             span: TextSpan { index, length },
-        })
+        });
+        if !self.data.order.is_empty() {
+            self.data.insert_ordered(token);
+        }
+        token
+    }
+
+    /// Registers a new synthetic token in this repository
+    /// A synthetic token is not matched in the input by a lexer, e.g. a token inserted by a preprocessor.
+    /// Its value is read from the `value` span in the input text,
+    /// while it is reported at the `origin` span, e.g. the invocation of the expanded macro.
+    pub fn add_synthetic(&mut self, terminal: usize, value: TextSpan, origin: TextSpan) -> usize {
+        let index = self.data.cells.push(TokenRepositoryCell {
+            terminal,
+            span: origin,
+        });
+        self.data.synthetics.push((index, value));
+        self.data.insert_ordered(index);
+        index
+    }
+
+    /// Gets whether the i-th token is synthetic
+    #[must_use]
+    pub fn is_synthetic(&self, index: usize) -> bool {
+        self.data
+            .synthetics
+            .binary_search_by_key(&index, |(i, _)| *i)
+            .is_ok()
     }
 
     /// Gets the number of tokens in this repository
@@ -140,6 +242,41 @@ impl<'s, 't, 'a> TokenRepository<'s, 't, 'a> {
         self.terminals[self.data.cells[index].terminal].id
     }
 
+    /// Gets the span in the input text for the i-th token
+    /// For a synthetic token, this is the span of its origin.
+    #[must_use]
+    pub fn get_span_for(&self, index: usize) -> TextSpan {
+        self.data.cells[index].span
+    }
+
+    /// Gets the value of the i-th token
+    #[must_use]
+    pub fn get_value_for(&self, index: usize) -> &'a str {
+        let span = match self
+            .data
+            .synthetics
+            .binary_search_by_key(&index, |(i, _)| *i)
+        {
+            Ok(synthetic) => self.data.synthetics[synthetic].1,
+            Err(_) => self.data.cells[index].span,
+        };
+        self.text.get_value_for(span)
+    }
+
+    /// Gets the bytes of the i-th token in the input
+    #[must_use]
+    pub fn get_bytes_for(&self, index: usize) -> &'a [u8] {
+        let span = match self
+            .data
+            .synthetics
+            .binary_search_by_key(&index, |(i, _)| *i)
+        {
+            Ok(synthetic) => self.data.synthetics[synthetic].1,
+            Err(_) => self.data.cells[index].span,
+        };
+        self.text.get_bytes_for(span)
+    }
+
     /// Gets the i-th token
     #[must_use]
     pub fn get_token(&'a self, index: usize) -> Token<'s, 't, 'a> {
@@ -156,12 +293,32 @@ impl<'s, 't, 'a> TokenRepository<'s, 't, 'a> {
     }
 
     /// Gets the token (if any) that contains the specified index in the input text
+    /// When several tokens contain the index, e.g. a synthetic token at the origin of another one,
+    /// the innermost is returned, or the last added one for the same position.
     #[must_use]
     pub fn find_token_at(&'a self, index: usize) -> Option<Token<'s, 't, 'a>> {
         let count = self.data.cells.len();
         if count == 0 {
             return None;
         }
+        if !self.data.order.is_empty() {
+            // synthetic and grafted tokens may break the ordering of the tokens in the input text
+            // grafted tokens are within the token they are grafted on, look for the innermost one
+            let order = &self.data.order;
+            let end = order.partition_point(|(i, _)| self.data.cells[*i].span.index <= index);
+            return order[..end]
+                .iter()
+                .rev()
+                .take_while(|(_, max_end)| index < *max_end)
+                .find(|(i, _)| {
+                    let span = self.data.cells[*i].span;
+                    index < span.index + span.length
+                })
+                .map(|(i, _)| Token {
+                    repository: self,
+                    index: *i,
+                });
+        }
         let mut l: usize = 0;
         let mut r = count - 1;
         while l <= r {
@@ -185,6 +342,14 @@ impl<'s, 't, 'a> TokenRepository<'s, 't, 'a> {
     }
 }
 
+impl<'a> Token<'_, '_, 'a> {
+    /// Gets the bytes of this token in the input
+    #[must_use]
+    pub fn get_bytes(&self) -> &'a [u8] {
+        self.repository.get_bytes_for(self.index)
+    }
+}
+
 impl<'s, 't, 'a> SemanticElementTrait<'s, 'a> for Token<'s, 't, 'a> {
     /// Gets the position in the input text of this element
     #[must_use]
@@ -220,10 +385,53 @@ impl<'s, 't, 'a> SemanticElementTrait<'s, 'a> for Token<'s, 't, 'a> {
     /// Gets the value of this element, if any
     #[must_use]
     fn get_value(&self) -> Option<&'a str> {
-        Some(
-            self.repository
-                .text
-                .get_value_for(self.repository.data.cells[self.index].span),
-        )
+        Some(self.repository.get_value_for(self.index))
     }
 }
+
+#[test]
+fn test_find_token_at_with_synthetic_tokens() {
+    const TERMINALS: &[Symbol] = &[
+        Symbol { id: 1, name: "ε" },
+        Symbol { id: 2, name: "$" },
+        Symbol { id: 3, name: "ID" },
+    ];
+    let text = Text::from_str("ab cd(ef) gh");
+    let mut tokens = TokenRepositoryImpl::default();
+    let mut repository = TokenRepository::new_mut(TERMINALS, &text, &mut tokens);
+    repository.add(2, 0, 2);
+    repository.add(2, 3, 6);
+    // the expansion of the macro `cd(ef)` is reported at its origin
+    let origin = TextSpan {
+        index: 3,
+        length: 6,
+    };
+    repository.add_synthetic(
+        2,
+        TextSpan {
+            index: 6,
+            length: 2,
+        },
+        origin,
+    );
+    repository.add(2, 10, 2);
+    repository.add(1, 12, 0);
+    let found: Vec<Option<usize>> = [0, 2, 3, 5, 8, 9, 10, 11, 12]
+        .into_iter()
+        .map(|index| repository.find_token_at(index).map(|token| token.index))
+        .collect();
+    assert_eq!(
+        found,
+        alloc::vec![
+            Some(0),
+            None,
+            Some(2),
+            Some(2),
+            Some(2),
+            None,
+            Some(3),
+            Some(3),
+            None
+        ]
+    );
+}
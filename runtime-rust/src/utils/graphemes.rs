@@ -0,0 +1,831 @@
+/*******************************************************************************
+ * Copyright (c) 2017 Association Cénotélie (cenotelie.fr)
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Lesser General Public License as
+ * published by the Free Software Foundation, either version 3
+ * of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU Lesser General Public License for more details.
+ *
+ * You should have received a copy of the GNU Lesser General
+ * Public License along with this program.
+ * If not, see <http://www.gnu.org/licenses/>.
+ ******************************************************************************/
+
+//! Module for the segmentation of text into extended grapheme clusters
+//! This implements the rules of Unicode Standard Annex #29, except for the rule on Indic conjuncts (`GB9c`).
+
+use core::cmp::Ordering;
+
+/// The version of the Unicode Character Database for the tables in this module
+pub const UNICODE_VERSION: &str = "16.0.0";
+
+/// The value of the `Grapheme_Cluster_Break` property of a character
+/// The `Extended_Pictographic` property is folded in as its own value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum GraphemeBreak {
+    Other,
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    ExtendedPictographic,
+}
+
+/// The first Hangul syllable
+const HANGUL_FIRST: u32 = 0xAC00;
+/// The last Hangul syllable
+const HANGUL_LAST: u32 = 0xD7A3;
+/// The number of trailing consonants in the Hangul syllables composition
+const HANGUL_T_COUNT: u32 = 28;
+
+/// Gets the grapheme break property of a character
+fn get_break(c: char) -> GraphemeBreak {
+    let cp = c as u32;
+    if (HANGUL_FIRST..=HANGUL_LAST).contains(&cp) {
+        return if (cp - HANGUL_FIRST).is_multiple_of(HANGUL_T_COUNT) {
+            GraphemeBreak::Lv
+        } else {
+            GraphemeBreak::Lvt
+        };
+    }
+    GRAPHEME_BREAKS
+        .binary_search_by(|&(begin, end, _)| {
+            if end < cp {
+                Ordering::Less
+            } else if begin > cp {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .map_or(GraphemeBreak::Other, |index| GRAPHEME_BREAKS[index].2)
+}
+
+/// Gets the length in bytes of the first extended grapheme cluster in a piece of text
+#[must_use]
+pub fn first_grapheme_len(value: &str) -> usize {
+    let mut chars = value.char_indices();
+    let Some((_, first)) = chars.next() else {
+        return 0;
+    };
+    let mut previous = get_break(first);
+    // whether the cluster so far is an extended pictographic followed by extenders (GB11)
+    let mut in_pictographic = previous == GraphemeBreak::ExtendedPictographic;
+    // the number of consecutive regional indicators (GB12, GB13)
+    let mut regional_count = usize::from(previous == GraphemeBreak::RegionalIndicator);
+    for (offset, c) in chars {
+        let current = get_break(c);
+        let join = match (previous, current) {
+            (GraphemeBreak::Cr, GraphemeBreak::Lf) => true,
+            (GraphemeBreak::Cr | GraphemeBreak::Lf | GraphemeBreak::Control, _)
+            | (_, GraphemeBreak::Cr | GraphemeBreak::Lf | GraphemeBreak::Control) => false,
+            (
+                GraphemeBreak::L,
+                GraphemeBreak::L | GraphemeBreak::V | GraphemeBreak::Lv | GraphemeBreak::Lvt,
+            )
+            | (GraphemeBreak::Lv | GraphemeBreak::V, GraphemeBreak::V | GraphemeBreak::T)
+            | (GraphemeBreak::Lvt | GraphemeBreak::T, GraphemeBreak::T)
+            | (_, GraphemeBreak::Extend | GraphemeBreak::Zwj | GraphemeBreak::SpacingMark)
+            | (GraphemeBreak::Prepend, _) => true,
+            (GraphemeBreak::Zwj, GraphemeBreak::ExtendedPictographic) => in_pictographic,
+            (GraphemeBreak::RegionalIndicator, GraphemeBreak::RegionalIndicator) => {
+                regional_count % 2 == 1
+            }
+            _ => false,
+        };
+        if !join {
+            return offset;
+        }
+        in_pictographic = match current {
+            GraphemeBreak::ExtendedPictographic => true,
+            GraphemeBreak::Extend | GraphemeBreak::Zwj => in_pictographic,
+            _ => false,
+        };
+        regional_count = if current == GraphemeBreak::RegionalIndicator {
+            regional_count + 1
+        } else {
+            0
+        };
+        previous = current;
+    }
+    value.len()
+}
+
+/// An iterator over the extended grapheme clusters in a piece of text
+#[derive(Debug, Clone)]
+pub struct Graphemes<'a> {
+    /// The remaining text
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (cluster, rest) = self.rest.split_at(first_grapheme_len(self.rest));
+        self.rest = rest;
+        Some(cluster)
+    }
+}
+
+/// Gets an iterator over the extended grapheme clusters in a piece of text
+#[must_use]
+pub fn graphemes(value: &str) -> Graphemes<'_> {
+    Graphemes { rest: value }
+}
+
+/// The grapheme break property of the characters, except for the Hangul syllables
+/// Generated from `GraphemeBreakProperty.txt` and `emoji-data.txt`
+const GRAPHEME_BREAKS: &[(u32, u32, GraphemeBreak)] = &[
+    (0x0, 0x9, GraphemeBreak::Control),
+    (0xA, 0xA, GraphemeBreak::Lf),
+    (0xB, 0xC, GraphemeBreak::Control),
+    (0xD, 0xD, GraphemeBreak::Cr),
+    (0xE, 0x1F, GraphemeBreak::Control),
+    (0x7F, 0x9F, GraphemeBreak::Control),
+    (0xA9, 0xA9, GraphemeBreak::ExtendedPictographic),
+    (0xAD, 0xAD, GraphemeBreak::Control),
+    (0xAE, 0xAE, GraphemeBreak::ExtendedPictographic),
+    (0x300, 0x36F, GraphemeBreak::Extend),
+    (0x483, 0x489, GraphemeBreak::Extend),
+    (0x591, 0x5BD, GraphemeBreak::Extend),
+    (0x5BF, 0x5BF, GraphemeBreak::Extend),
+    (0x5C1, 0x5C2, GraphemeBreak::Extend),
+    (0x5C4, 0x5C5, GraphemeBreak::Extend),
+    (0x5C7, 0x5C7, GraphemeBreak::Extend),
+    (0x600, 0x605, GraphemeBreak::Prepend),
+    (0x610, 0x61A, GraphemeBreak::Extend),
+    (0x61C, 0x61C, GraphemeBreak::Control),
+    (0x64B, 0x65F, GraphemeBreak::Extend),
+    (0x670, 0x670, GraphemeBreak::Extend),
+    (0x6D6, 0x6DC, GraphemeBreak::Extend),
+    (0x6DD, 0x6DD, GraphemeBreak::Prepend),
+    (0x6DF, 0x6E4, GraphemeBreak::Extend),
+    (0x6E7, 0x6E8, GraphemeBreak::Extend),
+    (0x6EA, 0x6ED, GraphemeBreak::Extend),
+    (0x70F, 0x70F, GraphemeBreak::Prepend),
+    (0x711, 0x711, GraphemeBreak::Extend),
+    (0x730, 0x74A, GraphemeBreak::Extend),
+    (0x7A6, 0x7B0, GraphemeBreak::Extend),
+    (0x7EB, 0x7F3, GraphemeBreak::Extend),
+    (0x7FD, 0x7FD, GraphemeBreak::Extend),
+    (0x816, 0x819, GraphemeBreak::Extend),
+    (0x81B, 0x823, GraphemeBreak::Extend),
+    (0x825, 0x827, GraphemeBreak::Extend),
+    (0x829, 0x82D, GraphemeBreak::Extend),
+    (0x859, 0x85B, GraphemeBreak::Extend),
+    (0x890, 0x891, GraphemeBreak::Prepend),
+    (0x897, 0x89F, GraphemeBreak::Extend),
+    (0x8CA, 0x8E1, GraphemeBreak::Extend),
+    (0x8E2, 0x8E2, GraphemeBreak::Prepend),
+    (0x8E3, 0x902, GraphemeBreak::Extend),
+    (0x903, 0x903, GraphemeBreak::SpacingMark),
+    (0x93A, 0x93A, GraphemeBreak::Extend),
+    (0x93B, 0x93B, GraphemeBreak::SpacingMark),
+    (0x93C, 0x93C, GraphemeBreak::Extend),
+    (0x93E, 0x940, GraphemeBreak::SpacingMark),
+    (0x941, 0x948, GraphemeBreak::Extend),
+    (0x949, 0x94C, GraphemeBreak::SpacingMark),
+    (0x94D, 0x94D, GraphemeBreak::Extend),
+    (0x94E, 0x94F, GraphemeBreak::SpacingMark),
+    (0x951, 0x957, GraphemeBreak::Extend),
+    (0x962, 0x963, GraphemeBreak::Extend),
+    (0x981, 0x981, GraphemeBreak::Extend),
+    (0x982, 0x983, GraphemeBreak::SpacingMark),
+    (0x9BC, 0x9BC, GraphemeBreak::Extend),
+    (0x9BE, 0x9BE, GraphemeBreak::Extend),
+    (0x9BF, 0x9C0, GraphemeBreak::SpacingMark),
+    (0x9C1, 0x9C4, GraphemeBreak::Extend),
+    (0x9C7, 0x9C8, GraphemeBreak::SpacingMark),
+    (0x9CB, 0x9CC, GraphemeBreak::SpacingMark),
+    (0x9CD, 0x9CD, GraphemeBreak::Extend),
+    (0x9D7, 0x9D7, GraphemeBreak::Extend),
+    (0x9E2, 0x9E3, GraphemeBreak::Extend),
+    (0x9FE, 0x9FE, GraphemeBreak::Extend),
+    (0xA01, 0xA02, GraphemeBreak::Extend),
+    (0xA03, 0xA03, GraphemeBreak::SpacingMark),
+    (0xA3C, 0xA3C, GraphemeBreak::Extend),
+    (0xA3E, 0xA40, GraphemeBreak::SpacingMark),
+    (0xA41, 0xA42, GraphemeBreak::Extend),
+    (0xA47, 0xA48, GraphemeBreak::Extend),
+    (0xA4B, 0xA4D, GraphemeBreak::Extend),
+    (0xA51, 0xA51, GraphemeBreak::Extend),
+    (0xA70, 0xA71, GraphemeBreak::Extend),
+    (0xA75, 0xA75, GraphemeBreak::Extend),
+    (0xA81, 0xA82, GraphemeBreak::Extend),
+    (0xA83, 0xA83, GraphemeBreak::SpacingMark),
+    (0xABC, 0xABC, GraphemeBreak::Extend),
+    (0xABE, 0xAC0, GraphemeBreak::SpacingMark),
+    (0xAC1, 0xAC5, GraphemeBreak::Extend),
+    (0xAC7, 0xAC8, GraphemeBreak::Extend),
+    (0xAC9, 0xAC9, GraphemeBreak::SpacingMark),
+    (0xACB, 0xACC, GraphemeBreak::SpacingMark),
+    (0xACD, 0xACD, GraphemeBreak::Extend),
+    (0xAE2, 0xAE3, GraphemeBreak::Extend),
+    (0xAFA, 0xAFF, GraphemeBreak::Extend),
+    (0xB01, 0xB01, GraphemeBreak::Extend),
+    (0xB02, 0xB03, GraphemeBreak::SpacingMark),
+    (0xB3C, 0xB3C, GraphemeBreak::Extend),
+    (0xB3E, 0xB3F, GraphemeBreak::Extend),
+    (0xB40, 0xB40, GraphemeBreak::SpacingMark),
+    (0xB41, 0xB44, GraphemeBreak::Extend),
+    (0xB47, 0xB48, GraphemeBreak::SpacingMark),
+    (0xB4B, 0xB4C, GraphemeBreak::SpacingMark),
+    (0xB4D, 0xB4D, GraphemeBreak::Extend),
+    (0xB55, 0xB57, GraphemeBreak::Extend),
+    (0xB62, 0xB63, GraphemeBreak::Extend),
+    (0xB82, 0xB82, GraphemeBreak::Extend),
+    (0xBBE, 0xBBE, GraphemeBreak::Extend),
+    (0xBBF, 0xBBF, GraphemeBreak::SpacingMark),
+    (0xBC0, 0xBC0, GraphemeBreak::Extend),
+    (0xBC1, 0xBC2, GraphemeBreak::SpacingMark),
+    (0xBC6, 0xBC8, GraphemeBreak::SpacingMark),
+    (0xBCA, 0xBCC, GraphemeBreak::SpacingMark),
+    (0xBCD, 0xBCD, GraphemeBreak::Extend),
+    (0xBD7, 0xBD7, GraphemeBreak::Extend),
+    (0xC00, 0xC00, GraphemeBreak::Extend),
+    (0xC01, 0xC03, GraphemeBreak::SpacingMark),
+    (0xC04, 0xC04, GraphemeBreak::Extend),
+    (0xC3C, 0xC3C, GraphemeBreak::Extend),
+    (0xC3E, 0xC40, GraphemeBreak::Extend),
+    (0xC41, 0xC44, GraphemeBreak::SpacingMark),
+    (0xC46, 0xC48, GraphemeBreak::Extend),
+    (0xC4A, 0xC4D, GraphemeBreak::Extend),
+    (0xC55, 0xC56, GraphemeBreak::Extend),
+    (0xC62, 0xC63, GraphemeBreak::Extend),
+    (0xC81, 0xC81, GraphemeBreak::Extend),
+    (0xC82, 0xC83, GraphemeBreak::SpacingMark),
+    (0xCBC, 0xCBC, GraphemeBreak::Extend),
+    (0xCBE, 0xCBE, GraphemeBreak::SpacingMark),
+    (0xCBF, 0xCC0, GraphemeBreak::Extend),
+    (0xCC1, 0xCC1, GraphemeBreak::SpacingMark),
+    (0xCC2, 0xCC2, GraphemeBreak::Extend),
+    (0xCC3, 0xCC4, GraphemeBreak::SpacingMark),
+    (0xCC6, 0xCC8, GraphemeBreak::Extend),
+    (0xCCA, 0xCCD, GraphemeBreak::Extend),
+    (0xCD5, 0xCD6, GraphemeBreak::Extend),
+    (0xCE2, 0xCE3, GraphemeBreak::Extend),
+    (0xCF3, 0xCF3, GraphemeBreak::SpacingMark),
+    (0xD00, 0xD01, GraphemeBreak::Extend),
+    (0xD02, 0xD03, GraphemeBreak::SpacingMark),
+    (0xD3B, 0xD3C, GraphemeBreak::Extend),
+    (0xD3E, 0xD3E, GraphemeBreak::Extend),
+    (0xD3F, 0xD40, GraphemeBreak::SpacingMark),
+    (0xD41, 0xD44, GraphemeBreak::Extend),
+    (0xD46, 0xD48, GraphemeBreak::SpacingMark),
+    (0xD4A, 0xD4C, GraphemeBreak::SpacingMark),
+    (0xD4D, 0xD4D, GraphemeBreak::Extend),
+    (0xD4E, 0xD4E, GraphemeBreak::Prepend),
+    (0xD57, 0xD57, GraphemeBreak::Extend),
+    (0xD62, 0xD63, GraphemeBreak::Extend),
+    (0xD81, 0xD81, GraphemeBreak::Extend),
+    (0xD82, 0xD83, GraphemeBreak::SpacingMark),
+    (0xDCA, 0xDCA, GraphemeBreak::Extend),
+    (0xDCF, 0xDCF, GraphemeBreak::Extend),
+    (0xDD0, 0xDD1, GraphemeBreak::SpacingMark),
+    (0xDD2, 0xDD4, GraphemeBreak::Extend),
+    (0xDD6, 0xDD6, GraphemeBreak::Extend),
+    (0xDD8, 0xDDE, GraphemeBreak::SpacingMark),
+    (0xDDF, 0xDDF, GraphemeBreak::Extend),
+    (0xDF2, 0xDF3, GraphemeBreak::SpacingMark),
+    (0xE31, 0xE31, GraphemeBreak::Extend),
+    (0xE33, 0xE33, GraphemeBreak::SpacingMark),
+    (0xE34, 0xE3A, GraphemeBreak::Extend),
+    (0xE47, 0xE4E, GraphemeBreak::Extend),
+    (0xEB1, 0xEB1, GraphemeBreak::Extend),
+    (0xEB3, 0xEB3, GraphemeBreak::SpacingMark),
+    (0xEB4, 0xEBC, GraphemeBreak::Extend),
+    (0xEC8, 0xECE, GraphemeBreak::Extend),
+    (0xF18, 0xF19, GraphemeBreak::Extend),
+    (0xF35, 0xF35, GraphemeBreak::Extend),
+    (0xF37, 0xF37, GraphemeBreak::Extend),
+    (0xF39, 0xF39, GraphemeBreak::Extend),
+    (0xF3E, 0xF3F, GraphemeBreak::SpacingMark),
+    (0xF71, 0xF7E, GraphemeBreak::Extend),
+    (0xF7F, 0xF7F, GraphemeBreak::SpacingMark),
+    (0xF80, 0xF84, GraphemeBreak::Extend),
+    (0xF86, 0xF87, GraphemeBreak::Extend),
+    (0xF8D, 0xF97, GraphemeBreak::Extend),
+    (0xF99, 0xFBC, GraphemeBreak::Extend),
+    (0xFC6, 0xFC6, GraphemeBreak::Extend),
+    (0x102D, 0x1030, GraphemeBreak::Extend),
+    (0x1031, 0x1031, GraphemeBreak::SpacingMark),
+    (0x1032, 0x1037, GraphemeBreak::Extend),
+    (0x1039, 0x103A, GraphemeBreak::Extend),
+    (0x103B, 0x103C, GraphemeBreak::SpacingMark),
+    (0x103D, 0x103E, GraphemeBreak::Extend),
+    (0x1056, 0x1057, GraphemeBreak::SpacingMark),
+    (0x1058, 0x1059, GraphemeBreak::Extend),
+    (0x105E, 0x1060, GraphemeBreak::Extend),
+    (0x1071, 0x1074, GraphemeBreak::Extend),
+    (0x1082, 0x1082, GraphemeBreak::Extend),
+    (0x1084, 0x1084, GraphemeBreak::SpacingMark),
+    (0x1085, 0x1086, GraphemeBreak::Extend),
+    (0x108D, 0x108D, GraphemeBreak::Extend),
+    (0x109D, 0x109D, GraphemeBreak::Extend),
+    (0x1100, 0x115F, GraphemeBreak::L),
+    (0x1160, 0x11A7, GraphemeBreak::V),
+    (0x11A8, 0x11FF, GraphemeBreak::T),
+    (0x135D, 0x135F, GraphemeBreak::Extend),
+    (0x1712, 0x1715, GraphemeBreak::Extend),
+    (0x1732, 0x1734, GraphemeBreak::Extend),
+    (0x1752, 0x1753, GraphemeBreak::Extend),
+    (0x1772, 0x1773, GraphemeBreak::Extend),
+    (0x17B4, 0x17B5, GraphemeBreak::Extend),
+    (0x17B6, 0x17B6, GraphemeBreak::SpacingMark),
+    (0x17B7, 0x17BD, GraphemeBreak::Extend),
+    (0x17BE, 0x17C5, GraphemeBreak::SpacingMark),
+    (0x17C6, 0x17C6, GraphemeBreak::Extend),
+    (0x17C7, 0x17C8, GraphemeBreak::SpacingMark),
+    (0x17C9, 0x17D3, GraphemeBreak::Extend),
+    (0x17DD, 0x17DD, GraphemeBreak::Extend),
+    (0x180B, 0x180D, GraphemeBreak::Extend),
+    (0x180E, 0x180E, GraphemeBreak::Control),
+    (0x180F, 0x180F, GraphemeBreak::Extend),
+    (0x1885, 0x1886, GraphemeBreak::Extend),
+    (0x18A9, 0x18A9, GraphemeBreak::Extend),
+    (0x1920, 0x1922, GraphemeBreak::Extend),
+    (0x1923, 0x1926, GraphemeBreak::SpacingMark),
+    (0x1927, 0x1928, GraphemeBreak::Extend),
+    (0x1929, 0x192B, GraphemeBreak::SpacingMark),
+    (0x1930, 0x1931, GraphemeBreak::SpacingMark),
+    (0x1932, 0x1932, GraphemeBreak::Extend),
+    (0x1933, 0x1938, GraphemeBreak::SpacingMark),
+    (0x1939, 0x193B, GraphemeBreak::Extend),
+    (0x1A17, 0x1A18, GraphemeBreak::Extend),
+    (0x1A19, 0x1A1A, GraphemeBreak::SpacingMark),
+    (0x1A1B, 0x1A1B, GraphemeBreak::Extend),
+    (0x1A55, 0x1A55, GraphemeBreak::SpacingMark),
+    (0x1A56, 0x1A56, GraphemeBreak::Extend),
+    (0x1A57, 0x1A57, GraphemeBreak::SpacingMark),
+    (0x1A58, 0x1A5E, GraphemeBreak::Extend),
+    (0x1A60, 0x1A60, GraphemeBreak::Extend),
+    (0x1A62, 0x1A62, GraphemeBreak::Extend),
+    (0x1A65, 0x1A6C, GraphemeBreak::Extend),
+    (0x1A6D, 0x1A72, GraphemeBreak::SpacingMark),
+    (0x1A73, 0x1A7C, GraphemeBreak::Extend),
+    (0x1A7F, 0x1A7F, GraphemeBreak::Extend),
+    (0x1AB0, 0x1ACE, GraphemeBreak::Extend),
+    (0x1B00, 0x1B03, GraphemeBreak::Extend),
+    (0x1B04, 0x1B04, GraphemeBreak::SpacingMark),
+    (0x1B34, 0x1B3D, GraphemeBreak::Extend),
+    (0x1B3E, 0x1B41, GraphemeBreak::SpacingMark),
+    (0x1B42, 0x1B44, GraphemeBreak::Extend),
+    (0x1B6B, 0x1B73, GraphemeBreak::Extend),
+    (0x1B80, 0x1B81, GraphemeBreak::Extend),
+    (0x1B82, 0x1B82, GraphemeBreak::SpacingMark),
+    (0x1BA1, 0x1BA1, GraphemeBreak::SpacingMark),
+    (0x1BA2, 0x1BA5, GraphemeBreak::Extend),
+    (0x1BA6, 0x1BA7, GraphemeBreak::SpacingMark),
+    (0x1BA8, 0x1BAD, GraphemeBreak::Extend),
+    (0x1BE6, 0x1BE6, GraphemeBreak::Extend),
+    (0x1BE7, 0x1BE7, GraphemeBreak::SpacingMark),
+    (0x1BE8, 0x1BE9, GraphemeBreak::Extend),
+    (0x1BEA, 0x1BEC, GraphemeBreak::SpacingMark),
+    (0x1BED, 0x1BED, GraphemeBreak::Extend),
+    (0x1BEE, 0x1BEE, GraphemeBreak::SpacingMark),
+    (0x1BEF, 0x1BF3, GraphemeBreak::Extend),
+    (0x1C24, 0x1C2B, GraphemeBreak::SpacingMark),
+    (0x1C2C, 0x1C33, GraphemeBreak::Extend),
+    (0x1C34, 0x1C35, GraphemeBreak::SpacingMark),
+    (0x1C36, 0x1C37, GraphemeBreak::Extend),
+    (0x1CD0, 0x1CD2, GraphemeBreak::Extend),
+    (0x1CD4, 0x1CE0, GraphemeBreak::Extend),
+    (0x1CE1, 0x1CE1, GraphemeBreak::SpacingMark),
+    (0x1CE2, 0x1CE8, GraphemeBreak::Extend),
+    (0x1CED, 0x1CED, GraphemeBreak::Extend),
+    (0x1CF4, 0x1CF4, GraphemeBreak::Extend),
+    (0x1CF7, 0x1CF7, GraphemeBreak::SpacingMark),
+    (0x1CF8, 0x1CF9, GraphemeBreak::Extend),
+    (0x1DC0, 0x1DFF, GraphemeBreak::Extend),
+    (0x200B, 0x200B, GraphemeBreak::Control),
+    (0x200C, 0x200C, GraphemeBreak::Extend),
+    (0x200D, 0x200D, GraphemeBreak::Zwj),
+    (0x200E, 0x200F, GraphemeBreak::Control),
+    (0x2028, 0x202E, GraphemeBreak::Control),
+    (0x203C, 0x203C, GraphemeBreak::ExtendedPictographic),
+    (0x2049, 0x2049, GraphemeBreak::ExtendedPictographic),
+    (0x2060, 0x206F, GraphemeBreak::Control),
+    (0x20D0, 0x20F0, GraphemeBreak::Extend),
+    (0x2122, 0x2122, GraphemeBreak::ExtendedPictographic),
+    (0x2139, 0x2139, GraphemeBreak::ExtendedPictographic),
+    (0x2194, 0x2199, GraphemeBreak::ExtendedPictographic),
+    (0x21A9, 0x21AA, GraphemeBreak::ExtendedPictographic),
+    (0x231A, 0x231B, GraphemeBreak::ExtendedPictographic),
+    (0x2328, 0x2328, GraphemeBreak::ExtendedPictographic),
+    (0x2388, 0x2388, GraphemeBreak::ExtendedPictographic),
+    (0x23CF, 0x23CF, GraphemeBreak::ExtendedPictographic),
+    (0x23E9, 0x23F3, GraphemeBreak::ExtendedPictographic),
+    (0x23F8, 0x23FA, GraphemeBreak::ExtendedPictographic),
+    (0x24C2, 0x24C2, GraphemeBreak::ExtendedPictographic),
+    (0x25AA, 0x25AB, GraphemeBreak::ExtendedPictographic),
+    (0x25B6, 0x25B6, GraphemeBreak::ExtendedPictographic),
+    (0x25C0, 0x25C0, GraphemeBreak::ExtendedPictographic),
+    (0x25FB, 0x25FE, GraphemeBreak::ExtendedPictographic),
+    (0x2600, 0x2605, GraphemeBreak::ExtendedPictographic),
+    (0x2607, 0x2612, GraphemeBreak::ExtendedPictographic),
+    (0x2614, 0x2685, GraphemeBreak::ExtendedPictographic),
+    (0x2690, 0x2705, GraphemeBreak::ExtendedPictographic),
+    (0x2708, 0x2712, GraphemeBreak::ExtendedPictographic),
+    (0x2714, 0x2714, GraphemeBreak::ExtendedPictographic),
+    (0x2716, 0x2716, GraphemeBreak::ExtendedPictographic),
+    (0x271D, 0x271D, GraphemeBreak::ExtendedPictographic),
+    (0x2721, 0x2721, GraphemeBreak::ExtendedPictographic),
+    (0x2728, 0x2728, GraphemeBreak::ExtendedPictographic),
+    (0x2733, 0x2734, GraphemeBreak::ExtendedPictographic),
+    (0x2744, 0x2744, GraphemeBreak::ExtendedPictographic),
+    (0x2747, 0x2747, GraphemeBreak::ExtendedPictographic),
+    (0x274C, 0x274C, GraphemeBreak::ExtendedPictographic),
+    (0x274E, 0x274E, GraphemeBreak::ExtendedPictographic),
+    (0x2753, 0x2755, GraphemeBreak::ExtendedPictographic),
+    (0x2757, 0x2757, GraphemeBreak::ExtendedPictographic),
+    (0x2763, 0x2767, GraphemeBreak::ExtendedPictographic),
+    (0x2795, 0x2797, GraphemeBreak::ExtendedPictographic),
+    (0x27A1, 0x27A1, GraphemeBreak::ExtendedPictographic),
+    (0x27B0, 0x27B0, GraphemeBreak::ExtendedPictographic),
+    (0x27BF, 0x27BF, GraphemeBreak::ExtendedPictographic),
+    (0x2934, 0x2935, GraphemeBreak::ExtendedPictographic),
+    (0x2B05, 0x2B07, GraphemeBreak::ExtendedPictographic),
+    (0x2B1B, 0x2B1C, GraphemeBreak::ExtendedPictographic),
+    (0x2B50, 0x2B50, GraphemeBreak::ExtendedPictographic),
+    (0x2B55, 0x2B55, GraphemeBreak::ExtendedPictographic),
+    (0x2CEF, 0x2CF1, GraphemeBreak::Extend),
+    (0x2D7F, 0x2D7F, GraphemeBreak::Extend),
+    (0x2DE0, 0x2DFF, GraphemeBreak::Extend),
+    (0x302A, 0x302F, GraphemeBreak::Extend),
+    (0x3030, 0x3030, GraphemeBreak::ExtendedPictographic),
+    (0x303D, 0x303D, GraphemeBreak::ExtendedPictographic),
+    (0x3099, 0x309A, GraphemeBreak::Extend),
+    (0x3297, 0x3297, GraphemeBreak::ExtendedPictographic),
+    (0x3299, 0x3299, GraphemeBreak::ExtendedPictographic),
+    (0xA66F, 0xA672, GraphemeBreak::Extend),
+    (0xA674, 0xA67D, GraphemeBreak::Extend),
+    (0xA69E, 0xA69F, GraphemeBreak::Extend),
+    (0xA6F0, 0xA6F1, GraphemeBreak::Extend),
+    (0xA802, 0xA802, GraphemeBreak::Extend),
+    (0xA806, 0xA806, GraphemeBreak::Extend),
+    (0xA80B, 0xA80B, GraphemeBreak::Extend),
+    (0xA823, 0xA824, GraphemeBreak::SpacingMark),
+    (0xA825, 0xA826, GraphemeBreak::Extend),
+    (0xA827, 0xA827, GraphemeBreak::SpacingMark),
+    (0xA82C, 0xA82C, GraphemeBreak::Extend),
+    (0xA880, 0xA881, GraphemeBreak::SpacingMark),
+    (0xA8B4, 0xA8C3, GraphemeBreak::SpacingMark),
+    (0xA8C4, 0xA8C5, GraphemeBreak::Extend),
+    (0xA8E0, 0xA8F1, GraphemeBreak::Extend),
+    (0xA8FF, 0xA8FF, GraphemeBreak::Extend),
+    (0xA926, 0xA92D, GraphemeBreak::Extend),
+    (0xA947, 0xA951, GraphemeBreak::Extend),
+    (0xA952, 0xA952, GraphemeBreak::SpacingMark),
+    (0xA953, 0xA953, GraphemeBreak::Extend),
+    (0xA960, 0xA97C, GraphemeBreak::L),
+    (0xA980, 0xA982, GraphemeBreak::Extend),
+    (0xA983, 0xA983, GraphemeBreak::SpacingMark),
+    (0xA9B3, 0xA9B3, GraphemeBreak::Extend),
+    (0xA9B4, 0xA9B5, GraphemeBreak::SpacingMark),
+    (0xA9B6, 0xA9B9, GraphemeBreak::Extend),
+    (0xA9BA, 0xA9BB, GraphemeBreak::SpacingMark),
+    (0xA9BC, 0xA9BD, GraphemeBreak::Extend),
+    (0xA9BE, 0xA9BF, GraphemeBreak::SpacingMark),
+    (0xA9C0, 0xA9C0, GraphemeBreak::Extend),
+    (0xA9E5, 0xA9E5, GraphemeBreak::Extend),
+    (0xAA29, 0xAA2E, GraphemeBreak::Extend),
+    (0xAA2F, 0xAA30, GraphemeBreak::SpacingMark),
+    (0xAA31, 0xAA32, GraphemeBreak::Extend),
+    (0xAA33, 0xAA34, GraphemeBreak::SpacingMark),
+    (0xAA35, 0xAA36, GraphemeBreak::Extend),
+    (0xAA43, 0xAA43, GraphemeBreak::Extend),
+    (0xAA4C, 0xAA4C, GraphemeBreak::Extend),
+    (0xAA4D, 0xAA4D, GraphemeBreak::SpacingMark),
+    (0xAA7C, 0xAA7C, GraphemeBreak::Extend),
+    (0xAAB0, 0xAAB0, GraphemeBreak::Extend),
+    (0xAAB2, 0xAAB4, GraphemeBreak::Extend),
+    (0xAAB7, 0xAAB8, GraphemeBreak::Extend),
+    (0xAABE, 0xAABF, GraphemeBreak::Extend),
+    (0xAAC1, 0xAAC1, GraphemeBreak::Extend),
+    (0xAAEB, 0xAAEB, GraphemeBreak::SpacingMark),
+    (0xAAEC, 0xAAED, GraphemeBreak::Extend),
+    (0xAAEE, 0xAAEF, GraphemeBreak::SpacingMark),
+    (0xAAF5, 0xAAF5, GraphemeBreak::SpacingMark),
+    (0xAAF6, 0xAAF6, GraphemeBreak::Extend),
+    (0xABE3, 0xABE4, GraphemeBreak::SpacingMark),
+    (0xABE5, 0xABE5, GraphemeBreak::Extend),
+    (0xABE6, 0xABE7, GraphemeBreak::SpacingMark),
+    (0xABE8, 0xABE8, GraphemeBreak::Extend),
+    (0xABE9, 0xABEA, GraphemeBreak::SpacingMark),
+    (0xABEC, 0xABEC, GraphemeBreak::SpacingMark),
+    (0xABED, 0xABED, GraphemeBreak::Extend),
+    (0xD7B0, 0xD7C6, GraphemeBreak::V),
+    (0xD7CB, 0xD7FB, GraphemeBreak::T),
+    (0xFB1E, 0xFB1E, GraphemeBreak::Extend),
+    (0xFE00, 0xFE0F, GraphemeBreak::Extend),
+    (0xFE20, 0xFE2F, GraphemeBreak::Extend),
+    (0xFEFF, 0xFEFF, GraphemeBreak::Control),
+    (0xFF9E, 0xFF9F, GraphemeBreak::Extend),
+    (0xFFF0, 0xFFFB, GraphemeBreak::Control),
+    (0x101FD, 0x101FD, GraphemeBreak::Extend),
+    (0x102E0, 0x102E0, GraphemeBreak::Extend),
+    (0x10376, 0x1037A, GraphemeBreak::Extend),
+    (0x10A01, 0x10A03, GraphemeBreak::Extend),
+    (0x10A05, 0x10A06, GraphemeBreak::Extend),
+    (0x10A0C, 0x10A0F, GraphemeBreak::Extend),
+    (0x10A38, 0x10A3A, GraphemeBreak::Extend),
+    (0x10A3F, 0x10A3F, GraphemeBreak::Extend),
+    (0x10AE5, 0x10AE6, GraphemeBreak::Extend),
+    (0x10D24, 0x10D27, GraphemeBreak::Extend),
+    (0x10D69, 0x10D6D, GraphemeBreak::Extend),
+    (0x10EAB, 0x10EAC, GraphemeBreak::Extend),
+    (0x10EFC, 0x10EFF, GraphemeBreak::Extend),
+    (0x10F46, 0x10F50, GraphemeBreak::Extend),
+    (0x10F82, 0x10F85, GraphemeBreak::Extend),
+    (0x11000, 0x11000, GraphemeBreak::SpacingMark),
+    (0x11001, 0x11001, GraphemeBreak::Extend),
+    (0x11002, 0x11002, GraphemeBreak::SpacingMark),
+    (0x11038, 0x11046, GraphemeBreak::Extend),
+    (0x11070, 0x11070, GraphemeBreak::Extend),
+    (0x11073, 0x11074, GraphemeBreak::Extend),
+    (0x1107F, 0x11081, GraphemeBreak::Extend),
+    (0x11082, 0x11082, GraphemeBreak::SpacingMark),
+    (0x110B0, 0x110B2, GraphemeBreak::SpacingMark),
+    (0x110B3, 0x110B6, GraphemeBreak::Extend),
+    (0x110B7, 0x110B8, GraphemeBreak::SpacingMark),
+    (0x110B9, 0x110BA, GraphemeBreak::Extend),
+    (0x110BD, 0x110BD, GraphemeBreak::Prepend),
+    (0x110C2, 0x110C2, GraphemeBreak::Extend),
+    (0x110CD, 0x110CD, GraphemeBreak::Prepend),
+    (0x11100, 0x11102, GraphemeBreak::Extend),
+    (0x11127, 0x1112B, GraphemeBreak::Extend),
+    (0x1112C, 0x1112C, GraphemeBreak::SpacingMark),
+    (0x1112D, 0x11134, GraphemeBreak::Extend),
+    (0x11145, 0x11146, GraphemeBreak::SpacingMark),
+    (0x11173, 0x11173, GraphemeBreak::Extend),
+    (0x11180, 0x11181, GraphemeBreak::Extend),
+    (0x11182, 0x11182, GraphemeBreak::SpacingMark),
+    (0x111B3, 0x111B5, GraphemeBreak::SpacingMark),
+    (0x111B6, 0x111BE, GraphemeBreak::Extend),
+    (0x111BF, 0x111BF, GraphemeBreak::SpacingMark),
+    (0x111C0, 0x111C0, GraphemeBreak::Extend),
+    (0x111C2, 0x111C3, GraphemeBreak::Prepend),
+    (0x111C9, 0x111CC, GraphemeBreak::Extend),
+    (0x111CE, 0x111CE, GraphemeBreak::SpacingMark),
+    (0x111CF, 0x111CF, GraphemeBreak::Extend),
+    (0x1122C, 0x1122E, GraphemeBreak::SpacingMark),
+    (0x1122F, 0x11231, GraphemeBreak::Extend),
+    (0x11232, 0x11233, GraphemeBreak::SpacingMark),
+    (0x11234, 0x11237, GraphemeBreak::Extend),
+    (0x1123E, 0x1123E, GraphemeBreak::Extend),
+    (0x11241, 0x11241, GraphemeBreak::Extend),
+    (0x112DF, 0x112DF, GraphemeBreak::Extend),
+    (0x112E0, 0x112E2, GraphemeBreak::SpacingMark),
+    (0x112E3, 0x112EA, GraphemeBreak::Extend),
+    (0x11300, 0x11301, GraphemeBreak::Extend),
+    (0x11302, 0x11303, GraphemeBreak::SpacingMark),
+    (0x1133B, 0x1133C, GraphemeBreak::Extend),
+    (0x1133E, 0x1133E, GraphemeBreak::Extend),
+    (0x1133F, 0x1133F, GraphemeBreak::SpacingMark),
+    (0x11340, 0x11340, GraphemeBreak::Extend),
+    (0x11341, 0x11344, GraphemeBreak::SpacingMark),
+    (0x11347, 0x11348, GraphemeBreak::SpacingMark),
+    (0x1134B, 0x1134C, GraphemeBreak::SpacingMark),
+    (0x1134D, 0x1134D, GraphemeBreak::Extend),
+    (0x11357, 0x11357, GraphemeBreak::Extend),
+    (0x11362, 0x11363, GraphemeBreak::SpacingMark),
+    (0x11366, 0x1136C, GraphemeBreak::Extend),
+    (0x11370, 0x11374, GraphemeBreak::Extend),
+    (0x113B8, 0x113B8, GraphemeBreak::Extend),
+    (0x113B9, 0x113BA, GraphemeBreak::SpacingMark),
+    (0x113BB, 0x113C0, GraphemeBreak::Extend),
+    (0x113C2, 0x113C2, GraphemeBreak::Extend),
+    (0x113C5, 0x113C5, GraphemeBreak::Extend),
+    (0x113C7, 0x113C9, GraphemeBreak::Extend),
+    (0x113CA, 0x113CA, GraphemeBreak::SpacingMark),
+    (0x113CC, 0x113CD, GraphemeBreak::SpacingMark),
+    (0x113CE, 0x113D0, GraphemeBreak::Extend),
+    (0x113D1, 0x113D1, GraphemeBreak::Prepend),
+    (0x113D2, 0x113D2, GraphemeBreak::Extend),
+    (0x113E1, 0x113E2, GraphemeBreak::Extend),
+    (0x11435, 0x11437, GraphemeBreak::SpacingMark),
+    (0x11438, 0x1143F, GraphemeBreak::Extend),
+    (0x11440, 0x11441, GraphemeBreak::SpacingMark),
+    (0x11442, 0x11444, GraphemeBreak::Extend),
+    (0x11445, 0x11445, GraphemeBreak::SpacingMark),
+    (0x11446, 0x11446, GraphemeBreak::Extend),
+    (0x1145E, 0x1145E, GraphemeBreak::Extend),
+    (0x114B0, 0x114B0, GraphemeBreak::Extend),
+    (0x114B1, 0x114B2, GraphemeBreak::SpacingMark),
+    (0x114B3, 0x114B8, GraphemeBreak::Extend),
+    (0x114B9, 0x114B9, GraphemeBreak::SpacingMark),
+    (0x114BA, 0x114BA, GraphemeBreak::Extend),
+    (0x114BB, 0x114BC, GraphemeBreak::SpacingMark),
+    (0x114BD, 0x114BD, GraphemeBreak::Extend),
+    (0x114BE, 0x114BE, GraphemeBreak::SpacingMark),
+    (0x114BF, 0x114C0, GraphemeBreak::Extend),
+    (0x114C1, 0x114C1, GraphemeBreak::SpacingMark),
+    (0x114C2, 0x114C3, GraphemeBreak::Extend),
+    (0x115AF, 0x115AF, GraphemeBreak::Extend),
+    (0x115B0, 0x115B1, GraphemeBreak::SpacingMark),
+    (0x115B2, 0x115B5, GraphemeBreak::Extend),
+    (0x115B8, 0x115BB, GraphemeBreak::SpacingMark),
+    (0x115BC, 0x115BD, GraphemeBreak::Extend),
+    (0x115BE, 0x115BE, GraphemeBreak::SpacingMark),
+    (0x115BF, 0x115C0, GraphemeBreak::Extend),
+    (0x115DC, 0x115DD, GraphemeBreak::Extend),
+    (0x11630, 0x11632, GraphemeBreak::SpacingMark),
+    (0x11633, 0x1163A, GraphemeBreak::Extend),
+    (0x1163B, 0x1163C, GraphemeBreak::SpacingMark),
+    (0x1163D, 0x1163D, GraphemeBreak::Extend),
+    (0x1163E, 0x1163E, GraphemeBreak::SpacingMark),
+    (0x1163F, 0x11640, GraphemeBreak::Extend),
+    (0x116AB, 0x116AB, GraphemeBreak::Extend),
+    (0x116AC, 0x116AC, GraphemeBreak::SpacingMark),
+    (0x116AD, 0x116AD, GraphemeBreak::Extend),
+    (0x116AE, 0x116AF, GraphemeBreak::SpacingMark),
+    (0x116B0, 0x116B7, GraphemeBreak::Extend),
+    (0x1171D, 0x1171D, GraphemeBreak::Extend),
+    (0x1171E, 0x1171E, GraphemeBreak::SpacingMark),
+    (0x1171F, 0x1171F, GraphemeBreak::Extend),
+    (0x11722, 0x11725, GraphemeBreak::Extend),
+    (0x11726, 0x11726, GraphemeBreak::SpacingMark),
+    (0x11727, 0x1172B, GraphemeBreak::Extend),
+    (0x1182C, 0x1182E, GraphemeBreak::SpacingMark),
+    (0x1182F, 0x11837, GraphemeBreak::Extend),
+    (0x11838, 0x11838, GraphemeBreak::SpacingMark),
+    (0x11839, 0x1183A, GraphemeBreak::Extend),
+    (0x11930, 0x11930, GraphemeBreak::Extend),
+    (0x11931, 0x11935, GraphemeBreak::SpacingMark),
+    (0x11937, 0x11938, GraphemeBreak::SpacingMark),
+    (0x1193B, 0x1193E, GraphemeBreak::Extend),
+    (0x1193F, 0x1193F, GraphemeBreak::Prepend),
+    (0x11940, 0x11940, GraphemeBreak::SpacingMark),
+    (0x11941, 0x11941, GraphemeBreak::Prepend),
+    (0x11942, 0x11942, GraphemeBreak::SpacingMark),
+    (0x11943, 0x11943, GraphemeBreak::Extend),
+    (0x119D1, 0x119D3, GraphemeBreak::SpacingMark),
+    (0x119D4, 0x119D7, GraphemeBreak::Extend),
+    (0x119DA, 0x119DB, GraphemeBreak::Extend),
+    (0x119DC, 0x119DF, GraphemeBreak::SpacingMark),
+    (0x119E0, 0x119E0, GraphemeBreak::Extend),
+    (0x119E4, 0x119E4, GraphemeBreak::SpacingMark),
+    (0x11A01, 0x11A0A, GraphemeBreak::Extend),
+    (0x11A33, 0x11A38, GraphemeBreak::Extend),
+    (0x11A39, 0x11A39, GraphemeBreak::SpacingMark),
+    (0x11A3A, 0x11A3A, GraphemeBreak::Prepend),
+    (0x11A3B, 0x11A3E, GraphemeBreak::Extend),
+    (0x11A47, 0x11A47, GraphemeBreak::Extend),
+    (0x11A51, 0x11A56, GraphemeBreak::Extend),
+    (0x11A57, 0x11A58, GraphemeBreak::SpacingMark),
+    (0x11A59, 0x11A5B, GraphemeBreak::Extend),
+    (0x11A84, 0x11A89, GraphemeBreak::Prepend),
+    (0x11A8A, 0x11A96, GraphemeBreak::Extend),
+    (0x11A97, 0x11A97, GraphemeBreak::SpacingMark),
+    (0x11A98, 0x11A99, GraphemeBreak::Extend),
+    (0x11C2F, 0x11C2F, GraphemeBreak::SpacingMark),
+    (0x11C30, 0x11C36, GraphemeBreak::Extend),
+    (0x11C38, 0x11C3D, GraphemeBreak::Extend),
+    (0x11C3E, 0x11C3E, GraphemeBreak::SpacingMark),
+    (0x11C3F, 0x11C3F, GraphemeBreak::Extend),
+    (0x11C92, 0x11CA7, GraphemeBreak::Extend),
+    (0x11CA9, 0x11CA9, GraphemeBreak::SpacingMark),
+    (0x11CAA, 0x11CB0, GraphemeBreak::Extend),
+    (0x11CB1, 0x11CB1, GraphemeBreak::SpacingMark),
+    (0x11CB2, 0x11CB3, GraphemeBreak::Extend),
+    (0x11CB4, 0x11CB4, GraphemeBreak::SpacingMark),
+    (0x11CB5, 0x11CB6, GraphemeBreak::Extend),
+    (0x11D31, 0x11D36, GraphemeBreak::Extend),
+    (0x11D3A, 0x11D3A, GraphemeBreak::Extend),
+    (0x11D3C, 0x11D3D, GraphemeBreak::Extend),
+    (0x11D3F, 0x11D45, GraphemeBreak::Extend),
+    (0x11D46, 0x11D46, GraphemeBreak::Prepend),
+    (0x11D47, 0x11D47, GraphemeBreak::Extend),
+    (0x11D8A, 0x11D8E, GraphemeBreak::SpacingMark),
+    (0x11D90, 0x11D91, GraphemeBreak::Extend),
+    (0x11D93, 0x11D94, GraphemeBreak::SpacingMark),
+    (0x11D95, 0x11D95, GraphemeBreak::Extend),
+    (0x11D96, 0x11D96, GraphemeBreak::SpacingMark),
+    (0x11D97, 0x11D97, GraphemeBreak::Extend),
+    (0x11EF3, 0x11EF4, GraphemeBreak::Extend),
+    (0x11EF5, 0x11EF6, GraphemeBreak::SpacingMark),
+    (0x11F00, 0x11F01, GraphemeBreak::Extend),
+    (0x11F02, 0x11F02, GraphemeBreak::Prepend),
+    (0x11F03, 0x11F03, GraphemeBreak::SpacingMark),
+    (0x11F34, 0x11F35, GraphemeBreak::SpacingMark),
+    (0x11F36, 0x11F3A, GraphemeBreak::Extend),
+    (0x11F3E, 0x11F3F, GraphemeBreak::SpacingMark),
+    (0x11F40, 0x11F42, GraphemeBreak::Extend),
+    (0x11F5A, 0x11F5A, GraphemeBreak::Extend),
+    (0x13430, 0x1343F, GraphemeBreak::Control),
+    (0x13440, 0x13440, GraphemeBreak::Extend),
+    (0x13447, 0x13455, GraphemeBreak::Extend),
+    (0x1611E, 0x16129, GraphemeBreak::Extend),
+    (0x1612A, 0x1612C, GraphemeBreak::SpacingMark),
+    (0x1612D, 0x1612F, GraphemeBreak::Extend),
+    (0x16AF0, 0x16AF4, GraphemeBreak::Extend),
+    (0x16B30, 0x16B36, GraphemeBreak::Extend),
+    (0x16D63, 0x16D63, GraphemeBreak::V),
+    (0x16D67, 0x16D6A, GraphemeBreak::V),
+    (0x16F4F, 0x16F4F, GraphemeBreak::Extend),
+    (0x16F51, 0x16F87, GraphemeBreak::SpacingMark),
+    (0x16F8F, 0x16F92, GraphemeBreak::Extend),
+    (0x16FE4, 0x16FE4, GraphemeBreak::Extend),
+    (0x16FF0, 0x16FF1, GraphemeBreak::Extend),
+    (0x1BC9D, 0x1BC9E, GraphemeBreak::Extend),
+    (0x1BCA0, 0x1BCA3, GraphemeBreak::Control),
+    (0x1CF00, 0x1CF2D, GraphemeBreak::Extend),
+    (0x1CF30, 0x1CF46, GraphemeBreak::Extend),
+    (0x1D165, 0x1D169, GraphemeBreak::Extend),
+    (0x1D16D, 0x1D172, GraphemeBreak::Extend),
+    (0x1D173, 0x1D17A, GraphemeBreak::Control),
+    (0x1D17B, 0x1D182, GraphemeBreak::Extend),
+    (0x1D185, 0x1D18B, GraphemeBreak::Extend),
+    (0x1D1AA, 0x1D1AD, GraphemeBreak::Extend),
+    (0x1D242, 0x1D244, GraphemeBreak::Extend),
+    (0x1DA00, 0x1DA36, GraphemeBreak::Extend),
+    (0x1DA3B, 0x1DA6C, GraphemeBreak::Extend),
+    (0x1DA75, 0x1DA75, GraphemeBreak::Extend),
+    (0x1DA84, 0x1DA84, GraphemeBreak::Extend),
+    (0x1DA9B, 0x1DA9F, GraphemeBreak::Extend),
+    (0x1DAA1, 0x1DAAF, GraphemeBreak::Extend),
+    (0x1E000, 0x1E006, GraphemeBreak::Extend),
+    (0x1E008, 0x1E018, GraphemeBreak::Extend),
+    (0x1E01B, 0x1E021, GraphemeBreak::Extend),
+    (0x1E023, 0x1E024, GraphemeBreak::Extend),
+    (0x1E026, 0x1E02A, GraphemeBreak::Extend),
+    (0x1E08F, 0x1E08F, GraphemeBreak::Extend),
+    (0x1E130, 0x1E136, GraphemeBreak::Extend),
+    (0x1E2AE, 0x1E2AE, GraphemeBreak::Extend),
+    (0x1E2EC, 0x1E2EF, GraphemeBreak::Extend),
+    (0x1E4EC, 0x1E4EF, GraphemeBreak::Extend),
+    (0x1E5EE, 0x1E5EF, GraphemeBreak::Extend),
+    (0x1E8D0, 0x1E8D6, GraphemeBreak::Extend),
+    (0x1E944, 0x1E94A, GraphemeBreak::Extend),
+    (0x1F000, 0x1F0FF, GraphemeBreak::ExtendedPictographic),
+    (0x1F10D, 0x1F10F, GraphemeBreak::ExtendedPictographic),
+    (0x1F12F, 0x1F12F, GraphemeBreak::ExtendedPictographic),
+    (0x1F16C, 0x1F171, GraphemeBreak::ExtendedPictographic),
+    (0x1F17E, 0x1F17F, GraphemeBreak::ExtendedPictographic),
+    (0x1F18E, 0x1F18E, GraphemeBreak::ExtendedPictographic),
+    (0x1F191, 0x1F19A, GraphemeBreak::ExtendedPictographic),
+    (0x1F1AD, 0x1F1E5, GraphemeBreak::ExtendedPictographic),
+    (0x1F1E6, 0x1F1FF, GraphemeBreak::RegionalIndicator),
+    (0x1F201, 0x1F20F, GraphemeBreak::ExtendedPictographic),
+    (0x1F21A, 0x1F21A, GraphemeBreak::ExtendedPictographic),
+    (0x1F22F, 0x1F22F, GraphemeBreak::ExtendedPictographic),
+    (0x1F232, 0x1F23A, GraphemeBreak::ExtendedPictographic),
+    (0x1F23C, 0x1F23F, GraphemeBreak::ExtendedPictographic),
+    (0x1F249, 0x1F3FA, GraphemeBreak::ExtendedPictographic),
+    (0x1F3FB, 0x1F3FF, GraphemeBreak::Extend),
+    (0x1F400, 0x1F53D, GraphemeBreak::ExtendedPictographic),
+    (0x1F546, 0x1F64F, GraphemeBreak::ExtendedPictographic),
+    (0x1F680, 0x1F6FF, GraphemeBreak::ExtendedPictographic),
+    (0x1F774, 0x1F77F, GraphemeBreak::ExtendedPictographic),
+    (0x1F7D5, 0x1F7FF, GraphemeBreak::ExtendedPictographic),
+    (0x1F80C, 0x1F80F, GraphemeBreak::ExtendedPictographic),
+    (0x1F848, 0x1F84F, GraphemeBreak::ExtendedPictographic),
+    (0x1F85A, 0x1F85F, GraphemeBreak::ExtendedPictographic),
+    (0x1F888, 0x1F88F, GraphemeBreak::ExtendedPictographic),
+    (0x1F8AE, 0x1F8FF, GraphemeBreak::ExtendedPictographic),
+    (0x1F90C, 0x1F93A, GraphemeBreak::ExtendedPictographic),
+    (0x1F93C, 0x1F945, GraphemeBreak::ExtendedPictographic),
+    (0x1F947, 0x1FAFF, GraphemeBreak::ExtendedPictographic),
+    (0x1FC00, 0x1FFFD, GraphemeBreak::ExtendedPictographic),
+    (0xE0000, 0xE001F, GraphemeBreak::Control),
+    (0xE0020, 0xE007F, GraphemeBreak::Extend),
+    (0xE0080, 0xE00FF, GraphemeBreak::Control),
+    (0xE0100, 0xE01EF, GraphemeBreak::Extend),
+    (0xE01F0, 0xE0FFF, GraphemeBreak::Control),
+];
+
+#[test]
+fn test_graphemes() {
+    let clusters: alloc::vec::Vec<&str> =
+        graphemes("e\u{301}x\r\n\u{1F469}\u{200D}\u{1F4BB}\u{1F1EB}\u{1F1F7}\u{1F1EB}\u{1100}\u{1161}\u{11A8}")
+            .collect();
+    assert_eq!(
+        clusters,
+        [
+            "e\u{301}",
+            "x",
+            "\r\n",
+            "\u{1F469}\u{200D}\u{1F4BB}",
+            "\u{1F1EB}\u{1F1F7}",
+            "\u{1F1EB}",
+            "\u{1100}\u{1161}\u{11A8}"
+        ]
+    );
+}
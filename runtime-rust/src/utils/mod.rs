@@ -21,6 +21,7 @@ use core::ops::{Deref, DerefMut};
 
 pub mod biglist;
 pub mod bin;
+pub mod graphemes;
 
 /// Represents a reference to a structure that can be either mutable or immutable
 pub enum EitherMut<'a, T: 'a> {
@@ -27,11 +27,11 @@ use hime_redist::lexers::automaton::{Automaton, DEAD_STATE};
 use hime_redist::parsers::lrk::LRkAutomaton;
 use hime_redist::parsers::rnglr::RNGLRAutomaton;
 use hime_redist::parsers::{
-    get_op_code_base, get_op_code_tree_action, LR_ACTION_CODE_ACCEPT, LR_ACTION_CODE_NONE,
-    LR_ACTION_CODE_REDUCE, LR_ACTION_CODE_SHIFT, LR_OP_CODE_BASE_ADD_NULLABLE_VARIABLE,
-    LR_OP_CODE_BASE_ADD_VIRTUAL, LR_OP_CODE_BASE_POP_STACK, LR_OP_CODE_BASE_SEMANTIC_ACTION,
-    TREE_ACTION_DROP, TREE_ACTION_PROMOTE, TREE_ACTION_REPLACE_BY_CHILDREN,
-    TREE_ACTION_REPLACE_BY_EPSILON,
+    get_op_code_base, get_op_code_tree_action, LR_ACTION_CODE_ACCEPT, LR_ACTION_CODE_LOOKAHEAD,
+    LR_ACTION_CODE_NONE, LR_ACTION_CODE_REDUCE, LR_ACTION_CODE_SHIFT,
+    LR_OP_CODE_BASE_ADD_NULLABLE_VARIABLE, LR_OP_CODE_BASE_ADD_VIRTUAL, LR_OP_CODE_BASE_POP_STACK,
+    LR_OP_CODE_BASE_SEMANTIC_ACTION, TREE_ACTION_DROP, TREE_ACTION_PROMOTE,
+    TREE_ACTION_REPLACE_BY_CHILDREN, TREE_ACTION_REPLACE_BY_EPSILON,
 };
 
 /// The name of this program
@@ -204,7 +204,7 @@ fn print_lexer(file_name: &str) -> Result<(), Box<dyn Error>> {
                 println!(
                     "    0x{:X} ('{}') -> {}",
                     i,
-                    char::from_u32(u32::from(i)).unwrap(),
+                    char::from_u32(i).unwrap(),
                     next
                 );
             }
@@ -304,6 +304,13 @@ fn print_parser_lrk(file_name: &str, show_bytecode: bool) -> Result<(), Box<dyn
                 LR_ACTION_CODE_ACCEPT => {
                     println!("    on 0x{c:X}, accept");
                 }
+                LR_ACTION_CODE_LOOKAHEAD => {
+                    println!(
+                        "    on 0x{:X}, look ahead with node {}",
+                        c,
+                        action.get_data()
+                    );
+                }
                 _ => {}
             }
         }
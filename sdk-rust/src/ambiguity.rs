@@ -0,0 +1,369 @@
+/*******************************************************************************
+ * Copyright (c) 2020 Association Cénotélie (cenotelie.fr)
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Lesser General Public License as
+ * published by the Free Software Foundation, either version 3
+ * of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU Lesser General Public License for more details.
+ *
+ * You should have received a copy of the GNU Lesser General
+ * Public License along with this program.
+ * If not, see <http://www.gnu.org/licenses/>.
+ ******************************************************************************/
+
+//! Module for the static detection of ambiguities in grammars
+//!
+//! The analysis is approximating and starts from the conflicts in the LR(1) graph of a grammar,
+//! because a grammar without conflicts is LR(1), and therefore unambiguous.
+//! The conflicts resolved by the precedence declarations are not considered.
+//! For each remaining conflict:
+//! * A unifying counterexample shows that the grammar is ambiguous.
+//! * A conflict that disappears with more tokens of lookahead is not an ambiguity.
+//! * Otherwise, a bounded-length exhaustive search looks for a sentence with two derivations
+//!   that both use the conflicting rules.
+//!
+//! The conflicts that could not be decided are reported as possibly ambiguous.
+
+use std::collections::HashMap;
+
+use crate::grammars::{Grammar, RuleRef, SymbolRef, TerminalRef, GENERATED_AXIOM};
+use crate::lr::{
+    build_graph_lrk, build_graph_rnglr1, find_counterexample, Conflict, ConflictKind,
+    Counterexample, Derivation,
+};
+
+/// The maximum number of tokens of lookahead used to check whether a conflict is an ambiguity
+pub const AMBIGUITY_MAX_LOOKAHEAD: usize = 3;
+
+/// The maximum length of the sentences explored by the bounded search
+pub const AMBIGUITY_MAX_LENGTH: usize = 8;
+
+/// The maximum number of steps for the bounded search
+const AMBIGUITY_MAX_STEPS: usize = 1_000_000;
+
+/// The maximum number of ambiguous sentences collected by the bounded search
+const AMBIGUITY_MAX_FOUND: usize = 1_000;
+
+/// The kinds of ambiguities
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AmbiguityKind {
+    /// The grammar is ambiguous, an input has two derivations
+    Ambiguous,
+    /// The analysis could not decide whether the conflict comes from an ambiguity
+    PossiblyAmbiguous,
+}
+
+/// An ambiguity in a grammar, as found by the static analysis
+#[derive(Debug, Clone)]
+pub struct Ambiguity {
+    /// The kind of ambiguity
+    pub kind: AmbiguityKind,
+    /// The conflict in the LR(1) graph at the origin of the ambiguity
+    pub conflict: Conflict,
+    /// For an ambiguity, two derivations of the same input,
+    /// otherwise the counterexample for the conflict, if any
+    pub counterexample: Option<Counterexample>,
+}
+
+impl Ambiguity {
+    /// Gets the rules involved in this ambiguity
+    #[must_use]
+    pub fn get_rules(&self) -> Vec<RuleRef> {
+        let mut rules: Vec<RuleRef> = Vec::new();
+        for item in self
+            .conflict
+            .shift_items
+            .iter()
+            .chain(self.conflict.reduce_items.iter())
+        {
+            if !rules.contains(&item.rule) {
+                rules.push(item.rule);
+            }
+        }
+        rules
+    }
+
+    /// Gets a description of this ambiguity
+    ///
+    /// # Panics
+    ///
+    /// A panic is raised when the symbols in the rules cannot be found
+    /// in their respective grammar (which should not happen).
+    #[must_use]
+    pub fn get_description(&self, grammar: &Grammar) -> String {
+        let rules: Vec<String> = self
+            .get_rules()
+            .into_iter()
+            .map(|rule_ref| {
+                let rule = rule_ref.get_rule_in(grammar);
+                let mut text = format!(
+                    "{} ->",
+                    grammar.get_symbol_name(SymbolRef::Variable(rule.head))
+                );
+                for element in &rule.body.choices[0].elements {
+                    text.push(' ');
+                    text.push_str(grammar.get_symbol_value(element.symbol));
+                }
+                text
+            })
+            .collect();
+        let mut lines = vec![format!(
+            "{} {} conflict on `{}` in state {}, between {}",
+            match self.kind {
+                AmbiguityKind::Ambiguous => "Ambiguous:",
+                AmbiguityKind::PossiblyAmbiguous => "Possibly ambiguous:",
+            },
+            match self.conflict.kind {
+                ConflictKind::ShiftReduce => "Shift/Reduce",
+                ConflictKind::ReduceReduce => "Reduce/Reduce",
+            },
+            self.conflict.lookahead.get_value(grammar),
+            self.conflict.state,
+            rules.join(" and ")
+        )];
+        match (&self.counterexample, self.kind) {
+            (Some(counterexample), AmbiguityKind::Ambiguous) => {
+                let example = counterexample.first.get_example(grammar);
+                lines.push(format!(
+                    "  Example of input that is ambiguous: {}",
+                    if example.is_empty() { "ε" } else { &example }
+                ));
+                lines.push(format!(
+                    "  First derivation: {}",
+                    counterexample.first.get_description(grammar)
+                ));
+                lines.push(format!(
+                    "  Second derivation: {}",
+                    counterexample.second.get_description(grammar)
+                ));
+            }
+            (Some(counterexample), AmbiguityKind::PossiblyAmbiguous) => {
+                lines.push(format!(
+                    "  First example: {}",
+                    counterexample.first.get_example(grammar)
+                ));
+                lines.push(format!(
+                    "  Second example: {}",
+                    counterexample.second.get_example(grammar)
+                ));
+            }
+            (None, _) => {}
+        }
+        lines.join("\n")
+    }
+}
+
+/// The sentences of a variable found by the bounded search, with their first derivation
+type Sentences = HashMap<Vec<TerminalRef>, Derivation>;
+
+/// A bounded-length exhaustive search for sentences with two derivations
+struct BoundedSearch<'a> {
+    /// The grammar
+    grammar: &'a Grammar,
+    /// The sentences found so far, for each variable
+    sentences: HashMap<usize, Sentences>,
+    /// The ambiguous sentences found so far, with their two derivations, from the shortest
+    ambiguities: Vec<(usize, Derivation, Derivation)>,
+    /// The remaining number of steps
+    steps: usize,
+}
+
+impl<'a> BoundedSearch<'a> {
+    /// Runs the search on a grammar with increasing sentence lengths
+    fn run(grammar: &'a Grammar) -> BoundedSearch<'a> {
+        let mut search = BoundedSearch {
+            grammar,
+            sentences: HashMap::new(),
+            ambiguities: Vec::new(),
+            steps: AMBIGUITY_MAX_STEPS,
+        };
+        for length in 0..=AMBIGUITY_MAX_LENGTH {
+            while search.steps > 0 && search.iterate(length) {}
+        }
+        search
+    }
+
+    /// Executes one iteration of the search for sentences of at most the specified length
+    /// Returns whether new sentences were found
+    fn iterate(&mut self, max_length: usize) -> bool {
+        let mut changed = false;
+        for variable in &self.grammar.variables {
+            if variable.name == GENERATED_AXIOM {
+                continue;
+            }
+            for (index, rule) in variable.rules.iter().enumerate() {
+                let symbols: Vec<SymbolRef> = rule.body.choices[0]
+                    .elements
+                    .iter()
+                    .map(|element| element.symbol)
+                    .filter(|symbol| {
+                        matches!(symbol, SymbolRef::Terminal(_) | SymbolRef::Variable(_))
+                    })
+                    .collect();
+                for (sentence, children) in self.expand(&symbols, max_length) {
+                    let derivation = Derivation::Rule(RuleRef::new(variable.id, index), children);
+                    let sentences = self.sentences.entry(variable.id).or_default();
+                    match sentences.get(&sentence) {
+                        None => {
+                            sentences.insert(sentence, derivation);
+                            changed = true;
+                        }
+                        Some(existing)
+                            if *existing != derivation
+                                && self.ambiguities.len() < AMBIGUITY_MAX_FOUND
+                                && !self.ambiguities.iter().any(|(_, first, second)| {
+                                    first == existing && second == &derivation
+                                }) =>
+                        {
+                            self.ambiguities
+                                .push((sentence.len(), existing.clone(), derivation));
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+        changed
+    }
+
+    /// Gets the sentences of at most the specified length for a sequence of symbols, with the derivations for each symbol
+    fn expand(
+        &mut self,
+        symbols: &[SymbolRef],
+        max_length: usize,
+    ) -> Vec<(Vec<TerminalRef>, Vec<Derivation>)> {
+        let mut partials: Vec<(Vec<TerminalRef>, Vec<Derivation>)> = vec![(Vec::new(), Vec::new())];
+        for symbol in symbols {
+            let mut next = Vec::new();
+            for (sentence, children) in &partials {
+                match symbol {
+                    SymbolRef::Terminal(id) if sentence.len() < max_length && self.steps > 0 => {
+                        self.steps -= 1;
+                        let mut sentence = sentence.clone();
+                        sentence.push(TerminalRef::Terminal(*id));
+                        let mut children = children.clone();
+                        children.push(Derivation::Symbol(*symbol));
+                        next.push((sentence, children));
+                    }
+                    SymbolRef::Variable(id) => {
+                        let Some(candidates) = self.sentences.get(id) else {
+                            continue;
+                        };
+                        for (part, derivation) in candidates {
+                            if sentence.len() + part.len() > max_length || self.steps == 0 {
+                                continue;
+                            }
+                            self.steps -= 1;
+                            let mut sentence = sentence.clone();
+                            sentence.extend_from_slice(part);
+                            let mut children = children.clone();
+                            children.push(derivation.clone());
+                            next.push((sentence, children));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            partials = next;
+        }
+        partials
+    }
+
+    /// Finds the shortest ambiguous sentence where both derivations use one of the rules
+    fn find_for(&self, rules: &[RuleRef]) -> Option<(&Derivation, &Derivation)> {
+        self.ambiguities
+            .iter()
+            .filter(|(_, first, second)| uses_any(first, rules) && uses_any(second, rules))
+            .min_by_key(|(length, _, _)| *length)
+            .map(|(_, first, second)| (first, second))
+    }
+}
+
+/// Gets whether a derivation uses one of the rules
+fn uses_any(derivation: &Derivation, rules: &[RuleRef]) -> bool {
+    match derivation {
+        Derivation::Rule(rule, children) => {
+            rules.contains(rule) || children.iter().any(|child| uses_any(child, rules))
+        }
+        _ => false,
+    }
+}
+
+/// Gets whether a conflict in the LR(1) graph also appears with more tokens of lookahead
+fn is_conflict_in(conflict: &Conflict, others: &[Conflict]) -> bool {
+    others.iter().any(|other| {
+        other.kind == conflict.kind
+            && other.lookahead.terminal == conflict.lookahead.terminal
+            && other.reduce_items.iter().any(|item| {
+                conflict
+                    .reduce_items
+                    .iter()
+                    .any(|reducing| reducing.same_base(item))
+            })
+    })
+}
+
+/// Finds the ambiguities in a grammar
+/// The grammar must have been prepared, for example by building it.
+///
+/// # Panics
+///
+/// A panic is raised when the symbols in the rules cannot be found
+/// in their respective grammar (which should not happen).
+#[must_use]
+pub fn find_ambiguities(grammar: &Grammar) -> Vec<Ambiguity> {
+    let (graph, conflicts) = build_graph_rnglr1(grammar);
+    if conflicts.is_empty() {
+        // the grammar is LR(1)
+        return Vec::new();
+    }
+    let inverse = graph.inverse();
+    let mut ambiguities = Vec::new();
+    let mut undecided = Vec::new();
+    for conflict in conflicts.get_conflicts() {
+        let counterexample = find_counterexample(&graph, &inverse, grammar, conflict);
+        if counterexample.as_ref().is_some_and(|c| c.unifying) {
+            ambiguities.push(Ambiguity {
+                kind: AmbiguityKind::Ambiguous,
+                conflict: conflict.clone(),
+                counterexample,
+            });
+        } else {
+            undecided.push((conflict, counterexample));
+        }
+    }
+    // remove the conflicts that disappear with more lookahead
+    for k in 2..=AMBIGUITY_MAX_LOOKAHEAD {
+        if undecided.is_empty() {
+            break;
+        }
+        let (_, conflicts_k) = build_graph_lrk(grammar, k);
+        undecided.retain(|(conflict, _)| is_conflict_in(conflict, conflicts_k.get_conflicts()));
+    }
+    if undecided.is_empty() {
+        return ambiguities;
+    }
+    // look for ambiguous sentences of the conflicting variables
+    let search = BoundedSearch::run(grammar);
+    for (conflict, counterexample) in undecided {
+        let mut ambiguity = Ambiguity {
+            kind: AmbiguityKind::PossiblyAmbiguous,
+            conflict: conflict.clone(),
+            counterexample,
+        };
+        if let Some((first, second)) = search.find_for(&ambiguity.get_rules()) {
+            ambiguity.kind = AmbiguityKind::Ambiguous;
+            ambiguity.counterexample = Some(Counterexample {
+                unifying: true,
+                first: first.clone(),
+                second: second.clone(),
+            });
+        }
+        ambiguities.push(ambiguity);
+    }
+    ambiguities
+}
@@ -0,0 +1,69 @@
+/*******************************************************************************
+ * Copyright (c) 2020 Association Cénotélie (cenotelie.fr)
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Lesser General Public License as
+ * published by the Free Software Foundation, either version 3
+ * of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU Lesser General Public License for more details.
+ *
+ * You should have received a copy of the GNU Lesser General
+ * Public License along with this program.
+ * If not, see <http://www.gnu.org/licenses/>.
+ ******************************************************************************/
+
+//! Stable codes for the errors and their long-form explanations
+
+/// The stable codes of the errors, with their long-form explanations
+pub const ERROR_CODES: [(&str, &str); 38] = [
+    ("H0001", include_str!("codes/H0001.md")),
+    ("H0002", include_str!("codes/H0002.md")),
+    ("H0003", include_str!("codes/H0003.md")),
+    ("H0004", include_str!("codes/H0004.md")),
+    ("H0005", include_str!("codes/H0005.md")),
+    ("H0006", include_str!("codes/H0006.md")),
+    ("H0007", include_str!("codes/H0007.md")),
+    ("H0008", include_str!("codes/H0008.md")),
+    ("H0009", include_str!("codes/H0009.md")),
+    ("H0010", include_str!("codes/H0010.md")),
+    ("H0011", include_str!("codes/H0011.md")),
+    ("H0012", include_str!("codes/H0012.md")),
+    ("H0013", include_str!("codes/H0013.md")),
+    ("H0014", include_str!("codes/H0014.md")),
+    ("H0015", include_str!("codes/H0015.md")),
+    ("H0016", include_str!("codes/H0016.md")),
+    ("H0017", include_str!("codes/H0017.md")),
+    ("H0018", include_str!("codes/H0018.md")),
+    ("H0019", include_str!("codes/H0019.md")),
+    ("H0020", include_str!("codes/H0020.md")),
+    ("H0021", include_str!("codes/H0021.md")),
+    ("H0022", include_str!("codes/H0022.md")),
+    ("H0023", include_str!("codes/H0023.md")),
+    ("H0024", include_str!("codes/H0024.md")),
+    ("H0025", include_str!("codes/H0025.md")),
+    ("H0026", include_str!("codes/H0026.md")),
+    ("H0027", include_str!("codes/H0027.md")),
+    ("H0028", include_str!("codes/H0028.md")),
+    ("H0029", include_str!("codes/H0029.md")),
+    ("H0030", include_str!("codes/H0030.md")),
+    ("H0031", include_str!("codes/H0031.md")),
+    ("H0032", include_str!("codes/H0032.md")),
+    ("H0033", include_str!("codes/H0033.md")),
+    ("H0034", include_str!("codes/H0034.md")),
+    ("H0035", include_str!("codes/H0035.md")),
+    ("H0036", include_str!("codes/H0036.md")),
+    ("H0037", include_str!("codes/H0037.md")),
+    ("H0038", include_str!("codes/H0038.md")),
+];
+
+/// Gets the long-form explanation for an error code
+#[must_use]
+pub fn get_explanation(code: &str) -> Option<&'static str> {
+    ERROR_CODES
+        .iter()
+        .find(|(candidate, _)| candidate.eq_ignore_ascii_case(code))
+        .map(|(_, explanation)| *explanation)
+}
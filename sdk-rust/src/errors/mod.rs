@@ -17,6 +17,7 @@
 
 //! Module for the management of errors in the SDK
 
+pub mod codes;
 #[cfg(feature = "print_errors")]
 pub mod print;
 
@@ -24,7 +25,7 @@ use std::fmt::{Display, Formatter};
 use std::io;
 
 use crate::grammars::{TerminalRef, OPTION_AXIOM, OPTION_SEPARATOR};
-use crate::lr::{Conflict, ConflictKind, ContextError};
+use crate::lr::{Conflict, ConflictKind, ContextError, ResolvedConflict};
 use crate::{InputReference, LoadedData};
 
 /// Represents an error where a token is used by cannot be produced by the lexer
@@ -36,6 +37,19 @@ pub struct UnmatchableTokenError {
     pub overriders: Vec<TerminalRef>,
 }
 
+/// The names of the warnings, used to allow them
+pub const WARNING_NAMES: [&str; 9] = [
+    "overriding-terminal",
+    "resolved-conflict",
+    "unreachable-variable",
+    "unproductive-variable",
+    "unused-terminal",
+    "unused-fragment",
+    "empty-context",
+    "duplicate-rule",
+    "shadowed-terminal",
+];
+
 /// The global error type
 #[derive(Debug)]
 pub enum Error {
@@ -79,12 +93,22 @@ pub enum Error {
     UnknownUnicodeBlock(InputReference, String),
     /// The unicode category is not known
     UnknownUnicodeCategory(InputReference, String),
+    /// The unicode script or binary property is not known
+    UnknownUnicodeProperty(InputReference, String),
+    /// The version of the Unicode Character Database is not supported (requested, supported)
+    UnsupportedUnicodeVersion(InputReference, String, Vec<String>),
     /// A unicode character not in plane 0 was used in a character class, which is not supported
     UnsupportedNonPlane0InCharacterClass(InputReference, char),
     /// The specified value is not a valid unicode code point
     InvalidCodePoint(InputReference, u32),
-    /// A terminal override a previous definition
+    /// A unicode character or class was used in a grammar whose terminals are defined over bytes
+    UnicodeInByteGrammar(InputReference),
+    /// A byte value was used in a grammar whose terminals are defined over characters
+    ByteInTextGrammar(InputReference),
+    /// Warning: a terminal override a previous definition, the new definition is ignored
     OverridingPreviousTerminal(InputReference, String, InputReference),
+    /// The precedence of a terminal is declared more than once
+    PrecedenceRedefined(InputReference, String, InputReference),
     /// The inherited grammar cannot be found
     GrammarNotDefined(InputReference, String),
     /// A conflict in a grammar
@@ -96,6 +120,78 @@ pub enum Error {
     /// A terminal matches the empty string
     /// (grammar_index, terminal)
     TerminalMatchesEmpty(usize, TerminalRef),
+    /// Warning: a conflict was resolved by the precedence declarations
+    ConflictResolved(usize, Box<ResolvedConflict>),
+    /// Warning: a variable cannot be reached from the grammar's axiom
+    UnreachableVariable(InputReference, String),
+    /// Warning: a variable cannot produce any finite sequence of terminals
+    UnproductiveVariable(InputReference, String),
+    /// Warning: a terminal is never used in the grammar's rules
+    UnusedTerminal(InputReference, String),
+    /// Warning: a fragment is never used
+    UnusedFragment(InputReference, String),
+    /// Warning: a lexical context has no terminal
+    EmptyContext(InputReference, String),
+    /// Warning: a rule has the same body as a previous rule for the same variable
+    DuplicateRuleBody(InputReference, String, InputReference),
+    /// Warning: a terminal is never used and can never be matched, it is overriden by others
+    ShadowedTerminal(usize, UnmatchableTokenError),
+}
+
+impl Clone for Error {
+    /// Clones this error
+    /// An IO error is cloned with its kind and message only.
+    #[allow(clippy::too_many_lines)]
+    fn clone(&self) -> Self {
+        match self {
+            Error::Io(err) => Error::Io(io::Error::new(err.kind(), err.to_string())),
+            Error::Msg(a) => Error::Msg(a.clone()),
+            Error::Parsing(a, b) => Error::Parsing(*a, b.clone()),
+            Error::GrammarNotSpecified => Error::GrammarNotSpecified,
+            Error::GrammarNotFound(a) => Error::GrammarNotFound(a.clone()),
+            Error::InvalidOption(a, b, c) => Error::InvalidOption(*a, b.clone(), c.clone()),
+            Error::AxiomNotSpecified(a) => Error::AxiomNotSpecified(*a),
+            Error::AxiomNotDefined(a) => Error::AxiomNotDefined(*a),
+            Error::SeparatorNotDefined(a) => Error::SeparatorNotDefined(*a),
+            Error::SeparatorIsContextual(a, b) => Error::SeparatorIsContextual(*a, *b),
+            Error::SeparatorCannotBeMatched(a, b) => Error::SeparatorCannotBeMatched(*a, b.clone()),
+            Error::TemplateRuleNotFound(a, b) => Error::TemplateRuleNotFound(*a, b.clone()),
+            Error::TemplateRuleWrongNumberOfArgs(a, b, c) => {
+                Error::TemplateRuleWrongNumberOfArgs(*a, *b, *c)
+            }
+            Error::SymbolNotFound(a, b) => Error::SymbolNotFound(*a, b.clone()),
+            Error::InvalidCharacterSpan(a) => Error::InvalidCharacterSpan(*a),
+            Error::UnknownUnicodeBlock(a, b) => Error::UnknownUnicodeBlock(*a, b.clone()),
+            Error::UnknownUnicodeCategory(a, b) => Error::UnknownUnicodeCategory(*a, b.clone()),
+            Error::UnknownUnicodeProperty(a, b) => Error::UnknownUnicodeProperty(*a, b.clone()),
+            Error::UnsupportedUnicodeVersion(a, b, c) => {
+                Error::UnsupportedUnicodeVersion(*a, b.clone(), c.clone())
+            }
+            Error::UnsupportedNonPlane0InCharacterClass(a, b) => {
+                Error::UnsupportedNonPlane0InCharacterClass(*a, *b)
+            }
+            Error::InvalidCodePoint(a, b) => Error::InvalidCodePoint(*a, *b),
+            Error::UnicodeInByteGrammar(a) => Error::UnicodeInByteGrammar(*a),
+            Error::ByteInTextGrammar(a) => Error::ByteInTextGrammar(*a),
+            Error::OverridingPreviousTerminal(a, b, c) => {
+                Error::OverridingPreviousTerminal(*a, b.clone(), *c)
+            }
+            Error::PrecedenceRedefined(a, b, c) => Error::PrecedenceRedefined(*a, b.clone(), *c),
+            Error::GrammarNotDefined(a, b) => Error::GrammarNotDefined(*a, b.clone()),
+            Error::LrConflict(a, b) => Error::LrConflict(*a, b.clone()),
+            Error::TerminalOutsideContext(a, b) => Error::TerminalOutsideContext(*a, b.clone()),
+            Error::TerminalCannotBeMatched(a, b) => Error::TerminalCannotBeMatched(*a, b.clone()),
+            Error::TerminalMatchesEmpty(a, b) => Error::TerminalMatchesEmpty(*a, *b),
+            Error::ConflictResolved(a, b) => Error::ConflictResolved(*a, b.clone()),
+            Error::UnreachableVariable(a, b) => Error::UnreachableVariable(*a, b.clone()),
+            Error::UnproductiveVariable(a, b) => Error::UnproductiveVariable(*a, b.clone()),
+            Error::UnusedTerminal(a, b) => Error::UnusedTerminal(*a, b.clone()),
+            Error::UnusedFragment(a, b) => Error::UnusedFragment(*a, b.clone()),
+            Error::EmptyContext(a, b) => Error::EmptyContext(*a, b.clone()),
+            Error::DuplicateRuleBody(a, b, c) => Error::DuplicateRuleBody(*a, b.clone(), *c),
+            Error::ShadowedTerminal(a, b) => Error::ShadowedTerminal(*a, b.clone()),
+        }
+    }
 }
 
 impl From<io::Error> for Error {
@@ -105,6 +201,7 @@ impl From<io::Error> for Error {
 }
 
 impl Display for Error {
+    #[allow(clippy::too_many_lines)]
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Io(e) => e.fmt(f),
@@ -145,6 +242,12 @@ impl Display for Error {
             Self::UnknownUnicodeCategory(_input, name) => {
                 write!(f, "Unknown unicode category `{name}`")
             }
+            Self::UnknownUnicodeProperty(_input, name) => {
+                write!(f, "Unknown unicode script or property `{name}`")
+            }
+            Self::UnsupportedUnicodeVersion(_input, version, _supported) => {
+                write!(f, "Unsupported unicode version `{version}`")
+            }
             Self::UnsupportedNonPlane0InCharacterClass(_input, c) => write!(
                 f,
                 "Unsupported non-plane 0 Unicode character {} (U+{:04X}) in character class",
@@ -155,19 +258,35 @@ impl Display for Error {
                 f,
                 "The value U+{c:0X} is not a supported unicode code point"
             ),
+            Self::UnicodeInByteGrammar(_input) => write!(
+                f,
+                "Unicode characters and classes cannot be used when the input is made of bytes"
+            ),
+            Self::ByteInTextGrammar(_input) => write!(
+                f,
+                "Byte values can only be used when the input is made of bytes (option Input = \"bytes\")"
+            ),
             Self::OverridingPreviousTerminal(_input, name, _previous) => {
                 write!(f, "Overriding the previous definition of `{name}`")
             }
+            Self::PrecedenceRedefined(_input, name, _previous) => {
+                write!(f, "The precedence of `{name}` is already declared")
+            }
             Self::GrammarNotDefined(_input, name) => {
                 write!(f, "Grammar `{name}` is not defined")
             }
             Self::LrConflict(_grammar_index, conflict) => {
                 write!(
                     f,
-                    "{} conflict, cannot decide what to do",
+                    "{} conflict{}, cannot decide what to do",
                     match conflict.kind {
                         ConflictKind::ShiftReduce => "Shift/Reduce",
                         ConflictKind::ReduceReduce => "Reduce/Reduce",
+                    },
+                    if conflict.lalr_merge.is_some() {
+                        " introduced by the LALR(1) merge of LR(1) states"
+                    } else {
+                        ""
                     }
                 )
             }
@@ -180,6 +299,13 @@ impl Display for Error {
             Self::TerminalMatchesEmpty(_grammar_index, _terminal_ref) => {
                 write!(f, "Terminal matches empty string, which is not allowed",)
             }
+            Self::ConflictResolved(_grammar_index, _resolved) => {
+                write!(f, "Conflict resolved by the precedence declarations")
+            }
+            Self::ShadowedTerminal(_grammar_index, _error) => {
+                write!(f, "Terminal is never used and can never be matched")
+            }
+            _ => self.fmt_warning(f),
         }
     }
 }
@@ -194,6 +320,98 @@ impl std::error::Error for Error {
 }
 
 impl Error {
+    /// Gets the stable code of this error, see `codes::ERROR_CODES`
+    #[must_use]
+    pub fn get_code(&self) -> &'static str {
+        match self {
+            Self::Io(..) => "H0001",
+            Self::Msg(..) => "H0002",
+            Self::Parsing(..) => "H0003",
+            Self::GrammarNotSpecified => "H0004",
+            Self::GrammarNotFound(..) => "H0005",
+            Self::InvalidOption(..) => "H0006",
+            Self::AxiomNotSpecified(..) => "H0007",
+            Self::AxiomNotDefined(..) => "H0008",
+            Self::SeparatorNotDefined(..) => "H0009",
+            Self::SeparatorIsContextual(..) => "H0010",
+            Self::SeparatorCannotBeMatched(..) => "H0011",
+            Self::TemplateRuleNotFound(..) => "H0012",
+            Self::TemplateRuleWrongNumberOfArgs(..) => "H0013",
+            Self::SymbolNotFound(..) => "H0014",
+            Self::InvalidCharacterSpan(..) => "H0015",
+            Self::UnknownUnicodeBlock(..) => "H0016",
+            Self::UnknownUnicodeCategory(..) => "H0017",
+            Self::UnknownUnicodeProperty(..) => "H0018",
+            Self::UnsupportedUnicodeVersion(..) => "H0019",
+            Self::UnsupportedNonPlane0InCharacterClass(..) => "H0020",
+            Self::InvalidCodePoint(..) => "H0021",
+            Self::UnicodeInByteGrammar(..) => "H0022",
+            Self::ByteInTextGrammar(..) => "H0023",
+            Self::OverridingPreviousTerminal(..) => "H0024",
+            Self::PrecedenceRedefined(..) => "H0025",
+            Self::GrammarNotDefined(..) => "H0026",
+            Self::LrConflict(..) => "H0027",
+            Self::TerminalOutsideContext(..) => "H0028",
+            Self::TerminalCannotBeMatched(..) => "H0029",
+            Self::TerminalMatchesEmpty(..) => "H0030",
+            Self::ConflictResolved(..) => "H0031",
+            Self::UnreachableVariable(..) => "H0032",
+            Self::UnproductiveVariable(..) => "H0033",
+            Self::UnusedTerminal(..) => "H0034",
+            Self::UnusedFragment(..) => "H0035",
+            Self::EmptyContext(..) => "H0036",
+            Self::DuplicateRuleBody(..) => "H0037",
+            Self::ShadowedTerminal(..) => "H0038",
+        }
+    }
+
+    /// Gets the name of this error if it is only a warning, see `WARNING_NAMES`
+    #[must_use]
+    pub fn get_warning_name(&self) -> Option<&'static str> {
+        match self {
+            Self::OverridingPreviousTerminal(..) => Some(WARNING_NAMES[0]),
+            Self::ConflictResolved(..) => Some(WARNING_NAMES[1]),
+            Self::UnreachableVariable(..) => Some(WARNING_NAMES[2]),
+            Self::UnproductiveVariable(..) => Some(WARNING_NAMES[3]),
+            Self::UnusedTerminal(..) => Some(WARNING_NAMES[4]),
+            Self::UnusedFragment(..) => Some(WARNING_NAMES[5]),
+            Self::EmptyContext(..) => Some(WARNING_NAMES[6]),
+            Self::DuplicateRuleBody(..) => Some(WARNING_NAMES[7]),
+            Self::ShadowedTerminal(..) => Some(WARNING_NAMES[8]),
+            _ => None,
+        }
+    }
+
+    /// Gets whether this error is only a warning
+    #[must_use]
+    pub fn is_warning(&self) -> bool {
+        self.get_warning_name().is_some()
+    }
+
+    /// Formats the message for a warning that does not require the context
+    fn fmt_warning(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnreachableVariable(_input, name) => {
+                write!(f, "Variable `{name}` is not reachable from the axiom")
+            }
+            Self::UnproductiveVariable(_input, name) => write!(
+                f,
+                "Variable `{name}` cannot produce any finite sequence of terminals"
+            ),
+            Self::UnusedTerminal(_input, name) => {
+                write!(f, "Terminal `{name}` is never used in the rules")
+            }
+            Self::UnusedFragment(_input, name) => write!(f, "Fragment `{name}` is never used"),
+            Self::EmptyContext(_input, name) => {
+                write!(f, "Lexical context `{name}` has no terminal")
+            }
+            Self::DuplicateRuleBody(_input, name, _previous) => {
+                write!(f, "Duplicate rule for variable `{name}`")
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Transform into this error into one with its context
     #[must_use]
     pub fn with_context<'context, 'error, 't>(
@@ -286,6 +504,12 @@ impl<'context, 'error, 't> Display for ContextualizedError<'context, 'error, 't>
             Error::UnknownUnicodeCategory(_input, name) => {
                 write!(f, "Unknown unicode category `{name}`")
             }
+            Error::UnknownUnicodeProperty(_input, name) => {
+                write!(f, "Unknown unicode script or property `{name}`")
+            }
+            Error::UnsupportedUnicodeVersion(_input, version, _supported) => {
+                write!(f, "Unsupported unicode version `{version}`")
+            }
             Error::UnsupportedNonPlane0InCharacterClass(_input, c) => write!(
                 f,
                 "Unsupported non-plane 0 Unicode character {} (U+{:04X}) in character class",
@@ -296,22 +520,38 @@ impl<'context, 'error, 't> Display for ContextualizedError<'context, 'error, 't>
                 f,
                 "The value U+{c:0X} is not a supported unicode code point"
             ),
+            Error::UnicodeInByteGrammar(_input) => write!(
+                f,
+                "Unicode characters and classes cannot be used when the input is made of bytes"
+            ),
+            Error::ByteInTextGrammar(_input) => write!(
+                f,
+                "Byte values can only be used when the input is made of bytes (option Input = \"bytes\")"
+            ),
             Error::OverridingPreviousTerminal(_input, name, _previous) => {
                 write!(f, "Overriding the previous definition of `{name}`")
             }
+            Error::PrecedenceRedefined(_input, name, _previous) => {
+                write!(f, "The precedence of `{name}` is already declared")
+            }
             Error::GrammarNotDefined(_input, name) => {
                 write!(f, "Grammar `{name}` is not defined")
             }
             Error::LrConflict(grammar_index, conflict) => {
                 let grammar = &self.context.grammars[*grammar_index];
-                let terminal = grammar.get_symbol_value(conflict.lookahead.terminal.into());
+                let terminal = conflict.lookahead.get_value(grammar);
                 write!(
                     f,
-                    "{} conflict, cannot decide what to do facing `{}`",
+                    "{} conflict{}, cannot decide what to do facing `{}`",
                     match conflict.kind {
                         ConflictKind::ShiftReduce => "Shift/Reduce",
                         ConflictKind::ReduceReduce => "Reduce/Reduce",
                     },
+                    if conflict.lalr_merge.is_some() {
+                        " introduced by the LALR(1) merge of LR(1) states"
+                    } else {
+                        ""
+                    },
                     terminal
                 )
             }
@@ -333,6 +573,25 @@ impl<'context, 'error, 't> Display for ContextualizedError<'context, 'error, 't>
                     &terminal.name
                 )
             }
+            Error::ConflictResolved(grammar_index, resolved) => {
+                let grammar = &self.context.grammars[*grammar_index];
+                write!(
+                    f,
+                    "Conflict resolved by the precedence declarations, {}",
+                    resolved.get_description(grammar)
+                )
+            }
+            Error::ShadowedTerminal(grammar_index, error) => {
+                let terminal = self.context.grammars[*grammar_index]
+                    .get_terminal(error.terminal.sid())
+                    .unwrap();
+                write!(
+                    f,
+                    "Terminal `{}` is never used and can never be matched",
+                    &terminal.name
+                )
+            }
+            _ => self.error.fmt_warning(f),
         }
     }
 }
@@ -25,7 +25,7 @@ use miette::{
 
 use super::{ContextualizedError, Error};
 use crate::grammars::{OPTION_AXIOM, OPTION_SEPARATOR};
-use crate::lr::LookaheadOrigin;
+use crate::lr::{ConflictKind, LookaheadOrigin};
 use crate::{InputReference, LoadedInput};
 
 /// The content for a miette span
@@ -166,7 +166,15 @@ impl<'context, 'error, 't> ContextualizedError<'context, 'error, 't> {
 
 impl<'context, 'error, 't> Diagnostic for ContextualizedError<'context, 'error, 't> {
     fn severity(&self) -> Option<Severity> {
-        Some(Severity::Error)
+        if self.error.is_warning() {
+            Some(Severity::Warning)
+        } else {
+            Some(Severity::Error)
+        }
+    }
+
+    fn code<'s>(&'s self) -> Option<Box<dyn std::fmt::Display + 's>> {
+        Some(Box::new(self.error.get_code()))
     }
 
     #[allow(clippy::match_same_arms)]
@@ -209,11 +217,21 @@ impl<'context, 'error, 't> Diagnostic for ContextualizedError<'context, 'error,
             Error::UnknownUnicodeCategory(input, _name) => {
                 Some(&self.context.inputs[input.input_index])
             }
+            Error::UnknownUnicodeProperty(input, _name) => {
+                Some(&self.context.inputs[input.input_index])
+            }
+            Error::UnsupportedUnicodeVersion(input, _version, _supported) => {
+                Some(&self.context.inputs[input.input_index])
+            }
             Error::UnsupportedNonPlane0InCharacterClass(input, _c) => {
                 Some(&self.context.inputs[input.input_index])
             }
             Error::InvalidCodePoint(input, _c) => Some(&self.context.inputs[input.input_index]),
-            Error::OverridingPreviousTerminal(input, _name, _previous) => {
+            Error::UnicodeInByteGrammar(input) | Error::ByteInTextGrammar(input) => {
+                Some(&self.context.inputs[input.input_index])
+            }
+            Error::OverridingPreviousTerminal(input, _name, _previous)
+            | Error::PrecedenceRedefined(input, _name, _previous) => {
                 Some(&self.context.inputs[input.input_index])
             }
             Error::GrammarNotDefined(input, _name) => Some(&self.context.inputs[input.input_index]),
@@ -229,6 +247,18 @@ impl<'context, 'error, 't> Diagnostic for ContextualizedError<'context, 'error,
             Error::TerminalMatchesEmpty(grammar_index, _terminal_ref) => {
                 Some(self.get_source_code_for_grammar(*grammar_index))
             }
+            Error::UnreachableVariable(input, _name)
+            | Error::UnproductiveVariable(input, _name)
+            | Error::UnusedTerminal(input, _name)
+            | Error::UnusedFragment(input, _name)
+            | Error::EmptyContext(input, _name)
+            | Error::DuplicateRuleBody(input, _name, _) => {
+                Some(&self.context.inputs[input.input_index])
+            }
+            Error::ConflictResolved(grammar_index, _)
+            | Error::ShadowedTerminal(grammar_index, _) => {
+                Some(self.get_source_code_for_grammar(*grammar_index))
+            }
         }
     }
 
@@ -241,10 +271,11 @@ impl<'context, 'error, 't> Diagnostic for ContextualizedError<'context, 'error,
             Error::GrammarNotFound(_) => Some(self.get_single_label_no_input()),
             Error::Parsing(input, _) => Some(self.get_single_label_with_input(input)),
             Error::InvalidOption(grammar_index, name, _valid) => {
-                let option = self.context.grammars[*grammar_index]
-                    .get_option(name)
-                    .unwrap();
-                Some(self.get_single_label_with_input(&option.value_input_ref))
+                // the option may also be given outside of the grammar, e.g. the target runtime
+                match self.context.grammars[*grammar_index].get_option(name) {
+                    Some(option) => Some(self.get_single_label_with_input(&option.value_input_ref)),
+                    None => Some(self.get_single_label_with_grammar(*grammar_index)),
+                }
             }
             Error::AxiomNotSpecified(grammar_index) => {
                 Some(self.get_single_label_with_grammar(*grammar_index))
@@ -295,10 +326,19 @@ impl<'context, 'error, 't> Diagnostic for ContextualizedError<'context, 'error,
             Error::UnknownUnicodeCategory(input, _name) => {
                 Some(self.get_single_label_with_input(input))
             }
+            Error::UnknownUnicodeProperty(input, _name) => {
+                Some(self.get_single_label_with_input(input))
+            }
+            Error::UnsupportedUnicodeVersion(input, _version, _supported) => {
+                Some(self.get_single_label_with_input(input))
+            }
             Error::UnsupportedNonPlane0InCharacterClass(input, _c) => {
                 Some(self.get_single_label_with_input(input))
             }
             Error::InvalidCodePoint(input, _c) => Some(self.get_single_label_with_input(input)),
+            Error::UnicodeInByteGrammar(input) | Error::ByteInTextGrammar(input) => {
+                Some(self.get_single_label_with_input(input))
+            }
             Error::OverridingPreviousTerminal(input, name, previous) => Some(Box::new(
                 vec![
                     self.label_for_input(input),
@@ -309,6 +349,16 @@ impl<'context, 'error, 't> Diagnostic for ContextualizedError<'context, 'error,
                 ]
                 .into_iter(),
             )),
+            Error::PrecedenceRedefined(input, name, previous) => Some(Box::new(
+                vec![
+                    self.label_for_input(input),
+                    self.label_for_input_with_text(
+                        previous,
+                        format!("previous declaration of {name}"),
+                    ),
+                ]
+                .into_iter(),
+            )),
             Error::GrammarNotDefined(input, _name) => Some(self.get_single_label_with_input(input)),
             Error::LrConflict(grammar_index, conflict) => {
                 let grammar = &self.context.grammars[*grammar_index];
@@ -316,7 +366,7 @@ impl<'context, 'error, 't> Diagnostic for ContextualizedError<'context, 'error,
                 for item in &conflict.shift_items {
                     let rule = item.rule.get_rule_in(grammar);
                     let choice = &rule.body.choices[0];
-                    let value = grammar.get_symbol_value(conflict.lookahead.terminal.into());
+                    let value = conflict.lookahead.get_value(grammar);
                     let input_ref = choice.elements[item.position].input_ref.unwrap();
                     labels.push(self.label_for_input_with_text(
                         &input_ref,
@@ -327,7 +377,7 @@ impl<'context, 'error, 't> Diagnostic for ContextualizedError<'context, 'error,
                     let rule = item.rule.get_rule_in(grammar);
                     let choice = &rule.body.choices[0];
                     let lookahead = item.lookaheads.get(conflict.lookahead.terminal).unwrap();
-                    let value = grammar.get_symbol_value(conflict.lookahead.terminal.into());
+                    let value = conflict.lookahead.get_value(grammar);
                     if choice.elements.is_empty() {
                         // do not display this choice
                     } else if item.position >= choice.elements.len() {
@@ -377,7 +427,8 @@ impl<'context, 'error, 't> Diagnostic for ContextualizedError<'context, 'error,
                 }
                 Some(Box::new(labels.into_iter()))
             }
-            Error::TerminalCannotBeMatched(grammar_index, error) => {
+            Error::TerminalCannotBeMatched(grammar_index, error)
+            | Error::ShadowedTerminal(grammar_index, error) => {
                 let grammar = &self.context.grammars[*grammar_index];
                 let separator = grammar.get_terminal(error.terminal.sid()).unwrap();
                 let mut labels = vec![self.label_for_input(&separator.input_ref)];
@@ -397,6 +448,31 @@ impl<'context, 'error, 't> Diagnostic for ContextualizedError<'context, 'error,
                     .input_ref;
                 Some(self.get_single_label_with_input(input))
             }
+            Error::UnreachableVariable(input, _name)
+            | Error::UnproductiveVariable(input, _name)
+            | Error::UnusedTerminal(input, _name)
+            | Error::UnusedFragment(input, _name)
+            | Error::EmptyContext(input, _name) => Some(self.get_single_label_with_input(input)),
+            Error::ConflictResolved(grammar_index, resolved) => {
+                let level = &self.context.grammars[*grammar_index].precedences[resolved.level];
+                Some(Box::new(
+                    vec![self.label_for_input_with_text(
+                        &level.input_ref,
+                        String::from("Precedence level that resolved the conflict"),
+                    )]
+                    .into_iter(),
+                ))
+            }
+            Error::DuplicateRuleBody(input, _name, previous) => Some(Box::new(
+                vec![
+                    self.label_for_input(input),
+                    self.label_for_input_with_text(
+                        previous,
+                        String::from("previous rule with the same body"),
+                    ),
+                ]
+                .into_iter(),
+            )),
         }
     }
 
@@ -409,11 +485,40 @@ impl<'context, 'error, 't> Diagnostic for ContextualizedError<'context, 'error,
                     Some(Box::new(format!("expected one of: {}", valid.join(", "))))
                 }
             }
+            Error::UnsupportedUnicodeVersion(_input, _version, supported) => Some(Box::new(
+                format!("supported versions: {}", supported.join(", ")),
+            )),
             Error::LrConflict(grammar_index, conflict) => {
-                if conflict.phrases.is_empty() {
+                let grammar = &self.context.grammars[*grammar_index];
+                if let Some(merge) = &conflict.lalr_merge {
+                    Some(Box::new(
+                        merge.get_description(grammar, &conflict.lookahead),
+                    ))
+                } else if let Some(counterexample) = &conflict.counterexample {
+                    let (first, second) = match conflict.kind {
+                        ConflictKind::ShiftReduce => ("Shift", "Reduce"),
+                        ConflictKind::ReduceReduce => ("First reduce", "Second reduce"),
+                    };
+                    let text = if counterexample.unifying {
+                        format!(
+                            "Example of input that is ambiguous: {}\n{first} derivation: {}\n{second} derivation: {}",
+                            counterexample.first.get_example(grammar),
+                            counterexample.first.get_description(grammar),
+                            counterexample.second.get_description(grammar)
+                        )
+                    } else {
+                        format!(
+                            "No ambiguous input was found, the conflict may come from the limited lookahead\n{first} example: {}\n{first} derivation: {}\n{second} example: {}\n{second} derivation: {}",
+                            counterexample.first.get_example(grammar),
+                            counterexample.first.get_description(grammar),
+                            counterexample.second.get_example(grammar),
+                            counterexample.second.get_description(grammar)
+                        )
+                    };
+                    Some(Box::new(text))
+                } else if conflict.phrases.is_empty() {
                     None
                 } else {
-                    let grammar = &self.context.grammars[*grammar_index];
                     Some(Box::new(format!(
                         "Example of input that is ambiguous: {}",
                         conflict.phrases[0]
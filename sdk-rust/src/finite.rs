@@ -21,6 +21,7 @@ use std::cmp::Ordering;
 use std::collections::HashMap;
 
 use crate::grammars::{TerminalRef, TerminalSet};
+use crate::unicode::{CodePoint, Span, UnicodeDatabase};
 use crate::{CharSpan, CHARSPAN_INVALID};
 
 /// Represents the value epsilon on NFA transitions
@@ -835,18 +836,19 @@ impl NFAState {
             let (starts, ends) = bound.count_starts_ends();
 
             // end all ongoing ranges
-            for &(_tid, next) in &current_nexts {
-                transitions.push(NFATransition {
-                    value: CharSpan::new(
-                        current_start,
-                        if starts == 0 {
-                            bound.value
-                        } else {
-                            bound.value - 1
-                        },
-                    ),
-                    next,
-                });
+            // the range may be empty when a previous bound ended right before this one
+            let current_end = if starts == 0 {
+                Some(bound.value)
+            } else {
+                bound.value.checked_sub(1)
+            };
+            if let Some(current_end) = current_end.filter(|&end| current_start <= end) {
+                for &(_tid, next) in &current_nexts {
+                    transitions.push(NFATransition {
+                        value: CharSpan::new(current_start, current_end),
+                        next,
+                    });
+                }
             }
             let ongoings = current_nexts
                 .iter()
@@ -1212,6 +1214,39 @@ mod tests_nfa_normalize {
         )
     }
 
+    #[test]
+    fn test_overlap_adjacent_bounds_other() {
+        let mut state = NFAState::new(0);
+        state.add_transition(CharSpan::new(0, 20), 1);
+        let mut map = vec![
+            NFATransitionBound {
+                value: 10,
+                effects: vec![NFATransitionBoundEffect::OtherEnd],
+            },
+            NFATransitionBound {
+                value: 11,
+                effects: vec![NFATransitionBoundEffect::OtherStart],
+            },
+        ];
+        state.fill_bounds_map(&mut map);
+        let bounds = map.iter().map(|b| b.value).collect::<Vec<_>>();
+        assert_eq!(bounds, vec![0, 10, 11, 20]);
+        state.normalize(&map);
+        assert_eq!(
+            state.transitions,
+            vec![
+                NFATransition {
+                    next: 1,
+                    value: CharSpan::new(0, 10)
+                },
+                NFATransition {
+                    next: 1,
+                    value: CharSpan::new(11, 20)
+                },
+            ]
+        );
+    }
+
     #[test]
     fn test_overlap_start_end_bound_other_2() {
         let mut state = NFAState::new(0);
@@ -1247,6 +1282,11 @@ mod tests_nfa_normalize {
     }
 }
 
+/// Decodes the code point encoded by a UTF-16 surrogate pair
+fn decode_surrogates(high: u16, low: u16) -> u32 {
+    0x10000 + ((u32::from(high) - 0xD800) << 10) + (u32::from(low) - 0xDC00)
+}
+
 /// Represents a Non-deterministic Finite Automaton
 #[derive(Debug, Clone)]
 pub struct NFA {
@@ -1405,6 +1445,104 @@ impl NFA {
         nfa
     }
 
+    /// Creates an automaton that also matches the other case of the ASCII letters matched by this one
+    /// This is used when the input is made of bytes.
+    #[must_use]
+    pub fn into_ascii_case_insensitive(self) -> NFA {
+        let mut result = self;
+        for origin in 0..result.states.len() {
+            let transitions = result.states[origin].transitions.clone();
+            for transition in transitions {
+                let span = transition.value;
+                if span == EPSILON {
+                    continue;
+                }
+                for (begin, end, other) in [(b'a', b'z', b'A'), (b'A', b'Z', b'a')] {
+                    let first = span.begin.max(u16::from(begin));
+                    let last = span.end.min(u16::from(end));
+                    if first <= last {
+                        let shift = u16::from(other);
+                        let base = u16::from(begin);
+                        result.add_transition(
+                            origin,
+                            CharSpan::new(first - base + shift, last - base + shift),
+                            transition.next,
+                        );
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Creates an automaton that also matches the case-equivalents of the characters matched by this one,
+    /// according to the simple case folding of the specified version of the Unicode Character Database
+    #[must_use]
+    pub fn into_case_insensitive(self, database: &UnicodeDatabase) -> NFA {
+        let mut result = self;
+        for origin in 0..result.states.len() {
+            let transitions = result.states[origin].transitions.clone();
+            for transition in transitions {
+                let span = transition.value;
+                if span == EPSILON {
+                    continue;
+                }
+                if span.begin >= 0xD800 && span.end <= 0xDBFF {
+                    // high surrogates, the characters are completed by the following low surrogates
+                    let lows = result.states[transition.next].transitions.clone();
+                    for low in lows
+                        .iter()
+                        .filter(|low| low.value != EPSILON && low.value.begin >= 0xDC00)
+                    {
+                        let begin = decode_surrogates(span.begin, low.value.begin);
+                        let end = decode_surrogates(span.end, low.value.end.min(0xDFFF));
+                        for &(value, equivalent) in
+                            database.get_case_equivalents(Span::new(begin, end))
+                        {
+                            let [high, low_value] = CodePoint::new(value).get_utf16();
+                            if span.begin <= high
+                                && high <= span.end
+                                && low.value.begin <= low_value
+                                && low_value <= low.value.end
+                            {
+                                result.add_code_point_path(origin, equivalent, low.next);
+                            }
+                        }
+                    }
+                } else {
+                    // skip the surrogate encoding points
+                    for (begin, end) in [
+                        (span.begin, span.end.min(0xD7FF)),
+                        (span.begin.max(0xE000), span.end),
+                    ] {
+                        if begin > end {
+                            continue;
+                        }
+                        for &(_, equivalent) in database
+                            .get_case_equivalents(Span::new(u32::from(begin), u32::from(end)))
+                        {
+                            result.add_code_point_path(origin, equivalent, transition.next);
+                        }
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Adds a path matching the UTF-16 encoding of a code point between two states
+    fn add_code_point_path(&mut self, from: usize, value: u32, to: usize) {
+        let code_point = CodePoint::new(value);
+        let [first, second] = code_point.get_utf16();
+        if code_point.is_plane0() {
+            self.add_transition(from, CharSpan::new(first, first), to);
+        } else {
+            let intermediate = self.add_state().id;
+            self.add_transition(from, CharSpan::new(first, first), intermediate);
+            self.add_transition(intermediate, CharSpan::new(second, second), to);
+        }
+    }
+
     /// Adds entry and exit states
     fn add_entry_exit(&mut self) -> (usize, usize) {
         self.entry = self.add_state().id;
@@ -22,12 +22,15 @@ use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
 
+use hime_redist::lexers::indentation::{IndentationConfig, DEFAULT_BRACKETS, DEFAULT_TAB_WIDTH};
+use hime_redist::lexers::LexerModeAction;
 use hime_redist::parsers::{TreeAction, TREE_ACTION_DROP, TREE_ACTION_NONE, TREE_ACTION_PROMOTE};
 
 use crate::errors::{Error, UnmatchableTokenError};
 use crate::finite::{FinalItem, DFA, EPSILON, NFA};
-use crate::lr::Graph;
+use crate::lr::{Graph, ResolvedConflict};
 use crate::sdk::InMemoryParser;
+use crate::unicode::{get_database, UnicodeDatabase, DATABASES};
 use crate::{InputReference, ParsingMethod};
 
 /// Represents a symbol in a grammar
@@ -53,6 +56,7 @@ pub struct TerminalReference {
 
 /// Represents a terminal symbol in a grammar
 #[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct Terminal {
     /// The unique indentifier (within a grammar) of this symbol
     pub id: usize,
@@ -70,6 +74,12 @@ pub struct Terminal {
     pub is_anonymous: bool,
     /// Whether the terminal is a fragment
     pub is_fragment: bool,
+    /// Whether the terminal is matched by an external scanner instead of the lexer's automaton
+    pub is_external: bool,
+    /// Whether the terminal is synthesized by the lexer, e.g. for indentation-sensitive lexing
+    pub is_synthesized: bool,
+    /// The action on the lexer's stack of contexts when this terminal is matched
+    pub mode_action: LexerModeAction,
     /// The references to this terminal by others
     pub terminal_references: Vec<TerminalReference>,
 }
@@ -100,7 +110,15 @@ impl Symbol for Terminal {
         } else {
             format!(
                 "Terminal {}`{}`",
-                if self.is_fragment { "(fragment) " } else { "" },
+                if self.is_fragment {
+                    "(fragment) "
+                } else if self.is_external {
+                    "(external) "
+                } else if self.is_synthesized {
+                    "(synthesized) "
+                } else {
+                    ""
+                },
                 self.name
             )
         }
@@ -344,6 +362,8 @@ pub struct Variable {
     pub generated_for: Option<usize>,
     /// The rules for this variable
     pub rules: Vec<Rule>,
+    /// The rules that were not added because they duplicate an existing one
+    pub duplicate_rules: Vec<Rule>,
     /// The FIRSTS set for this variable
     pub firsts: TerminalSet,
     /// The FOLLOWERS set for this variable
@@ -359,6 +379,7 @@ impl Variable {
             name,
             generated_for,
             rules: Vec::new(),
+            duplicate_rules: Vec::new(),
             firsts: TerminalSet::default(),
             followers: TerminalSet::default(),
         }
@@ -366,7 +387,9 @@ impl Variable {
 
     /// Adds the given rule for this variable as a unique element
     pub fn add_rule(&mut self, rule: Rule) {
-        if !self.rules.contains(&rule) {
+        if self.rules.contains(&rule) {
+            self.duplicate_rules.push(rule);
+        } else {
             self.rules.push(rule);
         }
     }
@@ -564,6 +587,7 @@ impl RuleChoice {
             elements,
             firsts: TerminalSet::default(),
             choices: Vec::new(),
+            precedence: None,
         }
     }
 
@@ -695,6 +719,8 @@ pub struct RuleBody {
     pub firsts: TerminalSet,
     /// The choices in this body
     pub choices: Vec<RuleChoice>,
+    /// The terminal whose precedence overrides the one of the rule (`%prec`), if any
+    pub precedence: Option<usize>,
 }
 
 impl RuleBodyTrait for RuleBody {
@@ -710,6 +736,7 @@ impl RuleBodyTrait for RuleBody {
             elements,
             firsts: TerminalSet::default(),
             choices: Vec::new(),
+            precedence: left.precedence.or(right.precedence),
         }
     }
 
@@ -728,6 +755,18 @@ impl RuleBody {
             elements: Vec::new(),
             firsts: TerminalSet::default(),
             choices: Vec::new(),
+            precedence: None,
+        }
+    }
+
+    /// Initializes an empty rule body that overrides the precedence with the one of a terminal
+    #[must_use]
+    pub fn precedence(terminal: usize) -> RuleBody {
+        RuleBody {
+            elements: Vec::new(),
+            firsts: TerminalSet::default(),
+            choices: Vec::new(),
+            precedence: Some(terminal),
         }
     }
 
@@ -742,6 +781,7 @@ impl RuleBody {
             )],
             firsts: TerminalSet::default(),
             choices: Vec::new(),
+            precedence: None,
         }
     }
 
@@ -752,6 +792,7 @@ impl RuleBody {
             elements,
             firsts: TerminalSet::default(),
             choices: Vec::new(),
+            precedence: None,
         }
     }
 
@@ -1050,6 +1091,8 @@ impl Eq for TemplateRuleInstance {}
 pub struct TemplateRuleBody {
     /// The elements in the rule's body
     pub elements: Vec<TemplateRuleElement>,
+    /// The terminal whose precedence overrides the one of the rule (`%prec`), if any
+    pub precedence: Option<usize>,
 }
 
 impl RuleBodyTrait for TemplateRuleBody {
@@ -1061,7 +1104,10 @@ impl RuleBodyTrait for TemplateRuleBody {
         for element in &right.elements {
             elements.push(element.clone());
         }
-        TemplateRuleBody { elements }
+        TemplateRuleBody {
+            elements,
+            precedence: left.precedence.or(right.precedence),
+        }
     }
 
     fn apply_action(&mut self, action: TreeAction) {
@@ -1077,6 +1123,16 @@ impl TemplateRuleBody {
     pub fn empty() -> TemplateRuleBody {
         TemplateRuleBody {
             elements: Vec::new(),
+            precedence: None,
+        }
+    }
+
+    /// Initializes an empty rule body that overrides the precedence with the one of a terminal
+    #[must_use]
+    pub fn precedence(terminal: usize) -> TemplateRuleBody {
+        TemplateRuleBody {
+            elements: Vec::new(),
+            precedence: Some(terminal),
         }
     }
 
@@ -1089,6 +1145,7 @@ impl TemplateRuleBody {
                 TREE_ACTION_NONE,
                 input_ref,
             )],
+            precedence: None,
         }
     }
 }
@@ -1161,6 +1218,7 @@ pub const PREFIX_GENERATED_VARIABLE: &str = "__V";
 /// The name of the generated axiom variable
 pub const GENERATED_AXIOM: &str = "__VAxiom";
 /// Name of the grammar option specifying the grammar's axiom variable
+/// A comma-separated list of variables specifies multiple axioms, the first one being the default.
 pub const OPTION_AXIOM: &str = "Axiom";
 /// Name of the grammar option specifying the grammar's separator terminal
 pub const OPTION_SEPARATOR: &str = "Separator";
@@ -1168,6 +1226,9 @@ pub const OPTION_SEPARATOR: &str = "Separator";
 pub const OPTION_OUTPUT_PATH: &str = "OutputPath";
 /// The parser type to generate, defaults to LALR1
 pub const OPTION_METHOD: &str = "Method";
+/// The number of tokens of lookahead for the parser, defaults to 1
+/// More than one token of lookahead is only supported by the LR(1), LALR(1) and IELR(1) methods.
+pub const OPTION_LOOKAHEAD: &str = "Lookahead";
 /// The runtime to target, defaults to Net
 pub const OPTION_RUNTIME: &str = "Runtime";
 /// The compilation mode, defaults to Sources
@@ -1176,6 +1237,35 @@ pub const OPTION_MODE: &str = "Mode";
 pub const OPTION_NAMESPACE: &str = "Namespace";
 /// The access mode for the generated code, defaults to Internal
 pub const OPTION_ACCESS_MODIFIER: &str = "Modifier";
+/// Whether the terminals are case-sensitive, defaults to true
+pub const OPTION_CASE_SENSITIVE: &str = "CaseSensitive";
+/// The lexical contexts in which the terminals are case-insensitive, as a comma-separated list
+/// (`default` denotes the default context)
+pub const OPTION_CASE_INSENSITIVE_CONTEXTS: &str = "CaseInsensitiveContexts";
+/// The version of the Unicode Character Database against which the Unicode classes are resolved,
+/// defaults to the latest supported version
+pub const OPTION_UNICODE_VERSION: &str = "UnicodeVersion";
+/// Whether the terminals are defined over characters (`text`) or bytes (`bytes`), defaults to `text`
+pub const OPTION_INPUT: &str = "Input";
+/// Whether the lexer is indentation-sensitive, defaults to false
+pub const OPTION_INDENTATION: &str = "Indentation";
+/// The width of a tab for indentation-sensitive lexing, defaults to 8
+pub const OPTION_INDENTATION_TAB_WIDTH: &str = "IndentationTabWidth";
+/// Whether blank lines produce a `NEWLINE` token (`newline`) or not (`ignore`), defaults to `ignore`
+pub const OPTION_INDENTATION_BLANK_LINES: &str = "IndentationBlankLines";
+/// The pairs of brackets within which indentation is ignored, defaults to `()[]{}`
+pub const OPTION_INDENTATION_BRACKETS: &str = "IndentationBrackets";
+/// The languages injected in the values of terminals,
+/// as a comma-separated list of `TERMINAL: Grammar` or `TERMINAL: Grammar.axiom` (Rust runtime only)
+/// The generated code refers to the parser of an injected grammar as `super::<grammar>`,
+/// so that the injected grammar must be generated as a sibling module named after it in snake case.
+pub const OPTION_INJECT: &str = "Inject";
+/// The name of the terminal synthesized when the indentation increases
+pub const TERMINAL_INDENT: &str = "INDENT";
+/// The name of the terminal synthesized when the indentation decreases
+pub const TERMINAL_DEDENT: &str = "DEDENT";
+/// The name of the terminal synthesized at the end of a logical line
+pub const TERMINAL_NEWLINE: &str = "NEWLINE";
 /// The name of the default lexical context
 pub const DEFAULT_CONTEXT_NAME: &str = "__default";
 
@@ -1222,6 +1312,86 @@ pub struct Grammar {
     pub actions: Vec<Action>,
     /// The template rules
     pub template_rules: Vec<TemplateRule>,
+    /// The precedence levels for terminals, from the lowest to the highest
+    pub precedences: Vec<PrecedenceLevel>,
+}
+
+/// The associativity of the terminals in a precedence level
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Associativity {
+    /// Conflicts between the same level are resolved by reducing
+    Left,
+    /// Conflicts between the same level are resolved by shifting
+    Right,
+    /// Conflicts between the same level are resolved as syntax errors
+    None,
+}
+
+impl Display for Associativity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Associativity::Left => write!(f, "left"),
+            Associativity::Right => write!(f, "right"),
+            Associativity::None => write!(f, "nonassoc"),
+        }
+    }
+}
+
+/// A level of precedence for terminals, as declared in the precedence block of a grammar
+#[derive(Debug, Clone)]
+pub struct PrecedenceLevel {
+    /// The associativity of the terminals in this level
+    pub associativity: Associativity,
+    /// The identifiers of the terminals in this level
+    pub terminals: Vec<usize>,
+    /// The input reference for the declaration of this level
+    pub input_ref: InputReference,
+}
+
+/// The settings for indentation-sensitive lexing
+#[derive(Debug, Clone)]
+pub struct IndentationSettings {
+    /// The identifier of the `INDENT` terminal
+    pub indent: usize,
+    /// The identifier of the `DEDENT` terminal
+    pub dedent: usize,
+    /// The identifier of the `NEWLINE` terminal
+    pub newline: usize,
+    /// The width of a tab
+    pub tab_width: usize,
+    /// Whether blank lines produce a `NEWLINE` token
+    pub blank_lines: bool,
+    /// The pairs of brackets within which indentation is ignored
+    pub brackets: String,
+}
+
+/// A language injected in the values of the tokens for a terminal
+/// The value of each token is parsed with the parser for the injected grammar
+/// and the resulting tree is grafted onto the token's node.
+#[derive(Debug, Clone)]
+pub struct Injection {
+    /// The identifier of the terminal
+    pub terminal: usize,
+    /// The name of the injected grammar
+    pub grammar: String,
+    /// The axiom of the injected grammar to parse the values as, if not the default one
+    pub axiom: Option<String>,
+}
+
+impl IndentationSettings {
+    /// Gets the corresponding configuration for the runtime
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_config(&self) -> IndentationConfig<'_> {
+        IndentationConfig {
+            indent_id: self.indent as u32,
+            dedent_id: self.dedent as u32,
+            newline_id: self.newline as u32,
+            tab_width: self.tab_width,
+            blank_lines: self.blank_lines,
+            brackets: &self.brackets,
+        }
+    }
 }
 
 /// Represents the build data for a grammar
@@ -1233,10 +1403,18 @@ pub struct BuildData {
     pub expected: TerminalSet,
     /// The separator terminal
     pub separator: Option<TerminalRef>,
+    /// The settings for indentation-sensitive lexing, if any
+    pub indentation: Option<IndentationSettings>,
+    /// The languages injected in the values of terminals
+    pub injections: Vec<Injection>,
     /// The parsing method
     pub method: ParsingMethod,
+    /// The number of tokens of lookahead for the parser
+    pub lookahead: usize,
     /// The LR graph
     pub graph: Graph,
+    /// The conflicts resolved by the precedence declarations
+    pub resolved: Vec<ResolvedConflict>,
 }
 
 impl Grammar {
@@ -1254,6 +1432,7 @@ impl Grammar {
             virtuals: Vec::new(),
             actions: Vec::new(),
             template_rules: Vec::new(),
+            precedences: Vec::new(),
         }
     }
 
@@ -1288,6 +1467,81 @@ impl Grammar {
         self.options.get(name)
     }
 
+    /// Gets whether the terminals in the specified lexical context are case-insensitive
+    #[must_use]
+    pub fn is_case_insensitive(&self, context: &str) -> bool {
+        if self
+            .get_option(OPTION_CASE_SENSITIVE)
+            .is_some_and(|option| option.value == "false")
+        {
+            return true;
+        }
+        self.get_option(OPTION_CASE_INSENSITIVE_CONTEXTS)
+            .is_some_and(|option| {
+                option.value.split(',').map(str::trim).any(|name| {
+                    name == context || (name == "default" && context == DEFAULT_CONTEXT_NAME)
+                })
+            })
+    }
+
+    /// Gets whether the terminals of this grammar are defined over bytes, for binary inputs
+    #[must_use]
+    pub fn is_byte_grammar(&self) -> bool {
+        self.get_option(OPTION_INPUT)
+            .is_some_and(|option| option.value == "bytes")
+    }
+
+    /// Gets the version of the Unicode Character Database against which the Unicode classes are resolved
+    /// This is the latest supported version when the grammar does not pin a supported one.
+    #[must_use]
+    pub fn get_unicode_database(&self) -> &'static UnicodeDatabase {
+        self.get_option(OPTION_UNICODE_VERSION)
+            .and_then(|option| get_database(Some(option.value.trim())))
+            .unwrap_or(&DATABASES[0])
+    }
+
+    /// Checks that the version of the Unicode Character Database pinned by this grammar, if any, is supported
+    #[must_use]
+    pub fn check_unicode_version(&self) -> Option<Error> {
+        let option = self.get_option(OPTION_UNICODE_VERSION)?;
+        if get_database(Some(option.value.trim())).is_some() {
+            return None;
+        }
+        Some(Error::UnsupportedUnicodeVersion(
+            option.value_input_ref,
+            option.value.clone(),
+            DATABASES
+                .iter()
+                .map(|database| database.version.to_string())
+                .collect(),
+        ))
+    }
+
+    /// Adds the terminals synthesized by the lexer when it is indentation-sensitive
+    /// The terminals that are already defined are left untouched.
+    pub fn add_indentation_terminals(&mut self) {
+        let Some(option) = self.options.get(OPTION_INDENTATION) else {
+            return;
+        };
+        if option.value != "true" {
+            return;
+        }
+        let input_ref = option.name_input_ref;
+        for name in [TERMINAL_INDENT, TERMINAL_DEDENT, TERMINAL_NEWLINE] {
+            if self.get_terminal_for_name(name).is_none() {
+                // the NFA never reaches its exit, the terminal is produced by the lexer itself
+                let terminal = self.add_terminal_named(
+                    name.to_string(),
+                    input_ref,
+                    NFA::new_minimal(),
+                    DEFAULT_CONTEXT_NAME,
+                    false,
+                );
+                terminal.is_synthesized = true;
+            }
+        }
+    }
+
     /// Gets the symbol with the given name in this grammar
     #[must_use]
     pub fn get_symbol(&self, name: &str) -> Option<SymbolRef> {
@@ -1373,6 +1627,29 @@ impl Grammar {
         }
     }
 
+    /// Gets whether this grammar has terminals matched by an external scanner
+    #[must_use]
+    pub fn has_external_terminals(&self) -> bool {
+        self.terminals.iter().any(|terminal| terminal.is_external)
+    }
+
+    /// Gets whether this grammar has terminals that push or pop lexer modes
+    #[must_use]
+    pub fn has_lexer_modes(&self) -> bool {
+        self.terminals
+            .iter()
+            .any(|terminal| terminal.mode_action != LexerModeAction::None)
+    }
+
+    /// Gets whether the specified context is used as a lexer mode,
+    /// i.e. it is pushed by a terminal onto the lexer's stack of contexts
+    #[must_use]
+    pub fn is_lexer_mode(&self, context: usize) -> bool {
+        self.terminals
+            .iter()
+            .any(|terminal| terminal.mode_action == LexerModeAction::Push(context as u16))
+    }
+
     /// Adds the given anonymous terminal to this grammar
     pub fn add_terminal_anonymous(
         &mut self,
@@ -1424,6 +1701,9 @@ impl Grammar {
             context,
             is_anonymous,
             is_fragment,
+            is_external: false,
+            is_synthesized: false,
+            mode_action: LexerModeAction::None,
             terminal_references: Vec::new(),
         };
         self.terminals.push(terminal);
@@ -1447,6 +1727,34 @@ impl Grammar {
         self.terminals.iter().find(|t| t.name == name)
     }
 
+    /// Gets the index of the precedence level of a terminal, if any
+    #[must_use]
+    pub fn get_terminal_precedence(&self, terminal: TerminalRef) -> Option<usize> {
+        let TerminalRef::Terminal(id) = terminal else {
+            return None;
+        };
+        self.precedences
+            .iter()
+            .position(|level| level.terminals.contains(&id))
+    }
+
+    /// Gets the index of the precedence level of a rule, if any
+    /// This is the level of the terminal given by `%prec`, or else of the last terminal in the rule.
+    #[must_use]
+    pub fn get_rule_precedence(&self, rule: &Rule) -> Option<usize> {
+        let terminal = rule.body.precedence.or_else(|| {
+            rule.body
+                .elements
+                .iter()
+                .rev()
+                .find_map(|element| match element.symbol {
+                    SymbolRef::Terminal(id) => Some(id),
+                    _ => None,
+                })
+        })?;
+        self.get_terminal_precedence(TerminalRef::Terminal(terminal))
+    }
+
     /// Gets the terminal with the given name
     #[must_use]
     pub fn get_terminal_for_value(&self, value: &str) -> Option<&Terminal> {
@@ -1701,7 +2009,9 @@ impl Grammar {
                         input_ref: Some(element.input_ref),
                     });
                 }
-                bodies.push(RuleBody::from_parts(elements));
+                let mut rule_body = RuleBody::from_parts(elements);
+                rule_body.precedence = body.precedence;
+                bodies.push(rule_body);
             }
             let head = {
                 let variable = self.add_variable(&name);
@@ -1730,6 +2040,7 @@ impl Grammar {
         self.inherit_actions(other);
         self.inherit_rules(other);
         self.inherit_template_rules(other);
+        self.inherit_precedences(other);
         self.next_sid += other.next_sid - 3;
     }
 
@@ -1751,6 +2062,12 @@ impl Grammar {
                 // not already defined in this grammar
                 let sid = self.next_sid + terminal.id - 3;
                 let context = self.resolve_context(&other.contexts[terminal.context]);
+                let mode_action = match terminal.mode_action {
+                    LexerModeAction::Push(pushed) => LexerModeAction::Push(
+                        self.resolve_context(&other.contexts[pushed as usize]) as u16,
+                    ),
+                    mode_action => mode_action,
+                };
                 let mut nfa = terminal.nfa.clone_no_finals();
                 nfa.states[nfa.exit]
                     .items
@@ -1764,7 +2081,17 @@ impl Grammar {
                     context,
                     is_fragment: terminal.is_fragment,
                     is_anonymous: terminal.is_anonymous,
-                    terminal_references: Vec::new(),
+                    is_external: terminal.is_external,
+                    is_synthesized: terminal.is_synthesized,
+                    mode_action,
+                    terminal_references: terminal
+                        .terminal_references
+                        .iter()
+                        .map(|reference| TerminalReference {
+                            referring_id: self.next_sid + reference.referring_id - 3,
+                            input_ref: reference.input_ref,
+                        })
+                        .collect(),
                 });
             }
         }
@@ -1784,6 +2111,32 @@ impl Grammar {
         }
     }
 
+    /// Inherits the precedence levels from the parent grammar
+    /// The inherited levels are lower than the ones in this grammar.
+    fn inherit_precedences(&mut self, other: &Grammar) {
+        let mut levels = Vec::with_capacity(other.precedences.len() + self.precedences.len());
+        for level in &other.precedences {
+            let terminals: Vec<usize> = level
+                .terminals
+                .iter()
+                .map(|terminal| self.map_terminal(other, *terminal))
+                .filter(|terminal| {
+                    self.get_terminal_precedence(TerminalRef::Terminal(*terminal))
+                        .is_none()
+                })
+                .collect();
+            if !terminals.is_empty() {
+                levels.push(PrecedenceLevel {
+                    associativity: level.associativity,
+                    terminals,
+                    input_ref: level.input_ref,
+                });
+            }
+        }
+        levels.append(&mut self.precedences);
+        self.precedences = levels;
+    }
+
     /// Inherits the variables from the parent grammar
     fn inherit_variables(&mut self, other: &Grammar) {
         for symbol in &other.variables {
@@ -1822,13 +2175,12 @@ impl Grammar {
                             )
                         })
                         .collect();
-                    Rule::new(
-                        head,
-                        rule.head_action,
-                        rule.head_input_ref,
-                        RuleBody::from_parts(elements),
-                        context,
-                    )
+                    let mut body = RuleBody::from_parts(elements);
+                    body.precedence = rule
+                        .body
+                        .precedence
+                        .map(|terminal| self.map_terminal(other, terminal));
+                    Rule::new(head, rule.head_action, rule.head_input_ref, body, context)
                 })
                 .collect();
             let head = self
@@ -1927,13 +2279,25 @@ impl Grammar {
                         input_ref: element.input_ref,
                     });
                 }
-                self.template_rules[index]
-                    .bodies
-                    .push(TemplateRuleBody { elements });
+                let precedence = body
+                    .precedence
+                    .map(|terminal| self.map_terminal(other, terminal));
+                self.template_rules[index].bodies.push(TemplateRuleBody {
+                    elements,
+                    precedence,
+                });
             }
         }
     }
 
+    /// Maps the identifier of a terminal from a grammar to this one
+    fn map_terminal(&self, other: &Grammar, terminal: usize) -> usize {
+        match self.map_symbol_ref(other, SymbolRef::Terminal(terminal)) {
+            SymbolRef::Terminal(id) => id,
+            _ => terminal,
+        }
+    }
+
     /// Maps a symbol from a grammar to this one
     fn map_symbol_ref(&self, other: &Grammar, symbol: SymbolRef) -> SymbolRef {
         match symbol {
@@ -2012,33 +2376,63 @@ impl Grammar {
     }
 
     /// Adds the real axiom to this grammar
+    /// The real axiom has a rule for each of the axioms specified in the options, in the same order
     fn add_real_axiom(&mut self, grammar_index: usize) -> Result<(), Error> {
         let axiom_option = self
             .options
             .get(OPTION_AXIOM)
             .ok_or(Error::AxiomNotSpecified(grammar_index))?;
-        let axiom_id = self
-            .variables
-            .iter()
-            .find(|v| v.name == axiom_option.value)
-            .ok_or(Error::AxiomNotDefined(grammar_index))?
-            .id;
+        let mut axiom_ids = Vec::new();
+        for name in axiom_option.value.split(',').map(str::trim) {
+            let axiom_id = self
+                .variables
+                .iter()
+                .find(|v| v.name == name)
+                .ok_or(Error::AxiomNotDefined(grammar_index))?
+                .id;
+            if !axiom_ids.contains(&axiom_id) {
+                axiom_ids.push(axiom_id);
+            }
+        }
         let input_ref = axiom_option.value_input_ref;
-        // Create the real axiom rule variable and rule
+        // Create the real axiom rule variable and rules
         let real_axiom = self.add_variable(GENERATED_AXIOM);
-        real_axiom.rules.push(Rule::new(
-            real_axiom.id,
-            TREE_ACTION_NONE,
-            input_ref,
-            RuleBody::from_parts(vec![
-                RuleBodyElement::new(SymbolRef::Variable(axiom_id), TREE_ACTION_PROMOTE, None),
-                RuleBodyElement::new(SymbolRef::Dollar, TREE_ACTION_DROP, None),
-            ]),
-            0,
-        ));
+        for axiom_id in axiom_ids {
+            real_axiom.rules.push(Rule::new(
+                real_axiom.id,
+                TREE_ACTION_NONE,
+                input_ref,
+                RuleBody::from_parts(vec![
+                    RuleBodyElement::new(SymbolRef::Variable(axiom_id), TREE_ACTION_PROMOTE, None),
+                    RuleBodyElement::new(SymbolRef::Dollar, TREE_ACTION_DROP, None),
+                ]),
+                0,
+            ));
+        }
         Ok(())
     }
 
+    /// Gets the axioms of this grammar, in the order of their entry states in the parser's automaton
+    /// The grammar must have been prepared, see `Grammar::prepare`.
+    #[must_use]
+    pub fn get_axioms(&self) -> Vec<&Variable> {
+        self.get_variable_for_name(GENERATED_AXIOM)
+            .map(|real_axiom| {
+                real_axiom
+                    .rules
+                    .iter()
+                    .filter_map(|rule| match rule.body.elements.first() {
+                        Some(RuleBodyElement {
+                            symbol: SymbolRef::Variable(sid),
+                            ..
+                        }) => self.get_variable(*sid),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Computes the FIRSTS sets for this grammar
     fn compute_firsts(&mut self) {
         let mut firsts_for_var = HashMap::new();
@@ -2081,11 +2475,15 @@ impl Grammar {
     pub fn build(
         &mut self,
         parsing_method: Option<ParsingMethod>,
+        lookahead: Option<usize>,
         grammar_index: usize,
     ) -> Result<BuildData, Vec<Error>> {
         if let Err(error) = self.prepare(grammar_index) {
             return Err(vec![error]);
         };
+        if let Err(error) = self.check_input(grammar_index) {
+            return Err(vec![error]);
+        }
         // Build DFA
         let dfa = self.build_dfa();
         // Check that no terminal match the empty string
@@ -2097,26 +2495,66 @@ impl Grammar {
                 .collect());
         }
         // Build the data for the lexer
-        let expected = dfa.get_expected();
+        let mut expected = dfa.get_expected();
+        for terminal in self
+            .terminals
+            .iter()
+            .filter(|t| t.is_external || t.is_synthesized)
+        {
+            // external and synthesized terminals are not matched by the DFA
+            expected.add(TerminalRef::Terminal(terminal.id));
+        }
+        expected.sort();
         let separator = match self.get_separator(grammar_index, &expected, &dfa) {
             Ok(separator) => separator,
             Err(error) => return Err(vec![error]),
         };
+        let indentation = match self.get_indentation(grammar_index) {
+            Ok(indentation) => indentation,
+            Err(error) => return Err(vec![error]),
+        };
+        let injections = match self.get_injections(grammar_index) {
+            Ok(injections) => injections,
+            Err(error) => return Err(vec![error]),
+        };
         let method = match self.get_parsing_method(parsing_method, grammar_index) {
             Ok(method) => method,
             Err(error) => return Err(vec![error]),
         };
+        let lookahead = match self.get_lookahead(lookahead, method, grammar_index) {
+            Ok(lookahead) => lookahead,
+            Err(error) => return Err(vec![error]),
+        };
         // Build the data for the parser
-        let graph = crate::lr::build_graph(self, grammar_index, &expected, &dfa, method)?;
+        let (graph, resolved) =
+            crate::lr::build_graph(self, grammar_index, &expected, &dfa, method, lookahead)?;
         Ok(BuildData {
             dfa,
             expected,
             separator,
+            indentation,
+            injections,
             method,
+            lookahead,
             graph,
+            resolved,
         })
     }
 
+    /// Checks the kind of input for the grammar
+    fn check_input(&self, grammar_index: usize) -> Result<(), Error> {
+        match self.get_option(OPTION_INPUT) {
+            Some(option) if option.value != "text" && option.value != "bytes" => {
+                Err(Error::InvalidOption(
+                    grammar_index,
+                    OPTION_INPUT.to_string(),
+                    vec![String::from("text"), String::from("bytes")],
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Gets the separator for the grammar
     fn get_separator(
         &self,
@@ -2150,6 +2588,146 @@ impl Grammar {
         ))
     }
 
+    /// Gets the settings for indentation-sensitive lexing, if activated
+    fn get_indentation(&self, grammar_index: usize) -> Result<Option<IndentationSettings>, Error> {
+        match self.get_option(OPTION_INDENTATION) {
+            None => return Ok(None),
+            Some(option) => match option.value.as_ref() {
+                "true" => {}
+                "false" => return Ok(None),
+                _ => {
+                    return Err(Error::InvalidOption(
+                        grammar_index,
+                        OPTION_INDENTATION.to_string(),
+                        vec![String::from("true"), String::from("false")],
+                    ))
+                }
+            },
+        }
+        let tab_width = match self.get_option(OPTION_INDENTATION_TAB_WIDTH) {
+            None => DEFAULT_TAB_WIDTH,
+            Some(option) => match option.value.parse::<usize>() {
+                Ok(width) if width > 0 => width,
+                _ => {
+                    return Err(Error::InvalidOption(
+                        grammar_index,
+                        OPTION_INDENTATION_TAB_WIDTH.to_string(),
+                        vec![String::from("a positive integer")],
+                    ))
+                }
+            },
+        };
+        let blank_lines = match self.get_option(OPTION_INDENTATION_BLANK_LINES) {
+            None => false,
+            Some(option) => match option.value.as_ref() {
+                "ignore" => false,
+                "newline" => true,
+                _ => {
+                    return Err(Error::InvalidOption(
+                        grammar_index,
+                        OPTION_INDENTATION_BLANK_LINES.to_string(),
+                        vec![String::from("ignore"), String::from("newline")],
+                    ))
+                }
+            },
+        };
+        let brackets = match self.get_option(OPTION_INDENTATION_BRACKETS) {
+            None => DEFAULT_BRACKETS.to_string(),
+            Some(option) if option.value.chars().count() % 2 == 0 => option.value.clone(),
+            Some(_) => {
+                return Err(Error::InvalidOption(
+                    grammar_index,
+                    OPTION_INDENTATION_BRACKETS.to_string(),
+                    vec![String::from("pairs of opening and closing characters")],
+                ))
+            }
+        };
+        let get_id = |name: &str| self.get_terminal_for_name(name).map_or(0, |t| t.id);
+        Ok(Some(IndentationSettings {
+            indent: get_id(TERMINAL_INDENT),
+            dedent: get_id(TERMINAL_DEDENT),
+            newline: get_id(TERMINAL_NEWLINE),
+            tab_width,
+            blank_lines,
+            brackets,
+        }))
+    }
+
+    /// Checks that the languages injected in the values of terminals are defined by the specified grammars
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when an injected grammar or axiom is not defined
+    pub fn check_injections(
+        &self,
+        grammar_index: usize,
+        grammars: &[Grammar],
+    ) -> Result<(), Error> {
+        for injection in self.get_injections(grammar_index)? {
+            let Some(injected) = grammars.iter().find(|g| g.name == injection.grammar) else {
+                return Err(Error::GrammarNotFound(injection.grammar));
+            };
+            let Some(axiom) = injection.axiom else {
+                continue;
+            };
+            // the axioms of the injected grammar are checked when building it
+            let Some(option) = injected.get_option(OPTION_AXIOM) else {
+                continue;
+            };
+            let axioms: Vec<&str> = option.value.split(',').map(str::trim).collect();
+            if !axioms.contains(&axiom.as_str()) {
+                return Err(Error::InvalidOption(
+                    grammar_index,
+                    OPTION_INJECT.to_string(),
+                    axioms
+                        .iter()
+                        .map(|axiom| format!("{}.{axiom}", injected.name))
+                        .collect(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets the languages injected in the values of terminals
+    fn get_injections(&self, grammar_index: usize) -> Result<Vec<Injection>, Error> {
+        let Some(option) = self.get_option(OPTION_INJECT) else {
+            return Ok(Vec::new());
+        };
+        let invalid = || {
+            Error::InvalidOption(
+                grammar_index,
+                OPTION_INJECT.to_string(),
+                vec![
+                    String::from("TERMINAL: Grammar"),
+                    String::from("TERMINAL: Grammar.axiom"),
+                ],
+            )
+        };
+        let mut injections = Vec::new();
+        for item in option.value.split(',') {
+            let Some((terminal, target)) = item.split_once(':') else {
+                return Err(invalid());
+            };
+            let Some(terminal) = self.get_terminal_for_name(terminal.trim()) else {
+                return Err(invalid());
+            };
+            let (grammar, axiom) = match target.trim().split_once('.') {
+                Some((grammar, axiom)) => (grammar.trim(), Some(axiom.trim().to_string())),
+                None => (target.trim(), None),
+            };
+            if grammar.is_empty() || axiom.as_ref().is_some_and(String::is_empty) {
+                return Err(invalid());
+            }
+            injections.push(Injection {
+                terminal: terminal.id,
+                grammar: grammar.to_string(),
+                axiom,
+            });
+        }
+        Ok(injections)
+    }
+
     /// Gets the parsing method
     fn get_parsing_method(
         &self,
@@ -2163,8 +2741,11 @@ impl Grammar {
                     "lr0" => Ok(ParsingMethod::LR0),
                     "lr1" => Ok(ParsingMethod::LR1),
                     "lalr1" => Ok(ParsingMethod::LALR1),
+                    "ielr1" => Ok(ParsingMethod::IELR1),
                     "rnglr1" => Ok(ParsingMethod::RNGLR1),
                     "rnglalr1" => Ok(ParsingMethod::RNGLALR1),
+                    "hlr1" => Ok(ParsingMethod::HLR1),
+                    "hlalr1" => Ok(ParsingMethod::HLALR1),
                     _ => Err(Error::InvalidOption(
                         grammar_index,
                         OPTION_METHOD.to_string(),
@@ -2172,8 +2753,11 @@ impl Grammar {
                             String::from("lr0"),
                             String::from("lr1"),
                             String::from("lalr1"),
+                            String::from("ielr1"),
                             String::from("rnglr1"),
                             String::from("rnglalr1"),
+                            String::from("hlr1"),
+                            String::from("hlalr1"),
                         ],
                     )),
                 },
@@ -2182,13 +2766,47 @@ impl Grammar {
         }
     }
 
+    /// Gets the number of tokens of lookahead for the parser
+    fn get_lookahead(
+        &self,
+        lookahead: Option<usize>,
+        method: ParsingMethod,
+        grammar_index: usize,
+    ) -> Result<usize, Error> {
+        let lookahead = match lookahead {
+            Some(lookahead) => lookahead,
+            None => match self.get_option(OPTION_LOOKAHEAD) {
+                None => 1,
+                Some(option) => match option.value.parse::<usize>() {
+                    Ok(lookahead) if lookahead > 0 => lookahead,
+                    _ => {
+                        return Err(Error::InvalidOption(
+                            grammar_index,
+                            OPTION_LOOKAHEAD.to_string(),
+                            vec![String::from("a positive integer")],
+                        ))
+                    }
+                },
+            },
+        };
+        match method {
+            ParsingMethod::LR1 | ParsingMethod::LALR1 | ParsingMethod::IELR1 => Ok(lookahead),
+            _ if lookahead == 1 => Ok(lookahead),
+            _ => Err(Error::InvalidOption(
+                grammar_index,
+                OPTION_LOOKAHEAD.to_string(),
+                vec![String::from("1")],
+            )),
+        }
+    }
+
     /// Builds the in-memory parser for a grammar
     ///
     /// # Errors
     ///
     /// Returns the errors produced by the grammar's compilation
     pub fn get_in_memory<'a>(&'a self, data: &BuildData) -> Result<InMemoryParser<'a>, Vec<Error>> {
-        crate::output::build_in_memory_grammar(self, data)
+        crate::output::build_in_memory_grammar(self, data, false)
     }
 }
 
@@ -21,9 +21,11 @@
 #![warn(clippy::pedantic)]
 #![allow(clippy::cast_possible_truncation, clippy::module_name_repetitions)]
 
+pub mod ambiguity;
 pub mod errors;
 pub mod finite;
 pub mod grammars;
+pub mod lints;
 pub mod loaders;
 pub mod lr;
 pub mod output;
@@ -41,8 +43,8 @@ use hime_redist::text::{Text, TextPosition};
 
 use crate::errors::{Error, Errors};
 use crate::grammars::{
-    Grammar, OPTION_ACCESS_MODIFIER, OPTION_MODE, OPTION_NAMESPACE, OPTION_OUTPUT_PATH,
-    OPTION_RUNTIME,
+    Grammar, GENERATED_AXIOM, OPTION_ACCESS_MODIFIER, OPTION_MODE, OPTION_NAMESPACE,
+    OPTION_OUTPUT_PATH, OPTION_RUNTIME,
 };
 use crate::sdk::InMemoryParser;
 
@@ -297,6 +299,8 @@ pub struct LoadedData<'t> {
     pub inputs: Vec<LoadedInput<'t>>,
     /// The loaded grammars
     pub grammars: Vec<Grammar>,
+    /// The warnings produced by the lint pass on the grammars
+    pub warnings: Vec<Error>,
 }
 
 /// Transforms into an owned static version of the data
@@ -308,6 +312,7 @@ fn loaded_data_into_static(data: LoadedData<'_>) -> LoadedData<'static> {
             .map(loaded_input_into_static)
             .collect(),
         grammars: data.grammars,
+        warnings: data.warnings,
     }
 }
 
@@ -382,10 +387,20 @@ pub enum ParsingMethod {
     LR1,
     /// The LALR(1) parsing method
     LALR1,
+    /// The minimal LR(1) parsing method, with LALR(1)-sized tables and the power of LR(1)
+    IELR1,
     /// The RNGLR parsing method based on a LR(1) graph
     RNGLR1,
     /// The RNGLR parsing method based on a LALR(1) graph
     RNGLALR1,
+    /// The hybrid LR/GLR parsing method based on a LR(1) graph
+    /// The parser is deterministic and only runs the RNGLR algorithm for conflicting cells.
+    /// The .NET and Java runtimes use the RNGLR parser instead.
+    HLR1,
+    /// The hybrid LR/GLR parsing method based on a LALR(1) graph
+    /// The parser is deterministic and only runs the RNGLR algorithm for conflicting cells.
+    /// The .NET and Java runtimes use the RNGLR parser instead.
+    HLALR1,
 }
 
 impl ParsingMethod {
@@ -399,10 +414,22 @@ impl ParsingMethod {
     #[must_use]
     pub fn is_rnglr(self) -> bool {
         match self {
-            ParsingMethod::LR0 | ParsingMethod::LR1 | ParsingMethod::LALR1 => false,
-            ParsingMethod::RNGLR1 | ParsingMethod::RNGLALR1 => true,
+            ParsingMethod::LR0
+            | ParsingMethod::LR1
+            | ParsingMethod::LALR1
+            | ParsingMethod::IELR1 => false,
+            ParsingMethod::RNGLR1
+            | ParsingMethod::RNGLALR1
+            | ParsingMethod::HLR1
+            | ParsingMethod::HLALR1 => true,
         }
     }
+
+    /// Gets whether this method is the hybrid LR/GLR method
+    #[must_use]
+    pub fn is_hybrid(self) -> bool {
+        matches!(self, ParsingMethod::HLR1 | ParsingMethod::HLALR1)
+    }
 }
 
 /// Represents a grammar's compilation mode
@@ -468,8 +495,18 @@ pub struct CompilationTask<'a> {
     pub output_modifier: Option<Modifier>,
     /// The parsing method use
     pub method: Option<ParsingMethod>,
+    /// The number of tokens of lookahead for the parser
+    pub lookahead: Option<usize>,
     /// Whether to print debug data when building a grammar
     pub print_debug_data: Option<bool>,
+    /// Whether to print the conflicts resolved by the precedence declarations
+    pub print_resolved_conflicts: Option<bool>,
+    /// Whether to analyze the grammars for ambiguities and print the result
+    pub print_ambiguities: Option<bool>,
+    /// Whether to treat the warnings as errors
+    pub deny_warnings: Option<bool>,
+    /// The names of the warnings that are not reported, see `errors::WARNING_NAMES`
+    pub allowed_warnings: Vec<String>,
     /// Java-only, the path to the local maven repository to use
     pub java_maven_repository: Option<String>,
     /// Rust-only, indicates whether standard library exclusive features are enabled
@@ -479,6 +516,8 @@ pub struct CompilationTask<'a> {
     pub rust_suppress_module_doc: Option<bool>,
     /// Rust-only, indicates whether to compress automata binary files
     pub rust_compress_automata: Option<bool>,
+    /// Rust-only, indicates whether the lexer consumes Unicode scalar values (`char`) instead of UTF-16 code units
+    pub rust_char_lexer: Option<bool>,
 }
 
 impl<'a> CompilationTask<'a> {
@@ -616,6 +655,12 @@ impl<'a> CompilationTask<'a> {
         self.rust_compress_automata.unwrap_or(false)
     }
 
+    /// Rust-only, gets whether the lexer consumes Unicode scalar values (`char`) instead of UTF-16 code units
+    #[must_use]
+    pub fn get_rust_char_lexer(&self) -> bool {
+        self.rust_char_lexer.unwrap_or(false)
+    }
+
     /// Executes this task
     ///
     /// # Errors
@@ -634,10 +679,50 @@ impl<'a> CompilationTask<'a> {
                 }
             }
         }
-        let all_data = match self.execute_build_grammars(&mut data.grammars) {
+        let built = self.execute_build_grammars(&mut data.grammars);
+        let mut warnings = std::mem::take(&mut data.warnings);
+        for (index, grammar) in data.grammars.iter().enumerate() {
+            warnings.append(&mut lints::lint(grammar, index));
+        }
+        if let Ok(all_data) = &built {
+            for (index, build) in all_data.iter().enumerate() {
+                warnings.extend(
+                    build
+                        .resolved
+                        .iter()
+                        .map(|resolved| Error::ConflictResolved(index, Box::new(resolved.clone()))),
+                );
+            }
+        }
+        warnings.retain(|warning| self.is_reported(warning));
+        if self.print_ambiguities.unwrap_or_default() {
+            // the analysis also explains the conflicts of the grammars that failed to build
+            for grammar in &data.grammars {
+                if grammar.get_variable_for_name(GENERATED_AXIOM).is_none() {
+                    // the grammar could not be prepared for the analysis
+                    continue;
+                }
+                println!("================ {}, ambiguities", &grammar.name);
+                let ambiguities = ambiguity::find_ambiguities(grammar);
+                if ambiguities.is_empty() {
+                    println!("No ambiguity found");
+                }
+                for ambiguity in &ambiguities {
+                    println!("{}", ambiguity.get_description(grammar));
+                }
+            }
+        }
+        let all_data = match built {
             Ok(d) => d,
-            Err(errors) => return Err(Errors::from(data, errors)),
+            Err(mut errors) => {
+                errors.append(&mut warnings);
+                return Err(Errors::from(data, errors));
+            }
         };
+        if self.deny_warnings.unwrap_or_default() && !warnings.is_empty() {
+            return Err(Errors::from(data, warnings));
+        }
+        data.warnings = warnings;
         if self.print_debug_data.unwrap_or_default() {
             for (grammar, data) in data.grammars.iter().zip(all_data.iter()) {
                 println!("================ {}", &grammar.name);
@@ -650,6 +735,21 @@ impl<'a> CompilationTask<'a> {
                 println!("{graph}");
             }
         }
+        if self.print_resolved_conflicts.unwrap_or_default() {
+            for (grammar, build) in data.grammars.iter().zip(all_data.iter()) {
+                println!("================ {}, resolved conflicts", &grammar.name);
+                for resolved in &build.resolved {
+                    let input_ref = &grammar.precedences[resolved.level].input_ref;
+                    println!(
+                        "{} ({}:{}:{})",
+                        resolved.get_description(grammar),
+                        &data.inputs[input_ref.input_index].name,
+                        input_ref.position.line,
+                        input_ref.position.column
+                    );
+                }
+            }
+        }
         if let Err(errors) = self.execute_grammar_artifacts(&data.grammars, &all_data) {
             return Err(Errors::from(data, errors));
         }
@@ -672,7 +772,23 @@ impl<'a> CompilationTask<'a> {
     /// Outputs all the errors obtained while loading the inputs, if any
     pub fn load(&self) -> Result<LoadedData<'a>, Errors<'a>> {
         let inputs = loaders::open_all(&self.inputs)?;
-        loaders::load(inputs)
+        match loaders::load(inputs) {
+            Ok(mut data) => {
+                data.warnings.retain(|warning| self.is_reported(warning));
+                Ok(data)
+            }
+            Err(mut errors) => {
+                errors.errors.retain(|error| self.is_reported(error));
+                Err(errors)
+            }
+        }
+    }
+
+    /// Gets whether an error or warning shall be reported, i.e. it is not an allowed warning
+    fn is_reported(&self, error: &Error) -> bool {
+        error
+            .get_warning_name()
+            .is_none_or(|name| !self.allowed_warnings.iter().any(|allowed| allowed == name))
     }
 
     /// Generates the in-memory parser for a grammar
@@ -685,8 +801,8 @@ impl<'a> CompilationTask<'a> {
         grammar: &'g mut Grammar,
         grammar_index: usize,
     ) -> Result<InMemoryParser<'g>, Vec<Error>> {
-        let data = grammar.build(self.method, grammar_index)?;
-        output::build_in_memory_grammar(grammar, &data)
+        let data = grammar.build(self.method, self.lookahead, grammar_index)?;
+        output::build_in_memory_grammar(grammar, &data, self.get_rust_char_lexer())
     }
 
     /// Build the specified grammars
@@ -698,7 +814,7 @@ impl<'a> CompilationTask<'a> {
         let mut results = Vec::new();
         // prepare the grammars
         for (index, grammar) in grammars.iter_mut().enumerate() {
-            match grammar.build(self.method, index) {
+            match grammar.build(self.method, self.lookahead, index) {
                 Ok(data) => {
                     results.push(data);
                 }
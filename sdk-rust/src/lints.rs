@@ -0,0 +1,226 @@
+/*******************************************************************************
+ * Copyright (c) 2020 Association Cénotélie (cenotelie.fr)
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Lesser General Public License as
+ * published by the Free Software Foundation, either version 3
+ * of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU Lesser General Public License for more details.
+ *
+ * You should have received a copy of the GNU Lesser General
+ * Public License along with this program.
+ * If not, see <http://www.gnu.org/licenses/>.
+ ******************************************************************************/
+
+//! Module for the lint pass on grammars, producing warnings
+
+use hime_redist::lexers::LexerModeAction;
+
+use crate::errors::{Error, UnmatchableTokenError};
+use crate::grammars::{
+    Grammar, Rule, SymbolRef, TerminalRef, GENERATED_AXIOM, OPTION_AXIOM, OPTION_SEPARATOR,
+    PREFIX_GENERATED_VARIABLE,
+};
+
+/// Runs the lint pass on a grammar and gets the warnings
+/// The grammar may be prepared or not.
+#[must_use]
+pub fn lint(grammar: &Grammar, grammar_index: usize) -> Vec<Error> {
+    let mut warnings = Vec::new();
+    lint_variables(grammar, &mut warnings);
+    lint_terminals(grammar, grammar_index, &mut warnings);
+    lint_contexts(grammar, &mut warnings);
+    lint_duplicate_rules(grammar, &mut warnings);
+    warnings
+}
+
+/// Gets whether a variable was written in the grammar, as opposed to generated
+fn is_user_variable(name: &str) -> bool {
+    !name.starts_with(PREFIX_GENERATED_VARIABLE)
+}
+
+/// Finds the variables that are not reachable from the axioms, or that are unproductive
+fn lint_variables(grammar: &Grammar, warnings: &mut Vec<Error>) {
+    // the variables reachable from the axioms
+    let mut reachable: Vec<usize> = match grammar.get_variable_for_name(GENERATED_AXIOM) {
+        Some(axiom) => vec![axiom.id],
+        None => grammar
+            .get_option(OPTION_AXIOM)
+            .map(|option| {
+                option
+                    .value
+                    .split(',')
+                    .filter_map(|name| grammar.get_variable_for_name(name.trim()))
+                    .map(|variable| variable.id)
+                    .collect()
+            })
+            .unwrap_or_default(),
+    };
+    let mut next = 0;
+    while next < reachable.len() {
+        let variable = grammar.get_variable(reachable[next]).unwrap();
+        for rule in &variable.rules {
+            for element in &rule.body.elements {
+                if let SymbolRef::Variable(id) = element.symbol {
+                    if !reachable.contains(&id) {
+                        reachable.push(id);
+                    }
+                }
+            }
+        }
+        next += 1;
+    }
+    // the variables that produce a finite sequence of terminals
+    let mut productive: Vec<usize> = Vec::new();
+    let mut modified = true;
+    while modified {
+        modified = false;
+        for variable in &grammar.variables {
+            if productive.contains(&variable.id) {
+                continue;
+            }
+            let is_productive = variable.rules.iter().any(|rule| {
+                rule.body
+                    .elements
+                    .iter()
+                    .all(|element| match element.symbol {
+                        SymbolRef::Variable(id) => productive.contains(&id),
+                        _ => true,
+                    })
+            });
+            if is_productive {
+                productive.push(variable.id);
+                modified = true;
+            }
+        }
+    }
+    for variable in &grammar.variables {
+        if !is_user_variable(&variable.name) || variable.rules.is_empty() {
+            continue;
+        }
+        let input_ref = variable.rules[0].head_input_ref;
+        if !reachable.contains(&variable.id) {
+            warnings.push(Error::UnreachableVariable(input_ref, variable.name.clone()));
+        }
+        if !productive.contains(&variable.id) {
+            warnings.push(Error::UnproductiveVariable(
+                input_ref,
+                variable.name.clone(),
+            ));
+        }
+    }
+}
+
+/// Finds the terminals and fragments that are never used, or that can never be matched
+fn lint_terminals(grammar: &Grammar, grammar_index: usize, warnings: &mut Vec<Error>) {
+    let separator = grammar
+        .get_option(OPTION_SEPARATOR)
+        .map(|option| &option.value);
+    let dfa = grammar.build_dfa();
+    let expected = dfa.get_expected();
+    for terminal in &grammar.terminals {
+        if terminal.is_fragment {
+            let is_used = !terminal.terminal_references.is_empty()
+                || grammar
+                    .precedences
+                    .iter()
+                    .any(|level| level.terminals.contains(&terminal.id))
+                || grammar.variables.iter().any(|variable| {
+                    variable
+                        .rules
+                        .iter()
+                        .any(|rule| rule.body.precedence == Some(terminal.id))
+                });
+            if !is_used {
+                warnings.push(Error::UnusedFragment(
+                    terminal.input_ref,
+                    terminal.name.clone(),
+                ));
+            }
+            continue;
+        }
+        if terminal.is_anonymous || terminal.is_synthesized || separator == Some(&terminal.name) {
+            continue;
+        }
+        let symbol = SymbolRef::Terminal(terminal.id);
+        let is_used = grammar.variables.iter().any(|variable| {
+            variable.rules.iter().any(|rule| {
+                rule.body
+                    .elements
+                    .iter()
+                    .any(|element| element.symbol == symbol)
+            })
+        });
+        if is_used {
+            // the terminals used in rules that cannot be matched are errors
+            continue;
+        }
+        let terminal_ref = TerminalRef::Terminal(terminal.id);
+        if terminal.is_external || expected.content.contains(&terminal_ref) {
+            warnings.push(Error::UnusedTerminal(
+                terminal.input_ref,
+                terminal.name.clone(),
+            ));
+        } else {
+            warnings.push(Error::ShadowedTerminal(
+                grammar_index,
+                UnmatchableTokenError {
+                    terminal: terminal_ref,
+                    overriders: dfa.get_overriders(terminal_ref, terminal.context),
+                },
+            ));
+        }
+    }
+}
+
+/// Finds the lexical contexts without terminals
+fn lint_contexts(grammar: &Grammar, warnings: &mut Vec<Error>) {
+    for (context, name) in grammar.contexts.iter().enumerate().skip(1) {
+        if grammar.terminals.iter().any(|t| t.context == context) {
+            continue;
+        }
+        // locate a use of the context
+        let input_ref = grammar
+            .variables
+            .iter()
+            .flat_map(|variable| variable.rules.iter())
+            .find(|rule| rule.context == context)
+            .map(|rule| rule.head_input_ref)
+            .or_else(|| {
+                grammar
+                    .terminals
+                    .iter()
+                    .find(|t| t.mode_action == LexerModeAction::Push(context as u16))
+                    .map(|t| t.input_ref)
+            })
+            .unwrap_or(grammar.input_ref);
+        warnings.push(Error::EmptyContext(input_ref, name.clone()));
+    }
+}
+
+/// Finds the rules that were dropped because they have the same body as a previous rule
+fn lint_duplicate_rules(grammar: &Grammar, warnings: &mut Vec<Error>) {
+    let locate = |rule: &Rule| {
+        rule.body
+            .elements
+            .first()
+            .and_then(|element| element.input_ref)
+            .unwrap_or(rule.head_input_ref)
+    };
+    for variable in &grammar.variables {
+        if !is_user_variable(&variable.name) {
+            continue;
+        }
+        for rule in &variable.duplicate_rules {
+            let previous = variable.rules.iter().find(|previous| *previous == rule);
+            warnings.push(Error::DuplicateRuleBody(
+                locate(rule),
+                variable.name.clone(),
+                locate(previous.unwrap_or(rule)),
+            ));
+        }
+    }
+}
@@ -7,8 +7,12 @@ use std::io::Read;
 use hime_redist::ast::{AstImpl, AstNode};
 use hime_redist::errors::ParseErrors;
 use hime_redist::lexers::automaton::Automaton;
-use hime_redist::lexers::impls::ContextFreeLexer;
+use hime_redist::lexers::filter::FilteredSource;
+use hime_redist::lexers::impls::ContextSensitiveLexer;
 use hime_redist::lexers::Lexer;
+use hime_redist::lexers::ParseHooks;
+use hime_redist::lexers::TokenSource;
+use hime_redist::lexers::UserContextProvider;
 use hime_redist::parsers::lrk::{LRkAutomaton, LRkParser};
 use hime_redist::parsers::Parser;
 use hime_redist::result::{ParseResult, ParseResultAst};
@@ -37,35 +41,61 @@ pub const ID_TERMINAL_LITERAL_CLASS: u32 = 0x000F;
 pub const ID_TERMINAL_UNICODE_BLOCK: u32 = 0x0010;
 /// The unique identifier for terminal `UNICODE_CATEGORY`
 pub const ID_TERMINAL_UNICODE_CATEGORY: u32 = 0x0011;
+/// The unique identifier for terminal `UNICODE_PROPERTY`
+pub const ID_TERMINAL_UNICODE_PROPERTY: u32 = 0x0012;
 /// The unique identifier for terminal `UNICODE_CODEPOINT`
-pub const ID_TERMINAL_UNICODE_CODEPOINT: u32 = 0x0012;
+pub const ID_TERMINAL_UNICODE_CODEPOINT: u32 = 0x0013;
+/// The unique identifier for terminal BYTE
+pub const ID_TERMINAL_BYTE: u32 = 0x0014;
 /// The unique identifier for terminal `UNICODE_SPAN_MARKER`
-pub const ID_TERMINAL_UNICODE_SPAN_MARKER: u32 = 0x0013;
+pub const ID_TERMINAL_UNICODE_SPAN_MARKER: u32 = 0x0015;
 /// The unique identifier for terminal `OPERATOR_OPTIONAL`
-pub const ID_TERMINAL_OPERATOR_OPTIONAL: u32 = 0x0014;
+pub const ID_TERMINAL_OPERATOR_OPTIONAL: u32 = 0x0016;
 /// The unique identifier for terminal `OPERATOR_ZEROMORE`
-pub const ID_TERMINAL_OPERATOR_ZEROMORE: u32 = 0x0015;
+pub const ID_TERMINAL_OPERATOR_ZEROMORE: u32 = 0x0017;
 /// The unique identifier for terminal `OPERATOR_ONEMORE`
-pub const ID_TERMINAL_OPERATOR_ONEMORE: u32 = 0x0016;
+pub const ID_TERMINAL_OPERATOR_ONEMORE: u32 = 0x0018;
 /// The unique identifier for terminal `OPERATOR_UNION`
-pub const ID_TERMINAL_OPERATOR_UNION: u32 = 0x0017;
+pub const ID_TERMINAL_OPERATOR_UNION: u32 = 0x0019;
 /// The unique identifier for terminal `OPERATOR_DIFFERENCE`
-pub const ID_TERMINAL_OPERATOR_DIFFERENCE: u32 = 0x0018;
+pub const ID_TERMINAL_OPERATOR_DIFFERENCE: u32 = 0x001A;
 /// The unique identifier for terminal `TREE_ACTION_PROMOTE`
-pub const ID_TERMINAL_TREE_ACTION_PROMOTE: u32 = 0x0019;
+pub const ID_TERMINAL_TREE_ACTION_PROMOTE: u32 = 0x001B;
 /// The unique identifier for terminal `TREE_ACTION_DROP`
-pub const ID_TERMINAL_TREE_ACTION_DROP: u32 = 0x001A;
+pub const ID_TERMINAL_TREE_ACTION_DROP: u32 = 0x001C;
 /// The unique identifier for terminal `BLOCK_OPTIONS`
-pub const ID_TERMINAL_BLOCK_OPTIONS: u32 = 0x001B;
+pub const ID_TERMINAL_BLOCK_OPTIONS: u32 = 0x001D;
 /// The unique identifier for terminal `BLOCK_TERMINALS`
-pub const ID_TERMINAL_BLOCK_TERMINALS: u32 = 0x001C;
+pub const ID_TERMINAL_BLOCK_TERMINALS: u32 = 0x001E;
 /// The unique identifier for terminal `BLOCK_RULES`
-pub const ID_TERMINAL_BLOCK_RULES: u32 = 0x001D;
+pub const ID_TERMINAL_BLOCK_RULES: u32 = 0x001F;
 /// The unique identifier for terminal `BLOCK_CONTEXT`
-pub const ID_TERMINAL_BLOCK_CONTEXT: u32 = 0x001E;
+pub const ID_TERMINAL_BLOCK_CONTEXT: u32 = 0x0020;
+/// The unique identifier for terminal `BLOCK_PRECEDENCE`
+pub const ID_TERMINAL_BLOCK_PRECEDENCE: u32 = 0x0021;
+/// The unique identifier for terminal `TERMINAL_EXTERNAL`
+pub const ID_TERMINAL_TERMINAL_EXTERNAL: u32 = 0x0022;
+/// The unique identifier for terminal `LEXER_MODE_PUSH`
+pub const ID_TERMINAL_LEXER_MODE_PUSH: u32 = 0x0023;
+/// The unique identifier for terminal `LEXER_MODE_POP`
+pub const ID_TERMINAL_LEXER_MODE_POP: u32 = 0x0024;
+/// The unique identifier for terminal `ASSOCIATIVITY_LEFT`
+pub const ID_TERMINAL_ASSOCIATIVITY_LEFT: u32 = 0x0025;
+/// The unique identifier for terminal `ASSOCIATIVITY_RIGHT`
+pub const ID_TERMINAL_ASSOCIATIVITY_RIGHT: u32 = 0x0026;
+/// The unique identifier for terminal `ASSOCIATIVITY_NONE`
+pub const ID_TERMINAL_ASSOCIATIVITY_NONE: u32 = 0x0027;
 
 /// The unique identifier for the default context
 pub const CONTEXT_DEFAULT: u16 = 0;
+/// The unique identifier for context `block_precedence`
+pub const CONTEXT_BLOCK_PRECEDENCE: u16 = 0x0001;
+/// The unique identifier for context `external_keyword`
+pub const CONTEXT_EXTERNAL_KEYWORD: u16 = 0x0002;
+/// The unique identifier for context `lexer_mode`
+pub const CONTEXT_LEXER_MODE: u16 = 0x0003;
+/// The unique identifier for context associativity
+pub const CONTEXT_ASSOCIATIVITY: u16 = 0x0004;
 
 /// The collection of terminals matched by this lexer
 /// The terminals are in an order consistent with the automaton,
@@ -117,114 +147,158 @@ pub const TERMINALS: &[Symbol] = &[
     },
     Symbol {
         id: 0x0012,
-        name: "UNICODE_CODEPOINT",
+        name: "UNICODE_PROPERTY",
     },
     Symbol {
         id: 0x0013,
-        name: "UNICODE_SPAN_MARKER",
+        name: "UNICODE_CODEPOINT",
     },
     Symbol {
         id: 0x0014,
-        name: "OPERATOR_OPTIONAL",
+        name: "BYTE",
     },
     Symbol {
         id: 0x0015,
-        name: "OPERATOR_ZEROMORE",
+        name: "UNICODE_SPAN_MARKER",
     },
     Symbol {
         id: 0x0016,
-        name: "OPERATOR_ONEMORE",
+        name: "OPERATOR_OPTIONAL",
     },
     Symbol {
         id: 0x0017,
-        name: "OPERATOR_UNION",
+        name: "OPERATOR_ZEROMORE",
     },
     Symbol {
         id: 0x0018,
-        name: "OPERATOR_DIFFERENCE",
+        name: "OPERATOR_ONEMORE",
     },
     Symbol {
         id: 0x0019,
-        name: "TREE_ACTION_PROMOTE",
+        name: "OPERATOR_UNION",
     },
     Symbol {
         id: 0x001A,
-        name: "TREE_ACTION_DROP",
+        name: "OPERATOR_DIFFERENCE",
     },
     Symbol {
         id: 0x001B,
-        name: "BLOCK_OPTIONS",
+        name: "TREE_ACTION_PROMOTE",
     },
     Symbol {
         id: 0x001C,
-        name: "BLOCK_TERMINALS",
+        name: "TREE_ACTION_DROP",
     },
     Symbol {
         id: 0x001D,
-        name: "BLOCK_RULES",
+        name: "BLOCK_OPTIONS",
     },
     Symbol {
         id: 0x001E,
+        name: "BLOCK_TERMINALS",
+    },
+    Symbol {
+        id: 0x001F,
+        name: "BLOCK_RULES",
+    },
+    Symbol {
+        id: 0x0020,
         name: "BLOCK_CONTEXT",
     },
     Symbol {
-        id: 0x0043,
+        id: 0x0021,
+        name: "BLOCK_PRECEDENCE",
+    },
+    Symbol {
+        id: 0x0022,
+        name: "TERMINAL_EXTERNAL",
+    },
+    Symbol {
+        id: 0x0023,
+        name: "LEXER_MODE_PUSH",
+    },
+    Symbol {
+        id: 0x0024,
+        name: "LEXER_MODE_POP",
+    },
+    Symbol {
+        id: 0x0025,
+        name: "ASSOCIATIVITY_LEFT",
+    },
+    Symbol {
+        id: 0x0026,
+        name: "ASSOCIATIVITY_RIGHT",
+    },
+    Symbol {
+        id: 0x0027,
+        name: "ASSOCIATIVITY_NONE",
+    },
+    Symbol {
+        id: 0x0051,
         name: "=",
     },
     Symbol {
-        id: 0x0044,
+        id: 0x0052,
         name: ";",
     },
     Symbol {
-        id: 0x0045,
+        id: 0x0053,
         name: "(",
     },
     Symbol {
-        id: 0x0046,
+        id: 0x0054,
         name: ")",
     },
     Symbol {
-        id: 0x0048,
+        id: 0x0056,
         name: "{",
     },
     Symbol {
-        id: 0x0049,
+        id: 0x0057,
         name: ",",
     },
     Symbol {
-        id: 0x004A,
+        id: 0x0058,
         name: "}",
     },
     Symbol {
-        id: 0x004F,
+        id: 0x005D,
+        name: "=>",
+    },
+    Symbol {
+        id: 0x005F,
         name: "->",
     },
     Symbol {
-        id: 0x0050,
+        id: 0x0061,
         name: "fragment",
     },
     Symbol {
-        id: 0x0052,
+        id: 0x0066,
         name: "@",
     },
     Symbol {
-        id: 0x0053,
+        id: 0x0067,
         name: "<",
     },
     Symbol {
-        id: 0x0055,
+        id: 0x0069,
         name: ">",
     },
     Symbol {
-        id: 0x0056,
+        id: 0x006A,
+        name: "%prec",
+    },
+    Symbol {
+        id: 0x006B,
         name: "#",
     },
     Symbol {
-        id: 0x005E,
+        id: 0x0075,
         name: ":",
     },
     Symbol {
-        id: 0x0060,
+        id: 0x0077,
         name: "grammar",
     },
 ];
@@ -235,294 +309,354 @@ fn new_lexer<'a: 'b, 'b, 'c>(
     errors: &'c mut ParseErrors<'a>,
 ) -> Lexer<'a, 'b, 'c> {
     let automaton = Automaton::new(LEXER_AUTOMATON);
-    Lexer::ContextFree(ContextFreeLexer::new(repository, errors, automaton, 0x0007))
+    Lexer::ContextSensitive(ContextSensitiveLexer::new(
+        repository, errors, automaton, 0x0007,
+    ))
 }
 
 /// Static resource for the serialized parser automaton
 const PARSER_AUTOMATON: &[u8] = include_bytes!("hime_grammar_parser.bin");
 
 /// The unique identifier for variable option
-pub const ID_VARIABLE_OPTION: u32 = 0x001F;
+pub const ID_VARIABLE_OPTION: u32 = 0x0028;
 /// The unique identifier for variable `terminal_def_atom`
-pub const ID_VARIABLE_TERMINAL_DEF_ATOM: u32 = 0x0020;
+pub const ID_VARIABLE_TERMINAL_DEF_ATOM: u32 = 0x0029;
 /// The unique identifier for variable `terminal_def_element`
-pub const ID_VARIABLE_TERMINAL_DEF_ELEMENT: u32 = 0x0021;
+pub const ID_VARIABLE_TERMINAL_DEF_ELEMENT: u32 = 0x002A;
 /// The unique identifier for variable `terminal_def_cardinalilty`
-pub const ID_VARIABLE_TERMINAL_DEF_CARDINALILTY: u32 = 0x0022;
+pub const ID_VARIABLE_TERMINAL_DEF_CARDINALILTY: u32 = 0x002B;
 /// The unique identifier for variable `terminal_def_repetition`
-pub const ID_VARIABLE_TERMINAL_DEF_REPETITION: u32 = 0x0023;
+pub const ID_VARIABLE_TERMINAL_DEF_REPETITION: u32 = 0x002C;
 /// The unique identifier for variable `terminal_def_fragment`
-pub const ID_VARIABLE_TERMINAL_DEF_FRAGMENT: u32 = 0x0024;
+pub const ID_VARIABLE_TERMINAL_DEF_FRAGMENT: u32 = 0x002D;
 /// The unique identifier for variable `terminal_def_restrict`
-pub const ID_VARIABLE_TERMINAL_DEF_RESTRICT: u32 = 0x0025;
+pub const ID_VARIABLE_TERMINAL_DEF_RESTRICT: u32 = 0x002E;
 /// The unique identifier for variable `terminal_definition`
-pub const ID_VARIABLE_TERMINAL_DEFINITION: u32 = 0x0026;
+pub const ID_VARIABLE_TERMINAL_DEFINITION: u32 = 0x002F;
+/// The unique identifier for variable `terminal_mode`
+pub const ID_VARIABLE_TERMINAL_MODE: u32 = 0x0030;
 /// The unique identifier for variable `terminal_rule`
-pub const ID_VARIABLE_TERMINAL_RULE: u32 = 0x0027;
+pub const ID_VARIABLE_TERMINAL_RULE: u32 = 0x0031;
 /// The unique identifier for variable `terminal_fragment`
-pub const ID_VARIABLE_TERMINAL_FRAGMENT: u32 = 0x0028;
+pub const ID_VARIABLE_TERMINAL_FRAGMENT: u32 = 0x0032;
+/// The unique identifier for variable `terminal_external`
+pub const ID_VARIABLE_TERMINAL_EXTERNAL: u32 = 0x0033;
 /// The unique identifier for variable `terminal_context`
-pub const ID_VARIABLE_TERMINAL_CONTEXT: u32 = 0x0029;
+pub const ID_VARIABLE_TERMINAL_CONTEXT: u32 = 0x0034;
 /// The unique identifier for variable `terminal_item`
-pub const ID_VARIABLE_TERMINAL_ITEM: u32 = 0x002A;
+pub const ID_VARIABLE_TERMINAL_ITEM: u32 = 0x0035;
+/// The unique identifier for variable `precedence_level`
+pub const ID_VARIABLE_PRECEDENCE_LEVEL: u32 = 0x0036;
 /// The unique identifier for variable `rule_sym_action`
-pub const ID_VARIABLE_RULE_SYM_ACTION: u32 = 0x002B;
+pub const ID_VARIABLE_RULE_SYM_ACTION: u32 = 0x0037;
 /// The unique identifier for variable `rule_sym_virtual`
-pub const ID_VARIABLE_RULE_SYM_VIRTUAL: u32 = 0x002C;
+pub const ID_VARIABLE_RULE_SYM_VIRTUAL: u32 = 0x0038;
 /// The unique identifier for variable `rule_sym_ref_params`
-pub const ID_VARIABLE_RULE_SYM_REF_PARAMS: u32 = 0x002D;
+pub const ID_VARIABLE_RULE_SYM_REF_PARAMS: u32 = 0x0039;
 /// The unique identifier for variable `rule_sym_ref_template`
-pub const ID_VARIABLE_RULE_SYM_REF_TEMPLATE: u32 = 0x002E;
+pub const ID_VARIABLE_RULE_SYM_REF_TEMPLATE: u32 = 0x003A;
 /// The unique identifier for variable `rule_sym_ref_simple`
-pub const ID_VARIABLE_RULE_SYM_REF_SIMPLE: u32 = 0x002F;
+pub const ID_VARIABLE_RULE_SYM_REF_SIMPLE: u32 = 0x003B;
+/// The unique identifier for variable `rule_sym_precedence`
+pub const ID_VARIABLE_RULE_SYM_PRECEDENCE: u32 = 0x003C;
 /// The unique identifier for variable `rule_def_atom`
-pub const ID_VARIABLE_RULE_DEF_ATOM: u32 = 0x0030;
+pub const ID_VARIABLE_RULE_DEF_ATOM: u32 = 0x003D;
 /// The unique identifier for variable `rule_def_context`
-pub const ID_VARIABLE_RULE_DEF_CONTEXT: u32 = 0x0031;
+pub const ID_VARIABLE_RULE_DEF_CONTEXT: u32 = 0x003E;
 /// The unique identifier for variable `rule_def_sub`
-pub const ID_VARIABLE_RULE_DEF_SUB: u32 = 0x0032;
+pub const ID_VARIABLE_RULE_DEF_SUB: u32 = 0x003F;
 /// The unique identifier for variable `rule_def_element`
-pub const ID_VARIABLE_RULE_DEF_ELEMENT: u32 = 0x0033;
+pub const ID_VARIABLE_RULE_DEF_ELEMENT: u32 = 0x0040;
 /// The unique identifier for variable `rule_def_tree_action`
-pub const ID_VARIABLE_RULE_DEF_TREE_ACTION: u32 = 0x0034;
+pub const ID_VARIABLE_RULE_DEF_TREE_ACTION: u32 = 0x0041;
 /// The unique identifier for variable `rule_def_repetition`
-pub const ID_VARIABLE_RULE_DEF_REPETITION: u32 = 0x0035;
+pub const ID_VARIABLE_RULE_DEF_REPETITION: u32 = 0x0042;
 /// The unique identifier for variable `rule_def_fragment`
-pub const ID_VARIABLE_RULE_DEF_FRAGMENT: u32 = 0x0036;
+pub const ID_VARIABLE_RULE_DEF_FRAGMENT: u32 = 0x0043;
 /// The unique identifier for variable `rule_def_choice`
-pub const ID_VARIABLE_RULE_DEF_CHOICE: u32 = 0x0037;
+pub const ID_VARIABLE_RULE_DEF_CHOICE: u32 = 0x0044;
 /// The unique identifier for variable `rule_definition`
-pub const ID_VARIABLE_RULE_DEFINITION: u32 = 0x0038;
+pub const ID_VARIABLE_RULE_DEFINITION: u32 = 0x0045;
 /// The unique identifier for variable `rule_template_params`
-pub const ID_VARIABLE_RULE_TEMPLATE_PARAMS: u32 = 0x0039;
+pub const ID_VARIABLE_RULE_TEMPLATE_PARAMS: u32 = 0x0046;
 /// The unique identifier for variable `cf_rule_template`
-pub const ID_VARIABLE_CF_RULE_TEMPLATE: u32 = 0x003A;
+pub const ID_VARIABLE_CF_RULE_TEMPLATE: u32 = 0x0047;
 /// The unique identifier for variable `cf_rule_simple`
-pub const ID_VARIABLE_CF_RULE_SIMPLE: u32 = 0x003B;
+pub const ID_VARIABLE_CF_RULE_SIMPLE: u32 = 0x0048;
 /// The unique identifier for variable `cf_rule`
-pub const ID_VARIABLE_CF_RULE: u32 = 0x003C;
+pub const ID_VARIABLE_CF_RULE: u32 = 0x0049;
 /// The unique identifier for variable `grammar_options`
-pub const ID_VARIABLE_GRAMMAR_OPTIONS: u32 = 0x003D;
+pub const ID_VARIABLE_GRAMMAR_OPTIONS: u32 = 0x004A;
 /// The unique identifier for variable `grammar_terminals`
-pub const ID_VARIABLE_GRAMMAR_TERMINALS: u32 = 0x003E;
+pub const ID_VARIABLE_GRAMMAR_TERMINALS: u32 = 0x004B;
+/// The unique identifier for variable `grammar_precedence`
+pub const ID_VARIABLE_GRAMMAR_PRECEDENCE: u32 = 0x004C;
 /// The unique identifier for variable `grammar_cf_rules`
-pub const ID_VARIABLE_GRAMMAR_CF_RULES: u32 = 0x003F;
+pub const ID_VARIABLE_GRAMMAR_CF_RULES: u32 = 0x004D;
 /// The unique identifier for variable `grammar_parency`
-pub const ID_VARIABLE_GRAMMAR_PARENCY: u32 = 0x0040;
+pub const ID_VARIABLE_GRAMMAR_PARENCY: u32 = 0x004E;
 /// The unique identifier for variable `cf_grammar`
-pub const ID_VARIABLE_CF_GRAMMAR: u32 = 0x0041;
+pub const ID_VARIABLE_CF_GRAMMAR: u32 = 0x004F;
 /// The unique identifier for variable file
-pub const ID_VARIABLE_FILE: u32 = 0x0042;
+pub const ID_VARIABLE_FILE: u32 = 0x0050;
 
 /// The unique identifier for virtual range
-pub const ID_VIRTUAL_RANGE: u32 = 0x0047;
+pub const ID_VIRTUAL_RANGE: u32 = 0x0055;
 /// The unique identifier for virtual concat
-pub const ID_VIRTUAL_CONCAT: u32 = 0x004B;
+pub const ID_VIRTUAL_CONCAT: u32 = 0x0059;
 /// The unique identifier for virtual emptypart
-pub const ID_VIRTUAL_EMPTYPART: u32 = 0x0058;
+pub const ID_VIRTUAL_EMPTYPART: u32 = 0x006D;
 
 /// The collection of variables matched by this parser
 /// The variables are in an order consistent with the automaton,
 /// so that variable indices in the automaton can be used to retrieve the variables in this table
 pub const VARIABLES: &[Symbol] = &[
     Symbol {
-        id: 0x001F,
+        id: 0x0028,
         name: "option",
     },
     Symbol {
-        id: 0x0020,
+        id: 0x0029,
         name: "terminal_def_atom",
     },
     Symbol {
-        id: 0x0021,
+        id: 0x002A,
         name: "terminal_def_element",
     },
     Symbol {
-        id: 0x0022,
+        id: 0x002B,
         name: "terminal_def_cardinalilty",
     },
     Symbol {
-        id: 0x0023,
+        id: 0x002C,
         name: "terminal_def_repetition",
     },
     Symbol {
-        id: 0x0024,
+        id: 0x002D,
         name: "terminal_def_fragment",
     },
     Symbol {
-        id: 0x0025,
+        id: 0x002E,
         name: "terminal_def_restrict",
     },
     Symbol {
-        id: 0x0026,
+        id: 0x002F,
         name: "terminal_definition",
     },
     Symbol {
-        id: 0x0027,
+        id: 0x0030,
+        name: "terminal_mode",
+    },
+    Symbol {
+        id: 0x0031,
         name: "terminal_rule",
     },
     Symbol {
-        id: 0x0028,
+        id: 0x0032,
         name: "terminal_fragment",
     },
     Symbol {
-        id: 0x0029,
+        id: 0x0033,
+        name: "terminal_external",
+    },
+    Symbol {
+        id: 0x0034,
         name: "terminal_context",
     },
     Symbol {
-        id: 0x002A,
+        id: 0x0035,
         name: "terminal_item",
     },
     Symbol {
-        id: 0x002B,
+        id: 0x0036,
+        name: "precedence_level",
+    },
+    Symbol {
+        id: 0x0037,
         name: "rule_sym_action",
     },
     Symbol {
-        id: 0x002C,
+        id: 0x0038,
         name: "rule_sym_virtual",
     },
     Symbol {
-        id: 0x002D,
+        id: 0x0039,
         name: "rule_sym_ref_params",
     },
     Symbol {
-        id: 0x002E,
+        id: 0x003A,
         name: "rule_sym_ref_template",
     },
     Symbol {
-        id: 0x002F,
+        id: 0x003B,
         name: "rule_sym_ref_simple",
     },
     Symbol {
-        id: 0x0030,
+        id: 0x003C,
+        name: "rule_sym_precedence",
+    },
+    Symbol {
+        id: 0x003D,
         name: "rule_def_atom",
     },
     Symbol {
-        id: 0x0031,
+        id: 0x003E,
         name: "rule_def_context",
     },
     Symbol {
-        id: 0x0032,
+        id: 0x003F,
         name: "rule_def_sub",
     },
     Symbol {
-        id: 0x0033,
+        id: 0x0040,
         name: "rule_def_element",
     },
     Symbol {
-        id: 0x0034,
+        id: 0x0041,
         name: "rule_def_tree_action",
     },
     Symbol {
-        id: 0x0035,
+        id: 0x0042,
         name: "rule_def_repetition",
     },
     Symbol {
-        id: 0x0036,
+        id: 0x0043,
         name: "rule_def_fragment",
     },
     Symbol {
-        id: 0x0037,
+        id: 0x0044,
         name: "rule_def_choice",
     },
     Symbol {
-        id: 0x0038,
+        id: 0x0045,
         name: "rule_definition",
     },
     Symbol {
-        id: 0x0039,
+        id: 0x0046,
         name: "rule_template_params",
     },
     Symbol {
-        id: 0x003A,
+        id: 0x0047,
         name: "cf_rule_template",
     },
     Symbol {
-        id: 0x003B,
+        id: 0x0048,
         name: "cf_rule_simple",
     },
     Symbol {
-        id: 0x003C,
+        id: 0x0049,
         name: "cf_rule",
     },
     Symbol {
-        id: 0x003D,
+        id: 0x004A,
         name: "grammar_options",
     },
     Symbol {
-        id: 0x003E,
+        id: 0x004B,
         name: "grammar_terminals",
     },
     Symbol {
-        id: 0x003F,
+        id: 0x004C,
+        name: "grammar_precedence",
+    },
+    Symbol {
+        id: 0x004D,
         name: "grammar_cf_rules",
     },
     Symbol {
-        id: 0x0040,
+        id: 0x004E,
         name: "grammar_parency",
     },
     Symbol {
-        id: 0x0041,
+        id: 0x004F,
         name: "cf_grammar",
     },
     Symbol {
-        id: 0x0042,
+        id: 0x0050,
         name: "file",
     },
     Symbol {
-        id: 0x004C,
-        name: "__V76",
+        id: 0x005A,
+        name: "__V90",
     },
     Symbol {
-        id: 0x004D,
-        name: "__V77",
+        id: 0x005B,
+        name: "__V91",
     },
     Symbol {
-        id: 0x004E,
-        name: "__V78",
+        id: 0x005C,
+        name: "__V92",
     },
     Symbol {
-        id: 0x0051,
-        name: "__V81",
+        id: 0x005E,
+        name: "__V94",
     },
     Symbol {
-        id: 0x0054,
-        name: "__V84",
+        id: 0x0060,
+        name: "__V96",
     },
     Symbol {
-        id: 0x0057,
-        name: "__V87",
+        id: 0x0062,
+        name: "__V98",
     },
     Symbol {
-        id: 0x0059,
-        name: "__V89",
+        id: 0x0063,
+        name: "__V99",
     },
     Symbol {
-        id: 0x005A,
-        name: "__V90",
+        id: 0x0064,
+        name: "__V100",
     },
     Symbol {
-        id: 0x005B,
-        name: "__V91",
+        id: 0x0065,
+        name: "__V101",
     },
     Symbol {
-        id: 0x005C,
-        name: "__V92",
+        id: 0x0068,
+        name: "__V104",
     },
     Symbol {
-        id: 0x005D,
-        name: "__V93",
+        id: 0x006C,
+        name: "__V108",
     },
     Symbol {
-        id: 0x005F,
-        name: "__V95",
+        id: 0x006E,
+        name: "__V110",
     },
     Symbol {
-        id: 0x0061,
-        name: "__V97",
+        id: 0x006F,
+        name: "__V111",
     },
     Symbol {
-        id: 0x0062,
+        id: 0x0070,
+        name: "__V112",
+    },
+    Symbol {
+        id: 0x0071,
+        name: "__V113",
+    },
+    Symbol {
+        id: 0x0072,
+        name: "__V114",
+    },
+    Symbol {
+        id: 0x0073,
+        name: "__V115",
+    },
+    Symbol {
+        id: 0x0074,
+        name: "__V116",
+    },
+    Symbol {
+        id: 0x0076,
+        name: "__V118",
+    },
+    Symbol {
+        id: 0x0078,
+        name: "__V120",
+    },
+    Symbol {
+        id: 0x0079,
         name: "__VAxiom",
     },
 ];
@@ -532,15 +666,15 @@ pub const VARIABLES: &[Symbol] = &[
 /// so that virtual indices in the automaton can be used to retrieve the virtuals in this table
 pub const VIRTUALS: &[Symbol] = &[
     Symbol {
-        id: 0x0047,
+        id: 0x0055,
         name: "range",
     },
     Symbol {
-        id: 0x004B,
+        id: 0x0059,
         name: "concat",
     },
     Symbol {
-        id: 0x0058,
+        id: 0x006D,
         name: "emptypart",
     },
 ];
@@ -569,9 +703,60 @@ pub fn parse_utf8_stream(input: &mut dyn Read) -> Result<ParseResultAst, std::io
     Ok(parse_text(text))
 }
 
+/// Parses the specified string with this parser, consulting a user-defined context provider
+#[must_use]
+pub fn parse_str_with_contexts<'t>(
+    input: &'t str,
+    contexts: &dyn UserContextProvider,
+) -> ParseResult<'static, 't, 'static, AstImpl> {
+    let text = Text::from_str(input);
+    parse_text_with(
+        text,
+        TERMINALS,
+        VARIABLES,
+        VIRTUALS,
+        ParseHooks::new().with_contexts(contexts),
+    )
+}
+
+/// Parses the specified string with this parser, consulting a user-defined context provider
+#[must_use]
+pub fn parse_string_with_contexts(
+    input: String,
+    contexts: &dyn UserContextProvider,
+) -> ParseResultAst {
+    let text = Text::from_string(input);
+    parse_text_with(
+        text,
+        TERMINALS,
+        VARIABLES,
+        VIRTUALS,
+        ParseHooks::new().with_contexts(contexts),
+    )
+}
+
+/// Parses the specified string as a `file` with this parser
+#[must_use]
+pub fn parse_axiom_file(input: &str) -> ParseResult<'static, '_, 'static, AstImpl> {
+    let text = Text::from_str(input);
+    parse_text_with(text, TERMINALS, VARIABLES, VIRTUALS, ParseHooks::new())
+}
+
 /// Parses the specified text with this parser
-fn parse_text(text: Text) -> ParseResult<'static, '_, 'static, AstImpl> {
-    parse_text_with(text, TERMINALS, VARIABLES, VIRTUALS)
+/// The text may be configured for the computation of columns, see `Text::with_config`.
+#[must_use]
+pub fn parse_text(text: Text) -> ParseResult<'static, '_, 'static, AstImpl> {
+    parse_text_with(text, TERMINALS, VARIABLES, VIRTUALS, ParseHooks::new())
+}
+
+/// Parses the specified text with this parser, with hooks into the production of the tokens
+/// The hooks combine a user-defined context provider, an external scanner and a filter of the tokens.
+#[must_use]
+pub fn parse_text_with_hooks<'t>(
+    text: Text<'t>,
+    hooks: ParseHooks,
+) -> ParseResult<'static, 't, 'static, AstImpl> {
+    parse_text_with(text, TERMINALS, VARIABLES, VIRTUALS, hooks)
 }
 
 /// Parses the specified text with this parser
@@ -580,15 +765,29 @@ fn parse_text_with<'s, 't, 'a>(
     terminals: &'a [Symbol<'s>],
     variables: &'a [Symbol<'s>],
     virtuals: &'a [Symbol<'s>],
+    hooks: ParseHooks,
 ) -> ParseResult<'s, 't, 'a, AstImpl> {
     let mut my_actions = |_index: usize, _head: Symbol, _body: &dyn SemanticBody| ();
     let mut result = ParseResult::<AstImpl>::new(terminals, variables, virtuals, text);
     {
         let data = result.get_parsing_data();
         let mut lexer = new_lexer(data.0, data.1);
+        if let Some(contexts) = hooks.contexts {
+            lexer.set_user_context_provider(contexts);
+        }
+        if let Some(scanner) = hooks.scanner {
+            lexer.set_external_scanner(scanner);
+        }
+        let mut filtered;
+        let source: &mut dyn TokenSource = if let Some(filter) = hooks.filter {
+            filtered = FilteredSource::new(lexer, filter);
+            &mut filtered
+        } else {
+            &mut lexer
+        };
         let automaton = LRkAutomaton::new(PARSER_AUTOMATON);
         let mut parser = LRkParser::new(
-            &mut lexer,
+            source,
             variables,
             virtuals,
             automaton,
@@ -600,6 +799,37 @@ fn parse_text_with<'s, 't, 'a>(
     result
 }
 
+/// Parses the specified text with this parser, using the tokens produced by a custom source
+/// The source is created from the repository of tokens and the repository for errors of the result.
+pub fn parse_text_from<'t, F>(
+    text: Text<'t>,
+    new_source: F,
+) -> ParseResult<'static, 't, 'static, AstImpl>
+where
+    F: for<'a> FnOnce(
+        TokenRepository<'static, 't, 'a>,
+        &'a mut ParseErrors<'static>,
+    ) -> Box<dyn TokenSource<'static, 't, 'a> + 'a>,
+{
+    let mut my_actions = |_index: usize, _head: Symbol, _body: &dyn SemanticBody| ();
+    let mut result = ParseResult::<AstImpl>::new(TERMINALS, VARIABLES, VIRTUALS, text);
+    {
+        let data = result.get_parsing_data();
+        let mut source = new_source(data.0, data.1);
+        let automaton = LRkAutomaton::new(PARSER_AUTOMATON);
+        let mut parser = LRkParser::new(
+            source.as_mut(),
+            VARIABLES,
+            VIRTUALS,
+            automaton,
+            data.2,
+            &mut my_actions,
+        );
+        parser.parse();
+    }
+    result
+}
+
 /// Visitor interface
 pub trait Visitor {
     fn on_terminal_separator(&self, _node: &AstNode) {}
@@ -611,7 +841,9 @@ pub trait Visitor {
     fn on_terminal_literal_class(&self, _node: &AstNode) {}
     fn on_terminal_unicode_block(&self, _node: &AstNode) {}
     fn on_terminal_unicode_category(&self, _node: &AstNode) {}
+    fn on_terminal_unicode_property(&self, _node: &AstNode) {}
     fn on_terminal_unicode_codepoint(&self, _node: &AstNode) {}
+    fn on_terminal_byte(&self, _node: &AstNode) {}
     fn on_terminal_unicode_span_marker(&self, _node: &AstNode) {}
     fn on_terminal_operator_optional(&self, _node: &AstNode) {}
     fn on_terminal_operator_zeromore(&self, _node: &AstNode) {}
@@ -624,6 +856,13 @@ pub trait Visitor {
     fn on_terminal_block_terminals(&self, _node: &AstNode) {}
     fn on_terminal_block_rules(&self, _node: &AstNode) {}
     fn on_terminal_block_context(&self, _node: &AstNode) {}
+    fn on_terminal_block_precedence(&self, _node: &AstNode) {}
+    fn on_terminal_terminal_external(&self, _node: &AstNode) {}
+    fn on_terminal_lexer_mode_push(&self, _node: &AstNode) {}
+    fn on_terminal_lexer_mode_pop(&self, _node: &AstNode) {}
+    fn on_terminal_associativity_left(&self, _node: &AstNode) {}
+    fn on_terminal_associativity_right(&self, _node: &AstNode) {}
+    fn on_terminal_associativity_none(&self, _node: &AstNode) {}
     fn on_variable_option(&self, _node: &AstNode) {}
     fn on_variable_terminal_def_atom(&self, _node: &AstNode) {}
     fn on_variable_terminal_def_element(&self, _node: &AstNode) {}
@@ -632,15 +871,19 @@ pub trait Visitor {
     fn on_variable_terminal_def_fragment(&self, _node: &AstNode) {}
     fn on_variable_terminal_def_restrict(&self, _node: &AstNode) {}
     fn on_variable_terminal_definition(&self, _node: &AstNode) {}
+    fn on_variable_terminal_mode(&self, _node: &AstNode) {}
     fn on_variable_terminal_rule(&self, _node: &AstNode) {}
     fn on_variable_terminal_fragment(&self, _node: &AstNode) {}
+    fn on_variable_terminal_external(&self, _node: &AstNode) {}
     fn on_variable_terminal_context(&self, _node: &AstNode) {}
     fn on_variable_terminal_item(&self, _node: &AstNode) {}
+    fn on_variable_precedence_level(&self, _node: &AstNode) {}
     fn on_variable_rule_sym_action(&self, _node: &AstNode) {}
     fn on_variable_rule_sym_virtual(&self, _node: &AstNode) {}
     fn on_variable_rule_sym_ref_params(&self, _node: &AstNode) {}
     fn on_variable_rule_sym_ref_template(&self, _node: &AstNode) {}
     fn on_variable_rule_sym_ref_simple(&self, _node: &AstNode) {}
+    fn on_variable_rule_sym_precedence(&self, _node: &AstNode) {}
     fn on_variable_rule_def_atom(&self, _node: &AstNode) {}
     fn on_variable_rule_def_context(&self, _node: &AstNode) {}
     fn on_variable_rule_def_sub(&self, _node: &AstNode) {}
@@ -656,6 +899,7 @@ pub trait Visitor {
     fn on_variable_cf_rule(&self, _node: &AstNode) {}
     fn on_variable_grammar_options(&self, _node: &AstNode) {}
     fn on_variable_grammar_terminals(&self, _node: &AstNode) {}
+    fn on_variable_grammar_precedence(&self, _node: &AstNode) {}
     fn on_variable_grammar_cf_rules(&self, _node: &AstNode) {}
     fn on_variable_grammar_parency(&self, _node: &AstNode) {}
     fn on_variable_cf_grammar(&self, _node: &AstNode) {}
@@ -688,58 +932,72 @@ pub fn visit_ast_node(node: AstNode, visitor: &dyn Visitor) {
         0x000F => visitor.on_terminal_literal_class(&node),
         0x0010 => visitor.on_terminal_unicode_block(&node),
         0x0011 => visitor.on_terminal_unicode_category(&node),
-        0x0012 => visitor.on_terminal_unicode_codepoint(&node),
-        0x0013 => visitor.on_terminal_unicode_span_marker(&node),
-        0x0014 => visitor.on_terminal_operator_optional(&node),
-        0x0015 => visitor.on_terminal_operator_zeromore(&node),
-        0x0016 => visitor.on_terminal_operator_onemore(&node),
-        0x0017 => visitor.on_terminal_operator_union(&node),
-        0x0018 => visitor.on_terminal_operator_difference(&node),
-        0x0019 => visitor.on_terminal_tree_action_promote(&node),
-        0x001A => visitor.on_terminal_tree_action_drop(&node),
-        0x001B => visitor.on_terminal_block_options(&node),
-        0x001C => visitor.on_terminal_block_terminals(&node),
-        0x001D => visitor.on_terminal_block_rules(&node),
-        0x001E => visitor.on_terminal_block_context(&node),
-        0x001F => visitor.on_variable_option(&node),
-        0x0020 => visitor.on_variable_terminal_def_atom(&node),
-        0x0021 => visitor.on_variable_terminal_def_element(&node),
-        0x0022 => visitor.on_variable_terminal_def_cardinalilty(&node),
-        0x0023 => visitor.on_variable_terminal_def_repetition(&node),
-        0x0024 => visitor.on_variable_terminal_def_fragment(&node),
-        0x0025 => visitor.on_variable_terminal_def_restrict(&node),
-        0x0026 => visitor.on_variable_terminal_definition(&node),
-        0x0027 => visitor.on_variable_terminal_rule(&node),
-        0x0028 => visitor.on_variable_terminal_fragment(&node),
-        0x0029 => visitor.on_variable_terminal_context(&node),
-        0x002A => visitor.on_variable_terminal_item(&node),
-        0x002B => visitor.on_variable_rule_sym_action(&node),
-        0x002C => visitor.on_variable_rule_sym_virtual(&node),
-        0x002D => visitor.on_variable_rule_sym_ref_params(&node),
-        0x002E => visitor.on_variable_rule_sym_ref_template(&node),
-        0x002F => visitor.on_variable_rule_sym_ref_simple(&node),
-        0x0030 => visitor.on_variable_rule_def_atom(&node),
-        0x0031 => visitor.on_variable_rule_def_context(&node),
-        0x0032 => visitor.on_variable_rule_def_sub(&node),
-        0x0033 => visitor.on_variable_rule_def_element(&node),
-        0x0034 => visitor.on_variable_rule_def_tree_action(&node),
-        0x0035 => visitor.on_variable_rule_def_repetition(&node),
-        0x0036 => visitor.on_variable_rule_def_fragment(&node),
-        0x0037 => visitor.on_variable_rule_def_choice(&node),
-        0x0038 => visitor.on_variable_rule_definition(&node),
-        0x0039 => visitor.on_variable_rule_template_params(&node),
-        0x003A => visitor.on_variable_cf_rule_template(&node),
-        0x003B => visitor.on_variable_cf_rule_simple(&node),
-        0x003C => visitor.on_variable_cf_rule(&node),
-        0x003D => visitor.on_variable_grammar_options(&node),
-        0x003E => visitor.on_variable_grammar_terminals(&node),
-        0x003F => visitor.on_variable_grammar_cf_rules(&node),
-        0x0040 => visitor.on_variable_grammar_parency(&node),
-        0x0041 => visitor.on_variable_cf_grammar(&node),
-        0x0042 => visitor.on_variable_file(&node),
-        0x0047 => visitor.on_virtual_range(&node),
-        0x004B => visitor.on_virtual_concat(&node),
-        0x0058 => visitor.on_virtual_emptypart(&node),
+        0x0012 => visitor.on_terminal_unicode_property(&node),
+        0x0013 => visitor.on_terminal_unicode_codepoint(&node),
+        0x0014 => visitor.on_terminal_byte(&node),
+        0x0015 => visitor.on_terminal_unicode_span_marker(&node),
+        0x0016 => visitor.on_terminal_operator_optional(&node),
+        0x0017 => visitor.on_terminal_operator_zeromore(&node),
+        0x0018 => visitor.on_terminal_operator_onemore(&node),
+        0x0019 => visitor.on_terminal_operator_union(&node),
+        0x001A => visitor.on_terminal_operator_difference(&node),
+        0x001B => visitor.on_terminal_tree_action_promote(&node),
+        0x001C => visitor.on_terminal_tree_action_drop(&node),
+        0x001D => visitor.on_terminal_block_options(&node),
+        0x001E => visitor.on_terminal_block_terminals(&node),
+        0x001F => visitor.on_terminal_block_rules(&node),
+        0x0020 => visitor.on_terminal_block_context(&node),
+        0x0021 => visitor.on_terminal_block_precedence(&node),
+        0x0022 => visitor.on_terminal_terminal_external(&node),
+        0x0023 => visitor.on_terminal_lexer_mode_push(&node),
+        0x0024 => visitor.on_terminal_lexer_mode_pop(&node),
+        0x0025 => visitor.on_terminal_associativity_left(&node),
+        0x0026 => visitor.on_terminal_associativity_right(&node),
+        0x0027 => visitor.on_terminal_associativity_none(&node),
+        0x0028 => visitor.on_variable_option(&node),
+        0x0029 => visitor.on_variable_terminal_def_atom(&node),
+        0x002A => visitor.on_variable_terminal_def_element(&node),
+        0x002B => visitor.on_variable_terminal_def_cardinalilty(&node),
+        0x002C => visitor.on_variable_terminal_def_repetition(&node),
+        0x002D => visitor.on_variable_terminal_def_fragment(&node),
+        0x002E => visitor.on_variable_terminal_def_restrict(&node),
+        0x002F => visitor.on_variable_terminal_definition(&node),
+        0x0030 => visitor.on_variable_terminal_mode(&node),
+        0x0031 => visitor.on_variable_terminal_rule(&node),
+        0x0032 => visitor.on_variable_terminal_fragment(&node),
+        0x0033 => visitor.on_variable_terminal_external(&node),
+        0x0034 => visitor.on_variable_terminal_context(&node),
+        0x0035 => visitor.on_variable_terminal_item(&node),
+        0x0036 => visitor.on_variable_precedence_level(&node),
+        0x0037 => visitor.on_variable_rule_sym_action(&node),
+        0x0038 => visitor.on_variable_rule_sym_virtual(&node),
+        0x0039 => visitor.on_variable_rule_sym_ref_params(&node),
+        0x003A => visitor.on_variable_rule_sym_ref_template(&node),
+        0x003B => visitor.on_variable_rule_sym_ref_simple(&node),
+        0x003C => visitor.on_variable_rule_sym_precedence(&node),
+        0x003D => visitor.on_variable_rule_def_atom(&node),
+        0x003E => visitor.on_variable_rule_def_context(&node),
+        0x003F => visitor.on_variable_rule_def_sub(&node),
+        0x0040 => visitor.on_variable_rule_def_element(&node),
+        0x0041 => visitor.on_variable_rule_def_tree_action(&node),
+        0x0042 => visitor.on_variable_rule_def_repetition(&node),
+        0x0043 => visitor.on_variable_rule_def_fragment(&node),
+        0x0044 => visitor.on_variable_rule_def_choice(&node),
+        0x0045 => visitor.on_variable_rule_definition(&node),
+        0x0046 => visitor.on_variable_rule_template_params(&node),
+        0x0047 => visitor.on_variable_cf_rule_template(&node),
+        0x0048 => visitor.on_variable_cf_rule_simple(&node),
+        0x0049 => visitor.on_variable_cf_rule(&node),
+        0x004A => visitor.on_variable_grammar_options(&node),
+        0x004B => visitor.on_variable_grammar_terminals(&node),
+        0x004C => visitor.on_variable_grammar_precedence(&node),
+        0x004D => visitor.on_variable_grammar_cf_rules(&node),
+        0x004E => visitor.on_variable_grammar_parency(&node),
+        0x004F => visitor.on_variable_cf_grammar(&node),
+        0x0050 => visitor.on_variable_file(&node),
+        0x0055 => visitor.on_virtual_range(&node),
+        0x0059 => visitor.on_virtual_concat(&node),
+        0x006D => visitor.on_virtual_emptypart(&node),
         _ => (),
     };
 }
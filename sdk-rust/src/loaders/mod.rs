@@ -24,7 +24,7 @@ use std::io::{self, Read};
 
 use hime_redist::ast::{Ast, AstImpl, AstNode};
 use hime_redist::errors::ParseErrorDataTrait;
-use hime_redist::lexers::DEFAULT_CONTEXT;
+use hime_redist::lexers::{LexerModeAction, DEFAULT_CONTEXT};
 use hime_redist::parsers::{
     TREE_ACTION_DROP, TREE_ACTION_NONE, TREE_ACTION_PROMOTE, TREE_ACTION_REPLACE_BY_CHILDREN,
     TREE_ACTION_REPLACE_BY_EPSILON,
@@ -35,10 +35,11 @@ use hime_redist::symbols::SemanticElementTrait;
 use crate::errors::{Error, Errors};
 use crate::finite::{FinalItem, NFA};
 use crate::grammars::{
-    BodySet, Grammar, Rule, RuleBody, SymbolRef, TemplateRuleBody, TemplateRuleParam,
-    TemplateRuleRef, TemplateRuleSymbol, TerminalReference, DEFAULT_CONTEXT_NAME,
+    Associativity, BodySet, Grammar, PrecedenceLevel, Rule, RuleBody, SymbolRef, TemplateRuleBody,
+    TemplateRuleParam, TemplateRuleRef, TemplateRuleSymbol, TerminalReference,
+    DEFAULT_CONTEXT_NAME,
 };
-use crate::unicode::{Span, BLOCKS, CATEGORIES};
+use crate::unicode::{Span, UnicodeDatabase};
 use crate::{CharSpan, Input, InputReference, LoadedData, LoadedInput, CHARSPAN_INVALID};
 
 /// Represents a generalised input for a loader
@@ -81,6 +82,7 @@ fn build_loaded_data<'t>(
             })
             .collect(),
         grammars,
+        warnings: Vec::new(),
     }
 }
 
@@ -114,8 +116,9 @@ pub fn load(inputs: Vec<LoadInput>) -> Result<LoadedData, Errors> {
         .collect();
     // get the grammars
     let (grammars, errors) = do_load_grammars(&roots);
-    let data = build_loaded_data(names, results, grammars);
-    if errors.is_empty() {
+    let mut data = build_loaded_data(names, results, grammars);
+    if errors.iter().all(Error::is_warning) {
+        data.warnings = errors;
         Ok(data)
     } else {
         Err(Errors::from(data, errors))
@@ -126,10 +129,11 @@ pub fn load(inputs: Vec<LoadInput>) -> Result<LoadedData, Errors> {
 ///
 /// # Errors
 ///
-/// Returns the parsing errors, if any
+/// Returns the parsing errors, if any.
+/// The warnings are ignored when there is no error.
 pub fn load_parsed(roots: &[(usize, AstNode)]) -> Result<Vec<Grammar>, Vec<Error>> {
     let (grammars, errors) = do_load_grammars(roots);
-    if errors.is_empty() {
+    if errors.iter().all(Error::is_warning) {
         Ok(grammars)
     } else {
         Err(errors)
@@ -150,10 +154,13 @@ fn do_load_grammars(roots: &[(usize, AstNode)]) -> (Vec<Grammar>, Vec<Error>) {
         }
     }
     resolve_inheritance(&mut completed, &mut to_resolve, &mut errors);
-    (
-        completed.into_iter().map(|loader| loader.grammar).collect(),
-        errors,
-    )
+    let grammars: Vec<Grammar> = completed.into_iter().map(|loader| loader.grammar).collect();
+    for (index, grammar) in grammars.iter().enumerate() {
+        if let Err(error) = grammar.check_injections(index, &grammars) {
+            errors.push(error);
+        }
+    }
+    (grammars, errors)
 }
 
 /// Resolves inheritance and load grammars
@@ -259,8 +266,6 @@ struct Loader<'s, 't, 'a> {
     inherited: Vec<&'a str>,
     /// The resulting grammar
     grammar: Grammar,
-    /// Flag for the global casing of the grammar
-    case_insensitive: bool,
 }
 
 impl<'s, 't, 'a> Loader<'s, 't, 'a> {
@@ -282,7 +287,6 @@ impl<'s, 't, 'a> Loader<'s, 't, 'a> {
             root,
             inherited,
             grammar: Grammar::new(input_ref, name.to_string()),
-            case_insensitive: false,
         };
         if loader.is_solved() {
             loader.load_content(errors);
@@ -334,21 +338,26 @@ impl<'s, 't, 'a> Loader<'s, 't, 'a> {
 
     /// Loads the content of the grammar
     fn load_content(&mut self, errors: &mut Vec<Error>) {
+        // the precedence block is loaded last as it may refer to the inline terminals in rules
+        let mut precedences = None;
         for node in self.root {
             let id = node.get_symbol().id;
             match id {
                 hime_grammar::ID_TERMINAL_BLOCK_OPTIONS => {
                     load_options(self.input_index, &mut self.grammar, node);
-                    if let Some(option) = self.grammar.options.get("CaseSensitive") {
-                        if &option.value == "false" {
-                            self.case_insensitive = true;
-                        }
+                    if let Some(error) = self.grammar.check_unicode_version() {
+                        errors.push(error);
                     }
                 }
                 hime_grammar::ID_TERMINAL_BLOCK_TERMINALS => {
                     load_terminals(self.input_index, errors, &mut self.grammar, node);
                 }
+                hime_grammar::ID_VARIABLE_GRAMMAR_PRECEDENCE => {
+                    precedences = Some(node);
+                }
                 hime_grammar::ID_TERMINAL_BLOCK_RULES => {
+                    // after the terminals block, which may define the terminals for the indentation
+                    self.grammar.add_indentation_terminals();
                     load_rules(self.input_index, errors, &mut self.grammar, node);
                 }
                 hime_grammar::ID_TERMINAL_NAME | hime_grammar::ID_VARIABLE_GRAMMAR_PARENCY => {}
@@ -357,6 +366,9 @@ impl<'s, 't, 'a> Loader<'s, 't, 'a> {
                 }
             }
         }
+        if let Some(node) = precedences {
+            load_precedences(self.input_index, errors, &mut self.grammar, node);
+        }
     }
 }
 
@@ -380,6 +392,83 @@ fn load_option(input_index: usize, grammar: &mut Grammar, node: AstNode) {
     );
 }
 
+/// Loads the precedence block of a grammar
+/// A terminal declared in this block overrides its inherited precedence, if any.
+fn load_precedences(
+    input_index: usize,
+    errors: &mut Vec<Error>,
+    grammar: &mut Grammar,
+    node: AstNode,
+) {
+    let mut declared: Vec<(usize, InputReference)> = Vec::new();
+    for level_node in node {
+        let associativity = match level_node.child(0).get_symbol().id {
+            hime_grammar::ID_TERMINAL_ASSOCIATIVITY_LEFT => Associativity::Left,
+            hime_grammar::ID_TERMINAL_ASSOCIATIVITY_RIGHT => Associativity::Right,
+            _ => Associativity::None,
+        };
+        let mut terminals = Vec::new();
+        for child in (1..level_node.children_count()).map(|index| level_node.child(index)) {
+            let Some(terminal) = load_precedence_terminal(input_index, errors, grammar, &child)
+            else {
+                continue;
+            };
+            let input_ref = InputReference::from(input_index, &child);
+            if let Some((_, previous)) = declared.iter().find(|(t, _)| *t == terminal) {
+                errors.push(Error::PrecedenceRedefined(
+                    input_ref,
+                    child.get_value().unwrap().to_string(),
+                    *previous,
+                ));
+                continue;
+            }
+            declared.push((terminal, input_ref));
+            terminals.push(terminal);
+        }
+        // remove the inherited declarations
+        for level in &mut grammar.precedences {
+            level
+                .terminals
+                .retain(|terminal| !terminals.contains(terminal));
+        }
+        grammar
+            .precedences
+            .retain(|level| !level.terminals.is_empty());
+        if terminals.is_empty() {
+            continue;
+        }
+        grammar.precedences.push(PrecedenceLevel {
+            associativity,
+            terminals,
+            input_ref: InputReference::from(input_index, &level_node),
+        });
+    }
+}
+
+/// Gets the terminal referred to by name or by its inline text in a precedence declaration
+fn load_precedence_terminal(
+    input_index: usize,
+    errors: &mut Vec<Error>,
+    grammar: &Grammar,
+    node: &AstNode,
+) -> Option<usize> {
+    let value = node.get_value().unwrap();
+    let terminal = if node.get_symbol().id == hime_grammar::ID_TERMINAL_LITERAL_TEXT {
+        let start = if value.starts_with('~') { 2 } else { 1 };
+        let value = replace_escapees(&value[start..(value.len() - 1)]);
+        grammar.get_terminal_for_value(&value)
+    } else {
+        grammar.get_terminal_for_name(value)
+    };
+    if terminal.is_none() {
+        errors.push(Error::SymbolNotFound(
+            InputReference::from(input_index, node),
+            value.to_string(),
+        ));
+    }
+    terminal.map(|terminal| terminal.id)
+}
+
 /// Loads the terminal blocks of a grammar
 fn load_terminals(
     input_index: usize,
@@ -400,6 +489,8 @@ fn load_terminals(
                 DEFAULT_CONTEXT_NAME,
                 true,
             );
+        } else if id == hime_grammar::ID_VARIABLE_TERMINAL_EXTERNAL {
+            load_terminal_external(input_index, errors, grammar, child);
         } else if id == hime_grammar::ID_VARIABLE_TERMINAL_RULE {
             load_terminal_rule(
                 input_index,
@@ -449,7 +540,15 @@ fn load_terminal_rule(
         return;
     }
     let mut references = Vec::new();
-    let nfa = load_nfa(input_index, errors, &mut references, grammar, node.child(1));
+    let mut nfa = load_nfa(input_index, errors, &mut references, grammar, node.child(1));
+    if grammar.is_case_insensitive(context) {
+        nfa = into_case_insensitive(grammar, nfa);
+    }
+    let mode_action = if node.children_count() > 2 {
+        load_terminal_mode_action(grammar, &node.child(2))
+    } else {
+        LexerModeAction::None
+    };
     let terminal = grammar.add_terminal_named(
         name.to_string(),
         InputReference::from(input_index, &node_name),
@@ -457,6 +556,7 @@ fn load_terminal_rule(
         context,
         is_fragment,
     );
+    terminal.mode_action = mode_action;
     terminal.nfa.states[terminal.nfa.exit]
         .add_item(FinalItem::Terminal(terminal.id, terminal.context));
     let referring_id = terminal.id;
@@ -470,6 +570,49 @@ fn load_terminal_rule(
     }
 }
 
+/// Loads the external terminal in the given AST
+fn load_terminal_external(
+    input_index: usize,
+    errors: &mut Vec<Error>,
+    grammar: &mut Grammar,
+    node: AstNode,
+) {
+    let node_name = node.child(0);
+    let name = node_name.get_value().unwrap();
+    if let Some(previous) = grammar.get_terminal_for_name(name) {
+        errors.push(Error::OverridingPreviousTerminal(
+            InputReference::from(input_index, &node_name),
+            name.to_string(),
+            previous.input_ref,
+        ));
+        return;
+    }
+    // the NFA never reaches its exit, the terminal is matched by an external scanner
+    let terminal = grammar.add_terminal_named(
+        name.to_string(),
+        InputReference::from(input_index, &node_name),
+        NFA::new_minimal(),
+        DEFAULT_CONTEXT_NAME,
+        false,
+    );
+    terminal.is_external = true;
+}
+
+/// Loads the action of a terminal on the lexer's stack of contexts
+/// The name `default` refers to the default context
+fn load_terminal_mode_action(grammar: &mut Grammar, node: &AstNode) -> LexerModeAction {
+    if node.child(0).get_symbol().id == hime_grammar::ID_TERMINAL_LEXER_MODE_POP {
+        return LexerModeAction::Pop;
+    }
+    let name = node.child(1).get_value().unwrap();
+    let context = if name == "default" {
+        DEFAULT_CONTEXT_NAME
+    } else {
+        name
+    };
+    LexerModeAction::Push(grammar.resolve_context(context) as u16)
+}
+
 /// Builds the NFA represented by the AST node
 fn load_nfa(
     input_index: usize,
@@ -479,20 +622,49 @@ fn load_nfa(
     node: AstNode,
 ) -> NFA {
     match node.get_symbol().id {
-        hime_grammar::ID_TERMINAL_LITERAL_TEXT => load_nfa_simple_text(&node),
+        hime_grammar::ID_TERMINAL_LITERAL_TEXT => load_nfa_simple_text(grammar, &node),
+        hime_grammar::ID_TERMINAL_UNICODE_CODEPOINT
+        | hime_grammar::ID_TERMINAL_UNICODE_CATEGORY
+        | hime_grammar::ID_TERMINAL_UNICODE_BLOCK
+        | hime_grammar::ID_TERMINAL_UNICODE_PROPERTY
+            if grammar.is_byte_grammar() =>
+        {
+            load_nfa_unicode_in_bytes(input_index, errors, &node)
+        }
+        hime_grammar::ID_TERMINAL_UNICODE_SPAN_MARKER
+            if grammar.is_byte_grammar()
+                && node.child(0).get_symbol().id != hime_grammar::ID_TERMINAL_BYTE =>
+        {
+            load_nfa_unicode_in_bytes(input_index, errors, &node)
+        }
         hime_grammar::ID_TERMINAL_UNICODE_CODEPOINT => {
             load_nfa_codepoint(input_index, errors, node)
         }
-        hime_grammar::ID_TERMINAL_LITERAL_CLASS => load_nfa_class(input_index, errors, node),
+        hime_grammar::ID_TERMINAL_LITERAL_CLASS if grammar.is_byte_grammar() => {
+            load_nfa_byte_class(input_index, errors, node)
+        }
+        hime_grammar::ID_TERMINAL_LITERAL_CLASS => {
+            load_nfa_class(input_index, errors, grammar.get_unicode_database(), node)
+        }
         hime_grammar::ID_TERMINAL_UNICODE_CATEGORY => {
-            load_nfa_unicode_category(input_index, errors, node)
+            load_nfa_unicode_category(input_index, errors, grammar.get_unicode_database(), node)
         }
         hime_grammar::ID_TERMINAL_UNICODE_BLOCK => {
-            load_nfa_unicode_block(input_index, errors, node)
+            load_nfa_unicode_block(input_index, errors, grammar.get_unicode_database(), node)
+        }
+        hime_grammar::ID_TERMINAL_UNICODE_PROPERTY => {
+            load_nfa_unicode_property(input_index, errors, grammar.get_unicode_database(), node)
+        }
+        hime_grammar::ID_TERMINAL_UNICODE_SPAN_MARKER
+            if node.child(0).get_symbol().id == hime_grammar::ID_TERMINAL_BYTE =>
+        {
+            load_nfa_byte_span(input_index, errors, grammar, node)
         }
         hime_grammar::ID_TERMINAL_UNICODE_SPAN_MARKER => {
             load_nfa_unicode_span(input_index, errors, node)
         }
+        hime_grammar::ID_TERMINAL_BYTE => load_nfa_byte(input_index, errors, grammar, node),
+        hime_grammar::ID_TERMINAL_LITERAL_ANY if grammar.is_byte_grammar() => load_nfa_any_byte(),
         hime_grammar::ID_TERMINAL_LITERAL_ANY => load_nfa_any(),
         hime_grammar::ID_TERMINAL_NAME => {
             load_nfa_reference(input_index, errors, references, grammar, node)
@@ -540,13 +712,23 @@ fn load_nfa(
     }
 }
 
+/// Makes the NFA of a terminal case-insensitive
+/// When the input is made of bytes, only the ASCII letters are affected.
+fn into_case_insensitive(grammar: &Grammar, nfa: NFA) -> NFA {
+    if grammar.is_byte_grammar() {
+        nfa.into_ascii_case_insensitive()
+    } else {
+        nfa.into_case_insensitive(grammar.get_unicode_database())
+    }
+}
+
 /// Builds a NFA from a piece of text
-fn load_nfa_simple_text(node: &AstNode) -> NFA {
+/// When the input is made of bytes, the text is matched as its UTF-8 encoding.
+fn load_nfa_simple_text(grammar: &Grammar, node: &AstNode) -> NFA {
     // build the raw piece of text
     let value = node.get_value().unwrap();
-    let mut insensitive = false;
-    let value = replace_escapees(if value.starts_with('~') {
-        insensitive = true;
+    let insensitive = value.starts_with('~');
+    let value = replace_escapees(if insensitive {
         &value[2..(value.len() - 1)]
     } else {
         &value[1..(value.len() - 1)]
@@ -556,20 +738,14 @@ fn load_nfa_simple_text(node: &AstNode) -> NFA {
     let mut nfa = NFA::new_minimal();
     let mut buffer = [0; 2];
     nfa.exit = nfa.entry;
-    for c in value.chars() {
-        if insensitive && c.is_ascii_alphabetic() {
-            let c2 = if c.is_ascii_lowercase() {
-                c.to_ascii_uppercase()
-            } else {
-                c.to_ascii_lowercase()
-            };
+    if grammar.is_byte_grammar() {
+        for byte in value.bytes() {
             let temp = nfa.add_state().id;
-            let encoded1 = c.encode_utf16(&mut buffer)[0];
-            let encoded2 = c2.encode_utf16(&mut buffer)[0];
-            nfa.add_transition(nfa.exit, CharSpan::new(encoded1, encoded1), temp);
-            nfa.add_transition(nfa.exit, CharSpan::new(encoded2, encoded2), temp);
+            nfa.add_transition(nfa.exit, CharSpan::new(byte.into(), byte.into()), temp);
             nfa.exit = temp;
-        } else {
+        }
+    } else {
+        for c in value.chars() {
             for encoded in &*c.encode_utf16(&mut buffer) {
                 let temp = nfa.add_state().id;
                 nfa.add_transition(nfa.exit, CharSpan::new(*encoded, *encoded), temp);
@@ -577,7 +753,11 @@ fn load_nfa_simple_text(node: &AstNode) -> NFA {
             }
         }
     }
-    nfa
+    if insensitive {
+        into_case_insensitive(grammar, nfa)
+    } else {
+        nfa
+    }
 }
 
 /// Builds a NFA from a unicode code point
@@ -605,10 +785,16 @@ fn load_nfa_codepoint(input_index: usize, errors: &mut Vec<Error>, node: AstNode
 }
 
 /// Builds a NFA from a character class
-fn load_nfa_class(input_index: usize, errors: &mut Vec<Error>, node: AstNode) -> NFA {
+fn load_nfa_class(
+    input_index: usize,
+    errors: &mut Vec<Error>,
+    database: &UnicodeDatabase,
+    node: AstNode,
+) -> NFA {
     // extract the value
     let node_value = node.get_value().unwrap();
-    let value = &node_value[1..(node_value.len() - 1)];
+    let insensitive = node_value.starts_with('~');
+    let value = &node_value[(if insensitive { 2 } else { 1 })..(node_value.len() - 1)];
     let mut positive = true;
     let chars: Vec<char> = if value.starts_with('^') {
         positive = false;
@@ -652,6 +838,9 @@ fn load_nfa_class(input_index: usize, errors: &mut Vec<Error>, node: AstNode) ->
             spans.push(CharSpan::new(b as u16, b as u16));
         }
     }
+    if insensitive {
+        spans = get_case_insensitive_spans(database, spans);
+    }
     let mut nfa = NFA::new_minimal();
     if positive {
         for span in spans {
@@ -686,12 +875,52 @@ fn load_nfa_class(input_index: usize, errors: &mut Vec<Error>, node: AstNode) ->
     nfa
 }
 
+/// Adds the case-equivalents of the characters in the spans of a class
+/// The resulting spans are sorted and do not overlap.
+fn get_case_insensitive_spans(database: &UnicodeDatabase, spans: Vec<CharSpan>) -> Vec<CharSpan> {
+    let mut all = spans.clone();
+    for span in spans {
+        // skip the surrogate encoding points
+        for (begin, end) in [
+            (span.begin, span.end.min(0xD7FF)),
+            (span.begin.max(0xE000), span.end),
+        ] {
+            if begin > end {
+                continue;
+            }
+            for &(_, equivalent) in
+                database.get_case_equivalents(Span::new(u32::from(begin), u32::from(end)))
+            {
+                if let Ok(equivalent) = u16::try_from(equivalent) {
+                    all.push(CharSpan::new(equivalent, equivalent));
+                }
+            }
+        }
+    }
+    all.sort();
+    let mut result: Vec<CharSpan> = Vec::new();
+    for span in all {
+        match result.last_mut() {
+            Some(last) if u32::from(span.begin) <= u32::from(last.end) + 1 => {
+                last.end = last.end.max(span.end);
+            }
+            _ => result.push(span),
+        }
+    }
+    result
+}
+
 /// Builds a NFA from a unicode category
-fn load_nfa_unicode_category(input_index: usize, errors: &mut Vec<Error>, node: AstNode) -> NFA {
+fn load_nfa_unicode_category(
+    input_index: usize,
+    errors: &mut Vec<Error>,
+    database: &UnicodeDatabase,
+    node: AstNode,
+) -> NFA {
     // extract the value
     let node_value = node.get_value().unwrap();
     let value = &node_value[3..(node_value.len() - 1)];
-    if let Some(category) = CATEGORIES.get(value) {
+    if let Some(category) = database.categories.get(value) {
         let mut nfa = NFA::new_minimal();
         for span in &category.spans {
             add_unicode_span_to_nfa(&mut nfa, *span);
@@ -706,12 +935,42 @@ fn load_nfa_unicode_category(input_index: usize, errors: &mut Vec<Error>, node:
     }
 }
 
+/// Builds a NFA from a unicode script or binary property
+fn load_nfa_unicode_property(
+    input_index: usize,
+    errors: &mut Vec<Error>,
+    database: &UnicodeDatabase,
+    node: AstNode,
+) -> NFA {
+    // extract the value
+    let node_value = node.get_value().unwrap();
+    let value = &node_value[3..(node_value.len() - 1)];
+    if let Some(property) = database.properties.get(value) {
+        let mut nfa = NFA::new_minimal();
+        for span in &property.spans {
+            add_unicode_span_to_nfa(&mut nfa, *span);
+        }
+        nfa
+    } else {
+        errors.push(Error::UnknownUnicodeProperty(
+            InputReference::from(input_index, &node),
+            value.to_string(),
+        ));
+        NFA::new_minimal()
+    }
+}
+
 /// Builds a NFA from a unicode block
-fn load_nfa_unicode_block(input_index: usize, errors: &mut Vec<Error>, node: AstNode) -> NFA {
+fn load_nfa_unicode_block(
+    input_index: usize,
+    errors: &mut Vec<Error>,
+    database: &UnicodeDatabase,
+    node: AstNode,
+) -> NFA {
     // extract the value
     let node_value = node.get_value().unwrap();
     let value = &node_value[3..(node_value.len() - 1)];
-    if let Some(block) = BLOCKS.get(value) {
+    if let Some(block) = database.blocks.get(value) {
         let mut nfa = NFA::new_minimal();
         add_unicode_span_to_nfa(&mut nfa, block.span);
         nfa
@@ -743,6 +1002,134 @@ fn load_nfa_unicode_span(input_index: usize, errors: &mut Vec<Error>, node: AstN
     nfa
 }
 
+/// Reports a Unicode construct used when the input is made of bytes
+fn load_nfa_unicode_in_bytes(input_index: usize, errors: &mut Vec<Error>, node: &AstNode) -> NFA {
+    errors.push(Error::UnicodeInByteGrammar(InputReference::from(
+        input_index,
+        node,
+    )));
+    NFA::new_minimal()
+}
+
+/// Gets the value of a byte in a grammar
+fn get_byte_value(node: &AstNode) -> u16 {
+    let value = node.get_value().unwrap();
+    u16::from_str_radix(&value[2..], 16).unwrap()
+}
+
+/// Builds a NFA from a byte value
+fn load_nfa_byte(
+    input_index: usize,
+    errors: &mut Vec<Error>,
+    grammar: &Grammar,
+    node: AstNode,
+) -> NFA {
+    if !grammar.is_byte_grammar() {
+        errors.push(Error::ByteInTextGrammar(InputReference::from(
+            input_index,
+            &node,
+        )));
+        return NFA::new_minimal();
+    }
+    let value = get_byte_value(&node);
+    let mut nfa = NFA::new_minimal();
+    nfa.add_transition(nfa.entry, CharSpan::new(value, value), nfa.exit);
+    nfa
+}
+
+/// Builds a NFA from a span of byte values
+fn load_nfa_byte_span(
+    input_index: usize,
+    errors: &mut Vec<Error>,
+    grammar: &Grammar,
+    node: AstNode,
+) -> NFA {
+    if !grammar.is_byte_grammar() {
+        errors.push(Error::ByteInTextGrammar(InputReference::from(
+            input_index,
+            &node,
+        )));
+        return NFA::new_minimal();
+    }
+    let begin = get_byte_value(&node.child(0));
+    let end = get_byte_value(&node.child(1));
+    if begin > end {
+        errors.push(Error::InvalidCharacterSpan(InputReference::from(
+            input_index,
+            &node,
+        )));
+        return NFA::new_minimal();
+    }
+    let mut nfa = NFA::new_minimal();
+    nfa.add_transition(nfa.entry, CharSpan::new(begin, end), nfa.exit);
+    nfa
+}
+
+/// Builds a NFA from a character class, when the input is made of bytes
+/// The characters in the class must be ASCII characters, they match the byte with the same value.
+fn load_nfa_byte_class(input_index: usize, errors: &mut Vec<Error>, node: AstNode) -> NFA {
+    // extract the value
+    let node_value = node.get_value().unwrap();
+    let insensitive = node_value.starts_with('~');
+    let value = &node_value[(if insensitive { 2 } else { 1 })..(node_value.len() - 1)];
+    let (positive, chars): (bool, Vec<char>) = match value.strip_prefix('^') {
+        Some(rest) => (false, rest.chars().collect()),
+        None => (true, value.chars().collect()),
+    };
+    let mut included = [false; 256];
+    let mut i = 0;
+    while i < chars.len() {
+        let (b, l) = get_char_value(&chars, i);
+        i += l;
+        let mut e = b;
+        if i + 2 <= chars.len() && chars[i] == '-' {
+            // this is range, match the -
+            let (last, l2) = get_char_value(&chars, i + 1);
+            i += 1 + l2;
+            e = last;
+        }
+        if !b.is_ascii() || !e.is_ascii() {
+            errors.push(Error::UnicodeInByteGrammar(InputReference::from(
+                input_index,
+                &node,
+            )));
+            continue;
+        }
+        for c in u32::from(b)..=u32::from(e) {
+            included[c as usize] = true;
+        }
+    }
+    if insensitive {
+        for c in b'a'..=b'z' {
+            let other = c.to_ascii_uppercase();
+            let both = included[usize::from(c)] || included[usize::from(other)];
+            included[usize::from(c)] = both;
+            included[usize::from(other)] = both;
+        }
+    }
+    // add a transition for each run of matched bytes
+    let mut nfa = NFA::new_minimal();
+    let mut begin = None;
+    for (value, is_included) in (0..=256_u16).zip(included.iter().copied().chain([!positive])) {
+        match (is_included == positive, begin) {
+            (true, None) => begin = Some(value),
+            (false, Some(first)) => {
+                nfa.add_transition(nfa.entry, CharSpan::new(first, value - 1), nfa.exit);
+                begin = None;
+            }
+            _ => {}
+        }
+    }
+    nfa
+}
+
+/// Builds a NFA that matches any byte
+fn load_nfa_any_byte() -> NFA {
+    let mut nfa = NFA::new_minimal();
+    nfa.add_transition(nfa.entry, CharSpan::new(0x00, 0xFF), nfa.exit);
+    nfa
+}
+
 /// Builds a NFA that matches everything (a single character)
 fn load_nfa_any() -> NFA {
     let mut nfa = NFA::new_minimal();
@@ -1162,6 +1549,14 @@ fn load_simple_rule_atomic(
         hime_grammar::ID_TERMINAL_LITERAL_TEXT => {
             load_simple_rule_atomic_inline_text(input_index, grammar, node)
         }
+        hime_grammar::ID_VARIABLE_RULE_SYM_PRECEDENCE => {
+            match load_precedence_terminal(input_index, errors, grammar, &node.child(0)) {
+                Some(terminal) => BodySet {
+                    bodies: vec![RuleBody::precedence(terminal)],
+                },
+                None => BodySet { bodies: Vec::new() },
+            }
+        }
         _ => {
             panic!("Unrecognized symbol: {}", node.get_symbol().name)
         }
@@ -1273,7 +1668,10 @@ fn load_simple_rule_atomic_inline_text(
     let id = match grammar.get_terminal_for_value(&value) {
         None => {
             // Create the terminal
-            let nfa = load_nfa_simple_text(&node);
+            let mut nfa = load_nfa_simple_text(grammar, &node);
+            if grammar.is_case_insensitive(DEFAULT_CONTEXT_NAME) {
+                nfa = into_case_insensitive(grammar, nfa);
+            }
             let terminal = grammar.add_terminal_anonymous(
                 value.into_owned(),
                 InputReference::from(input_index, &node),
@@ -1630,6 +2028,14 @@ fn load_template_rule_atomic(
         hime_grammar::ID_TERMINAL_LITERAL_TEXT => {
             load_template_rule_atomic_inline_text(input_index, grammar, node)
         }
+        hime_grammar::ID_VARIABLE_RULE_SYM_PRECEDENCE => {
+            match load_precedence_terminal(input_index, errors, grammar, &node.child(0)) {
+                Some(terminal) => BodySet {
+                    bodies: vec![TemplateRuleBody::precedence(terminal)],
+                },
+                None => BodySet { bodies: Vec::new() },
+            }
+        }
         _ => {
             panic!("Unrecognized symbol: {}", node.get_symbol().name);
         }
@@ -1773,7 +2179,10 @@ fn load_template_rule_atomic_inline_text(
     let id = match grammar.get_terminal_for_value(&value) {
         None => {
             // Create the terminal
-            let nfa = load_nfa_simple_text(&node);
+            let mut nfa = load_nfa_simple_text(grammar, &node);
+            if grammar.is_case_insensitive(DEFAULT_CONTEXT_NAME) {
+                nfa = into_case_insensitive(grammar, nfa);
+            }
             let terminal = grammar.add_terminal_anonymous(
                 value.into_owned(),
                 InputReference::from(input_index, &node),
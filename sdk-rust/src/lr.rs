@@ -17,6 +17,7 @@
 
 //! Module for LR automata
 
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 
@@ -25,8 +26,8 @@ use hime_redist::parsers::{LRActionCode, LR_ACTION_CODE_REDUCE, LR_ACTION_CODE_S
 use crate::errors::{Error, UnmatchableTokenError};
 use crate::finite::DFA;
 use crate::grammars::{
-    Grammar, RuleChoice, RuleChoiceRef, RuleRef, SymbolRef, Terminal, TerminalRef, TerminalSet,
-    GENERATED_AXIOM,
+    Associativity, Grammar, RuleBodyElement, RuleChoice, RuleChoiceRef, RuleRef, SymbolRef,
+    Terminal, TerminalRef, TerminalSet, GENERATED_AXIOM,
 };
 use crate::ParsingMethod;
 
@@ -41,6 +42,9 @@ pub enum LookaheadMode {
     LALR1,
 }
 
+/// A string of terminals
+pub type TerminalString = Vec<TerminalRef>;
+
 /// The possible origin of a lookahead
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum LookaheadOrigin {
@@ -53,13 +57,15 @@ pub enum LookaheadOrigin {
 pub struct Lookahead {
     /// The terminal
     pub terminal: TerminalRef,
+    /// The terminals following the first one, for lookaheads of more than one token
+    pub rest: TerminalString,
     /// Its origin
     pub origins: Vec<LookaheadOrigin>,
 }
 
 impl PartialEq for Lookahead {
     fn eq(&self, other: &Lookahead) -> bool {
-        self.terminal == other.terminal
+        self.terminal == other.terminal && self.rest == other.rest
     }
 }
 
@@ -67,7 +73,11 @@ impl Lookahead {
     /// Create a lookahead
     #[must_use]
     pub fn new(terminal: TerminalRef, origins: Vec<LookaheadOrigin>) -> Lookahead {
-        Lookahead { terminal, origins }
+        Lookahead {
+            terminal,
+            rest: Vec::new(),
+            origins,
+        }
     }
 
     /// Creates a lookahead without origin
@@ -75,9 +85,45 @@ impl Lookahead {
     pub fn from(terminal: TerminalRef) -> Lookahead {
         Lookahead {
             terminal,
+            rest: Vec::new(),
             origins: Vec::new(),
         }
     }
+
+    /// Creates a lookahead for a string of terminals, the empty string being represented by ε
+    #[must_use]
+    pub fn from_string(string: &[TerminalRef], origins: Vec<LookaheadOrigin>) -> Lookahead {
+        match string.split_first() {
+            None => Lookahead::new(TerminalRef::Epsilon, origins),
+            Some((terminal, rest)) => Lookahead {
+                terminal: *terminal,
+                rest: rest.to_vec(),
+                origins,
+            },
+        }
+    }
+
+    /// Gets the string of terminals for this lookahead, ε being represented by the empty string
+    #[must_use]
+    pub fn get_string(&self) -> TerminalString {
+        if self.terminal == TerminalRef::Epsilon {
+            return Vec::new();
+        }
+        let mut result = vec![self.terminal];
+        result.extend_from_slice(&self.rest);
+        result
+    }
+
+    /// Gets the values of the terminals in this lookahead, separated by spaces
+    #[must_use]
+    pub fn get_value(&self, grammar: &Grammar) -> String {
+        let mut result = grammar.get_symbol_value(self.terminal.into()).to_string();
+        for terminal in &self.rest {
+            result.push(' ');
+            result.push_str(grammar.get_symbol_value((*terminal).into()));
+        }
+        result
+    }
 }
 
 /// A set of lookahead in a LR graph
@@ -86,7 +132,7 @@ pub struct Lookaheads(Vec<Lookahead>);
 
 impl PartialEq for Lookaheads {
     fn eq(&self, other: &Lookaheads) -> bool {
-        self.0.iter().all(|lookahead| other.0.contains(lookahead))
+        self.0.len() == other.0.len() && self.0.iter().all(|lookahead| other.0.contains(lookahead))
     }
 }
 
@@ -127,6 +173,12 @@ impl Lookaheads {
             .any(|candidate| candidate.terminal == terminal)
     }
 
+    /// Gets whether this set and the other one have a lookahead in common
+    #[must_use]
+    pub fn intersects(&self, other: &Lookaheads) -> bool {
+        self.0.iter().any(|lookahead| other.0.contains(lookahead))
+    }
+
     /// Removes the specified terminal
     pub fn remove(&mut self, terminal: TerminalRef) {
         self.0.retain(|candidate| candidate.terminal != terminal);
@@ -139,10 +191,7 @@ impl Lookaheads {
             firsts
                 .content
                 .iter()
-                .map(|terminal| Lookahead {
-                    terminal: *terminal,
-                    origins: vec![LookaheadOrigin::FirstOf(choice)],
-                })
+                .map(|terminal| Lookahead::new(*terminal, vec![LookaheadOrigin::FirstOf(choice)]))
                 .collect(),
         )
     }
@@ -154,6 +203,105 @@ impl Lookaheads {
     }
 }
 
+/// The strings of at most k terminals that can begin the derivations of the variables in a grammar
+/// A string shorter than k terminals is a complete derivation.
+#[derive(Debug, Clone)]
+pub struct FirstsK {
+    /// The maximum length of the strings
+    pub k: usize,
+    /// The strings for each variable
+    variables: HashMap<usize, Vec<TerminalString>>,
+}
+
+impl FirstsK {
+    /// Computes the strings of at most k terminals for the variables of a grammar
+    #[must_use]
+    pub fn new(grammar: &Grammar, k: usize) -> FirstsK {
+        let mut firsts = FirstsK {
+            k,
+            variables: grammar
+                .variables
+                .iter()
+                .map(|variable| (variable.id, Vec::new()))
+                .collect(),
+        };
+        let mut modified = true;
+        while modified {
+            modified = false;
+            for variable in &grammar.variables {
+                let mut strings = Vec::new();
+                for rule in &variable.rules {
+                    let symbols: Vec<SymbolRef> = rule.body.choices[0]
+                        .elements
+                        .iter()
+                        .map(|element| element.symbol)
+                        .collect();
+                    for string in firsts.get_for(&symbols, &[Vec::new()]) {
+                        if !strings.contains(&string) {
+                            strings.push(string);
+                        }
+                    }
+                }
+                let current = firsts.variables.entry(variable.id).or_default();
+                if current.len() != strings.len() {
+                    *current = strings;
+                    modified = true;
+                }
+            }
+        }
+        firsts
+    }
+
+    /// Gets the strings of at most k terminals that can begin a sequence of symbols followed by one of the specified strings
+    #[must_use]
+    pub fn get_for(
+        &self,
+        symbols: &[SymbolRef],
+        follows: &[TerminalString],
+    ) -> Vec<TerminalString> {
+        let mut result: Vec<TerminalString> = vec![Vec::new()];
+        for symbol in symbols {
+            if result.iter().all(|string| string.len() >= self.k) {
+                return result;
+            }
+            let strings = match symbol {
+                SymbolRef::Variable(sid) => self.variables[sid].clone(),
+                SymbolRef::Terminal(sid) => vec![vec![TerminalRef::Terminal(*sid)]],
+                SymbolRef::Dollar => vec![vec![TerminalRef::Dollar]],
+                SymbolRef::NullTerminal => vec![vec![TerminalRef::NullTerminal]],
+                _ => continue,
+            };
+            result = self.concat(&result, &strings);
+        }
+        self.concat(&result, follows)
+    }
+
+    /// Concatenates the strings with the suffixes, truncating to k terminals
+    fn concat(
+        &self,
+        prefixes: &[TerminalString],
+        suffixes: &[TerminalString],
+    ) -> Vec<TerminalString> {
+        let mut result = Vec::new();
+        for prefix in prefixes {
+            if prefix.len() >= self.k {
+                if !result.contains(prefix) {
+                    result.push(prefix.clone());
+                }
+                continue;
+            }
+            for suffix in suffixes {
+                let mut string = prefix.clone();
+                string.extend(suffix.iter().take(self.k - prefix.len()));
+                if !result.contains(&string) {
+                    result.push(string);
+                }
+            }
+        }
+        result
+    }
+}
+
 /// Represents a base LR item
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Item {
@@ -298,6 +446,54 @@ impl Item {
         }
     }
 
+    /// Closes this item into the given closure, using lookaheads of at most k terminals
+    /// As for LALR(1), the lookaheads of items with the same base are merged.
+    ///
+    /// # Panics
+    ///
+    /// A panic is raised when the symbols in the rule cannot be found
+    /// in their respective grammar (which should not happen).
+    pub fn close_to_k(&self, grammar: &Grammar, firsts: &FirstsK, closure: &mut Vec<Item>) {
+        if let Some(SymbolRef::Variable(sid)) = self.get_next_symbol(grammar) {
+            // the lookaheads of the child items are the strings that begin beta followed by the lookaheads
+            let rule = self.rule.get_rule_in(grammar);
+            let beta: Vec<SymbolRef> = rule.body.choices[0].elements[(self.position + 1)..]
+                .iter()
+                .map(|element| element.symbol)
+                .collect();
+            let follows: Vec<TerminalString> = self
+                .lookaheads
+                .0
+                .iter()
+                .map(Lookahead::get_string)
+                .collect();
+            let origin = LookaheadOrigin::FirstOf(RuleChoiceRef {
+                rule: self.rule,
+                position: self.position + 1,
+            });
+            let lookaheads = Lookaheads(
+                firsts
+                    .get_for(&beta, &follows)
+                    .iter()
+                    .map(|string| Lookahead::from_string(string, vec![origin]))
+                    .collect(),
+            );
+            let variable = grammar.get_variable(sid).unwrap();
+            for index in 0..variable.rules.len() {
+                let candidate = Item {
+                    rule: RuleRef::new(sid, index),
+                    position: 0,
+                    lookaheads: lookaheads.clone(),
+                };
+                if let Some(other) = closure.iter_mut().find(|item| item.same_base(&candidate)) {
+                    other.lookaheads.add_others(&candidate.lookaheads);
+                } else {
+                    closure.push(candidate);
+                }
+            }
+        }
+    }
+
     /// Gets whether the two items have the same base
     #[must_use]
     pub fn same_base(&self, other: &Item) -> bool {
@@ -359,9 +555,9 @@ impl Item {
         write!(f, "  ❰")?;
         for (index, lookahead) in self.lookaheads.0.iter().enumerate() {
             if index > 0 {
-                write!(f, " ")?;
+                write!(f, "{}", if lookahead.rest.is_empty() { " " } else { ", " })?;
             }
-            write!(f, "{}", grammar.get_symbol_value(lookahead.terminal.into()))?;
+            write!(f, "{}", lookahead.get_value(grammar))?;
         }
         writeln!(f, "❱")?;
         Ok(())
@@ -400,18 +596,36 @@ impl StateKernel {
     /// Gets the closure of this kernel
     #[must_use]
     pub fn into_state(self, grammar: &Grammar, mode: LookaheadMode) -> State {
+        let items = self.close_with(|item, closure| item.close_to(grammar, closure, mode));
+        State::new(self, items)
+    }
+
+    /// Gets the closure of this kernel, using lookaheads of at most k terminals
+    #[must_use]
+    pub fn into_state_k(self, grammar: &Grammar, firsts: &FirstsK) -> State {
+        let items = self.close_with(|item, closure| item.close_to_k(grammar, firsts, closure));
+        State::new(self, items)
+    }
+
+    /// Computes the closure of this kernel with the closing function for an item
+    /// When the lookaheads of items that were already closed are merged with new ones, the closure is computed again.
+    fn close_with<F>(&self, close: F) -> Vec<Item>
+    where
+        F: Fn(&Item, &mut Vec<Item>),
+    {
         let mut items = self.items.clone();
-        let mut i = 0;
-        while i < items.len() {
-            items[i].clone().close_to(grammar, &mut items, mode);
-            i += 1;
-        }
-        State {
-            kernel: self,
-            items,
-            children: HashMap::new(),
-            opening_contexts: HashMap::new(),
-            reductions: Vec::new(),
+        let mut size = 0;
+        loop {
+            let mut i = 0;
+            while i < items.len() {
+                close(&items[i].clone(), &mut items);
+                i += 1;
+            }
+            let new_size = items.iter().map(|item| 1 + item.lookaheads.0.len()).sum();
+            if new_size == size {
+                return items;
+            }
+            size = new_size;
         }
     }
 
@@ -421,6 +635,74 @@ impl StateKernel {
             self.items.push(item);
         }
     }
+
+    /// Gets whether the two kernels have the same items, regardless of the lookaheads
+    #[must_use]
+    pub fn same_core(&self, other: &StateKernel) -> bool {
+        self.items.len() == other.items.len()
+            && self.items.iter().all(|item| {
+                other
+                    .items
+                    .iter()
+                    .any(|candidate| candidate.same_base(item))
+            })
+    }
+
+    /// Gets whether two kernels with the same core can be merged without introducing a reduce/reduce conflict
+    /// This is the weak compatibility of Pager's practical general method:
+    /// two kernels are compatible when, for any two items, the lookaheads that would be mixed by the merge
+    /// were already shared by the items in one of the kernels.
+    ///
+    /// # Panics
+    ///
+    /// A panic is raised when the kernels do not have the same core.
+    #[must_use]
+    pub fn is_weakly_compatible(&self, other: &StateKernel) -> bool {
+        let pairs: Vec<(&Lookaheads, &Lookaheads)> = self
+            .items
+            .iter()
+            .map(|item| {
+                let candidate = other
+                    .items
+                    .iter()
+                    .find(|candidate| candidate.same_base(item))
+                    .unwrap();
+                (&item.lookaheads, &candidate.lookaheads)
+            })
+            .collect();
+        for (i, (left_i, right_i)) in pairs.iter().enumerate() {
+            for (left_j, right_j) in pairs.iter().skip(i + 1) {
+                if (left_i.intersects(right_j) || right_i.intersects(left_j))
+                    && !left_i.intersects(left_j)
+                    && !right_i.intersects(right_j)
+                {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Merges the lookaheads of a kernel with the same core into this one
+    /// Returns whether new lookaheads were added
+    ///
+    /// # Panics
+    ///
+    /// A panic is raised when the kernels do not have the same core.
+    pub fn merge_lookaheads(&mut self, other: &StateKernel) -> bool {
+        let mut modified = false;
+        for item in &mut self.items {
+            let candidate = other
+                .items
+                .iter()
+                .find(|candidate| candidate.same_base(item))
+                .unwrap();
+            let before = item.lookaheads.0.len();
+            item.lookaheads.add_others(&candidate.lookaheads);
+            modified |= item.lookaheads.0.len() != before;
+        }
+        modified
+    }
 }
 
 /// Represents a reduction action in a LR state
@@ -447,9 +729,36 @@ pub struct State {
     pub opening_contexts: HashMap<TerminalRef, Vec<usize>>,
     /// The reductions on this state
     pub reductions: Vec<Reduction>,
+    /// The decisions that require more than one token of lookahead, for the first terminal
+    pub decisions: HashMap<TerminalRef, LookaheadDecision>,
+}
+
+/// A decision in a LR state that depends on the next tokens
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LookaheadDecision {
+    /// Shift the current token
+    Shift,
+    /// Reduce with a rule
+    Reduce(RuleRef, usize),
+    /// Decide by looking at the next token
+    Next(Vec<(TerminalRef, LookaheadDecision)>),
+    /// Fail on the current token, for non-associative terminals
+    Error,
 }
 
 impl State {
+    /// Creates a state for a kernel and its closure
+    fn new(kernel: StateKernel, items: Vec<Item>) -> State {
+        State {
+            kernel,
+            items,
+            children: HashMap::new(),
+            opening_contexts: HashMap::new(),
+            reductions: Vec::new(),
+            decisions: HashMap::new(),
+        }
+    }
+
     /// Builds reductions for this state
     pub fn build_reductions_lr0(&mut self, id: usize, grammar: &Grammar) -> Conflicts {
         let mut conflicts = Conflicts::default();
@@ -473,6 +782,7 @@ impl State {
                 let previous: &Item = &self.items[previous_index];
                 conflicts.raise_reduce_reduce(
                     id,
+                    grammar,
                     previous.clone(),
                     item.clone(),
                     Lookahead::from(TerminalRef::NullTerminal),
@@ -493,30 +803,63 @@ impl State {
     pub fn build_reductions_lr1(&mut self, id: usize, grammar: &Grammar) -> Conflicts {
         let mut conflicts = Conflicts::default();
         let mut reductions: HashMap<TerminalRef, usize> = HashMap::new();
+        // the terminals that are no longer shifted, or that lead to an error, after resolving conflicts
+        let mut unshifted: Vec<SymbolRef> = Vec::new();
+        let mut failing: Vec<TerminalRef> = Vec::new();
         for (index, item) in self.items.iter().enumerate() {
             if item.get_action(grammar) != LR_ACTION_CODE_REDUCE {
                 continue;
             }
             for lookahead in &item.lookaheads.0 {
                 let symbol_ref: SymbolRef = lookahead.terminal.into();
-                if self.children.contains_key(&symbol_ref) {
+                if failing.contains(&lookahead.terminal) {
+                    // resolved as an error
+                } else if self.children.contains_key(&symbol_ref)
+                    && !unshifted.contains(&symbol_ref)
+                {
                     // There is already a shift action for the lookahead => conflict
-                    conflicts.raise_shift_reduce(
+                    match conflicts.raise_shift_reduce(
                         self,
                         id,
                         grammar,
                         item.clone(),
                         lookahead.clone(),
-                    );
+                    ) {
+                        Some(ConflictResolution::Reduce(_)) => {
+                            unshifted.push(symbol_ref);
+                            reductions.insert(lookahead.terminal, index);
+                            self.reductions.push(Reduction {
+                                lookahead: lookahead.clone(),
+                                rule: item.rule,
+                                length: item.position,
+                            });
+                        }
+                        Some(ConflictResolution::Error) => {
+                            unshifted.push(symbol_ref);
+                            failing.push(lookahead.terminal);
+                        }
+                        _ => {}
+                    }
                 } else if let Some(previous_index) = reductions.get(&lookahead.terminal) {
                     // There is already a reduction action for the lookahead => conflict
                     let previous: &Item = &self.items[*previous_index];
-                    conflicts.raise_reduce_reduce(
+                    if conflicts.raise_reduce_reduce(
                         id,
+                        grammar,
                         previous.clone(),
                         item.clone(),
                         lookahead.clone(),
-                    );
+                    ) == Some(ConflictResolution::Reduce(item.rule))
+                    {
+                        // replace the previous reduction
+                        reductions.insert(lookahead.terminal, index);
+                        for reduction in &mut self.reductions {
+                            if reduction.lookahead.terminal == lookahead.terminal {
+                                reduction.rule = item.rule;
+                                reduction.length = item.position;
+                            }
+                        }
+                    }
                 } else {
                     // no conflict
                     reductions.insert(lookahead.terminal, index);
@@ -528,6 +871,9 @@ impl State {
                 }
             }
         }
+        for symbol_ref in &unshifted {
+            self.children.remove(symbol_ref);
+        }
         conflicts
     }
 
@@ -535,6 +881,9 @@ impl State {
     pub fn build_reductions_rnglr1(&mut self, id: usize, grammar: &Grammar) -> Conflicts {
         let mut conflicts = Conflicts::default();
         let mut reductions: HashMap<TerminalRef, usize> = HashMap::new();
+        // the terminals that are no longer shifted, or that lead to an error, after resolving conflicts
+        let mut unshifted: Vec<SymbolRef> = Vec::new();
+        let mut failing: Vec<TerminalRef> = Vec::new();
         for (index, item) in self.items.iter().enumerate() {
             let rule = item.rule.get_rule_in(grammar);
             if item.get_action(grammar) == LR_ACTION_CODE_SHIFT
@@ -548,24 +897,48 @@ impl State {
             }
             for lookahead in &item.lookaheads.0 {
                 let symbol_ref: SymbolRef = lookahead.terminal.into();
-                if self.children.contains_key(&symbol_ref) {
+                if failing.contains(&lookahead.terminal) {
+                    // resolved as an error
+                    continue;
+                }
+                if self.children.contains_key(&symbol_ref) && !unshifted.contains(&symbol_ref) {
                     // There is already a shift action for the lookahead => conflict
-                    conflicts.raise_shift_reduce(
+                    match conflicts.raise_shift_reduce(
                         self,
                         id,
                         grammar,
                         item.clone(),
                         lookahead.clone(),
-                    );
+                    ) {
+                        Some(ConflictResolution::Shift) => continue,
+                        Some(ConflictResolution::Reduce(_)) => unshifted.push(symbol_ref),
+                        Some(ConflictResolution::Error) => {
+                            unshifted.push(symbol_ref);
+                            failing.push(lookahead.terminal);
+                            continue;
+                        }
+                        None => {}
+                    }
                 } else if let Some(previous_index) = reductions.get(&lookahead.terminal) {
                     // There is already a reduction action for the lookahead => conflict
                     let previous: &Item = &self.items[*previous_index];
-                    conflicts.raise_reduce_reduce(
+                    match conflicts.raise_reduce_reduce(
                         id,
+                        grammar,
                         previous.clone(),
                         item.clone(),
                         lookahead.clone(),
-                    );
+                    ) {
+                        Some(ConflictResolution::Reduce(rule)) if rule == item.rule => {
+                            // drop the previous reduction
+                            self.reductions.retain(|reduction| {
+                                reduction.lookahead.terminal != lookahead.terminal
+                                    || reduction.rule != previous.rule
+                            });
+                        }
+                        Some(_) => continue,
+                        None => {}
+                    }
                 }
                 reductions.insert(lookahead.terminal, index);
                 self.reductions.push(Reduction {
@@ -575,106 +948,243 @@ impl State {
                 });
             }
         }
+        for symbol_ref in &unshifted {
+            self.children.remove(symbol_ref);
+        }
         conflicts
     }
 
-    /// Gets the reduction for the specified terminal
-    #[must_use]
-    pub fn get_reduction_for(&self, terminal: TerminalRef) -> Option<&Reduction> {
-        self.reductions
-            .iter()
-            .find(|reduction| reduction.lookahead.terminal == terminal)
-    }
-
-    /// Formats this state
+    /// Builds reductions for this state, using lookaheads of at most k terminals
+    /// When the first terminal is not enough to decide, the decision is deferred to the next tokens.
     ///
-    /// # Errors
+    /// # Panics
     ///
-    /// Return an error when formatting fails
-    pub fn format(
-        &self,
-        f: &mut Formatter,
-        state_index: usize,
+    /// A panic is raised when the symbols in the rule cannot be found
+    /// in their respective grammar (which should not happen).
+    pub fn build_reductions_lrk(
+        &mut self,
+        id: usize,
         grammar: &Grammar,
-    ) -> std::fmt::Result {
-        writeln!(f, "state {state_index} {{")?;
-        writeln!(f, "  transitions {{")?;
-        for (&symbol, target) in &self.children {
-            writeln!(
-                f,
-                "    on {} goto {}",
-                grammar.get_symbol_value(symbol),
-                target
-            )?;
-        }
-        writeln!(f, "  }}")?;
-        writeln!(f, "  reductions {{")?;
-        for reduction in &self.reductions {
-            writeln!(
-                f,
-                "    on {} reduce a {}",
-                grammar.get_symbol_value(reduction.lookahead.terminal.into()),
-                grammar.get_symbol_value(SymbolRef::Variable(
-                    reduction.rule.get_rule_in(grammar).head
-                ))
-            )?;
+        firsts: &FirstsK,
+    ) -> Conflicts {
+        let mut conflicts = Conflicts::default();
+        // the possible strings for each first terminal, with the index of the corresponding item
+        let mut candidates: HashMap<TerminalRef, Vec<(TerminalString, usize)>> = HashMap::new();
+        for (index, item) in self.items.iter().enumerate() {
+            let follows: Vec<TerminalString> = item
+                .lookaheads
+                .0
+                .iter()
+                .map(Lookahead::get_string)
+                .collect();
+            let strings = match item.get_next_symbol(grammar) {
+                None => follows,
+                Some(SymbolRef::Variable(_)) => continue,
+                Some(_) => {
+                    let rule = item.rule.get_rule_in(grammar);
+                    let symbols: Vec<SymbolRef> = rule.body.choices[0].elements[item.position..]
+                        .iter()
+                        .map(|element| element.symbol)
+                        .collect();
+                    firsts.get_for(&symbols, &follows)
+                }
+            };
+            for string in strings {
+                let first = string.first().copied().unwrap_or(TerminalRef::Epsilon);
+                let entries = candidates.entry(first).or_default();
+                if !entries.contains(&(string.clone(), index)) {
+                    entries.push((string, index));
+                }
+            }
         }
-        writeln!(f, "  }}")?;
-        writeln!(f, "  items {{")?;
-        for item in &self.items {
-            write!(f, "    ")?;
-            item.format(f, grammar)?;
+        let mut candidates: Vec<(TerminalRef, Vec<(TerminalString, usize)>)> =
+            candidates.into_iter().collect();
+        candidates.sort_by_key(|(terminal, _)| *terminal);
+        for (terminal, entries) in candidates {
+            let decision = self.decide(grammar, &entries, 1, firsts.k, &mut conflicts, id);
+            match decision {
+                LookaheadDecision::Shift => {}
+                LookaheadDecision::Error => {
+                    self.children.remove(&terminal.into());
+                }
+                LookaheadDecision::Reduce(rule, length) => {
+                    // the shift, if any, was resolved by precedence
+                    self.children.remove(&terminal.into());
+                    let item = entries
+                        .iter()
+                        .map(|(_, index)| &self.items[*index])
+                        .find(|item| item.rule == rule && item.position == length)
+                        .unwrap_or(&self.items[entries[0].1]);
+                    let origins = item
+                        .lookaheads
+                        .0
+                        .iter()
+                        .filter(|lookahead| lookahead.terminal == terminal)
+                        .flat_map(|lookahead| lookahead.origins.iter().copied())
+                        .collect();
+                    self.reductions.push(Reduction {
+                        lookahead: Lookahead::new(terminal, origins),
+                        rule,
+                        length,
+                    });
+                }
+                LookaheadDecision::Next(_) => {
+                    self.decisions.insert(terminal, decision);
+                }
+            }
         }
-        writeln!(f, "  }}")?;
-        writeln!(f, "}}")?;
-        Ok(())
+        conflicts
     }
-}
-
-/// Represents a LR graph
-#[derive(Debug, Clone, Default)]
-pub struct Graph {
-    /// The states in this graph
-    pub states: Vec<State>,
-}
 
-impl Graph {
-    /// Initializes a graph from the given state
-    #[must_use]
-    pub fn from(state: State, grammar: &Grammar, mode: LookaheadMode) -> Graph {
-        let mut graph = Graph::default();
-        graph.states.push(state);
-        let mut i = 0;
-        while i < graph.states.len() {
-            graph.build_at_state(grammar, i, mode);
-            i += 1;
+    /// Resolves the conflict between strings that cannot be decided further, if possible
+    /// Otherwise, the conflicts are recorded.
+    fn resolve_undecided(
+        &self,
+        grammar: &Grammar,
+        conflicting: &[&(TerminalString, usize)],
+        depth: usize,
+        conflicts: &mut Conflicts,
+        id: usize,
+    ) -> Option<LookaheadDecision> {
+        let lookahead = Lookahead::from_string(
+            &conflicting[0].0[..depth.min(conflicting[0].0.len())],
+            Vec::new(),
+        );
+        let mut reducing: Vec<&Item> = Vec::new();
+        for (_, index) in conflicting {
+            let item = &self.items[*index];
+            if item.get_action(grammar) == LR_ACTION_CODE_REDUCE
+                && reducing.iter().all(|other| !std::ptr::eq(*other, item))
+            {
+                reducing.push(item);
+            }
         }
-        graph
+        if conflicting
+            .iter()
+            .any(|(_, index)| self.items[*index].get_action(grammar) == LR_ACTION_CODE_SHIFT)
+        {
+            if reducing.len() == 1 {
+                match conflicts.raise_shift_reduce(
+                    self,
+                    id,
+                    grammar,
+                    reducing[0].clone(),
+                    lookahead,
+                ) {
+                    Some(ConflictResolution::Shift) => return Some(LookaheadDecision::Shift),
+                    Some(ConflictResolution::Reduce(rule)) => {
+                        return Some(LookaheadDecision::Reduce(rule, reducing[0].position))
+                    }
+                    Some(ConflictResolution::Error) => return Some(LookaheadDecision::Error),
+                    None => {}
+                }
+            } else {
+                for item in reducing {
+                    conflicts.add_shift_reduce(self, id, grammar, item.clone(), lookahead.clone());
+                }
+            }
+        } else if reducing.len() == 2 {
+            if let Some(ConflictResolution::Reduce(rule)) = conflicts.raise_reduce_reduce(
+                id,
+                grammar,
+                reducing[0].clone(),
+                reducing[1].clone(),
+                lookahead,
+            ) {
+                let winner = reducing.iter().find(|item| item.rule == rule).unwrap();
+                return Some(LookaheadDecision::Reduce(rule, winner.position));
+            }
+        } else {
+            for item in &reducing[1..] {
+                conflicts.add_reduce_reduce(
+                    id,
+                    reducing[0].clone(),
+                    (*item).clone(),
+                    lookahead.clone(),
+                );
+            }
+        }
+        None
     }
 
-    /// Build this graph at the given state
-    fn build_at_state(&mut self, grammar: &Grammar, state_id: usize, mode: LookaheadMode) {
-        // Shift dictionnary for the current set
-        let mut shifts: HashMap<SymbolRef, StateKernel> = HashMap::new();
-        // Build the children kernels from the shift actions
-        for item in &self.states[state_id].items {
-            if let Some(next) = item.get_next_symbol(grammar) {
-                shifts.entry(next).or_default().add_item(item.get_child());
+    /// Builds the decision for strings with the same prefix of the specified length
+    /// Conflicts are raised when the decision cannot be made within k terminals.
+    fn decide(
+        &self,
+        grammar: &Grammar,
+        entries: &[(TerminalString, usize)],
+        depth: usize,
+        k: usize,
+        conflicts: &mut Conflicts,
+        id: usize,
+    ) -> LookaheadDecision {
+        let get_decision = |index: usize| {
+            let item = &self.items[index];
+            if item.get_action(grammar) == LR_ACTION_CODE_SHIFT {
+                LookaheadDecision::Shift
+            } else {
+                LookaheadDecision::Reduce(item.rule, item.position)
             }
+        };
+        let first = get_decision(entries[0].1);
+        if entries
+            .iter()
+            .all(|(_, index)| get_decision(*index) == first)
+        {
+            return first;
         }
-        // Close the children and add them to the graph
-        let mut shifts: Vec<(SymbolRef, StateKernel)> = shifts.into_iter().collect();
-        shifts.sort_by_key(|(s, _)| *s);
-        for (next, kernel) in shifts {
-            let child_index = match self.get_state_for(&kernel) {
-                Some(child_index) => child_index,
-                None => self.add_state(kernel.into_state(grammar, mode)),
+        // the strings that end here cannot be decided further
+        let ended: Vec<&(TerminalString, usize)> = entries
+            .iter()
+            .filter(|(string, _)| string.len() <= depth)
+            .collect();
+        if depth >= k || !ended.is_empty() {
+            let conflicting = if depth >= k {
+                entries.iter().collect()
+            } else {
+                ended
             };
-            self.states[state_id].children.insert(next, child_index);
+            if let Some(decision) =
+                self.resolve_undecided(grammar, &conflicting, depth, conflicts, id)
+            {
+                return decision;
+            }
+            return get_decision(conflicting[0].1);
         }
-        // Build the context data
-        let state = &mut self.states[state_id];
-        for item in &state.items {
+        // group by the next terminal
+        let mut groups: Vec<(TerminalRef, Vec<(TerminalString, usize)>)> = Vec::new();
+        for (string, index) in entries {
+            let terminal = string[depth];
+            match groups
+                .iter_mut()
+                .find(|(candidate, _)| *candidate == terminal)
+            {
+                Some((_, group)) => group.push((string.clone(), *index)),
+                None => groups.push((terminal, vec![(string.clone(), *index)])),
+            }
+        }
+        groups.sort_by_key(|(terminal, _)| *terminal);
+        LookaheadDecision::Next(
+            groups
+                .into_iter()
+                .map(|(terminal, group)| {
+                    (
+                        terminal,
+                        self.decide(grammar, &group, depth + 1, k, conflicts, id),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// Builds the contexts opened by the transitions from this state
+    ///
+    /// # Panics
+    ///
+    /// A panic is raised when the symbols in the rule cannot be found
+    /// in their respective grammar (which should not happen).
+    pub fn build_opening_contexts(&mut self, grammar: &Grammar) {
+        for item in &self.items {
             if let Some(context) = item.get_opened_context(grammar) {
                 let mut opening_terminals = TerminalSet::default();
                 match item.get_next_symbol(grammar) {
@@ -700,7 +1210,7 @@ impl Graph {
                     _ => {}
                 }
                 for terminal in opening_terminals.content {
-                    let contexts = state.opening_contexts.entry(terminal).or_default();
+                    let contexts = self.opening_contexts.entry(terminal).or_default();
                     if !contexts.contains(&context) {
                         contexts.push(context);
                     }
@@ -709,6 +1219,127 @@ impl Graph {
         }
     }
 
+    /// Gets the reduction for the specified terminal
+    #[must_use]
+    pub fn get_reduction_for(&self, terminal: TerminalRef) -> Option<&Reduction> {
+        self.reductions
+            .iter()
+            .find(|reduction| reduction.lookahead.terminal == terminal)
+    }
+
+    /// Formats this state
+    ///
+    /// # Errors
+    ///
+    /// Return an error when formatting fails
+    pub fn format(
+        &self,
+        f: &mut Formatter,
+        state_index: usize,
+        grammar: &Grammar,
+    ) -> std::fmt::Result {
+        writeln!(f, "state {state_index} {{")?;
+        writeln!(f, "  transitions {{")?;
+        for (&symbol, target) in &self.children {
+            writeln!(
+                f,
+                "    on {} goto {}",
+                grammar.get_symbol_value(symbol),
+                target
+            )?;
+        }
+        writeln!(f, "  }}")?;
+        writeln!(f, "  reductions {{")?;
+        for reduction in &self.reductions {
+            writeln!(
+                f,
+                "    on {} reduce a {}",
+                grammar.get_symbol_value(reduction.lookahead.terminal.into()),
+                grammar.get_symbol_value(SymbolRef::Variable(
+                    reduction.rule.get_rule_in(grammar).head
+                ))
+            )?;
+        }
+        writeln!(f, "  }}")?;
+        writeln!(f, "  items {{")?;
+        for item in &self.items {
+            write!(f, "    ")?;
+            item.format(f, grammar)?;
+        }
+        writeln!(f, "  }}")?;
+        writeln!(f, "}}")?;
+        Ok(())
+    }
+}
+
+/// Represents a LR graph
+#[derive(Debug, Clone, Default)]
+pub struct Graph {
+    /// The states in this graph
+    pub states: Vec<State>,
+}
+
+impl Graph {
+    /// Initializes a graph from the given state
+    #[must_use]
+    pub fn from(state: State, grammar: &Grammar, mode: LookaheadMode) -> Graph {
+        Graph::from_entries(vec![state], grammar, mode)
+    }
+
+    /// Initializes a graph from the given entry states
+    /// The entry states are the first states in the graph, in the same order
+    #[must_use]
+    pub fn from_entries(states: Vec<State>, grammar: &Grammar, mode: LookaheadMode) -> Graph {
+        Graph::from_entries_with(states, grammar, &|kernel: StateKernel| {
+            kernel.into_state(grammar, mode)
+        })
+    }
+
+    /// Initializes a graph from the given entry states, with the function computing the closure of a kernel
+    /// The entry states are the first states in the graph, in the same order
+    #[must_use]
+    pub fn from_entries_with(
+        states: Vec<State>,
+        grammar: &Grammar,
+        close: &dyn Fn(StateKernel) -> State,
+    ) -> Graph {
+        let mut graph = Graph { states };
+        let mut i = 0;
+        while i < graph.states.len() {
+            graph.build_at_state(grammar, i, close);
+            i += 1;
+        }
+        graph
+    }
+
+    /// Build this graph at the given state
+    fn build_at_state(
+        &mut self,
+        grammar: &Grammar,
+        state_id: usize,
+        close: &dyn Fn(StateKernel) -> State,
+    ) {
+        // Shift dictionnary for the current set
+        let mut shifts: HashMap<SymbolRef, StateKernel> = HashMap::new();
+        // Build the children kernels from the shift actions
+        for item in &self.states[state_id].items {
+            if let Some(next) = item.get_next_symbol(grammar) {
+                shifts.entry(next).or_default().add_item(item.get_child());
+            }
+        }
+        // Close the children and add them to the graph
+        let mut shifts: Vec<(SymbolRef, StateKernel)> = shifts.into_iter().collect();
+        shifts.sort_by_key(|(s, _)| *s);
+        for (next, kernel) in shifts {
+            let child_index = match self.get_state_for(&kernel) {
+                Some(child_index) => child_index,
+                None => self.add_state(close(kernel)),
+            };
+            self.states[state_id].children.insert(next, child_index);
+        }
+        self.states[state_id].build_opening_contexts(grammar);
+    }
+
     /// Determines whether the given state (as a kernel) is already in this graph
     #[must_use]
     pub fn get_state_for(&self, kernel: &StateKernel) -> Option<usize> {
@@ -740,6 +1371,15 @@ impl Graph {
         conflicts
     }
 
+    /// Builds the reductions for this graph, using lookaheads of at most k terminals
+    pub fn build_reductions_lrk(&mut self, grammar: &Grammar, firsts: &FirstsK) -> Conflicts {
+        let mut conflicts = Conflicts::default();
+        for (index, state) in self.states.iter_mut().enumerate() {
+            conflicts.aggregate(state.build_reductions_lrk(index, grammar, firsts));
+        }
+        conflicts
+    }
+
     /// Builds the reductions for this graph
     pub fn build_reductions_rnglr1(&mut self, grammar: &Grammar) -> Conflicts {
         let mut conflicts = Conflicts::default();
@@ -846,13 +1486,14 @@ impl InverseGraph {
         InverseGraph(transitions)
     }
 
-    /// Gets all the paths from state 0 to the specified one
+    /// Gets all the paths from an entry state to the specified one
+    /// The entry states are the only states without antecedents, state 0 being the first.
     #[must_use]
     pub fn get_paths_to(&self, target: usize) -> Vec<Path> {
-        if target == 0 {
-            // for the first state, a single path that is empty
+        if !self.0.contains_key(&target) {
+            // for an entry state, a single path that is empty
             return vec![Path(vec![PathElem {
-                state: 0,
+                state: target,
                 transition: None,
             }])];
         }
@@ -872,10 +1513,10 @@ impl InverseGraph {
                         visited_with.push(*symbol);
                         let index = elements.len();
                         elements.push(PNode::new(*previous, Some(*symbol), Some(current)));
-                        if *previous == 0 {
-                            goals.push(index);
-                        } else {
+                        if self.0.contains_key(previous) {
                             queue.push(index);
+                        } else {
+                            goals.push(index);
                         }
                     }
                 }
@@ -899,7 +1540,7 @@ impl InverseGraph {
             .collect()
     }
 
-    /// Gets possible inputs that allows for reaching the specified state from state 0
+    /// Gets possible inputs that allows for reaching the specified state from an entry state
     #[must_use]
     pub fn get_inputs_for(&self, state: usize, grammar: &Grammar) -> Vec<Phrase> {
         self.get_paths_to(state)
@@ -977,6 +1618,474 @@ impl Phrase {
     }
 }
 
+/// The maximum number of candidate derivations explored for each side of a conflict
+const COUNTEREXAMPLE_MAX_CANDIDATES: usize = 16;
+
+/// The maximum number of search nodes explored when looking for derivations
+const COUNTEREXAMPLE_MAX_NODES: usize = 50_000;
+
+/// A derivation in a counterexample for a conflict
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Derivation {
+    /// A symbol that is not derived further
+    Symbol(SymbolRef),
+    /// The point of the conflict, where the lookahead is expected
+    Conflict,
+    /// The application of a rule
+    Rule(RuleRef, Vec<Derivation>),
+}
+
+impl Derivation {
+    /// Gets the sentential form produced by this derivation, where `None` is the point of the conflict
+    fn get_form(&self, form: &mut Vec<Option<SymbolRef>>) {
+        match self {
+            Derivation::Symbol(SymbolRef::Dollar) => {}
+            Derivation::Symbol(symbol) => form.push(Some(*symbol)),
+            Derivation::Conflict => form.push(None),
+            Derivation::Rule(_, children) => {
+                for child in children {
+                    child.get_form(form);
+                }
+            }
+        }
+    }
+
+    /// Gets the sentential form produced by this derivation
+    #[must_use]
+    pub fn get_example(&self, grammar: &Grammar) -> String {
+        let mut form = Vec::new();
+        self.get_form(&mut form);
+        form.into_iter()
+            .map(|symbol| match symbol {
+                Some(symbol) => grammar.get_symbol_value(symbol),
+                None => "•",
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Gets a description of this derivation, with the application of rules within brackets
+    ///
+    /// # Panics
+    ///
+    /// A panic is raised when the symbols in the rules cannot be found
+    /// in their respective grammar (which should not happen).
+    #[must_use]
+    pub fn get_description(&self, grammar: &Grammar) -> String {
+        match self {
+            Derivation::Symbol(symbol) => grammar.get_symbol_value(*symbol).to_string(),
+            Derivation::Conflict => String::from("•"),
+            Derivation::Rule(rule, children) => {
+                let children = children
+                    .iter()
+                    .filter(|child| **child != Derivation::Symbol(SymbolRef::Dollar))
+                    .map(|child| child.get_description(grammar))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let head = grammar.get_variable(rule.variable).unwrap();
+                if head.name == GENERATED_AXIOM {
+                    // do not show the generated axiom
+                    children
+                } else {
+                    format!("[{} -> {}]", head.name, children)
+                }
+            }
+        }
+    }
+}
+
+/// A counterexample for a conflict, with a derivation for each side of the conflict
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counterexample {
+    /// Whether both derivations produce the same sentential form, showing that the grammar is ambiguous
+    pub unifying: bool,
+    /// The derivation for the first side of the conflict (shift or first reduction)
+    pub first: Derivation,
+    /// The derivation for the second side of the conflict (reduction)
+    pub second: Derivation,
+}
+
+/// Node when searching backward for the derivations leading to an item
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct DNode {
+    /// The associated LR state
+    state: usize,
+    /// The grammar rule for the item
+    rule: RuleRef,
+    /// The position in the grammar rule
+    position: usize,
+    /// The terminal that must follow the reduction of the conflicting item, if not yet produced
+    follow: Option<TerminalRef>,
+    /// Whether the next element is reached by entering a rule instead of a transition
+    entering: bool,
+    /// The next element
+    next: Option<usize>,
+}
+
+/// Determines whether the sequence of elements can start with the terminal and whether it is nullable
+fn get_first_of(
+    grammar: &Grammar,
+    elements: &[RuleBodyElement],
+    terminal: TerminalRef,
+) -> (bool, bool) {
+    for element in elements {
+        match element.symbol {
+            SymbolRef::Variable(id) => {
+                let firsts = &grammar.get_variable(id).unwrap().firsts;
+                if firsts.content.contains(&terminal) {
+                    return (true, false);
+                }
+                if !firsts.content.contains(&TerminalRef::Epsilon) {
+                    return (false, false);
+                }
+            }
+            SymbolRef::Epsilon => {}
+            symbol => return (symbol == terminal.into(), false),
+        }
+    }
+    (false, true)
+}
+
+/// Gets the antecedents of a node in the backward search for derivations
+fn get_antecedents(
+    graph: &Graph,
+    inverse: &InverseGraph,
+    grammar: &Grammar,
+    node: &DNode,
+) -> Vec<DNode> {
+    let mut result = Vec::new();
+    if node.position > 0 {
+        // go back through a transition
+        let elements = &node.rule.get_rule_in(grammar).body.choices[0].elements;
+        let symbol = elements[node.position - 1].symbol;
+        let antecedents = inverse
+            .0
+            .get(&node.state)
+            .and_then(|transitions| transitions.get(&symbol));
+        for antecedent in antecedents.into_iter().flatten() {
+            if graph.states[*antecedent]
+                .items
+                .iter()
+                .any(|item| item.rule == node.rule && item.position == node.position - 1)
+            {
+                result.push(DNode {
+                    state: *antecedent,
+                    position: node.position - 1,
+                    entering: false,
+                    ..*node
+                });
+            }
+        }
+    } else {
+        // go back to the items that enter the rule
+        let head = SymbolRef::Variable(node.rule.variable);
+        for item in &graph.states[node.state].items {
+            let parent = &item.rule.get_rule_in(grammar).body.choices[0].elements;
+            if item.position >= parent.len() || parent[item.position].symbol != head {
+                continue;
+            }
+            let follow = match node.follow {
+                None => None,
+                Some(terminal) => {
+                    match get_first_of(grammar, &parent[(item.position + 1)..], terminal) {
+                        (true, _) => None,
+                        (false, true) => Some(terminal),
+                        (false, false) => continue,
+                    }
+                }
+            };
+            result.push(DNode {
+                state: node.state,
+                rule: item.rule,
+                position: item.position,
+                follow,
+                entering: true,
+                next: None,
+            });
+        }
+    }
+    result
+}
+
+/// Finds the derivations from an entry state leading to the specified items in a state
+/// For a reducing item, the derivation must be followed by the lookahead.
+/// The derivations are found in increasing order of length.
+fn find_derivations(
+    graph: &Graph,
+    inverse: &InverseGraph,
+    grammar: &Grammar,
+    state: usize,
+    items: &[&Item],
+    lookahead: TerminalRef,
+) -> Vec<(Derivation, Vec<Option<SymbolRef>>)> {
+    let mut nodes: Vec<DNode> = Vec::new();
+    for item in items {
+        let reducing = item.get_action(grammar) == LR_ACTION_CODE_REDUCE;
+        if graph.states[state]
+            .items
+            .iter()
+            .any(|candidate| candidate.same_base(item))
+        {
+            nodes.push(DNode {
+                state,
+                rule: item.rule,
+                position: item.position,
+                follow: if reducing { Some(lookahead) } else { None },
+                entering: false,
+                next: None,
+            });
+        }
+    }
+    let mut visited: HashMap<(usize, usize, usize, usize, Option<TerminalRef>), usize> =
+        HashMap::new();
+    let mut goals = Vec::new();
+    let mut current = 0;
+    while current < nodes.len()
+        && goals.len() < COUNTEREXAMPLE_MAX_CANDIDATES
+        && nodes.len() < COUNTEREXAMPLE_MAX_NODES
+    {
+        let node = nodes[current];
+        if node.position == 0
+            && grammar.get_variable(node.rule.variable).unwrap().name == GENERATED_AXIOM
+        {
+            if node.follow.is_none() {
+                goals.push(current);
+            }
+        } else {
+            for previous in get_antecedents(graph, inverse, grammar, &node) {
+                let key = (
+                    previous.state,
+                    previous.rule.variable,
+                    previous.rule.index,
+                    previous.position,
+                    previous.follow,
+                );
+                let count = visited.entry(key).or_default();
+                if *count < COUNTEREXAMPLE_MAX_CANDIDATES {
+                    *count += 1;
+                    nodes.push(DNode {
+                        next: Some(current),
+                        ..previous
+                    });
+                }
+            }
+        }
+        current += 1;
+    }
+    goals
+        .into_iter()
+        .map(|goal| {
+            let derivation = build_derivation(grammar, &nodes, goal);
+            let mut form = Vec::new();
+            derivation.get_form(&mut form);
+            (derivation, form)
+        })
+        .collect()
+}
+
+/// Builds the derivation for a path found by the backward search
+fn build_derivation(grammar: &Grammar, nodes: &[DNode], goal: usize) -> Derivation {
+    // the rules being derived, with their children and position
+    let mut stack: Vec<(RuleRef, Vec<Derivation>, usize)> = Vec::new();
+    stack.push((nodes[goal].rule, Vec::new(), 0));
+    let mut current = goal;
+    while let Some(next) = nodes[current].next {
+        if nodes[current].entering {
+            stack.push((nodes[next].rule, Vec::new(), 0));
+        } else {
+            let (rule, children, position) = stack.last_mut().unwrap();
+            let elements = &rule.get_rule_in(grammar).body.choices[0].elements;
+            children.push(Derivation::Symbol(elements[*position].symbol));
+            *position += 1;
+        }
+        current = next;
+    }
+    stack.last_mut().unwrap().1.push(Derivation::Conflict);
+    // close the rules being derived
+    let mut derivation = None;
+    while let Some((rule, mut children, mut position)) = stack.pop() {
+        if let Some(child) = derivation.take() {
+            children.push(child);
+            position += 1;
+        }
+        let elements = &rule.get_rule_in(grammar).body.choices[0].elements;
+        for element in &elements[position..] {
+            children.push(Derivation::Symbol(element.symbol));
+        }
+        derivation = Some(Derivation::Rule(rule, children));
+    }
+    derivation.unwrap()
+}
+
+/// Finds a counterexample for a conflict
+/// A unifying counterexample shows two derivations of the same sentential form.
+/// When none can be found, the shortest derivations for each side of the conflict are used.
+#[must_use]
+pub fn find_counterexample(
+    graph: &Graph,
+    inverse: &InverseGraph,
+    grammar: &Grammar,
+    conflict: &Conflict,
+) -> Option<Counterexample> {
+    let (first, second): (Vec<&Item>, Vec<&Item>) = match conflict.kind {
+        ConflictKind::ShiftReduce => (
+            conflict.shift_items.iter().collect(),
+            conflict.reduce_items.iter().collect(),
+        ),
+        ConflictKind::ReduceReduce => (
+            conflict.reduce_items.iter().take(1).collect(),
+            conflict.reduce_items.iter().skip(1).collect(),
+        ),
+    };
+    let lookahead = conflict.lookahead.terminal;
+    let first = find_derivations(graph, inverse, grammar, conflict.state, &first, lookahead);
+    let second = find_derivations(graph, inverse, grammar, conflict.state, &second, lookahead);
+    let mut best: Option<(usize, usize, usize)> = None;
+    for (i, (_, form_first)) in first.iter().enumerate() {
+        for (j, (_, form_second)) in second.iter().enumerate() {
+            if form_first == form_second
+                && best.is_none_or(|(length, _, _)| form_first.len() < length)
+            {
+                best = Some((form_first.len(), i, j));
+            }
+        }
+    }
+    let (unifying, i, j) = match best {
+        Some((_, i, j)) => (true, i, j),
+        None => (false, 0, 0),
+    };
+    Some(Counterexample {
+        unifying,
+        first: first.get(i)?.0.clone(),
+        second: second.get(j)?.0.clone(),
+    })
+}
+
+/// Gets the text of a rule
+fn get_rule_text(grammar: &Grammar, rule_ref: RuleRef) -> String {
+    let rule = rule_ref.get_rule_in(grammar);
+    let mut result = format!(
+        "{} ->",
+        grammar.get_symbol_name(SymbolRef::Variable(rule.head))
+    );
+    for element in &rule.body.choices[0].elements {
+        result.push(' ');
+        result.push_str(grammar.get_symbol_value(element.symbol));
+    }
+    result
+}
+
+/// A lookahead that leaked into a conflict by the merge of LR(1) states in a LALR(1) graph
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LalrLeak {
+    /// The reducing rule that expects the lookahead
+    pub rule: RuleRef,
+    /// The LR(1) state where the rule expects the lookahead
+    pub state: usize,
+    /// An example of input leading to the LR(1) state
+    pub phrase: Phrase,
+}
+
+/// The origin of a conflict introduced by the merge of LR(1) states in a LALR(1) graph
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LalrMerge {
+    /// The LR(1) states merged into the conflicting LALR(1) state
+    pub states: Vec<usize>,
+    /// The lookaheads that leaked from the merged LR(1) states
+    pub leaks: Vec<LalrLeak>,
+}
+
+impl LalrMerge {
+    /// Gets a description of the merge for the lookahead of the conflict
+    #[must_use]
+    pub fn get_description(&self, grammar: &Grammar, lookahead: &Lookahead) -> String {
+        let states: Vec<String> = self.states.iter().map(ToString::to_string).collect();
+        let mut lines = vec![format!(
+            "The LR(1) states {} are merged into a single LALR(1) state",
+            states.join(", ")
+        )];
+        let value = lookahead.get_value(grammar);
+        for leak in &self.leaks {
+            let phrase: Vec<&str> = leak
+                .phrase
+                .0
+                .iter()
+                .map(|terminal| grammar.get_symbol_value((*terminal).into()))
+                .collect();
+            lines.push(format!(
+                "`{value}` follows `{}` in LR(1) state {}, after: {}",
+                get_rule_text(grammar, leak.rule),
+                leak.state,
+                phrase.join(" ")
+            ));
+        }
+        lines.push(String::from(
+            "The LR(1) states do not conflict, consider using the LR1 or IELR1 parsing method",
+        ));
+        lines.join("\n")
+    }
+}
+
+/// Finds whether a conflict in a LALR(1) graph is introduced by the merge of states of the canonical LR(1) graph
+/// There is no such merge when one of the LR(1) states with the same core has the same conflict.
+#[must_use]
+pub fn find_lalr_merge(
+    graph: &Graph,
+    lr1: &Graph,
+    lr1_inverse: &InverseGraph,
+    grammar: &Grammar,
+    conflict: &Conflict,
+) -> Option<LalrMerge> {
+    let kernel = &graph.states[conflict.state].kernel;
+    let states: Vec<usize> = lr1
+        .states
+        .iter()
+        .enumerate()
+        .filter(|(_, state)| state.kernel.same_core(kernel))
+        .map(|(id, _)| id)
+        .collect();
+    let terminal = conflict.lookahead.terminal;
+    let symbol = Some(SymbolRef::from(terminal));
+    let is_conflicting = states.iter().any(|id| {
+        let items = &lr1.states[*id].items;
+        let shifts = items
+            .iter()
+            .any(|item| item.get_next_symbol(grammar) == symbol);
+        let reductions = items
+            .iter()
+            .filter(|item| {
+                item.get_action(grammar) == LR_ACTION_CODE_REDUCE
+                    && item.lookaheads.contains(terminal)
+            })
+            .count();
+        reductions > 1 || (shifts && reductions > 0)
+    });
+    if states.len() < 2 || is_conflicting {
+        return None;
+    }
+    let mut leaks = Vec::new();
+    for reducing in &conflict.reduce_items {
+        for id in &states {
+            if lr1.states[*id]
+                .items
+                .iter()
+                .any(|item| item.same_base(reducing) && item.lookaheads.contains(terminal))
+            {
+                leaks.push(LalrLeak {
+                    rule: reducing.rule,
+                    state: *id,
+                    phrase: lr1_inverse
+                        .get_inputs_for(*id, grammar)
+                        .into_iter()
+                        .next()
+                        .unwrap_or_default(),
+                });
+            }
+        }
+    }
+    Some(LalrMerge { states, leaks })
+}
+
 /// The kinds of LR conflicts
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ConflictKind {
@@ -1001,6 +2110,10 @@ pub struct Conflict {
     pub lookahead: Lookahead,
     /// Example phrases for the conflict
     pub phrases: Vec<Phrase>,
+    /// A counterexample showing the derivations in conflict
+    pub counterexample: Option<Counterexample>,
+    /// The origin of the conflict when it is introduced by the LALR(1) merge of LR(1) states
+    pub lalr_merge: Option<LalrMerge>,
 }
 
 impl PartialEq for Conflict {
@@ -1009,19 +2122,100 @@ impl PartialEq for Conflict {
     }
 }
 
+/// The resolution of a conflict by the precedence declarations
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConflictResolution {
+    /// Shift the lookahead
+    Shift,
+    /// Reduce with the rule
+    Reduce(RuleRef),
+    /// Fail on the lookahead, for non-associative terminals
+    Error,
+}
+
+/// A conflict that was resolved by the precedence declarations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedConflict {
+    /// The state where the conflict was resolved
+    pub state: usize,
+    /// The kind of conflict
+    pub kind: ConflictKind,
+    /// The reducing rules in the conflict
+    pub rules: Vec<RuleRef>,
+    /// The terminal that poses the conflict
+    pub lookahead: Lookahead,
+    /// The chosen resolution
+    pub resolution: ConflictResolution,
+    /// The index of the precedence level that decided the resolution
+    pub level: usize,
+}
+
+impl ResolvedConflict {
+    /// Gets a description of this resolution
+    #[must_use]
+    pub fn get_description(&self, grammar: &Grammar) -> String {
+        let rules: Vec<String> = self
+            .rules
+            .iter()
+            .map(|rule_ref| format!("`{}`", get_rule_text(grammar, *rule_ref)))
+            .collect();
+        let resolution = match self.resolution {
+            ConflictResolution::Shift => String::from("shift"),
+            ConflictResolution::Reduce(rule_ref) => {
+                let index = self.rules.iter().position(|r| *r == rule_ref).unwrap_or(0);
+                format!("reduce {}", rules[index])
+            }
+            ConflictResolution::Error => String::from("error"),
+        };
+        let level = &grammar.precedences[self.level];
+        format!(
+            "state {}: {} conflict on `{}` with {} resolved as {} by the {} precedence level {}",
+            self.state,
+            match self.kind {
+                ConflictKind::ShiftReduce => "shift/reduce",
+                ConflictKind::ReduceReduce => "reduce/reduce",
+            },
+            self.lookahead.get_value(grammar),
+            rules.join(", "),
+            resolution,
+            level.associativity,
+            self.level
+        )
+    }
+}
+
 /// A set of conflicts
 #[derive(Debug, Default, Clone)]
-pub struct Conflicts(Vec<Conflict>);
+pub struct Conflicts {
+    /// The unresolved conflicts
+    conflicts: Vec<Conflict>,
+    /// The conflicts resolved by the precedence declarations
+    pub resolved: Vec<ResolvedConflict>,
+}
 
 impl Conflicts {
+    /// Gets whether there is no unresolved conflict
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+
+    /// Gets the unresolved conflicts
+    #[must_use]
+    pub fn get_conflicts(&self) -> &[Conflict] {
+        &self.conflicts
+    }
+
     /// Find a similar conflict already regsitered
     fn find_similar(&mut self, kind: ConflictKind, lookahead: &Lookahead) -> Option<&mut Conflict> {
-        self.0
+        self.conflicts
             .iter_mut()
             .find(|c| c.kind == kind && &c.lookahead == lookahead)
     }
 
     /// Raise a shift/reduce conflict
+    /// When the precedence of the reducing rule and of the lookahead are declared,
+    /// the conflict is resolved and the resolution is returned.
     pub fn raise_shift_reduce(
         &mut self,
         state: &State,
@@ -1029,6 +2223,44 @@ impl Conflicts {
         grammar: &Grammar,
         reducing: Item,
         lookahead: Lookahead,
+    ) -> Option<ConflictResolution> {
+        let rule_level = grammar.get_rule_precedence(reducing.rule.get_rule_in(grammar));
+        let terminal_level = grammar.get_terminal_precedence(lookahead.terminal);
+        if let (Some(rule_level), Some(terminal_level)) = (rule_level, terminal_level) {
+            let (resolution, level) = match rule_level.cmp(&terminal_level) {
+                Ordering::Greater => (ConflictResolution::Reduce(reducing.rule), rule_level),
+                Ordering::Less => (ConflictResolution::Shift, terminal_level),
+                Ordering::Equal => (
+                    match grammar.precedences[rule_level].associativity {
+                        Associativity::Left => ConflictResolution::Reduce(reducing.rule),
+                        Associativity::Right => ConflictResolution::Shift,
+                        Associativity::None => ConflictResolution::Error,
+                    },
+                    rule_level,
+                ),
+            };
+            self.resolved.push(ResolvedConflict {
+                state: state_id,
+                kind: ConflictKind::ShiftReduce,
+                rules: vec![reducing.rule],
+                lookahead,
+                resolution,
+                level,
+            });
+            return Some(resolution);
+        }
+        self.add_shift_reduce(state, state_id, grammar, reducing, lookahead);
+        None
+    }
+
+    /// Adds an unresolved shift/reduce conflict
+    fn add_shift_reduce(
+        &mut self,
+        state: &State,
+        state_id: usize,
+        grammar: &Grammar,
+        reducing: Item,
+        lookahead: Lookahead,
     ) {
         // look for previous conflict
         if let Some(previous) = self.find_similar(ConflictKind::ShiftReduce, &lookahead) {
@@ -1047,18 +2279,56 @@ impl Conflicts {
                 shift_items.push(item.clone());
             }
         }
-        self.0.push(Conflict {
+        self.conflicts.push(Conflict {
             state: state_id,
             kind: ConflictKind::ShiftReduce,
             shift_items,
             reduce_items: vec![reducing],
             lookahead,
             phrases: Vec::new(),
+            counterexample: None,
+            lalr_merge: None,
         });
     }
 
     /// Raise a reduce/reduce conflict
+    /// When the precedences of both rules are declared and different,
+    /// the conflict is resolved in favor of the highest one and the resolution is returned.
     pub fn raise_reduce_reduce(
+        &mut self,
+        state_id: usize,
+        grammar: &Grammar,
+        previous: Item,
+        reducing: Item,
+        lookahead: Lookahead,
+    ) -> Option<ConflictResolution> {
+        let previous_level = grammar.get_rule_precedence(previous.rule.get_rule_in(grammar));
+        let reducing_level = grammar.get_rule_precedence(reducing.rule.get_rule_in(grammar));
+        if let (Some(previous_level), Some(reducing_level)) = (previous_level, reducing_level) {
+            if previous_level != reducing_level {
+                let (winner, level) = if previous_level > reducing_level {
+                    (previous.rule, previous_level)
+                } else {
+                    (reducing.rule, reducing_level)
+                };
+                let resolution = ConflictResolution::Reduce(winner);
+                self.resolved.push(ResolvedConflict {
+                    state: state_id,
+                    kind: ConflictKind::ReduceReduce,
+                    rules: vec![previous.rule, reducing.rule],
+                    lookahead,
+                    resolution,
+                    level,
+                });
+                return Some(resolution);
+            }
+        }
+        self.add_reduce_reduce(state_id, previous, reducing, lookahead);
+        None
+    }
+
+    /// Adds an unresolved reduce/reduce conflict
+    fn add_reduce_reduce(
         &mut self,
         state_id: usize,
         previous: Item,
@@ -1071,19 +2341,21 @@ impl Conflicts {
             return;
         }
         // No previous conflict was found
-        self.0.push(Conflict {
+        self.conflicts.push(Conflict {
             state: state_id,
             kind: ConflictKind::ReduceReduce,
             shift_items: Vec::new(),
             reduce_items: vec![previous, reducing],
             lookahead,
             phrases: Vec::new(),
+            counterexample: None,
+            lalr_merge: None,
         });
     }
 
     /// Aggregate other conflicts into this collection
     pub fn aggregate(&mut self, other: Conflicts) {
-        for conflict in other.0 {
+        for conflict in other.conflicts {
             if let Some(previous) = self.find_similar(conflict.kind, &conflict.lookahead) {
                 for item in conflict.shift_items {
                     if previous.shift_items.iter().all(|i| i != &item) {
@@ -1096,7 +2368,12 @@ impl Conflicts {
                     }
                 }
             } else {
-                self.0.push(conflict);
+                self.conflicts.push(conflict);
+            }
+        }
+        for resolved in other.resolved {
+            if !self.resolved.contains(&resolved) {
+                self.resolved.push(resolved);
             }
         }
     }
@@ -1123,18 +2400,28 @@ impl PartialEq for ContextError {
     }
 }
 
+/// Gets the kernels of the entry states, one for each axiom
+fn get_entry_kernels(grammar: &Grammar) -> Vec<StateKernel> {
+    let axiom = grammar.get_variable_for_name(GENERATED_AXIOM).unwrap();
+    (0..axiom.rules.len())
+        .map(|index| StateKernel {
+            items: vec![Item {
+                rule: RuleRef::new(axiom.id, index),
+                position: 0,
+                lookaheads: Lookaheads::default(),
+            }],
+        })
+        .collect()
+}
+
 /// Gets the LR(0) graph
 fn get_graph_lr0(grammar: &Grammar) -> Graph {
     // Create the base LR(0) graph
-    let axiom = grammar.get_variable_for_name(GENERATED_AXIOM).unwrap();
-    let item = Item {
-        rule: RuleRef::new(axiom.id, 0),
-        position: 0,
-        lookaheads: Lookaheads::default(),
-    };
-    let kernel = StateKernel { items: vec![item] };
-    let state0 = kernel.into_state(grammar, LookaheadMode::LR0);
-    Graph::from(state0, grammar, LookaheadMode::LR0)
+    let entries = get_entry_kernels(grammar)
+        .into_iter()
+        .map(|kernel| kernel.into_state(grammar, LookaheadMode::LR0))
+        .collect();
+    Graph::from_entries(entries, grammar, LookaheadMode::LR0)
 }
 
 /// Builds a LR(0) graph
@@ -1147,16 +2434,13 @@ pub fn build_graph_lr0(grammar: &Grammar) -> (Graph, Conflicts) {
 
 /// Gets the LR(1) graph
 fn get_graph_lr1(grammar: &Grammar) -> Graph {
-    // Create the base LR(0) graph
-    let axiom = grammar.get_variable_for_name(GENERATED_AXIOM).unwrap();
-    let item = Item {
-        rule: RuleRef::new(axiom.id, 0),
-        position: 0,
-        lookaheads: Lookaheads::default(),
-    };
-    let kernel = StateKernel { items: vec![item] };
-    let state0 = kernel.into_state(grammar, LookaheadMode::LR1);
-    Graph::from(state0, grammar, LookaheadMode::LR1)
+    // Create the base LR(1) graph
+    // the entry items are looking ahead on ε, so that the accepting states are marked for RNGLR
+    let entries = get_entry_kernels_k(grammar)
+        .into_iter()
+        .map(|kernel| kernel.into_state(grammar, LookaheadMode::LR1))
+        .collect();
+    Graph::from_entries(entries, grammar, LookaheadMode::LR1)
 }
 
 /// Builds a LR(1) graph
@@ -1183,9 +2467,14 @@ fn build_graph_lalr1_kernels(graph0: &Graph) -> Vec<StateKernel> {
         .iter()
         .map(|state| state.kernel.clone())
         .collect();
-    // set epsilon as lookahead on all items in kernel 0
-    for item in &mut kernels[0].items {
-        item.lookaheads.add(Lookahead::from(TerminalRef::Epsilon));
+    // set epsilon as lookahead on all items in the kernels of the entry states
+    // these are the only kernels with items at the start of their rule
+    for kernel in &mut kernels {
+        if kernel.items.iter().all(|item| item.position == 0) {
+            for item in &mut kernel.items {
+                item.lookaheads.add(Lookahead::from(TerminalRef::Epsilon));
+            }
+        }
     }
     kernels
 }
@@ -1327,6 +2616,185 @@ pub fn build_graph_rnglalr1(grammar: &Grammar) -> (Graph, Conflicts) {
     (graph, conflicts)
 }
 
+/// Gets the minimal LR(1) graph
+/// The graph is built as the canonical LR(1) graph, except that a new kernel is merged
+/// into an existing state with the same core when they are weakly compatible (Pager's practical general method).
+/// When the lookaheads of a state grow after a merge, its children are computed again.
+/// This yields a graph of the size of the LALR(1) graph for LALR(1) grammars,
+/// without the reduce/reduce conflicts introduced by LALR(1) for LR(1) grammars.
+fn get_graph_ielr1(grammar: &Grammar) -> Graph {
+    get_graph_ielr(
+        grammar,
+        get_entry_kernels(grammar),
+        &|kernel: StateKernel| kernel.into_state(grammar, LookaheadMode::LALR1),
+    )
+}
+
+/// Gets the minimal LR graph from the entry kernels, with the function computing the closure of a kernel
+fn get_graph_ielr(
+    grammar: &Grammar,
+    mut kernels: Vec<StateKernel>,
+    close: &dyn Fn(StateKernel) -> State,
+) -> Graph {
+    let entries = kernels.len();
+    let mut states: Vec<Option<State>> = vec![None; kernels.len()];
+    let mut queued = vec![true; kernels.len()];
+    let mut queue: Vec<usize> = (0..kernels.len()).rev().collect();
+    while let Some(state_id) = queue.pop() {
+        queued[state_id] = false;
+        let mut state = close(kernels[state_id].clone());
+        // Build the children kernels from the shift actions
+        let mut shifts: HashMap<SymbolRef, StateKernel> = HashMap::new();
+        for item in &state.items {
+            if let Some(next) = item.get_next_symbol(grammar) {
+                shifts.entry(next).or_default().add_item(item.get_child());
+            }
+        }
+        let mut shifts: Vec<(SymbolRef, StateKernel)> = shifts.into_iter().collect();
+        shifts.sort_by_key(|(s, _)| *s);
+        for (next, kernel) in shifts {
+            let existing = kernels.iter().position(|candidate| {
+                candidate.same_core(&kernel) && candidate.is_weakly_compatible(&kernel)
+            });
+            let child_index = if let Some(child_index) = existing {
+                if kernels[child_index].merge_lookaheads(&kernel) && !queued[child_index] {
+                    queued[child_index] = true;
+                    queue.push(child_index);
+                }
+                child_index
+            } else {
+                kernels.push(kernel);
+                states.push(None);
+                queued.push(true);
+                queue.push(kernels.len() - 1);
+                kernels.len() - 1
+            };
+            state.children.insert(next, child_index);
+        }
+        state.build_opening_contexts(grammar);
+        states[state_id] = Some(state);
+    }
+    // Remove the states that are no longer reachable after the merges
+    let mut reachable = vec![false; states.len()];
+    let mut stack: Vec<usize> = (0..entries).collect();
+    while let Some(state_id) = stack.pop() {
+        if !reachable[state_id] {
+            reachable[state_id] = true;
+            stack.extend(states[state_id].as_ref().unwrap().children.values());
+        }
+    }
+    let mut renumbering = vec![0; states.len()];
+    let mut count = 0;
+    for (state_id, is_reachable) in reachable.iter().enumerate() {
+        renumbering[state_id] = count;
+        if *is_reachable {
+            count += 1;
+        }
+    }
+    let states = states
+        .into_iter()
+        .zip(reachable)
+        .filter_map(|(state, is_reachable)| if is_reachable { state } else { None })
+        .map(|mut state| {
+            for child in state.children.values_mut() {
+                *child = renumbering[*child];
+            }
+            state
+        })
+        .collect();
+    Graph { states }
+}
+
+/// Builds a minimal LR(1) graph
+#[must_use]
+pub fn build_graph_ielr1(grammar: &Grammar) -> (Graph, Conflicts) {
+    let mut graph = get_graph_ielr1(grammar);
+    let conflicts = graph.build_reductions_lr1(grammar);
+    (graph, conflicts)
+}
+
+/// Gets the kernels of the entry states for lookaheads of at most k terminals
+/// The lookahead of the entry items is the empty string, so that the end of input is the end of the lookaheads.
+fn get_entry_kernels_k(grammar: &Grammar) -> Vec<StateKernel> {
+    let mut kernels = get_entry_kernels(grammar);
+    for kernel in &mut kernels {
+        for item in &mut kernel.items {
+            item.lookaheads.add(Lookahead::from(TerminalRef::Epsilon));
+        }
+    }
+    kernels
+}
+
+/// Gets the canonical LR(k) graph
+fn get_graph_lrk(grammar: &Grammar, firsts: &FirstsK) -> Graph {
+    let close = |kernel: StateKernel| kernel.into_state_k(grammar, firsts);
+    let entries = get_entry_kernels_k(grammar)
+        .into_iter()
+        .map(close)
+        .collect();
+    Graph::from_entries_with(entries, grammar, &close)
+}
+
+/// Builds a LR(k) graph
+#[must_use]
+pub fn build_graph_lrk(grammar: &Grammar, k: usize) -> (Graph, Conflicts) {
+    let firsts = FirstsK::new(grammar, k);
+    let mut graph = get_graph_lrk(grammar, &firsts);
+    let conflicts = graph.build_reductions_lrk(grammar, &firsts);
+    (graph, conflicts)
+}
+
+/// Builds a LALR(k) graph by merging the states of the LR(k) graph with the same core
+#[must_use]
+pub fn build_graph_lalrk(grammar: &Grammar, k: usize) -> (Graph, Conflicts) {
+    let firsts = FirstsK::new(grammar, k);
+    let graph = get_graph_lrk(grammar, &firsts);
+    // find the representative state for each state, i.e. the first one with the same core
+    let mut representatives: Vec<usize> = Vec::with_capacity(graph.states.len());
+    let mut kernels: Vec<StateKernel> = Vec::new();
+    for state in &graph.states {
+        if let Some(index) = kernels
+            .iter()
+            .position(|kernel| kernel.same_core(&state.kernel))
+        {
+            kernels[index].merge_lookaheads(&state.kernel);
+            representatives.push(index);
+        } else {
+            representatives.push(kernels.len());
+            kernels.push(state.kernel.clone());
+        }
+    }
+    let mut states: Vec<State> = kernels
+        .into_iter()
+        .map(|kernel| kernel.into_state_k(grammar, &firsts))
+        .collect();
+    for (state, representative) in graph.states.iter().zip(representatives.iter()) {
+        let merged = &mut states[*representative];
+        for (symbol, child) in &state.children {
+            merged.children.insert(*symbol, representatives[*child]);
+        }
+    }
+    for state in &mut states {
+        state.build_opening_contexts(grammar);
+    }
+    let mut graph = Graph { states };
+    let conflicts = graph.build_reductions_lrk(grammar, &firsts);
+    (graph, conflicts)
+}
+
+/// Builds a minimal LR(k) graph
+#[must_use]
+pub fn build_graph_ielrk(grammar: &Grammar, k: usize) -> (Graph, Conflicts) {
+    let firsts = FirstsK::new(grammar, k);
+    let mut graph = get_graph_ielr(
+        grammar,
+        get_entry_kernels_k(grammar),
+        &|kernel: StateKernel| kernel.into_state_k(grammar, &firsts),
+    );
+    let conflicts = graph.build_reductions_lrk(grammar, &firsts);
+    (graph, conflicts)
+}
+
 /// Find the potential context errors in the graph
 fn find_context_errors(
     graph: &Graph,
@@ -1338,7 +2806,8 @@ fn find_context_errors(
         for (symbol, to_state) in &state.children {
             if let SymbolRef::Terminal(tid) = *symbol {
                 let terminal = grammar.get_terminal(tid).unwrap();
-                if terminal.context == 0 {
+                if terminal.context == 0 || grammar.is_lexer_mode(terminal.context) {
+                    // the terminal is always available or its context is driven by the lexer
                     continue;
                 }
                 // this is a contextual terminal, can we reach this state without the right context being available
@@ -1452,6 +2921,7 @@ fn find_unmatchable_tokens(
 }
 
 /// Build the specified grammar
+/// For lookaheads of more than one terminal, the method must be LR(1), LALR(1) or IELR(1).
 ///
 /// # Errors
 ///
@@ -1462,21 +2932,44 @@ pub fn build_graph(
     expected: &TerminalSet,
     dfa: &DFA,
     method: ParsingMethod,
-) -> Result<Graph, Vec<Error>> {
+    lookahead: usize,
+) -> Result<(Graph, Vec<ResolvedConflict>), Vec<Error>> {
     let (graph, conflicts) = match method {
+        ParsingMethod::LR1 if lookahead > 1 => build_graph_lrk(grammar, lookahead),
+        ParsingMethod::LALR1 if lookahead > 1 => build_graph_lalrk(grammar, lookahead),
+        ParsingMethod::IELR1 if lookahead > 1 => build_graph_ielrk(grammar, lookahead),
         ParsingMethod::LR0 => build_graph_lr0(grammar),
         ParsingMethod::LR1 => build_graph_lr1(grammar),
         ParsingMethod::LALR1 => build_graph_lalr1(grammar),
-        ParsingMethod::RNGLR1 => build_graph_rnglr1(grammar),
-        ParsingMethod::RNGLALR1 => build_graph_rnglalr1(grammar),
+        ParsingMethod::IELR1 => build_graph_ielr1(grammar),
+        ParsingMethod::RNGLR1 | ParsingMethod::HLR1 => build_graph_rnglr1(grammar),
+        ParsingMethod::RNGLALR1 | ParsingMethod::HLALR1 => build_graph_rnglalr1(grammar),
     };
     let inverse = graph.inverse();
     let mut errors = Vec::new();
     if method.raise_conflict() {
-        for mut conflict in conflicts.0 {
+        // the canonical LR(1) graph to explain the conflicts introduced by the LALR(1) merge
+        let lr1 = if method == ParsingMethod::LALR1
+            && lookahead <= 1
+            && !conflicts.conflicts.is_empty()
+        {
+            let lr1 = get_graph_lr1(grammar);
+            let lr1_inverse = lr1.inverse();
+            Some((lr1, lr1_inverse))
+        } else {
+            None
+        };
+        for mut conflict in conflicts.conflicts {
             conflict.phrases = inverse.get_inputs_for(conflict.state, grammar);
             for phrase in &mut conflict.phrases {
                 phrase.append(conflict.lookahead.terminal);
+                for terminal in &conflict.lookahead.rest {
+                    phrase.append(*terminal);
+                }
+            }
+            conflict.counterexample = find_counterexample(&graph, &inverse, grammar, &conflict);
+            if let Some((lr1, lr1_inverse)) = &lr1 {
+                conflict.lalr_merge = find_lalr_merge(&graph, lr1, lr1_inverse, grammar, &conflict);
             }
             errors.push(Error::LrConflict(grammar_index, Box::new(conflict)));
         }
@@ -1495,7 +2988,7 @@ pub fn build_graph(
         ));
     }
     if errors.is_empty() {
-        return Ok(graph);
+        return Ok((graph, conflicts.resolved));
     }
     Err(errors)
 }
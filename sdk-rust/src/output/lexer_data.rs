@@ -22,20 +22,23 @@ use std::io::{self, Write};
 use std::path::PathBuf;
 
 use hime_redist::lexers::automaton::DEAD_STATE;
+use hime_redist::lexers::LexerModeAction;
 
 use crate::errors::Error;
-use crate::finite::{DFAState, DFA};
+use crate::finite::{DFAState, FinalItem, DFA};
 use crate::grammars::{Grammar, TerminalRef, TerminalSet};
 use crate::output::helper::{write_u16, write_u32};
 use crate::CharSpan;
 
 /// Writes the lexer's data
+/// When `char_lexer` is set, the automaton is written for the runtime path on Unicode scalar values
 pub fn write_lexer_data_file(
     path: Option<&String>,
     file_name: String,
     grammar: &Grammar,
     dfa: &DFA,
     expected: &TerminalSet,
+    char_lexer: bool,
 ) -> Result<(), Error> {
     let mut final_path = PathBuf::new();
     if let Some(path) = path {
@@ -44,7 +47,11 @@ pub fn write_lexer_data_file(
     final_path.push(file_name);
     let file = File::create(final_path)?;
     let mut writer = io::BufWriter::new(file);
-    write_lexer_data(&mut writer, grammar, dfa, expected)
+    if char_lexer {
+        write_lexer_data_chars(&mut writer, grammar, dfa, expected)
+    } else {
+        write_lexer_data(&mut writer, grammar, dfa, expected)
+    }
 }
 
 /// Writes the lexer's data
@@ -85,6 +92,29 @@ pub fn write_lexer_data(
     Ok(())
 }
 
+/// Gets the actions on the lexer's stack of modes for the expected terminals, by terminal index
+/// Returns an empty vector when no terminal acts on the stack of modes
+#[must_use]
+pub fn get_lexer_mode_actions(grammar: &Grammar, expected: &TerminalSet) -> Vec<LexerModeAction> {
+    let actions: Vec<LexerModeAction> = expected
+        .content
+        .iter()
+        .map(|terminal_ref| {
+            grammar
+                .get_terminal(terminal_ref.sid())
+                .map_or(LexerModeAction::None, |terminal| terminal.mode_action)
+        })
+        .collect();
+    if actions
+        .iter()
+        .all(|action| *action == LexerModeAction::None)
+    {
+        Vec::new()
+    } else {
+        actions
+    }
+}
+
 /// Writes the lexer's data
 fn write_lexer_data_state(
     writer: &mut dyn Write,
@@ -111,24 +141,7 @@ fn write_lexer_data_state(
             slow.push((*span, *next));
         }
     }
-    let mut contexts = Vec::new();
-    let mut matched = Vec::new();
-    for item in &state.items {
-        let terminal = grammar.get_terminal(item.sid()).unwrap();
-        let terminal_ref = TerminalRef::Terminal(terminal.id);
-        if !contexts.contains(&terminal.context) {
-            // this is the first time this context is found in the current DFA state
-            // this is the terminal with the most priority for this context
-            contexts.push(terminal.context);
-            matched.push(
-                expected
-                    .content
-                    .iter()
-                    .position(|t| t == &terminal_ref)
-                    .unwrap(),
-            );
-        }
-    }
+    let matched = get_matched_terminals(grammar, expected, &state.items);
 
     // write the number of matched terminals
     write_u16(writer, matched.len() as u16)?;
@@ -137,7 +150,7 @@ fn write_lexer_data_state(
     // write the number of non-cached transitions
     write_u16(writer, slow.len() as u16)?;
     // write the matched terminals
-    for (context, index) in contexts.into_iter().zip(matched.into_iter()) {
+    for (context, index) in matched {
         write_u16(writer, context as u16)?;
         write_u16(writer, index as u16)?;
     }
@@ -154,3 +167,194 @@ fn write_lexer_data_state(
     }
     Ok(())
 }
+
+/// Gets the terminals matched by a DFA state, as pairs of (context, index in the expected terminals)
+/// For each context, this is the terminal with the most priority
+fn get_matched_terminals(
+    grammar: &Grammar,
+    expected: &TerminalSet,
+    items: &[FinalItem],
+) -> Vec<(usize, usize)> {
+    let mut matched: Vec<(usize, usize)> = Vec::new();
+    for item in items {
+        let terminal = grammar.get_terminal(item.sid()).unwrap();
+        let terminal_ref = TerminalRef::Terminal(terminal.id);
+        if matched
+            .iter()
+            .all(|(context, _)| *context != terminal.context)
+        {
+            // this is the first time this context is found in the current DFA state
+            // this is the terminal with the most priority for this context
+            matched.push((
+                terminal.context,
+                expected
+                    .content
+                    .iter()
+                    .position(|t| t == &terminal_ref)
+                    .unwrap(),
+            ));
+        }
+    }
+    matched
+}
+
+/// A state of a lexer's automaton on Unicode scalar values
+struct CharState<'a> {
+    /// The items on the original DFA state
+    items: &'a [FinalItem],
+    /// The transitions, as (begin, end, target) with both bounds included, sorted and disjoint
+    transitions: Vec<(u32, u32, usize)>,
+}
+
+/// Decodes a surrogate pair into a Unicode scalar value
+fn decode_surrogates(high: u16, low: u16) -> u32 {
+    0x10000 + ((u32::from(high) - 0xD800) << 10) + (u32::from(low) - 0xDC00)
+}
+
+/// Translates the DFA on UTF-16 code units into an automaton on Unicode scalar values
+/// Transitions on high surrogates are fused with the transitions on low surrogates of their target state.
+/// The states that are only reachable through a high surrogate are dropped.
+fn get_char_states(dfa: &DFA) -> Vec<CharState<'_>> {
+    let mut translated: Vec<Vec<(u32, u32, usize)>> = Vec::with_capacity(dfa.states.len());
+    for state in &dfa.states {
+        let mut transitions = Vec::new();
+        for (span, next) in &state.transitions {
+            let (begin, end) = (span.begin, span.end);
+            // plane 0 parts, outside the surrogates
+            if begin < 0xD800 {
+                transitions.push((u32::from(begin), u32::from(end.min(0xD7FF)), *next));
+            }
+            if end > 0xDFFF {
+                transitions.push((u32::from(begin.max(0xE000)), u32::from(end), *next));
+            }
+            // high surrogates, followed by low surrogates on the next state
+            if begin <= 0xDBFF && end >= 0xD800 {
+                let lows: Vec<(CharSpan, usize)> = dfa.states[*next]
+                    .transitions
+                    .iter()
+                    .filter(|(low, _)| low.begin <= 0xDFFF && low.end >= 0xDC00)
+                    .map(|(low, target)| {
+                        (
+                            CharSpan::new(low.begin.max(0xDC00), low.end.min(0xDFFF)),
+                            *target,
+                        )
+                    })
+                    .collect();
+                for high in begin.max(0xD800)..=end.min(0xDBFF) {
+                    for (low, target) in &lows {
+                        transitions.push((
+                            decode_surrogates(high, low.begin),
+                            decode_surrogates(high, low.end),
+                            *target,
+                        ));
+                    }
+                }
+            }
+            // lone low surrogates cannot appear in a valid string and are dropped
+        }
+        transitions.sort_unstable();
+        // merge the adjacent ranges with the same target
+        let mut merged: Vec<(u32, u32, usize)> = Vec::with_capacity(transitions.len());
+        for (begin, end, next) in transitions {
+            match merged.last_mut() {
+                Some(last) if last.2 == next && last.1 + 1 == begin => last.1 = end,
+                _ => merged.push((begin, end, next)),
+            }
+        }
+        translated.push(merged);
+    }
+    // keep only the states reachable from the initial state and renumber them
+    let mut renumbering = vec![usize::MAX; dfa.states.len()];
+    let mut order = vec![0];
+    renumbering[0] = 0;
+    let mut i = 0;
+    while i < order.len() {
+        for (_, _, next) in &translated[order[i]] {
+            if renumbering[*next] == usize::MAX {
+                renumbering[*next] = order.len();
+                order.push(*next);
+            }
+        }
+        i += 1;
+    }
+    order
+        .into_iter()
+        .map(|index| CharState {
+            items: &dfa.states[index].items,
+            transitions: translated[index]
+                .iter()
+                .map(|(begin, end, next)| (*begin, *end, renumbering[*next]))
+                .collect(),
+        })
+        .collect()
+}
+
+/// Writes the lexer's data for an automaton on Unicode scalar values
+/// The layout is the same as for UTF-16 code units, except that all the cells are u32
+pub fn write_lexer_data_chars(
+    writer: &mut dyn Write,
+    grammar: &Grammar,
+    dfa: &DFA,
+    expected: &TerminalSet,
+) -> Result<(), Error> {
+    let states = get_char_states(dfa);
+    // write number of states
+    write_u32(writer, states.len() as u32)?;
+    // write the offsets to all the states
+    let mut offset: u32 = 0;
+    for state in &states {
+        write_u32(writer, offset)?;
+        // header + transitions for [0-255] characters
+        offset += 3 + 256;
+        // context information
+        offset += 2 * get_matched_terminals(grammar, expected, state.items).len() as u32;
+        for (_, end, _) in &state.transitions {
+            if *end >= 256 {
+                // transition outside the [0-255] range
+                offset += 3;
+            }
+        }
+    }
+    // write each state
+    for state in &states {
+        let mut cache = [DEAD_STATE; 256];
+        let mut cached: u32 = 0; // number of cached transitions
+        let mut slow = Vec::new();
+        for (begin, end, next) in &state.transitions {
+            if *begin <= 255 {
+                cached += 1;
+                if *end >= 256 {
+                    slow.push((256, *end, *next));
+                }
+                for i in *begin..=(*end).min(255) {
+                    cache[i as usize] = *next as u32;
+                }
+            } else {
+                slow.push((*begin, *end, *next));
+            }
+        }
+        let matched = get_matched_terminals(grammar, expected, state.items);
+        // write the number of matched terminals
+        write_u32(writer, matched.len() as u32)?;
+        // write the total number of transitions
+        write_u32(writer, slow.len() as u32 + cached)?;
+        // write the number of non-cached transitions
+        write_u32(writer, slow.len() as u32)?;
+        // write the matched terminals
+        for (context, index) in matched {
+            write_u32(writer, context as u32)?;
+            write_u32(writer, index as u32)?;
+        }
+        // write the cached transitions
+        for value in &cache {
+            write_u32(writer, *value)?;
+        }
+        // write the non-cached transitions
+        for (begin, end, next) in slow {
+            write_u32(writer, begin)?;
+            write_u32(writer, end)?;
+            write_u32(writer, next as u32)?;
+        }
+    }
+    Ok(())
+}
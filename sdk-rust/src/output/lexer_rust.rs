@@ -21,10 +21,16 @@ use std::fs::File;
 use std::io::{self, Write};
 use std::path::PathBuf;
 
+use hime_redist::lexers::automaton::AutomatonUnit;
+use hime_redist::lexers::LexerModeAction;
+
 use crate::errors::Error;
-use crate::grammars::{Grammar, TerminalRef, TerminalSet, PREFIX_GENERATED_TERMINAL};
+use crate::grammars::{
+    Grammar, IndentationSettings, TerminalRef, TerminalSet, PREFIX_GENERATED_TERMINAL,
+};
 use crate::output::get_lexer_bin_name_rust;
 use crate::output::helper::{to_upper_camel_case, to_upper_case};
+use crate::output::lexer_data::get_lexer_mode_actions;
 use crate::CRATE_VERSION;
 
 /// Generates code for the specified file
@@ -39,10 +45,13 @@ pub fn write(
     grammar: &Grammar,
     expected: &TerminalSet,
     separator: Option<TerminalRef>,
+    indentation: Option<&IndentationSettings>,
+    has_injections: bool,
     is_rnglr: bool,
     with_std: bool,
     suppress_module_doc: bool,
     compress_automata: bool,
+    lexer_unit: AutomatonUnit,
 ) -> Result<(), Error> {
     let mut final_path = PathBuf::new();
     if let Some(path) = path {
@@ -58,6 +67,11 @@ pub fn write(
     } else {
         "ContextFree"
     };
+    let mode_actions = if grammar.contexts.len() > 1 {
+        get_lexer_mode_actions(grammar, expected)
+    } else {
+        Vec::new()
+    };
     let bin_name = get_lexer_bin_name_rust(grammar);
     let separator = match separator {
         None => 0xFFFF,
@@ -76,14 +90,33 @@ pub fn write(
     }
 
     if !with_std {
+        writeln!(writer, "use alloc::boxed::Box;")?;
         writeln!(writer, "use alloc::string::String;")?;
     }
 
     writeln!(writer, "use hime_redist::ast::{{AstImpl, AstNode}};")?;
     writeln!(writer, "use hime_redist::errors::ParseErrors;")?;
     writeln!(writer, "use hime_redist::lexers::automaton::Automaton;")?;
+    writeln!(writer, "use hime_redist::lexers::filter::FilteredSource;")?;
     writeln!(writer, "use hime_redist::lexers::impls::{base_lexer}Lexer;")?;
+    if indentation.is_some() {
+        writeln!(
+            writer,
+            "use hime_redist::lexers::indentation::IndentationConfig;"
+        )?;
+    }
+    if grammar.has_external_terminals() {
+        writeln!(writer, "use hime_redist::lexers::ExternalScanner;")?;
+    }
     writeln!(writer, "use hime_redist::lexers::Lexer;")?;
+    if !mode_actions.is_empty() {
+        writeln!(writer, "use hime_redist::lexers::LexerModeAction;")?;
+    }
+    writeln!(writer, "use hime_redist::lexers::ParseHooks;")?;
+    writeln!(writer, "use hime_redist::lexers::TokenSource;")?;
+    if grammar.contexts.len() > 1 {
+        writeln!(writer, "use hime_redist::lexers::UserContextProvider;")?;
+    }
     if is_rnglr {
         writeln!(writer, "use hime_redist::parsers::rnglr::RNGLRAutomaton;")?;
         writeln!(writer, "use hime_redist::parsers::rnglr::RNGLRParser;")?;
@@ -94,12 +127,20 @@ pub fn write(
     writeln!(writer, "use hime_redist::parsers::Parser;")?;
     writeln!(
         writer,
-        "use hime_redist::result::{{ParseResult, ParseResultAst{}}};",
+        "use hime_redist::result::{{{}ParseResult, ParseResultAst{}}};",
+        if has_injections {
+            "InjectionOffsets, "
+        } else {
+            ""
+        },
         if is_rnglr { ", ParseResultSppf" } else { "" }
     )?;
     if is_rnglr {
         writeln!(writer, "use hime_redist::sppf::SppfImpl;")?;
     }
+    if has_injections {
+        writeln!(writer, "use hime_redist::symbols::concat_symbols;")?;
+    }
     writeln!(writer, "use hime_redist::symbols::SemanticBody;")?;
     writeln!(writer, "use hime_redist::symbols::SemanticElementTrait;")?;
     writeln!(writer, "use hime_redist::symbols::Symbol;")?;
@@ -190,6 +231,44 @@ pub fn write(
     writeln!(writer, "];")?;
     writeln!(writer)?;
 
+    if !mode_actions.is_empty() {
+        writeln!(
+            writer,
+            "/// The actions on the lexer's stack of modes for each terminal, in the same order as the terminals"
+        )?;
+        writeln!(writer, "const LEXER_MODE_ACTIONS: &[LexerModeAction] = &[")?;
+        for action in &mode_actions {
+            match action {
+                LexerModeAction::None => writeln!(writer, "    LexerModeAction::None,")?,
+                LexerModeAction::Push(context) => {
+                    writeln!(writer, "    LexerModeAction::Push(0x{context:04X}),")?;
+                }
+                LexerModeAction::Pop => writeln!(writer, "    LexerModeAction::Pop,")?,
+            }
+        }
+        writeln!(writer, "];")?;
+        writeln!(writer)?;
+    }
+
+    if let Some(indentation) = indentation {
+        writeln!(
+            writer,
+            "/// The configuration for the indentation-sensitive lexing"
+        )?;
+        writeln!(
+            writer,
+            "const INDENTATION: IndentationConfig = IndentationConfig {{"
+        )?;
+        writeln!(writer, "    indent_id: 0x{:04X},", indentation.indent)?;
+        writeln!(writer, "    dedent_id: 0x{:04X},", indentation.dedent)?;
+        writeln!(writer, "    newline_id: 0x{:04X},", indentation.newline)?;
+        writeln!(writer, "    tab_width: {},", indentation.tab_width)?;
+        writeln!(writer, "    blank_lines: {},", indentation.blank_lines)?;
+        writeln!(writer, "    brackets: {:?},", indentation.brackets)?;
+        writeln!(writer, "}};")?;
+        writeln!(writer)?;
+    }
+
     writeln!(writer, "/// Creates a new lexer")?;
     writeln!(writer, "fn new_lexer<'a: 'b, 'b, 'c>(")?;
     writeln!(writer, "    repository: TokenRepository<'a, 'b, 'c>,")?;
@@ -197,13 +276,32 @@ pub fn write(
     writeln!(writer, ") -> Lexer<'a, 'b, 'c> {{")?;
     writeln!(
         writer,
-        "    let automaton = Automaton::new(LEXER_AUTOMATON{});",
+        "    let automaton = Automaton::{}(LEXER_AUTOMATON{});",
+        match lexer_unit {
+            AutomatonUnit::Utf16 => "new",
+            AutomatonUnit::Char => "new_chars",
+            AutomatonUnit::Byte => "new_bytes",
+        },
         if compress_automata { ".as_ref()" } else { "" }
     )?;
-    writeln!(
-        writer,
-        "    Lexer::{base_lexer}({base_lexer}Lexer::new(repository, errors, automaton, 0x{separator:04X}))"
-    )?;
+    if mode_actions.is_empty() && indentation.is_none() {
+        writeln!(
+            writer,
+            "    Lexer::{base_lexer}({base_lexer}Lexer::new(repository, errors, automaton, 0x{separator:04X}))"
+        )?;
+    } else {
+        writeln!(
+            writer,
+            "    let mut lexer = {base_lexer}Lexer::new(repository, errors, automaton, 0x{separator:04X});"
+        )?;
+        if !mode_actions.is_empty() {
+            writeln!(writer, "    lexer.set_mode_actions(LEXER_MODE_ACTIONS);")?;
+        }
+        if indentation.is_some() {
+            writeln!(writer, "    lexer.set_indentation(INDENTATION);")?;
+        }
+        writeln!(writer, "    Lexer::{base_lexer}(lexer)")?;
+    }
     writeln!(writer, "}}")?;
     writeln!(writer)?;
     Ok(())
@@ -35,7 +35,7 @@ use std::fs::File;
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 
-use hime_redist::lexers::automaton::Automaton;
+use hime_redist::lexers::automaton::{Automaton, AutomatonUnit};
 use hime_redist::parsers::lrk::LRkAutomaton;
 use hime_redist::parsers::rnglr::RNGLRAutomaton;
 use hime_redist::symbols::Symbol;
@@ -43,7 +43,10 @@ use rand::distributions::Alphanumeric;
 use rand::{thread_rng, Rng};
 
 use crate::errors::Error;
-use crate::grammars::{BuildData, Grammar};
+use crate::grammars::{
+    BuildData, Grammar, OPTION_INDENTATION, OPTION_INJECT, OPTION_INPUT, OPTION_LOOKAHEAD,
+    OPTION_RUNTIME,
+};
 use crate::sdk::{InMemoryParser, ParserAutomaton};
 use crate::{CompilationTask, ParsingMethod, Runtime};
 
@@ -84,17 +87,64 @@ pub fn output_grammar_artifacts(
 
     // write data
     let output_path = task.get_output_path_for(grammar);
+    if grammar.is_byte_grammar() && runtime != Runtime::Rust {
+        return Err(vec![Error::InvalidOption(
+            grammar_index,
+            OPTION_INPUT.to_string(),
+            vec![String::from("text")],
+        )]);
+    }
+    if data.lookahead > 1 && runtime != Runtime::Rust {
+        return Err(vec![Error::InvalidOption(
+            grammar_index,
+            OPTION_LOOKAHEAD.to_string(),
+            vec![String::from("1")],
+        )]);
+    }
+    if grammar.has_lexer_modes() && runtime != Runtime::Rust {
+        return Err(vec![Error::InvalidOption(
+            grammar_index,
+            OPTION_RUNTIME.to_string(),
+            vec![String::from("rust")],
+        )]);
+    }
+    if grammar.has_external_terminals() && runtime != Runtime::Rust {
+        return Err(vec![Error::InvalidOption(
+            grammar_index,
+            OPTION_RUNTIME.to_string(),
+            vec![String::from("rust")],
+        )]);
+    }
+    if data.indentation.is_some() && runtime != Runtime::Rust {
+        return Err(vec![Error::InvalidOption(
+            grammar_index,
+            OPTION_INDENTATION.to_string(),
+            vec![String::from("false")],
+        )]);
+    }
+    if !data.injections.is_empty() && runtime != Runtime::Rust {
+        return Err(vec![Error::InvalidOption(
+            grammar_index,
+            OPTION_INJECT.to_string(),
+            Vec::new(),
+        )]);
+    }
+    let lexer_unit = get_lexer_unit(
+        grammar,
+        runtime == Runtime::Rust && task.get_rust_char_lexer(),
+    );
     if let Err(error) = lexer_data::write_lexer_data_file(
         output_path.as_ref(),
         get_lexer_bin_name(grammar, runtime),
         grammar,
         &data.dfa,
         &data.expected,
+        lexer_unit == AutomatonUnit::Char,
     ) {
         return Err(vec![error]);
     }
     if let Err(error) = match data.method {
-        ParsingMethod::LR0 | ParsingMethod::LR1 | ParsingMethod::LALR1 => {
+        ParsingMethod::LR0 | ParsingMethod::LR1 | ParsingMethod::LALR1 | ParsingMethod::IELR1 => {
             parser_data::write_parser_lrk_data_file(
                 output_path.as_ref(),
                 get_parser_bin_name(grammar, runtime),
@@ -103,15 +153,16 @@ pub fn output_grammar_artifacts(
                 &data.graph,
             )
         }
-        ParsingMethod::RNGLR1 | ParsingMethod::RNGLALR1 => {
-            parser_data::write_parser_rnglr_data_file(
-                output_path.as_ref(),
-                get_parser_bin_name(grammar, runtime),
-                grammar,
-                &data.expected,
-                &data.graph,
-            )
-        }
+        ParsingMethod::RNGLR1
+        | ParsingMethod::RNGLALR1
+        | ParsingMethod::HLR1
+        | ParsingMethod::HLALR1 => parser_data::write_parser_rnglr_data_file(
+            output_path.as_ref(),
+            get_parser_bin_name(grammar, runtime),
+            grammar,
+            &data.expected,
+            &data.graph,
+        ),
     } {
         return Err(vec![error]);
     }
@@ -175,10 +226,13 @@ pub fn output_grammar_artifacts(
                 grammar,
                 &data.expected,
                 data.separator,
+                data.indentation.as_ref(),
+                !data.injections.is_empty(),
                 data.method.is_rnglr(),
                 with_std,
                 suppress_module_doc,
                 compress_automata,
+                lexer_unit,
             ) {
                 return Err(vec![error]);
             }
@@ -188,6 +242,7 @@ pub fn output_grammar_artifacts(
                 grammar,
                 &data.expected,
                 data.method,
+                &data.injections,
                 &nmspace,
                 mode.output_assembly(),
                 with_std,
@@ -200,7 +255,21 @@ pub fn output_grammar_artifacts(
     Ok(())
 }
 
+/// Gets the unit of the input consumed by the lexer for a grammar
+/// The lexer of a grammar over bytes always consumes bytes,
+/// otherwise when `char_lexer` is set, the lexer consumes Unicode scalar values instead of UTF-16 code units.
+fn get_lexer_unit(grammar: &Grammar, char_lexer: bool) -> AutomatonUnit {
+    if grammar.is_byte_grammar() {
+        AutomatonUnit::Byte
+    } else if char_lexer {
+        AutomatonUnit::Char
+    } else {
+        AutomatonUnit::Utf16
+    }
+}
+
 /// Builds the in-memory parser for a grammar
+/// When `char_lexer` is set, the lexer consumes Unicode scalar values instead of UTF-16 code units
 ///
 /// # Errors
 ///
@@ -208,6 +277,7 @@ pub fn output_grammar_artifacts(
 pub fn build_in_memory_grammar<'a>(
     grammar: &'a Grammar,
     data: &BuildData,
+    char_lexer: bool,
 ) -> Result<InMemoryParser<'a>, Vec<Error>> {
     // get symbols
     let mut terminals: Vec<Symbol<'a>> = vec![
@@ -244,24 +314,35 @@ pub fn build_in_memory_grammar<'a>(
             name: &symbol.name,
         })
         .collect();
+    let axioms: Vec<Symbol<'a>> = grammar
+        .get_axioms()
+        .into_iter()
+        .map(|variable| Symbol {
+            id: variable.id as u32,
+            name: &variable.name,
+        })
+        .collect();
 
     // build automata
+    let lexer_unit = get_lexer_unit(grammar, char_lexer);
     let mut lexer_automaton = Vec::new();
-    if let Err(error) =
+    if let Err(error) = if lexer_unit == AutomatonUnit::Char {
+        lexer_data::write_lexer_data_chars(&mut lexer_automaton, grammar, &data.dfa, &data.expected)
+    } else {
         lexer_data::write_lexer_data(&mut lexer_automaton, grammar, &data.dfa, &data.expected)
-    {
+    } {
         return Err(vec![error]);
     }
     let mut parser_automaton = Vec::new();
     if let Err(error) = if data.method.is_rnglr() {
-        parser_data::write_parser_lrk_data(
+        parser_data::write_parser_rnglr_data(
             &mut parser_automaton,
             grammar,
             &data.expected,
             &data.graph,
         )
     } else {
-        parser_data::write_parser_rnglr_data(
+        parser_data::write_parser_lrk_data(
             &mut parser_automaton,
             grammar,
             &data.expected,
@@ -276,17 +357,25 @@ pub fn build_in_memory_grammar<'a>(
         terminals,
         variables,
         virtuals,
+        axioms,
         separator: match data.separator {
             None => 0xFFFF,
             Some(terminal_ref) => terminal_ref.sid() as u32,
         },
-        lexer_automaton: Automaton::new(&lexer_automaton),
+        lexer_automaton: match lexer_unit {
+            AutomatonUnit::Utf16 => Automaton::new(&lexer_automaton),
+            AutomatonUnit::Char => Automaton::new_chars(&lexer_automaton),
+            AutomatonUnit::Byte => Automaton::new_bytes(&lexer_automaton),
+        },
         lexer_is_context_sensitive: grammar.contexts.len() > 1,
+        lexer_mode_actions: lexer_data::get_lexer_mode_actions(grammar, &data.expected),
+        lexer_indentation: data.indentation.clone(),
         parser_automaton: if data.method.is_rnglr() {
             ParserAutomaton::Rnglr(RNGLRAutomaton::new(&parser_automaton))
         } else {
             ParserAutomaton::Lrk(LRkAutomaton::new(&parser_automaton))
         },
+        parser_is_hybrid: data.method.is_hybrid(),
     })
 }
 
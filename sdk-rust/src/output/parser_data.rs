@@ -23,16 +23,16 @@ use std::io::{self, Write};
 use std::path::PathBuf;
 
 use hime_redist::parsers::{
-    LR_ACTION_CODE_ACCEPT, LR_ACTION_CODE_NONE, LR_ACTION_CODE_REDUCE, LR_ACTION_CODE_SHIFT,
-    LR_OP_CODE_BASE_ADD_NULLABLE_VARIABLE, LR_OP_CODE_BASE_ADD_VIRTUAL, LR_OP_CODE_BASE_POP_STACK,
-    LR_OP_CODE_BASE_SEMANTIC_ACTION,
+    LR_ACTION_CODE_ACCEPT, LR_ACTION_CODE_LOOKAHEAD, LR_ACTION_CODE_NONE, LR_ACTION_CODE_REDUCE,
+    LR_ACTION_CODE_SHIFT, LR_OP_CODE_BASE_ADD_NULLABLE_VARIABLE, LR_OP_CODE_BASE_ADD_VIRTUAL,
+    LR_OP_CODE_BASE_POP_STACK, LR_OP_CODE_BASE_SEMANTIC_ACTION,
 };
 
 use crate::errors::Error;
 use crate::grammars::{
     Grammar, Rule, RuleRef, SymbolRef, TerminalRef, TerminalSet, GENERATED_AXIOM,
 };
-use crate::lr::{Graph, State};
+use crate::lr::{Graph, LookaheadDecision, State};
 use crate::output::helper::{write_u16, write_u32, write_u8};
 
 /// Writes the data for a LR(k) parser
@@ -77,8 +77,9 @@ pub fn write_parser_lrk_data(
     write_parser_opening_contexts(writer, graph)?;
 
     // write the LR table
+    let mut nodes = Vec::new();
     for state in &graph.states {
-        write_parser_lrk_data_state(writer, grammar, expected, &rules, state)?;
+        write_parser_lrk_data_state(writer, grammar, expected, &rules, state, &mut nodes)?;
     }
     // write production rules
     for variable in &grammar.variables {
@@ -86,9 +87,56 @@ pub fn write_parser_lrk_data(
             write_parser_lrk_data_rule(writer, grammar, rule)?;
         }
     }
+    // write the lookahead nodes, only when there are some
+    if !nodes.is_empty() {
+        write_u16(writer, nodes.len() as u16)?;
+        for node in &nodes {
+            write_u16(writer, node.len() as u16)?;
+            for (sid, code, data) in node {
+                write_u16(writer, *sid)?;
+                write_u16(writer, *code)?;
+                write_u16(writer, *data)?;
+            }
+        }
+    }
     Ok(())
 }
 
+/// A node for deciding on the next token, as a list of (terminal sid, action code, action data)
+type LookaheadNode = Vec<(u16, u16, u16)>;
+
+/// Flattens a decision on the next tokens into lookahead nodes
+/// The shifts in the decision are for the current token,
+/// the shifted state is then the child of the state for the current terminal.
+/// Returns the index of the node for the decision.
+fn write_parser_lrk_data_decision(
+    rules: &[RuleRef],
+    shifted: u16,
+    branches: &[(TerminalRef, LookaheadDecision)],
+    nodes: &mut Vec<LookaheadNode>,
+) -> u16 {
+    let index = nodes.len();
+    nodes.push(Vec::new());
+    let mut node = Vec::with_capacity(branches.len());
+    for (terminal, decision) in branches {
+        let (code, data) = match decision {
+            LookaheadDecision::Shift => (LR_ACTION_CODE_SHIFT, shifted),
+            LookaheadDecision::Error => (LR_ACTION_CODE_NONE, 0),
+            LookaheadDecision::Reduce(rule, _) => (
+                LR_ACTION_CODE_REDUCE,
+                rules.iter().position(|r| r == rule).unwrap() as u16,
+            ),
+            LookaheadDecision::Next(next) => (
+                LR_ACTION_CODE_LOOKAHEAD,
+                write_parser_lrk_data_decision(rules, shifted, next, nodes),
+            ),
+        };
+        node.push((terminal.sid() as u16, code, data));
+    }
+    nodes[index] = node;
+    index as u16
+}
+
 /// Writes the column headers for a parser data
 fn write_parser_column_headers(
     writer: &mut dyn Write,
@@ -132,6 +180,7 @@ fn write_parser_lrk_data_state(
     expected: &TerminalSet,
     rules: &[RuleRef],
     state: &State,
+    nodes: &mut Vec<LookaheadNode>,
 ) -> Result<(), Error> {
     // write action on epsilon
     if state.get_reduction_for(TerminalRef::Epsilon).is_some()
@@ -145,7 +194,15 @@ fn write_parser_lrk_data_state(
     // write actions for terminals
     for terminal in expected.content.iter().skip(1) {
         let terminal = *terminal;
-        if let Some(next) = state.children.get(&terminal.into()) {
+        if let Some(LookaheadDecision::Next(branches)) = state.decisions.get(&terminal) {
+            let shifted = state
+                .children
+                .get(&terminal.into())
+                .map_or(0, |next| *next as u16);
+            let node = write_parser_lrk_data_decision(rules, shifted, branches, nodes);
+            write_u16(writer, LR_ACTION_CODE_LOOKAHEAD)?;
+            write_u16(writer, node)?;
+        } else if let Some(next) = state.children.get(&terminal.into()) {
             write_u16(writer, LR_ACTION_CODE_SHIFT)?;
             write_u16(writer, *next as u16)?;
         } else if let Some(reduction) = state.get_reduction_for(terminal) {
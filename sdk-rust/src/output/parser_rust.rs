@@ -22,7 +22,9 @@ use std::io::{self, Write};
 use std::path::PathBuf;
 
 use crate::errors::Error;
-use crate::grammars::{Grammar, TerminalSet, PREFIX_GENERATED_TERMINAL, PREFIX_GENERATED_VARIABLE};
+use crate::grammars::{
+    Grammar, Injection, TerminalSet, PREFIX_GENERATED_TERMINAL, PREFIX_GENERATED_VARIABLE,
+};
 use crate::output::get_parser_bin_name_rust;
 use crate::output::helper::{to_snake_case, to_upper_case};
 use crate::ParsingMethod;
@@ -35,6 +37,7 @@ pub fn write(
     grammar: &Grammar,
     expected: &TerminalSet,
     method: ParsingMethod,
+    injections: &[Injection],
     nmespace: &str,
     output_assembly: bool,
     with_std: bool,
@@ -78,6 +81,7 @@ pub fn write(
     write_code_symbols(&mut writer, grammar)?;
     write_code_variables(&mut writer, grammar)?;
     write_code_virtuals(&mut writer, grammar)?;
+    write_code_injections(&mut writer, injections)?;
     write_code_actions(&mut writer, grammar)?;
     write_code_constructors(
         &mut writer,
@@ -90,6 +94,8 @@ pub fn write(
         "AstImpl",
         "ParseResultAst",
         "",
+        !injections.is_empty(),
+        method.is_hybrid(),
         with_std,
         compress_automata,
     )?;
@@ -106,11 +112,13 @@ pub fn write(
             "SppfImpl",
             "ParseResultSppf",
             "_to_sppf",
+            false,
+            method.is_hybrid(),
             with_std,
             compress_automata,
         )?;
     }
-    write_code_visitor(&mut writer, grammar, expected)?;
+    write_code_visitor(&mut writer, grammar, expected, injections)?;
     Ok(())
 }
 
@@ -205,6 +213,99 @@ fn write_code_virtuals(writer: &mut dyn Write, grammar: &Grammar) -> Result<(),
     Ok(())
 }
 
+/// Generates the code for the injected languages
+/// The symbol tables of the injected grammars are appended to the ones of this grammar
+/// and the parsers of the injected grammars are expected in sibling modules.
+fn write_code_injections(writer: &mut dyn Write, injections: &[Injection]) -> Result<(), Error> {
+    if injections.is_empty() {
+        return Ok(());
+    }
+    let mut grammars: Vec<&str> = Vec::new();
+    for injection in injections {
+        if !grammars.contains(&injection.grammar.as_str()) {
+            grammars.push(&injection.grammar);
+        }
+    }
+    for (index, grammar) in grammars.iter().enumerate() {
+        writeln!(
+            writer,
+            "/// The offsets of the symbols of the injected grammar `{grammar}` in the symbol tables"
+        )?;
+        writeln!(
+            writer,
+            "const INJECTION_{}: InjectionOffsets = InjectionOffsets {{",
+            to_upper_case(grammar)
+        )?;
+        for table in ["terminals", "variables", "virtuals"] {
+            let upper = to_upper_case(table);
+            write!(writer, "    {table}: {upper}.len()")?;
+            for previous in &grammars[..index] {
+                write!(
+                    writer,
+                    " + super::{}::{upper}.len()",
+                    to_snake_case(previous)
+                )?;
+            }
+            writeln!(writer, ",")?;
+        }
+        writeln!(writer, "}};")?;
+        writeln!(writer)?;
+    }
+    for table in ["terminals", "variables", "virtuals"] {
+        let upper = to_upper_case(table);
+        writeln!(
+            writer,
+            "/// The collection of {table}, including the ones of the injected grammars"
+        )?;
+        write!(
+            writer,
+            "pub const ALL_{upper}: &[Symbol] = &concat_symbols::<{{ {upper}.len()"
+        )?;
+        for grammar in &grammars {
+            write!(
+                writer,
+                " + super::{}::{upper}.len()",
+                to_snake_case(grammar)
+            )?;
+        }
+        write!(writer, " }}>(&[{upper}")?;
+        for grammar in &grammars {
+            write!(writer, ", super::{}::{upper}", to_snake_case(grammar))?;
+        }
+        writeln!(writer, "]);")?;
+        writeln!(writer)?;
+    }
+    writeln!(
+        writer,
+        "/// Parses the values of the tokens in the injected languages and grafts the results"
+    )?;
+    writeln!(
+        writer,
+        "/// The parsers of the injected languages are expected in sibling modules, e.g. `super::{}`.",
+        to_snake_case(grammars[0])
+    )?;
+    writeln!(
+        writer,
+        "fn inject(result: &mut ParseResult<'_, '_, '_, AstImpl>) {{"
+    )?;
+    for injection in injections {
+        let function = match &injection.axiom {
+            Some(axiom) => format!("parse_axiom_{}", to_snake_case(axiom)),
+            None => String::from("parse_str"),
+        };
+        writeln!(
+            writer,
+            "    result.inject(0x{:04X}, &INJECTION_{}, super::{}::{function});",
+            injection.terminal,
+            to_upper_case(&injection.grammar),
+            to_snake_case(&injection.grammar)
+        )?;
+    }
+    writeln!(writer, "}}")?;
+    writeln!(writer)?;
+    Ok(())
+}
+
 /// Generates the code for the semantic actions
 fn write_code_actions(writer: &mut dyn Write, grammar: &Grammar) -> Result<(), Error> {
     if grammar.actions.is_empty() {
@@ -235,7 +336,11 @@ fn write_code_actions(writer: &mut dyn Write, grammar: &Grammar) -> Result<(), E
 }
 
 /// Generates the code for the constructors
-#[allow(clippy::too_many_lines, clippy::too_many_arguments)]
+#[allow(
+    clippy::too_many_lines,
+    clippy::too_many_arguments,
+    clippy::fn_params_excessive_bools
+)]
 fn write_code_constructors(
     writer: &mut dyn Write,
     grammar: &Grammar,
@@ -247,10 +352,19 @@ fn write_code_constructors(
     tree_type: &str,
     parse_result_type: &str,
     fn_suffix: &str,
+    has_injections: bool,
+    is_hybrid: bool,
     with_std: bool,
     compress_automata: bool,
 ) -> Result<(), Error> {
     let has_actions = !grammar.actions.is_empty();
+    let tables = if has_injections {
+        "ALL_TERMINALS, ALL_VARIABLES, ALL_VIRTUALS"
+    } else {
+        "TERMINALS, VARIABLES, VIRTUALS"
+    };
+    let has_contexts = grammar.contexts.len() > 1;
+    let has_external = grammar.has_external_terminals();
     writeln!(writer, "/// Parses the specified string with this parser")?;
     if output_assembly {
         writeln!(writer, "#[no_mangle]")?;
@@ -338,6 +452,58 @@ fn write_code_constructors(
         writeln!(writer, "}}")?;
     }
 
+    if grammar.is_byte_grammar() {
+        writeln!(writer)?;
+        writeln!(
+            writer,
+            "/// Parses the specified binary input with this parser"
+        )?;
+        if output_assembly {
+            writeln!(writer, "#[no_mangle]")?;
+            writeln!(
+                writer,
+                "#[export_name = \"{nmespace}_parse_bytes{fn_suffix}\"]"
+            )?;
+        }
+        writeln!(writer, "#[must_use]")?;
+        writeln!(
+            writer,
+            "pub fn parse_bytes{fn_suffix}(input: &[u8]) -> ParseResult<'static, '_, 'static, {tree_type}> {{"
+        )?;
+        writeln!(writer, "    let text = Text::from_bytes(input);")?;
+        writeln!(
+            writer,
+            "    parse_text{fn_suffix}(text{})",
+            if has_actions {
+                ", &mut NoActions {}"
+            } else {
+                ""
+            }
+        )?;
+        writeln!(writer, "}}")?;
+        if has_actions {
+            writeln!(writer)?;
+            writeln!(
+                writer,
+                "/// Parses the specified binary input with this parser"
+            )?;
+            if output_assembly {
+                writeln!(writer, "#[no_mangle]")?;
+                writeln!(
+                    writer,
+                    "#[export_name = \"{nmespace}_parse_bytes{fn_suffix}_with\"]"
+                )?;
+            }
+            writeln!(
+                writer,
+                "pub fn parse_bytes{fn_suffix}_with<'t>(input: &'t [u8], actions: &mut dyn Actions) -> ParseResult<'static, 't, 'static, {tree_type}> {{"
+            )?;
+            writeln!(writer, "    let text = Text::from_bytes(input);")?;
+            writeln!(writer, "    parse_text{fn_suffix}(text, actions)")?;
+            writeln!(writer, "}}")?;
+        }
+    }
+
     if with_std {
         writeln!(writer)?;
         writeln!(
@@ -395,11 +561,111 @@ fn write_code_constructors(
         }
     }
 
+    let axioms = grammar.get_axioms();
+    let has_axioms = axioms.len() > 1;
+    // the trailing arguments of parse_text_with, with the specified hooks and entry state
+    let hook_args = |hooks: &str, entry: usize| -> String {
+        if has_axioms {
+            format!(", {hooks}, {entry}")
+        } else {
+            format!(", {hooks}")
+        }
+    };
+    if has_contexts {
+        write_code_constructors_with_hook(
+            writer,
+            output_assembly,
+            nmespace,
+            tree_type,
+            parse_result_type,
+            fn_suffix,
+            has_actions,
+            tables,
+            (
+                "contexts",
+                "&dyn UserContextProvider",
+                "a user-defined context provider",
+            ),
+            &hook_args("ParseHooks::new().with_contexts(contexts)", 0),
+        )?;
+    }
+    if has_external {
+        write_code_constructors_with_hook(
+            writer,
+            output_assembly,
+            nmespace,
+            tree_type,
+            parse_result_type,
+            fn_suffix,
+            has_actions,
+            tables,
+            ("scanner", "&mut dyn ExternalScanner", "an external scanner"),
+            &hook_args("ParseHooks::new().with_scanner(scanner)", 0),
+        )?;
+    }
+    // the entry points are also generated for a single axiom, for the grammars that inject this one
+    for (entry, axiom) in axioms.iter().enumerate() {
+        write_code_axiom_constructors(
+            writer,
+            output_assembly,
+            nmespace,
+            tree_type,
+            fn_suffix,
+            has_actions,
+            tables,
+            &axiom.name,
+            &hook_args("ParseHooks::new()", entry),
+        )?;
+    }
+
     writeln!(writer)?;
     writeln!(writer, "/// Parses the specified text with this parser")?;
     writeln!(
         writer,
-        "fn parse_text{fn_suffix}<'t>(text: Text<'t>{}) -> ParseResult<'static, 't, 'static, {tree_type}> {{",
+        "/// The text may be configured for the computation of columns, see `Text::with_config`."
+    )?;
+    if !has_actions {
+        writeln!(writer, "#[must_use]")?;
+    }
+    writeln!(
+        writer,
+        "pub fn parse_text{fn_suffix}<'t>(text: Text<'t>{}) -> ParseResult<'static, 't, 'static, {tree_type}> {{",
+        if has_actions {
+            ", actions: &mut dyn Actions"
+        } else {
+            ""
+        }
+    )?;
+    writeln!(
+        writer,
+        "    parse_text{fn_suffix}_with(text, {tables}{}{})",
+        if has_actions { ", actions" } else { "" },
+        hook_args("ParseHooks::new()", 0)
+    )?;
+    writeln!(writer, "}}")?;
+
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "/// Parses the specified text with this parser, with hooks into the production of the tokens"
+    )?;
+    writeln!(
+        writer,
+        "/// The hooks combine a user-defined context provider, an external scanner and a filter of the tokens."
+    )?;
+    if has_axioms {
+        writeln!(
+            writer,
+            "/// The text is parsed as the first axiom, `{}`.",
+            axioms[0].name
+        )?;
+    }
+    if !has_actions {
+        writeln!(writer, "#[must_use]")?;
+    }
+    writeln!(
+        writer,
+        "pub fn parse_text{fn_suffix}_with_hooks<'t>(text: Text<'t>{}, hooks: ParseHooks) -> ParseResult<'static, 't, 'static, {tree_type}> {{",
         if has_actions {
             ", actions: &mut dyn Actions"
         } else {
@@ -408,8 +674,9 @@ fn write_code_constructors(
     )?;
     writeln!(
         writer,
-        "    parse_text{fn_suffix}_with(text, TERMINALS, VARIABLES, VIRTUALS{})",
-        if has_actions { ", actions" } else { "" }
+        "    parse_text{fn_suffix}_with(text, {tables}{}{})",
+        if has_actions { ", actions" } else { "" },
+        hook_args("hooks", 0)
     )?;
     writeln!(writer, "}}")?;
     writeln!(writer)?;
@@ -420,10 +687,139 @@ fn write_code_constructors(
     writeln!(writer, "    variables: &'a [Symbol<'s>],")?;
     writeln!(writer, "    virtuals: &'a [Symbol<'s>],")?;
     if has_actions {
-        writeln!(writer, "    actions: &mut dyn Actions")?;
+        writeln!(writer, "    actions: &mut dyn Actions,")?;
+    }
+    writeln!(writer, "    hooks: ParseHooks,")?;
+    if has_axioms {
+        writeln!(writer, "    entry: u32,")?;
     }
     writeln!(writer, ") -> ParseResult<'s, 't, 'a, {tree_type}> {{")?;
+    write_code_actions_closure(writer, grammar)?;
+    writeln!(
+        writer,
+        "    let mut result = ParseResult::<{tree_type}>::new(terminals, variables, virtuals, text);"
+    )?;
+    writeln!(writer, "    {{")?;
+    writeln!(writer, "        let data = result.get_parsing_data();")?;
+    writeln!(writer, "        let mut lexer = new_lexer(data.0, data.1);")?;
+    writeln!(writer, "        if let Some(contexts) = hooks.contexts {{")?;
+    writeln!(
+        writer,
+        "            lexer.set_user_context_provider(contexts);"
+    )?;
+    writeln!(writer, "        }}")?;
+    writeln!(writer, "        if let Some(scanner) = hooks.scanner {{")?;
+    writeln!(writer, "            lexer.set_external_scanner(scanner);")?;
+    writeln!(writer, "        }}")?;
+    writeln!(writer, "        let mut filtered;")?;
+    writeln!(
+        writer,
+        "        let source: &mut dyn TokenSource = if let Some(filter) = hooks.filter {{"
+    )?;
+    writeln!(
+        writer,
+        "            filtered = FilteredSource::new(lexer, filter);"
+    )?;
+    writeln!(writer, "            &mut filtered")?;
+    writeln!(writer, "        }} else {{")?;
+    writeln!(writer, "            &mut lexer")?;
+    writeln!(writer, "        }};")?;
+    writeln!(
+        writer,
+        "        let automaton = {automaton_type}::new(PARSER_AUTOMATON{});",
+        if compress_automata { ".as_ref()" } else { "" }
+    )?;
+    writeln!(
+        writer,
+        "        let mut parser = {parser_type}::{parser_ctor}(source, variables, virtuals, automaton, data.2, &mut my_actions);"
+    )?;
+    if has_axioms {
+        writeln!(writer, "        parser.set_entry_state(entry);")?;
+    }
+    if is_hybrid {
+        writeln!(writer, "        parser.set_hybrid(true);")?;
+    }
+    writeln!(writer, "        parser.parse();")?;
+    writeln!(writer, "    }}")?;
+    if has_injections {
+        writeln!(writer, "    inject(&mut result);")?;
+    }
+    writeln!(writer, "    result")?;
+    writeln!(writer, "}}")?;
+
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "/// Parses the specified text with this parser, using the tokens produced by a custom source"
+    )?;
+    writeln!(
+        writer,
+        "/// The source is created from the repository of tokens and the repository for errors of the result."
+    )?;
+    if has_axioms {
+        writeln!(
+            writer,
+            "/// The text is parsed as the axiom at the `entry` index, 0 for the first axiom `{}`.",
+            axioms[0].name
+        )?;
+    }
+    writeln!(writer, "pub fn parse_text{fn_suffix}_from<'t, F>(")?;
+    writeln!(writer, "    text: Text<'t>,")?;
     if has_actions {
+        writeln!(writer, "    actions: &mut dyn Actions,")?;
+    }
+    writeln!(writer, "    new_source: F,")?;
+    if has_axioms {
+        writeln!(writer, "    entry: u32,")?;
+    }
+    writeln!(
+        writer,
+        ") -> ParseResult<'static, 't, 'static, {tree_type}>"
+    )?;
+    writeln!(writer, "where")?;
+    writeln!(writer, "    F: for<'a> FnOnce(TokenRepository<'static, 't, 'a>, &'a mut ParseErrors<'static>) -> Box<dyn TokenSource<'static, 't, 'a> + 'a>,")?;
+    writeln!(writer, "{{")?;
+    write_code_actions_closure(writer, grammar)?;
+    writeln!(
+        writer,
+        "    let mut result = ParseResult::<{tree_type}>::new({tables}, text);"
+    )?;
+    writeln!(writer, "    {{")?;
+    writeln!(writer, "        let data = result.get_parsing_data();")?;
+    writeln!(
+        writer,
+        "        let mut source = new_source(data.0, data.1);"
+    )?;
+    writeln!(
+        writer,
+        "        let automaton = {automaton_type}::new(PARSER_AUTOMATON{});",
+        if compress_automata { ".as_ref()" } else { "" }
+    )?;
+    writeln!(
+        writer,
+        "        let mut parser = {parser_type}::{parser_ctor}(source.as_mut(), VARIABLES, VIRTUALS, automaton, data.2, &mut my_actions);"
+    )?;
+    if has_axioms {
+        writeln!(writer, "        parser.set_entry_state(entry);")?;
+    }
+    if is_hybrid {
+        writeln!(writer, "        parser.set_hybrid(true);")?;
+    }
+    writeln!(writer, "        parser.parse();")?;
+    writeln!(writer, "    }}")?;
+    if has_injections {
+        writeln!(writer, "    inject(&mut result);")?;
+    }
+    writeln!(writer, "    result")?;
+    writeln!(writer, "}}")?;
+    Ok(())
+}
+
+/// Generates the closure dispatching the semantic actions
+fn write_code_actions_closure(writer: &mut dyn Write, grammar: &Grammar) -> Result<(), Error> {
+    if grammar.actions.is_empty() {
+        writeln!(writer, "    let mut my_actions = |_index: usize, _head: Symbol, _body: &dyn SemanticBody| {{}};")?;
+    } else {
         writeln!(writer, "    let mut my_actions = |index: usize, head: Symbol, body: &dyn SemanticBody| match index {{")?;
         for (index, action) in grammar.actions.iter().enumerate() {
             writeln!(
@@ -436,29 +832,151 @@ fn write_code_constructors(
         writeln!(writer, "        _ => ()")?;
         writeln!(writer, "    }};")?;
         writeln!(writer)?;
+    }
+    Ok(())
+}
+
+/// Generates the code for the constructors that accept a hook into the lexer
+/// The hook is given as its parameter name, its type and its description
+#[allow(clippy::too_many_arguments)]
+fn write_code_constructors_with_hook(
+    writer: &mut dyn Write,
+    output_assembly: bool,
+    nmespace: &str,
+    tree_type: &str,
+    parse_result_type: &str,
+    fn_suffix: &str,
+    has_actions: bool,
+    tables: &str,
+    (hook, hook_type, hook_description): (&str, &str, &str),
+    hook_args: &str,
+) -> Result<(), Error> {
+    let actions_param = if has_actions {
+        ", actions: &mut dyn Actions"
     } else {
-        writeln!(writer, "    let mut my_actions = |_index: usize, _head: Symbol, _body: &dyn SemanticBody| {{}};")?;
+        ""
+    };
+    let actions_arg = if has_actions { ", actions" } else { "" };
+
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "/// Parses the specified string with this parser, consulting {hook_description}"
+    )?;
+    if output_assembly {
+        writeln!(writer, "#[no_mangle]")?;
+        writeln!(
+            writer,
+            "#[export_name = \"{nmespace}_parse_str{fn_suffix}_with_{hook}\"]"
+        )?;
     }
+    writeln!(writer, "#[must_use]")?;
     writeln!(
         writer,
-        "    let mut result = ParseResult::<{tree_type}>::new(terminals, variables, virtuals, text);"
+        "pub fn parse_str{fn_suffix}_with_{hook}<'t>(input: &'t str{actions_param}, {hook}: {hook_type}) -> ParseResult<'static, 't, 'static, {tree_type}> {{"
     )?;
-    writeln!(writer, "    {{")?;
-    writeln!(writer, "        let data = result.get_parsing_data();")?;
-    writeln!(writer, "        let mut lexer = new_lexer(data.0, data.1);")?;
+    writeln!(writer, "    let text = Text::from_str(input);")?;
     writeln!(
         writer,
-        "        let automaton = {automaton_type}::new(PARSER_AUTOMATON{});",
-        if compress_automata { ".as_ref()" } else { "" }
+        "    parse_text{fn_suffix}_with(text, {tables}{actions_arg}{hook_args})"
     )?;
+    writeln!(writer, "}}")?;
+
+    writeln!(writer)?;
     writeln!(
         writer,
-        "        let mut parser = {parser_type}::{parser_ctor}(&mut lexer, variables, virtuals, automaton, data.2, &mut my_actions);"
+        "/// Parses the specified string with this parser, consulting {hook_description}"
+    )?;
+    if output_assembly {
+        writeln!(writer, "#[no_mangle]")?;
+        writeln!(
+            writer,
+            "#[export_name = \"{nmespace}_parse_string{fn_suffix}_with_{hook}\"]"
+        )?;
+    }
+    writeln!(writer, "#[must_use]")?;
+    writeln!(
+        writer,
+        "pub fn parse_string{fn_suffix}_with_{hook}(input: String{actions_param}, {hook}: {hook_type}) -> {parse_result_type} {{"
+    )?;
+    writeln!(writer, "    let text = Text::from_string(input);")?;
+    writeln!(
+        writer,
+        "    parse_text{fn_suffix}_with(text, {tables}{actions_arg}{hook_args})"
+    )?;
+    writeln!(writer, "}}")?;
+    Ok(())
+}
+
+/// Generates the code for the constructors that parse the input as one of the axioms
+/// The constructors are prefixed with `parse_axiom_` so that they cannot collide with the other ones.
+/// The arguments for the hooks and the entry state are given for the call to `parse_text_with`
+#[allow(clippy::too_many_arguments)]
+fn write_code_axiom_constructors(
+    writer: &mut dyn Write,
+    output_assembly: bool,
+    nmespace: &str,
+    tree_type: &str,
+    fn_suffix: &str,
+    has_actions: bool,
+    tables: &str,
+    axiom: &str,
+    hook_args: &str,
+) -> Result<(), Error> {
+    let axiom_snake = to_snake_case(axiom);
+
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "/// Parses the specified string as a `{axiom}` with this parser"
+    )?;
+    if output_assembly {
+        writeln!(writer, "#[no_mangle]")?;
+        writeln!(
+            writer,
+            "#[export_name = \"{nmespace}_parse_axiom_{axiom_snake}{fn_suffix}\"]"
+        )?;
+    }
+    writeln!(writer, "#[must_use]")?;
+    writeln!(
+        writer,
+        "pub fn parse_axiom_{axiom_snake}{fn_suffix}(input: &str) -> ParseResult<'static, '_, 'static, {tree_type}> {{"
+    )?;
+    writeln!(writer, "    let text = Text::from_str(input);")?;
+    writeln!(
+        writer,
+        "    parse_text{fn_suffix}_with(text, {tables}{}{hook_args})",
+        if has_actions {
+            ", &mut NoActions {}"
+        } else {
+            ""
+        }
     )?;
-    writeln!(writer, "        parser.parse();")?;
-    writeln!(writer, "    }}")?;
-    writeln!(writer, "    result")?;
     writeln!(writer, "}}")?;
+    if has_actions {
+        writeln!(writer)?;
+        writeln!(
+            writer,
+            "/// Parses the specified string as a `{axiom}` with this parser"
+        )?;
+        if output_assembly {
+            writeln!(writer, "#[no_mangle]")?;
+            writeln!(
+                writer,
+                "#[export_name = \"{nmespace}_parse_axiom_{axiom_snake}{fn_suffix}_with\"]"
+            )?;
+        }
+        writeln!(
+            writer,
+            "pub fn parse_axiom_{axiom_snake}{fn_suffix}_with<'t>(input: &'t str, actions: &mut dyn Actions) -> ParseResult<'static, 't, 'static, {tree_type}> {{"
+        )?;
+        writeln!(writer, "    let text = Text::from_str(input);")?;
+        writeln!(
+            writer,
+            "    parse_text{fn_suffix}_with(text, {tables}, actions{hook_args})"
+        )?;
+        writeln!(writer, "}}")?;
+    }
     Ok(())
 }
 
@@ -467,6 +985,7 @@ fn write_code_visitor(
     writer: &mut dyn Write,
     grammar: &Grammar,
     expected: &TerminalSet,
+    injections: &[Injection],
 ) -> Result<(), Error> {
     writeln!(writer)?;
     writeln!(writer, "/// Visitor interface")?;
@@ -522,10 +1041,7 @@ fn write_code_visitor(
         writer,
         "pub fn visit_ast_node(node: AstNode, visitor: &dyn Visitor) {{"
     )?;
-    writeln!(writer, "    let children = node.children();")?;
-    writeln!(writer, "    for child in children.iter() {{")?;
-    writeln!(writer, "        visit_ast_node(child, visitor);")?;
-    writeln!(writer, "    }}")?;
+    write_code_visitor_children(writer, injections)?;
     writeln!(writer, "    match node.get_symbol().id {{")?;
     for terminal_ref in &expected.content {
         let Some(terminal) = grammar.get_terminal(terminal_ref.sid()) else {
@@ -565,3 +1081,37 @@ fn write_code_visitor(
     writeln!(writer, "}}")?;
     Ok(())
 }
+
+/// Generates the visit of the children of a node in the visitor
+/// The trees grafted onto the tokens of the injected languages are not visited.
+fn write_code_visitor_children(
+    writer: &mut dyn Write,
+    injections: &[Injection],
+) -> Result<(), Error> {
+    if injections.is_empty() {
+        writeln!(writer, "    let children = node.children();")?;
+        writeln!(writer, "    for child in children.iter() {{")?;
+        writeln!(writer, "        visit_ast_node(child, visitor);")?;
+        writeln!(writer, "    }}")?;
+    } else {
+        let terminals = injections
+            .iter()
+            .map(|injection| format!("0x{:04X}", injection.terminal))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        writeln!(
+            writer,
+            "    // the trees of the injected languages are not visited"
+        )?;
+        writeln!(
+            writer,
+            "    if !matches!(node.get_symbol().id, {terminals}) {{"
+        )?;
+        writeln!(writer, "        let children = node.children();")?;
+        writeln!(writer, "        for child in children.iter() {{")?;
+        writeln!(writer, "            visit_ast_node(child, visitor);")?;
+        writeln!(writer, "        }}")?;
+        writeln!(writer, "    }}")?;
+    }
+    Ok(())
+}
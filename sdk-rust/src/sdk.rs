@@ -20,8 +20,9 @@
 use hime_redist::ast::AstImpl;
 use hime_redist::errors::ParseErrors;
 use hime_redist::lexers::automaton::Automaton;
+use hime_redist::lexers::filter::FilteredSource;
 use hime_redist::lexers::impls::{ContextFreeLexer, ContextSensitiveLexer};
-use hime_redist::lexers::Lexer;
+use hime_redist::lexers::{Lexer, LexerModeAction, ParseHooks, TokenSource};
 use hime_redist::parsers::lrk::{LRkAutomaton, LRkParser};
 use hime_redist::parsers::rnglr::{RNGLRAutomaton, RNGLRParser};
 use hime_redist::parsers::Parser;
@@ -30,6 +31,8 @@ use hime_redist::symbols::{SemanticBody, Symbol};
 use hime_redist::text::Text;
 use hime_redist::tokens::TokenRepository;
 
+use crate::grammars::IndentationSettings;
+
 /// The automaton for a parser
 #[derive(Clone)]
 pub enum ParserAutomaton {
@@ -50,28 +53,93 @@ pub struct InMemoryParser<'s> {
     pub variables: Vec<Symbol<'s>>,
     /// The virtuals
     pub virtuals: Vec<Symbol<'s>>,
+    /// The axioms, in the order of their entry states in the parser's automaton
+    pub axioms: Vec<Symbol<'s>>,
     /// The identifier of the separator terminal, if any
     pub separator: u32,
     /// The lexer's automaton
     pub lexer_automaton: Automaton,
     /// Whether the lexer is context-sensitive
     pub lexer_is_context_sensitive: bool,
+    /// The actions on the lexer's stack of modes for each terminal, if any
+    pub lexer_mode_actions: Vec<LexerModeAction>,
+    /// The settings for indentation-sensitive lexing, if any
+    pub lexer_indentation: Option<IndentationSettings>,
     /// The parser's automaton
     pub parser_automaton: ParserAutomaton,
+    /// Whether the RNGLR parser runs as a hybrid LR/GLR parser
+    pub parser_is_hybrid: bool,
 }
 
 impl<'s> InMemoryParser<'s> {
     /// Parses an input parser
     #[must_use]
     pub fn parse<'a, 't>(&'a self, input: &'t str) -> ParseResult<'s, 't, 'a, AstImpl> {
-        let text = Text::from_str(input);
+        self.parse_from(0, Text::from_str(input), ParseHooks::new())
+    }
+
+    /// Parses a binary input, for a grammar whose terminals are defined over bytes
+    #[must_use]
+    pub fn parse_bytes<'a, 't>(&'a self, input: &'t [u8]) -> ParseResult<'s, 't, 'a, AstImpl> {
+        self.parse_from(0, Text::from_bytes(input), ParseHooks::new())
+    }
+
+    /// Parses a text, that may be configured for the computation of columns
+    #[must_use]
+    pub fn parse_text<'a, 't>(&'a self, text: Text<'t>) -> ParseResult<'s, 't, 'a, AstImpl> {
+        self.parse_from(0, text, ParseHooks::new())
+    }
+
+    /// Parses a text with hooks into the production of the tokens
+    #[must_use]
+    pub fn parse_with_hooks<'a, 't>(
+        &'a self,
+        text: Text<'t>,
+        hooks: ParseHooks,
+    ) -> ParseResult<'s, 't, 'a, AstImpl> {
+        self.parse_from(0, text, hooks)
+    }
+
+    /// Parses an input as the specified variable, which must be one of the grammar's axioms
+    /// Returns `None` when the variable is not an axiom
+    #[must_use]
+    pub fn parse_as<'a, 't>(
+        &'a self,
+        variable: &str,
+        input: &'t str,
+    ) -> Option<ParseResult<'s, 't, 'a, AstImpl>> {
+        let entry = self
+            .axioms
+            .iter()
+            .position(|axiom| axiom.name == variable)?;
+        Some(self.parse_from(entry as u32, Text::from_str(input), ParseHooks::new()))
+    }
+
+    /// Parses an input from the specified entry state
+    fn parse_from<'a, 't>(
+        &'a self,
+        entry: u32,
+        text: Text<'t>,
+        hooks: ParseHooks,
+    ) -> ParseResult<'s, 't, 'a, AstImpl> {
         let mut result =
             ParseResult::<AstImpl>::new(&self.terminals, &self.variables, &self.virtuals, text);
         let mut my_actions = |_index: usize, _head: Symbol, _body: &dyn SemanticBody| ();
         {
             let data = result.get_parsing_data();
             let mut lexer = self.new_lexer(data.0, data.1);
-            self.do_parse(&mut lexer, data.2, &mut my_actions);
+            if let Some(contexts) = hooks.contexts {
+                lexer.set_user_context_provider(contexts);
+            }
+            if let Some(scanner) = hooks.scanner {
+                lexer.set_external_scanner(scanner);
+            }
+            if let Some(filter) = hooks.filter {
+                let mut filtered = FilteredSource::new(lexer, filter);
+                self.do_parse(&mut filtered, data.2, &mut my_actions, entry);
+            } else {
+                self.do_parse(&mut lexer, data.2, &mut my_actions, entry);
+            }
         }
         result
     }
@@ -79,27 +147,37 @@ impl<'s> InMemoryParser<'s> {
     /// Execute the parser
     fn do_parse<'a, 't>(
         &'a self,
-        lexer: &'a mut Lexer<'s, 't, 'a>,
+        source: &'a mut dyn TokenSource<'s, 't, 'a>,
         ast: &'a mut AstImpl,
         actions: &'a mut dyn FnMut(usize, Symbol, &dyn SemanticBody),
+        entry: u32,
     ) {
         let mut parser: Box<dyn Parser> = match &self.parser_automaton {
-            ParserAutomaton::Lrk(automaton) => Box::new(LRkParser::new(
-                lexer,
-                &self.variables,
-                &self.virtuals,
-                automaton.clone(),
-                ast,
-                actions,
-            )),
-            ParserAutomaton::Rnglr(automaton) => Box::new(RNGLRParser::new_with_ast(
-                lexer,
-                &self.variables,
-                &self.virtuals,
-                automaton.clone(),
-                ast,
-                actions,
-            )),
+            ParserAutomaton::Lrk(automaton) => {
+                let mut parser = LRkParser::new(
+                    source,
+                    &self.variables,
+                    &self.virtuals,
+                    automaton.clone(),
+                    ast,
+                    actions,
+                );
+                parser.set_entry_state(entry);
+                Box::new(parser)
+            }
+            ParserAutomaton::Rnglr(automaton) => {
+                let mut parser = RNGLRParser::new_with_ast(
+                    source,
+                    &self.variables,
+                    &self.virtuals,
+                    automaton.clone(),
+                    ast,
+                    actions,
+                );
+                parser.set_entry_state(entry);
+                parser.set_hybrid(self.parser_is_hybrid);
+                Box::new(parser)
+            }
         };
         parser.parse();
     }
@@ -110,20 +188,26 @@ impl<'s> InMemoryParser<'s> {
         repository: TokenRepository<'s, 't, 'a>,
         errors: &'a mut ParseErrors<'s>,
     ) -> Lexer<'s, 't, 'a> {
-        if self.lexer_is_context_sensitive {
-            Lexer::ContextFree(ContextFreeLexer::new(
+        let mut lexer = if self.lexer_is_context_sensitive {
+            let mut lexer = ContextSensitiveLexer::new(
                 repository,
                 errors,
                 self.lexer_automaton.clone(),
                 self.separator,
-            ))
+            );
+            lexer.set_mode_actions(&self.lexer_mode_actions);
+            Lexer::ContextSensitive(lexer)
         } else {
-            Lexer::ContextSensitive(ContextSensitiveLexer::new(
+            Lexer::ContextFree(ContextFreeLexer::new(
                 repository,
                 errors,
                 self.lexer_automaton.clone(),
                 self.separator,
             ))
+        };
+        if let Some(indentation) = &self.lexer_indentation {
+            lexer.set_indentation(indentation.to_config());
         }
+        lexer
     }
 }
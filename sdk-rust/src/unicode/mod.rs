@@ -17,8 +17,7 @@
 
 //! Unicode support
 
-mod blocks;
-mod categories;
+mod v16_0_0;
 
 use std::collections::HashMap;
 
@@ -27,13 +26,93 @@ use lazy_static::lazy_static;
 use crate::{CharSpan, CHARSPAN_INVALID};
 
 lazy_static! {
-    /// Contains the supported Unicode blocks
-    pub static ref BLOCKS: HashMap<&'static str, Block> = blocks::get_blocks();
+    /// Contains the supported versions of the Unicode Character Database, the latest first
+    pub static ref DATABASES: Vec<UnicodeDatabase> = vec![UnicodeDatabase::new(
+        v16_0_0::VERSION,
+        v16_0_0::get_blocks(),
+        v16_0_0::get_categories(),
+        v16_0_0::get_properties(),
+        v16_0_0::CASE_FOLDING_CLASSES,
+    )];
+    /// Contains the supported Unicode blocks, in the latest version of the Unicode Character Database
+    pub static ref BLOCKS: HashMap<&'static str, Block> = DATABASES[0].blocks.clone();
+    /// Contains the supported Unicode categories, in the latest version of the Unicode Character Database
+    pub static ref CATEGORIES: HashMap<&'static str, Category> = DATABASES[0].categories.clone();
 }
 
-lazy_static! {
-    /// Contains the supported Unicode blocks
-    pub static ref CATEGORIES: HashMap<&'static str, Category> = categories::get_categories();
+/// Gets the tables for a version of the Unicode Character Database, or the latest one when no version is given
+/// A version matches when it is the full version or a prefix of it, e.g. `16` or `16.0` for `16.0.0`.
+#[must_use]
+pub fn get_database(version: Option<&str>) -> Option<&'static UnicodeDatabase> {
+    let Some(version) = version else {
+        return DATABASES.first();
+    };
+    DATABASES.iter().find(|database| {
+        database.version == version
+            || database
+                .version
+                .strip_prefix(version)
+                .is_some_and(|rest| rest.starts_with('.'))
+    })
+}
+
+/// The tables for a version of the Unicode Character Database
+#[derive(Debug, Clone)]
+pub struct UnicodeDatabase {
+    /// The version of the Unicode Character Database
+    pub version: &'static str,
+    /// The Unicode blocks
+    pub blocks: HashMap<&'static str, Block>,
+    /// The Unicode general categories
+    pub categories: HashMap<&'static str, Category>,
+    /// The Unicode scripts (as `Script=Name`) and binary properties
+    pub properties: HashMap<&'static str, Category>,
+    /// The pairs of case-equivalent code points, sorted by the first code point
+    case_equivalents: Vec<(u32, u32)>,
+}
+
+impl UnicodeDatabase {
+    /// Initializes the tables for a version of the Unicode Character Database
+    #[must_use]
+    pub fn new(
+        version: &'static str,
+        blocks: HashMap<&'static str, Block>,
+        categories: HashMap<&'static str, Category>,
+        properties: HashMap<&'static str, Category>,
+        case_folding: &[&[u32]],
+    ) -> UnicodeDatabase {
+        let mut case_equivalents = Vec::new();
+        for class in case_folding {
+            for &member in *class {
+                for &other in *class {
+                    if member != other {
+                        case_equivalents.push((member, other));
+                    }
+                }
+            }
+        }
+        case_equivalents.sort_unstable();
+        UnicodeDatabase {
+            version,
+            blocks,
+            categories,
+            properties,
+            case_equivalents,
+        }
+    }
+
+    /// Gets the pairs of case-equivalent code points (according to the simple case folding)
+    /// for which the first code point is within the specified span
+    #[must_use]
+    pub fn get_case_equivalents(&self, span: Span) -> &[(u32, u32)] {
+        let begin = self
+            .case_equivalents
+            .partition_point(|(c, _)| *c < span.begin.0);
+        let end = self
+            .case_equivalents
+            .partition_point(|(c, _)| *c <= span.end.0);
+        &self.case_equivalents[begin..end]
+    }
 }
 
 /// Represents a Unicode code point
@@ -128,7 +207,7 @@ impl Span {
             vec![[CharSpan::new(b[0], e[0]), CHARSPAN_INVALID]]
         } else if self.begin.is_plane0() {
             // begins in plane 0, split into two spans
-            let mut result = Self::get_char_spans_plane_x(CodePoint(0x10_0000).get_utf16(), e);
+            let mut result = Self::get_char_spans_plane_x(CodePoint(0x1_0000).get_utf16(), e);
             result.push([CharSpan::new(b[0], 0xFFFF), CHARSPAN_INVALID]);
             result
         } else {
@@ -175,6 +254,17 @@ fn test_get_char_spans_simple() {
     );
 }
 
+#[test]
+fn test_get_char_spans_across_plane0() {
+    assert_eq!(
+        Span::new(0xFF00, 0x10010).get_char_spans(),
+        vec![
+            [CharSpan::new(0xD800, 0xD800), CharSpan::new(0xDC00, 0xDC10)],
+            [CharSpan::new(0xFF00, 0xFFFF), CHARSPAN_INVALID]
+        ]
+    );
+}
+
 /// Represents a Unicode block of characters
 #[derive(Debug, Clone)]
 pub struct Block {
@@ -245,26 +335,92 @@ impl Category {
 
 #[test]
 fn test_unicode_blocks_well_formed() {
-    for (_, block) in &*BLOCKS {
-        assert!(
-            block.span.begin <= block.span.end,
-            "invalid unicode block {}",
-            block.name
-        );
+    for database in &*DATABASES {
+        for block in database.blocks.values() {
+            assert!(
+                block.span.begin <= block.span.end,
+                "invalid unicode block {}",
+                block.name
+            );
+        }
     }
 }
 
 #[test]
 fn test_unicode_categories_well_formed() {
-    for (_, category) in &*CATEGORIES {
-        for span in &category.spans {
-            assert!(
-                span.begin <= span.end,
-                "invalid unicode category {}: begin {:04X} end {:04X}",
-                category.name,
-                span.begin.0,
-                span.end.0
-            );
+    for database in &*DATABASES {
+        for category in database.categories.values() {
+            for span in &category.spans {
+                assert!(
+                    span.begin <= span.end,
+                    "invalid unicode category {}: begin {:04X} end {:04X}",
+                    category.name,
+                    span.begin.0,
+                    span.end.0
+                );
+            }
         }
     }
 }
+
+#[test]
+fn test_unicode_properties_well_formed() {
+    for database in &*DATABASES {
+        for property in database.properties.values() {
+            for span in &property.spans {
+                assert!(
+                    span.begin <= span.end,
+                    "invalid unicode property {}: begin {:04X} end {:04X}",
+                    property.name,
+                    span.begin.0,
+                    span.end.0
+                );
+            }
+        }
+        assert!(database.properties.contains_key("Script=Greek"));
+        assert!(database.properties.contains_key("XID_Start"));
+    }
+}
+
+#[test]
+fn test_get_database() {
+    let latest = get_database(None).unwrap();
+    assert_eq!(latest.version, "16.0.0");
+    for version in ["16", "16.0", "16.0.0"] {
+        assert_eq!(get_database(Some(version)).unwrap().version, "16.0.0");
+    }
+    assert!(get_database(Some("1")).is_none());
+    assert!(get_database(Some("16.0.1")).is_none());
+}
+
+#[test]
+fn test_case_equivalents() {
+    let database = get_database(None).unwrap();
+    assert_eq!(
+        database.get_case_equivalents(Span::new(0x4B, 0x4B)),
+        &[(0x4B, 0x6B), (0x4B, 0x212A)]
+    );
+    // outside plane 0: DESERET CAPITAL LETTER LONG I
+    assert_eq!(
+        database.get_case_equivalents(Span::new(0x10400, 0x10400)),
+        &[(0x10400, 0x10428)]
+    );
+    assert!(database
+        .get_case_equivalents(Span::new(0x30, 0x39))
+        .is_empty());
+}
+
+#[test]
+fn test_unicode_16_tables() {
+    // GARAY LETTER ALIF, new in Unicode 16.0
+    let database = get_database(Some("16.0.0")).unwrap();
+    let block = &database.blocks["Garay"];
+    assert_eq!((block.span.begin.0, block.span.end.0), (0x10D40, 0x10D8F));
+    assert!(database.categories["Lo"]
+        .spans
+        .iter()
+        .any(|span| span.begin.0 <= 0x10D4A && 0x10D4A <= span.end.0));
+    assert!(database.categories["Cs"].spans.is_empty());
+    assert!(BLOCKS.contains_key("Garay"));
+    assert!(CATEGORIES.contains_key("Lo"));
+}
@@ -3,12 +3,15 @@
  * Hime Parser Generator
  */
 
-use std::collections::HashMap;
-
 use crate::unicode::Block;
+use std::collections::HashMap;
 
 /// Gets all blocks
-#[allow(clippy::similar_names, clippy::too_many_lines)]
+#[allow(
+    clippy::similar_names,
+    clippy::too_many_lines,
+    clippy::unreadable_literal
+)]
 #[must_use]
 pub fn get_blocks() -> HashMap<&'static str, Block> {
     let mut db = HashMap::new();
@@ -281,6 +284,10 @@ pub fn get_blocks() -> HashMap<&'static str, Block> {
         "EgyptianHieroglyphs",
         Block::new("EgyptianHieroglyphs", 0x13000, 0x1342F),
     );
+    db.insert(
+        "EgyptianHieroglyphsExtended-A",
+        Block::new("EgyptianHieroglyphsExtended-A", 0x13460, 0x143FF),
+    );
     db.insert("Elbasan", Block::new("Elbasan", 0x10500, 0x1052F));
     db.insert("Elymaic", Block::new("Elymaic", 0x10FE0, 0x10FFF));
     db.insert("Emoticons", Block::new("Emoticons", 0x1F600, 0x1F64F));
@@ -317,6 +324,7 @@ pub fn get_blocks() -> HashMap<&'static str, Block> {
         "EthiopicSupplement",
         Block::new("EthiopicSupplement", 0x1380, 0x139F),
     );
+    db.insert("Garay", Block::new("Garay", 0x10D40, 0x10D8F));
     db.insert(
         "GeneralPunctuation",
         Block::new("GeneralPunctuation", 0x2000, 0x206F),
@@ -350,6 +358,7 @@ pub fn get_blocks() -> HashMap<&'static str, Block> {
     db.insert("Gujarati", Block::new("Gujarati", 0xA80, 0xAFF));
     db.insert("GunjalaGondi", Block::new("GunjalaGondi", 0x11D60, 0x11DAF));
     db.insert("Gurmukhi", Block::new("Gurmukhi", 0xA00, 0xA7F));
+    db.insert("GurungKhema", Block::new("GurungKhema", 0x16100, 0x1613F));
     db.insert(
         "HalfwidthandFullwidthForms",
         Block::new("HalfwidthandFullwidthForms", 0xFF00, 0xFFEF),
@@ -444,6 +453,7 @@ pub fn get_blocks() -> HashMap<&'static str, Block> {
     db.insert("KhmerSymbols", Block::new("KhmerSymbols", 0x19E0, 0x19FF));
     db.insert("Khojki", Block::new("Khojki", 0x11200, 0x1124F));
     db.insert("Khudawadi", Block::new("Khudawadi", 0x112B0, 0x112FF));
+    db.insert("KiratRai", Block::new("KiratRai", 0x16D40, 0x16D7F));
     db.insert("Lao", Block::new("Lao", 0xE80, 0xEFF));
     db.insert(
         "Latin-1Supplement",
@@ -588,6 +598,10 @@ pub fn get_blocks() -> HashMap<&'static str, Block> {
         "MyanmarExtended-B",
         Block::new("MyanmarExtended-B", 0xA9E0, 0xA9FF),
     );
+    db.insert(
+        "MyanmarExtended-C",
+        Block::new("MyanmarExtended-C", 0x116D0, 0x116FF),
+    );
     db.insert("NKo", Block::new("NKo", 0x7C0, 0x7FF));
     db.insert("Nabataean", Block::new("Nabataean", 0x10880, 0x108AF));
     db.insert("NagMundari", Block::new("NagMundari", 0x1E4D0, 0x1E4FF));
@@ -602,6 +616,7 @@ pub fn get_blocks() -> HashMap<&'static str, Block> {
     );
     db.insert("Ogham", Block::new("Ogham", 0x1680, 0x169F));
     db.insert("OlChiki", Block::new("OlChiki", 0x1C50, 0x1C7F));
+    db.insert("OlOnal", Block::new("OlOnal", 0x1E5D0, 0x1E5FF));
     db.insert("OldHungarian", Block::new("OldHungarian", 0x10C80, 0x10CFF));
     db.insert("OldItalic", Block::new("OldItalic", 0x10300, 0x1032F));
     db.insert(
@@ -696,6 +711,7 @@ pub fn get_blocks() -> HashMap<&'static str, Block> {
         "SundaneseSupplement",
         Block::new("SundaneseSupplement", 0x1CC0, 0x1CCF),
     );
+    db.insert("Sunuwar", Block::new("Sunuwar", 0x11BC0, 0x11BFF));
     db.insert(
         "SuperscriptsandSubscripts",
         Block::new("SuperscriptsandSubscripts", 0x2070, 0x209F),
@@ -730,7 +746,7 @@ pub fn get_blocks() -> HashMap<&'static str, Block> {
     );
     db.insert(
         "SupplementaryPrivateUseArea-B",
-        Block::new("SupplementaryPrivateUseArea-B", 0x0010_0000, 0x0010_FFFF),
+        Block::new("SupplementaryPrivateUseArea-B", 0x100000, 0x10FFFF),
     );
     db.insert(
         "SuttonSignWriting",
@@ -745,6 +761,10 @@ pub fn get_blocks() -> HashMap<&'static str, Block> {
         "SymbolsforLegacyComputing",
         Block::new("SymbolsforLegacyComputing", 0x1FB00, 0x1FBFF),
     );
+    db.insert(
+        "SymbolsforLegacyComputingSupplement",
+        Block::new("SymbolsforLegacyComputingSupplement", 0x1CC00, 0x1CEBF),
+    );
     db.insert("Syriac", Block::new("Syriac", 0x700, 0x74F));
     db.insert(
         "SyriacSupplement",
@@ -782,11 +802,16 @@ pub fn get_blocks() -> HashMap<&'static str, Block> {
     db.insert("Tibetan", Block::new("Tibetan", 0xF00, 0xFFF));
     db.insert("Tifinagh", Block::new("Tifinagh", 0x2D30, 0x2D7F));
     db.insert("Tirhuta", Block::new("Tirhuta", 0x11480, 0x114DF));
+    db.insert("Todhri", Block::new("Todhri", 0x105C0, 0x105FF));
     db.insert("Toto", Block::new("Toto", 0x1E290, 0x1E2BF));
     db.insert(
         "TransportandMapSymbols",
         Block::new("TransportandMapSymbols", 0x1F680, 0x1F6FF),
     );
+    db.insert(
+        "Tulu-Tigalari",
+        Block::new("Tulu-Tigalari", 0x11380, 0x113FF),
+    );
     db.insert("Ugaritic", Block::new("Ugaritic", 0x10380, 0x1039F));
     db.insert(
         "UnifiedCanadianAboriginalSyllabics",
@@ -3,12 +3,15 @@
  * Hime Parser Generator
  */
 
-use std::collections::HashMap;
-
 use crate::unicode::Category;
+use std::collections::HashMap;
 
 /// Gets all categories
-#[allow(clippy::similar_names, clippy::too_many_lines)]
+#[allow(
+    clippy::similar_names,
+    clippy::too_many_lines,
+    clippy::unreadable_literal
+)]
 #[must_use]
 pub fn get_categories() -> HashMap<&'static str, Category> {
     let mut db = HashMap::new();
@@ -37,7 +40,7 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_cf.add_span(0xE0001, 0xE007F);
     let mut cat_co = Category::new("Co");
     cat_co.add_span(0xE000, 0xF8FF);
-    cat_co.add_span(0xF0000, 0x0010_FFFD);
+    cat_co.add_span(0xF0000, 0x10FFFD);
     let cat_cs = Category::new("Cs");
     let mut cat_ll = Category::new("Ll");
     cat_ll.add_span(0x61, 0x7A);
@@ -314,6 +317,7 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_ll.add_span(0x10FD, 0x10FF);
     cat_ll.add_span(0x13F8, 0x13FD);
     cat_ll.add_span(0x1C80, 0x1C88);
+    cat_ll.add_span(0x1C8A, 0x1C8A);
     cat_ll.add_span(0x1D00, 0x1D2B);
     cat_ll.add_span(0x1D6B, 0x1D77);
     cat_ll.add_span(0x1D79, 0x1D9A);
@@ -637,9 +641,11 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_ll.add_span(0xA7C3, 0xA7C3);
     cat_ll.add_span(0xA7C8, 0xA7C8);
     cat_ll.add_span(0xA7CA, 0xA7CA);
+    cat_ll.add_span(0xA7CD, 0xA7CD);
     cat_ll.add_span(0xA7D1, 0xA7D5);
     cat_ll.add_span(0xA7D7, 0xA7D7);
     cat_ll.add_span(0xA7D9, 0xA7D9);
+    cat_ll.add_span(0xA7DB, 0xA7DB);
     cat_ll.add_span(0xA7F6, 0xA7F6);
     cat_ll.add_span(0xA7FA, 0xA7FA);
     cat_ll.add_span(0xAB30, 0xAB5A);
@@ -651,6 +657,7 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_ll.add_span(0x104D8, 0x104FB);
     cat_ll.add_span(0x10597, 0x105BC);
     cat_ll.add_span(0x10CC0, 0x10CF2);
+    cat_ll.add_span(0x10D70, 0x10D85);
     cat_ll.add_span(0x118C0, 0x118DF);
     cat_ll.add_span(0x16E60, 0x16E7F);
     cat_ll.add_span(0x1D41A, 0x1D433);
@@ -739,7 +746,11 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_lm.add_span(0xFF70, 0xFF70);
     cat_lm.add_span(0xFF9E, 0xFF9F);
     cat_lm.add_span(0x10780, 0x107BA);
+    cat_lm.add_span(0x10D4E, 0x10D4E);
+    cat_lm.add_span(0x10D6F, 0x10D6F);
     cat_lm.add_span(0x16B40, 0x16B43);
+    cat_lm.add_span(0x16D40, 0x16D42);
+    cat_lm.add_span(0x16D6B, 0x16D6C);
     cat_lm.add_span(0x16F93, 0x16FE1);
     cat_lm.add_span(0x16FE3, 0x16FE3);
     cat_lm.add_span(0x1AFF0, 0x1AFFE);
@@ -935,7 +946,7 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_lo.add_span(0x103A0, 0x103CF);
     cat_lo.add_span(0x10450, 0x1049D);
     cat_lo.add_span(0x10500, 0x10563);
-    cat_lo.add_span(0x10600, 0x10767);
+    cat_lo.add_span(0x105C0, 0x10767);
     cat_lo.add_span(0x10800, 0x10855);
     cat_lo.add_span(0x10860, 0x10876);
     cat_lo.add_span(0x10880, 0x1089E);
@@ -956,8 +967,10 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_lo.add_span(0x10B80, 0x10B91);
     cat_lo.add_span(0x10C00, 0x10C48);
     cat_lo.add_span(0x10D00, 0x10D23);
+    cat_lo.add_span(0x10D4A, 0x10D4D);
+    cat_lo.add_span(0x10D4F, 0x10D4F);
     cat_lo.add_span(0x10E80, 0x10EA9);
-    cat_lo.add_span(0x10EB0, 0x10EB1);
+    cat_lo.add_span(0x10EB0, 0x10EC4);
     cat_lo.add_span(0x10F00, 0x10F1C);
     cat_lo.add_span(0x10F27, 0x10F45);
     cat_lo.add_span(0x10F70, 0x10F81);
@@ -984,6 +997,9 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_lo.add_span(0x1133D, 0x1133D);
     cat_lo.add_span(0x11350, 0x11350);
     cat_lo.add_span(0x1135D, 0x11361);
+    cat_lo.add_span(0x11380, 0x113B7);
+    cat_lo.add_span(0x113D1, 0x113D1);
+    cat_lo.add_span(0x113D3, 0x113D3);
     cat_lo.add_span(0x11400, 0x11434);
     cat_lo.add_span(0x11447, 0x1144A);
     cat_lo.add_span(0x1145F, 0x114AF);
@@ -1010,6 +1026,7 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_lo.add_span(0x11A5C, 0x11A89);
     cat_lo.add_span(0x11A9D, 0x11A9D);
     cat_lo.add_span(0x11AB0, 0x11AF8);
+    cat_lo.add_span(0x11BC0, 0x11BE0);
     cat_lo.add_span(0x11C00, 0x11C2E);
     cat_lo.add_span(0x11C40, 0x11C40);
     cat_lo.add_span(0x11C72, 0x11C8F);
@@ -1025,11 +1042,13 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_lo.add_span(0x12480, 0x12FF0);
     cat_lo.add_span(0x13000, 0x1342F);
     cat_lo.add_span(0x13441, 0x13446);
-    cat_lo.add_span(0x14400, 0x16A5E);
+    cat_lo.add_span(0x13460, 0x1611D);
+    cat_lo.add_span(0x16800, 0x16A5E);
     cat_lo.add_span(0x16A70, 0x16ABE);
     cat_lo.add_span(0x16AD0, 0x16AED);
     cat_lo.add_span(0x16B00, 0x16B2F);
     cat_lo.add_span(0x16B63, 0x16B8F);
+    cat_lo.add_span(0x16D43, 0x16D6A);
     cat_lo.add_span(0x16F00, 0x16F4A);
     cat_lo.add_span(0x16F50, 0x16F50);
     cat_lo.add_span(0x17000, 0x18D08);
@@ -1040,6 +1059,8 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_lo.add_span(0x1E290, 0x1E2AD);
     cat_lo.add_span(0x1E2C0, 0x1E2EB);
     cat_lo.add_span(0x1E4D0, 0x1E4EA);
+    cat_lo.add_span(0x1E5D0, 0x1E5ED);
+    cat_lo.add_span(0x1E5F0, 0x1E5F0);
     cat_lo.add_span(0x1E7E0, 0x1E8C4);
     cat_lo.add_span(0x1EE00, 0x1EEBB);
     cat_lo.add_span(0x20000, 0x323AF);
@@ -1326,6 +1347,7 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_lu.add_span(0x531, 0x556);
     cat_lu.add_span(0x10A0, 0x10CD);
     cat_lu.add_span(0x13A0, 0x13F5);
+    cat_lu.add_span(0x1C89, 0x1C89);
     cat_lu.add_span(0x1C90, 0x1CBF);
     cat_lu.add_span(0x1E00, 0x1E00);
     cat_lu.add_span(0x1E02, 0x1E02);
@@ -1646,15 +1668,19 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_lu.add_span(0xA7C2, 0xA7C2);
     cat_lu.add_span(0xA7C4, 0xA7C7);
     cat_lu.add_span(0xA7C9, 0xA7C9);
+    cat_lu.add_span(0xA7CB, 0xA7CC);
     cat_lu.add_span(0xA7D0, 0xA7D0);
     cat_lu.add_span(0xA7D6, 0xA7D6);
     cat_lu.add_span(0xA7D8, 0xA7D8);
+    cat_lu.add_span(0xA7DA, 0xA7DA);
+    cat_lu.add_span(0xA7DC, 0xA7DC);
     cat_lu.add_span(0xA7F5, 0xA7F5);
     cat_lu.add_span(0xFF21, 0xFF3A);
     cat_lu.add_span(0x10400, 0x10427);
     cat_lu.add_span(0x104B0, 0x104D3);
     cat_lu.add_span(0x10570, 0x10595);
     cat_lu.add_span(0x10C80, 0x10CB2);
+    cat_lu.add_span(0x10D50, 0x10D65);
     cat_lu.add_span(0x118A0, 0x118BF);
     cat_lu.add_span(0x16E40, 0x16E5F);
     cat_lu.add_span(0x1D400, 0x1D419);
@@ -1802,6 +1828,9 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_mc.add_span(0x11341, 0x1134D);
     cat_mc.add_span(0x11357, 0x11357);
     cat_mc.add_span(0x11362, 0x11363);
+    cat_mc.add_span(0x113B8, 0x113BA);
+    cat_mc.add_span(0x113C2, 0x113CD);
+    cat_mc.add_span(0x113CF, 0x113CF);
     cat_mc.add_span(0x11435, 0x11437);
     cat_mc.add_span(0x11440, 0x11441);
     cat_mc.add_span(0x11445, 0x11445);
@@ -1818,6 +1847,7 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_mc.add_span(0x116AC, 0x116AC);
     cat_mc.add_span(0x116AE, 0x116AF);
     cat_mc.add_span(0x116B6, 0x116B6);
+    cat_mc.add_span(0x1171E, 0x1171E);
     cat_mc.add_span(0x11720, 0x11721);
     cat_mc.add_span(0x11726, 0x11726);
     cat_mc.add_span(0x1182C, 0x1182E);
@@ -1845,6 +1875,7 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_mc.add_span(0x11F34, 0x11F35);
     cat_mc.add_span(0x11F3E, 0x11F3F);
     cat_mc.add_span(0x11F41, 0x11F41);
+    cat_mc.add_span(0x1612A, 0x1612C);
     cat_mc.add_span(0x16F51, 0x16F87);
     cat_mc.add_span(0x16FF0, 0x16FF1);
     cat_mc.add_span(0x1D165, 0x1D166);
@@ -1880,7 +1911,7 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_mn.add_span(0x825, 0x827);
     cat_mn.add_span(0x829, 0x82D);
     cat_mn.add_span(0x859, 0x85B);
-    cat_mn.add_span(0x898, 0x89F);
+    cat_mn.add_span(0x897, 0x89F);
     cat_mn.add_span(0x8CA, 0x8E1);
     cat_mn.add_span(0x8E3, 0x902);
     cat_mn.add_span(0x93A, 0x93A);
@@ -2061,8 +2092,9 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_mn.add_span(0x10A38, 0x10A3F);
     cat_mn.add_span(0x10AE5, 0x10AE6);
     cat_mn.add_span(0x10D24, 0x10D27);
+    cat_mn.add_span(0x10D69, 0x10D6D);
     cat_mn.add_span(0x10EAB, 0x10EAC);
-    cat_mn.add_span(0x10EFD, 0x10EFF);
+    cat_mn.add_span(0x10EFC, 0x10EFF);
     cat_mn.add_span(0x10F46, 0x10F50);
     cat_mn.add_span(0x10F82, 0x10F85);
     cat_mn.add_span(0x11001, 0x11001);
@@ -2092,6 +2124,11 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_mn.add_span(0x1133B, 0x1133C);
     cat_mn.add_span(0x11340, 0x11340);
     cat_mn.add_span(0x11366, 0x11374);
+    cat_mn.add_span(0x113BB, 0x113C0);
+    cat_mn.add_span(0x113CE, 0x113CE);
+    cat_mn.add_span(0x113D0, 0x113D0);
+    cat_mn.add_span(0x113D2, 0x113D2);
+    cat_mn.add_span(0x113E1, 0x113E2);
     cat_mn.add_span(0x11438, 0x1143F);
     cat_mn.add_span(0x11442, 0x11444);
     cat_mn.add_span(0x11446, 0x11446);
@@ -2111,7 +2148,8 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_mn.add_span(0x116AD, 0x116AD);
     cat_mn.add_span(0x116B0, 0x116B5);
     cat_mn.add_span(0x116B7, 0x116B7);
-    cat_mn.add_span(0x1171D, 0x1171F);
+    cat_mn.add_span(0x1171D, 0x1171D);
+    cat_mn.add_span(0x1171F, 0x1171F);
     cat_mn.add_span(0x11722, 0x11725);
     cat_mn.add_span(0x11727, 0x1172B);
     cat_mn.add_span(0x1182F, 0x11837);
@@ -2145,8 +2183,11 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_mn.add_span(0x11F36, 0x11F3A);
     cat_mn.add_span(0x11F40, 0x11F40);
     cat_mn.add_span(0x11F42, 0x11F42);
+    cat_mn.add_span(0x11F5A, 0x11F5A);
     cat_mn.add_span(0x13440, 0x13440);
     cat_mn.add_span(0x13447, 0x13455);
+    cat_mn.add_span(0x1611E, 0x16129);
+    cat_mn.add_span(0x1612D, 0x1612F);
     cat_mn.add_span(0x16AF0, 0x16AF4);
     cat_mn.add_span(0x16B30, 0x16B36);
     cat_mn.add_span(0x16F4F, 0x16F4F);
@@ -2170,6 +2211,7 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_mn.add_span(0x1E2AE, 0x1E2AE);
     cat_mn.add_span(0x1E2EC, 0x1E2EF);
     cat_mn.add_span(0x1E4EC, 0x1E4EF);
+    cat_mn.add_span(0x1E5EE, 0x1E5EF);
     cat_mn.add_span(0x1E8D0, 0x1E8D6);
     cat_mn.add_span(0x1E944, 0x1E94A);
     cat_mn.add_span(0xE0100, 0xE01EF);
@@ -2211,7 +2253,7 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_nd.add_span(0xABF0, 0xABF9);
     cat_nd.add_span(0xFF10, 0xFF19);
     cat_nd.add_span(0x104A0, 0x104A9);
-    cat_nd.add_span(0x10D30, 0x10D39);
+    cat_nd.add_span(0x10D30, 0x10D49);
     cat_nd.add_span(0x11066, 0x1106F);
     cat_nd.add_span(0x110F0, 0x110F9);
     cat_nd.add_span(0x11136, 0x1113F);
@@ -2220,21 +2262,26 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_nd.add_span(0x11450, 0x11459);
     cat_nd.add_span(0x114D0, 0x114D9);
     cat_nd.add_span(0x11650, 0x11659);
-    cat_nd.add_span(0x116C0, 0x116C9);
+    cat_nd.add_span(0x116C0, 0x116E3);
     cat_nd.add_span(0x11730, 0x11739);
     cat_nd.add_span(0x118E0, 0x118E9);
     cat_nd.add_span(0x11950, 0x11959);
+    cat_nd.add_span(0x11BF0, 0x11BF9);
     cat_nd.add_span(0x11C50, 0x11C59);
     cat_nd.add_span(0x11D50, 0x11D59);
     cat_nd.add_span(0x11DA0, 0x11DA9);
     cat_nd.add_span(0x11F50, 0x11F59);
+    cat_nd.add_span(0x16130, 0x16139);
     cat_nd.add_span(0x16A60, 0x16A69);
     cat_nd.add_span(0x16AC0, 0x16AC9);
     cat_nd.add_span(0x16B50, 0x16B59);
+    cat_nd.add_span(0x16D70, 0x16D79);
+    cat_nd.add_span(0x1CCF0, 0x1CCF9);
     cat_nd.add_span(0x1D7CE, 0x1D7FF);
     cat_nd.add_span(0x1E140, 0x1E149);
     cat_nd.add_span(0x1E2F0, 0x1E2F9);
     cat_nd.add_span(0x1E4F0, 0x1E4F9);
+    cat_nd.add_span(0x1E5F1, 0x1E5FA);
     cat_nd.add_span(0x1E950, 0x1E959);
     cat_nd.add_span(0x1FBF0, 0x1FBF9);
     let mut cat_nl = Category::new("Nl");
@@ -2346,6 +2393,7 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_pd.add_span(0xFE58, 0xFE58);
     cat_pd.add_span(0xFE63, 0xFE63);
     cat_pd.add_span(0xFF0D, 0xFF0D);
+    cat_pd.add_span(0x10D6E, 0x10D6E);
     cat_pd.add_span(0x10EAD, 0x10EAD);
     let mut cat_pe = Category::new("Pe");
     cat_pe.add_span(0x29, 0x29);
@@ -2507,8 +2555,9 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_po.add_span(0x1A1E, 0x1A1F);
     cat_po.add_span(0x1AA0, 0x1AA6);
     cat_po.add_span(0x1AA8, 0x1AAD);
+    cat_po.add_span(0x1B4E, 0x1B4F);
     cat_po.add_span(0x1B5A, 0x1B60);
-    cat_po.add_span(0x1B7D, 0x1B7E);
+    cat_po.add_span(0x1B7D, 0x1B7F);
     cat_po.add_span(0x1BFC, 0x1BFF);
     cat_po.add_span(0x1C3B, 0x1C3F);
     cat_po.add_span(0x1C7E, 0x1C7F);
@@ -2602,6 +2651,7 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_po.add_span(0x111DD, 0x111DF);
     cat_po.add_span(0x11238, 0x1123D);
     cat_po.add_span(0x112A9, 0x112A9);
+    cat_po.add_span(0x113D4, 0x113D8);
     cat_po.add_span(0x1144B, 0x1144F);
     cat_po.add_span(0x1145A, 0x1145D);
     cat_po.add_span(0x114C6, 0x114C6);
@@ -2617,6 +2667,7 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_po.add_span(0x11A9A, 0x11A9C);
     cat_po.add_span(0x11A9E, 0x11AA2);
     cat_po.add_span(0x11B00, 0x11B09);
+    cat_po.add_span(0x11BE1, 0x11BE1);
     cat_po.add_span(0x11C41, 0x11C45);
     cat_po.add_span(0x11C70, 0x11C71);
     cat_po.add_span(0x11EF7, 0x11EF8);
@@ -2628,10 +2679,12 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_po.add_span(0x16AF5, 0x16AF5);
     cat_po.add_span(0x16B37, 0x16B3B);
     cat_po.add_span(0x16B44, 0x16B44);
+    cat_po.add_span(0x16D6D, 0x16D6F);
     cat_po.add_span(0x16E97, 0x16E9A);
     cat_po.add_span(0x16FE2, 0x16FE2);
     cat_po.add_span(0x1BC9F, 0x1BC9F);
     cat_po.add_span(0x1DA87, 0x1DA8B);
+    cat_po.add_span(0x1E5FF, 0x1E5FF);
     cat_po.add_span(0x1E95E, 0x1E95F);
     let mut cat_ps = Category::new("Ps");
     cat_ps.add_span(0x28, 0x28);
@@ -2821,6 +2874,7 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_sm.add_span(0xFF5E, 0xFF5E);
     cat_sm.add_span(0xFFE2, 0xFFE2);
     cat_sm.add_span(0xFFE9, 0xFFEC);
+    cat_sm.add_span(0x10D8E, 0x10D8F);
     cat_sm.add_span(0x1D6C1, 0x1D6C1);
     cat_sm.add_span(0x1D6DB, 0x1D6DB);
     cat_sm.add_span(0x1D6FB, 0x1D6FB);
@@ -2951,6 +3005,8 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_so.add_span(0x16B3C, 0x16B3F);
     cat_so.add_span(0x16B45, 0x16B45);
     cat_so.add_span(0x1BC9C, 0x1BC9C);
+    cat_so.add_span(0x1CC00, 0x1CCEF);
+    cat_so.add_span(0x1CD00, 0x1CEB3);
     cat_so.add_span(0x1CF50, 0x1D164);
     cat_so.add_span(0x1D16A, 0x1D16C);
     cat_so.add_span(0x1D183, 0x1D184);
@@ -2968,7 +3024,7 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_so.add_span(0x1ED2E, 0x1ED2E);
     cat_so.add_span(0x1F000, 0x1F0F5);
     cat_so.add_span(0x1F10D, 0x1F3FA);
-    cat_so.add_span(0x1F400, 0x1FBCA);
+    cat_so.add_span(0x1F400, 0x1FBEF);
     let mut cat_zl = Category::new("Zl");
     cat_zl.add_span(0x2028, 0x2028);
     let mut cat_zp = Category::new("Zp");
@@ -2981,10 +3037,6 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_zs.add_span(0x202F, 0x202F);
     cat_zs.add_span(0x205F, 0x205F);
     cat_zs.add_span(0x3000, 0x3000);
-    let mut cat_n = Category::new("N");
-    cat_n.aggregate(&cat_nd);
-    cat_n.aggregate(&cat_nl);
-    cat_n.aggregate(&cat_no);
     let mut cat_c = Category::new("C");
     cat_c.aggregate(&cat_cc);
     cat_c.aggregate(&cat_cf);
@@ -3000,6 +3052,10 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_m.aggregate(&cat_mc);
     cat_m.aggregate(&cat_me);
     cat_m.aggregate(&cat_mn);
+    let mut cat_n = Category::new("N");
+    cat_n.aggregate(&cat_nd);
+    cat_n.aggregate(&cat_nl);
+    cat_n.aggregate(&cat_no);
     let mut cat_p = Category::new("P");
     cat_p.aggregate(&cat_pc);
     cat_p.aggregate(&cat_pd);
@@ -3008,15 +3064,15 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     cat_p.aggregate(&cat_pi);
     cat_p.aggregate(&cat_po);
     cat_p.aggregate(&cat_ps);
-    let mut cat_z = Category::new("Z");
-    cat_z.aggregate(&cat_zl);
-    cat_z.aggregate(&cat_zp);
-    cat_z.aggregate(&cat_zs);
     let mut cat_s = Category::new("S");
     cat_s.aggregate(&cat_sc);
     cat_s.aggregate(&cat_sk);
     cat_s.aggregate(&cat_sm);
     cat_s.aggregate(&cat_so);
+    let mut cat_z = Category::new("Z");
+    cat_z.aggregate(&cat_zl);
+    cat_z.aggregate(&cat_zp);
+    cat_z.aggregate(&cat_zs);
     db.insert("Cc", cat_cc);
     db.insert("Cf", cat_cf);
     db.insert("Co", cat_co);
@@ -3046,12 +3102,12 @@ pub fn get_categories() -> HashMap<&'static str, Category> {
     db.insert("Zl", cat_zl);
     db.insert("Zp", cat_zp);
     db.insert("Zs", cat_zs);
-    db.insert("N", cat_n);
     db.insert("C", cat_c);
     db.insert("L", cat_l);
     db.insert("M", cat_m);
+    db.insert("N", cat_n);
     db.insert("P", cat_p);
-    db.insert("Z", cat_z);
     db.insert("S", cat_s);
+    db.insert("Z", cat_z);
     db
 }
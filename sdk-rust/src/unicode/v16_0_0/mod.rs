@@ -0,0 +1,19 @@
+/*
+ * WARNING: this file has been generated by
+ * Hime Parser Generator
+ */
+
+//! Tables for version 16.0.0 of the Unicode Character Database
+
+mod blocks;
+mod casefolding;
+mod categories;
+mod properties;
+
+pub use blocks::get_blocks;
+pub use casefolding::CASE_FOLDING_CLASSES;
+pub use categories::get_categories;
+pub use properties::get_properties;
+
+/// The version of the Unicode Character Database for these tables
+pub const VERSION: &str = "16.0.0";
@@ -0,0 +1,6591 @@
+/*
+ * WARNING: this file has been generated by
+ * Hime Parser Generator
+ */
+
+use crate::unicode::Category;
+use std::collections::HashMap;
+
+/// Gets all scripts and binary properties
+#[allow(clippy::too_many_lines, clippy::unreadable_literal)]
+#[must_use]
+pub fn get_properties() -> HashMap<&'static str, Category> {
+    let mut db = HashMap::new();
+    let mut property = Category::new("Alphabetic");
+    property.add_span(0x41, 0x5A);
+    property.add_span(0x61, 0x7A);
+    property.add_span(0xAA, 0xAA);
+    property.add_span(0xB5, 0xB5);
+    property.add_span(0xBA, 0xBA);
+    property.add_span(0xC0, 0xD6);
+    property.add_span(0xD8, 0xF6);
+    property.add_span(0xF8, 0x2C1);
+    property.add_span(0x2C6, 0x2D1);
+    property.add_span(0x2E0, 0x2E4);
+    property.add_span(0x2EC, 0x2EC);
+    property.add_span(0x2EE, 0x2EE);
+    property.add_span(0x345, 0x345);
+    property.add_span(0x363, 0x374);
+    property.add_span(0x376, 0x377);
+    property.add_span(0x37A, 0x37D);
+    property.add_span(0x37F, 0x37F);
+    property.add_span(0x386, 0x386);
+    property.add_span(0x388, 0x38A);
+    property.add_span(0x38C, 0x38C);
+    property.add_span(0x38E, 0x3A1);
+    property.add_span(0x3A3, 0x3F5);
+    property.add_span(0x3F7, 0x481);
+    property.add_span(0x48A, 0x52F);
+    property.add_span(0x531, 0x556);
+    property.add_span(0x559, 0x559);
+    property.add_span(0x560, 0x588);
+    property.add_span(0x5B0, 0x5BD);
+    property.add_span(0x5BF, 0x5BF);
+    property.add_span(0x5C1, 0x5C2);
+    property.add_span(0x5C4, 0x5C5);
+    property.add_span(0x5C7, 0x5C7);
+    property.add_span(0x5D0, 0x5EA);
+    property.add_span(0x5EF, 0x5F2);
+    property.add_span(0x610, 0x61A);
+    property.add_span(0x620, 0x657);
+    property.add_span(0x659, 0x65F);
+    property.add_span(0x66E, 0x6D3);
+    property.add_span(0x6D5, 0x6DC);
+    property.add_span(0x6E1, 0x6E8);
+    property.add_span(0x6ED, 0x6EF);
+    property.add_span(0x6FA, 0x6FC);
+    property.add_span(0x6FF, 0x6FF);
+    property.add_span(0x710, 0x73F);
+    property.add_span(0x74D, 0x7B1);
+    property.add_span(0x7CA, 0x7EA);
+    property.add_span(0x7F4, 0x7F5);
+    property.add_span(0x7FA, 0x7FA);
+    property.add_span(0x800, 0x817);
+    property.add_span(0x81A, 0x82C);
+    property.add_span(0x840, 0x858);
+    property.add_span(0x860, 0x86A);
+    property.add_span(0x870, 0x887);
+    property.add_span(0x889, 0x88E);
+    property.add_span(0x897, 0x897);
+    property.add_span(0x8A0, 0x8C9);
+    property.add_span(0x8D4, 0x8DF);
+    property.add_span(0x8E3, 0x8E9);
+    property.add_span(0x8F0, 0x93B);
+    property.add_span(0x93D, 0x94C);
+    property.add_span(0x94E, 0x950);
+    property.add_span(0x955, 0x963);
+    property.add_span(0x971, 0x983);
+    property.add_span(0x985, 0x98C);
+    property.add_span(0x98F, 0x990);
+    property.add_span(0x993, 0x9A8);
+    property.add_span(0x9AA, 0x9B0);
+    property.add_span(0x9B2, 0x9B2);
+    property.add_span(0x9B6, 0x9B9);
+    property.add_span(0x9BD, 0x9C4);
+    property.add_span(0x9C7, 0x9C8);
+    property.add_span(0x9CB, 0x9CC);
+    property.add_span(0x9CE, 0x9CE);
+    property.add_span(0x9D7, 0x9D7);
+    property.add_span(0x9DC, 0x9DD);
+    property.add_span(0x9DF, 0x9E3);
+    property.add_span(0x9F0, 0x9F1);
+    property.add_span(0x9FC, 0x9FC);
+    property.add_span(0xA01, 0xA03);
+    property.add_span(0xA05, 0xA0A);
+    property.add_span(0xA0F, 0xA10);
+    property.add_span(0xA13, 0xA28);
+    property.add_span(0xA2A, 0xA30);
+    property.add_span(0xA32, 0xA33);
+    property.add_span(0xA35, 0xA36);
+    property.add_span(0xA38, 0xA39);
+    property.add_span(0xA3E, 0xA42);
+    property.add_span(0xA47, 0xA48);
+    property.add_span(0xA4B, 0xA4C);
+    property.add_span(0xA51, 0xA51);
+    property.add_span(0xA59, 0xA5C);
+    property.add_span(0xA5E, 0xA5E);
+    property.add_span(0xA70, 0xA75);
+    property.add_span(0xA81, 0xA83);
+    property.add_span(0xA85, 0xA8D);
+    property.add_span(0xA8F, 0xA91);
+    property.add_span(0xA93, 0xAA8);
+    property.add_span(0xAAA, 0xAB0);
+    property.add_span(0xAB2, 0xAB3);
+    property.add_span(0xAB5, 0xAB9);
+    property.add_span(0xABD, 0xAC5);
+    property.add_span(0xAC7, 0xAC9);
+    property.add_span(0xACB, 0xACC);
+    property.add_span(0xAD0, 0xAD0);
+    property.add_span(0xAE0, 0xAE3);
+    property.add_span(0xAF9, 0xAFC);
+    property.add_span(0xB01, 0xB03);
+    property.add_span(0xB05, 0xB0C);
+    property.add_span(0xB0F, 0xB10);
+    property.add_span(0xB13, 0xB28);
+    property.add_span(0xB2A, 0xB30);
+    property.add_span(0xB32, 0xB33);
+    property.add_span(0xB35, 0xB39);
+    property.add_span(0xB3D, 0xB44);
+    property.add_span(0xB47, 0xB48);
+    property.add_span(0xB4B, 0xB4C);
+    property.add_span(0xB56, 0xB57);
+    property.add_span(0xB5C, 0xB5D);
+    property.add_span(0xB5F, 0xB63);
+    property.add_span(0xB71, 0xB71);
+    property.add_span(0xB82, 0xB83);
+    property.add_span(0xB85, 0xB8A);
+    property.add_span(0xB8E, 0xB90);
+    property.add_span(0xB92, 0xB95);
+    property.add_span(0xB99, 0xB9A);
+    property.add_span(0xB9C, 0xB9C);
+    property.add_span(0xB9E, 0xB9F);
+    property.add_span(0xBA3, 0xBA4);
+    property.add_span(0xBA8, 0xBAA);
+    property.add_span(0xBAE, 0xBB9);
+    property.add_span(0xBBE, 0xBC2);
+    property.add_span(0xBC6, 0xBC8);
+    property.add_span(0xBCA, 0xBCC);
+    property.add_span(0xBD0, 0xBD0);
+    property.add_span(0xBD7, 0xBD7);
+    property.add_span(0xC00, 0xC0C);
+    property.add_span(0xC0E, 0xC10);
+    property.add_span(0xC12, 0xC28);
+    property.add_span(0xC2A, 0xC39);
+    property.add_span(0xC3D, 0xC44);
+    property.add_span(0xC46, 0xC48);
+    property.add_span(0xC4A, 0xC4C);
+    property.add_span(0xC55, 0xC56);
+    property.add_span(0xC58, 0xC5A);
+    property.add_span(0xC5D, 0xC5D);
+    property.add_span(0xC60, 0xC63);
+    property.add_span(0xC80, 0xC83);
+    property.add_span(0xC85, 0xC8C);
+    property.add_span(0xC8E, 0xC90);
+    property.add_span(0xC92, 0xCA8);
+    property.add_span(0xCAA, 0xCB3);
+    property.add_span(0xCB5, 0xCB9);
+    property.add_span(0xCBD, 0xCC4);
+    property.add_span(0xCC6, 0xCC8);
+    property.add_span(0xCCA, 0xCCC);
+    property.add_span(0xCD5, 0xCD6);
+    property.add_span(0xCDD, 0xCDE);
+    property.add_span(0xCE0, 0xCE3);
+    property.add_span(0xCF1, 0xCF3);
+    property.add_span(0xD00, 0xD0C);
+    property.add_span(0xD0E, 0xD10);
+    property.add_span(0xD12, 0xD3A);
+    property.add_span(0xD3D, 0xD44);
+    property.add_span(0xD46, 0xD48);
+    property.add_span(0xD4A, 0xD4C);
+    property.add_span(0xD4E, 0xD4E);
+    property.add_span(0xD54, 0xD57);
+    property.add_span(0xD5F, 0xD63);
+    property.add_span(0xD7A, 0xD7F);
+    property.add_span(0xD81, 0xD83);
+    property.add_span(0xD85, 0xD96);
+    property.add_span(0xD9A, 0xDB1);
+    property.add_span(0xDB3, 0xDBB);
+    property.add_span(0xDBD, 0xDBD);
+    property.add_span(0xDC0, 0xDC6);
+    property.add_span(0xDCF, 0xDD4);
+    property.add_span(0xDD6, 0xDD6);
+    property.add_span(0xDD8, 0xDDF);
+    property.add_span(0xDF2, 0xDF3);
+    property.add_span(0xE01, 0xE3A);
+    property.add_span(0xE40, 0xE46);
+    property.add_span(0xE4D, 0xE4D);
+    property.add_span(0xE81, 0xE82);
+    property.add_span(0xE84, 0xE84);
+    property.add_span(0xE86, 0xE8A);
+    property.add_span(0xE8C, 0xEA3);
+    property.add_span(0xEA5, 0xEA5);
+    property.add_span(0xEA7, 0xEB9);
+    property.add_span(0xEBB, 0xEBD);
+    property.add_span(0xEC0, 0xEC4);
+    property.add_span(0xEC6, 0xEC6);
+    property.add_span(0xECD, 0xECD);
+    property.add_span(0xEDC, 0xEDF);
+    property.add_span(0xF00, 0xF00);
+    property.add_span(0xF40, 0xF47);
+    property.add_span(0xF49, 0xF6C);
+    property.add_span(0xF71, 0xF83);
+    property.add_span(0xF88, 0xF97);
+    property.add_span(0xF99, 0xFBC);
+    property.add_span(0x1000, 0x1036);
+    property.add_span(0x1038, 0x1038);
+    property.add_span(0x103B, 0x103F);
+    property.add_span(0x1050, 0x108F);
+    property.add_span(0x109A, 0x109D);
+    property.add_span(0x10A0, 0x10C5);
+    property.add_span(0x10C7, 0x10C7);
+    property.add_span(0x10CD, 0x10CD);
+    property.add_span(0x10D0, 0x10FA);
+    property.add_span(0x10FC, 0x1248);
+    property.add_span(0x124A, 0x124D);
+    property.add_span(0x1250, 0x1256);
+    property.add_span(0x1258, 0x1258);
+    property.add_span(0x125A, 0x125D);
+    property.add_span(0x1260, 0x1288);
+    property.add_span(0x128A, 0x128D);
+    property.add_span(0x1290, 0x12B0);
+    property.add_span(0x12B2, 0x12B5);
+    property.add_span(0x12B8, 0x12BE);
+    property.add_span(0x12C0, 0x12C0);
+    property.add_span(0x12C2, 0x12C5);
+    property.add_span(0x12C8, 0x12D6);
+    property.add_span(0x12D8, 0x1310);
+    property.add_span(0x1312, 0x1315);
+    property.add_span(0x1318, 0x135A);
+    property.add_span(0x1380, 0x138F);
+    property.add_span(0x13A0, 0x13F5);
+    property.add_span(0x13F8, 0x13FD);
+    property.add_span(0x1401, 0x166C);
+    property.add_span(0x166F, 0x167F);
+    property.add_span(0x1681, 0x169A);
+    property.add_span(0x16A0, 0x16EA);
+    property.add_span(0x16EE, 0x16F8);
+    property.add_span(0x1700, 0x1713);
+    property.add_span(0x171F, 0x1733);
+    property.add_span(0x1740, 0x1753);
+    property.add_span(0x1760, 0x176C);
+    property.add_span(0x176E, 0x1770);
+    property.add_span(0x1772, 0x1773);
+    property.add_span(0x1780, 0x17B3);
+    property.add_span(0x17B6, 0x17C8);
+    property.add_span(0x17D7, 0x17D7);
+    property.add_span(0x17DC, 0x17DC);
+    property.add_span(0x1820, 0x1878);
+    property.add_span(0x1880, 0x18AA);
+    property.add_span(0x18B0, 0x18F5);
+    property.add_span(0x1900, 0x191E);
+    property.add_span(0x1920, 0x192B);
+    property.add_span(0x1930, 0x1938);
+    property.add_span(0x1950, 0x196D);
+    property.add_span(0x1970, 0x1974);
+    property.add_span(0x1980, 0x19AB);
+    property.add_span(0x19B0, 0x19C9);
+    property.add_span(0x1A00, 0x1A1B);
+    property.add_span(0x1A20, 0x1A5E);
+    property.add_span(0x1A61, 0x1A74);
+    property.add_span(0x1AA7, 0x1AA7);
+    property.add_span(0x1ABF, 0x1AC0);
+    property.add_span(0x1ACC, 0x1ACE);
+    property.add_span(0x1B00, 0x1B33);
+    property.add_span(0x1B35, 0x1B43);
+    property.add_span(0x1B45, 0x1B4C);
+    property.add_span(0x1B80, 0x1BA9);
+    property.add_span(0x1BAC, 0x1BAF);
+    property.add_span(0x1BBA, 0x1BE5);
+    property.add_span(0x1BE7, 0x1BF1);
+    property.add_span(0x1C00, 0x1C36);
+    property.add_span(0x1C4D, 0x1C4F);
+    property.add_span(0x1C5A, 0x1C7D);
+    property.add_span(0x1C80, 0x1C8A);
+    property.add_span(0x1C90, 0x1CBA);
+    property.add_span(0x1CBD, 0x1CBF);
+    property.add_span(0x1CE9, 0x1CEC);
+    property.add_span(0x1CEE, 0x1CF3);
+    property.add_span(0x1CF5, 0x1CF6);
+    property.add_span(0x1CFA, 0x1CFA);
+    property.add_span(0x1D00, 0x1DBF);
+    property.add_span(0x1DD3, 0x1DF4);
+    property.add_span(0x1E00, 0x1F15);
+    property.add_span(0x1F18, 0x1F1D);
+    property.add_span(0x1F20, 0x1F45);
+    property.add_span(0x1F48, 0x1F4D);
+    property.add_span(0x1F50, 0x1F57);
+    property.add_span(0x1F59, 0x1F59);
+    property.add_span(0x1F5B, 0x1F5B);
+    property.add_span(0x1F5D, 0x1F5D);
+    property.add_span(0x1F5F, 0x1F7D);
+    property.add_span(0x1F80, 0x1FB4);
+    property.add_span(0x1FB6, 0x1FBC);
+    property.add_span(0x1FBE, 0x1FBE);
+    property.add_span(0x1FC2, 0x1FC4);
+    property.add_span(0x1FC6, 0x1FCC);
+    property.add_span(0x1FD0, 0x1FD3);
+    property.add_span(0x1FD6, 0x1FDB);
+    property.add_span(0x1FE0, 0x1FEC);
+    property.add_span(0x1FF2, 0x1FF4);
+    property.add_span(0x1FF6, 0x1FFC);
+    property.add_span(0x2071, 0x2071);
+    property.add_span(0x207F, 0x207F);
+    property.add_span(0x2090, 0x209C);
+    property.add_span(0x2102, 0x2102);
+    property.add_span(0x2107, 0x2107);
+    property.add_span(0x210A, 0x2113);
+    property.add_span(0x2115, 0x2115);
+    property.add_span(0x2119, 0x211D);
+    property.add_span(0x2124, 0x2124);
+    property.add_span(0x2126, 0x2126);
+    property.add_span(0x2128, 0x2128);
+    property.add_span(0x212A, 0x212D);
+    property.add_span(0x212F, 0x2139);
+    property.add_span(0x213C, 0x213F);
+    property.add_span(0x2145, 0x2149);
+    property.add_span(0x214E, 0x214E);
+    property.add_span(0x2160, 0x2188);
+    property.add_span(0x24B6, 0x24E9);
+    property.add_span(0x2C00, 0x2CE4);
+    property.add_span(0x2CEB, 0x2CEE);
+    property.add_span(0x2CF2, 0x2CF3);
+    property.add_span(0x2D00, 0x2D25);
+    property.add_span(0x2D27, 0x2D27);
+    property.add_span(0x2D2D, 0x2D2D);
+    property.add_span(0x2D30, 0x2D67);
+    property.add_span(0x2D6F, 0x2D6F);
+    property.add_span(0x2D80, 0x2D96);
+    property.add_span(0x2DA0, 0x2DA6);
+    property.add_span(0x2DA8, 0x2DAE);
+    property.add_span(0x2DB0, 0x2DB6);
+    property.add_span(0x2DB8, 0x2DBE);
+    property.add_span(0x2DC0, 0x2DC6);
+    property.add_span(0x2DC8, 0x2DCE);
+    property.add_span(0x2DD0, 0x2DD6);
+    property.add_span(0x2DD8, 0x2DDE);
+    property.add_span(0x2DE0, 0x2DFF);
+    property.add_span(0x2E2F, 0x2E2F);
+    property.add_span(0x3005, 0x3007);
+    property.add_span(0x3021, 0x3029);
+    property.add_span(0x3031, 0x3035);
+    property.add_span(0x3038, 0x303C);
+    property.add_span(0x3041, 0x3096);
+    property.add_span(0x309D, 0x309F);
+    property.add_span(0x30A1, 0x30FA);
+    property.add_span(0x30FC, 0x30FF);
+    property.add_span(0x3105, 0x312F);
+    property.add_span(0x3131, 0x318E);
+    property.add_span(0x31A0, 0x31BF);
+    property.add_span(0x31F0, 0x31FF);
+    property.add_span(0x3400, 0x4DBF);
+    property.add_span(0x4E00, 0xA48C);
+    property.add_span(0xA4D0, 0xA4FD);
+    property.add_span(0xA500, 0xA60C);
+    property.add_span(0xA610, 0xA61F);
+    property.add_span(0xA62A, 0xA62B);
+    property.add_span(0xA640, 0xA66E);
+    property.add_span(0xA674, 0xA67B);
+    property.add_span(0xA67F, 0xA6EF);
+    property.add_span(0xA717, 0xA71F);
+    property.add_span(0xA722, 0xA788);
+    property.add_span(0xA78B, 0xA7CD);
+    property.add_span(0xA7D0, 0xA7D1);
+    property.add_span(0xA7D3, 0xA7D3);
+    property.add_span(0xA7D5, 0xA7DC);
+    property.add_span(0xA7F2, 0xA805);
+    property.add_span(0xA807, 0xA827);
+    property.add_span(0xA840, 0xA873);
+    property.add_span(0xA880, 0xA8C3);
+    property.add_span(0xA8C5, 0xA8C5);
+    property.add_span(0xA8F2, 0xA8F7);
+    property.add_span(0xA8FB, 0xA8FB);
+    property.add_span(0xA8FD, 0xA8FF);
+    property.add_span(0xA90A, 0xA92A);
+    property.add_span(0xA930, 0xA952);
+    property.add_span(0xA960, 0xA97C);
+    property.add_span(0xA980, 0xA9B2);
+    property.add_span(0xA9B4, 0xA9BF);
+    property.add_span(0xA9CF, 0xA9CF);
+    property.add_span(0xA9E0, 0xA9EF);
+    property.add_span(0xA9FA, 0xA9FE);
+    property.add_span(0xAA00, 0xAA36);
+    property.add_span(0xAA40, 0xAA4D);
+    property.add_span(0xAA60, 0xAA76);
+    property.add_span(0xAA7A, 0xAABE);
+    property.add_span(0xAAC0, 0xAAC0);
+    property.add_span(0xAAC2, 0xAAC2);
+    property.add_span(0xAADB, 0xAADD);
+    property.add_span(0xAAE0, 0xAAEF);
+    property.add_span(0xAAF2, 0xAAF5);
+    property.add_span(0xAB01, 0xAB06);
+    property.add_span(0xAB09, 0xAB0E);
+    property.add_span(0xAB11, 0xAB16);
+    property.add_span(0xAB20, 0xAB26);
+    property.add_span(0xAB28, 0xAB2E);
+    property.add_span(0xAB30, 0xAB5A);
+    property.add_span(0xAB5C, 0xAB69);
+    property.add_span(0xAB70, 0xABEA);
+    property.add_span(0xAC00, 0xD7A3);
+    property.add_span(0xD7B0, 0xD7C6);
+    property.add_span(0xD7CB, 0xD7FB);
+    property.add_span(0xF900, 0xFA6D);
+    property.add_span(0xFA70, 0xFAD9);
+    property.add_span(0xFB00, 0xFB06);
+    property.add_span(0xFB13, 0xFB17);
+    property.add_span(0xFB1D, 0xFB28);
+    property.add_span(0xFB2A, 0xFB36);
+    property.add_span(0xFB38, 0xFB3C);
+    property.add_span(0xFB3E, 0xFB3E);
+    property.add_span(0xFB40, 0xFB41);
+    property.add_span(0xFB43, 0xFB44);
+    property.add_span(0xFB46, 0xFBB1);
+    property.add_span(0xFBD3, 0xFD3D);
+    property.add_span(0xFD50, 0xFD8F);
+    property.add_span(0xFD92, 0xFDC7);
+    property.add_span(0xFDF0, 0xFDFB);
+    property.add_span(0xFE70, 0xFE74);
+    property.add_span(0xFE76, 0xFEFC);
+    property.add_span(0xFF21, 0xFF3A);
+    property.add_span(0xFF41, 0xFF5A);
+    property.add_span(0xFF66, 0xFFBE);
+    property.add_span(0xFFC2, 0xFFC7);
+    property.add_span(0xFFCA, 0xFFCF);
+    property.add_span(0xFFD2, 0xFFD7);
+    property.add_span(0xFFDA, 0xFFDC);
+    property.add_span(0x10000, 0x1000B);
+    property.add_span(0x1000D, 0x10026);
+    property.add_span(0x10028, 0x1003A);
+    property.add_span(0x1003C, 0x1003D);
+    property.add_span(0x1003F, 0x1004D);
+    property.add_span(0x10050, 0x1005D);
+    property.add_span(0x10080, 0x100FA);
+    property.add_span(0x10140, 0x10174);
+    property.add_span(0x10280, 0x1029C);
+    property.add_span(0x102A0, 0x102D0);
+    property.add_span(0x10300, 0x1031F);
+    property.add_span(0x1032D, 0x1034A);
+    property.add_span(0x10350, 0x1037A);
+    property.add_span(0x10380, 0x1039D);
+    property.add_span(0x103A0, 0x103C3);
+    property.add_span(0x103C8, 0x103CF);
+    property.add_span(0x103D1, 0x103D5);
+    property.add_span(0x10400, 0x1049D);
+    property.add_span(0x104B0, 0x104D3);
+    property.add_span(0x104D8, 0x104FB);
+    property.add_span(0x10500, 0x10527);
+    property.add_span(0x10530, 0x10563);
+    property.add_span(0x10570, 0x1057A);
+    property.add_span(0x1057C, 0x1058A);
+    property.add_span(0x1058C, 0x10592);
+    property.add_span(0x10594, 0x10595);
+    property.add_span(0x10597, 0x105A1);
+    property.add_span(0x105A3, 0x105B1);
+    property.add_span(0x105B3, 0x105B9);
+    property.add_span(0x105BB, 0x105BC);
+    property.add_span(0x105C0, 0x105F3);
+    property.add_span(0x10600, 0x10736);
+    property.add_span(0x10740, 0x10755);
+    property.add_span(0x10760, 0x10767);
+    property.add_span(0x10780, 0x10785);
+    property.add_span(0x10787, 0x107B0);
+    property.add_span(0x107B2, 0x107BA);
+    property.add_span(0x10800, 0x10805);
+    property.add_span(0x10808, 0x10808);
+    property.add_span(0x1080A, 0x10835);
+    property.add_span(0x10837, 0x10838);
+    property.add_span(0x1083C, 0x1083C);
+    property.add_span(0x1083F, 0x10855);
+    property.add_span(0x10860, 0x10876);
+    property.add_span(0x10880, 0x1089E);
+    property.add_span(0x108E0, 0x108F2);
+    property.add_span(0x108F4, 0x108F5);
+    property.add_span(0x10900, 0x10915);
+    property.add_span(0x10920, 0x10939);
+    property.add_span(0x10980, 0x109B7);
+    property.add_span(0x109BE, 0x109BF);
+    property.add_span(0x10A00, 0x10A03);
+    property.add_span(0x10A05, 0x10A06);
+    property.add_span(0x10A0C, 0x10A13);
+    property.add_span(0x10A15, 0x10A17);
+    property.add_span(0x10A19, 0x10A35);
+    property.add_span(0x10A60, 0x10A7C);
+    property.add_span(0x10A80, 0x10A9C);
+    property.add_span(0x10AC0, 0x10AC7);
+    property.add_span(0x10AC9, 0x10AE4);
+    property.add_span(0x10B00, 0x10B35);
+    property.add_span(0x10B40, 0x10B55);
+    property.add_span(0x10B60, 0x10B72);
+    property.add_span(0x10B80, 0x10B91);
+    property.add_span(0x10C00, 0x10C48);
+    property.add_span(0x10C80, 0x10CB2);
+    property.add_span(0x10CC0, 0x10CF2);
+    property.add_span(0x10D00, 0x10D27);
+    property.add_span(0x10D4A, 0x10D65);
+    property.add_span(0x10D69, 0x10D69);
+    property.add_span(0x10D6F, 0x10D85);
+    property.add_span(0x10E80, 0x10EA9);
+    property.add_span(0x10EAB, 0x10EAC);
+    property.add_span(0x10EB0, 0x10EB1);
+    property.add_span(0x10EC2, 0x10EC4);
+    property.add_span(0x10EFC, 0x10EFC);
+    property.add_span(0x10F00, 0x10F1C);
+    property.add_span(0x10F27, 0x10F27);
+    property.add_span(0x10F30, 0x10F45);
+    property.add_span(0x10F70, 0x10F81);
+    property.add_span(0x10FB0, 0x10FC4);
+    property.add_span(0x10FE0, 0x10FF6);
+    property.add_span(0x11000, 0x11045);
+    property.add_span(0x11071, 0x11075);
+    property.add_span(0x11080, 0x110B8);
+    property.add_span(0x110C2, 0x110C2);
+    property.add_span(0x110D0, 0x110E8);
+    property.add_span(0x11100, 0x11132);
+    property.add_span(0x11144, 0x11147);
+    property.add_span(0x11150, 0x11172);
+    property.add_span(0x11176, 0x11176);
+    property.add_span(0x11180, 0x111BF);
+    property.add_span(0x111C1, 0x111C4);
+    property.add_span(0x111CE, 0x111CF);
+    property.add_span(0x111DA, 0x111DA);
+    property.add_span(0x111DC, 0x111DC);
+    property.add_span(0x11200, 0x11211);
+    property.add_span(0x11213, 0x11234);
+    property.add_span(0x11237, 0x11237);
+    property.add_span(0x1123E, 0x11241);
+    property.add_span(0x11280, 0x11286);
+    property.add_span(0x11288, 0x11288);
+    property.add_span(0x1128A, 0x1128D);
+    property.add_span(0x1128F, 0x1129D);
+    property.add_span(0x1129F, 0x112A8);
+    property.add_span(0x112B0, 0x112E8);
+    property.add_span(0x11300, 0x11303);
+    property.add_span(0x11305, 0x1130C);
+    property.add_span(0x1130F, 0x11310);
+    property.add_span(0x11313, 0x11328);
+    property.add_span(0x1132A, 0x11330);
+    property.add_span(0x11332, 0x11333);
+    property.add_span(0x11335, 0x11339);
+    property.add_span(0x1133D, 0x11344);
+    property.add_span(0x11347, 0x11348);
+    property.add_span(0x1134B, 0x1134C);
+    property.add_span(0x11350, 0x11350);
+    property.add_span(0x11357, 0x11357);
+    property.add_span(0x1135D, 0x11363);
+    property.add_span(0x11380, 0x11389);
+    property.add_span(0x1138B, 0x1138B);
+    property.add_span(0x1138E, 0x1138E);
+    property.add_span(0x11390, 0x113B5);
+    property.add_span(0x113B7, 0x113C0);
+    property.add_span(0x113C2, 0x113C2);
+    property.add_span(0x113C5, 0x113C5);
+    property.add_span(0x113C7, 0x113CA);
+    property.add_span(0x113CC, 0x113CD);
+    property.add_span(0x113D1, 0x113D1);
+    property.add_span(0x113D3, 0x113D3);
+    property.add_span(0x11400, 0x11441);
+    property.add_span(0x11443, 0x11445);
+    property.add_span(0x11447, 0x1144A);
+    property.add_span(0x1145F, 0x11461);
+    property.add_span(0x11480, 0x114C1);
+    property.add_span(0x114C4, 0x114C5);
+    property.add_span(0x114C7, 0x114C7);
+    property.add_span(0x11580, 0x115B5);
+    property.add_span(0x115B8, 0x115BE);
+    property.add_span(0x115D8, 0x115DD);
+    property.add_span(0x11600, 0x1163E);
+    property.add_span(0x11640, 0x11640);
+    property.add_span(0x11644, 0x11644);
+    property.add_span(0x11680, 0x116B5);
+    property.add_span(0x116B8, 0x116B8);
+    property.add_span(0x11700, 0x1171A);
+    property.add_span(0x1171D, 0x1172A);
+    property.add_span(0x11740, 0x11746);
+    property.add_span(0x11800, 0x11838);
+    property.add_span(0x118A0, 0x118DF);
+    property.add_span(0x118FF, 0x11906);
+    property.add_span(0x11909, 0x11909);
+    property.add_span(0x1190C, 0x11913);
+    property.add_span(0x11915, 0x11916);
+    property.add_span(0x11918, 0x11935);
+    property.add_span(0x11937, 0x11938);
+    property.add_span(0x1193B, 0x1193C);
+    property.add_span(0x1193F, 0x11942);
+    property.add_span(0x119A0, 0x119A7);
+    property.add_span(0x119AA, 0x119D7);
+    property.add_span(0x119DA, 0x119DF);
+    property.add_span(0x119E1, 0x119E1);
+    property.add_span(0x119E3, 0x119E4);
+    property.add_span(0x11A00, 0x11A32);
+    property.add_span(0x11A35, 0x11A3E);
+    property.add_span(0x11A50, 0x11A97);
+    property.add_span(0x11A9D, 0x11A9D);
+    property.add_span(0x11AB0, 0x11AF8);
+    property.add_span(0x11BC0, 0x11BE0);
+    property.add_span(0x11C00, 0x11C08);
+    property.add_span(0x11C0A, 0x11C36);
+    property.add_span(0x11C38, 0x11C3E);
+    property.add_span(0x11C40, 0x11C40);
+    property.add_span(0x11C72, 0x11C8F);
+    property.add_span(0x11C92, 0x11CA7);
+    property.add_span(0x11CA9, 0x11CB6);
+    property.add_span(0x11D00, 0x11D06);
+    property.add_span(0x11D08, 0x11D09);
+    property.add_span(0x11D0B, 0x11D36);
+    property.add_span(0x11D3A, 0x11D3A);
+    property.add_span(0x11D3C, 0x11D3D);
+    property.add_span(0x11D3F, 0x11D41);
+    property.add_span(0x11D43, 0x11D43);
+    property.add_span(0x11D46, 0x11D47);
+    property.add_span(0x11D60, 0x11D65);
+    property.add_span(0x11D67, 0x11D68);
+    property.add_span(0x11D6A, 0x11D8E);
+    property.add_span(0x11D90, 0x11D91);
+    property.add_span(0x11D93, 0x11D96);
+    property.add_span(0x11D98, 0x11D98);
+    property.add_span(0x11EE0, 0x11EF6);
+    property.add_span(0x11F00, 0x11F10);
+    property.add_span(0x11F12, 0x11F3A);
+    property.add_span(0x11F3E, 0x11F40);
+    property.add_span(0x11FB0, 0x11FB0);
+    property.add_span(0x12000, 0x12399);
+    property.add_span(0x12400, 0x1246E);
+    property.add_span(0x12480, 0x12543);
+    property.add_span(0x12F90, 0x12FF0);
+    property.add_span(0x13000, 0x1342F);
+    property.add_span(0x13441, 0x13446);
+    property.add_span(0x13460, 0x143FA);
+    property.add_span(0x14400, 0x14646);
+    property.add_span(0x16100, 0x1612E);
+    property.add_span(0x16800, 0x16A38);
+    property.add_span(0x16A40, 0x16A5E);
+    property.add_span(0x16A70, 0x16ABE);
+    property.add_span(0x16AD0, 0x16AED);
+    property.add_span(0x16B00, 0x16B2F);
+    property.add_span(0x16B40, 0x16B43);
+    property.add_span(0x16B63, 0x16B77);
+    property.add_span(0x16B7D, 0x16B8F);
+    property.add_span(0x16D40, 0x16D6C);
+    property.add_span(0x16E40, 0x16E7F);
+    property.add_span(0x16F00, 0x16F4A);
+    property.add_span(0x16F4F, 0x16F87);
+    property.add_span(0x16F8F, 0x16F9F);
+    property.add_span(0x16FE0, 0x16FE1);
+    property.add_span(0x16FE3, 0x16FE3);
+    property.add_span(0x16FF0, 0x16FF1);
+    property.add_span(0x17000, 0x187F7);
+    property.add_span(0x18800, 0x18CD5);
+    property.add_span(0x18CFF, 0x18D08);
+    property.add_span(0x1AFF0, 0x1AFF3);
+    property.add_span(0x1AFF5, 0x1AFFB);
+    property.add_span(0x1AFFD, 0x1AFFE);
+    property.add_span(0x1B000, 0x1B122);
+    property.add_span(0x1B132, 0x1B132);
+    property.add_span(0x1B150, 0x1B152);
+    property.add_span(0x1B155, 0x1B155);
+    property.add_span(0x1B164, 0x1B167);
+    property.add_span(0x1B170, 0x1B2FB);
+    property.add_span(0x1BC00, 0x1BC6A);
+    property.add_span(0x1BC70, 0x1BC7C);
+    property.add_span(0x1BC80, 0x1BC88);
+    property.add_span(0x1BC90, 0x1BC99);
+    property.add_span(0x1BC9E, 0x1BC9E);
+    property.add_span(0x1D400, 0x1D454);
+    property.add_span(0x1D456, 0x1D49C);
+    property.add_span(0x1D49E, 0x1D49F);
+    property.add_span(0x1D4A2, 0x1D4A2);
+    property.add_span(0x1D4A5, 0x1D4A6);
+    property.add_span(0x1D4A9, 0x1D4AC);
+    property.add_span(0x1D4AE, 0x1D4B9);
+    property.add_span(0x1D4BB, 0x1D4BB);
+    property.add_span(0x1D4BD, 0x1D4C3);
+    property.add_span(0x1D4C5, 0x1D505);
+    property.add_span(0x1D507, 0x1D50A);
+    property.add_span(0x1D50D, 0x1D514);
+    property.add_span(0x1D516, 0x1D51C);
+    property.add_span(0x1D51E, 0x1D539);
+    property.add_span(0x1D53B, 0x1D53E);
+    property.add_span(0x1D540, 0x1D544);
+    property.add_span(0x1D546, 0x1D546);
+    property.add_span(0x1D54A, 0x1D550);
+    property.add_span(0x1D552, 0x1D6A5);
+    property.add_span(0x1D6A8, 0x1D6C0);
+    property.add_span(0x1D6C2, 0x1D6DA);
+    property.add_span(0x1D6DC, 0x1D6FA);
+    property.add_span(0x1D6FC, 0x1D714);
+    property.add_span(0x1D716, 0x1D734);
+    property.add_span(0x1D736, 0x1D74E);
+    property.add_span(0x1D750, 0x1D76E);
+    property.add_span(0x1D770, 0x1D788);
+    property.add_span(0x1D78A, 0x1D7A8);
+    property.add_span(0x1D7AA, 0x1D7C2);
+    property.add_span(0x1D7C4, 0x1D7CB);
+    property.add_span(0x1DF00, 0x1DF1E);
+    property.add_span(0x1DF25, 0x1DF2A);
+    property.add_span(0x1E000, 0x1E006);
+    property.add_span(0x1E008, 0x1E018);
+    property.add_span(0x1E01B, 0x1E021);
+    property.add_span(0x1E023, 0x1E024);
+    property.add_span(0x1E026, 0x1E02A);
+    property.add_span(0x1E030, 0x1E06D);
+    property.add_span(0x1E08F, 0x1E08F);
+    property.add_span(0x1E100, 0x1E12C);
+    property.add_span(0x1E137, 0x1E13D);
+    property.add_span(0x1E14E, 0x1E14E);
+    property.add_span(0x1E290, 0x1E2AD);
+    property.add_span(0x1E2C0, 0x1E2EB);
+    property.add_span(0x1E4D0, 0x1E4EB);
+    property.add_span(0x1E5D0, 0x1E5ED);
+    property.add_span(0x1E5F0, 0x1E5F0);
+    property.add_span(0x1E7E0, 0x1E7E6);
+    property.add_span(0x1E7E8, 0x1E7EB);
+    property.add_span(0x1E7ED, 0x1E7EE);
+    property.add_span(0x1E7F0, 0x1E7FE);
+    property.add_span(0x1E800, 0x1E8C4);
+    property.add_span(0x1E900, 0x1E943);
+    property.add_span(0x1E947, 0x1E947);
+    property.add_span(0x1E94B, 0x1E94B);
+    property.add_span(0x1EE00, 0x1EE03);
+    property.add_span(0x1EE05, 0x1EE1F);
+    property.add_span(0x1EE21, 0x1EE22);
+    property.add_span(0x1EE24, 0x1EE24);
+    property.add_span(0x1EE27, 0x1EE27);
+    property.add_span(0x1EE29, 0x1EE32);
+    property.add_span(0x1EE34, 0x1EE37);
+    property.add_span(0x1EE39, 0x1EE39);
+    property.add_span(0x1EE3B, 0x1EE3B);
+    property.add_span(0x1EE42, 0x1EE42);
+    property.add_span(0x1EE47, 0x1EE47);
+    property.add_span(0x1EE49, 0x1EE49);
+    property.add_span(0x1EE4B, 0x1EE4B);
+    property.add_span(0x1EE4D, 0x1EE4F);
+    property.add_span(0x1EE51, 0x1EE52);
+    property.add_span(0x1EE54, 0x1EE54);
+    property.add_span(0x1EE57, 0x1EE57);
+    property.add_span(0x1EE59, 0x1EE59);
+    property.add_span(0x1EE5B, 0x1EE5B);
+    property.add_span(0x1EE5D, 0x1EE5D);
+    property.add_span(0x1EE5F, 0x1EE5F);
+    property.add_span(0x1EE61, 0x1EE62);
+    property.add_span(0x1EE64, 0x1EE64);
+    property.add_span(0x1EE67, 0x1EE6A);
+    property.add_span(0x1EE6C, 0x1EE72);
+    property.add_span(0x1EE74, 0x1EE77);
+    property.add_span(0x1EE79, 0x1EE7C);
+    property.add_span(0x1EE7E, 0x1EE7E);
+    property.add_span(0x1EE80, 0x1EE89);
+    property.add_span(0x1EE8B, 0x1EE9B);
+    property.add_span(0x1EEA1, 0x1EEA3);
+    property.add_span(0x1EEA5, 0x1EEA9);
+    property.add_span(0x1EEAB, 0x1EEBB);
+    property.add_span(0x1F130, 0x1F149);
+    property.add_span(0x1F150, 0x1F169);
+    property.add_span(0x1F170, 0x1F189);
+    property.add_span(0x20000, 0x2A6DF);
+    property.add_span(0x2A700, 0x2B739);
+    property.add_span(0x2B740, 0x2B81D);
+    property.add_span(0x2B820, 0x2CEA1);
+    property.add_span(0x2CEB0, 0x2EBE0);
+    property.add_span(0x2EBF0, 0x2EE5D);
+    property.add_span(0x2F800, 0x2FA1D);
+    property.add_span(0x30000, 0x3134A);
+    property.add_span(0x31350, 0x323AF);
+    db.insert("Alphabetic", property);
+    let mut property = Category::new("Emoji");
+    property.add_span(0x23, 0x23);
+    property.add_span(0x2A, 0x2A);
+    property.add_span(0x30, 0x39);
+    property.add_span(0xA9, 0xA9);
+    property.add_span(0xAE, 0xAE);
+    property.add_span(0x203C, 0x203C);
+    property.add_span(0x2049, 0x2049);
+    property.add_span(0x2122, 0x2122);
+    property.add_span(0x2139, 0x2139);
+    property.add_span(0x2194, 0x2199);
+    property.add_span(0x21A9, 0x21AA);
+    property.add_span(0x231A, 0x231B);
+    property.add_span(0x2328, 0x2328);
+    property.add_span(0x23CF, 0x23CF);
+    property.add_span(0x23E9, 0x23F3);
+    property.add_span(0x23F8, 0x23FA);
+    property.add_span(0x24C2, 0x24C2);
+    property.add_span(0x25AA, 0x25AB);
+    property.add_span(0x25B6, 0x25B6);
+    property.add_span(0x25C0, 0x25C0);
+    property.add_span(0x25FB, 0x25FE);
+    property.add_span(0x2600, 0x2604);
+    property.add_span(0x260E, 0x260E);
+    property.add_span(0x2611, 0x2611);
+    property.add_span(0x2614, 0x2615);
+    property.add_span(0x2618, 0x2618);
+    property.add_span(0x261D, 0x261D);
+    property.add_span(0x2620, 0x2620);
+    property.add_span(0x2622, 0x2623);
+    property.add_span(0x2626, 0x2626);
+    property.add_span(0x262A, 0x262A);
+    property.add_span(0x262E, 0x262F);
+    property.add_span(0x2638, 0x263A);
+    property.add_span(0x2640, 0x2640);
+    property.add_span(0x2642, 0x2642);
+    property.add_span(0x2648, 0x2653);
+    property.add_span(0x265F, 0x2660);
+    property.add_span(0x2663, 0x2663);
+    property.add_span(0x2665, 0x2666);
+    property.add_span(0x2668, 0x2668);
+    property.add_span(0x267B, 0x267B);
+    property.add_span(0x267E, 0x267F);
+    property.add_span(0x2692, 0x2697);
+    property.add_span(0x2699, 0x2699);
+    property.add_span(0x269B, 0x269C);
+    property.add_span(0x26A0, 0x26A1);
+    property.add_span(0x26A7, 0x26A7);
+    property.add_span(0x26AA, 0x26AB);
+    property.add_span(0x26B0, 0x26B1);
+    property.add_span(0x26BD, 0x26BE);
+    property.add_span(0x26C4, 0x26C5);
+    property.add_span(0x26C8, 0x26C8);
+    property.add_span(0x26CE, 0x26CF);
+    property.add_span(0x26D1, 0x26D1);
+    property.add_span(0x26D3, 0x26D4);
+    property.add_span(0x26E9, 0x26EA);
+    property.add_span(0x26F0, 0x26F5);
+    property.add_span(0x26F7, 0x26FA);
+    property.add_span(0x26FD, 0x26FD);
+    property.add_span(0x2702, 0x2702);
+    property.add_span(0x2705, 0x2705);
+    property.add_span(0x2708, 0x270D);
+    property.add_span(0x270F, 0x270F);
+    property.add_span(0x2712, 0x2712);
+    property.add_span(0x2714, 0x2714);
+    property.add_span(0x2716, 0x2716);
+    property.add_span(0x271D, 0x271D);
+    property.add_span(0x2721, 0x2721);
+    property.add_span(0x2728, 0x2728);
+    property.add_span(0x2733, 0x2734);
+    property.add_span(0x2744, 0x2744);
+    property.add_span(0x2747, 0x2747);
+    property.add_span(0x274C, 0x274C);
+    property.add_span(0x274E, 0x274E);
+    property.add_span(0x2753, 0x2755);
+    property.add_span(0x2757, 0x2757);
+    property.add_span(0x2763, 0x2764);
+    property.add_span(0x2795, 0x2797);
+    property.add_span(0x27A1, 0x27A1);
+    property.add_span(0x27B0, 0x27B0);
+    property.add_span(0x27BF, 0x27BF);
+    property.add_span(0x2934, 0x2935);
+    property.add_span(0x2B05, 0x2B07);
+    property.add_span(0x2B1B, 0x2B1C);
+    property.add_span(0x2B50, 0x2B50);
+    property.add_span(0x2B55, 0x2B55);
+    property.add_span(0x3030, 0x3030);
+    property.add_span(0x303D, 0x303D);
+    property.add_span(0x3297, 0x3297);
+    property.add_span(0x3299, 0x3299);
+    property.add_span(0x1F004, 0x1F004);
+    property.add_span(0x1F0CF, 0x1F0CF);
+    property.add_span(0x1F170, 0x1F171);
+    property.add_span(0x1F17E, 0x1F17F);
+    property.add_span(0x1F18E, 0x1F18E);
+    property.add_span(0x1F191, 0x1F19A);
+    property.add_span(0x1F1E6, 0x1F1FF);
+    property.add_span(0x1F201, 0x1F202);
+    property.add_span(0x1F21A, 0x1F21A);
+    property.add_span(0x1F22F, 0x1F22F);
+    property.add_span(0x1F232, 0x1F23A);
+    property.add_span(0x1F250, 0x1F251);
+    property.add_span(0x1F300, 0x1F321);
+    property.add_span(0x1F324, 0x1F393);
+    property.add_span(0x1F396, 0x1F397);
+    property.add_span(0x1F399, 0x1F39B);
+    property.add_span(0x1F39E, 0x1F3F0);
+    property.add_span(0x1F3F3, 0x1F3F5);
+    property.add_span(0x1F3F7, 0x1F4FD);
+    property.add_span(0x1F4FF, 0x1F53D);
+    property.add_span(0x1F549, 0x1F54E);
+    property.add_span(0x1F550, 0x1F567);
+    property.add_span(0x1F56F, 0x1F570);
+    property.add_span(0x1F573, 0x1F57A);
+    property.add_span(0x1F587, 0x1F587);
+    property.add_span(0x1F58A, 0x1F58D);
+    property.add_span(0x1F590, 0x1F590);
+    property.add_span(0x1F595, 0x1F596);
+    property.add_span(0x1F5A4, 0x1F5A5);
+    property.add_span(0x1F5A8, 0x1F5A8);
+    property.add_span(0x1F5B1, 0x1F5B2);
+    property.add_span(0x1F5BC, 0x1F5BC);
+    property.add_span(0x1F5C2, 0x1F5C4);
+    property.add_span(0x1F5D1, 0x1F5D3);
+    property.add_span(0x1F5DC, 0x1F5DE);
+    property.add_span(0x1F5E1, 0x1F5E1);
+    property.add_span(0x1F5E3, 0x1F5E3);
+    property.add_span(0x1F5E8, 0x1F5E8);
+    property.add_span(0x1F5EF, 0x1F5EF);
+    property.add_span(0x1F5F3, 0x1F5F3);
+    property.add_span(0x1F5FA, 0x1F64F);
+    property.add_span(0x1F680, 0x1F6C5);
+    property.add_span(0x1F6CB, 0x1F6D2);
+    property.add_span(0x1F6D5, 0x1F6D7);
+    property.add_span(0x1F6DC, 0x1F6E5);
+    property.add_span(0x1F6E9, 0x1F6E9);
+    property.add_span(0x1F6EB, 0x1F6EC);
+    property.add_span(0x1F6F0, 0x1F6F0);
+    property.add_span(0x1F6F3, 0x1F6FC);
+    property.add_span(0x1F7E0, 0x1F7EB);
+    property.add_span(0x1F7F0, 0x1F7F0);
+    property.add_span(0x1F90C, 0x1F93A);
+    property.add_span(0x1F93C, 0x1F945);
+    property.add_span(0x1F947, 0x1F9FF);
+    property.add_span(0x1FA70, 0x1FA7C);
+    property.add_span(0x1FA80, 0x1FA89);
+    property.add_span(0x1FA8F, 0x1FAC6);
+    property.add_span(0x1FACE, 0x1FADC);
+    property.add_span(0x1FADF, 0x1FAE9);
+    property.add_span(0x1FAF0, 0x1FAF8);
+    db.insert("Emoji", property);
+    let mut property = Category::new("ID_Continue");
+    property.add_span(0x30, 0x39);
+    property.add_span(0x41, 0x5A);
+    property.add_span(0x5F, 0x5F);
+    property.add_span(0x61, 0x7A);
+    property.add_span(0xAA, 0xAA);
+    property.add_span(0xB5, 0xB5);
+    property.add_span(0xB7, 0xB7);
+    property.add_span(0xBA, 0xBA);
+    property.add_span(0xC0, 0xD6);
+    property.add_span(0xD8, 0xF6);
+    property.add_span(0xF8, 0x2C1);
+    property.add_span(0x2C6, 0x2D1);
+    property.add_span(0x2E0, 0x2E4);
+    property.add_span(0x2EC, 0x2EC);
+    property.add_span(0x2EE, 0x2EE);
+    property.add_span(0x300, 0x374);
+    property.add_span(0x376, 0x377);
+    property.add_span(0x37A, 0x37D);
+    property.add_span(0x37F, 0x37F);
+    property.add_span(0x386, 0x38A);
+    property.add_span(0x38C, 0x38C);
+    property.add_span(0x38E, 0x3A1);
+    property.add_span(0x3A3, 0x3F5);
+    property.add_span(0x3F7, 0x481);
+    property.add_span(0x483, 0x487);
+    property.add_span(0x48A, 0x52F);
+    property.add_span(0x531, 0x556);
+    property.add_span(0x559, 0x559);
+    property.add_span(0x560, 0x588);
+    property.add_span(0x591, 0x5BD);
+    property.add_span(0x5BF, 0x5BF);
+    property.add_span(0x5C1, 0x5C2);
+    property.add_span(0x5C4, 0x5C5);
+    property.add_span(0x5C7, 0x5C7);
+    property.add_span(0x5D0, 0x5EA);
+    property.add_span(0x5EF, 0x5F2);
+    property.add_span(0x610, 0x61A);
+    property.add_span(0x620, 0x669);
+    property.add_span(0x66E, 0x6D3);
+    property.add_span(0x6D5, 0x6DC);
+    property.add_span(0x6DF, 0x6E8);
+    property.add_span(0x6EA, 0x6FC);
+    property.add_span(0x6FF, 0x6FF);
+    property.add_span(0x710, 0x74A);
+    property.add_span(0x74D, 0x7B1);
+    property.add_span(0x7C0, 0x7F5);
+    property.add_span(0x7FA, 0x7FA);
+    property.add_span(0x7FD, 0x7FD);
+    property.add_span(0x800, 0x82D);
+    property.add_span(0x840, 0x85B);
+    property.add_span(0x860, 0x86A);
+    property.add_span(0x870, 0x887);
+    property.add_span(0x889, 0x88E);
+    property.add_span(0x897, 0x8E1);
+    property.add_span(0x8E3, 0x963);
+    property.add_span(0x966, 0x96F);
+    property.add_span(0x971, 0x983);
+    property.add_span(0x985, 0x98C);
+    property.add_span(0x98F, 0x990);
+    property.add_span(0x993, 0x9A8);
+    property.add_span(0x9AA, 0x9B0);
+    property.add_span(0x9B2, 0x9B2);
+    property.add_span(0x9B6, 0x9B9);
+    property.add_span(0x9BC, 0x9C4);
+    property.add_span(0x9C7, 0x9C8);
+    property.add_span(0x9CB, 0x9CE);
+    property.add_span(0x9D7, 0x9D7);
+    property.add_span(0x9DC, 0x9DD);
+    property.add_span(0x9DF, 0x9E3);
+    property.add_span(0x9E6, 0x9F1);
+    property.add_span(0x9FC, 0x9FC);
+    property.add_span(0x9FE, 0x9FE);
+    property.add_span(0xA01, 0xA03);
+    property.add_span(0xA05, 0xA0A);
+    property.add_span(0xA0F, 0xA10);
+    property.add_span(0xA13, 0xA28);
+    property.add_span(0xA2A, 0xA30);
+    property.add_span(0xA32, 0xA33);
+    property.add_span(0xA35, 0xA36);
+    property.add_span(0xA38, 0xA39);
+    property.add_span(0xA3C, 0xA3C);
+    property.add_span(0xA3E, 0xA42);
+    property.add_span(0xA47, 0xA48);
+    property.add_span(0xA4B, 0xA4D);
+    property.add_span(0xA51, 0xA51);
+    property.add_span(0xA59, 0xA5C);
+    property.add_span(0xA5E, 0xA5E);
+    property.add_span(0xA66, 0xA75);
+    property.add_span(0xA81, 0xA83);
+    property.add_span(0xA85, 0xA8D);
+    property.add_span(0xA8F, 0xA91);
+    property.add_span(0xA93, 0xAA8);
+    property.add_span(0xAAA, 0xAB0);
+    property.add_span(0xAB2, 0xAB3);
+    property.add_span(0xAB5, 0xAB9);
+    property.add_span(0xABC, 0xAC5);
+    property.add_span(0xAC7, 0xAC9);
+    property.add_span(0xACB, 0xACD);
+    property.add_span(0xAD0, 0xAD0);
+    property.add_span(0xAE0, 0xAE3);
+    property.add_span(0xAE6, 0xAEF);
+    property.add_span(0xAF9, 0xAFF);
+    property.add_span(0xB01, 0xB03);
+    property.add_span(0xB05, 0xB0C);
+    property.add_span(0xB0F, 0xB10);
+    property.add_span(0xB13, 0xB28);
+    property.add_span(0xB2A, 0xB30);
+    property.add_span(0xB32, 0xB33);
+    property.add_span(0xB35, 0xB39);
+    property.add_span(0xB3C, 0xB44);
+    property.add_span(0xB47, 0xB48);
+    property.add_span(0xB4B, 0xB4D);
+    property.add_span(0xB55, 0xB57);
+    property.add_span(0xB5C, 0xB5D);
+    property.add_span(0xB5F, 0xB63);
+    property.add_span(0xB66, 0xB6F);
+    property.add_span(0xB71, 0xB71);
+    property.add_span(0xB82, 0xB83);
+    property.add_span(0xB85, 0xB8A);
+    property.add_span(0xB8E, 0xB90);
+    property.add_span(0xB92, 0xB95);
+    property.add_span(0xB99, 0xB9A);
+    property.add_span(0xB9C, 0xB9C);
+    property.add_span(0xB9E, 0xB9F);
+    property.add_span(0xBA3, 0xBA4);
+    property.add_span(0xBA8, 0xBAA);
+    property.add_span(0xBAE, 0xBB9);
+    property.add_span(0xBBE, 0xBC2);
+    property.add_span(0xBC6, 0xBC8);
+    property.add_span(0xBCA, 0xBCD);
+    property.add_span(0xBD0, 0xBD0);
+    property.add_span(0xBD7, 0xBD7);
+    property.add_span(0xBE6, 0xBEF);
+    property.add_span(0xC00, 0xC0C);
+    property.add_span(0xC0E, 0xC10);
+    property.add_span(0xC12, 0xC28);
+    property.add_span(0xC2A, 0xC39);
+    property.add_span(0xC3C, 0xC44);
+    property.add_span(0xC46, 0xC48);
+    property.add_span(0xC4A, 0xC4D);
+    property.add_span(0xC55, 0xC56);
+    property.add_span(0xC58, 0xC5A);
+    property.add_span(0xC5D, 0xC5D);
+    property.add_span(0xC60, 0xC63);
+    property.add_span(0xC66, 0xC6F);
+    property.add_span(0xC80, 0xC83);
+    property.add_span(0xC85, 0xC8C);
+    property.add_span(0xC8E, 0xC90);
+    property.add_span(0xC92, 0xCA8);
+    property.add_span(0xCAA, 0xCB3);
+    property.add_span(0xCB5, 0xCB9);
+    property.add_span(0xCBC, 0xCC4);
+    property.add_span(0xCC6, 0xCC8);
+    property.add_span(0xCCA, 0xCCD);
+    property.add_span(0xCD5, 0xCD6);
+    property.add_span(0xCDD, 0xCDE);
+    property.add_span(0xCE0, 0xCE3);
+    property.add_span(0xCE6, 0xCEF);
+    property.add_span(0xCF1, 0xCF3);
+    property.add_span(0xD00, 0xD0C);
+    property.add_span(0xD0E, 0xD10);
+    property.add_span(0xD12, 0xD44);
+    property.add_span(0xD46, 0xD48);
+    property.add_span(0xD4A, 0xD4E);
+    property.add_span(0xD54, 0xD57);
+    property.add_span(0xD5F, 0xD63);
+    property.add_span(0xD66, 0xD6F);
+    property.add_span(0xD7A, 0xD7F);
+    property.add_span(0xD81, 0xD83);
+    property.add_span(0xD85, 0xD96);
+    property.add_span(0xD9A, 0xDB1);
+    property.add_span(0xDB3, 0xDBB);
+    property.add_span(0xDBD, 0xDBD);
+    property.add_span(0xDC0, 0xDC6);
+    property.add_span(0xDCA, 0xDCA);
+    property.add_span(0xDCF, 0xDD4);
+    property.add_span(0xDD6, 0xDD6);
+    property.add_span(0xDD8, 0xDDF);
+    property.add_span(0xDE6, 0xDEF);
+    property.add_span(0xDF2, 0xDF3);
+    property.add_span(0xE01, 0xE3A);
+    property.add_span(0xE40, 0xE4E);
+    property.add_span(0xE50, 0xE59);
+    property.add_span(0xE81, 0xE82);
+    property.add_span(0xE84, 0xE84);
+    property.add_span(0xE86, 0xE8A);
+    property.add_span(0xE8C, 0xEA3);
+    property.add_span(0xEA5, 0xEA5);
+    property.add_span(0xEA7, 0xEBD);
+    property.add_span(0xEC0, 0xEC4);
+    property.add_span(0xEC6, 0xEC6);
+    property.add_span(0xEC8, 0xECE);
+    property.add_span(0xED0, 0xED9);
+    property.add_span(0xEDC, 0xEDF);
+    property.add_span(0xF00, 0xF00);
+    property.add_span(0xF18, 0xF19);
+    property.add_span(0xF20, 0xF29);
+    property.add_span(0xF35, 0xF35);
+    property.add_span(0xF37, 0xF37);
+    property.add_span(0xF39, 0xF39);
+    property.add_span(0xF3E, 0xF47);
+    property.add_span(0xF49, 0xF6C);
+    property.add_span(0xF71, 0xF84);
+    property.add_span(0xF86, 0xF97);
+    property.add_span(0xF99, 0xFBC);
+    property.add_span(0xFC6, 0xFC6);
+    property.add_span(0x1000, 0x1049);
+    property.add_span(0x1050, 0x109D);
+    property.add_span(0x10A0, 0x10C5);
+    property.add_span(0x10C7, 0x10C7);
+    property.add_span(0x10CD, 0x10CD);
+    property.add_span(0x10D0, 0x10FA);
+    property.add_span(0x10FC, 0x1248);
+    property.add_span(0x124A, 0x124D);
+    property.add_span(0x1250, 0x1256);
+    property.add_span(0x1258, 0x1258);
+    property.add_span(0x125A, 0x125D);
+    property.add_span(0x1260, 0x1288);
+    property.add_span(0x128A, 0x128D);
+    property.add_span(0x1290, 0x12B0);
+    property.add_span(0x12B2, 0x12B5);
+    property.add_span(0x12B8, 0x12BE);
+    property.add_span(0x12C0, 0x12C0);
+    property.add_span(0x12C2, 0x12C5);
+    property.add_span(0x12C8, 0x12D6);
+    property.add_span(0x12D8, 0x1310);
+    property.add_span(0x1312, 0x1315);
+    property.add_span(0x1318, 0x135A);
+    property.add_span(0x135D, 0x135F);
+    property.add_span(0x1369, 0x1371);
+    property.add_span(0x1380, 0x138F);
+    property.add_span(0x13A0, 0x13F5);
+    property.add_span(0x13F8, 0x13FD);
+    property.add_span(0x1401, 0x166C);
+    property.add_span(0x166F, 0x167F);
+    property.add_span(0x1681, 0x169A);
+    property.add_span(0x16A0, 0x16EA);
+    property.add_span(0x16EE, 0x16F8);
+    property.add_span(0x1700, 0x1715);
+    property.add_span(0x171F, 0x1734);
+    property.add_span(0x1740, 0x1753);
+    property.add_span(0x1760, 0x176C);
+    property.add_span(0x176E, 0x1770);
+    property.add_span(0x1772, 0x1773);
+    property.add_span(0x1780, 0x17D3);
+    property.add_span(0x17D7, 0x17D7);
+    property.add_span(0x17DC, 0x17DD);
+    property.add_span(0x17E0, 0x17E9);
+    property.add_span(0x180B, 0x180D);
+    property.add_span(0x180F, 0x1819);
+    property.add_span(0x1820, 0x1878);
+    property.add_span(0x1880, 0x18AA);
+    property.add_span(0x18B0, 0x18F5);
+    property.add_span(0x1900, 0x191E);
+    property.add_span(0x1920, 0x192B);
+    property.add_span(0x1930, 0x193B);
+    property.add_span(0x1946, 0x196D);
+    property.add_span(0x1970, 0x1974);
+    property.add_span(0x1980, 0x19AB);
+    property.add_span(0x19B0, 0x19C9);
+    property.add_span(0x19D0, 0x19DA);
+    property.add_span(0x1A00, 0x1A1B);
+    property.add_span(0x1A20, 0x1A5E);
+    property.add_span(0x1A60, 0x1A7C);
+    property.add_span(0x1A7F, 0x1A89);
+    property.add_span(0x1A90, 0x1A99);
+    property.add_span(0x1AA7, 0x1AA7);
+    property.add_span(0x1AB0, 0x1ABD);
+    property.add_span(0x1ABF, 0x1ACE);
+    property.add_span(0x1B00, 0x1B4C);
+    property.add_span(0x1B50, 0x1B59);
+    property.add_span(0x1B6B, 0x1B73);
+    property.add_span(0x1B80, 0x1BF3);
+    property.add_span(0x1C00, 0x1C37);
+    property.add_span(0x1C40, 0x1C49);
+    property.add_span(0x1C4D, 0x1C7D);
+    property.add_span(0x1C80, 0x1C8A);
+    property.add_span(0x1C90, 0x1CBA);
+    property.add_span(0x1CBD, 0x1CBF);
+    property.add_span(0x1CD0, 0x1CD2);
+    property.add_span(0x1CD4, 0x1CFA);
+    property.add_span(0x1D00, 0x1F15);
+    property.add_span(0x1F18, 0x1F1D);
+    property.add_span(0x1F20, 0x1F45);
+    property.add_span(0x1F48, 0x1F4D);
+    property.add_span(0x1F50, 0x1F57);
+    property.add_span(0x1F59, 0x1F59);
+    property.add_span(0x1F5B, 0x1F5B);
+    property.add_span(0x1F5D, 0x1F5D);
+    property.add_span(0x1F5F, 0x1F7D);
+    property.add_span(0x1F80, 0x1FB4);
+    property.add_span(0x1FB6, 0x1FBC);
+    property.add_span(0x1FBE, 0x1FBE);
+    property.add_span(0x1FC2, 0x1FC4);
+    property.add_span(0x1FC6, 0x1FCC);
+    property.add_span(0x1FD0, 0x1FD3);
+    property.add_span(0x1FD6, 0x1FDB);
+    property.add_span(0x1FE0, 0x1FEC);
+    property.add_span(0x1FF2, 0x1FF4);
+    property.add_span(0x1FF6, 0x1FFC);
+    property.add_span(0x200C, 0x200D);
+    property.add_span(0x203F, 0x2040);
+    property.add_span(0x2054, 0x2054);
+    property.add_span(0x2071, 0x2071);
+    property.add_span(0x207F, 0x207F);
+    property.add_span(0x2090, 0x209C);
+    property.add_span(0x20D0, 0x20DC);
+    property.add_span(0x20E1, 0x20E1);
+    property.add_span(0x20E5, 0x20F0);
+    property.add_span(0x2102, 0x2102);
+    property.add_span(0x2107, 0x2107);
+    property.add_span(0x210A, 0x2113);
+    property.add_span(0x2115, 0x2115);
+    property.add_span(0x2118, 0x211D);
+    property.add_span(0x2124, 0x2124);
+    property.add_span(0x2126, 0x2126);
+    property.add_span(0x2128, 0x2128);
+    property.add_span(0x212A, 0x2139);
+    property.add_span(0x213C, 0x213F);
+    property.add_span(0x2145, 0x2149);
+    property.add_span(0x214E, 0x214E);
+    property.add_span(0x2160, 0x2188);
+    property.add_span(0x2C00, 0x2CE4);
+    property.add_span(0x2CEB, 0x2CF3);
+    property.add_span(0x2D00, 0x2D25);
+    property.add_span(0x2D27, 0x2D27);
+    property.add_span(0x2D2D, 0x2D2D);
+    property.add_span(0x2D30, 0x2D67);
+    property.add_span(0x2D6F, 0x2D6F);
+    property.add_span(0x2D7F, 0x2D96);
+    property.add_span(0x2DA0, 0x2DA6);
+    property.add_span(0x2DA8, 0x2DAE);
+    property.add_span(0x2DB0, 0x2DB6);
+    property.add_span(0x2DB8, 0x2DBE);
+    property.add_span(0x2DC0, 0x2DC6);
+    property.add_span(0x2DC8, 0x2DCE);
+    property.add_span(0x2DD0, 0x2DD6);
+    property.add_span(0x2DD8, 0x2DDE);
+    property.add_span(0x2DE0, 0x2DFF);
+    property.add_span(0x3005, 0x3007);
+    property.add_span(0x3021, 0x302F);
+    property.add_span(0x3031, 0x3035);
+    property.add_span(0x3038, 0x303C);
+    property.add_span(0x3041, 0x3096);
+    property.add_span(0x3099, 0x309F);
+    property.add_span(0x30A1, 0x30FF);
+    property.add_span(0x3105, 0x312F);
+    property.add_span(0x3131, 0x318E);
+    property.add_span(0x31A0, 0x31BF);
+    property.add_span(0x31F0, 0x31FF);
+    property.add_span(0x3400, 0x4DBF);
+    property.add_span(0x4E00, 0xA48C);
+    property.add_span(0xA4D0, 0xA4FD);
+    property.add_span(0xA500, 0xA60C);
+    property.add_span(0xA610, 0xA62B);
+    property.add_span(0xA640, 0xA66F);
+    property.add_span(0xA674, 0xA67D);
+    property.add_span(0xA67F, 0xA6F1);
+    property.add_span(0xA717, 0xA71F);
+    property.add_span(0xA722, 0xA788);
+    property.add_span(0xA78B, 0xA7CD);
+    property.add_span(0xA7D0, 0xA7D1);
+    property.add_span(0xA7D3, 0xA7D3);
+    property.add_span(0xA7D5, 0xA7DC);
+    property.add_span(0xA7F2, 0xA827);
+    property.add_span(0xA82C, 0xA82C);
+    property.add_span(0xA840, 0xA873);
+    property.add_span(0xA880, 0xA8C5);
+    property.add_span(0xA8D0, 0xA8D9);
+    property.add_span(0xA8E0, 0xA8F7);
+    property.add_span(0xA8FB, 0xA8FB);
+    property.add_span(0xA8FD, 0xA92D);
+    property.add_span(0xA930, 0xA953);
+    property.add_span(0xA960, 0xA97C);
+    property.add_span(0xA980, 0xA9C0);
+    property.add_span(0xA9CF, 0xA9D9);
+    property.add_span(0xA9E0, 0xA9FE);
+    property.add_span(0xAA00, 0xAA36);
+    property.add_span(0xAA40, 0xAA4D);
+    property.add_span(0xAA50, 0xAA59);
+    property.add_span(0xAA60, 0xAA76);
+    property.add_span(0xAA7A, 0xAAC2);
+    property.add_span(0xAADB, 0xAADD);
+    property.add_span(0xAAE0, 0xAAEF);
+    property.add_span(0xAAF2, 0xAAF6);
+    property.add_span(0xAB01, 0xAB06);
+    property.add_span(0xAB09, 0xAB0E);
+    property.add_span(0xAB11, 0xAB16);
+    property.add_span(0xAB20, 0xAB26);
+    property.add_span(0xAB28, 0xAB2E);
+    property.add_span(0xAB30, 0xAB5A);
+    property.add_span(0xAB5C, 0xAB69);
+    property.add_span(0xAB70, 0xABEA);
+    property.add_span(0xABEC, 0xABED);
+    property.add_span(0xABF0, 0xABF9);
+    property.add_span(0xAC00, 0xD7A3);
+    property.add_span(0xD7B0, 0xD7C6);
+    property.add_span(0xD7CB, 0xD7FB);
+    property.add_span(0xF900, 0xFA6D);
+    property.add_span(0xFA70, 0xFAD9);
+    property.add_span(0xFB00, 0xFB06);
+    property.add_span(0xFB13, 0xFB17);
+    property.add_span(0xFB1D, 0xFB28);
+    property.add_span(0xFB2A, 0xFB36);
+    property.add_span(0xFB38, 0xFB3C);
+    property.add_span(0xFB3E, 0xFB3E);
+    property.add_span(0xFB40, 0xFB41);
+    property.add_span(0xFB43, 0xFB44);
+    property.add_span(0xFB46, 0xFBB1);
+    property.add_span(0xFBD3, 0xFD3D);
+    property.add_span(0xFD50, 0xFD8F);
+    property.add_span(0xFD92, 0xFDC7);
+    property.add_span(0xFDF0, 0xFDFB);
+    property.add_span(0xFE00, 0xFE0F);
+    property.add_span(0xFE20, 0xFE2F);
+    property.add_span(0xFE33, 0xFE34);
+    property.add_span(0xFE4D, 0xFE4F);
+    property.add_span(0xFE70, 0xFE74);
+    property.add_span(0xFE76, 0xFEFC);
+    property.add_span(0xFF10, 0xFF19);
+    property.add_span(0xFF21, 0xFF3A);
+    property.add_span(0xFF3F, 0xFF3F);
+    property.add_span(0xFF41, 0xFF5A);
+    property.add_span(0xFF65, 0xFFBE);
+    property.add_span(0xFFC2, 0xFFC7);
+    property.add_span(0xFFCA, 0xFFCF);
+    property.add_span(0xFFD2, 0xFFD7);
+    property.add_span(0xFFDA, 0xFFDC);
+    property.add_span(0x10000, 0x1000B);
+    property.add_span(0x1000D, 0x10026);
+    property.add_span(0x10028, 0x1003A);
+    property.add_span(0x1003C, 0x1003D);
+    property.add_span(0x1003F, 0x1004D);
+    property.add_span(0x10050, 0x1005D);
+    property.add_span(0x10080, 0x100FA);
+    property.add_span(0x10140, 0x10174);
+    property.add_span(0x101FD, 0x101FD);
+    property.add_span(0x10280, 0x1029C);
+    property.add_span(0x102A0, 0x102D0);
+    property.add_span(0x102E0, 0x102E0);
+    property.add_span(0x10300, 0x1031F);
+    property.add_span(0x1032D, 0x1034A);
+    property.add_span(0x10350, 0x1037A);
+    property.add_span(0x10380, 0x1039D);
+    property.add_span(0x103A0, 0x103C3);
+    property.add_span(0x103C8, 0x103CF);
+    property.add_span(0x103D1, 0x103D5);
+    property.add_span(0x10400, 0x1049D);
+    property.add_span(0x104A0, 0x104A9);
+    property.add_span(0x104B0, 0x104D3);
+    property.add_span(0x104D8, 0x104FB);
+    property.add_span(0x10500, 0x10527);
+    property.add_span(0x10530, 0x10563);
+    property.add_span(0x10570, 0x1057A);
+    property.add_span(0x1057C, 0x1058A);
+    property.add_span(0x1058C, 0x10592);
+    property.add_span(0x10594, 0x10595);
+    property.add_span(0x10597, 0x105A1);
+    property.add_span(0x105A3, 0x105B1);
+    property.add_span(0x105B3, 0x105B9);
+    property.add_span(0x105BB, 0x105BC);
+    property.add_span(0x105C0, 0x105F3);
+    property.add_span(0x10600, 0x10736);
+    property.add_span(0x10740, 0x10755);
+    property.add_span(0x10760, 0x10767);
+    property.add_span(0x10780, 0x10785);
+    property.add_span(0x10787, 0x107B0);
+    property.add_span(0x107B2, 0x107BA);
+    property.add_span(0x10800, 0x10805);
+    property.add_span(0x10808, 0x10808);
+    property.add_span(0x1080A, 0x10835);
+    property.add_span(0x10837, 0x10838);
+    property.add_span(0x1083C, 0x1083C);
+    property.add_span(0x1083F, 0x10855);
+    property.add_span(0x10860, 0x10876);
+    property.add_span(0x10880, 0x1089E);
+    property.add_span(0x108E0, 0x108F2);
+    property.add_span(0x108F4, 0x108F5);
+    property.add_span(0x10900, 0x10915);
+    property.add_span(0x10920, 0x10939);
+    property.add_span(0x10980, 0x109B7);
+    property.add_span(0x109BE, 0x109BF);
+    property.add_span(0x10A00, 0x10A03);
+    property.add_span(0x10A05, 0x10A06);
+    property.add_span(0x10A0C, 0x10A13);
+    property.add_span(0x10A15, 0x10A17);
+    property.add_span(0x10A19, 0x10A35);
+    property.add_span(0x10A38, 0x10A3A);
+    property.add_span(0x10A3F, 0x10A3F);
+    property.add_span(0x10A60, 0x10A7C);
+    property.add_span(0x10A80, 0x10A9C);
+    property.add_span(0x10AC0, 0x10AC7);
+    property.add_span(0x10AC9, 0x10AE6);
+    property.add_span(0x10B00, 0x10B35);
+    property.add_span(0x10B40, 0x10B55);
+    property.add_span(0x10B60, 0x10B72);
+    property.add_span(0x10B80, 0x10B91);
+    property.add_span(0x10C00, 0x10C48);
+    property.add_span(0x10C80, 0x10CB2);
+    property.add_span(0x10CC0, 0x10CF2);
+    property.add_span(0x10D00, 0x10D27);
+    property.add_span(0x10D30, 0x10D39);
+    property.add_span(0x10D40, 0x10D65);
+    property.add_span(0x10D69, 0x10D6D);
+    property.add_span(0x10D6F, 0x10D85);
+    property.add_span(0x10E80, 0x10EA9);
+    property.add_span(0x10EAB, 0x10EAC);
+    property.add_span(0x10EB0, 0x10EB1);
+    property.add_span(0x10EC2, 0x10EC4);
+    property.add_span(0x10EFC, 0x10F1C);
+    property.add_span(0x10F27, 0x10F27);
+    property.add_span(0x10F30, 0x10F50);
+    property.add_span(0x10F70, 0x10F85);
+    property.add_span(0x10FB0, 0x10FC4);
+    property.add_span(0x10FE0, 0x10FF6);
+    property.add_span(0x11000, 0x11046);
+    property.add_span(0x11066, 0x11075);
+    property.add_span(0x1107F, 0x110BA);
+    property.add_span(0x110C2, 0x110C2);
+    property.add_span(0x110D0, 0x110E8);
+    property.add_span(0x110F0, 0x110F9);
+    property.add_span(0x11100, 0x11134);
+    property.add_span(0x11136, 0x1113F);
+    property.add_span(0x11144, 0x11147);
+    property.add_span(0x11150, 0x11173);
+    property.add_span(0x11176, 0x11176);
+    property.add_span(0x11180, 0x111C4);
+    property.add_span(0x111C9, 0x111CC);
+    property.add_span(0x111CE, 0x111DA);
+    property.add_span(0x111DC, 0x111DC);
+    property.add_span(0x11200, 0x11211);
+    property.add_span(0x11213, 0x11237);
+    property.add_span(0x1123E, 0x11241);
+    property.add_span(0x11280, 0x11286);
+    property.add_span(0x11288, 0x11288);
+    property.add_span(0x1128A, 0x1128D);
+    property.add_span(0x1128F, 0x1129D);
+    property.add_span(0x1129F, 0x112A8);
+    property.add_span(0x112B0, 0x112EA);
+    property.add_span(0x112F0, 0x112F9);
+    property.add_span(0x11300, 0x11303);
+    property.add_span(0x11305, 0x1130C);
+    property.add_span(0x1130F, 0x11310);
+    property.add_span(0x11313, 0x11328);
+    property.add_span(0x1132A, 0x11330);
+    property.add_span(0x11332, 0x11333);
+    property.add_span(0x11335, 0x11339);
+    property.add_span(0x1133B, 0x11344);
+    property.add_span(0x11347, 0x11348);
+    property.add_span(0x1134B, 0x1134D);
+    property.add_span(0x11350, 0x11350);
+    property.add_span(0x11357, 0x11357);
+    property.add_span(0x1135D, 0x11363);
+    property.add_span(0x11366, 0x1136C);
+    property.add_span(0x11370, 0x11374);
+    property.add_span(0x11380, 0x11389);
+    property.add_span(0x1138B, 0x1138B);
+    property.add_span(0x1138E, 0x1138E);
+    property.add_span(0x11390, 0x113B5);
+    property.add_span(0x113B7, 0x113C0);
+    property.add_span(0x113C2, 0x113C2);
+    property.add_span(0x113C5, 0x113C5);
+    property.add_span(0x113C7, 0x113CA);
+    property.add_span(0x113CC, 0x113D3);
+    property.add_span(0x113E1, 0x113E2);
+    property.add_span(0x11400, 0x1144A);
+    property.add_span(0x11450, 0x11459);
+    property.add_span(0x1145E, 0x11461);
+    property.add_span(0x11480, 0x114C5);
+    property.add_span(0x114C7, 0x114C7);
+    property.add_span(0x114D0, 0x114D9);
+    property.add_span(0x11580, 0x115B5);
+    property.add_span(0x115B8, 0x115C0);
+    property.add_span(0x115D8, 0x115DD);
+    property.add_span(0x11600, 0x11640);
+    property.add_span(0x11644, 0x11644);
+    property.add_span(0x11650, 0x11659);
+    property.add_span(0x11680, 0x116B8);
+    property.add_span(0x116C0, 0x116C9);
+    property.add_span(0x116D0, 0x116E3);
+    property.add_span(0x11700, 0x1171A);
+    property.add_span(0x1171D, 0x1172B);
+    property.add_span(0x11730, 0x11739);
+    property.add_span(0x11740, 0x11746);
+    property.add_span(0x11800, 0x1183A);
+    property.add_span(0x118A0, 0x118E9);
+    property.add_span(0x118FF, 0x11906);
+    property.add_span(0x11909, 0x11909);
+    property.add_span(0x1190C, 0x11913);
+    property.add_span(0x11915, 0x11916);
+    property.add_span(0x11918, 0x11935);
+    property.add_span(0x11937, 0x11938);
+    property.add_span(0x1193B, 0x11943);
+    property.add_span(0x11950, 0x11959);
+    property.add_span(0x119A0, 0x119A7);
+    property.add_span(0x119AA, 0x119D7);
+    property.add_span(0x119DA, 0x119E1);
+    property.add_span(0x119E3, 0x119E4);
+    property.add_span(0x11A00, 0x11A3E);
+    property.add_span(0x11A47, 0x11A47);
+    property.add_span(0x11A50, 0x11A99);
+    property.add_span(0x11A9D, 0x11A9D);
+    property.add_span(0x11AB0, 0x11AF8);
+    property.add_span(0x11BC0, 0x11BE0);
+    property.add_span(0x11BF0, 0x11BF9);
+    property.add_span(0x11C00, 0x11C08);
+    property.add_span(0x11C0A, 0x11C36);
+    property.add_span(0x11C38, 0x11C40);
+    property.add_span(0x11C50, 0x11C59);
+    property.add_span(0x11C72, 0x11C8F);
+    property.add_span(0x11C92, 0x11CA7);
+    property.add_span(0x11CA9, 0x11CB6);
+    property.add_span(0x11D00, 0x11D06);
+    property.add_span(0x11D08, 0x11D09);
+    property.add_span(0x11D0B, 0x11D36);
+    property.add_span(0x11D3A, 0x11D3A);
+    property.add_span(0x11D3C, 0x11D3D);
+    property.add_span(0x11D3F, 0x11D47);
+    property.add_span(0x11D50, 0x11D59);
+    property.add_span(0x11D60, 0x11D65);
+    property.add_span(0x11D67, 0x11D68);
+    property.add_span(0x11D6A, 0x11D8E);
+    property.add_span(0x11D90, 0x11D91);
+    property.add_span(0x11D93, 0x11D98);
+    property.add_span(0x11DA0, 0x11DA9);
+    property.add_span(0x11EE0, 0x11EF6);
+    property.add_span(0x11F00, 0x11F10);
+    property.add_span(0x11F12, 0x11F3A);
+    property.add_span(0x11F3E, 0x11F42);
+    property.add_span(0x11F50, 0x11F5A);
+    property.add_span(0x11FB0, 0x11FB0);
+    property.add_span(0x12000, 0x12399);
+    property.add_span(0x12400, 0x1246E);
+    property.add_span(0x12480, 0x12543);
+    property.add_span(0x12F90, 0x12FF0);
+    property.add_span(0x13000, 0x1342F);
+    property.add_span(0x13440, 0x13455);
+    property.add_span(0x13460, 0x143FA);
+    property.add_span(0x14400, 0x14646);
+    property.add_span(0x16100, 0x16139);
+    property.add_span(0x16800, 0x16A38);
+    property.add_span(0x16A40, 0x16A5E);
+    property.add_span(0x16A60, 0x16A69);
+    property.add_span(0x16A70, 0x16ABE);
+    property.add_span(0x16AC0, 0x16AC9);
+    property.add_span(0x16AD0, 0x16AED);
+    property.add_span(0x16AF0, 0x16AF4);
+    property.add_span(0x16B00, 0x16B36);
+    property.add_span(0x16B40, 0x16B43);
+    property.add_span(0x16B50, 0x16B59);
+    property.add_span(0x16B63, 0x16B77);
+    property.add_span(0x16B7D, 0x16B8F);
+    property.add_span(0x16D40, 0x16D6C);
+    property.add_span(0x16D70, 0x16D79);
+    property.add_span(0x16E40, 0x16E7F);
+    property.add_span(0x16F00, 0x16F4A);
+    property.add_span(0x16F4F, 0x16F87);
+    property.add_span(0x16F8F, 0x16F9F);
+    property.add_span(0x16FE0, 0x16FE1);
+    property.add_span(0x16FE3, 0x16FE4);
+    property.add_span(0x16FF0, 0x16FF1);
+    property.add_span(0x17000, 0x187F7);
+    property.add_span(0x18800, 0x18CD5);
+    property.add_span(0x18CFF, 0x18D08);
+    property.add_span(0x1AFF0, 0x1AFF3);
+    property.add_span(0x1AFF5, 0x1AFFB);
+    property.add_span(0x1AFFD, 0x1AFFE);
+    property.add_span(0x1B000, 0x1B122);
+    property.add_span(0x1B132, 0x1B132);
+    property.add_span(0x1B150, 0x1B152);
+    property.add_span(0x1B155, 0x1B155);
+    property.add_span(0x1B164, 0x1B167);
+    property.add_span(0x1B170, 0x1B2FB);
+    property.add_span(0x1BC00, 0x1BC6A);
+    property.add_span(0x1BC70, 0x1BC7C);
+    property.add_span(0x1BC80, 0x1BC88);
+    property.add_span(0x1BC90, 0x1BC99);
+    property.add_span(0x1BC9D, 0x1BC9E);
+    property.add_span(0x1CCF0, 0x1CCF9);
+    property.add_span(0x1CF00, 0x1CF2D);
+    property.add_span(0x1CF30, 0x1CF46);
+    property.add_span(0x1D165, 0x1D169);
+    property.add_span(0x1D16D, 0x1D172);
+    property.add_span(0x1D17B, 0x1D182);
+    property.add_span(0x1D185, 0x1D18B);
+    property.add_span(0x1D1AA, 0x1D1AD);
+    property.add_span(0x1D242, 0x1D244);
+    property.add_span(0x1D400, 0x1D454);
+    property.add_span(0x1D456, 0x1D49C);
+    property.add_span(0x1D49E, 0x1D49F);
+    property.add_span(0x1D4A2, 0x1D4A2);
+    property.add_span(0x1D4A5, 0x1D4A6);
+    property.add_span(0x1D4A9, 0x1D4AC);
+    property.add_span(0x1D4AE, 0x1D4B9);
+    property.add_span(0x1D4BB, 0x1D4BB);
+    property.add_span(0x1D4BD, 0x1D4C3);
+    property.add_span(0x1D4C5, 0x1D505);
+    property.add_span(0x1D507, 0x1D50A);
+    property.add_span(0x1D50D, 0x1D514);
+    property.add_span(0x1D516, 0x1D51C);
+    property.add_span(0x1D51E, 0x1D539);
+    property.add_span(0x1D53B, 0x1D53E);
+    property.add_span(0x1D540, 0x1D544);
+    property.add_span(0x1D546, 0x1D546);
+    property.add_span(0x1D54A, 0x1D550);
+    property.add_span(0x1D552, 0x1D6A5);
+    property.add_span(0x1D6A8, 0x1D6C0);
+    property.add_span(0x1D6C2, 0x1D6DA);
+    property.add_span(0x1D6DC, 0x1D6FA);
+    property.add_span(0x1D6FC, 0x1D714);
+    property.add_span(0x1D716, 0x1D734);
+    property.add_span(0x1D736, 0x1D74E);
+    property.add_span(0x1D750, 0x1D76E);
+    property.add_span(0x1D770, 0x1D788);
+    property.add_span(0x1D78A, 0x1D7A8);
+    property.add_span(0x1D7AA, 0x1D7C2);
+    property.add_span(0x1D7C4, 0x1D7CB);
+    property.add_span(0x1D7CE, 0x1D7FF);
+    property.add_span(0x1DA00, 0x1DA36);
+    property.add_span(0x1DA3B, 0x1DA6C);
+    property.add_span(0x1DA75, 0x1DA75);
+    property.add_span(0x1DA84, 0x1DA84);
+    property.add_span(0x1DA9B, 0x1DA9F);
+    property.add_span(0x1DAA1, 0x1DAAF);
+    property.add_span(0x1DF00, 0x1DF1E);
+    property.add_span(0x1DF25, 0x1DF2A);
+    property.add_span(0x1E000, 0x1E006);
+    property.add_span(0x1E008, 0x1E018);
+    property.add_span(0x1E01B, 0x1E021);
+    property.add_span(0x1E023, 0x1E024);
+    property.add_span(0x1E026, 0x1E02A);
+    property.add_span(0x1E030, 0x1E06D);
+    property.add_span(0x1E08F, 0x1E08F);
+    property.add_span(0x1E100, 0x1E12C);
+    property.add_span(0x1E130, 0x1E13D);
+    property.add_span(0x1E140, 0x1E149);
+    property.add_span(0x1E14E, 0x1E14E);
+    property.add_span(0x1E290, 0x1E2AE);
+    property.add_span(0x1E2C0, 0x1E2F9);
+    property.add_span(0x1E4D0, 0x1E4F9);
+    property.add_span(0x1E5D0, 0x1E5FA);
+    property.add_span(0x1E7E0, 0x1E7E6);
+    property.add_span(0x1E7E8, 0x1E7EB);
+    property.add_span(0x1E7ED, 0x1E7EE);
+    property.add_span(0x1E7F0, 0x1E7FE);
+    property.add_span(0x1E800, 0x1E8C4);
+    property.add_span(0x1E8D0, 0x1E8D6);
+    property.add_span(0x1E900, 0x1E94B);
+    property.add_span(0x1E950, 0x1E959);
+    property.add_span(0x1EE00, 0x1EE03);
+    property.add_span(0x1EE05, 0x1EE1F);
+    property.add_span(0x1EE21, 0x1EE22);
+    property.add_span(0x1EE24, 0x1EE24);
+    property.add_span(0x1EE27, 0x1EE27);
+    property.add_span(0x1EE29, 0x1EE32);
+    property.add_span(0x1EE34, 0x1EE37);
+    property.add_span(0x1EE39, 0x1EE39);
+    property.add_span(0x1EE3B, 0x1EE3B);
+    property.add_span(0x1EE42, 0x1EE42);
+    property.add_span(0x1EE47, 0x1EE47);
+    property.add_span(0x1EE49, 0x1EE49);
+    property.add_span(0x1EE4B, 0x1EE4B);
+    property.add_span(0x1EE4D, 0x1EE4F);
+    property.add_span(0x1EE51, 0x1EE52);
+    property.add_span(0x1EE54, 0x1EE54);
+    property.add_span(0x1EE57, 0x1EE57);
+    property.add_span(0x1EE59, 0x1EE59);
+    property.add_span(0x1EE5B, 0x1EE5B);
+    property.add_span(0x1EE5D, 0x1EE5D);
+    property.add_span(0x1EE5F, 0x1EE5F);
+    property.add_span(0x1EE61, 0x1EE62);
+    property.add_span(0x1EE64, 0x1EE64);
+    property.add_span(0x1EE67, 0x1EE6A);
+    property.add_span(0x1EE6C, 0x1EE72);
+    property.add_span(0x1EE74, 0x1EE77);
+    property.add_span(0x1EE79, 0x1EE7C);
+    property.add_span(0x1EE7E, 0x1EE7E);
+    property.add_span(0x1EE80, 0x1EE89);
+    property.add_span(0x1EE8B, 0x1EE9B);
+    property.add_span(0x1EEA1, 0x1EEA3);
+    property.add_span(0x1EEA5, 0x1EEA9);
+    property.add_span(0x1EEAB, 0x1EEBB);
+    property.add_span(0x1FBF0, 0x1FBF9);
+    property.add_span(0x20000, 0x2A6DF);
+    property.add_span(0x2A700, 0x2B739);
+    property.add_span(0x2B740, 0x2B81D);
+    property.add_span(0x2B820, 0x2CEA1);
+    property.add_span(0x2CEB0, 0x2EBE0);
+    property.add_span(0x2EBF0, 0x2EE5D);
+    property.add_span(0x2F800, 0x2FA1D);
+    property.add_span(0x30000, 0x3134A);
+    property.add_span(0x31350, 0x323AF);
+    property.add_span(0xE0100, 0xE01EF);
+    db.insert("ID_Continue", property);
+    let mut property = Category::new("ID_Start");
+    property.add_span(0x41, 0x5A);
+    property.add_span(0x61, 0x7A);
+    property.add_span(0xAA, 0xAA);
+    property.add_span(0xB5, 0xB5);
+    property.add_span(0xBA, 0xBA);
+    property.add_span(0xC0, 0xD6);
+    property.add_span(0xD8, 0xF6);
+    property.add_span(0xF8, 0x2C1);
+    property.add_span(0x2C6, 0x2D1);
+    property.add_span(0x2E0, 0x2E4);
+    property.add_span(0x2EC, 0x2EC);
+    property.add_span(0x2EE, 0x2EE);
+    property.add_span(0x370, 0x374);
+    property.add_span(0x376, 0x377);
+    property.add_span(0x37A, 0x37D);
+    property.add_span(0x37F, 0x37F);
+    property.add_span(0x386, 0x386);
+    property.add_span(0x388, 0x38A);
+    property.add_span(0x38C, 0x38C);
+    property.add_span(0x38E, 0x3A1);
+    property.add_span(0x3A3, 0x3F5);
+    property.add_span(0x3F7, 0x481);
+    property.add_span(0x48A, 0x52F);
+    property.add_span(0x531, 0x556);
+    property.add_span(0x559, 0x559);
+    property.add_span(0x560, 0x588);
+    property.add_span(0x5D0, 0x5EA);
+    property.add_span(0x5EF, 0x5F2);
+    property.add_span(0x620, 0x64A);
+    property.add_span(0x66E, 0x66F);
+    property.add_span(0x671, 0x6D3);
+    property.add_span(0x6D5, 0x6D5);
+    property.add_span(0x6E5, 0x6E6);
+    property.add_span(0x6EE, 0x6EF);
+    property.add_span(0x6FA, 0x6FC);
+    property.add_span(0x6FF, 0x6FF);
+    property.add_span(0x710, 0x710);
+    property.add_span(0x712, 0x72F);
+    property.add_span(0x74D, 0x7A5);
+    property.add_span(0x7B1, 0x7B1);
+    property.add_span(0x7CA, 0x7EA);
+    property.add_span(0x7F4, 0x7F5);
+    property.add_span(0x7FA, 0x7FA);
+    property.add_span(0x800, 0x815);
+    property.add_span(0x81A, 0x81A);
+    property.add_span(0x824, 0x824);
+    property.add_span(0x828, 0x828);
+    property.add_span(0x840, 0x858);
+    property.add_span(0x860, 0x86A);
+    property.add_span(0x870, 0x887);
+    property.add_span(0x889, 0x88E);
+    property.add_span(0x8A0, 0x8C9);
+    property.add_span(0x904, 0x939);
+    property.add_span(0x93D, 0x93D);
+    property.add_span(0x950, 0x950);
+    property.add_span(0x958, 0x961);
+    property.add_span(0x971, 0x980);
+    property.add_span(0x985, 0x98C);
+    property.add_span(0x98F, 0x990);
+    property.add_span(0x993, 0x9A8);
+    property.add_span(0x9AA, 0x9B0);
+    property.add_span(0x9B2, 0x9B2);
+    property.add_span(0x9B6, 0x9B9);
+    property.add_span(0x9BD, 0x9BD);
+    property.add_span(0x9CE, 0x9CE);
+    property.add_span(0x9DC, 0x9DD);
+    property.add_span(0x9DF, 0x9E1);
+    property.add_span(0x9F0, 0x9F1);
+    property.add_span(0x9FC, 0x9FC);
+    property.add_span(0xA05, 0xA0A);
+    property.add_span(0xA0F, 0xA10);
+    property.add_span(0xA13, 0xA28);
+    property.add_span(0xA2A, 0xA30);
+    property.add_span(0xA32, 0xA33);
+    property.add_span(0xA35, 0xA36);
+    property.add_span(0xA38, 0xA39);
+    property.add_span(0xA59, 0xA5C);
+    property.add_span(0xA5E, 0xA5E);
+    property.add_span(0xA72, 0xA74);
+    property.add_span(0xA85, 0xA8D);
+    property.add_span(0xA8F, 0xA91);
+    property.add_span(0xA93, 0xAA8);
+    property.add_span(0xAAA, 0xAB0);
+    property.add_span(0xAB2, 0xAB3);
+    property.add_span(0xAB5, 0xAB9);
+    property.add_span(0xABD, 0xABD);
+    property.add_span(0xAD0, 0xAD0);
+    property.add_span(0xAE0, 0xAE1);
+    property.add_span(0xAF9, 0xAF9);
+    property.add_span(0xB05, 0xB0C);
+    property.add_span(0xB0F, 0xB10);
+    property.add_span(0xB13, 0xB28);
+    property.add_span(0xB2A, 0xB30);
+    property.add_span(0xB32, 0xB33);
+    property.add_span(0xB35, 0xB39);
+    property.add_span(0xB3D, 0xB3D);
+    property.add_span(0xB5C, 0xB5D);
+    property.add_span(0xB5F, 0xB61);
+    property.add_span(0xB71, 0xB71);
+    property.add_span(0xB83, 0xB83);
+    property.add_span(0xB85, 0xB8A);
+    property.add_span(0xB8E, 0xB90);
+    property.add_span(0xB92, 0xB95);
+    property.add_span(0xB99, 0xB9A);
+    property.add_span(0xB9C, 0xB9C);
+    property.add_span(0xB9E, 0xB9F);
+    property.add_span(0xBA3, 0xBA4);
+    property.add_span(0xBA8, 0xBAA);
+    property.add_span(0xBAE, 0xBB9);
+    property.add_span(0xBD0, 0xBD0);
+    property.add_span(0xC05, 0xC0C);
+    property.add_span(0xC0E, 0xC10);
+    property.add_span(0xC12, 0xC28);
+    property.add_span(0xC2A, 0xC39);
+    property.add_span(0xC3D, 0xC3D);
+    property.add_span(0xC58, 0xC5A);
+    property.add_span(0xC5D, 0xC5D);
+    property.add_span(0xC60, 0xC61);
+    property.add_span(0xC80, 0xC80);
+    property.add_span(0xC85, 0xC8C);
+    property.add_span(0xC8E, 0xC90);
+    property.add_span(0xC92, 0xCA8);
+    property.add_span(0xCAA, 0xCB3);
+    property.add_span(0xCB5, 0xCB9);
+    property.add_span(0xCBD, 0xCBD);
+    property.add_span(0xCDD, 0xCDE);
+    property.add_span(0xCE0, 0xCE1);
+    property.add_span(0xCF1, 0xCF2);
+    property.add_span(0xD04, 0xD0C);
+    property.add_span(0xD0E, 0xD10);
+    property.add_span(0xD12, 0xD3A);
+    property.add_span(0xD3D, 0xD3D);
+    property.add_span(0xD4E, 0xD4E);
+    property.add_span(0xD54, 0xD56);
+    property.add_span(0xD5F, 0xD61);
+    property.add_span(0xD7A, 0xD7F);
+    property.add_span(0xD85, 0xD96);
+    property.add_span(0xD9A, 0xDB1);
+    property.add_span(0xDB3, 0xDBB);
+    property.add_span(0xDBD, 0xDBD);
+    property.add_span(0xDC0, 0xDC6);
+    property.add_span(0xE01, 0xE30);
+    property.add_span(0xE32, 0xE33);
+    property.add_span(0xE40, 0xE46);
+    property.add_span(0xE81, 0xE82);
+    property.add_span(0xE84, 0xE84);
+    property.add_span(0xE86, 0xE8A);
+    property.add_span(0xE8C, 0xEA3);
+    property.add_span(0xEA5, 0xEA5);
+    property.add_span(0xEA7, 0xEB0);
+    property.add_span(0xEB2, 0xEB3);
+    property.add_span(0xEBD, 0xEBD);
+    property.add_span(0xEC0, 0xEC4);
+    property.add_span(0xEC6, 0xEC6);
+    property.add_span(0xEDC, 0xEDF);
+    property.add_span(0xF00, 0xF00);
+    property.add_span(0xF40, 0xF47);
+    property.add_span(0xF49, 0xF6C);
+    property.add_span(0xF88, 0xF8C);
+    property.add_span(0x1000, 0x102A);
+    property.add_span(0x103F, 0x103F);
+    property.add_span(0x1050, 0x1055);
+    property.add_span(0x105A, 0x105D);
+    property.add_span(0x1061, 0x1061);
+    property.add_span(0x1065, 0x1066);
+    property.add_span(0x106E, 0x1070);
+    property.add_span(0x1075, 0x1081);
+    property.add_span(0x108E, 0x108E);
+    property.add_span(0x10A0, 0x10C5);
+    property.add_span(0x10C7, 0x10C7);
+    property.add_span(0x10CD, 0x10CD);
+    property.add_span(0x10D0, 0x10FA);
+    property.add_span(0x10FC, 0x1248);
+    property.add_span(0x124A, 0x124D);
+    property.add_span(0x1250, 0x1256);
+    property.add_span(0x1258, 0x1258);
+    property.add_span(0x125A, 0x125D);
+    property.add_span(0x1260, 0x1288);
+    property.add_span(0x128A, 0x128D);
+    property.add_span(0x1290, 0x12B0);
+    property.add_span(0x12B2, 0x12B5);
+    property.add_span(0x12B8, 0x12BE);
+    property.add_span(0x12C0, 0x12C0);
+    property.add_span(0x12C2, 0x12C5);
+    property.add_span(0x12C8, 0x12D6);
+    property.add_span(0x12D8, 0x1310);
+    property.add_span(0x1312, 0x1315);
+    property.add_span(0x1318, 0x135A);
+    property.add_span(0x1380, 0x138F);
+    property.add_span(0x13A0, 0x13F5);
+    property.add_span(0x13F8, 0x13FD);
+    property.add_span(0x1401, 0x166C);
+    property.add_span(0x166F, 0x167F);
+    property.add_span(0x1681, 0x169A);
+    property.add_span(0x16A0, 0x16EA);
+    property.add_span(0x16EE, 0x16F8);
+    property.add_span(0x1700, 0x1711);
+    property.add_span(0x171F, 0x1731);
+    property.add_span(0x1740, 0x1751);
+    property.add_span(0x1760, 0x176C);
+    property.add_span(0x176E, 0x1770);
+    property.add_span(0x1780, 0x17B3);
+    property.add_span(0x17D7, 0x17D7);
+    property.add_span(0x17DC, 0x17DC);
+    property.add_span(0x1820, 0x1878);
+    property.add_span(0x1880, 0x18A8);
+    property.add_span(0x18AA, 0x18AA);
+    property.add_span(0x18B0, 0x18F5);
+    property.add_span(0x1900, 0x191E);
+    property.add_span(0x1950, 0x196D);
+    property.add_span(0x1970, 0x1974);
+    property.add_span(0x1980, 0x19AB);
+    property.add_span(0x19B0, 0x19C9);
+    property.add_span(0x1A00, 0x1A16);
+    property.add_span(0x1A20, 0x1A54);
+    property.add_span(0x1AA7, 0x1AA7);
+    property.add_span(0x1B05, 0x1B33);
+    property.add_span(0x1B45, 0x1B4C);
+    property.add_span(0x1B83, 0x1BA0);
+    property.add_span(0x1BAE, 0x1BAF);
+    property.add_span(0x1BBA, 0x1BE5);
+    property.add_span(0x1C00, 0x1C23);
+    property.add_span(0x1C4D, 0x1C4F);
+    property.add_span(0x1C5A, 0x1C7D);
+    property.add_span(0x1C80, 0x1C8A);
+    property.add_span(0x1C90, 0x1CBA);
+    property.add_span(0x1CBD, 0x1CBF);
+    property.add_span(0x1CE9, 0x1CEC);
+    property.add_span(0x1CEE, 0x1CF3);
+    property.add_span(0x1CF5, 0x1CF6);
+    property.add_span(0x1CFA, 0x1CFA);
+    property.add_span(0x1D00, 0x1DBF);
+    property.add_span(0x1E00, 0x1F15);
+    property.add_span(0x1F18, 0x1F1D);
+    property.add_span(0x1F20, 0x1F45);
+    property.add_span(0x1F48, 0x1F4D);
+    property.add_span(0x1F50, 0x1F57);
+    property.add_span(0x1F59, 0x1F59);
+    property.add_span(0x1F5B, 0x1F5B);
+    property.add_span(0x1F5D, 0x1F5D);
+    property.add_span(0x1F5F, 0x1F7D);
+    property.add_span(0x1F80, 0x1FB4);
+    property.add_span(0x1FB6, 0x1FBC);
+    property.add_span(0x1FBE, 0x1FBE);
+    property.add_span(0x1FC2, 0x1FC4);
+    property.add_span(0x1FC6, 0x1FCC);
+    property.add_span(0x1FD0, 0x1FD3);
+    property.add_span(0x1FD6, 0x1FDB);
+    property.add_span(0x1FE0, 0x1FEC);
+    property.add_span(0x1FF2, 0x1FF4);
+    property.add_span(0x1FF6, 0x1FFC);
+    property.add_span(0x2071, 0x2071);
+    property.add_span(0x207F, 0x207F);
+    property.add_span(0x2090, 0x209C);
+    property.add_span(0x2102, 0x2102);
+    property.add_span(0x2107, 0x2107);
+    property.add_span(0x210A, 0x2113);
+    property.add_span(0x2115, 0x2115);
+    property.add_span(0x2118, 0x211D);
+    property.add_span(0x2124, 0x2124);
+    property.add_span(0x2126, 0x2126);
+    property.add_span(0x2128, 0x2128);
+    property.add_span(0x212A, 0x2139);
+    property.add_span(0x213C, 0x213F);
+    property.add_span(0x2145, 0x2149);
+    property.add_span(0x214E, 0x214E);
+    property.add_span(0x2160, 0x2188);
+    property.add_span(0x2C00, 0x2CE4);
+    property.add_span(0x2CEB, 0x2CEE);
+    property.add_span(0x2CF2, 0x2CF3);
+    property.add_span(0x2D00, 0x2D25);
+    property.add_span(0x2D27, 0x2D27);
+    property.add_span(0x2D2D, 0x2D2D);
+    property.add_span(0x2D30, 0x2D67);
+    property.add_span(0x2D6F, 0x2D6F);
+    property.add_span(0x2D80, 0x2D96);
+    property.add_span(0x2DA0, 0x2DA6);
+    property.add_span(0x2DA8, 0x2DAE);
+    property.add_span(0x2DB0, 0x2DB6);
+    property.add_span(0x2DB8, 0x2DBE);
+    property.add_span(0x2DC0, 0x2DC6);
+    property.add_span(0x2DC8, 0x2DCE);
+    property.add_span(0x2DD0, 0x2DD6);
+    property.add_span(0x2DD8, 0x2DDE);
+    property.add_span(0x3005, 0x3007);
+    property.add_span(0x3021, 0x3029);
+    property.add_span(0x3031, 0x3035);
+    property.add_span(0x3038, 0x303C);
+    property.add_span(0x3041, 0x3096);
+    property.add_span(0x309B, 0x309F);
+    property.add_span(0x30A1, 0x30FA);
+    property.add_span(0x30FC, 0x30FF);
+    property.add_span(0x3105, 0x312F);
+    property.add_span(0x3131, 0x318E);
+    property.add_span(0x31A0, 0x31BF);
+    property.add_span(0x31F0, 0x31FF);
+    property.add_span(0x3400, 0x4DBF);
+    property.add_span(0x4E00, 0xA48C);
+    property.add_span(0xA4D0, 0xA4FD);
+    property.add_span(0xA500, 0xA60C);
+    property.add_span(0xA610, 0xA61F);
+    property.add_span(0xA62A, 0xA62B);
+    property.add_span(0xA640, 0xA66E);
+    property.add_span(0xA67F, 0xA69D);
+    property.add_span(0xA6A0, 0xA6EF);
+    property.add_span(0xA717, 0xA71F);
+    property.add_span(0xA722, 0xA788);
+    property.add_span(0xA78B, 0xA7CD);
+    property.add_span(0xA7D0, 0xA7D1);
+    property.add_span(0xA7D3, 0xA7D3);
+    property.add_span(0xA7D5, 0xA7DC);
+    property.add_span(0xA7F2, 0xA801);
+    property.add_span(0xA803, 0xA805);
+    property.add_span(0xA807, 0xA80A);
+    property.add_span(0xA80C, 0xA822);
+    property.add_span(0xA840, 0xA873);
+    property.add_span(0xA882, 0xA8B3);
+    property.add_span(0xA8F2, 0xA8F7);
+    property.add_span(0xA8FB, 0xA8FB);
+    property.add_span(0xA8FD, 0xA8FE);
+    property.add_span(0xA90A, 0xA925);
+    property.add_span(0xA930, 0xA946);
+    property.add_span(0xA960, 0xA97C);
+    property.add_span(0xA984, 0xA9B2);
+    property.add_span(0xA9CF, 0xA9CF);
+    property.add_span(0xA9E0, 0xA9E4);
+    property.add_span(0xA9E6, 0xA9EF);
+    property.add_span(0xA9FA, 0xA9FE);
+    property.add_span(0xAA00, 0xAA28);
+    property.add_span(0xAA40, 0xAA42);
+    property.add_span(0xAA44, 0xAA4B);
+    property.add_span(0xAA60, 0xAA76);
+    property.add_span(0xAA7A, 0xAA7A);
+    property.add_span(0xAA7E, 0xAAAF);
+    property.add_span(0xAAB1, 0xAAB1);
+    property.add_span(0xAAB5, 0xAAB6);
+    property.add_span(0xAAB9, 0xAABD);
+    property.add_span(0xAAC0, 0xAAC0);
+    property.add_span(0xAAC2, 0xAAC2);
+    property.add_span(0xAADB, 0xAADD);
+    property.add_span(0xAAE0, 0xAAEA);
+    property.add_span(0xAAF2, 0xAAF4);
+    property.add_span(0xAB01, 0xAB06);
+    property.add_span(0xAB09, 0xAB0E);
+    property.add_span(0xAB11, 0xAB16);
+    property.add_span(0xAB20, 0xAB26);
+    property.add_span(0xAB28, 0xAB2E);
+    property.add_span(0xAB30, 0xAB5A);
+    property.add_span(0xAB5C, 0xAB69);
+    property.add_span(0xAB70, 0xABE2);
+    property.add_span(0xAC00, 0xD7A3);
+    property.add_span(0xD7B0, 0xD7C6);
+    property.add_span(0xD7CB, 0xD7FB);
+    property.add_span(0xF900, 0xFA6D);
+    property.add_span(0xFA70, 0xFAD9);
+    property.add_span(0xFB00, 0xFB06);
+    property.add_span(0xFB13, 0xFB17);
+    property.add_span(0xFB1D, 0xFB1D);
+    property.add_span(0xFB1F, 0xFB28);
+    property.add_span(0xFB2A, 0xFB36);
+    property.add_span(0xFB38, 0xFB3C);
+    property.add_span(0xFB3E, 0xFB3E);
+    property.add_span(0xFB40, 0xFB41);
+    property.add_span(0xFB43, 0xFB44);
+    property.add_span(0xFB46, 0xFBB1);
+    property.add_span(0xFBD3, 0xFD3D);
+    property.add_span(0xFD50, 0xFD8F);
+    property.add_span(0xFD92, 0xFDC7);
+    property.add_span(0xFDF0, 0xFDFB);
+    property.add_span(0xFE70, 0xFE74);
+    property.add_span(0xFE76, 0xFEFC);
+    property.add_span(0xFF21, 0xFF3A);
+    property.add_span(0xFF41, 0xFF5A);
+    property.add_span(0xFF66, 0xFFBE);
+    property.add_span(0xFFC2, 0xFFC7);
+    property.add_span(0xFFCA, 0xFFCF);
+    property.add_span(0xFFD2, 0xFFD7);
+    property.add_span(0xFFDA, 0xFFDC);
+    property.add_span(0x10000, 0x1000B);
+    property.add_span(0x1000D, 0x10026);
+    property.add_span(0x10028, 0x1003A);
+    property.add_span(0x1003C, 0x1003D);
+    property.add_span(0x1003F, 0x1004D);
+    property.add_span(0x10050, 0x1005D);
+    property.add_span(0x10080, 0x100FA);
+    property.add_span(0x10140, 0x10174);
+    property.add_span(0x10280, 0x1029C);
+    property.add_span(0x102A0, 0x102D0);
+    property.add_span(0x10300, 0x1031F);
+    property.add_span(0x1032D, 0x1034A);
+    property.add_span(0x10350, 0x10375);
+    property.add_span(0x10380, 0x1039D);
+    property.add_span(0x103A0, 0x103C3);
+    property.add_span(0x103C8, 0x103CF);
+    property.add_span(0x103D1, 0x103D5);
+    property.add_span(0x10400, 0x1049D);
+    property.add_span(0x104B0, 0x104D3);
+    property.add_span(0x104D8, 0x104FB);
+    property.add_span(0x10500, 0x10527);
+    property.add_span(0x10530, 0x10563);
+    property.add_span(0x10570, 0x1057A);
+    property.add_span(0x1057C, 0x1058A);
+    property.add_span(0x1058C, 0x10592);
+    property.add_span(0x10594, 0x10595);
+    property.add_span(0x10597, 0x105A1);
+    property.add_span(0x105A3, 0x105B1);
+    property.add_span(0x105B3, 0x105B9);
+    property.add_span(0x105BB, 0x105BC);
+    property.add_span(0x105C0, 0x105F3);
+    property.add_span(0x10600, 0x10736);
+    property.add_span(0x10740, 0x10755);
+    property.add_span(0x10760, 0x10767);
+    property.add_span(0x10780, 0x10785);
+    property.add_span(0x10787, 0x107B0);
+    property.add_span(0x107B2, 0x107BA);
+    property.add_span(0x10800, 0x10805);
+    property.add_span(0x10808, 0x10808);
+    property.add_span(0x1080A, 0x10835);
+    property.add_span(0x10837, 0x10838);
+    property.add_span(0x1083C, 0x1083C);
+    property.add_span(0x1083F, 0x10855);
+    property.add_span(0x10860, 0x10876);
+    property.add_span(0x10880, 0x1089E);
+    property.add_span(0x108E0, 0x108F2);
+    property.add_span(0x108F4, 0x108F5);
+    property.add_span(0x10900, 0x10915);
+    property.add_span(0x10920, 0x10939);
+    property.add_span(0x10980, 0x109B7);
+    property.add_span(0x109BE, 0x109BF);
+    property.add_span(0x10A00, 0x10A00);
+    property.add_span(0x10A10, 0x10A13);
+    property.add_span(0x10A15, 0x10A17);
+    property.add_span(0x10A19, 0x10A35);
+    property.add_span(0x10A60, 0x10A7C);
+    property.add_span(0x10A80, 0x10A9C);
+    property.add_span(0x10AC0, 0x10AC7);
+    property.add_span(0x10AC9, 0x10AE4);
+    property.add_span(0x10B00, 0x10B35);
+    property.add_span(0x10B40, 0x10B55);
+    property.add_span(0x10B60, 0x10B72);
+    property.add_span(0x10B80, 0x10B91);
+    property.add_span(0x10C00, 0x10C48);
+    property.add_span(0x10C80, 0x10CB2);
+    property.add_span(0x10CC0, 0x10CF2);
+    property.add_span(0x10D00, 0x10D23);
+    property.add_span(0x10D4A, 0x10D65);
+    property.add_span(0x10D6F, 0x10D85);
+    property.add_span(0x10E80, 0x10EA9);
+    property.add_span(0x10EB0, 0x10EB1);
+    property.add_span(0x10EC2, 0x10EC4);
+    property.add_span(0x10F00, 0x10F1C);
+    property.add_span(0x10F27, 0x10F27);
+    property.add_span(0x10F30, 0x10F45);
+    property.add_span(0x10F70, 0x10F81);
+    property.add_span(0x10FB0, 0x10FC4);
+    property.add_span(0x10FE0, 0x10FF6);
+    property.add_span(0x11003, 0x11037);
+    property.add_span(0x11071, 0x11072);
+    property.add_span(0x11075, 0x11075);
+    property.add_span(0x11083, 0x110AF);
+    property.add_span(0x110D0, 0x110E8);
+    property.add_span(0x11103, 0x11126);
+    property.add_span(0x11144, 0x11144);
+    property.add_span(0x11147, 0x11147);
+    property.add_span(0x11150, 0x11172);
+    property.add_span(0x11176, 0x11176);
+    property.add_span(0x11183, 0x111B2);
+    property.add_span(0x111C1, 0x111C4);
+    property.add_span(0x111DA, 0x111DA);
+    property.add_span(0x111DC, 0x111DC);
+    property.add_span(0x11200, 0x11211);
+    property.add_span(0x11213, 0x1122B);
+    property.add_span(0x1123F, 0x11240);
+    property.add_span(0x11280, 0x11286);
+    property.add_span(0x11288, 0x11288);
+    property.add_span(0x1128A, 0x1128D);
+    property.add_span(0x1128F, 0x1129D);
+    property.add_span(0x1129F, 0x112A8);
+    property.add_span(0x112B0, 0x112DE);
+    property.add_span(0x11305, 0x1130C);
+    property.add_span(0x1130F, 0x11310);
+    property.add_span(0x11313, 0x11328);
+    property.add_span(0x1132A, 0x11330);
+    property.add_span(0x11332, 0x11333);
+    property.add_span(0x11335, 0x11339);
+    property.add_span(0x1133D, 0x1133D);
+    property.add_span(0x11350, 0x11350);
+    property.add_span(0x1135D, 0x11361);
+    property.add_span(0x11380, 0x11389);
+    property.add_span(0x1138B, 0x1138B);
+    property.add_span(0x1138E, 0x1138E);
+    property.add_span(0x11390, 0x113B5);
+    property.add_span(0x113B7, 0x113B7);
+    property.add_span(0x113D1, 0x113D1);
+    property.add_span(0x113D3, 0x113D3);
+    property.add_span(0x11400, 0x11434);
+    property.add_span(0x11447, 0x1144A);
+    property.add_span(0x1145F, 0x11461);
+    property.add_span(0x11480, 0x114AF);
+    property.add_span(0x114C4, 0x114C5);
+    property.add_span(0x114C7, 0x114C7);
+    property.add_span(0x11580, 0x115AE);
+    property.add_span(0x115D8, 0x115DB);
+    property.add_span(0x11600, 0x1162F);
+    property.add_span(0x11644, 0x11644);
+    property.add_span(0x11680, 0x116AA);
+    property.add_span(0x116B8, 0x116B8);
+    property.add_span(0x11700, 0x1171A);
+    property.add_span(0x11740, 0x11746);
+    property.add_span(0x11800, 0x1182B);
+    property.add_span(0x118A0, 0x118DF);
+    property.add_span(0x118FF, 0x11906);
+    property.add_span(0x11909, 0x11909);
+    property.add_span(0x1190C, 0x11913);
+    property.add_span(0x11915, 0x11916);
+    property.add_span(0x11918, 0x1192F);
+    property.add_span(0x1193F, 0x1193F);
+    property.add_span(0x11941, 0x11941);
+    property.add_span(0x119A0, 0x119A7);
+    property.add_span(0x119AA, 0x119D0);
+    property.add_span(0x119E1, 0x119E1);
+    property.add_span(0x119E3, 0x119E3);
+    property.add_span(0x11A00, 0x11A00);
+    property.add_span(0x11A0B, 0x11A32);
+    property.add_span(0x11A3A, 0x11A3A);
+    property.add_span(0x11A50, 0x11A50);
+    property.add_span(0x11A5C, 0x11A89);
+    property.add_span(0x11A9D, 0x11A9D);
+    property.add_span(0x11AB0, 0x11AF8);
+    property.add_span(0x11BC0, 0x11BE0);
+    property.add_span(0x11C00, 0x11C08);
+    property.add_span(0x11C0A, 0x11C2E);
+    property.add_span(0x11C40, 0x11C40);
+    property.add_span(0x11C72, 0x11C8F);
+    property.add_span(0x11D00, 0x11D06);
+    property.add_span(0x11D08, 0x11D09);
+    property.add_span(0x11D0B, 0x11D30);
+    property.add_span(0x11D46, 0x11D46);
+    property.add_span(0x11D60, 0x11D65);
+    property.add_span(0x11D67, 0x11D68);
+    property.add_span(0x11D6A, 0x11D89);
+    property.add_span(0x11D98, 0x11D98);
+    property.add_span(0x11EE0, 0x11EF2);
+    property.add_span(0x11F02, 0x11F02);
+    property.add_span(0x11F04, 0x11F10);
+    property.add_span(0x11F12, 0x11F33);
+    property.add_span(0x11FB0, 0x11FB0);
+    property.add_span(0x12000, 0x12399);
+    property.add_span(0x12400, 0x1246E);
+    property.add_span(0x12480, 0x12543);
+    property.add_span(0x12F90, 0x12FF0);
+    property.add_span(0x13000, 0x1342F);
+    property.add_span(0x13441, 0x13446);
+    property.add_span(0x13460, 0x143FA);
+    property.add_span(0x14400, 0x14646);
+    property.add_span(0x16100, 0x1611D);
+    property.add_span(0x16800, 0x16A38);
+    property.add_span(0x16A40, 0x16A5E);
+    property.add_span(0x16A70, 0x16ABE);
+    property.add_span(0x16AD0, 0x16AED);
+    property.add_span(0x16B00, 0x16B2F);
+    property.add_span(0x16B40, 0x16B43);
+    property.add_span(0x16B63, 0x16B77);
+    property.add_span(0x16B7D, 0x16B8F);
+    property.add_span(0x16D40, 0x16D6C);
+    property.add_span(0x16E40, 0x16E7F);
+    property.add_span(0x16F00, 0x16F4A);
+    property.add_span(0x16F50, 0x16F50);
+    property.add_span(0x16F93, 0x16F9F);
+    property.add_span(0x16FE0, 0x16FE1);
+    property.add_span(0x16FE3, 0x16FE3);
+    property.add_span(0x17000, 0x187F7);
+    property.add_span(0x18800, 0x18CD5);
+    property.add_span(0x18CFF, 0x18D08);
+    property.add_span(0x1AFF0, 0x1AFF3);
+    property.add_span(0x1AFF5, 0x1AFFB);
+    property.add_span(0x1AFFD, 0x1AFFE);
+    property.add_span(0x1B000, 0x1B122);
+    property.add_span(0x1B132, 0x1B132);
+    property.add_span(0x1B150, 0x1B152);
+    property.add_span(0x1B155, 0x1B155);
+    property.add_span(0x1B164, 0x1B167);
+    property.add_span(0x1B170, 0x1B2FB);
+    property.add_span(0x1BC00, 0x1BC6A);
+    property.add_span(0x1BC70, 0x1BC7C);
+    property.add_span(0x1BC80, 0x1BC88);
+    property.add_span(0x1BC90, 0x1BC99);
+    property.add_span(0x1D400, 0x1D454);
+    property.add_span(0x1D456, 0x1D49C);
+    property.add_span(0x1D49E, 0x1D49F);
+    property.add_span(0x1D4A2, 0x1D4A2);
+    property.add_span(0x1D4A5, 0x1D4A6);
+    property.add_span(0x1D4A9, 0x1D4AC);
+    property.add_span(0x1D4AE, 0x1D4B9);
+    property.add_span(0x1D4BB, 0x1D4BB);
+    property.add_span(0x1D4BD, 0x1D4C3);
+    property.add_span(0x1D4C5, 0x1D505);
+    property.add_span(0x1D507, 0x1D50A);
+    property.add_span(0x1D50D, 0x1D514);
+    property.add_span(0x1D516, 0x1D51C);
+    property.add_span(0x1D51E, 0x1D539);
+    property.add_span(0x1D53B, 0x1D53E);
+    property.add_span(0x1D540, 0x1D544);
+    property.add_span(0x1D546, 0x1D546);
+    property.add_span(0x1D54A, 0x1D550);
+    property.add_span(0x1D552, 0x1D6A5);
+    property.add_span(0x1D6A8, 0x1D6C0);
+    property.add_span(0x1D6C2, 0x1D6DA);
+    property.add_span(0x1D6DC, 0x1D6FA);
+    property.add_span(0x1D6FC, 0x1D714);
+    property.add_span(0x1D716, 0x1D734);
+    property.add_span(0x1D736, 0x1D74E);
+    property.add_span(0x1D750, 0x1D76E);
+    property.add_span(0x1D770, 0x1D788);
+    property.add_span(0x1D78A, 0x1D7A8);
+    property.add_span(0x1D7AA, 0x1D7C2);
+    property.add_span(0x1D7C4, 0x1D7CB);
+    property.add_span(0x1DF00, 0x1DF1E);
+    property.add_span(0x1DF25, 0x1DF2A);
+    property.add_span(0x1E030, 0x1E06D);
+    property.add_span(0x1E100, 0x1E12C);
+    property.add_span(0x1E137, 0x1E13D);
+    property.add_span(0x1E14E, 0x1E14E);
+    property.add_span(0x1E290, 0x1E2AD);
+    property.add_span(0x1E2C0, 0x1E2EB);
+    property.add_span(0x1E4D0, 0x1E4EB);
+    property.add_span(0x1E5D0, 0x1E5ED);
+    property.add_span(0x1E5F0, 0x1E5F0);
+    property.add_span(0x1E7E0, 0x1E7E6);
+    property.add_span(0x1E7E8, 0x1E7EB);
+    property.add_span(0x1E7ED, 0x1E7EE);
+    property.add_span(0x1E7F0, 0x1E7FE);
+    property.add_span(0x1E800, 0x1E8C4);
+    property.add_span(0x1E900, 0x1E943);
+    property.add_span(0x1E94B, 0x1E94B);
+    property.add_span(0x1EE00, 0x1EE03);
+    property.add_span(0x1EE05, 0x1EE1F);
+    property.add_span(0x1EE21, 0x1EE22);
+    property.add_span(0x1EE24, 0x1EE24);
+    property.add_span(0x1EE27, 0x1EE27);
+    property.add_span(0x1EE29, 0x1EE32);
+    property.add_span(0x1EE34, 0x1EE37);
+    property.add_span(0x1EE39, 0x1EE39);
+    property.add_span(0x1EE3B, 0x1EE3B);
+    property.add_span(0x1EE42, 0x1EE42);
+    property.add_span(0x1EE47, 0x1EE47);
+    property.add_span(0x1EE49, 0x1EE49);
+    property.add_span(0x1EE4B, 0x1EE4B);
+    property.add_span(0x1EE4D, 0x1EE4F);
+    property.add_span(0x1EE51, 0x1EE52);
+    property.add_span(0x1EE54, 0x1EE54);
+    property.add_span(0x1EE57, 0x1EE57);
+    property.add_span(0x1EE59, 0x1EE59);
+    property.add_span(0x1EE5B, 0x1EE5B);
+    property.add_span(0x1EE5D, 0x1EE5D);
+    property.add_span(0x1EE5F, 0x1EE5F);
+    property.add_span(0x1EE61, 0x1EE62);
+    property.add_span(0x1EE64, 0x1EE64);
+    property.add_span(0x1EE67, 0x1EE6A);
+    property.add_span(0x1EE6C, 0x1EE72);
+    property.add_span(0x1EE74, 0x1EE77);
+    property.add_span(0x1EE79, 0x1EE7C);
+    property.add_span(0x1EE7E, 0x1EE7E);
+    property.add_span(0x1EE80, 0x1EE89);
+    property.add_span(0x1EE8B, 0x1EE9B);
+    property.add_span(0x1EEA1, 0x1EEA3);
+    property.add_span(0x1EEA5, 0x1EEA9);
+    property.add_span(0x1EEAB, 0x1EEBB);
+    property.add_span(0x20000, 0x2A6DF);
+    property.add_span(0x2A700, 0x2B739);
+    property.add_span(0x2B740, 0x2B81D);
+    property.add_span(0x2B820, 0x2CEA1);
+    property.add_span(0x2CEB0, 0x2EBE0);
+    property.add_span(0x2EBF0, 0x2EE5D);
+    property.add_span(0x2F800, 0x2FA1D);
+    property.add_span(0x30000, 0x3134A);
+    property.add_span(0x31350, 0x323AF);
+    db.insert("ID_Start", property);
+    let mut property = Category::new("Lowercase");
+    property.add_span(0x61, 0x7A);
+    property.add_span(0xAA, 0xAA);
+    property.add_span(0xB5, 0xB5);
+    property.add_span(0xBA, 0xBA);
+    property.add_span(0xDF, 0xF6);
+    property.add_span(0xF8, 0xFF);
+    property.add_span(0x101, 0x101);
+    property.add_span(0x103, 0x103);
+    property.add_span(0x105, 0x105);
+    property.add_span(0x107, 0x107);
+    property.add_span(0x109, 0x109);
+    property.add_span(0x10B, 0x10B);
+    property.add_span(0x10D, 0x10D);
+    property.add_span(0x10F, 0x10F);
+    property.add_span(0x111, 0x111);
+    property.add_span(0x113, 0x113);
+    property.add_span(0x115, 0x115);
+    property.add_span(0x117, 0x117);
+    property.add_span(0x119, 0x119);
+    property.add_span(0x11B, 0x11B);
+    property.add_span(0x11D, 0x11D);
+    property.add_span(0x11F, 0x11F);
+    property.add_span(0x121, 0x121);
+    property.add_span(0x123, 0x123);
+    property.add_span(0x125, 0x125);
+    property.add_span(0x127, 0x127);
+    property.add_span(0x129, 0x129);
+    property.add_span(0x12B, 0x12B);
+    property.add_span(0x12D, 0x12D);
+    property.add_span(0x12F, 0x12F);
+    property.add_span(0x131, 0x131);
+    property.add_span(0x133, 0x133);
+    property.add_span(0x135, 0x135);
+    property.add_span(0x137, 0x138);
+    property.add_span(0x13A, 0x13A);
+    property.add_span(0x13C, 0x13C);
+    property.add_span(0x13E, 0x13E);
+    property.add_span(0x140, 0x140);
+    property.add_span(0x142, 0x142);
+    property.add_span(0x144, 0x144);
+    property.add_span(0x146, 0x146);
+    property.add_span(0x148, 0x149);
+    property.add_span(0x14B, 0x14B);
+    property.add_span(0x14D, 0x14D);
+    property.add_span(0x14F, 0x14F);
+    property.add_span(0x151, 0x151);
+    property.add_span(0x153, 0x153);
+    property.add_span(0x155, 0x155);
+    property.add_span(0x157, 0x157);
+    property.add_span(0x159, 0x159);
+    property.add_span(0x15B, 0x15B);
+    property.add_span(0x15D, 0x15D);
+    property.add_span(0x15F, 0x15F);
+    property.add_span(0x161, 0x161);
+    property.add_span(0x163, 0x163);
+    property.add_span(0x165, 0x165);
+    property.add_span(0x167, 0x167);
+    property.add_span(0x169, 0x169);
+    property.add_span(0x16B, 0x16B);
+    property.add_span(0x16D, 0x16D);
+    property.add_span(0x16F, 0x16F);
+    property.add_span(0x171, 0x171);
+    property.add_span(0x173, 0x173);
+    property.add_span(0x175, 0x175);
+    property.add_span(0x177, 0x177);
+    property.add_span(0x17A, 0x17A);
+    property.add_span(0x17C, 0x17C);
+    property.add_span(0x17E, 0x180);
+    property.add_span(0x183, 0x183);
+    property.add_span(0x185, 0x185);
+    property.add_span(0x188, 0x188);
+    property.add_span(0x18C, 0x18D);
+    property.add_span(0x192, 0x192);
+    property.add_span(0x195, 0x195);
+    property.add_span(0x199, 0x19B);
+    property.add_span(0x19E, 0x19E);
+    property.add_span(0x1A1, 0x1A1);
+    property.add_span(0x1A3, 0x1A3);
+    property.add_span(0x1A5, 0x1A5);
+    property.add_span(0x1A8, 0x1A8);
+    property.add_span(0x1AA, 0x1AB);
+    property.add_span(0x1AD, 0x1AD);
+    property.add_span(0x1B0, 0x1B0);
+    property.add_span(0x1B4, 0x1B4);
+    property.add_span(0x1B6, 0x1B6);
+    property.add_span(0x1B9, 0x1BA);
+    property.add_span(0x1BD, 0x1BF);
+    property.add_span(0x1C6, 0x1C6);
+    property.add_span(0x1C9, 0x1C9);
+    property.add_span(0x1CC, 0x1CC);
+    property.add_span(0x1CE, 0x1CE);
+    property.add_span(0x1D0, 0x1D0);
+    property.add_span(0x1D2, 0x1D2);
+    property.add_span(0x1D4, 0x1D4);
+    property.add_span(0x1D6, 0x1D6);
+    property.add_span(0x1D8, 0x1D8);
+    property.add_span(0x1DA, 0x1DA);
+    property.add_span(0x1DC, 0x1DD);
+    property.add_span(0x1DF, 0x1DF);
+    property.add_span(0x1E1, 0x1E1);
+    property.add_span(0x1E3, 0x1E3);
+    property.add_span(0x1E5, 0x1E5);
+    property.add_span(0x1E7, 0x1E7);
+    property.add_span(0x1E9, 0x1E9);
+    property.add_span(0x1EB, 0x1EB);
+    property.add_span(0x1ED, 0x1ED);
+    property.add_span(0x1EF, 0x1F0);
+    property.add_span(0x1F3, 0x1F3);
+    property.add_span(0x1F5, 0x1F5);
+    property.add_span(0x1F9, 0x1F9);
+    property.add_span(0x1FB, 0x1FB);
+    property.add_span(0x1FD, 0x1FD);
+    property.add_span(0x1FF, 0x1FF);
+    property.add_span(0x201, 0x201);
+    property.add_span(0x203, 0x203);
+    property.add_span(0x205, 0x205);
+    property.add_span(0x207, 0x207);
+    property.add_span(0x209, 0x209);
+    property.add_span(0x20B, 0x20B);
+    property.add_span(0x20D, 0x20D);
+    property.add_span(0x20F, 0x20F);
+    property.add_span(0x211, 0x211);
+    property.add_span(0x213, 0x213);
+    property.add_span(0x215, 0x215);
+    property.add_span(0x217, 0x217);
+    property.add_span(0x219, 0x219);
+    property.add_span(0x21B, 0x21B);
+    property.add_span(0x21D, 0x21D);
+    property.add_span(0x21F, 0x21F);
+    property.add_span(0x221, 0x221);
+    property.add_span(0x223, 0x223);
+    property.add_span(0x225, 0x225);
+    property.add_span(0x227, 0x227);
+    property.add_span(0x229, 0x229);
+    property.add_span(0x22B, 0x22B);
+    property.add_span(0x22D, 0x22D);
+    property.add_span(0x22F, 0x22F);
+    property.add_span(0x231, 0x231);
+    property.add_span(0x233, 0x239);
+    property.add_span(0x23C, 0x23C);
+    property.add_span(0x23F, 0x240);
+    property.add_span(0x242, 0x242);
+    property.add_span(0x247, 0x247);
+    property.add_span(0x249, 0x249);
+    property.add_span(0x24B, 0x24B);
+    property.add_span(0x24D, 0x24D);
+    property.add_span(0x24F, 0x293);
+    property.add_span(0x295, 0x2B8);
+    property.add_span(0x2C0, 0x2C1);
+    property.add_span(0x2E0, 0x2E4);
+    property.add_span(0x345, 0x345);
+    property.add_span(0x371, 0x371);
+    property.add_span(0x373, 0x373);
+    property.add_span(0x377, 0x377);
+    property.add_span(0x37A, 0x37D);
+    property.add_span(0x390, 0x390);
+    property.add_span(0x3AC, 0x3CE);
+    property.add_span(0x3D0, 0x3D1);
+    property.add_span(0x3D5, 0x3D7);
+    property.add_span(0x3D9, 0x3D9);
+    property.add_span(0x3DB, 0x3DB);
+    property.add_span(0x3DD, 0x3DD);
+    property.add_span(0x3DF, 0x3DF);
+    property.add_span(0x3E1, 0x3E1);
+    property.add_span(0x3E3, 0x3E3);
+    property.add_span(0x3E5, 0x3E5);
+    property.add_span(0x3E7, 0x3E7);
+    property.add_span(0x3E9, 0x3E9);
+    property.add_span(0x3EB, 0x3EB);
+    property.add_span(0x3ED, 0x3ED);
+    property.add_span(0x3EF, 0x3F3);
+    property.add_span(0x3F5, 0x3F5);
+    property.add_span(0x3F8, 0x3F8);
+    property.add_span(0x3FB, 0x3FC);
+    property.add_span(0x430, 0x45F);
+    property.add_span(0x461, 0x461);
+    property.add_span(0x463, 0x463);
+    property.add_span(0x465, 0x465);
+    property.add_span(0x467, 0x467);
+    property.add_span(0x469, 0x469);
+    property.add_span(0x46B, 0x46B);
+    property.add_span(0x46D, 0x46D);
+    property.add_span(0x46F, 0x46F);
+    property.add_span(0x471, 0x471);
+    property.add_span(0x473, 0x473);
+    property.add_span(0x475, 0x475);
+    property.add_span(0x477, 0x477);
+    property.add_span(0x479, 0x479);
+    property.add_span(0x47B, 0x47B);
+    property.add_span(0x47D, 0x47D);
+    property.add_span(0x47F, 0x47F);
+    property.add_span(0x481, 0x481);
+    property.add_span(0x48B, 0x48B);
+    property.add_span(0x48D, 0x48D);
+    property.add_span(0x48F, 0x48F);
+    property.add_span(0x491, 0x491);
+    property.add_span(0x493, 0x493);
+    property.add_span(0x495, 0x495);
+    property.add_span(0x497, 0x497);
+    property.add_span(0x499, 0x499);
+    property.add_span(0x49B, 0x49B);
+    property.add_span(0x49D, 0x49D);
+    property.add_span(0x49F, 0x49F);
+    property.add_span(0x4A1, 0x4A1);
+    property.add_span(0x4A3, 0x4A3);
+    property.add_span(0x4A5, 0x4A5);
+    property.add_span(0x4A7, 0x4A7);
+    property.add_span(0x4A9, 0x4A9);
+    property.add_span(0x4AB, 0x4AB);
+    property.add_span(0x4AD, 0x4AD);
+    property.add_span(0x4AF, 0x4AF);
+    property.add_span(0x4B1, 0x4B1);
+    property.add_span(0x4B3, 0x4B3);
+    property.add_span(0x4B5, 0x4B5);
+    property.add_span(0x4B7, 0x4B7);
+    property.add_span(0x4B9, 0x4B9);
+    property.add_span(0x4BB, 0x4BB);
+    property.add_span(0x4BD, 0x4BD);
+    property.add_span(0x4BF, 0x4BF);
+    property.add_span(0x4C2, 0x4C2);
+    property.add_span(0x4C4, 0x4C4);
+    property.add_span(0x4C6, 0x4C6);
+    property.add_span(0x4C8, 0x4C8);
+    property.add_span(0x4CA, 0x4CA);
+    property.add_span(0x4CC, 0x4CC);
+    property.add_span(0x4CE, 0x4CF);
+    property.add_span(0x4D1, 0x4D1);
+    property.add_span(0x4D3, 0x4D3);
+    property.add_span(0x4D5, 0x4D5);
+    property.add_span(0x4D7, 0x4D7);
+    property.add_span(0x4D9, 0x4D9);
+    property.add_span(0x4DB, 0x4DB);
+    property.add_span(0x4DD, 0x4DD);
+    property.add_span(0x4DF, 0x4DF);
+    property.add_span(0x4E1, 0x4E1);
+    property.add_span(0x4E3, 0x4E3);
+    property.add_span(0x4E5, 0x4E5);
+    property.add_span(0x4E7, 0x4E7);
+    property.add_span(0x4E9, 0x4E9);
+    property.add_span(0x4EB, 0x4EB);
+    property.add_span(0x4ED, 0x4ED);
+    property.add_span(0x4EF, 0x4EF);
+    property.add_span(0x4F1, 0x4F1);
+    property.add_span(0x4F3, 0x4F3);
+    property.add_span(0x4F5, 0x4F5);
+    property.add_span(0x4F7, 0x4F7);
+    property.add_span(0x4F9, 0x4F9);
+    property.add_span(0x4FB, 0x4FB);
+    property.add_span(0x4FD, 0x4FD);
+    property.add_span(0x4FF, 0x4FF);
+    property.add_span(0x501, 0x501);
+    property.add_span(0x503, 0x503);
+    property.add_span(0x505, 0x505);
+    property.add_span(0x507, 0x507);
+    property.add_span(0x509, 0x509);
+    property.add_span(0x50B, 0x50B);
+    property.add_span(0x50D, 0x50D);
+    property.add_span(0x50F, 0x50F);
+    property.add_span(0x511, 0x511);
+    property.add_span(0x513, 0x513);
+    property.add_span(0x515, 0x515);
+    property.add_span(0x517, 0x517);
+    property.add_span(0x519, 0x519);
+    property.add_span(0x51B, 0x51B);
+    property.add_span(0x51D, 0x51D);
+    property.add_span(0x51F, 0x51F);
+    property.add_span(0x521, 0x521);
+    property.add_span(0x523, 0x523);
+    property.add_span(0x525, 0x525);
+    property.add_span(0x527, 0x527);
+    property.add_span(0x529, 0x529);
+    property.add_span(0x52B, 0x52B);
+    property.add_span(0x52D, 0x52D);
+    property.add_span(0x52F, 0x52F);
+    property.add_span(0x560, 0x588);
+    property.add_span(0x10D0, 0x10FA);
+    property.add_span(0x10FC, 0x10FF);
+    property.add_span(0x13F8, 0x13FD);
+    property.add_span(0x1C80, 0x1C88);
+    property.add_span(0x1C8A, 0x1C8A);
+    property.add_span(0x1D00, 0x1DBF);
+    property.add_span(0x1E01, 0x1E01);
+    property.add_span(0x1E03, 0x1E03);
+    property.add_span(0x1E05, 0x1E05);
+    property.add_span(0x1E07, 0x1E07);
+    property.add_span(0x1E09, 0x1E09);
+    property.add_span(0x1E0B, 0x1E0B);
+    property.add_span(0x1E0D, 0x1E0D);
+    property.add_span(0x1E0F, 0x1E0F);
+    property.add_span(0x1E11, 0x1E11);
+    property.add_span(0x1E13, 0x1E13);
+    property.add_span(0x1E15, 0x1E15);
+    property.add_span(0x1E17, 0x1E17);
+    property.add_span(0x1E19, 0x1E19);
+    property.add_span(0x1E1B, 0x1E1B);
+    property.add_span(0x1E1D, 0x1E1D);
+    property.add_span(0x1E1F, 0x1E1F);
+    property.add_span(0x1E21, 0x1E21);
+    property.add_span(0x1E23, 0x1E23);
+    property.add_span(0x1E25, 0x1E25);
+    property.add_span(0x1E27, 0x1E27);
+    property.add_span(0x1E29, 0x1E29);
+    property.add_span(0x1E2B, 0x1E2B);
+    property.add_span(0x1E2D, 0x1E2D);
+    property.add_span(0x1E2F, 0x1E2F);
+    property.add_span(0x1E31, 0x1E31);
+    property.add_span(0x1E33, 0x1E33);
+    property.add_span(0x1E35, 0x1E35);
+    property.add_span(0x1E37, 0x1E37);
+    property.add_span(0x1E39, 0x1E39);
+    property.add_span(0x1E3B, 0x1E3B);
+    property.add_span(0x1E3D, 0x1E3D);
+    property.add_span(0x1E3F, 0x1E3F);
+    property.add_span(0x1E41, 0x1E41);
+    property.add_span(0x1E43, 0x1E43);
+    property.add_span(0x1E45, 0x1E45);
+    property.add_span(0x1E47, 0x1E47);
+    property.add_span(0x1E49, 0x1E49);
+    property.add_span(0x1E4B, 0x1E4B);
+    property.add_span(0x1E4D, 0x1E4D);
+    property.add_span(0x1E4F, 0x1E4F);
+    property.add_span(0x1E51, 0x1E51);
+    property.add_span(0x1E53, 0x1E53);
+    property.add_span(0x1E55, 0x1E55);
+    property.add_span(0x1E57, 0x1E57);
+    property.add_span(0x1E59, 0x1E59);
+    property.add_span(0x1E5B, 0x1E5B);
+    property.add_span(0x1E5D, 0x1E5D);
+    property.add_span(0x1E5F, 0x1E5F);
+    property.add_span(0x1E61, 0x1E61);
+    property.add_span(0x1E63, 0x1E63);
+    property.add_span(0x1E65, 0x1E65);
+    property.add_span(0x1E67, 0x1E67);
+    property.add_span(0x1E69, 0x1E69);
+    property.add_span(0x1E6B, 0x1E6B);
+    property.add_span(0x1E6D, 0x1E6D);
+    property.add_span(0x1E6F, 0x1E6F);
+    property.add_span(0x1E71, 0x1E71);
+    property.add_span(0x1E73, 0x1E73);
+    property.add_span(0x1E75, 0x1E75);
+    property.add_span(0x1E77, 0x1E77);
+    property.add_span(0x1E79, 0x1E79);
+    property.add_span(0x1E7B, 0x1E7B);
+    property.add_span(0x1E7D, 0x1E7D);
+    property.add_span(0x1E7F, 0x1E7F);
+    property.add_span(0x1E81, 0x1E81);
+    property.add_span(0x1E83, 0x1E83);
+    property.add_span(0x1E85, 0x1E85);
+    property.add_span(0x1E87, 0x1E87);
+    property.add_span(0x1E89, 0x1E89);
+    property.add_span(0x1E8B, 0x1E8B);
+    property.add_span(0x1E8D, 0x1E8D);
+    property.add_span(0x1E8F, 0x1E8F);
+    property.add_span(0x1E91, 0x1E91);
+    property.add_span(0x1E93, 0x1E93);
+    property.add_span(0x1E95, 0x1E9D);
+    property.add_span(0x1E9F, 0x1E9F);
+    property.add_span(0x1EA1, 0x1EA1);
+    property.add_span(0x1EA3, 0x1EA3);
+    property.add_span(0x1EA5, 0x1EA5);
+    property.add_span(0x1EA7, 0x1EA7);
+    property.add_span(0x1EA9, 0x1EA9);
+    property.add_span(0x1EAB, 0x1EAB);
+    property.add_span(0x1EAD, 0x1EAD);
+    property.add_span(0x1EAF, 0x1EAF);
+    property.add_span(0x1EB1, 0x1EB1);
+    property.add_span(0x1EB3, 0x1EB3);
+    property.add_span(0x1EB5, 0x1EB5);
+    property.add_span(0x1EB7, 0x1EB7);
+    property.add_span(0x1EB9, 0x1EB9);
+    property.add_span(0x1EBB, 0x1EBB);
+    property.add_span(0x1EBD, 0x1EBD);
+    property.add_span(0x1EBF, 0x1EBF);
+    property.add_span(0x1EC1, 0x1EC1);
+    property.add_span(0x1EC3, 0x1EC3);
+    property.add_span(0x1EC5, 0x1EC5);
+    property.add_span(0x1EC7, 0x1EC7);
+    property.add_span(0x1EC9, 0x1EC9);
+    property.add_span(0x1ECB, 0x1ECB);
+    property.add_span(0x1ECD, 0x1ECD);
+    property.add_span(0x1ECF, 0x1ECF);
+    property.add_span(0x1ED1, 0x1ED1);
+    property.add_span(0x1ED3, 0x1ED3);
+    property.add_span(0x1ED5, 0x1ED5);
+    property.add_span(0x1ED7, 0x1ED7);
+    property.add_span(0x1ED9, 0x1ED9);
+    property.add_span(0x1EDB, 0x1EDB);
+    property.add_span(0x1EDD, 0x1EDD);
+    property.add_span(0x1EDF, 0x1EDF);
+    property.add_span(0x1EE1, 0x1EE1);
+    property.add_span(0x1EE3, 0x1EE3);
+    property.add_span(0x1EE5, 0x1EE5);
+    property.add_span(0x1EE7, 0x1EE7);
+    property.add_span(0x1EE9, 0x1EE9);
+    property.add_span(0x1EEB, 0x1EEB);
+    property.add_span(0x1EED, 0x1EED);
+    property.add_span(0x1EEF, 0x1EEF);
+    property.add_span(0x1EF1, 0x1EF1);
+    property.add_span(0x1EF3, 0x1EF3);
+    property.add_span(0x1EF5, 0x1EF5);
+    property.add_span(0x1EF7, 0x1EF7);
+    property.add_span(0x1EF9, 0x1EF9);
+    property.add_span(0x1EFB, 0x1EFB);
+    property.add_span(0x1EFD, 0x1EFD);
+    property.add_span(0x1EFF, 0x1F07);
+    property.add_span(0x1F10, 0x1F15);
+    property.add_span(0x1F20, 0x1F27);
+    property.add_span(0x1F30, 0x1F37);
+    property.add_span(0x1F40, 0x1F45);
+    property.add_span(0x1F50, 0x1F57);
+    property.add_span(0x1F60, 0x1F67);
+    property.add_span(0x1F70, 0x1F7D);
+    property.add_span(0x1F80, 0x1F87);
+    property.add_span(0x1F90, 0x1F97);
+    property.add_span(0x1FA0, 0x1FA7);
+    property.add_span(0x1FB0, 0x1FB4);
+    property.add_span(0x1FB6, 0x1FB7);
+    property.add_span(0x1FBE, 0x1FBE);
+    property.add_span(0x1FC2, 0x1FC4);
+    property.add_span(0x1FC6, 0x1FC7);
+    property.add_span(0x1FD0, 0x1FD3);
+    property.add_span(0x1FD6, 0x1FD7);
+    property.add_span(0x1FE0, 0x1FE7);
+    property.add_span(0x1FF2, 0x1FF4);
+    property.add_span(0x1FF6, 0x1FF7);
+    property.add_span(0x2071, 0x2071);
+    property.add_span(0x207F, 0x207F);
+    property.add_span(0x2090, 0x209C);
+    property.add_span(0x210A, 0x210A);
+    property.add_span(0x210E, 0x210F);
+    property.add_span(0x2113, 0x2113);
+    property.add_span(0x212F, 0x212F);
+    property.add_span(0x2134, 0x2134);
+    property.add_span(0x2139, 0x2139);
+    property.add_span(0x213C, 0x213D);
+    property.add_span(0x2146, 0x2149);
+    property.add_span(0x214E, 0x214E);
+    property.add_span(0x2170, 0x217F);
+    property.add_span(0x2184, 0x2184);
+    property.add_span(0x24D0, 0x24E9);
+    property.add_span(0x2C30, 0x2C5F);
+    property.add_span(0x2C61, 0x2C61);
+    property.add_span(0x2C65, 0x2C66);
+    property.add_span(0x2C68, 0x2C68);
+    property.add_span(0x2C6A, 0x2C6A);
+    property.add_span(0x2C6C, 0x2C6C);
+    property.add_span(0x2C71, 0x2C71);
+    property.add_span(0x2C73, 0x2C74);
+    property.add_span(0x2C76, 0x2C7D);
+    property.add_span(0x2C81, 0x2C81);
+    property.add_span(0x2C83, 0x2C83);
+    property.add_span(0x2C85, 0x2C85);
+    property.add_span(0x2C87, 0x2C87);
+    property.add_span(0x2C89, 0x2C89);
+    property.add_span(0x2C8B, 0x2C8B);
+    property.add_span(0x2C8D, 0x2C8D);
+    property.add_span(0x2C8F, 0x2C8F);
+    property.add_span(0x2C91, 0x2C91);
+    property.add_span(0x2C93, 0x2C93);
+    property.add_span(0x2C95, 0x2C95);
+    property.add_span(0x2C97, 0x2C97);
+    property.add_span(0x2C99, 0x2C99);
+    property.add_span(0x2C9B, 0x2C9B);
+    property.add_span(0x2C9D, 0x2C9D);
+    property.add_span(0x2C9F, 0x2C9F);
+    property.add_span(0x2CA1, 0x2CA1);
+    property.add_span(0x2CA3, 0x2CA3);
+    property.add_span(0x2CA5, 0x2CA5);
+    property.add_span(0x2CA7, 0x2CA7);
+    property.add_span(0x2CA9, 0x2CA9);
+    property.add_span(0x2CAB, 0x2CAB);
+    property.add_span(0x2CAD, 0x2CAD);
+    property.add_span(0x2CAF, 0x2CAF);
+    property.add_span(0x2CB1, 0x2CB1);
+    property.add_span(0x2CB3, 0x2CB3);
+    property.add_span(0x2CB5, 0x2CB5);
+    property.add_span(0x2CB7, 0x2CB7);
+    property.add_span(0x2CB9, 0x2CB9);
+    property.add_span(0x2CBB, 0x2CBB);
+    property.add_span(0x2CBD, 0x2CBD);
+    property.add_span(0x2CBF, 0x2CBF);
+    property.add_span(0x2CC1, 0x2CC1);
+    property.add_span(0x2CC3, 0x2CC3);
+    property.add_span(0x2CC5, 0x2CC5);
+    property.add_span(0x2CC7, 0x2CC7);
+    property.add_span(0x2CC9, 0x2CC9);
+    property.add_span(0x2CCB, 0x2CCB);
+    property.add_span(0x2CCD, 0x2CCD);
+    property.add_span(0x2CCF, 0x2CCF);
+    property.add_span(0x2CD1, 0x2CD1);
+    property.add_span(0x2CD3, 0x2CD3);
+    property.add_span(0x2CD5, 0x2CD5);
+    property.add_span(0x2CD7, 0x2CD7);
+    property.add_span(0x2CD9, 0x2CD9);
+    property.add_span(0x2CDB, 0x2CDB);
+    property.add_span(0x2CDD, 0x2CDD);
+    property.add_span(0x2CDF, 0x2CDF);
+    property.add_span(0x2CE1, 0x2CE1);
+    property.add_span(0x2CE3, 0x2CE4);
+    property.add_span(0x2CEC, 0x2CEC);
+    property.add_span(0x2CEE, 0x2CEE);
+    property.add_span(0x2CF3, 0x2CF3);
+    property.add_span(0x2D00, 0x2D25);
+    property.add_span(0x2D27, 0x2D27);
+    property.add_span(0x2D2D, 0x2D2D);
+    property.add_span(0xA641, 0xA641);
+    property.add_span(0xA643, 0xA643);
+    property.add_span(0xA645, 0xA645);
+    property.add_span(0xA647, 0xA647);
+    property.add_span(0xA649, 0xA649);
+    property.add_span(0xA64B, 0xA64B);
+    property.add_span(0xA64D, 0xA64D);
+    property.add_span(0xA64F, 0xA64F);
+    property.add_span(0xA651, 0xA651);
+    property.add_span(0xA653, 0xA653);
+    property.add_span(0xA655, 0xA655);
+    property.add_span(0xA657, 0xA657);
+    property.add_span(0xA659, 0xA659);
+    property.add_span(0xA65B, 0xA65B);
+    property.add_span(0xA65D, 0xA65D);
+    property.add_span(0xA65F, 0xA65F);
+    property.add_span(0xA661, 0xA661);
+    property.add_span(0xA663, 0xA663);
+    property.add_span(0xA665, 0xA665);
+    property.add_span(0xA667, 0xA667);
+    property.add_span(0xA669, 0xA669);
+    property.add_span(0xA66B, 0xA66B);
+    property.add_span(0xA66D, 0xA66D);
+    property.add_span(0xA681, 0xA681);
+    property.add_span(0xA683, 0xA683);
+    property.add_span(0xA685, 0xA685);
+    property.add_span(0xA687, 0xA687);
+    property.add_span(0xA689, 0xA689);
+    property.add_span(0xA68B, 0xA68B);
+    property.add_span(0xA68D, 0xA68D);
+    property.add_span(0xA68F, 0xA68F);
+    property.add_span(0xA691, 0xA691);
+    property.add_span(0xA693, 0xA693);
+    property.add_span(0xA695, 0xA695);
+    property.add_span(0xA697, 0xA697);
+    property.add_span(0xA699, 0xA699);
+    property.add_span(0xA69B, 0xA69D);
+    property.add_span(0xA723, 0xA723);
+    property.add_span(0xA725, 0xA725);
+    property.add_span(0xA727, 0xA727);
+    property.add_span(0xA729, 0xA729);
+    property.add_span(0xA72B, 0xA72B);
+    property.add_span(0xA72D, 0xA72D);
+    property.add_span(0xA72F, 0xA731);
+    property.add_span(0xA733, 0xA733);
+    property.add_span(0xA735, 0xA735);
+    property.add_span(0xA737, 0xA737);
+    property.add_span(0xA739, 0xA739);
+    property.add_span(0xA73B, 0xA73B);
+    property.add_span(0xA73D, 0xA73D);
+    property.add_span(0xA73F, 0xA73F);
+    property.add_span(0xA741, 0xA741);
+    property.add_span(0xA743, 0xA743);
+    property.add_span(0xA745, 0xA745);
+    property.add_span(0xA747, 0xA747);
+    property.add_span(0xA749, 0xA749);
+    property.add_span(0xA74B, 0xA74B);
+    property.add_span(0xA74D, 0xA74D);
+    property.add_span(0xA74F, 0xA74F);
+    property.add_span(0xA751, 0xA751);
+    property.add_span(0xA753, 0xA753);
+    property.add_span(0xA755, 0xA755);
+    property.add_span(0xA757, 0xA757);
+    property.add_span(0xA759, 0xA759);
+    property.add_span(0xA75B, 0xA75B);
+    property.add_span(0xA75D, 0xA75D);
+    property.add_span(0xA75F, 0xA75F);
+    property.add_span(0xA761, 0xA761);
+    property.add_span(0xA763, 0xA763);
+    property.add_span(0xA765, 0xA765);
+    property.add_span(0xA767, 0xA767);
+    property.add_span(0xA769, 0xA769);
+    property.add_span(0xA76B, 0xA76B);
+    property.add_span(0xA76D, 0xA76D);
+    property.add_span(0xA76F, 0xA778);
+    property.add_span(0xA77A, 0xA77A);
+    property.add_span(0xA77C, 0xA77C);
+    property.add_span(0xA77F, 0xA77F);
+    property.add_span(0xA781, 0xA781);
+    property.add_span(0xA783, 0xA783);
+    property.add_span(0xA785, 0xA785);
+    property.add_span(0xA787, 0xA787);
+    property.add_span(0xA78C, 0xA78C);
+    property.add_span(0xA78E, 0xA78E);
+    property.add_span(0xA791, 0xA791);
+    property.add_span(0xA793, 0xA795);
+    property.add_span(0xA797, 0xA797);
+    property.add_span(0xA799, 0xA799);
+    property.add_span(0xA79B, 0xA79B);
+    property.add_span(0xA79D, 0xA79D);
+    property.add_span(0xA79F, 0xA79F);
+    property.add_span(0xA7A1, 0xA7A1);
+    property.add_span(0xA7A3, 0xA7A3);
+    property.add_span(0xA7A5, 0xA7A5);
+    property.add_span(0xA7A7, 0xA7A7);
+    property.add_span(0xA7A9, 0xA7A9);
+    property.add_span(0xA7AF, 0xA7AF);
+    property.add_span(0xA7B5, 0xA7B5);
+    property.add_span(0xA7B7, 0xA7B7);
+    property.add_span(0xA7B9, 0xA7B9);
+    property.add_span(0xA7BB, 0xA7BB);
+    property.add_span(0xA7BD, 0xA7BD);
+    property.add_span(0xA7BF, 0xA7BF);
+    property.add_span(0xA7C1, 0xA7C1);
+    property.add_span(0xA7C3, 0xA7C3);
+    property.add_span(0xA7C8, 0xA7C8);
+    property.add_span(0xA7CA, 0xA7CA);
+    property.add_span(0xA7CD, 0xA7CD);
+    property.add_span(0xA7D1, 0xA7D1);
+    property.add_span(0xA7D3, 0xA7D3);
+    property.add_span(0xA7D5, 0xA7D5);
+    property.add_span(0xA7D7, 0xA7D7);
+    property.add_span(0xA7D9, 0xA7D9);
+    property.add_span(0xA7DB, 0xA7DB);
+    property.add_span(0xA7F2, 0xA7F4);
+    property.add_span(0xA7F6, 0xA7F6);
+    property.add_span(0xA7F8, 0xA7FA);
+    property.add_span(0xAB30, 0xAB5A);
+    property.add_span(0xAB5C, 0xAB69);
+    property.add_span(0xAB70, 0xABBF);
+    property.add_span(0xFB00, 0xFB06);
+    property.add_span(0xFB13, 0xFB17);
+    property.add_span(0xFF41, 0xFF5A);
+    property.add_span(0x10428, 0x1044F);
+    property.add_span(0x104D8, 0x104FB);
+    property.add_span(0x10597, 0x105A1);
+    property.add_span(0x105A3, 0x105B1);
+    property.add_span(0x105B3, 0x105B9);
+    property.add_span(0x105BB, 0x105BC);
+    property.add_span(0x10780, 0x10780);
+    property.add_span(0x10783, 0x10785);
+    property.add_span(0x10787, 0x107B0);
+    property.add_span(0x107B2, 0x107BA);
+    property.add_span(0x10CC0, 0x10CF2);
+    property.add_span(0x10D70, 0x10D85);
+    property.add_span(0x118C0, 0x118DF);
+    property.add_span(0x16E60, 0x16E7F);
+    property.add_span(0x1D41A, 0x1D433);
+    property.add_span(0x1D44E, 0x1D454);
+    property.add_span(0x1D456, 0x1D467);
+    property.add_span(0x1D482, 0x1D49B);
+    property.add_span(0x1D4B6, 0x1D4B9);
+    property.add_span(0x1D4BB, 0x1D4BB);
+    property.add_span(0x1D4BD, 0x1D4C3);
+    property.add_span(0x1D4C5, 0x1D4CF);
+    property.add_span(0x1D4EA, 0x1D503);
+    property.add_span(0x1D51E, 0x1D537);
+    property.add_span(0x1D552, 0x1D56B);
+    property.add_span(0x1D586, 0x1D59F);
+    property.add_span(0x1D5BA, 0x1D5D3);
+    property.add_span(0x1D5EE, 0x1D607);
+    property.add_span(0x1D622, 0x1D63B);
+    property.add_span(0x1D656, 0x1D66F);
+    property.add_span(0x1D68A, 0x1D6A5);
+    property.add_span(0x1D6C2, 0x1D6DA);
+    property.add_span(0x1D6DC, 0x1D6E1);
+    property.add_span(0x1D6FC, 0x1D714);
+    property.add_span(0x1D716, 0x1D71B);
+    property.add_span(0x1D736, 0x1D74E);
+    property.add_span(0x1D750, 0x1D755);
+    property.add_span(0x1D770, 0x1D788);
+    property.add_span(0x1D78A, 0x1D78F);
+    property.add_span(0x1D7AA, 0x1D7C2);
+    property.add_span(0x1D7C4, 0x1D7C9);
+    property.add_span(0x1D7CB, 0x1D7CB);
+    property.add_span(0x1DF00, 0x1DF09);
+    property.add_span(0x1DF0B, 0x1DF1E);
+    property.add_span(0x1DF25, 0x1DF2A);
+    property.add_span(0x1E030, 0x1E06D);
+    property.add_span(0x1E922, 0x1E943);
+    db.insert("Lowercase", property);
+    let mut property = Category::new("Pattern_Syntax");
+    property.add_span(0x21, 0x2F);
+    property.add_span(0x3A, 0x40);
+    property.add_span(0x5B, 0x5E);
+    property.add_span(0x60, 0x60);
+    property.add_span(0x7B, 0x7E);
+    property.add_span(0xA1, 0xA7);
+    property.add_span(0xA9, 0xA9);
+    property.add_span(0xAB, 0xAC);
+    property.add_span(0xAE, 0xAE);
+    property.add_span(0xB0, 0xB1);
+    property.add_span(0xB6, 0xB6);
+    property.add_span(0xBB, 0xBB);
+    property.add_span(0xBF, 0xBF);
+    property.add_span(0xD7, 0xD7);
+    property.add_span(0xF7, 0xF7);
+    property.add_span(0x2010, 0x2027);
+    property.add_span(0x2030, 0x203E);
+    property.add_span(0x2041, 0x2053);
+    property.add_span(0x2055, 0x205E);
+    property.add_span(0x2190, 0x245F);
+    property.add_span(0x2500, 0x2775);
+    property.add_span(0x2794, 0x2BFF);
+    property.add_span(0x2E00, 0x2E7F);
+    property.add_span(0x3001, 0x3003);
+    property.add_span(0x3008, 0x3020);
+    property.add_span(0x3030, 0x3030);
+    property.add_span(0xFD3E, 0xFD3F);
+    property.add_span(0xFE45, 0xFE46);
+    db.insert("Pattern_Syntax", property);
+    let mut property = Category::new("Pattern_White_Space");
+    property.add_span(0x9, 0xD);
+    property.add_span(0x20, 0x20);
+    property.add_span(0x85, 0x85);
+    property.add_span(0x200E, 0x200F);
+    property.add_span(0x2028, 0x2029);
+    db.insert("Pattern_White_Space", property);
+    let mut property = Category::new("Script=Adlam");
+    property.add_span(0x1E900, 0x1E94B);
+    property.add_span(0x1E950, 0x1E959);
+    property.add_span(0x1E95E, 0x1E95F);
+    db.insert("Script=Adlam", property);
+    let mut property = Category::new("Script=Ahom");
+    property.add_span(0x11700, 0x1171A);
+    property.add_span(0x1171D, 0x1172B);
+    property.add_span(0x11730, 0x11746);
+    db.insert("Script=Ahom", property);
+    let mut property = Category::new("Script=Anatolian_Hieroglyphs");
+    property.add_span(0x14400, 0x14646);
+    db.insert("Script=Anatolian_Hieroglyphs", property);
+    let mut property = Category::new("Script=Arabic");
+    property.add_span(0x600, 0x604);
+    property.add_span(0x606, 0x60B);
+    property.add_span(0x60D, 0x61A);
+    property.add_span(0x61C, 0x61E);
+    property.add_span(0x620, 0x63F);
+    property.add_span(0x641, 0x64A);
+    property.add_span(0x656, 0x66F);
+    property.add_span(0x671, 0x6DC);
+    property.add_span(0x6DE, 0x6FF);
+    property.add_span(0x750, 0x77F);
+    property.add_span(0x870, 0x88E);
+    property.add_span(0x890, 0x891);
+    property.add_span(0x897, 0x8E1);
+    property.add_span(0x8E3, 0x8FF);
+    property.add_span(0xFB50, 0xFBC2);
+    property.add_span(0xFBD3, 0xFD3D);
+    property.add_span(0xFD40, 0xFD8F);
+    property.add_span(0xFD92, 0xFDC7);
+    property.add_span(0xFDCF, 0xFDCF);
+    property.add_span(0xFDF0, 0xFDFF);
+    property.add_span(0xFE70, 0xFE74);
+    property.add_span(0xFE76, 0xFEFC);
+    property.add_span(0x10E60, 0x10E7E);
+    property.add_span(0x10EC2, 0x10EC4);
+    property.add_span(0x10EFC, 0x10EFF);
+    property.add_span(0x1EE00, 0x1EE03);
+    property.add_span(0x1EE05, 0x1EE1F);
+    property.add_span(0x1EE21, 0x1EE22);
+    property.add_span(0x1EE24, 0x1EE24);
+    property.add_span(0x1EE27, 0x1EE27);
+    property.add_span(0x1EE29, 0x1EE32);
+    property.add_span(0x1EE34, 0x1EE37);
+    property.add_span(0x1EE39, 0x1EE39);
+    property.add_span(0x1EE3B, 0x1EE3B);
+    property.add_span(0x1EE42, 0x1EE42);
+    property.add_span(0x1EE47, 0x1EE47);
+    property.add_span(0x1EE49, 0x1EE49);
+    property.add_span(0x1EE4B, 0x1EE4B);
+    property.add_span(0x1EE4D, 0x1EE4F);
+    property.add_span(0x1EE51, 0x1EE52);
+    property.add_span(0x1EE54, 0x1EE54);
+    property.add_span(0x1EE57, 0x1EE57);
+    property.add_span(0x1EE59, 0x1EE59);
+    property.add_span(0x1EE5B, 0x1EE5B);
+    property.add_span(0x1EE5D, 0x1EE5D);
+    property.add_span(0x1EE5F, 0x1EE5F);
+    property.add_span(0x1EE61, 0x1EE62);
+    property.add_span(0x1EE64, 0x1EE64);
+    property.add_span(0x1EE67, 0x1EE6A);
+    property.add_span(0x1EE6C, 0x1EE72);
+    property.add_span(0x1EE74, 0x1EE77);
+    property.add_span(0x1EE79, 0x1EE7C);
+    property.add_span(0x1EE7E, 0x1EE7E);
+    property.add_span(0x1EE80, 0x1EE89);
+    property.add_span(0x1EE8B, 0x1EE9B);
+    property.add_span(0x1EEA1, 0x1EEA3);
+    property.add_span(0x1EEA5, 0x1EEA9);
+    property.add_span(0x1EEAB, 0x1EEBB);
+    property.add_span(0x1EEF0, 0x1EEF1);
+    db.insert("Script=Arabic", property);
+    let mut property = Category::new("Script=Armenian");
+    property.add_span(0x531, 0x556);
+    property.add_span(0x559, 0x58A);
+    property.add_span(0x58D, 0x58F);
+    property.add_span(0xFB13, 0xFB17);
+    db.insert("Script=Armenian", property);
+    let mut property = Category::new("Script=Avestan");
+    property.add_span(0x10B00, 0x10B35);
+    property.add_span(0x10B39, 0x10B3F);
+    db.insert("Script=Avestan", property);
+    let mut property = Category::new("Script=Balinese");
+    property.add_span(0x1B00, 0x1B4C);
+    property.add_span(0x1B4E, 0x1B7F);
+    db.insert("Script=Balinese", property);
+    let mut property = Category::new("Script=Bamum");
+    property.add_span(0xA6A0, 0xA6F7);
+    property.add_span(0x16800, 0x16A38);
+    db.insert("Script=Bamum", property);
+    let mut property = Category::new("Script=Bassa_Vah");
+    property.add_span(0x16AD0, 0x16AED);
+    property.add_span(0x16AF0, 0x16AF5);
+    db.insert("Script=Bassa_Vah", property);
+    let mut property = Category::new("Script=Batak");
+    property.add_span(0x1BC0, 0x1BF3);
+    property.add_span(0x1BFC, 0x1BFF);
+    db.insert("Script=Batak", property);
+    let mut property = Category::new("Script=Bengali");
+    property.add_span(0x980, 0x983);
+    property.add_span(0x985, 0x98C);
+    property.add_span(0x98F, 0x990);
+    property.add_span(0x993, 0x9A8);
+    property.add_span(0x9AA, 0x9B0);
+    property.add_span(0x9B2, 0x9B2);
+    property.add_span(0x9B6, 0x9B9);
+    property.add_span(0x9BC, 0x9C4);
+    property.add_span(0x9C7, 0x9C8);
+    property.add_span(0x9CB, 0x9CE);
+    property.add_span(0x9D7, 0x9D7);
+    property.add_span(0x9DC, 0x9DD);
+    property.add_span(0x9DF, 0x9E3);
+    property.add_span(0x9E6, 0x9FE);
+    db.insert("Script=Bengali", property);
+    let mut property = Category::new("Script=Bhaiksuki");
+    property.add_span(0x11C00, 0x11C08);
+    property.add_span(0x11C0A, 0x11C36);
+    property.add_span(0x11C38, 0x11C45);
+    property.add_span(0x11C50, 0x11C6C);
+    db.insert("Script=Bhaiksuki", property);
+    let mut property = Category::new("Script=Bopomofo");
+    property.add_span(0x2EA, 0x2EB);
+    property.add_span(0x3105, 0x312F);
+    property.add_span(0x31A0, 0x31BF);
+    db.insert("Script=Bopomofo", property);
+    let mut property = Category::new("Script=Brahmi");
+    property.add_span(0x11000, 0x1104D);
+    property.add_span(0x11052, 0x11075);
+    property.add_span(0x1107F, 0x1107F);
+    db.insert("Script=Brahmi", property);
+    let mut property = Category::new("Script=Braille");
+    property.add_span(0x2800, 0x28FF);
+    db.insert("Script=Braille", property);
+    let mut property = Category::new("Script=Buginese");
+    property.add_span(0x1A00, 0x1A1B);
+    property.add_span(0x1A1E, 0x1A1F);
+    db.insert("Script=Buginese", property);
+    let mut property = Category::new("Script=Buhid");
+    property.add_span(0x1740, 0x1753);
+    db.insert("Script=Buhid", property);
+    let mut property = Category::new("Script=Canadian_Aboriginal");
+    property.add_span(0x1400, 0x167F);
+    property.add_span(0x18B0, 0x18F5);
+    property.add_span(0x11AB0, 0x11ABF);
+    db.insert("Script=Canadian_Aboriginal", property);
+    let mut property = Category::new("Script=Carian");
+    property.add_span(0x102A0, 0x102D0);
+    db.insert("Script=Carian", property);
+    let mut property = Category::new("Script=Caucasian_Albanian");
+    property.add_span(0x10530, 0x10563);
+    property.add_span(0x1056F, 0x1056F);
+    db.insert("Script=Caucasian_Albanian", property);
+    let mut property = Category::new("Script=Chakma");
+    property.add_span(0x11100, 0x11134);
+    property.add_span(0x11136, 0x11147);
+    db.insert("Script=Chakma", property);
+    let mut property = Category::new("Script=Cham");
+    property.add_span(0xAA00, 0xAA36);
+    property.add_span(0xAA40, 0xAA4D);
+    property.add_span(0xAA50, 0xAA59);
+    property.add_span(0xAA5C, 0xAA5F);
+    db.insert("Script=Cham", property);
+    let mut property = Category::new("Script=Cherokee");
+    property.add_span(0x13A0, 0x13F5);
+    property.add_span(0x13F8, 0x13FD);
+    property.add_span(0xAB70, 0xABBF);
+    db.insert("Script=Cherokee", property);
+    let mut property = Category::new("Script=Chorasmian");
+    property.add_span(0x10FB0, 0x10FCB);
+    db.insert("Script=Chorasmian", property);
+    let mut property = Category::new("Script=Common");
+    property.add_span(0x0, 0x40);
+    property.add_span(0x5B, 0x60);
+    property.add_span(0x7B, 0xA9);
+    property.add_span(0xAB, 0xB9);
+    property.add_span(0xBB, 0xBF);
+    property.add_span(0xD7, 0xD7);
+    property.add_span(0xF7, 0xF7);
+    property.add_span(0x2B9, 0x2DF);
+    property.add_span(0x2E5, 0x2E9);
+    property.add_span(0x2EC, 0x2FF);
+    property.add_span(0x374, 0x374);
+    property.add_span(0x37E, 0x37E);
+    property.add_span(0x385, 0x385);
+    property.add_span(0x387, 0x387);
+    property.add_span(0x605, 0x605);
+    property.add_span(0x60C, 0x60C);
+    property.add_span(0x61B, 0x61B);
+    property.add_span(0x61F, 0x61F);
+    property.add_span(0x640, 0x640);
+    property.add_span(0x6DD, 0x6DD);
+    property.add_span(0x8E2, 0x8E2);
+    property.add_span(0x964, 0x965);
+    property.add_span(0xE3F, 0xE3F);
+    property.add_span(0xFD5, 0xFD8);
+    property.add_span(0x10FB, 0x10FB);
+    property.add_span(0x16EB, 0x16ED);
+    property.add_span(0x1735, 0x1736);
+    property.add_span(0x1802, 0x1803);
+    property.add_span(0x1805, 0x1805);
+    property.add_span(0x1CD3, 0x1CD3);
+    property.add_span(0x1CE1, 0x1CE1);
+    property.add_span(0x1CE9, 0x1CEC);
+    property.add_span(0x1CEE, 0x1CF3);
+    property.add_span(0x1CF5, 0x1CF7);
+    property.add_span(0x1CFA, 0x1CFA);
+    property.add_span(0x2000, 0x200B);
+    property.add_span(0x200E, 0x2064);
+    property.add_span(0x2066, 0x2070);
+    property.add_span(0x2074, 0x207E);
+    property.add_span(0x2080, 0x208E);
+    property.add_span(0x20A0, 0x20C0);
+    property.add_span(0x2100, 0x2125);
+    property.add_span(0x2127, 0x2129);
+    property.add_span(0x212C, 0x2131);
+    property.add_span(0x2133, 0x214D);
+    property.add_span(0x214F, 0x215F);
+    property.add_span(0x2189, 0x218B);
+    property.add_span(0x2190, 0x2429);
+    property.add_span(0x2440, 0x244A);
+    property.add_span(0x2460, 0x27FF);
+    property.add_span(0x2900, 0x2B73);
+    property.add_span(0x2B76, 0x2B95);
+    property.add_span(0x2B97, 0x2BFF);
+    property.add_span(0x2E00, 0x2E5D);
+    property.add_span(0x2FF0, 0x3004);
+    property.add_span(0x3006, 0x3006);
+    property.add_span(0x3008, 0x3020);
+    property.add_span(0x3030, 0x3037);
+    property.add_span(0x303C, 0x303F);
+    property.add_span(0x309B, 0x309C);
+    property.add_span(0x30A0, 0x30A0);
+    property.add_span(0x30FB, 0x30FC);
+    property.add_span(0x3190, 0x319F);
+    property.add_span(0x31C0, 0x31E5);
+    property.add_span(0x31EF, 0x31EF);
+    property.add_span(0x3220, 0x325F);
+    property.add_span(0x327F, 0x32CF);
+    property.add_span(0x32FF, 0x32FF);
+    property.add_span(0x3358, 0x33FF);
+    property.add_span(0x4DC0, 0x4DFF);
+    property.add_span(0xA700, 0xA721);
+    property.add_span(0xA788, 0xA78A);
+    property.add_span(0xA830, 0xA839);
+    property.add_span(0xA92E, 0xA92E);
+    property.add_span(0xA9CF, 0xA9CF);
+    property.add_span(0xAB5B, 0xAB5B);
+    property.add_span(0xAB6A, 0xAB6B);
+    property.add_span(0xFD3E, 0xFD3F);
+    property.add_span(0xFE10, 0xFE19);
+    property.add_span(0xFE30, 0xFE52);
+    property.add_span(0xFE54, 0xFE66);
+    property.add_span(0xFE68, 0xFE6B);
+    property.add_span(0xFEFF, 0xFEFF);
+    property.add_span(0xFF01, 0xFF20);
+    property.add_span(0xFF3B, 0xFF40);
+    property.add_span(0xFF5B, 0xFF65);
+    property.add_span(0xFF70, 0xFF70);
+    property.add_span(0xFF9E, 0xFF9F);
+    property.add_span(0xFFE0, 0xFFE6);
+    property.add_span(0xFFE8, 0xFFEE);
+    property.add_span(0xFFF9, 0xFFFD);
+    property.add_span(0x10100, 0x10102);
+    property.add_span(0x10107, 0x10133);
+    property.add_span(0x10137, 0x1013F);
+    property.add_span(0x10190, 0x1019C);
+    property.add_span(0x101D0, 0x101FC);
+    property.add_span(0x102E1, 0x102FB);
+    property.add_span(0x1BCA0, 0x1BCA3);
+    property.add_span(0x1CC00, 0x1CCF9);
+    property.add_span(0x1CD00, 0x1CEB3);
+    property.add_span(0x1CF50, 0x1CFC3);
+    property.add_span(0x1D000, 0x1D0F5);
+    property.add_span(0x1D100, 0x1D126);
+    property.add_span(0x1D129, 0x1D166);
+    property.add_span(0x1D16A, 0x1D17A);
+    property.add_span(0x1D183, 0x1D184);
+    property.add_span(0x1D18C, 0x1D1A9);
+    property.add_span(0x1D1AE, 0x1D1EA);
+    property.add_span(0x1D2C0, 0x1D2D3);
+    property.add_span(0x1D2E0, 0x1D2F3);
+    property.add_span(0x1D300, 0x1D356);
+    property.add_span(0x1D360, 0x1D378);
+    property.add_span(0x1D400, 0x1D454);
+    property.add_span(0x1D456, 0x1D49C);
+    property.add_span(0x1D49E, 0x1D49F);
+    property.add_span(0x1D4A2, 0x1D4A2);
+    property.add_span(0x1D4A5, 0x1D4A6);
+    property.add_span(0x1D4A9, 0x1D4AC);
+    property.add_span(0x1D4AE, 0x1D4B9);
+    property.add_span(0x1D4BB, 0x1D4BB);
+    property.add_span(0x1D4BD, 0x1D4C3);
+    property.add_span(0x1D4C5, 0x1D505);
+    property.add_span(0x1D507, 0x1D50A);
+    property.add_span(0x1D50D, 0x1D514);
+    property.add_span(0x1D516, 0x1D51C);
+    property.add_span(0x1D51E, 0x1D539);
+    property.add_span(0x1D53B, 0x1D53E);
+    property.add_span(0x1D540, 0x1D544);
+    property.add_span(0x1D546, 0x1D546);
+    property.add_span(0x1D54A, 0x1D550);
+    property.add_span(0x1D552, 0x1D6A5);
+    property.add_span(0x1D6A8, 0x1D7CB);
+    property.add_span(0x1D7CE, 0x1D7FF);
+    property.add_span(0x1EC71, 0x1ECB4);
+    property.add_span(0x1ED01, 0x1ED3D);
+    property.add_span(0x1F000, 0x1F02B);
+    property.add_span(0x1F030, 0x1F093);
+    property.add_span(0x1F0A0, 0x1F0AE);
+    property.add_span(0x1F0B1, 0x1F0BF);
+    property.add_span(0x1F0C1, 0x1F0CF);
+    property.add_span(0x1F0D1, 0x1F0F5);
+    property.add_span(0x1F100, 0x1F1AD);
+    property.add_span(0x1F1E6, 0x1F1FF);
+    property.add_span(0x1F201, 0x1F202);
+    property.add_span(0x1F210, 0x1F23B);
+    property.add_span(0x1F240, 0x1F248);
+    property.add_span(0x1F250, 0x1F251);
+    property.add_span(0x1F260, 0x1F265);
+    property.add_span(0x1F300, 0x1F6D7);
+    property.add_span(0x1F6DC, 0x1F6EC);
+    property.add_span(0x1F6F0, 0x1F6FC);
+    property.add_span(0x1F700, 0x1F776);
+    property.add_span(0x1F77B, 0x1F7D9);
+    property.add_span(0x1F7E0, 0x1F7EB);
+    property.add_span(0x1F7F0, 0x1F7F0);
+    property.add_span(0x1F800, 0x1F80B);
+    property.add_span(0x1F810, 0x1F847);
+    property.add_span(0x1F850, 0x1F859);
+    property.add_span(0x1F860, 0x1F887);
+    property.add_span(0x1F890, 0x1F8AD);
+    property.add_span(0x1F8B0, 0x1F8BB);
+    property.add_span(0x1F8C0, 0x1F8C1);
+    property.add_span(0x1F900, 0x1FA53);
+    property.add_span(0x1FA60, 0x1FA6D);
+    property.add_span(0x1FA70, 0x1FA7C);
+    property.add_span(0x1FA80, 0x1FA89);
+    property.add_span(0x1FA8F, 0x1FAC6);
+    property.add_span(0x1FACE, 0x1FADC);
+    property.add_span(0x1FADF, 0x1FAE9);
+    property.add_span(0x1FAF0, 0x1FAF8);
+    property.add_span(0x1FB00, 0x1FB92);
+    property.add_span(0x1FB94, 0x1FBF9);
+    property.add_span(0xE0001, 0xE0001);
+    property.add_span(0xE0020, 0xE007F);
+    db.insert("Script=Common", property);
+    let mut property = Category::new("Script=Coptic");
+    property.add_span(0x3E2, 0x3EF);
+    property.add_span(0x2C80, 0x2CF3);
+    property.add_span(0x2CF9, 0x2CFF);
+    db.insert("Script=Coptic", property);
+    let mut property = Category::new("Script=Cuneiform");
+    property.add_span(0x12000, 0x12399);
+    property.add_span(0x12400, 0x1246E);
+    property.add_span(0x12470, 0x12474);
+    property.add_span(0x12480, 0x12543);
+    db.insert("Script=Cuneiform", property);
+    let mut property = Category::new("Script=Cypriot");
+    property.add_span(0x10800, 0x10805);
+    property.add_span(0x10808, 0x10808);
+    property.add_span(0x1080A, 0x10835);
+    property.add_span(0x10837, 0x10838);
+    property.add_span(0x1083C, 0x1083C);
+    property.add_span(0x1083F, 0x1083F);
+    db.insert("Script=Cypriot", property);
+    let mut property = Category::new("Script=Cypro_Minoan");
+    property.add_span(0x12F90, 0x12FF2);
+    db.insert("Script=Cypro_Minoan", property);
+    let mut property = Category::new("Script=Cyrillic");
+    property.add_span(0x400, 0x484);
+    property.add_span(0x487, 0x52F);
+    property.add_span(0x1C80, 0x1C8A);
+    property.add_span(0x1D2B, 0x1D2B);
+    property.add_span(0x1D78, 0x1D78);
+    property.add_span(0x2DE0, 0x2DFF);
+    property.add_span(0xA640, 0xA69F);
+    property.add_span(0xFE2E, 0xFE2F);
+    property.add_span(0x1E030, 0x1E06D);
+    property.add_span(0x1E08F, 0x1E08F);
+    db.insert("Script=Cyrillic", property);
+    let mut property = Category::new("Script=Deseret");
+    property.add_span(0x10400, 0x1044F);
+    db.insert("Script=Deseret", property);
+    let mut property = Category::new("Script=Devanagari");
+    property.add_span(0x900, 0x950);
+    property.add_span(0x955, 0x963);
+    property.add_span(0x966, 0x97F);
+    property.add_span(0xA8E0, 0xA8FF);
+    property.add_span(0x11B00, 0x11B09);
+    db.insert("Script=Devanagari", property);
+    let mut property = Category::new("Script=Dives_Akuru");
+    property.add_span(0x11900, 0x11906);
+    property.add_span(0x11909, 0x11909);
+    property.add_span(0x1190C, 0x11913);
+    property.add_span(0x11915, 0x11916);
+    property.add_span(0x11918, 0x11935);
+    property.add_span(0x11937, 0x11938);
+    property.add_span(0x1193B, 0x11946);
+    property.add_span(0x11950, 0x11959);
+    db.insert("Script=Dives_Akuru", property);
+    let mut property = Category::new("Script=Dogra");
+    property.add_span(0x11800, 0x1183B);
+    db.insert("Script=Dogra", property);
+    let mut property = Category::new("Script=Duployan");
+    property.add_span(0x1BC00, 0x1BC6A);
+    property.add_span(0x1BC70, 0x1BC7C);
+    property.add_span(0x1BC80, 0x1BC88);
+    property.add_span(0x1BC90, 0x1BC99);
+    property.add_span(0x1BC9C, 0x1BC9F);
+    db.insert("Script=Duployan", property);
+    let mut property = Category::new("Script=Egyptian_Hieroglyphs");
+    property.add_span(0x13000, 0x13455);
+    property.add_span(0x13460, 0x143FA);
+    db.insert("Script=Egyptian_Hieroglyphs", property);
+    let mut property = Category::new("Script=Elbasan");
+    property.add_span(0x10500, 0x10527);
+    db.insert("Script=Elbasan", property);
+    let mut property = Category::new("Script=Elymaic");
+    property.add_span(0x10FE0, 0x10FF6);
+    db.insert("Script=Elymaic", property);
+    let mut property = Category::new("Script=Ethiopic");
+    property.add_span(0x1200, 0x1248);
+    property.add_span(0x124A, 0x124D);
+    property.add_span(0x1250, 0x1256);
+    property.add_span(0x1258, 0x1258);
+    property.add_span(0x125A, 0x125D);
+    property.add_span(0x1260, 0x1288);
+    property.add_span(0x128A, 0x128D);
+    property.add_span(0x1290, 0x12B0);
+    property.add_span(0x12B2, 0x12B5);
+    property.add_span(0x12B8, 0x12BE);
+    property.add_span(0x12C0, 0x12C0);
+    property.add_span(0x12C2, 0x12C5);
+    property.add_span(0x12C8, 0x12D6);
+    property.add_span(0x12D8, 0x1310);
+    property.add_span(0x1312, 0x1315);
+    property.add_span(0x1318, 0x135A);
+    property.add_span(0x135D, 0x137C);
+    property.add_span(0x1380, 0x1399);
+    property.add_span(0x2D80, 0x2D96);
+    property.add_span(0x2DA0, 0x2DA6);
+    property.add_span(0x2DA8, 0x2DAE);
+    property.add_span(0x2DB0, 0x2DB6);
+    property.add_span(0x2DB8, 0x2DBE);
+    property.add_span(0x2DC0, 0x2DC6);
+    property.add_span(0x2DC8, 0x2DCE);
+    property.add_span(0x2DD0, 0x2DD6);
+    property.add_span(0x2DD8, 0x2DDE);
+    property.add_span(0xAB01, 0xAB06);
+    property.add_span(0xAB09, 0xAB0E);
+    property.add_span(0xAB11, 0xAB16);
+    property.add_span(0xAB20, 0xAB26);
+    property.add_span(0xAB28, 0xAB2E);
+    property.add_span(0x1E7E0, 0x1E7E6);
+    property.add_span(0x1E7E8, 0x1E7EB);
+    property.add_span(0x1E7ED, 0x1E7EE);
+    property.add_span(0x1E7F0, 0x1E7FE);
+    db.insert("Script=Ethiopic", property);
+    let mut property = Category::new("Script=Garay");
+    property.add_span(0x10D40, 0x10D65);
+    property.add_span(0x10D69, 0x10D85);
+    property.add_span(0x10D8E, 0x10D8F);
+    db.insert("Script=Garay", property);
+    let mut property = Category::new("Script=Georgian");
+    property.add_span(0x10A0, 0x10C5);
+    property.add_span(0x10C7, 0x10C7);
+    property.add_span(0x10CD, 0x10CD);
+    property.add_span(0x10D0, 0x10FA);
+    property.add_span(0x10FC, 0x10FF);
+    property.add_span(0x1C90, 0x1CBA);
+    property.add_span(0x1CBD, 0x1CBF);
+    property.add_span(0x2D00, 0x2D25);
+    property.add_span(0x2D27, 0x2D27);
+    property.add_span(0x2D2D, 0x2D2D);
+    db.insert("Script=Georgian", property);
+    let mut property = Category::new("Script=Glagolitic");
+    property.add_span(0x2C00, 0x2C5F);
+    property.add_span(0x1E000, 0x1E006);
+    property.add_span(0x1E008, 0x1E018);
+    property.add_span(0x1E01B, 0x1E021);
+    property.add_span(0x1E023, 0x1E024);
+    property.add_span(0x1E026, 0x1E02A);
+    db.insert("Script=Glagolitic", property);
+    let mut property = Category::new("Script=Gothic");
+    property.add_span(0x10330, 0x1034A);
+    db.insert("Script=Gothic", property);
+    let mut property = Category::new("Script=Grantha");
+    property.add_span(0x11300, 0x11303);
+    property.add_span(0x11305, 0x1130C);
+    property.add_span(0x1130F, 0x11310);
+    property.add_span(0x11313, 0x11328);
+    property.add_span(0x1132A, 0x11330);
+    property.add_span(0x11332, 0x11333);
+    property.add_span(0x11335, 0x11339);
+    property.add_span(0x1133C, 0x11344);
+    property.add_span(0x11347, 0x11348);
+    property.add_span(0x1134B, 0x1134D);
+    property.add_span(0x11350, 0x11350);
+    property.add_span(0x11357, 0x11357);
+    property.add_span(0x1135D, 0x11363);
+    property.add_span(0x11366, 0x1136C);
+    property.add_span(0x11370, 0x11374);
+    db.insert("Script=Grantha", property);
+    let mut property = Category::new("Script=Greek");
+    property.add_span(0x370, 0x373);
+    property.add_span(0x375, 0x377);
+    property.add_span(0x37A, 0x37D);
+    property.add_span(0x37F, 0x37F);
+    property.add_span(0x384, 0x384);
+    property.add_span(0x386, 0x386);
+    property.add_span(0x388, 0x38A);
+    property.add_span(0x38C, 0x38C);
+    property.add_span(0x38E, 0x3A1);
+    property.add_span(0x3A3, 0x3E1);
+    property.add_span(0x3F0, 0x3FF);
+    property.add_span(0x1D26, 0x1D2A);
+    property.add_span(0x1D5D, 0x1D61);
+    property.add_span(0x1D66, 0x1D6A);
+    property.add_span(0x1DBF, 0x1DBF);
+    property.add_span(0x1F00, 0x1F15);
+    property.add_span(0x1F18, 0x1F1D);
+    property.add_span(0x1F20, 0x1F45);
+    property.add_span(0x1F48, 0x1F4D);
+    property.add_span(0x1F50, 0x1F57);
+    property.add_span(0x1F59, 0x1F59);
+    property.add_span(0x1F5B, 0x1F5B);
+    property.add_span(0x1F5D, 0x1F5D);
+    property.add_span(0x1F5F, 0x1F7D);
+    property.add_span(0x1F80, 0x1FB4);
+    property.add_span(0x1FB6, 0x1FC4);
+    property.add_span(0x1FC6, 0x1FD3);
+    property.add_span(0x1FD6, 0x1FDB);
+    property.add_span(0x1FDD, 0x1FEF);
+    property.add_span(0x1FF2, 0x1FF4);
+    property.add_span(0x1FF6, 0x1FFE);
+    property.add_span(0x2126, 0x2126);
+    property.add_span(0xAB65, 0xAB65);
+    property.add_span(0x10140, 0x1018E);
+    property.add_span(0x101A0, 0x101A0);
+    property.add_span(0x1D200, 0x1D245);
+    db.insert("Script=Greek", property);
+    let mut property = Category::new("Script=Gujarati");
+    property.add_span(0xA81, 0xA83);
+    property.add_span(0xA85, 0xA8D);
+    property.add_span(0xA8F, 0xA91);
+    property.add_span(0xA93, 0xAA8);
+    property.add_span(0xAAA, 0xAB0);
+    property.add_span(0xAB2, 0xAB3);
+    property.add_span(0xAB5, 0xAB9);
+    property.add_span(0xABC, 0xAC5);
+    property.add_span(0xAC7, 0xAC9);
+    property.add_span(0xACB, 0xACD);
+    property.add_span(0xAD0, 0xAD0);
+    property.add_span(0xAE0, 0xAE3);
+    property.add_span(0xAE6, 0xAF1);
+    property.add_span(0xAF9, 0xAFF);
+    db.insert("Script=Gujarati", property);
+    let mut property = Category::new("Script=Gunjala_Gondi");
+    property.add_span(0x11D60, 0x11D65);
+    property.add_span(0x11D67, 0x11D68);
+    property.add_span(0x11D6A, 0x11D8E);
+    property.add_span(0x11D90, 0x11D91);
+    property.add_span(0x11D93, 0x11D98);
+    property.add_span(0x11DA0, 0x11DA9);
+    db.insert("Script=Gunjala_Gondi", property);
+    let mut property = Category::new("Script=Gurmukhi");
+    property.add_span(0xA01, 0xA03);
+    property.add_span(0xA05, 0xA0A);
+    property.add_span(0xA0F, 0xA10);
+    property.add_span(0xA13, 0xA28);
+    property.add_span(0xA2A, 0xA30);
+    property.add_span(0xA32, 0xA33);
+    property.add_span(0xA35, 0xA36);
+    property.add_span(0xA38, 0xA39);
+    property.add_span(0xA3C, 0xA3C);
+    property.add_span(0xA3E, 0xA42);
+    property.add_span(0xA47, 0xA48);
+    property.add_span(0xA4B, 0xA4D);
+    property.add_span(0xA51, 0xA51);
+    property.add_span(0xA59, 0xA5C);
+    property.add_span(0xA5E, 0xA5E);
+    property.add_span(0xA66, 0xA76);
+    db.insert("Script=Gurmukhi", property);
+    let mut property = Category::new("Script=Gurung_Khema");
+    property.add_span(0x16100, 0x16139);
+    db.insert("Script=Gurung_Khema", property);
+    let mut property = Category::new("Script=Han");
+    property.add_span(0x2E80, 0x2E99);
+    property.add_span(0x2E9B, 0x2EF3);
+    property.add_span(0x2F00, 0x2FD5);
+    property.add_span(0x3005, 0x3005);
+    property.add_span(0x3007, 0x3007);
+    property.add_span(0x3021, 0x3029);
+    property.add_span(0x3038, 0x303B);
+    property.add_span(0x3400, 0x4DBF);
+    property.add_span(0x4E00, 0x9FFF);
+    property.add_span(0xF900, 0xFA6D);
+    property.add_span(0xFA70, 0xFAD9);
+    property.add_span(0x16FE2, 0x16FE3);
+    property.add_span(0x16FF0, 0x16FF1);
+    property.add_span(0x20000, 0x2A6DF);
+    property.add_span(0x2A700, 0x2B739);
+    property.add_span(0x2B740, 0x2B81D);
+    property.add_span(0x2B820, 0x2CEA1);
+    property.add_span(0x2CEB0, 0x2EBE0);
+    property.add_span(0x2EBF0, 0x2EE5D);
+    property.add_span(0x2F800, 0x2FA1D);
+    property.add_span(0x30000, 0x3134A);
+    property.add_span(0x31350, 0x323AF);
+    db.insert("Script=Han", property);
+    let mut property = Category::new("Script=Hangul");
+    property.add_span(0x1100, 0x11FF);
+    property.add_span(0x302E, 0x302F);
+    property.add_span(0x3131, 0x318E);
+    property.add_span(0x3200, 0x321E);
+    property.add_span(0x3260, 0x327E);
+    property.add_span(0xA960, 0xA97C);
+    property.add_span(0xAC00, 0xD7A3);
+    property.add_span(0xD7B0, 0xD7C6);
+    property.add_span(0xD7CB, 0xD7FB);
+    property.add_span(0xFFA0, 0xFFBE);
+    property.add_span(0xFFC2, 0xFFC7);
+    property.add_span(0xFFCA, 0xFFCF);
+    property.add_span(0xFFD2, 0xFFD7);
+    property.add_span(0xFFDA, 0xFFDC);
+    db.insert("Script=Hangul", property);
+    let mut property = Category::new("Script=Hanifi_Rohingya");
+    property.add_span(0x10D00, 0x10D27);
+    property.add_span(0x10D30, 0x10D39);
+    db.insert("Script=Hanifi_Rohingya", property);
+    let mut property = Category::new("Script=Hanunoo");
+    property.add_span(0x1720, 0x1734);
+    db.insert("Script=Hanunoo", property);
+    let mut property = Category::new("Script=Hatran");
+    property.add_span(0x108E0, 0x108F2);
+    property.add_span(0x108F4, 0x108F5);
+    property.add_span(0x108FB, 0x108FF);
+    db.insert("Script=Hatran", property);
+    let mut property = Category::new("Script=Hebrew");
+    property.add_span(0x591, 0x5C7);
+    property.add_span(0x5D0, 0x5EA);
+    property.add_span(0x5EF, 0x5F4);
+    property.add_span(0xFB1D, 0xFB36);
+    property.add_span(0xFB38, 0xFB3C);
+    property.add_span(0xFB3E, 0xFB3E);
+    property.add_span(0xFB40, 0xFB41);
+    property.add_span(0xFB43, 0xFB44);
+    property.add_span(0xFB46, 0xFB4F);
+    db.insert("Script=Hebrew", property);
+    let mut property = Category::new("Script=Hiragana");
+    property.add_span(0x3041, 0x3096);
+    property.add_span(0x309D, 0x309F);
+    property.add_span(0x1B001, 0x1B11F);
+    property.add_span(0x1B132, 0x1B132);
+    property.add_span(0x1B150, 0x1B152);
+    property.add_span(0x1F200, 0x1F200);
+    db.insert("Script=Hiragana", property);
+    let mut property = Category::new("Script=Imperial_Aramaic");
+    property.add_span(0x10840, 0x10855);
+    property.add_span(0x10857, 0x1085F);
+    db.insert("Script=Imperial_Aramaic", property);
+    let mut property = Category::new("Script=Inherited");
+    property.add_span(0x300, 0x36F);
+    property.add_span(0x485, 0x486);
+    property.add_span(0x64B, 0x655);
+    property.add_span(0x670, 0x670);
+    property.add_span(0x951, 0x954);
+    property.add_span(0x1AB0, 0x1ACE);
+    property.add_span(0x1CD0, 0x1CD2);
+    property.add_span(0x1CD4, 0x1CE0);
+    property.add_span(0x1CE2, 0x1CE8);
+    property.add_span(0x1CED, 0x1CED);
+    property.add_span(0x1CF4, 0x1CF4);
+    property.add_span(0x1CF8, 0x1CF9);
+    property.add_span(0x1DC0, 0x1DFF);
+    property.add_span(0x200C, 0x200D);
+    property.add_span(0x20D0, 0x20F0);
+    property.add_span(0x302A, 0x302D);
+    property.add_span(0x3099, 0x309A);
+    property.add_span(0xFE00, 0xFE0F);
+    property.add_span(0xFE20, 0xFE2D);
+    property.add_span(0x101FD, 0x101FD);
+    property.add_span(0x102E0, 0x102E0);
+    property.add_span(0x1133B, 0x1133B);
+    property.add_span(0x1CF00, 0x1CF2D);
+    property.add_span(0x1CF30, 0x1CF46);
+    property.add_span(0x1D167, 0x1D169);
+    property.add_span(0x1D17B, 0x1D182);
+    property.add_span(0x1D185, 0x1D18B);
+    property.add_span(0x1D1AA, 0x1D1AD);
+    property.add_span(0xE0100, 0xE01EF);
+    db.insert("Script=Inherited", property);
+    let mut property = Category::new("Script=Inscriptional_Pahlavi");
+    property.add_span(0x10B60, 0x10B72);
+    property.add_span(0x10B78, 0x10B7F);
+    db.insert("Script=Inscriptional_Pahlavi", property);
+    let mut property = Category::new("Script=Inscriptional_Parthian");
+    property.add_span(0x10B40, 0x10B55);
+    property.add_span(0x10B58, 0x10B5F);
+    db.insert("Script=Inscriptional_Parthian", property);
+    let mut property = Category::new("Script=Javanese");
+    property.add_span(0xA980, 0xA9CD);
+    property.add_span(0xA9D0, 0xA9D9);
+    property.add_span(0xA9DE, 0xA9DF);
+    db.insert("Script=Javanese", property);
+    let mut property = Category::new("Script=Kaithi");
+    property.add_span(0x11080, 0x110C2);
+    property.add_span(0x110CD, 0x110CD);
+    db.insert("Script=Kaithi", property);
+    let mut property = Category::new("Script=Kannada");
+    property.add_span(0xC80, 0xC8C);
+    property.add_span(0xC8E, 0xC90);
+    property.add_span(0xC92, 0xCA8);
+    property.add_span(0xCAA, 0xCB3);
+    property.add_span(0xCB5, 0xCB9);
+    property.add_span(0xCBC, 0xCC4);
+    property.add_span(0xCC6, 0xCC8);
+    property.add_span(0xCCA, 0xCCD);
+    property.add_span(0xCD5, 0xCD6);
+    property.add_span(0xCDD, 0xCDE);
+    property.add_span(0xCE0, 0xCE3);
+    property.add_span(0xCE6, 0xCEF);
+    property.add_span(0xCF1, 0xCF3);
+    db.insert("Script=Kannada", property);
+    let mut property = Category::new("Script=Katakana");
+    property.add_span(0x30A1, 0x30FA);
+    property.add_span(0x30FD, 0x30FF);
+    property.add_span(0x31F0, 0x31FF);
+    property.add_span(0x32D0, 0x32FE);
+    property.add_span(0x3300, 0x3357);
+    property.add_span(0xFF66, 0xFF6F);
+    property.add_span(0xFF71, 0xFF9D);
+    property.add_span(0x1AFF0, 0x1AFF3);
+    property.add_span(0x1AFF5, 0x1AFFB);
+    property.add_span(0x1AFFD, 0x1AFFE);
+    property.add_span(0x1B000, 0x1B000);
+    property.add_span(0x1B120, 0x1B122);
+    property.add_span(0x1B155, 0x1B155);
+    property.add_span(0x1B164, 0x1B167);
+    db.insert("Script=Katakana", property);
+    let mut property = Category::new("Script=Kawi");
+    property.add_span(0x11F00, 0x11F10);
+    property.add_span(0x11F12, 0x11F3A);
+    property.add_span(0x11F3E, 0x11F5A);
+    db.insert("Script=Kawi", property);
+    let mut property = Category::new("Script=Kayah_Li");
+    property.add_span(0xA900, 0xA92D);
+    property.add_span(0xA92F, 0xA92F);
+    db.insert("Script=Kayah_Li", property);
+    let mut property = Category::new("Script=Kharoshthi");
+    property.add_span(0x10A00, 0x10A03);
+    property.add_span(0x10A05, 0x10A06);
+    property.add_span(0x10A0C, 0x10A13);
+    property.add_span(0x10A15, 0x10A17);
+    property.add_span(0x10A19, 0x10A35);
+    property.add_span(0x10A38, 0x10A3A);
+    property.add_span(0x10A3F, 0x10A48);
+    property.add_span(0x10A50, 0x10A58);
+    db.insert("Script=Kharoshthi", property);
+    let mut property = Category::new("Script=Khitan_Small_Script");
+    property.add_span(0x16FE4, 0x16FE4);
+    property.add_span(0x18B00, 0x18CD5);
+    property.add_span(0x18CFF, 0x18CFF);
+    db.insert("Script=Khitan_Small_Script", property);
+    let mut property = Category::new("Script=Khmer");
+    property.add_span(0x1780, 0x17DD);
+    property.add_span(0x17E0, 0x17E9);
+    property.add_span(0x17F0, 0x17F9);
+    property.add_span(0x19E0, 0x19FF);
+    db.insert("Script=Khmer", property);
+    let mut property = Category::new("Script=Khojki");
+    property.add_span(0x11200, 0x11211);
+    property.add_span(0x11213, 0x11241);
+    db.insert("Script=Khojki", property);
+    let mut property = Category::new("Script=Khudawadi");
+    property.add_span(0x112B0, 0x112EA);
+    property.add_span(0x112F0, 0x112F9);
+    db.insert("Script=Khudawadi", property);
+    let mut property = Category::new("Script=Kirat_Rai");
+    property.add_span(0x16D40, 0x16D79);
+    db.insert("Script=Kirat_Rai", property);
+    let mut property = Category::new("Script=Lao");
+    property.add_span(0xE81, 0xE82);
+    property.add_span(0xE84, 0xE84);
+    property.add_span(0xE86, 0xE8A);
+    property.add_span(0xE8C, 0xEA3);
+    property.add_span(0xEA5, 0xEA5);
+    property.add_span(0xEA7, 0xEBD);
+    property.add_span(0xEC0, 0xEC4);
+    property.add_span(0xEC6, 0xEC6);
+    property.add_span(0xEC8, 0xECE);
+    property.add_span(0xED0, 0xED9);
+    property.add_span(0xEDC, 0xEDF);
+    db.insert("Script=Lao", property);
+    let mut property = Category::new("Script=Latin");
+    property.add_span(0x41, 0x5A);
+    property.add_span(0x61, 0x7A);
+    property.add_span(0xAA, 0xAA);
+    property.add_span(0xBA, 0xBA);
+    property.add_span(0xC0, 0xD6);
+    property.add_span(0xD8, 0xF6);
+    property.add_span(0xF8, 0x2B8);
+    property.add_span(0x2E0, 0x2E4);
+    property.add_span(0x1D00, 0x1D25);
+    property.add_span(0x1D2C, 0x1D5C);
+    property.add_span(0x1D62, 0x1D65);
+    property.add_span(0x1D6B, 0x1D77);
+    property.add_span(0x1D79, 0x1DBE);
+    property.add_span(0x1E00, 0x1EFF);
+    property.add_span(0x2071, 0x2071);
+    property.add_span(0x207F, 0x207F);
+    property.add_span(0x2090, 0x209C);
+    property.add_span(0x212A, 0x212B);
+    property.add_span(0x2132, 0x2132);
+    property.add_span(0x214E, 0x214E);
+    property.add_span(0x2160, 0x2188);
+    property.add_span(0x2C60, 0x2C7F);
+    property.add_span(0xA722, 0xA787);
+    property.add_span(0xA78B, 0xA7CD);
+    property.add_span(0xA7D0, 0xA7D1);
+    property.add_span(0xA7D3, 0xA7D3);
+    property.add_span(0xA7D5, 0xA7DC);
+    property.add_span(0xA7F2, 0xA7FF);
+    property.add_span(0xAB30, 0xAB5A);
+    property.add_span(0xAB5C, 0xAB64);
+    property.add_span(0xAB66, 0xAB69);
+    property.add_span(0xFB00, 0xFB06);
+    property.add_span(0xFF21, 0xFF3A);
+    property.add_span(0xFF41, 0xFF5A);
+    property.add_span(0x10780, 0x10785);
+    property.add_span(0x10787, 0x107B0);
+    property.add_span(0x107B2, 0x107BA);
+    property.add_span(0x1DF00, 0x1DF1E);
+    property.add_span(0x1DF25, 0x1DF2A);
+    db.insert("Script=Latin", property);
+    let mut property = Category::new("Script=Lepcha");
+    property.add_span(0x1C00, 0x1C37);
+    property.add_span(0x1C3B, 0x1C49);
+    property.add_span(0x1C4D, 0x1C4F);
+    db.insert("Script=Lepcha", property);
+    let mut property = Category::new("Script=Limbu");
+    property.add_span(0x1900, 0x191E);
+    property.add_span(0x1920, 0x192B);
+    property.add_span(0x1930, 0x193B);
+    property.add_span(0x1940, 0x1940);
+    property.add_span(0x1944, 0x194F);
+    db.insert("Script=Limbu", property);
+    let mut property = Category::new("Script=Linear_A");
+    property.add_span(0x10600, 0x10736);
+    property.add_span(0x10740, 0x10755);
+    property.add_span(0x10760, 0x10767);
+    db.insert("Script=Linear_A", property);
+    let mut property = Category::new("Script=Linear_B");
+    property.add_span(0x10000, 0x1000B);
+    property.add_span(0x1000D, 0x10026);
+    property.add_span(0x10028, 0x1003A);
+    property.add_span(0x1003C, 0x1003D);
+    property.add_span(0x1003F, 0x1004D);
+    property.add_span(0x10050, 0x1005D);
+    property.add_span(0x10080, 0x100FA);
+    db.insert("Script=Linear_B", property);
+    let mut property = Category::new("Script=Lisu");
+    property.add_span(0xA4D0, 0xA4FF);
+    property.add_span(0x11FB0, 0x11FB0);
+    db.insert("Script=Lisu", property);
+    let mut property = Category::new("Script=Lycian");
+    property.add_span(0x10280, 0x1029C);
+    db.insert("Script=Lycian", property);
+    let mut property = Category::new("Script=Lydian");
+    property.add_span(0x10920, 0x10939);
+    property.add_span(0x1093F, 0x1093F);
+    db.insert("Script=Lydian", property);
+    let mut property = Category::new("Script=Mahajani");
+    property.add_span(0x11150, 0x11176);
+    db.insert("Script=Mahajani", property);
+    let mut property = Category::new("Script=Makasar");
+    property.add_span(0x11EE0, 0x11EF8);
+    db.insert("Script=Makasar", property);
+    let mut property = Category::new("Script=Malayalam");
+    property.add_span(0xD00, 0xD0C);
+    property.add_span(0xD0E, 0xD10);
+    property.add_span(0xD12, 0xD44);
+    property.add_span(0xD46, 0xD48);
+    property.add_span(0xD4A, 0xD4F);
+    property.add_span(0xD54, 0xD63);
+    property.add_span(0xD66, 0xD7F);
+    db.insert("Script=Malayalam", property);
+    let mut property = Category::new("Script=Mandaic");
+    property.add_span(0x840, 0x85B);
+    property.add_span(0x85E, 0x85E);
+    db.insert("Script=Mandaic", property);
+    let mut property = Category::new("Script=Manichaean");
+    property.add_span(0x10AC0, 0x10AE6);
+    property.add_span(0x10AEB, 0x10AF6);
+    db.insert("Script=Manichaean", property);
+    let mut property = Category::new("Script=Marchen");
+    property.add_span(0x11C70, 0x11C8F);
+    property.add_span(0x11C92, 0x11CA7);
+    property.add_span(0x11CA9, 0x11CB6);
+    db.insert("Script=Marchen", property);
+    let mut property = Category::new("Script=Masaram_Gondi");
+    property.add_span(0x11D00, 0x11D06);
+    property.add_span(0x11D08, 0x11D09);
+    property.add_span(0x11D0B, 0x11D36);
+    property.add_span(0x11D3A, 0x11D3A);
+    property.add_span(0x11D3C, 0x11D3D);
+    property.add_span(0x11D3F, 0x11D47);
+    property.add_span(0x11D50, 0x11D59);
+    db.insert("Script=Masaram_Gondi", property);
+    let mut property = Category::new("Script=Medefaidrin");
+    property.add_span(0x16E40, 0x16E9A);
+    db.insert("Script=Medefaidrin", property);
+    let mut property = Category::new("Script=Meetei_Mayek");
+    property.add_span(0xAAE0, 0xAAF6);
+    property.add_span(0xABC0, 0xABED);
+    property.add_span(0xABF0, 0xABF9);
+    db.insert("Script=Meetei_Mayek", property);
+    let mut property = Category::new("Script=Mende_Kikakui");
+    property.add_span(0x1E800, 0x1E8C4);
+    property.add_span(0x1E8C7, 0x1E8D6);
+    db.insert("Script=Mende_Kikakui", property);
+    let mut property = Category::new("Script=Meroitic_Cursive");
+    property.add_span(0x109A0, 0x109B7);
+    property.add_span(0x109BC, 0x109CF);
+    property.add_span(0x109D2, 0x109FF);
+    db.insert("Script=Meroitic_Cursive", property);
+    let mut property = Category::new("Script=Meroitic_Hieroglyphs");
+    property.add_span(0x10980, 0x1099F);
+    db.insert("Script=Meroitic_Hieroglyphs", property);
+    let mut property = Category::new("Script=Miao");
+    property.add_span(0x16F00, 0x16F4A);
+    property.add_span(0x16F4F, 0x16F87);
+    property.add_span(0x16F8F, 0x16F9F);
+    db.insert("Script=Miao", property);
+    let mut property = Category::new("Script=Modi");
+    property.add_span(0x11600, 0x11644);
+    property.add_span(0x11650, 0x11659);
+    db.insert("Script=Modi", property);
+    let mut property = Category::new("Script=Mongolian");
+    property.add_span(0x1800, 0x1801);
+    property.add_span(0x1804, 0x1804);
+    property.add_span(0x1806, 0x1819);
+    property.add_span(0x1820, 0x1878);
+    property.add_span(0x1880, 0x18AA);
+    property.add_span(0x11660, 0x1166C);
+    db.insert("Script=Mongolian", property);
+    let mut property = Category::new("Script=Mro");
+    property.add_span(0x16A40, 0x16A5E);
+    property.add_span(0x16A60, 0x16A69);
+    property.add_span(0x16A6E, 0x16A6F);
+    db.insert("Script=Mro", property);
+    let mut property = Category::new("Script=Multani");
+    property.add_span(0x11280, 0x11286);
+    property.add_span(0x11288, 0x11288);
+    property.add_span(0x1128A, 0x1128D);
+    property.add_span(0x1128F, 0x1129D);
+    property.add_span(0x1129F, 0x112A9);
+    db.insert("Script=Multani", property);
+    let mut property = Category::new("Script=Myanmar");
+    property.add_span(0x1000, 0x109F);
+    property.add_span(0xA9E0, 0xA9FE);
+    property.add_span(0xAA60, 0xAA7F);
+    property.add_span(0x116D0, 0x116E3);
+    db.insert("Script=Myanmar", property);
+    let mut property = Category::new("Script=Nabataean");
+    property.add_span(0x10880, 0x1089E);
+    property.add_span(0x108A7, 0x108AF);
+    db.insert("Script=Nabataean", property);
+    let mut property = Category::new("Script=Nag_Mundari");
+    property.add_span(0x1E4D0, 0x1E4F9);
+    db.insert("Script=Nag_Mundari", property);
+    let mut property = Category::new("Script=Nandinagari");
+    property.add_span(0x119A0, 0x119A7);
+    property.add_span(0x119AA, 0x119D7);
+    property.add_span(0x119DA, 0x119E4);
+    db.insert("Script=Nandinagari", property);
+    let mut property = Category::new("Script=New_Tai_Lue");
+    property.add_span(0x1980, 0x19AB);
+    property.add_span(0x19B0, 0x19C9);
+    property.add_span(0x19D0, 0x19DA);
+    property.add_span(0x19DE, 0x19DF);
+    db.insert("Script=New_Tai_Lue", property);
+    let mut property = Category::new("Script=Newa");
+    property.add_span(0x11400, 0x1145B);
+    property.add_span(0x1145D, 0x11461);
+    db.insert("Script=Newa", property);
+    let mut property = Category::new("Script=Nko");
+    property.add_span(0x7C0, 0x7FA);
+    property.add_span(0x7FD, 0x7FF);
+    db.insert("Script=Nko", property);
+    let mut property = Category::new("Script=Nushu");
+    property.add_span(0x16FE1, 0x16FE1);
+    property.add_span(0x1B170, 0x1B2FB);
+    db.insert("Script=Nushu", property);
+    let mut property = Category::new("Script=Nyiakeng_Puachue_Hmong");
+    property.add_span(0x1E100, 0x1E12C);
+    property.add_span(0x1E130, 0x1E13D);
+    property.add_span(0x1E140, 0x1E149);
+    property.add_span(0x1E14E, 0x1E14F);
+    db.insert("Script=Nyiakeng_Puachue_Hmong", property);
+    let mut property = Category::new("Script=Ogham");
+    property.add_span(0x1680, 0x169C);
+    db.insert("Script=Ogham", property);
+    let mut property = Category::new("Script=Ol_Chiki");
+    property.add_span(0x1C50, 0x1C7F);
+    db.insert("Script=Ol_Chiki", property);
+    let mut property = Category::new("Script=Ol_Onal");
+    property.add_span(0x1E5D0, 0x1E5FA);
+    property.add_span(0x1E5FF, 0x1E5FF);
+    db.insert("Script=Ol_Onal", property);
+    let mut property = Category::new("Script=Old_Hungarian");
+    property.add_span(0x10C80, 0x10CB2);
+    property.add_span(0x10CC0, 0x10CF2);
+    property.add_span(0x10CFA, 0x10CFF);
+    db.insert("Script=Old_Hungarian", property);
+    let mut property = Category::new("Script=Old_Italic");
+    property.add_span(0x10300, 0x10323);
+    property.add_span(0x1032D, 0x1032F);
+    db.insert("Script=Old_Italic", property);
+    let mut property = Category::new("Script=Old_North_Arabian");
+    property.add_span(0x10A80, 0x10A9F);
+    db.insert("Script=Old_North_Arabian", property);
+    let mut property = Category::new("Script=Old_Permic");
+    property.add_span(0x10350, 0x1037A);
+    db.insert("Script=Old_Permic", property);
+    let mut property = Category::new("Script=Old_Persian");
+    property.add_span(0x103A0, 0x103C3);
+    property.add_span(0x103C8, 0x103D5);
+    db.insert("Script=Old_Persian", property);
+    let mut property = Category::new("Script=Old_Sogdian");
+    property.add_span(0x10F00, 0x10F27);
+    db.insert("Script=Old_Sogdian", property);
+    let mut property = Category::new("Script=Old_South_Arabian");
+    property.add_span(0x10A60, 0x10A7F);
+    db.insert("Script=Old_South_Arabian", property);
+    let mut property = Category::new("Script=Old_Turkic");
+    property.add_span(0x10C00, 0x10C48);
+    db.insert("Script=Old_Turkic", property);
+    let mut property = Category::new("Script=Old_Uyghur");
+    property.add_span(0x10F70, 0x10F89);
+    db.insert("Script=Old_Uyghur", property);
+    let mut property = Category::new("Script=Oriya");
+    property.add_span(0xB01, 0xB03);
+    property.add_span(0xB05, 0xB0C);
+    property.add_span(0xB0F, 0xB10);
+    property.add_span(0xB13, 0xB28);
+    property.add_span(0xB2A, 0xB30);
+    property.add_span(0xB32, 0xB33);
+    property.add_span(0xB35, 0xB39);
+    property.add_span(0xB3C, 0xB44);
+    property.add_span(0xB47, 0xB48);
+    property.add_span(0xB4B, 0xB4D);
+    property.add_span(0xB55, 0xB57);
+    property.add_span(0xB5C, 0xB5D);
+    property.add_span(0xB5F, 0xB63);
+    property.add_span(0xB66, 0xB77);
+    db.insert("Script=Oriya", property);
+    let mut property = Category::new("Script=Osage");
+    property.add_span(0x104B0, 0x104D3);
+    property.add_span(0x104D8, 0x104FB);
+    db.insert("Script=Osage", property);
+    let mut property = Category::new("Script=Osmanya");
+    property.add_span(0x10480, 0x1049D);
+    property.add_span(0x104A0, 0x104A9);
+    db.insert("Script=Osmanya", property);
+    let mut property = Category::new("Script=Pahawh_Hmong");
+    property.add_span(0x16B00, 0x16B45);
+    property.add_span(0x16B50, 0x16B59);
+    property.add_span(0x16B5B, 0x16B61);
+    property.add_span(0x16B63, 0x16B77);
+    property.add_span(0x16B7D, 0x16B8F);
+    db.insert("Script=Pahawh_Hmong", property);
+    let mut property = Category::new("Script=Palmyrene");
+    property.add_span(0x10860, 0x1087F);
+    db.insert("Script=Palmyrene", property);
+    let mut property = Category::new("Script=Pau_Cin_Hau");
+    property.add_span(0x11AC0, 0x11AF8);
+    db.insert("Script=Pau_Cin_Hau", property);
+    let mut property = Category::new("Script=Phags_Pa");
+    property.add_span(0xA840, 0xA877);
+    db.insert("Script=Phags_Pa", property);
+    let mut property = Category::new("Script=Phoenician");
+    property.add_span(0x10900, 0x1091B);
+    property.add_span(0x1091F, 0x1091F);
+    db.insert("Script=Phoenician", property);
+    let mut property = Category::new("Script=Psalter_Pahlavi");
+    property.add_span(0x10B80, 0x10B91);
+    property.add_span(0x10B99, 0x10B9C);
+    property.add_span(0x10BA9, 0x10BAF);
+    db.insert("Script=Psalter_Pahlavi", property);
+    let mut property = Category::new("Script=Rejang");
+    property.add_span(0xA930, 0xA953);
+    property.add_span(0xA95F, 0xA95F);
+    db.insert("Script=Rejang", property);
+    let mut property = Category::new("Script=Runic");
+    property.add_span(0x16A0, 0x16EA);
+    property.add_span(0x16EE, 0x16F8);
+    db.insert("Script=Runic", property);
+    let mut property = Category::new("Script=Samaritan");
+    property.add_span(0x800, 0x82D);
+    property.add_span(0x830, 0x83E);
+    db.insert("Script=Samaritan", property);
+    let mut property = Category::new("Script=Saurashtra");
+    property.add_span(0xA880, 0xA8C5);
+    property.add_span(0xA8CE, 0xA8D9);
+    db.insert("Script=Saurashtra", property);
+    let mut property = Category::new("Script=Sharada");
+    property.add_span(0x11180, 0x111DF);
+    db.insert("Script=Sharada", property);
+    let mut property = Category::new("Script=Shavian");
+    property.add_span(0x10450, 0x1047F);
+    db.insert("Script=Shavian", property);
+    let mut property = Category::new("Script=Siddham");
+    property.add_span(0x11580, 0x115B5);
+    property.add_span(0x115B8, 0x115DD);
+    db.insert("Script=Siddham", property);
+    let mut property = Category::new("Script=SignWriting");
+    property.add_span(0x1D800, 0x1DA8B);
+    property.add_span(0x1DA9B, 0x1DA9F);
+    property.add_span(0x1DAA1, 0x1DAAF);
+    db.insert("Script=SignWriting", property);
+    let mut property = Category::new("Script=Sinhala");
+    property.add_span(0xD81, 0xD83);
+    property.add_span(0xD85, 0xD96);
+    property.add_span(0xD9A, 0xDB1);
+    property.add_span(0xDB3, 0xDBB);
+    property.add_span(0xDBD, 0xDBD);
+    property.add_span(0xDC0, 0xDC6);
+    property.add_span(0xDCA, 0xDCA);
+    property.add_span(0xDCF, 0xDD4);
+    property.add_span(0xDD6, 0xDD6);
+    property.add_span(0xDD8, 0xDDF);
+    property.add_span(0xDE6, 0xDEF);
+    property.add_span(0xDF2, 0xDF4);
+    property.add_span(0x111E1, 0x111F4);
+    db.insert("Script=Sinhala", property);
+    let mut property = Category::new("Script=Sogdian");
+    property.add_span(0x10F30, 0x10F59);
+    db.insert("Script=Sogdian", property);
+    let mut property = Category::new("Script=Sora_Sompeng");
+    property.add_span(0x110D0, 0x110E8);
+    property.add_span(0x110F0, 0x110F9);
+    db.insert("Script=Sora_Sompeng", property);
+    let mut property = Category::new("Script=Soyombo");
+    property.add_span(0x11A50, 0x11AA2);
+    db.insert("Script=Soyombo", property);
+    let mut property = Category::new("Script=Sundanese");
+    property.add_span(0x1B80, 0x1BBF);
+    property.add_span(0x1CC0, 0x1CC7);
+    db.insert("Script=Sundanese", property);
+    let mut property = Category::new("Script=Sunuwar");
+    property.add_span(0x11BC0, 0x11BE1);
+    property.add_span(0x11BF0, 0x11BF9);
+    db.insert("Script=Sunuwar", property);
+    let mut property = Category::new("Script=Syloti_Nagri");
+    property.add_span(0xA800, 0xA82C);
+    db.insert("Script=Syloti_Nagri", property);
+    let mut property = Category::new("Script=Syriac");
+    property.add_span(0x700, 0x70D);
+    property.add_span(0x70F, 0x74A);
+    property.add_span(0x74D, 0x74F);
+    property.add_span(0x860, 0x86A);
+    db.insert("Script=Syriac", property);
+    let mut property = Category::new("Script=Tagalog");
+    property.add_span(0x1700, 0x1715);
+    property.add_span(0x171F, 0x171F);
+    db.insert("Script=Tagalog", property);
+    let mut property = Category::new("Script=Tagbanwa");
+    property.add_span(0x1760, 0x176C);
+    property.add_span(0x176E, 0x1770);
+    property.add_span(0x1772, 0x1773);
+    db.insert("Script=Tagbanwa", property);
+    let mut property = Category::new("Script=Tai_Le");
+    property.add_span(0x1950, 0x196D);
+    property.add_span(0x1970, 0x1974);
+    db.insert("Script=Tai_Le", property);
+    let mut property = Category::new("Script=Tai_Tham");
+    property.add_span(0x1A20, 0x1A5E);
+    property.add_span(0x1A60, 0x1A7C);
+    property.add_span(0x1A7F, 0x1A89);
+    property.add_span(0x1A90, 0x1A99);
+    property.add_span(0x1AA0, 0x1AAD);
+    db.insert("Script=Tai_Tham", property);
+    let mut property = Category::new("Script=Tai_Viet");
+    property.add_span(0xAA80, 0xAAC2);
+    property.add_span(0xAADB, 0xAADF);
+    db.insert("Script=Tai_Viet", property);
+    let mut property = Category::new("Script=Takri");
+    property.add_span(0x11680, 0x116B9);
+    property.add_span(0x116C0, 0x116C9);
+    db.insert("Script=Takri", property);
+    let mut property = Category::new("Script=Tamil");
+    property.add_span(0xB82, 0xB83);
+    property.add_span(0xB85, 0xB8A);
+    property.add_span(0xB8E, 0xB90);
+    property.add_span(0xB92, 0xB95);
+    property.add_span(0xB99, 0xB9A);
+    property.add_span(0xB9C, 0xB9C);
+    property.add_span(0xB9E, 0xB9F);
+    property.add_span(0xBA3, 0xBA4);
+    property.add_span(0xBA8, 0xBAA);
+    property.add_span(0xBAE, 0xBB9);
+    property.add_span(0xBBE, 0xBC2);
+    property.add_span(0xBC6, 0xBC8);
+    property.add_span(0xBCA, 0xBCD);
+    property.add_span(0xBD0, 0xBD0);
+    property.add_span(0xBD7, 0xBD7);
+    property.add_span(0xBE6, 0xBFA);
+    property.add_span(0x11FC0, 0x11FF1);
+    property.add_span(0x11FFF, 0x11FFF);
+    db.insert("Script=Tamil", property);
+    let mut property = Category::new("Script=Tangsa");
+    property.add_span(0x16A70, 0x16ABE);
+    property.add_span(0x16AC0, 0x16AC9);
+    db.insert("Script=Tangsa", property);
+    let mut property = Category::new("Script=Tangut");
+    property.add_span(0x16FE0, 0x16FE0);
+    property.add_span(0x17000, 0x187F7);
+    property.add_span(0x18800, 0x18AFF);
+    property.add_span(0x18D00, 0x18D08);
+    db.insert("Script=Tangut", property);
+    let mut property = Category::new("Script=Telugu");
+    property.add_span(0xC00, 0xC0C);
+    property.add_span(0xC0E, 0xC10);
+    property.add_span(0xC12, 0xC28);
+    property.add_span(0xC2A, 0xC39);
+    property.add_span(0xC3C, 0xC44);
+    property.add_span(0xC46, 0xC48);
+    property.add_span(0xC4A, 0xC4D);
+    property.add_span(0xC55, 0xC56);
+    property.add_span(0xC58, 0xC5A);
+    property.add_span(0xC5D, 0xC5D);
+    property.add_span(0xC60, 0xC63);
+    property.add_span(0xC66, 0xC6F);
+    property.add_span(0xC77, 0xC7F);
+    db.insert("Script=Telugu", property);
+    let mut property = Category::new("Script=Thaana");
+    property.add_span(0x780, 0x7B1);
+    db.insert("Script=Thaana", property);
+    let mut property = Category::new("Script=Thai");
+    property.add_span(0xE01, 0xE3A);
+    property.add_span(0xE40, 0xE5B);
+    db.insert("Script=Thai", property);
+    let mut property = Category::new("Script=Tibetan");
+    property.add_span(0xF00, 0xF47);
+    property.add_span(0xF49, 0xF6C);
+    property.add_span(0xF71, 0xF97);
+    property.add_span(0xF99, 0xFBC);
+    property.add_span(0xFBE, 0xFCC);
+    property.add_span(0xFCE, 0xFD4);
+    property.add_span(0xFD9, 0xFDA);
+    db.insert("Script=Tibetan", property);
+    let mut property = Category::new("Script=Tifinagh");
+    property.add_span(0x2D30, 0x2D67);
+    property.add_span(0x2D6F, 0x2D70);
+    property.add_span(0x2D7F, 0x2D7F);
+    db.insert("Script=Tifinagh", property);
+    let mut property = Category::new("Script=Tirhuta");
+    property.add_span(0x11480, 0x114C7);
+    property.add_span(0x114D0, 0x114D9);
+    db.insert("Script=Tirhuta", property);
+    let mut property = Category::new("Script=Todhri");
+    property.add_span(0x105C0, 0x105F3);
+    db.insert("Script=Todhri", property);
+    let mut property = Category::new("Script=Toto");
+    property.add_span(0x1E290, 0x1E2AE);
+    db.insert("Script=Toto", property);
+    let mut property = Category::new("Script=Tulu_Tigalari");
+    property.add_span(0x11380, 0x11389);
+    property.add_span(0x1138B, 0x1138B);
+    property.add_span(0x1138E, 0x1138E);
+    property.add_span(0x11390, 0x113B5);
+    property.add_span(0x113B7, 0x113C0);
+    property.add_span(0x113C2, 0x113C2);
+    property.add_span(0x113C5, 0x113C5);
+    property.add_span(0x113C7, 0x113CA);
+    property.add_span(0x113CC, 0x113D5);
+    property.add_span(0x113D7, 0x113D8);
+    property.add_span(0x113E1, 0x113E2);
+    db.insert("Script=Tulu_Tigalari", property);
+    let mut property = Category::new("Script=Ugaritic");
+    property.add_span(0x10380, 0x1039D);
+    property.add_span(0x1039F, 0x1039F);
+    db.insert("Script=Ugaritic", property);
+    let mut property = Category::new("Script=Vai");
+    property.add_span(0xA500, 0xA62B);
+    db.insert("Script=Vai", property);
+    let mut property = Category::new("Script=Vithkuqi");
+    property.add_span(0x10570, 0x1057A);
+    property.add_span(0x1057C, 0x1058A);
+    property.add_span(0x1058C, 0x10592);
+    property.add_span(0x10594, 0x10595);
+    property.add_span(0x10597, 0x105A1);
+    property.add_span(0x105A3, 0x105B1);
+    property.add_span(0x105B3, 0x105B9);
+    property.add_span(0x105BB, 0x105BC);
+    db.insert("Script=Vithkuqi", property);
+    let mut property = Category::new("Script=Wancho");
+    property.add_span(0x1E2C0, 0x1E2F9);
+    property.add_span(0x1E2FF, 0x1E2FF);
+    db.insert("Script=Wancho", property);
+    let mut property = Category::new("Script=Warang_Citi");
+    property.add_span(0x118A0, 0x118F2);
+    property.add_span(0x118FF, 0x118FF);
+    db.insert("Script=Warang_Citi", property);
+    let mut property = Category::new("Script=Yezidi");
+    property.add_span(0x10E80, 0x10EA9);
+    property.add_span(0x10EAB, 0x10EAD);
+    property.add_span(0x10EB0, 0x10EB1);
+    db.insert("Script=Yezidi", property);
+    let mut property = Category::new("Script=Yi");
+    property.add_span(0xA000, 0xA48C);
+    property.add_span(0xA490, 0xA4C6);
+    db.insert("Script=Yi", property);
+    let mut property = Category::new("Script=Zanabazar_Square");
+    property.add_span(0x11A00, 0x11A47);
+    db.insert("Script=Zanabazar_Square", property);
+    let mut property = Category::new("Uppercase");
+    property.add_span(0x41, 0x5A);
+    property.add_span(0xC0, 0xD6);
+    property.add_span(0xD8, 0xDE);
+    property.add_span(0x100, 0x100);
+    property.add_span(0x102, 0x102);
+    property.add_span(0x104, 0x104);
+    property.add_span(0x106, 0x106);
+    property.add_span(0x108, 0x108);
+    property.add_span(0x10A, 0x10A);
+    property.add_span(0x10C, 0x10C);
+    property.add_span(0x10E, 0x10E);
+    property.add_span(0x110, 0x110);
+    property.add_span(0x112, 0x112);
+    property.add_span(0x114, 0x114);
+    property.add_span(0x116, 0x116);
+    property.add_span(0x118, 0x118);
+    property.add_span(0x11A, 0x11A);
+    property.add_span(0x11C, 0x11C);
+    property.add_span(0x11E, 0x11E);
+    property.add_span(0x120, 0x120);
+    property.add_span(0x122, 0x122);
+    property.add_span(0x124, 0x124);
+    property.add_span(0x126, 0x126);
+    property.add_span(0x128, 0x128);
+    property.add_span(0x12A, 0x12A);
+    property.add_span(0x12C, 0x12C);
+    property.add_span(0x12E, 0x12E);
+    property.add_span(0x130, 0x130);
+    property.add_span(0x132, 0x132);
+    property.add_span(0x134, 0x134);
+    property.add_span(0x136, 0x136);
+    property.add_span(0x139, 0x139);
+    property.add_span(0x13B, 0x13B);
+    property.add_span(0x13D, 0x13D);
+    property.add_span(0x13F, 0x13F);
+    property.add_span(0x141, 0x141);
+    property.add_span(0x143, 0x143);
+    property.add_span(0x145, 0x145);
+    property.add_span(0x147, 0x147);
+    property.add_span(0x14A, 0x14A);
+    property.add_span(0x14C, 0x14C);
+    property.add_span(0x14E, 0x14E);
+    property.add_span(0x150, 0x150);
+    property.add_span(0x152, 0x152);
+    property.add_span(0x154, 0x154);
+    property.add_span(0x156, 0x156);
+    property.add_span(0x158, 0x158);
+    property.add_span(0x15A, 0x15A);
+    property.add_span(0x15C, 0x15C);
+    property.add_span(0x15E, 0x15E);
+    property.add_span(0x160, 0x160);
+    property.add_span(0x162, 0x162);
+    property.add_span(0x164, 0x164);
+    property.add_span(0x166, 0x166);
+    property.add_span(0x168, 0x168);
+    property.add_span(0x16A, 0x16A);
+    property.add_span(0x16C, 0x16C);
+    property.add_span(0x16E, 0x16E);
+    property.add_span(0x170, 0x170);
+    property.add_span(0x172, 0x172);
+    property.add_span(0x174, 0x174);
+    property.add_span(0x176, 0x176);
+    property.add_span(0x178, 0x179);
+    property.add_span(0x17B, 0x17B);
+    property.add_span(0x17D, 0x17D);
+    property.add_span(0x181, 0x182);
+    property.add_span(0x184, 0x184);
+    property.add_span(0x186, 0x187);
+    property.add_span(0x189, 0x18B);
+    property.add_span(0x18E, 0x191);
+    property.add_span(0x193, 0x194);
+    property.add_span(0x196, 0x198);
+    property.add_span(0x19C, 0x19D);
+    property.add_span(0x19F, 0x1A0);
+    property.add_span(0x1A2, 0x1A2);
+    property.add_span(0x1A4, 0x1A4);
+    property.add_span(0x1A6, 0x1A7);
+    property.add_span(0x1A9, 0x1A9);
+    property.add_span(0x1AC, 0x1AC);
+    property.add_span(0x1AE, 0x1AF);
+    property.add_span(0x1B1, 0x1B3);
+    property.add_span(0x1B5, 0x1B5);
+    property.add_span(0x1B7, 0x1B8);
+    property.add_span(0x1BC, 0x1BC);
+    property.add_span(0x1C4, 0x1C4);
+    property.add_span(0x1C7, 0x1C7);
+    property.add_span(0x1CA, 0x1CA);
+    property.add_span(0x1CD, 0x1CD);
+    property.add_span(0x1CF, 0x1CF);
+    property.add_span(0x1D1, 0x1D1);
+    property.add_span(0x1D3, 0x1D3);
+    property.add_span(0x1D5, 0x1D5);
+    property.add_span(0x1D7, 0x1D7);
+    property.add_span(0x1D9, 0x1D9);
+    property.add_span(0x1DB, 0x1DB);
+    property.add_span(0x1DE, 0x1DE);
+    property.add_span(0x1E0, 0x1E0);
+    property.add_span(0x1E2, 0x1E2);
+    property.add_span(0x1E4, 0x1E4);
+    property.add_span(0x1E6, 0x1E6);
+    property.add_span(0x1E8, 0x1E8);
+    property.add_span(0x1EA, 0x1EA);
+    property.add_span(0x1EC, 0x1EC);
+    property.add_span(0x1EE, 0x1EE);
+    property.add_span(0x1F1, 0x1F1);
+    property.add_span(0x1F4, 0x1F4);
+    property.add_span(0x1F6, 0x1F8);
+    property.add_span(0x1FA, 0x1FA);
+    property.add_span(0x1FC, 0x1FC);
+    property.add_span(0x1FE, 0x1FE);
+    property.add_span(0x200, 0x200);
+    property.add_span(0x202, 0x202);
+    property.add_span(0x204, 0x204);
+    property.add_span(0x206, 0x206);
+    property.add_span(0x208, 0x208);
+    property.add_span(0x20A, 0x20A);
+    property.add_span(0x20C, 0x20C);
+    property.add_span(0x20E, 0x20E);
+    property.add_span(0x210, 0x210);
+    property.add_span(0x212, 0x212);
+    property.add_span(0x214, 0x214);
+    property.add_span(0x216, 0x216);
+    property.add_span(0x218, 0x218);
+    property.add_span(0x21A, 0x21A);
+    property.add_span(0x21C, 0x21C);
+    property.add_span(0x21E, 0x21E);
+    property.add_span(0x220, 0x220);
+    property.add_span(0x222, 0x222);
+    property.add_span(0x224, 0x224);
+    property.add_span(0x226, 0x226);
+    property.add_span(0x228, 0x228);
+    property.add_span(0x22A, 0x22A);
+    property.add_span(0x22C, 0x22C);
+    property.add_span(0x22E, 0x22E);
+    property.add_span(0x230, 0x230);
+    property.add_span(0x232, 0x232);
+    property.add_span(0x23A, 0x23B);
+    property.add_span(0x23D, 0x23E);
+    property.add_span(0x241, 0x241);
+    property.add_span(0x243, 0x246);
+    property.add_span(0x248, 0x248);
+    property.add_span(0x24A, 0x24A);
+    property.add_span(0x24C, 0x24C);
+    property.add_span(0x24E, 0x24E);
+    property.add_span(0x370, 0x370);
+    property.add_span(0x372, 0x372);
+    property.add_span(0x376, 0x376);
+    property.add_span(0x37F, 0x37F);
+    property.add_span(0x386, 0x386);
+    property.add_span(0x388, 0x38A);
+    property.add_span(0x38C, 0x38C);
+    property.add_span(0x38E, 0x38F);
+    property.add_span(0x391, 0x3A1);
+    property.add_span(0x3A3, 0x3AB);
+    property.add_span(0x3CF, 0x3CF);
+    property.add_span(0x3D2, 0x3D4);
+    property.add_span(0x3D8, 0x3D8);
+    property.add_span(0x3DA, 0x3DA);
+    property.add_span(0x3DC, 0x3DC);
+    property.add_span(0x3DE, 0x3DE);
+    property.add_span(0x3E0, 0x3E0);
+    property.add_span(0x3E2, 0x3E2);
+    property.add_span(0x3E4, 0x3E4);
+    property.add_span(0x3E6, 0x3E6);
+    property.add_span(0x3E8, 0x3E8);
+    property.add_span(0x3EA, 0x3EA);
+    property.add_span(0x3EC, 0x3EC);
+    property.add_span(0x3EE, 0x3EE);
+    property.add_span(0x3F4, 0x3F4);
+    property.add_span(0x3F7, 0x3F7);
+    property.add_span(0x3F9, 0x3FA);
+    property.add_span(0x3FD, 0x42F);
+    property.add_span(0x460, 0x460);
+    property.add_span(0x462, 0x462);
+    property.add_span(0x464, 0x464);
+    property.add_span(0x466, 0x466);
+    property.add_span(0x468, 0x468);
+    property.add_span(0x46A, 0x46A);
+    property.add_span(0x46C, 0x46C);
+    property.add_span(0x46E, 0x46E);
+    property.add_span(0x470, 0x470);
+    property.add_span(0x472, 0x472);
+    property.add_span(0x474, 0x474);
+    property.add_span(0x476, 0x476);
+    property.add_span(0x478, 0x478);
+    property.add_span(0x47A, 0x47A);
+    property.add_span(0x47C, 0x47C);
+    property.add_span(0x47E, 0x47E);
+    property.add_span(0x480, 0x480);
+    property.add_span(0x48A, 0x48A);
+    property.add_span(0x48C, 0x48C);
+    property.add_span(0x48E, 0x48E);
+    property.add_span(0x490, 0x490);
+    property.add_span(0x492, 0x492);
+    property.add_span(0x494, 0x494);
+    property.add_span(0x496, 0x496);
+    property.add_span(0x498, 0x498);
+    property.add_span(0x49A, 0x49A);
+    property.add_span(0x49C, 0x49C);
+    property.add_span(0x49E, 0x49E);
+    property.add_span(0x4A0, 0x4A0);
+    property.add_span(0x4A2, 0x4A2);
+    property.add_span(0x4A4, 0x4A4);
+    property.add_span(0x4A6, 0x4A6);
+    property.add_span(0x4A8, 0x4A8);
+    property.add_span(0x4AA, 0x4AA);
+    property.add_span(0x4AC, 0x4AC);
+    property.add_span(0x4AE, 0x4AE);
+    property.add_span(0x4B0, 0x4B0);
+    property.add_span(0x4B2, 0x4B2);
+    property.add_span(0x4B4, 0x4B4);
+    property.add_span(0x4B6, 0x4B6);
+    property.add_span(0x4B8, 0x4B8);
+    property.add_span(0x4BA, 0x4BA);
+    property.add_span(0x4BC, 0x4BC);
+    property.add_span(0x4BE, 0x4BE);
+    property.add_span(0x4C0, 0x4C1);
+    property.add_span(0x4C3, 0x4C3);
+    property.add_span(0x4C5, 0x4C5);
+    property.add_span(0x4C7, 0x4C7);
+    property.add_span(0x4C9, 0x4C9);
+    property.add_span(0x4CB, 0x4CB);
+    property.add_span(0x4CD, 0x4CD);
+    property.add_span(0x4D0, 0x4D0);
+    property.add_span(0x4D2, 0x4D2);
+    property.add_span(0x4D4, 0x4D4);
+    property.add_span(0x4D6, 0x4D6);
+    property.add_span(0x4D8, 0x4D8);
+    property.add_span(0x4DA, 0x4DA);
+    property.add_span(0x4DC, 0x4DC);
+    property.add_span(0x4DE, 0x4DE);
+    property.add_span(0x4E0, 0x4E0);
+    property.add_span(0x4E2, 0x4E2);
+    property.add_span(0x4E4, 0x4E4);
+    property.add_span(0x4E6, 0x4E6);
+    property.add_span(0x4E8, 0x4E8);
+    property.add_span(0x4EA, 0x4EA);
+    property.add_span(0x4EC, 0x4EC);
+    property.add_span(0x4EE, 0x4EE);
+    property.add_span(0x4F0, 0x4F0);
+    property.add_span(0x4F2, 0x4F2);
+    property.add_span(0x4F4, 0x4F4);
+    property.add_span(0x4F6, 0x4F6);
+    property.add_span(0x4F8, 0x4F8);
+    property.add_span(0x4FA, 0x4FA);
+    property.add_span(0x4FC, 0x4FC);
+    property.add_span(0x4FE, 0x4FE);
+    property.add_span(0x500, 0x500);
+    property.add_span(0x502, 0x502);
+    property.add_span(0x504, 0x504);
+    property.add_span(0x506, 0x506);
+    property.add_span(0x508, 0x508);
+    property.add_span(0x50A, 0x50A);
+    property.add_span(0x50C, 0x50C);
+    property.add_span(0x50E, 0x50E);
+    property.add_span(0x510, 0x510);
+    property.add_span(0x512, 0x512);
+    property.add_span(0x514, 0x514);
+    property.add_span(0x516, 0x516);
+    property.add_span(0x518, 0x518);
+    property.add_span(0x51A, 0x51A);
+    property.add_span(0x51C, 0x51C);
+    property.add_span(0x51E, 0x51E);
+    property.add_span(0x520, 0x520);
+    property.add_span(0x522, 0x522);
+    property.add_span(0x524, 0x524);
+    property.add_span(0x526, 0x526);
+    property.add_span(0x528, 0x528);
+    property.add_span(0x52A, 0x52A);
+    property.add_span(0x52C, 0x52C);
+    property.add_span(0x52E, 0x52E);
+    property.add_span(0x531, 0x556);
+    property.add_span(0x10A0, 0x10C5);
+    property.add_span(0x10C7, 0x10C7);
+    property.add_span(0x10CD, 0x10CD);
+    property.add_span(0x13A0, 0x13F5);
+    property.add_span(0x1C89, 0x1C89);
+    property.add_span(0x1C90, 0x1CBA);
+    property.add_span(0x1CBD, 0x1CBF);
+    property.add_span(0x1E00, 0x1E00);
+    property.add_span(0x1E02, 0x1E02);
+    property.add_span(0x1E04, 0x1E04);
+    property.add_span(0x1E06, 0x1E06);
+    property.add_span(0x1E08, 0x1E08);
+    property.add_span(0x1E0A, 0x1E0A);
+    property.add_span(0x1E0C, 0x1E0C);
+    property.add_span(0x1E0E, 0x1E0E);
+    property.add_span(0x1E10, 0x1E10);
+    property.add_span(0x1E12, 0x1E12);
+    property.add_span(0x1E14, 0x1E14);
+    property.add_span(0x1E16, 0x1E16);
+    property.add_span(0x1E18, 0x1E18);
+    property.add_span(0x1E1A, 0x1E1A);
+    property.add_span(0x1E1C, 0x1E1C);
+    property.add_span(0x1E1E, 0x1E1E);
+    property.add_span(0x1E20, 0x1E20);
+    property.add_span(0x1E22, 0x1E22);
+    property.add_span(0x1E24, 0x1E24);
+    property.add_span(0x1E26, 0x1E26);
+    property.add_span(0x1E28, 0x1E28);
+    property.add_span(0x1E2A, 0x1E2A);
+    property.add_span(0x1E2C, 0x1E2C);
+    property.add_span(0x1E2E, 0x1E2E);
+    property.add_span(0x1E30, 0x1E30);
+    property.add_span(0x1E32, 0x1E32);
+    property.add_span(0x1E34, 0x1E34);
+    property.add_span(0x1E36, 0x1E36);
+    property.add_span(0x1E38, 0x1E38);
+    property.add_span(0x1E3A, 0x1E3A);
+    property.add_span(0x1E3C, 0x1E3C);
+    property.add_span(0x1E3E, 0x1E3E);
+    property.add_span(0x1E40, 0x1E40);
+    property.add_span(0x1E42, 0x1E42);
+    property.add_span(0x1E44, 0x1E44);
+    property.add_span(0x1E46, 0x1E46);
+    property.add_span(0x1E48, 0x1E48);
+    property.add_span(0x1E4A, 0x1E4A);
+    property.add_span(0x1E4C, 0x1E4C);
+    property.add_span(0x1E4E, 0x1E4E);
+    property.add_span(0x1E50, 0x1E50);
+    property.add_span(0x1E52, 0x1E52);
+    property.add_span(0x1E54, 0x1E54);
+    property.add_span(0x1E56, 0x1E56);
+    property.add_span(0x1E58, 0x1E58);
+    property.add_span(0x1E5A, 0x1E5A);
+    property.add_span(0x1E5C, 0x1E5C);
+    property.add_span(0x1E5E, 0x1E5E);
+    property.add_span(0x1E60, 0x1E60);
+    property.add_span(0x1E62, 0x1E62);
+    property.add_span(0x1E64, 0x1E64);
+    property.add_span(0x1E66, 0x1E66);
+    property.add_span(0x1E68, 0x1E68);
+    property.add_span(0x1E6A, 0x1E6A);
+    property.add_span(0x1E6C, 0x1E6C);
+    property.add_span(0x1E6E, 0x1E6E);
+    property.add_span(0x1E70, 0x1E70);
+    property.add_span(0x1E72, 0x1E72);
+    property.add_span(0x1E74, 0x1E74);
+    property.add_span(0x1E76, 0x1E76);
+    property.add_span(0x1E78, 0x1E78);
+    property.add_span(0x1E7A, 0x1E7A);
+    property.add_span(0x1E7C, 0x1E7C);
+    property.add_span(0x1E7E, 0x1E7E);
+    property.add_span(0x1E80, 0x1E80);
+    property.add_span(0x1E82, 0x1E82);
+    property.add_span(0x1E84, 0x1E84);
+    property.add_span(0x1E86, 0x1E86);
+    property.add_span(0x1E88, 0x1E88);
+    property.add_span(0x1E8A, 0x1E8A);
+    property.add_span(0x1E8C, 0x1E8C);
+    property.add_span(0x1E8E, 0x1E8E);
+    property.add_span(0x1E90, 0x1E90);
+    property.add_span(0x1E92, 0x1E92);
+    property.add_span(0x1E94, 0x1E94);
+    property.add_span(0x1E9E, 0x1E9E);
+    property.add_span(0x1EA0, 0x1EA0);
+    property.add_span(0x1EA2, 0x1EA2);
+    property.add_span(0x1EA4, 0x1EA4);
+    property.add_span(0x1EA6, 0x1EA6);
+    property.add_span(0x1EA8, 0x1EA8);
+    property.add_span(0x1EAA, 0x1EAA);
+    property.add_span(0x1EAC, 0x1EAC);
+    property.add_span(0x1EAE, 0x1EAE);
+    property.add_span(0x1EB0, 0x1EB0);
+    property.add_span(0x1EB2, 0x1EB2);
+    property.add_span(0x1EB4, 0x1EB4);
+    property.add_span(0x1EB6, 0x1EB6);
+    property.add_span(0x1EB8, 0x1EB8);
+    property.add_span(0x1EBA, 0x1EBA);
+    property.add_span(0x1EBC, 0x1EBC);
+    property.add_span(0x1EBE, 0x1EBE);
+    property.add_span(0x1EC0, 0x1EC0);
+    property.add_span(0x1EC2, 0x1EC2);
+    property.add_span(0x1EC4, 0x1EC4);
+    property.add_span(0x1EC6, 0x1EC6);
+    property.add_span(0x1EC8, 0x1EC8);
+    property.add_span(0x1ECA, 0x1ECA);
+    property.add_span(0x1ECC, 0x1ECC);
+    property.add_span(0x1ECE, 0x1ECE);
+    property.add_span(0x1ED0, 0x1ED0);
+    property.add_span(0x1ED2, 0x1ED2);
+    property.add_span(0x1ED4, 0x1ED4);
+    property.add_span(0x1ED6, 0x1ED6);
+    property.add_span(0x1ED8, 0x1ED8);
+    property.add_span(0x1EDA, 0x1EDA);
+    property.add_span(0x1EDC, 0x1EDC);
+    property.add_span(0x1EDE, 0x1EDE);
+    property.add_span(0x1EE0, 0x1EE0);
+    property.add_span(0x1EE2, 0x1EE2);
+    property.add_span(0x1EE4, 0x1EE4);
+    property.add_span(0x1EE6, 0x1EE6);
+    property.add_span(0x1EE8, 0x1EE8);
+    property.add_span(0x1EEA, 0x1EEA);
+    property.add_span(0x1EEC, 0x1EEC);
+    property.add_span(0x1EEE, 0x1EEE);
+    property.add_span(0x1EF0, 0x1EF0);
+    property.add_span(0x1EF2, 0x1EF2);
+    property.add_span(0x1EF4, 0x1EF4);
+    property.add_span(0x1EF6, 0x1EF6);
+    property.add_span(0x1EF8, 0x1EF8);
+    property.add_span(0x1EFA, 0x1EFA);
+    property.add_span(0x1EFC, 0x1EFC);
+    property.add_span(0x1EFE, 0x1EFE);
+    property.add_span(0x1F08, 0x1F0F);
+    property.add_span(0x1F18, 0x1F1D);
+    property.add_span(0x1F28, 0x1F2F);
+    property.add_span(0x1F38, 0x1F3F);
+    property.add_span(0x1F48, 0x1F4D);
+    property.add_span(0x1F59, 0x1F59);
+    property.add_span(0x1F5B, 0x1F5B);
+    property.add_span(0x1F5D, 0x1F5D);
+    property.add_span(0x1F5F, 0x1F5F);
+    property.add_span(0x1F68, 0x1F6F);
+    property.add_span(0x1FB8, 0x1FBB);
+    property.add_span(0x1FC8, 0x1FCB);
+    property.add_span(0x1FD8, 0x1FDB);
+    property.add_span(0x1FE8, 0x1FEC);
+    property.add_span(0x1FF8, 0x1FFB);
+    property.add_span(0x2102, 0x2102);
+    property.add_span(0x2107, 0x2107);
+    property.add_span(0x210B, 0x210D);
+    property.add_span(0x2110, 0x2112);
+    property.add_span(0x2115, 0x2115);
+    property.add_span(0x2119, 0x211D);
+    property.add_span(0x2124, 0x2124);
+    property.add_span(0x2126, 0x2126);
+    property.add_span(0x2128, 0x2128);
+    property.add_span(0x212A, 0x212D);
+    property.add_span(0x2130, 0x2133);
+    property.add_span(0x213E, 0x213F);
+    property.add_span(0x2145, 0x2145);
+    property.add_span(0x2160, 0x216F);
+    property.add_span(0x2183, 0x2183);
+    property.add_span(0x24B6, 0x24CF);
+    property.add_span(0x2C00, 0x2C2F);
+    property.add_span(0x2C60, 0x2C60);
+    property.add_span(0x2C62, 0x2C64);
+    property.add_span(0x2C67, 0x2C67);
+    property.add_span(0x2C69, 0x2C69);
+    property.add_span(0x2C6B, 0x2C6B);
+    property.add_span(0x2C6D, 0x2C70);
+    property.add_span(0x2C72, 0x2C72);
+    property.add_span(0x2C75, 0x2C75);
+    property.add_span(0x2C7E, 0x2C80);
+    property.add_span(0x2C82, 0x2C82);
+    property.add_span(0x2C84, 0x2C84);
+    property.add_span(0x2C86, 0x2C86);
+    property.add_span(0x2C88, 0x2C88);
+    property.add_span(0x2C8A, 0x2C8A);
+    property.add_span(0x2C8C, 0x2C8C);
+    property.add_span(0x2C8E, 0x2C8E);
+    property.add_span(0x2C90, 0x2C90);
+    property.add_span(0x2C92, 0x2C92);
+    property.add_span(0x2C94, 0x2C94);
+    property.add_span(0x2C96, 0x2C96);
+    property.add_span(0x2C98, 0x2C98);
+    property.add_span(0x2C9A, 0x2C9A);
+    property.add_span(0x2C9C, 0x2C9C);
+    property.add_span(0x2C9E, 0x2C9E);
+    property.add_span(0x2CA0, 0x2CA0);
+    property.add_span(0x2CA2, 0x2CA2);
+    property.add_span(0x2CA4, 0x2CA4);
+    property.add_span(0x2CA6, 0x2CA6);
+    property.add_span(0x2CA8, 0x2CA8);
+    property.add_span(0x2CAA, 0x2CAA);
+    property.add_span(0x2CAC, 0x2CAC);
+    property.add_span(0x2CAE, 0x2CAE);
+    property.add_span(0x2CB0, 0x2CB0);
+    property.add_span(0x2CB2, 0x2CB2);
+    property.add_span(0x2CB4, 0x2CB4);
+    property.add_span(0x2CB6, 0x2CB6);
+    property.add_span(0x2CB8, 0x2CB8);
+    property.add_span(0x2CBA, 0x2CBA);
+    property.add_span(0x2CBC, 0x2CBC);
+    property.add_span(0x2CBE, 0x2CBE);
+    property.add_span(0x2CC0, 0x2CC0);
+    property.add_span(0x2CC2, 0x2CC2);
+    property.add_span(0x2CC4, 0x2CC4);
+    property.add_span(0x2CC6, 0x2CC6);
+    property.add_span(0x2CC8, 0x2CC8);
+    property.add_span(0x2CCA, 0x2CCA);
+    property.add_span(0x2CCC, 0x2CCC);
+    property.add_span(0x2CCE, 0x2CCE);
+    property.add_span(0x2CD0, 0x2CD0);
+    property.add_span(0x2CD2, 0x2CD2);
+    property.add_span(0x2CD4, 0x2CD4);
+    property.add_span(0x2CD6, 0x2CD6);
+    property.add_span(0x2CD8, 0x2CD8);
+    property.add_span(0x2CDA, 0x2CDA);
+    property.add_span(0x2CDC, 0x2CDC);
+    property.add_span(0x2CDE, 0x2CDE);
+    property.add_span(0x2CE0, 0x2CE0);
+    property.add_span(0x2CE2, 0x2CE2);
+    property.add_span(0x2CEB, 0x2CEB);
+    property.add_span(0x2CED, 0x2CED);
+    property.add_span(0x2CF2, 0x2CF2);
+    property.add_span(0xA640, 0xA640);
+    property.add_span(0xA642, 0xA642);
+    property.add_span(0xA644, 0xA644);
+    property.add_span(0xA646, 0xA646);
+    property.add_span(0xA648, 0xA648);
+    property.add_span(0xA64A, 0xA64A);
+    property.add_span(0xA64C, 0xA64C);
+    property.add_span(0xA64E, 0xA64E);
+    property.add_span(0xA650, 0xA650);
+    property.add_span(0xA652, 0xA652);
+    property.add_span(0xA654, 0xA654);
+    property.add_span(0xA656, 0xA656);
+    property.add_span(0xA658, 0xA658);
+    property.add_span(0xA65A, 0xA65A);
+    property.add_span(0xA65C, 0xA65C);
+    property.add_span(0xA65E, 0xA65E);
+    property.add_span(0xA660, 0xA660);
+    property.add_span(0xA662, 0xA662);
+    property.add_span(0xA664, 0xA664);
+    property.add_span(0xA666, 0xA666);
+    property.add_span(0xA668, 0xA668);
+    property.add_span(0xA66A, 0xA66A);
+    property.add_span(0xA66C, 0xA66C);
+    property.add_span(0xA680, 0xA680);
+    property.add_span(0xA682, 0xA682);
+    property.add_span(0xA684, 0xA684);
+    property.add_span(0xA686, 0xA686);
+    property.add_span(0xA688, 0xA688);
+    property.add_span(0xA68A, 0xA68A);
+    property.add_span(0xA68C, 0xA68C);
+    property.add_span(0xA68E, 0xA68E);
+    property.add_span(0xA690, 0xA690);
+    property.add_span(0xA692, 0xA692);
+    property.add_span(0xA694, 0xA694);
+    property.add_span(0xA696, 0xA696);
+    property.add_span(0xA698, 0xA698);
+    property.add_span(0xA69A, 0xA69A);
+    property.add_span(0xA722, 0xA722);
+    property.add_span(0xA724, 0xA724);
+    property.add_span(0xA726, 0xA726);
+    property.add_span(0xA728, 0xA728);
+    property.add_span(0xA72A, 0xA72A);
+    property.add_span(0xA72C, 0xA72C);
+    property.add_span(0xA72E, 0xA72E);
+    property.add_span(0xA732, 0xA732);
+    property.add_span(0xA734, 0xA734);
+    property.add_span(0xA736, 0xA736);
+    property.add_span(0xA738, 0xA738);
+    property.add_span(0xA73A, 0xA73A);
+    property.add_span(0xA73C, 0xA73C);
+    property.add_span(0xA73E, 0xA73E);
+    property.add_span(0xA740, 0xA740);
+    property.add_span(0xA742, 0xA742);
+    property.add_span(0xA744, 0xA744);
+    property.add_span(0xA746, 0xA746);
+    property.add_span(0xA748, 0xA748);
+    property.add_span(0xA74A, 0xA74A);
+    property.add_span(0xA74C, 0xA74C);
+    property.add_span(0xA74E, 0xA74E);
+    property.add_span(0xA750, 0xA750);
+    property.add_span(0xA752, 0xA752);
+    property.add_span(0xA754, 0xA754);
+    property.add_span(0xA756, 0xA756);
+    property.add_span(0xA758, 0xA758);
+    property.add_span(0xA75A, 0xA75A);
+    property.add_span(0xA75C, 0xA75C);
+    property.add_span(0xA75E, 0xA75E);
+    property.add_span(0xA760, 0xA760);
+    property.add_span(0xA762, 0xA762);
+    property.add_span(0xA764, 0xA764);
+    property.add_span(0xA766, 0xA766);
+    property.add_span(0xA768, 0xA768);
+    property.add_span(0xA76A, 0xA76A);
+    property.add_span(0xA76C, 0xA76C);
+    property.add_span(0xA76E, 0xA76E);
+    property.add_span(0xA779, 0xA779);
+    property.add_span(0xA77B, 0xA77B);
+    property.add_span(0xA77D, 0xA77E);
+    property.add_span(0xA780, 0xA780);
+    property.add_span(0xA782, 0xA782);
+    property.add_span(0xA784, 0xA784);
+    property.add_span(0xA786, 0xA786);
+    property.add_span(0xA78B, 0xA78B);
+    property.add_span(0xA78D, 0xA78D);
+    property.add_span(0xA790, 0xA790);
+    property.add_span(0xA792, 0xA792);
+    property.add_span(0xA796, 0xA796);
+    property.add_span(0xA798, 0xA798);
+    property.add_span(0xA79A, 0xA79A);
+    property.add_span(0xA79C, 0xA79C);
+    property.add_span(0xA79E, 0xA79E);
+    property.add_span(0xA7A0, 0xA7A0);
+    property.add_span(0xA7A2, 0xA7A2);
+    property.add_span(0xA7A4, 0xA7A4);
+    property.add_span(0xA7A6, 0xA7A6);
+    property.add_span(0xA7A8, 0xA7A8);
+    property.add_span(0xA7AA, 0xA7AE);
+    property.add_span(0xA7B0, 0xA7B4);
+    property.add_span(0xA7B6, 0xA7B6);
+    property.add_span(0xA7B8, 0xA7B8);
+    property.add_span(0xA7BA, 0xA7BA);
+    property.add_span(0xA7BC, 0xA7BC);
+    property.add_span(0xA7BE, 0xA7BE);
+    property.add_span(0xA7C0, 0xA7C0);
+    property.add_span(0xA7C2, 0xA7C2);
+    property.add_span(0xA7C4, 0xA7C7);
+    property.add_span(0xA7C9, 0xA7C9);
+    property.add_span(0xA7CB, 0xA7CC);
+    property.add_span(0xA7D0, 0xA7D0);
+    property.add_span(0xA7D6, 0xA7D6);
+    property.add_span(0xA7D8, 0xA7D8);
+    property.add_span(0xA7DA, 0xA7DA);
+    property.add_span(0xA7DC, 0xA7DC);
+    property.add_span(0xA7F5, 0xA7F5);
+    property.add_span(0xFF21, 0xFF3A);
+    property.add_span(0x10400, 0x10427);
+    property.add_span(0x104B0, 0x104D3);
+    property.add_span(0x10570, 0x1057A);
+    property.add_span(0x1057C, 0x1058A);
+    property.add_span(0x1058C, 0x10592);
+    property.add_span(0x10594, 0x10595);
+    property.add_span(0x10C80, 0x10CB2);
+    property.add_span(0x10D50, 0x10D65);
+    property.add_span(0x118A0, 0x118BF);
+    property.add_span(0x16E40, 0x16E5F);
+    property.add_span(0x1D400, 0x1D419);
+    property.add_span(0x1D434, 0x1D44D);
+    property.add_span(0x1D468, 0x1D481);
+    property.add_span(0x1D49C, 0x1D49C);
+    property.add_span(0x1D49E, 0x1D49F);
+    property.add_span(0x1D4A2, 0x1D4A2);
+    property.add_span(0x1D4A5, 0x1D4A6);
+    property.add_span(0x1D4A9, 0x1D4AC);
+    property.add_span(0x1D4AE, 0x1D4B5);
+    property.add_span(0x1D4D0, 0x1D4E9);
+    property.add_span(0x1D504, 0x1D505);
+    property.add_span(0x1D507, 0x1D50A);
+    property.add_span(0x1D50D, 0x1D514);
+    property.add_span(0x1D516, 0x1D51C);
+    property.add_span(0x1D538, 0x1D539);
+    property.add_span(0x1D53B, 0x1D53E);
+    property.add_span(0x1D540, 0x1D544);
+    property.add_span(0x1D546, 0x1D546);
+    property.add_span(0x1D54A, 0x1D550);
+    property.add_span(0x1D56C, 0x1D585);
+    property.add_span(0x1D5A0, 0x1D5B9);
+    property.add_span(0x1D5D4, 0x1D5ED);
+    property.add_span(0x1D608, 0x1D621);
+    property.add_span(0x1D63C, 0x1D655);
+    property.add_span(0x1D670, 0x1D689);
+    property.add_span(0x1D6A8, 0x1D6C0);
+    property.add_span(0x1D6E2, 0x1D6FA);
+    property.add_span(0x1D71C, 0x1D734);
+    property.add_span(0x1D756, 0x1D76E);
+    property.add_span(0x1D790, 0x1D7A8);
+    property.add_span(0x1D7CA, 0x1D7CA);
+    property.add_span(0x1E900, 0x1E921);
+    property.add_span(0x1F130, 0x1F149);
+    property.add_span(0x1F150, 0x1F169);
+    property.add_span(0x1F170, 0x1F189);
+    db.insert("Uppercase", property);
+    let mut property = Category::new("White_Space");
+    property.add_span(0x9, 0xD);
+    property.add_span(0x20, 0x20);
+    property.add_span(0x85, 0x85);
+    property.add_span(0xA0, 0xA0);
+    property.add_span(0x1680, 0x1680);
+    property.add_span(0x2000, 0x200A);
+    property.add_span(0x2028, 0x2029);
+    property.add_span(0x202F, 0x202F);
+    property.add_span(0x205F, 0x205F);
+    property.add_span(0x3000, 0x3000);
+    db.insert("White_Space", property);
+    let mut property = Category::new("XID_Continue");
+    property.add_span(0x30, 0x39);
+    property.add_span(0x41, 0x5A);
+    property.add_span(0x5F, 0x5F);
+    property.add_span(0x61, 0x7A);
+    property.add_span(0xAA, 0xAA);
+    property.add_span(0xB5, 0xB5);
+    property.add_span(0xB7, 0xB7);
+    property.add_span(0xBA, 0xBA);
+    property.add_span(0xC0, 0xD6);
+    property.add_span(0xD8, 0xF6);
+    property.add_span(0xF8, 0x2C1);
+    property.add_span(0x2C6, 0x2D1);
+    property.add_span(0x2E0, 0x2E4);
+    property.add_span(0x2EC, 0x2EC);
+    property.add_span(0x2EE, 0x2EE);
+    property.add_span(0x300, 0x374);
+    property.add_span(0x376, 0x377);
+    property.add_span(0x37B, 0x37D);
+    property.add_span(0x37F, 0x37F);
+    property.add_span(0x386, 0x38A);
+    property.add_span(0x38C, 0x38C);
+    property.add_span(0x38E, 0x3A1);
+    property.add_span(0x3A3, 0x3F5);
+    property.add_span(0x3F7, 0x481);
+    property.add_span(0x483, 0x487);
+    property.add_span(0x48A, 0x52F);
+    property.add_span(0x531, 0x556);
+    property.add_span(0x559, 0x559);
+    property.add_span(0x560, 0x588);
+    property.add_span(0x591, 0x5BD);
+    property.add_span(0x5BF, 0x5BF);
+    property.add_span(0x5C1, 0x5C2);
+    property.add_span(0x5C4, 0x5C5);
+    property.add_span(0x5C7, 0x5C7);
+    property.add_span(0x5D0, 0x5EA);
+    property.add_span(0x5EF, 0x5F2);
+    property.add_span(0x610, 0x61A);
+    property.add_span(0x620, 0x669);
+    property.add_span(0x66E, 0x6D3);
+    property.add_span(0x6D5, 0x6DC);
+    property.add_span(0x6DF, 0x6E8);
+    property.add_span(0x6EA, 0x6FC);
+    property.add_span(0x6FF, 0x6FF);
+    property.add_span(0x710, 0x74A);
+    property.add_span(0x74D, 0x7B1);
+    property.add_span(0x7C0, 0x7F5);
+    property.add_span(0x7FA, 0x7FA);
+    property.add_span(0x7FD, 0x7FD);
+    property.add_span(0x800, 0x82D);
+    property.add_span(0x840, 0x85B);
+    property.add_span(0x860, 0x86A);
+    property.add_span(0x870, 0x887);
+    property.add_span(0x889, 0x88E);
+    property.add_span(0x897, 0x8E1);
+    property.add_span(0x8E3, 0x963);
+    property.add_span(0x966, 0x96F);
+    property.add_span(0x971, 0x983);
+    property.add_span(0x985, 0x98C);
+    property.add_span(0x98F, 0x990);
+    property.add_span(0x993, 0x9A8);
+    property.add_span(0x9AA, 0x9B0);
+    property.add_span(0x9B2, 0x9B2);
+    property.add_span(0x9B6, 0x9B9);
+    property.add_span(0x9BC, 0x9C4);
+    property.add_span(0x9C7, 0x9C8);
+    property.add_span(0x9CB, 0x9CE);
+    property.add_span(0x9D7, 0x9D7);
+    property.add_span(0x9DC, 0x9DD);
+    property.add_span(0x9DF, 0x9E3);
+    property.add_span(0x9E6, 0x9F1);
+    property.add_span(0x9FC, 0x9FC);
+    property.add_span(0x9FE, 0x9FE);
+    property.add_span(0xA01, 0xA03);
+    property.add_span(0xA05, 0xA0A);
+    property.add_span(0xA0F, 0xA10);
+    property.add_span(0xA13, 0xA28);
+    property.add_span(0xA2A, 0xA30);
+    property.add_span(0xA32, 0xA33);
+    property.add_span(0xA35, 0xA36);
+    property.add_span(0xA38, 0xA39);
+    property.add_span(0xA3C, 0xA3C);
+    property.add_span(0xA3E, 0xA42);
+    property.add_span(0xA47, 0xA48);
+    property.add_span(0xA4B, 0xA4D);
+    property.add_span(0xA51, 0xA51);
+    property.add_span(0xA59, 0xA5C);
+    property.add_span(0xA5E, 0xA5E);
+    property.add_span(0xA66, 0xA75);
+    property.add_span(0xA81, 0xA83);
+    property.add_span(0xA85, 0xA8D);
+    property.add_span(0xA8F, 0xA91);
+    property.add_span(0xA93, 0xAA8);
+    property.add_span(0xAAA, 0xAB0);
+    property.add_span(0xAB2, 0xAB3);
+    property.add_span(0xAB5, 0xAB9);
+    property.add_span(0xABC, 0xAC5);
+    property.add_span(0xAC7, 0xAC9);
+    property.add_span(0xACB, 0xACD);
+    property.add_span(0xAD0, 0xAD0);
+    property.add_span(0xAE0, 0xAE3);
+    property.add_span(0xAE6, 0xAEF);
+    property.add_span(0xAF9, 0xAFF);
+    property.add_span(0xB01, 0xB03);
+    property.add_span(0xB05, 0xB0C);
+    property.add_span(0xB0F, 0xB10);
+    property.add_span(0xB13, 0xB28);
+    property.add_span(0xB2A, 0xB30);
+    property.add_span(0xB32, 0xB33);
+    property.add_span(0xB35, 0xB39);
+    property.add_span(0xB3C, 0xB44);
+    property.add_span(0xB47, 0xB48);
+    property.add_span(0xB4B, 0xB4D);
+    property.add_span(0xB55, 0xB57);
+    property.add_span(0xB5C, 0xB5D);
+    property.add_span(0xB5F, 0xB63);
+    property.add_span(0xB66, 0xB6F);
+    property.add_span(0xB71, 0xB71);
+    property.add_span(0xB82, 0xB83);
+    property.add_span(0xB85, 0xB8A);
+    property.add_span(0xB8E, 0xB90);
+    property.add_span(0xB92, 0xB95);
+    property.add_span(0xB99, 0xB9A);
+    property.add_span(0xB9C, 0xB9C);
+    property.add_span(0xB9E, 0xB9F);
+    property.add_span(0xBA3, 0xBA4);
+    property.add_span(0xBA8, 0xBAA);
+    property.add_span(0xBAE, 0xBB9);
+    property.add_span(0xBBE, 0xBC2);
+    property.add_span(0xBC6, 0xBC8);
+    property.add_span(0xBCA, 0xBCD);
+    property.add_span(0xBD0, 0xBD0);
+    property.add_span(0xBD7, 0xBD7);
+    property.add_span(0xBE6, 0xBEF);
+    property.add_span(0xC00, 0xC0C);
+    property.add_span(0xC0E, 0xC10);
+    property.add_span(0xC12, 0xC28);
+    property.add_span(0xC2A, 0xC39);
+    property.add_span(0xC3C, 0xC44);
+    property.add_span(0xC46, 0xC48);
+    property.add_span(0xC4A, 0xC4D);
+    property.add_span(0xC55, 0xC56);
+    property.add_span(0xC58, 0xC5A);
+    property.add_span(0xC5D, 0xC5D);
+    property.add_span(0xC60, 0xC63);
+    property.add_span(0xC66, 0xC6F);
+    property.add_span(0xC80, 0xC83);
+    property.add_span(0xC85, 0xC8C);
+    property.add_span(0xC8E, 0xC90);
+    property.add_span(0xC92, 0xCA8);
+    property.add_span(0xCAA, 0xCB3);
+    property.add_span(0xCB5, 0xCB9);
+    property.add_span(0xCBC, 0xCC4);
+    property.add_span(0xCC6, 0xCC8);
+    property.add_span(0xCCA, 0xCCD);
+    property.add_span(0xCD5, 0xCD6);
+    property.add_span(0xCDD, 0xCDE);
+    property.add_span(0xCE0, 0xCE3);
+    property.add_span(0xCE6, 0xCEF);
+    property.add_span(0xCF1, 0xCF3);
+    property.add_span(0xD00, 0xD0C);
+    property.add_span(0xD0E, 0xD10);
+    property.add_span(0xD12, 0xD44);
+    property.add_span(0xD46, 0xD48);
+    property.add_span(0xD4A, 0xD4E);
+    property.add_span(0xD54, 0xD57);
+    property.add_span(0xD5F, 0xD63);
+    property.add_span(0xD66, 0xD6F);
+    property.add_span(0xD7A, 0xD7F);
+    property.add_span(0xD81, 0xD83);
+    property.add_span(0xD85, 0xD96);
+    property.add_span(0xD9A, 0xDB1);
+    property.add_span(0xDB3, 0xDBB);
+    property.add_span(0xDBD, 0xDBD);
+    property.add_span(0xDC0, 0xDC6);
+    property.add_span(0xDCA, 0xDCA);
+    property.add_span(0xDCF, 0xDD4);
+    property.add_span(0xDD6, 0xDD6);
+    property.add_span(0xDD8, 0xDDF);
+    property.add_span(0xDE6, 0xDEF);
+    property.add_span(0xDF2, 0xDF3);
+    property.add_span(0xE01, 0xE3A);
+    property.add_span(0xE40, 0xE4E);
+    property.add_span(0xE50, 0xE59);
+    property.add_span(0xE81, 0xE82);
+    property.add_span(0xE84, 0xE84);
+    property.add_span(0xE86, 0xE8A);
+    property.add_span(0xE8C, 0xEA3);
+    property.add_span(0xEA5, 0xEA5);
+    property.add_span(0xEA7, 0xEBD);
+    property.add_span(0xEC0, 0xEC4);
+    property.add_span(0xEC6, 0xEC6);
+    property.add_span(0xEC8, 0xECE);
+    property.add_span(0xED0, 0xED9);
+    property.add_span(0xEDC, 0xEDF);
+    property.add_span(0xF00, 0xF00);
+    property.add_span(0xF18, 0xF19);
+    property.add_span(0xF20, 0xF29);
+    property.add_span(0xF35, 0xF35);
+    property.add_span(0xF37, 0xF37);
+    property.add_span(0xF39, 0xF39);
+    property.add_span(0xF3E, 0xF47);
+    property.add_span(0xF49, 0xF6C);
+    property.add_span(0xF71, 0xF84);
+    property.add_span(0xF86, 0xF97);
+    property.add_span(0xF99, 0xFBC);
+    property.add_span(0xFC6, 0xFC6);
+    property.add_span(0x1000, 0x1049);
+    property.add_span(0x1050, 0x109D);
+    property.add_span(0x10A0, 0x10C5);
+    property.add_span(0x10C7, 0x10C7);
+    property.add_span(0x10CD, 0x10CD);
+    property.add_span(0x10D0, 0x10FA);
+    property.add_span(0x10FC, 0x1248);
+    property.add_span(0x124A, 0x124D);
+    property.add_span(0x1250, 0x1256);
+    property.add_span(0x1258, 0x1258);
+    property.add_span(0x125A, 0x125D);
+    property.add_span(0x1260, 0x1288);
+    property.add_span(0x128A, 0x128D);
+    property.add_span(0x1290, 0x12B0);
+    property.add_span(0x12B2, 0x12B5);
+    property.add_span(0x12B8, 0x12BE);
+    property.add_span(0x12C0, 0x12C0);
+    property.add_span(0x12C2, 0x12C5);
+    property.add_span(0x12C8, 0x12D6);
+    property.add_span(0x12D8, 0x1310);
+    property.add_span(0x1312, 0x1315);
+    property.add_span(0x1318, 0x135A);
+    property.add_span(0x135D, 0x135F);
+    property.add_span(0x1369, 0x1371);
+    property.add_span(0x1380, 0x138F);
+    property.add_span(0x13A0, 0x13F5);
+    property.add_span(0x13F8, 0x13FD);
+    property.add_span(0x1401, 0x166C);
+    property.add_span(0x166F, 0x167F);
+    property.add_span(0x1681, 0x169A);
+    property.add_span(0x16A0, 0x16EA);
+    property.add_span(0x16EE, 0x16F8);
+    property.add_span(0x1700, 0x1715);
+    property.add_span(0x171F, 0x1734);
+    property.add_span(0x1740, 0x1753);
+    property.add_span(0x1760, 0x176C);
+    property.add_span(0x176E, 0x1770);
+    property.add_span(0x1772, 0x1773);
+    property.add_span(0x1780, 0x17D3);
+    property.add_span(0x17D7, 0x17D7);
+    property.add_span(0x17DC, 0x17DD);
+    property.add_span(0x17E0, 0x17E9);
+    property.add_span(0x180B, 0x180D);
+    property.add_span(0x180F, 0x1819);
+    property.add_span(0x1820, 0x1878);
+    property.add_span(0x1880, 0x18AA);
+    property.add_span(0x18B0, 0x18F5);
+    property.add_span(0x1900, 0x191E);
+    property.add_span(0x1920, 0x192B);
+    property.add_span(0x1930, 0x193B);
+    property.add_span(0x1946, 0x196D);
+    property.add_span(0x1970, 0x1974);
+    property.add_span(0x1980, 0x19AB);
+    property.add_span(0x19B0, 0x19C9);
+    property.add_span(0x19D0, 0x19DA);
+    property.add_span(0x1A00, 0x1A1B);
+    property.add_span(0x1A20, 0x1A5E);
+    property.add_span(0x1A60, 0x1A7C);
+    property.add_span(0x1A7F, 0x1A89);
+    property.add_span(0x1A90, 0x1A99);
+    property.add_span(0x1AA7, 0x1AA7);
+    property.add_span(0x1AB0, 0x1ABD);
+    property.add_span(0x1ABF, 0x1ACE);
+    property.add_span(0x1B00, 0x1B4C);
+    property.add_span(0x1B50, 0x1B59);
+    property.add_span(0x1B6B, 0x1B73);
+    property.add_span(0x1B80, 0x1BF3);
+    property.add_span(0x1C00, 0x1C37);
+    property.add_span(0x1C40, 0x1C49);
+    property.add_span(0x1C4D, 0x1C7D);
+    property.add_span(0x1C80, 0x1C8A);
+    property.add_span(0x1C90, 0x1CBA);
+    property.add_span(0x1CBD, 0x1CBF);
+    property.add_span(0x1CD0, 0x1CD2);
+    property.add_span(0x1CD4, 0x1CFA);
+    property.add_span(0x1D00, 0x1F15);
+    property.add_span(0x1F18, 0x1F1D);
+    property.add_span(0x1F20, 0x1F45);
+    property.add_span(0x1F48, 0x1F4D);
+    property.add_span(0x1F50, 0x1F57);
+    property.add_span(0x1F59, 0x1F59);
+    property.add_span(0x1F5B, 0x1F5B);
+    property.add_span(0x1F5D, 0x1F5D);
+    property.add_span(0x1F5F, 0x1F7D);
+    property.add_span(0x1F80, 0x1FB4);
+    property.add_span(0x1FB6, 0x1FBC);
+    property.add_span(0x1FBE, 0x1FBE);
+    property.add_span(0x1FC2, 0x1FC4);
+    property.add_span(0x1FC6, 0x1FCC);
+    property.add_span(0x1FD0, 0x1FD3);
+    property.add_span(0x1FD6, 0x1FDB);
+    property.add_span(0x1FE0, 0x1FEC);
+    property.add_span(0x1FF2, 0x1FF4);
+    property.add_span(0x1FF6, 0x1FFC);
+    property.add_span(0x200C, 0x200D);
+    property.add_span(0x203F, 0x2040);
+    property.add_span(0x2054, 0x2054);
+    property.add_span(0x2071, 0x2071);
+    property.add_span(0x207F, 0x207F);
+    property.add_span(0x2090, 0x209C);
+    property.add_span(0x20D0, 0x20DC);
+    property.add_span(0x20E1, 0x20E1);
+    property.add_span(0x20E5, 0x20F0);
+    property.add_span(0x2102, 0x2102);
+    property.add_span(0x2107, 0x2107);
+    property.add_span(0x210A, 0x2113);
+    property.add_span(0x2115, 0x2115);
+    property.add_span(0x2118, 0x211D);
+    property.add_span(0x2124, 0x2124);
+    property.add_span(0x2126, 0x2126);
+    property.add_span(0x2128, 0x2128);
+    property.add_span(0x212A, 0x2139);
+    property.add_span(0x213C, 0x213F);
+    property.add_span(0x2145, 0x2149);
+    property.add_span(0x214E, 0x214E);
+    property.add_span(0x2160, 0x2188);
+    property.add_span(0x2C00, 0x2CE4);
+    property.add_span(0x2CEB, 0x2CF3);
+    property.add_span(0x2D00, 0x2D25);
+    property.add_span(0x2D27, 0x2D27);
+    property.add_span(0x2D2D, 0x2D2D);
+    property.add_span(0x2D30, 0x2D67);
+    property.add_span(0x2D6F, 0x2D6F);
+    property.add_span(0x2D7F, 0x2D96);
+    property.add_span(0x2DA0, 0x2DA6);
+    property.add_span(0x2DA8, 0x2DAE);
+    property.add_span(0x2DB0, 0x2DB6);
+    property.add_span(0x2DB8, 0x2DBE);
+    property.add_span(0x2DC0, 0x2DC6);
+    property.add_span(0x2DC8, 0x2DCE);
+    property.add_span(0x2DD0, 0x2DD6);
+    property.add_span(0x2DD8, 0x2DDE);
+    property.add_span(0x2DE0, 0x2DFF);
+    property.add_span(0x3005, 0x3007);
+    property.add_span(0x3021, 0x302F);
+    property.add_span(0x3031, 0x3035);
+    property.add_span(0x3038, 0x303C);
+    property.add_span(0x3041, 0x3096);
+    property.add_span(0x3099, 0x309A);
+    property.add_span(0x309D, 0x309F);
+    property.add_span(0x30A1, 0x30FF);
+    property.add_span(0x3105, 0x312F);
+    property.add_span(0x3131, 0x318E);
+    property.add_span(0x31A0, 0x31BF);
+    property.add_span(0x31F0, 0x31FF);
+    property.add_span(0x3400, 0x4DBF);
+    property.add_span(0x4E00, 0xA48C);
+    property.add_span(0xA4D0, 0xA4FD);
+    property.add_span(0xA500, 0xA60C);
+    property.add_span(0xA610, 0xA62B);
+    property.add_span(0xA640, 0xA66F);
+    property.add_span(0xA674, 0xA67D);
+    property.add_span(0xA67F, 0xA6F1);
+    property.add_span(0xA717, 0xA71F);
+    property.add_span(0xA722, 0xA788);
+    property.add_span(0xA78B, 0xA7CD);
+    property.add_span(0xA7D0, 0xA7D1);
+    property.add_span(0xA7D3, 0xA7D3);
+    property.add_span(0xA7D5, 0xA7DC);
+    property.add_span(0xA7F2, 0xA827);
+    property.add_span(0xA82C, 0xA82C);
+    property.add_span(0xA840, 0xA873);
+    property.add_span(0xA880, 0xA8C5);
+    property.add_span(0xA8D0, 0xA8D9);
+    property.add_span(0xA8E0, 0xA8F7);
+    property.add_span(0xA8FB, 0xA8FB);
+    property.add_span(0xA8FD, 0xA92D);
+    property.add_span(0xA930, 0xA953);
+    property.add_span(0xA960, 0xA97C);
+    property.add_span(0xA980, 0xA9C0);
+    property.add_span(0xA9CF, 0xA9D9);
+    property.add_span(0xA9E0, 0xA9FE);
+    property.add_span(0xAA00, 0xAA36);
+    property.add_span(0xAA40, 0xAA4D);
+    property.add_span(0xAA50, 0xAA59);
+    property.add_span(0xAA60, 0xAA76);
+    property.add_span(0xAA7A, 0xAAC2);
+    property.add_span(0xAADB, 0xAADD);
+    property.add_span(0xAAE0, 0xAAEF);
+    property.add_span(0xAAF2, 0xAAF6);
+    property.add_span(0xAB01, 0xAB06);
+    property.add_span(0xAB09, 0xAB0E);
+    property.add_span(0xAB11, 0xAB16);
+    property.add_span(0xAB20, 0xAB26);
+    property.add_span(0xAB28, 0xAB2E);
+    property.add_span(0xAB30, 0xAB5A);
+    property.add_span(0xAB5C, 0xAB69);
+    property.add_span(0xAB70, 0xABEA);
+    property.add_span(0xABEC, 0xABED);
+    property.add_span(0xABF0, 0xABF9);
+    property.add_span(0xAC00, 0xD7A3);
+    property.add_span(0xD7B0, 0xD7C6);
+    property.add_span(0xD7CB, 0xD7FB);
+    property.add_span(0xF900, 0xFA6D);
+    property.add_span(0xFA70, 0xFAD9);
+    property.add_span(0xFB00, 0xFB06);
+    property.add_span(0xFB13, 0xFB17);
+    property.add_span(0xFB1D, 0xFB28);
+    property.add_span(0xFB2A, 0xFB36);
+    property.add_span(0xFB38, 0xFB3C);
+    property.add_span(0xFB3E, 0xFB3E);
+    property.add_span(0xFB40, 0xFB41);
+    property.add_span(0xFB43, 0xFB44);
+    property.add_span(0xFB46, 0xFBB1);
+    property.add_span(0xFBD3, 0xFC5D);
+    property.add_span(0xFC64, 0xFD3D);
+    property.add_span(0xFD50, 0xFD8F);
+    property.add_span(0xFD92, 0xFDC7);
+    property.add_span(0xFDF0, 0xFDF9);
+    property.add_span(0xFE00, 0xFE0F);
+    property.add_span(0xFE20, 0xFE2F);
+    property.add_span(0xFE33, 0xFE34);
+    property.add_span(0xFE4D, 0xFE4F);
+    property.add_span(0xFE71, 0xFE71);
+    property.add_span(0xFE73, 0xFE73);
+    property.add_span(0xFE77, 0xFE77);
+    property.add_span(0xFE79, 0xFE79);
+    property.add_span(0xFE7B, 0xFE7B);
+    property.add_span(0xFE7D, 0xFE7D);
+    property.add_span(0xFE7F, 0xFEFC);
+    property.add_span(0xFF10, 0xFF19);
+    property.add_span(0xFF21, 0xFF3A);
+    property.add_span(0xFF3F, 0xFF3F);
+    property.add_span(0xFF41, 0xFF5A);
+    property.add_span(0xFF65, 0xFFBE);
+    property.add_span(0xFFC2, 0xFFC7);
+    property.add_span(0xFFCA, 0xFFCF);
+    property.add_span(0xFFD2, 0xFFD7);
+    property.add_span(0xFFDA, 0xFFDC);
+    property.add_span(0x10000, 0x1000B);
+    property.add_span(0x1000D, 0x10026);
+    property.add_span(0x10028, 0x1003A);
+    property.add_span(0x1003C, 0x1003D);
+    property.add_span(0x1003F, 0x1004D);
+    property.add_span(0x10050, 0x1005D);
+    property.add_span(0x10080, 0x100FA);
+    property.add_span(0x10140, 0x10174);
+    property.add_span(0x101FD, 0x101FD);
+    property.add_span(0x10280, 0x1029C);
+    property.add_span(0x102A0, 0x102D0);
+    property.add_span(0x102E0, 0x102E0);
+    property.add_span(0x10300, 0x1031F);
+    property.add_span(0x1032D, 0x1034A);
+    property.add_span(0x10350, 0x1037A);
+    property.add_span(0x10380, 0x1039D);
+    property.add_span(0x103A0, 0x103C3);
+    property.add_span(0x103C8, 0x103CF);
+    property.add_span(0x103D1, 0x103D5);
+    property.add_span(0x10400, 0x1049D);
+    property.add_span(0x104A0, 0x104A9);
+    property.add_span(0x104B0, 0x104D3);
+    property.add_span(0x104D8, 0x104FB);
+    property.add_span(0x10500, 0x10527);
+    property.add_span(0x10530, 0x10563);
+    property.add_span(0x10570, 0x1057A);
+    property.add_span(0x1057C, 0x1058A);
+    property.add_span(0x1058C, 0x10592);
+    property.add_span(0x10594, 0x10595);
+    property.add_span(0x10597, 0x105A1);
+    property.add_span(0x105A3, 0x105B1);
+    property.add_span(0x105B3, 0x105B9);
+    property.add_span(0x105BB, 0x105BC);
+    property.add_span(0x105C0, 0x105F3);
+    property.add_span(0x10600, 0x10736);
+    property.add_span(0x10740, 0x10755);
+    property.add_span(0x10760, 0x10767);
+    property.add_span(0x10780, 0x10785);
+    property.add_span(0x10787, 0x107B0);
+    property.add_span(0x107B2, 0x107BA);
+    property.add_span(0x10800, 0x10805);
+    property.add_span(0x10808, 0x10808);
+    property.add_span(0x1080A, 0x10835);
+    property.add_span(0x10837, 0x10838);
+    property.add_span(0x1083C, 0x1083C);
+    property.add_span(0x1083F, 0x10855);
+    property.add_span(0x10860, 0x10876);
+    property.add_span(0x10880, 0x1089E);
+    property.add_span(0x108E0, 0x108F2);
+    property.add_span(0x108F4, 0x108F5);
+    property.add_span(0x10900, 0x10915);
+    property.add_span(0x10920, 0x10939);
+    property.add_span(0x10980, 0x109B7);
+    property.add_span(0x109BE, 0x109BF);
+    property.add_span(0x10A00, 0x10A03);
+    property.add_span(0x10A05, 0x10A06);
+    property.add_span(0x10A0C, 0x10A13);
+    property.add_span(0x10A15, 0x10A17);
+    property.add_span(0x10A19, 0x10A35);
+    property.add_span(0x10A38, 0x10A3A);
+    property.add_span(0x10A3F, 0x10A3F);
+    property.add_span(0x10A60, 0x10A7C);
+    property.add_span(0x10A80, 0x10A9C);
+    property.add_span(0x10AC0, 0x10AC7);
+    property.add_span(0x10AC9, 0x10AE6);
+    property.add_span(0x10B00, 0x10B35);
+    property.add_span(0x10B40, 0x10B55);
+    property.add_span(0x10B60, 0x10B72);
+    property.add_span(0x10B80, 0x10B91);
+    property.add_span(0x10C00, 0x10C48);
+    property.add_span(0x10C80, 0x10CB2);
+    property.add_span(0x10CC0, 0x10CF2);
+    property.add_span(0x10D00, 0x10D27);
+    property.add_span(0x10D30, 0x10D39);
+    property.add_span(0x10D40, 0x10D65);
+    property.add_span(0x10D69, 0x10D6D);
+    property.add_span(0x10D6F, 0x10D85);
+    property.add_span(0x10E80, 0x10EA9);
+    property.add_span(0x10EAB, 0x10EAC);
+    property.add_span(0x10EB0, 0x10EB1);
+    property.add_span(0x10EC2, 0x10EC4);
+    property.add_span(0x10EFC, 0x10F1C);
+    property.add_span(0x10F27, 0x10F27);
+    property.add_span(0x10F30, 0x10F50);
+    property.add_span(0x10F70, 0x10F85);
+    property.add_span(0x10FB0, 0x10FC4);
+    property.add_span(0x10FE0, 0x10FF6);
+    property.add_span(0x11000, 0x11046);
+    property.add_span(0x11066, 0x11075);
+    property.add_span(0x1107F, 0x110BA);
+    property.add_span(0x110C2, 0x110C2);
+    property.add_span(0x110D0, 0x110E8);
+    property.add_span(0x110F0, 0x110F9);
+    property.add_span(0x11100, 0x11134);
+    property.add_span(0x11136, 0x1113F);
+    property.add_span(0x11144, 0x11147);
+    property.add_span(0x11150, 0x11173);
+    property.add_span(0x11176, 0x11176);
+    property.add_span(0x11180, 0x111C4);
+    property.add_span(0x111C9, 0x111CC);
+    property.add_span(0x111CE, 0x111DA);
+    property.add_span(0x111DC, 0x111DC);
+    property.add_span(0x11200, 0x11211);
+    property.add_span(0x11213, 0x11237);
+    property.add_span(0x1123E, 0x11241);
+    property.add_span(0x11280, 0x11286);
+    property.add_span(0x11288, 0x11288);
+    property.add_span(0x1128A, 0x1128D);
+    property.add_span(0x1128F, 0x1129D);
+    property.add_span(0x1129F, 0x112A8);
+    property.add_span(0x112B0, 0x112EA);
+    property.add_span(0x112F0, 0x112F9);
+    property.add_span(0x11300, 0x11303);
+    property.add_span(0x11305, 0x1130C);
+    property.add_span(0x1130F, 0x11310);
+    property.add_span(0x11313, 0x11328);
+    property.add_span(0x1132A, 0x11330);
+    property.add_span(0x11332, 0x11333);
+    property.add_span(0x11335, 0x11339);
+    property.add_span(0x1133B, 0x11344);
+    property.add_span(0x11347, 0x11348);
+    property.add_span(0x1134B, 0x1134D);
+    property.add_span(0x11350, 0x11350);
+    property.add_span(0x11357, 0x11357);
+    property.add_span(0x1135D, 0x11363);
+    property.add_span(0x11366, 0x1136C);
+    property.add_span(0x11370, 0x11374);
+    property.add_span(0x11380, 0x11389);
+    property.add_span(0x1138B, 0x1138B);
+    property.add_span(0x1138E, 0x1138E);
+    property.add_span(0x11390, 0x113B5);
+    property.add_span(0x113B7, 0x113C0);
+    property.add_span(0x113C2, 0x113C2);
+    property.add_span(0x113C5, 0x113C5);
+    property.add_span(0x113C7, 0x113CA);
+    property.add_span(0x113CC, 0x113D3);
+    property.add_span(0x113E1, 0x113E2);
+    property.add_span(0x11400, 0x1144A);
+    property.add_span(0x11450, 0x11459);
+    property.add_span(0x1145E, 0x11461);
+    property.add_span(0x11480, 0x114C5);
+    property.add_span(0x114C7, 0x114C7);
+    property.add_span(0x114D0, 0x114D9);
+    property.add_span(0x11580, 0x115B5);
+    property.add_span(0x115B8, 0x115C0);
+    property.add_span(0x115D8, 0x115DD);
+    property.add_span(0x11600, 0x11640);
+    property.add_span(0x11644, 0x11644);
+    property.add_span(0x11650, 0x11659);
+    property.add_span(0x11680, 0x116B8);
+    property.add_span(0x116C0, 0x116C9);
+    property.add_span(0x116D0, 0x116E3);
+    property.add_span(0x11700, 0x1171A);
+    property.add_span(0x1171D, 0x1172B);
+    property.add_span(0x11730, 0x11739);
+    property.add_span(0x11740, 0x11746);
+    property.add_span(0x11800, 0x1183A);
+    property.add_span(0x118A0, 0x118E9);
+    property.add_span(0x118FF, 0x11906);
+    property.add_span(0x11909, 0x11909);
+    property.add_span(0x1190C, 0x11913);
+    property.add_span(0x11915, 0x11916);
+    property.add_span(0x11918, 0x11935);
+    property.add_span(0x11937, 0x11938);
+    property.add_span(0x1193B, 0x11943);
+    property.add_span(0x11950, 0x11959);
+    property.add_span(0x119A0, 0x119A7);
+    property.add_span(0x119AA, 0x119D7);
+    property.add_span(0x119DA, 0x119E1);
+    property.add_span(0x119E3, 0x119E4);
+    property.add_span(0x11A00, 0x11A3E);
+    property.add_span(0x11A47, 0x11A47);
+    property.add_span(0x11A50, 0x11A99);
+    property.add_span(0x11A9D, 0x11A9D);
+    property.add_span(0x11AB0, 0x11AF8);
+    property.add_span(0x11BC0, 0x11BE0);
+    property.add_span(0x11BF0, 0x11BF9);
+    property.add_span(0x11C00, 0x11C08);
+    property.add_span(0x11C0A, 0x11C36);
+    property.add_span(0x11C38, 0x11C40);
+    property.add_span(0x11C50, 0x11C59);
+    property.add_span(0x11C72, 0x11C8F);
+    property.add_span(0x11C92, 0x11CA7);
+    property.add_span(0x11CA9, 0x11CB6);
+    property.add_span(0x11D00, 0x11D06);
+    property.add_span(0x11D08, 0x11D09);
+    property.add_span(0x11D0B, 0x11D36);
+    property.add_span(0x11D3A, 0x11D3A);
+    property.add_span(0x11D3C, 0x11D3D);
+    property.add_span(0x11D3F, 0x11D47);
+    property.add_span(0x11D50, 0x11D59);
+    property.add_span(0x11D60, 0x11D65);
+    property.add_span(0x11D67, 0x11D68);
+    property.add_span(0x11D6A, 0x11D8E);
+    property.add_span(0x11D90, 0x11D91);
+    property.add_span(0x11D93, 0x11D98);
+    property.add_span(0x11DA0, 0x11DA9);
+    property.add_span(0x11EE0, 0x11EF6);
+    property.add_span(0x11F00, 0x11F10);
+    property.add_span(0x11F12, 0x11F3A);
+    property.add_span(0x11F3E, 0x11F42);
+    property.add_span(0x11F50, 0x11F5A);
+    property.add_span(0x11FB0, 0x11FB0);
+    property.add_span(0x12000, 0x12399);
+    property.add_span(0x12400, 0x1246E);
+    property.add_span(0x12480, 0x12543);
+    property.add_span(0x12F90, 0x12FF0);
+    property.add_span(0x13000, 0x1342F);
+    property.add_span(0x13440, 0x13455);
+    property.add_span(0x13460, 0x143FA);
+    property.add_span(0x14400, 0x14646);
+    property.add_span(0x16100, 0x16139);
+    property.add_span(0x16800, 0x16A38);
+    property.add_span(0x16A40, 0x16A5E);
+    property.add_span(0x16A60, 0x16A69);
+    property.add_span(0x16A70, 0x16ABE);
+    property.add_span(0x16AC0, 0x16AC9);
+    property.add_span(0x16AD0, 0x16AED);
+    property.add_span(0x16AF0, 0x16AF4);
+    property.add_span(0x16B00, 0x16B36);
+    property.add_span(0x16B40, 0x16B43);
+    property.add_span(0x16B50, 0x16B59);
+    property.add_span(0x16B63, 0x16B77);
+    property.add_span(0x16B7D, 0x16B8F);
+    property.add_span(0x16D40, 0x16D6C);
+    property.add_span(0x16D70, 0x16D79);
+    property.add_span(0x16E40, 0x16E7F);
+    property.add_span(0x16F00, 0x16F4A);
+    property.add_span(0x16F4F, 0x16F87);
+    property.add_span(0x16F8F, 0x16F9F);
+    property.add_span(0x16FE0, 0x16FE1);
+    property.add_span(0x16FE3, 0x16FE4);
+    property.add_span(0x16FF0, 0x16FF1);
+    property.add_span(0x17000, 0x187F7);
+    property.add_span(0x18800, 0x18CD5);
+    property.add_span(0x18CFF, 0x18D08);
+    property.add_span(0x1AFF0, 0x1AFF3);
+    property.add_span(0x1AFF5, 0x1AFFB);
+    property.add_span(0x1AFFD, 0x1AFFE);
+    property.add_span(0x1B000, 0x1B122);
+    property.add_span(0x1B132, 0x1B132);
+    property.add_span(0x1B150, 0x1B152);
+    property.add_span(0x1B155, 0x1B155);
+    property.add_span(0x1B164, 0x1B167);
+    property.add_span(0x1B170, 0x1B2FB);
+    property.add_span(0x1BC00, 0x1BC6A);
+    property.add_span(0x1BC70, 0x1BC7C);
+    property.add_span(0x1BC80, 0x1BC88);
+    property.add_span(0x1BC90, 0x1BC99);
+    property.add_span(0x1BC9D, 0x1BC9E);
+    property.add_span(0x1CCF0, 0x1CCF9);
+    property.add_span(0x1CF00, 0x1CF2D);
+    property.add_span(0x1CF30, 0x1CF46);
+    property.add_span(0x1D165, 0x1D169);
+    property.add_span(0x1D16D, 0x1D172);
+    property.add_span(0x1D17B, 0x1D182);
+    property.add_span(0x1D185, 0x1D18B);
+    property.add_span(0x1D1AA, 0x1D1AD);
+    property.add_span(0x1D242, 0x1D244);
+    property.add_span(0x1D400, 0x1D454);
+    property.add_span(0x1D456, 0x1D49C);
+    property.add_span(0x1D49E, 0x1D49F);
+    property.add_span(0x1D4A2, 0x1D4A2);
+    property.add_span(0x1D4A5, 0x1D4A6);
+    property.add_span(0x1D4A9, 0x1D4AC);
+    property.add_span(0x1D4AE, 0x1D4B9);
+    property.add_span(0x1D4BB, 0x1D4BB);
+    property.add_span(0x1D4BD, 0x1D4C3);
+    property.add_span(0x1D4C5, 0x1D505);
+    property.add_span(0x1D507, 0x1D50A);
+    property.add_span(0x1D50D, 0x1D514);
+    property.add_span(0x1D516, 0x1D51C);
+    property.add_span(0x1D51E, 0x1D539);
+    property.add_span(0x1D53B, 0x1D53E);
+    property.add_span(0x1D540, 0x1D544);
+    property.add_span(0x1D546, 0x1D546);
+    property.add_span(0x1D54A, 0x1D550);
+    property.add_span(0x1D552, 0x1D6A5);
+    property.add_span(0x1D6A8, 0x1D6C0);
+    property.add_span(0x1D6C2, 0x1D6DA);
+    property.add_span(0x1D6DC, 0x1D6FA);
+    property.add_span(0x1D6FC, 0x1D714);
+    property.add_span(0x1D716, 0x1D734);
+    property.add_span(0x1D736, 0x1D74E);
+    property.add_span(0x1D750, 0x1D76E);
+    property.add_span(0x1D770, 0x1D788);
+    property.add_span(0x1D78A, 0x1D7A8);
+    property.add_span(0x1D7AA, 0x1D7C2);
+    property.add_span(0x1D7C4, 0x1D7CB);
+    property.add_span(0x1D7CE, 0x1D7FF);
+    property.add_span(0x1DA00, 0x1DA36);
+    property.add_span(0x1DA3B, 0x1DA6C);
+    property.add_span(0x1DA75, 0x1DA75);
+    property.add_span(0x1DA84, 0x1DA84);
+    property.add_span(0x1DA9B, 0x1DA9F);
+    property.add_span(0x1DAA1, 0x1DAAF);
+    property.add_span(0x1DF00, 0x1DF1E);
+    property.add_span(0x1DF25, 0x1DF2A);
+    property.add_span(0x1E000, 0x1E006);
+    property.add_span(0x1E008, 0x1E018);
+    property.add_span(0x1E01B, 0x1E021);
+    property.add_span(0x1E023, 0x1E024);
+    property.add_span(0x1E026, 0x1E02A);
+    property.add_span(0x1E030, 0x1E06D);
+    property.add_span(0x1E08F, 0x1E08F);
+    property.add_span(0x1E100, 0x1E12C);
+    property.add_span(0x1E130, 0x1E13D);
+    property.add_span(0x1E140, 0x1E149);
+    property.add_span(0x1E14E, 0x1E14E);
+    property.add_span(0x1E290, 0x1E2AE);
+    property.add_span(0x1E2C0, 0x1E2F9);
+    property.add_span(0x1E4D0, 0x1E4F9);
+    property.add_span(0x1E5D0, 0x1E5FA);
+    property.add_span(0x1E7E0, 0x1E7E6);
+    property.add_span(0x1E7E8, 0x1E7EB);
+    property.add_span(0x1E7ED, 0x1E7EE);
+    property.add_span(0x1E7F0, 0x1E7FE);
+    property.add_span(0x1E800, 0x1E8C4);
+    property.add_span(0x1E8D0, 0x1E8D6);
+    property.add_span(0x1E900, 0x1E94B);
+    property.add_span(0x1E950, 0x1E959);
+    property.add_span(0x1EE00, 0x1EE03);
+    property.add_span(0x1EE05, 0x1EE1F);
+    property.add_span(0x1EE21, 0x1EE22);
+    property.add_span(0x1EE24, 0x1EE24);
+    property.add_span(0x1EE27, 0x1EE27);
+    property.add_span(0x1EE29, 0x1EE32);
+    property.add_span(0x1EE34, 0x1EE37);
+    property.add_span(0x1EE39, 0x1EE39);
+    property.add_span(0x1EE3B, 0x1EE3B);
+    property.add_span(0x1EE42, 0x1EE42);
+    property.add_span(0x1EE47, 0x1EE47);
+    property.add_span(0x1EE49, 0x1EE49);
+    property.add_span(0x1EE4B, 0x1EE4B);
+    property.add_span(0x1EE4D, 0x1EE4F);
+    property.add_span(0x1EE51, 0x1EE52);
+    property.add_span(0x1EE54, 0x1EE54);
+    property.add_span(0x1EE57, 0x1EE57);
+    property.add_span(0x1EE59, 0x1EE59);
+    property.add_span(0x1EE5B, 0x1EE5B);
+    property.add_span(0x1EE5D, 0x1EE5D);
+    property.add_span(0x1EE5F, 0x1EE5F);
+    property.add_span(0x1EE61, 0x1EE62);
+    property.add_span(0x1EE64, 0x1EE64);
+    property.add_span(0x1EE67, 0x1EE6A);
+    property.add_span(0x1EE6C, 0x1EE72);
+    property.add_span(0x1EE74, 0x1EE77);
+    property.add_span(0x1EE79, 0x1EE7C);
+    property.add_span(0x1EE7E, 0x1EE7E);
+    property.add_span(0x1EE80, 0x1EE89);
+    property.add_span(0x1EE8B, 0x1EE9B);
+    property.add_span(0x1EEA1, 0x1EEA3);
+    property.add_span(0x1EEA5, 0x1EEA9);
+    property.add_span(0x1EEAB, 0x1EEBB);
+    property.add_span(0x1FBF0, 0x1FBF9);
+    property.add_span(0x20000, 0x2A6DF);
+    property.add_span(0x2A700, 0x2B739);
+    property.add_span(0x2B740, 0x2B81D);
+    property.add_span(0x2B820, 0x2CEA1);
+    property.add_span(0x2CEB0, 0x2EBE0);
+    property.add_span(0x2EBF0, 0x2EE5D);
+    property.add_span(0x2F800, 0x2FA1D);
+    property.add_span(0x30000, 0x3134A);
+    property.add_span(0x31350, 0x323AF);
+    property.add_span(0xE0100, 0xE01EF);
+    db.insert("XID_Continue", property);
+    let mut property = Category::new("XID_Start");
+    property.add_span(0x41, 0x5A);
+    property.add_span(0x61, 0x7A);
+    property.add_span(0xAA, 0xAA);
+    property.add_span(0xB5, 0xB5);
+    property.add_span(0xBA, 0xBA);
+    property.add_span(0xC0, 0xD6);
+    property.add_span(0xD8, 0xF6);
+    property.add_span(0xF8, 0x2C1);
+    property.add_span(0x2C6, 0x2D1);
+    property.add_span(0x2E0, 0x2E4);
+    property.add_span(0x2EC, 0x2EC);
+    property.add_span(0x2EE, 0x2EE);
+    property.add_span(0x370, 0x374);
+    property.add_span(0x376, 0x377);
+    property.add_span(0x37B, 0x37D);
+    property.add_span(0x37F, 0x37F);
+    property.add_span(0x386, 0x386);
+    property.add_span(0x388, 0x38A);
+    property.add_span(0x38C, 0x38C);
+    property.add_span(0x38E, 0x3A1);
+    property.add_span(0x3A3, 0x3F5);
+    property.add_span(0x3F7, 0x481);
+    property.add_span(0x48A, 0x52F);
+    property.add_span(0x531, 0x556);
+    property.add_span(0x559, 0x559);
+    property.add_span(0x560, 0x588);
+    property.add_span(0x5D0, 0x5EA);
+    property.add_span(0x5EF, 0x5F2);
+    property.add_span(0x620, 0x64A);
+    property.add_span(0x66E, 0x66F);
+    property.add_span(0x671, 0x6D3);
+    property.add_span(0x6D5, 0x6D5);
+    property.add_span(0x6E5, 0x6E6);
+    property.add_span(0x6EE, 0x6EF);
+    property.add_span(0x6FA, 0x6FC);
+    property.add_span(0x6FF, 0x6FF);
+    property.add_span(0x710, 0x710);
+    property.add_span(0x712, 0x72F);
+    property.add_span(0x74D, 0x7A5);
+    property.add_span(0x7B1, 0x7B1);
+    property.add_span(0x7CA, 0x7EA);
+    property.add_span(0x7F4, 0x7F5);
+    property.add_span(0x7FA, 0x7FA);
+    property.add_span(0x800, 0x815);
+    property.add_span(0x81A, 0x81A);
+    property.add_span(0x824, 0x824);
+    property.add_span(0x828, 0x828);
+    property.add_span(0x840, 0x858);
+    property.add_span(0x860, 0x86A);
+    property.add_span(0x870, 0x887);
+    property.add_span(0x889, 0x88E);
+    property.add_span(0x8A0, 0x8C9);
+    property.add_span(0x904, 0x939);
+    property.add_span(0x93D, 0x93D);
+    property.add_span(0x950, 0x950);
+    property.add_span(0x958, 0x961);
+    property.add_span(0x971, 0x980);
+    property.add_span(0x985, 0x98C);
+    property.add_span(0x98F, 0x990);
+    property.add_span(0x993, 0x9A8);
+    property.add_span(0x9AA, 0x9B0);
+    property.add_span(0x9B2, 0x9B2);
+    property.add_span(0x9B6, 0x9B9);
+    property.add_span(0x9BD, 0x9BD);
+    property.add_span(0x9CE, 0x9CE);
+    property.add_span(0x9DC, 0x9DD);
+    property.add_span(0x9DF, 0x9E1);
+    property.add_span(0x9F0, 0x9F1);
+    property.add_span(0x9FC, 0x9FC);
+    property.add_span(0xA05, 0xA0A);
+    property.add_span(0xA0F, 0xA10);
+    property.add_span(0xA13, 0xA28);
+    property.add_span(0xA2A, 0xA30);
+    property.add_span(0xA32, 0xA33);
+    property.add_span(0xA35, 0xA36);
+    property.add_span(0xA38, 0xA39);
+    property.add_span(0xA59, 0xA5C);
+    property.add_span(0xA5E, 0xA5E);
+    property.add_span(0xA72, 0xA74);
+    property.add_span(0xA85, 0xA8D);
+    property.add_span(0xA8F, 0xA91);
+    property.add_span(0xA93, 0xAA8);
+    property.add_span(0xAAA, 0xAB0);
+    property.add_span(0xAB2, 0xAB3);
+    property.add_span(0xAB5, 0xAB9);
+    property.add_span(0xABD, 0xABD);
+    property.add_span(0xAD0, 0xAD0);
+    property.add_span(0xAE0, 0xAE1);
+    property.add_span(0xAF9, 0xAF9);
+    property.add_span(0xB05, 0xB0C);
+    property.add_span(0xB0F, 0xB10);
+    property.add_span(0xB13, 0xB28);
+    property.add_span(0xB2A, 0xB30);
+    property.add_span(0xB32, 0xB33);
+    property.add_span(0xB35, 0xB39);
+    property.add_span(0xB3D, 0xB3D);
+    property.add_span(0xB5C, 0xB5D);
+    property.add_span(0xB5F, 0xB61);
+    property.add_span(0xB71, 0xB71);
+    property.add_span(0xB83, 0xB83);
+    property.add_span(0xB85, 0xB8A);
+    property.add_span(0xB8E, 0xB90);
+    property.add_span(0xB92, 0xB95);
+    property.add_span(0xB99, 0xB9A);
+    property.add_span(0xB9C, 0xB9C);
+    property.add_span(0xB9E, 0xB9F);
+    property.add_span(0xBA3, 0xBA4);
+    property.add_span(0xBA8, 0xBAA);
+    property.add_span(0xBAE, 0xBB9);
+    property.add_span(0xBD0, 0xBD0);
+    property.add_span(0xC05, 0xC0C);
+    property.add_span(0xC0E, 0xC10);
+    property.add_span(0xC12, 0xC28);
+    property.add_span(0xC2A, 0xC39);
+    property.add_span(0xC3D, 0xC3D);
+    property.add_span(0xC58, 0xC5A);
+    property.add_span(0xC5D, 0xC5D);
+    property.add_span(0xC60, 0xC61);
+    property.add_span(0xC80, 0xC80);
+    property.add_span(0xC85, 0xC8C);
+    property.add_span(0xC8E, 0xC90);
+    property.add_span(0xC92, 0xCA8);
+    property.add_span(0xCAA, 0xCB3);
+    property.add_span(0xCB5, 0xCB9);
+    property.add_span(0xCBD, 0xCBD);
+    property.add_span(0xCDD, 0xCDE);
+    property.add_span(0xCE0, 0xCE1);
+    property.add_span(0xCF1, 0xCF2);
+    property.add_span(0xD04, 0xD0C);
+    property.add_span(0xD0E, 0xD10);
+    property.add_span(0xD12, 0xD3A);
+    property.add_span(0xD3D, 0xD3D);
+    property.add_span(0xD4E, 0xD4E);
+    property.add_span(0xD54, 0xD56);
+    property.add_span(0xD5F, 0xD61);
+    property.add_span(0xD7A, 0xD7F);
+    property.add_span(0xD85, 0xD96);
+    property.add_span(0xD9A, 0xDB1);
+    property.add_span(0xDB3, 0xDBB);
+    property.add_span(0xDBD, 0xDBD);
+    property.add_span(0xDC0, 0xDC6);
+    property.add_span(0xE01, 0xE30);
+    property.add_span(0xE32, 0xE32);
+    property.add_span(0xE40, 0xE46);
+    property.add_span(0xE81, 0xE82);
+    property.add_span(0xE84, 0xE84);
+    property.add_span(0xE86, 0xE8A);
+    property.add_span(0xE8C, 0xEA3);
+    property.add_span(0xEA5, 0xEA5);
+    property.add_span(0xEA7, 0xEB0);
+    property.add_span(0xEB2, 0xEB2);
+    property.add_span(0xEBD, 0xEBD);
+    property.add_span(0xEC0, 0xEC4);
+    property.add_span(0xEC6, 0xEC6);
+    property.add_span(0xEDC, 0xEDF);
+    property.add_span(0xF00, 0xF00);
+    property.add_span(0xF40, 0xF47);
+    property.add_span(0xF49, 0xF6C);
+    property.add_span(0xF88, 0xF8C);
+    property.add_span(0x1000, 0x102A);
+    property.add_span(0x103F, 0x103F);
+    property.add_span(0x1050, 0x1055);
+    property.add_span(0x105A, 0x105D);
+    property.add_span(0x1061, 0x1061);
+    property.add_span(0x1065, 0x1066);
+    property.add_span(0x106E, 0x1070);
+    property.add_span(0x1075, 0x1081);
+    property.add_span(0x108E, 0x108E);
+    property.add_span(0x10A0, 0x10C5);
+    property.add_span(0x10C7, 0x10C7);
+    property.add_span(0x10CD, 0x10CD);
+    property.add_span(0x10D0, 0x10FA);
+    property.add_span(0x10FC, 0x1248);
+    property.add_span(0x124A, 0x124D);
+    property.add_span(0x1250, 0x1256);
+    property.add_span(0x1258, 0x1258);
+    property.add_span(0x125A, 0x125D);
+    property.add_span(0x1260, 0x1288);
+    property.add_span(0x128A, 0x128D);
+    property.add_span(0x1290, 0x12B0);
+    property.add_span(0x12B2, 0x12B5);
+    property.add_span(0x12B8, 0x12BE);
+    property.add_span(0x12C0, 0x12C0);
+    property.add_span(0x12C2, 0x12C5);
+    property.add_span(0x12C8, 0x12D6);
+    property.add_span(0x12D8, 0x1310);
+    property.add_span(0x1312, 0x1315);
+    property.add_span(0x1318, 0x135A);
+    property.add_span(0x1380, 0x138F);
+    property.add_span(0x13A0, 0x13F5);
+    property.add_span(0x13F8, 0x13FD);
+    property.add_span(0x1401, 0x166C);
+    property.add_span(0x166F, 0x167F);
+    property.add_span(0x1681, 0x169A);
+    property.add_span(0x16A0, 0x16EA);
+    property.add_span(0x16EE, 0x16F8);
+    property.add_span(0x1700, 0x1711);
+    property.add_span(0x171F, 0x1731);
+    property.add_span(0x1740, 0x1751);
+    property.add_span(0x1760, 0x176C);
+    property.add_span(0x176E, 0x1770);
+    property.add_span(0x1780, 0x17B3);
+    property.add_span(0x17D7, 0x17D7);
+    property.add_span(0x17DC, 0x17DC);
+    property.add_span(0x1820, 0x1878);
+    property.add_span(0x1880, 0x18A8);
+    property.add_span(0x18AA, 0x18AA);
+    property.add_span(0x18B0, 0x18F5);
+    property.add_span(0x1900, 0x191E);
+    property.add_span(0x1950, 0x196D);
+    property.add_span(0x1970, 0x1974);
+    property.add_span(0x1980, 0x19AB);
+    property.add_span(0x19B0, 0x19C9);
+    property.add_span(0x1A00, 0x1A16);
+    property.add_span(0x1A20, 0x1A54);
+    property.add_span(0x1AA7, 0x1AA7);
+    property.add_span(0x1B05, 0x1B33);
+    property.add_span(0x1B45, 0x1B4C);
+    property.add_span(0x1B83, 0x1BA0);
+    property.add_span(0x1BAE, 0x1BAF);
+    property.add_span(0x1BBA, 0x1BE5);
+    property.add_span(0x1C00, 0x1C23);
+    property.add_span(0x1C4D, 0x1C4F);
+    property.add_span(0x1C5A, 0x1C7D);
+    property.add_span(0x1C80, 0x1C8A);
+    property.add_span(0x1C90, 0x1CBA);
+    property.add_span(0x1CBD, 0x1CBF);
+    property.add_span(0x1CE9, 0x1CEC);
+    property.add_span(0x1CEE, 0x1CF3);
+    property.add_span(0x1CF5, 0x1CF6);
+    property.add_span(0x1CFA, 0x1CFA);
+    property.add_span(0x1D00, 0x1DBF);
+    property.add_span(0x1E00, 0x1F15);
+    property.add_span(0x1F18, 0x1F1D);
+    property.add_span(0x1F20, 0x1F45);
+    property.add_span(0x1F48, 0x1F4D);
+    property.add_span(0x1F50, 0x1F57);
+    property.add_span(0x1F59, 0x1F59);
+    property.add_span(0x1F5B, 0x1F5B);
+    property.add_span(0x1F5D, 0x1F5D);
+    property.add_span(0x1F5F, 0x1F7D);
+    property.add_span(0x1F80, 0x1FB4);
+    property.add_span(0x1FB6, 0x1FBC);
+    property.add_span(0x1FBE, 0x1FBE);
+    property.add_span(0x1FC2, 0x1FC4);
+    property.add_span(0x1FC6, 0x1FCC);
+    property.add_span(0x1FD0, 0x1FD3);
+    property.add_span(0x1FD6, 0x1FDB);
+    property.add_span(0x1FE0, 0x1FEC);
+    property.add_span(0x1FF2, 0x1FF4);
+    property.add_span(0x1FF6, 0x1FFC);
+    property.add_span(0x2071, 0x2071);
+    property.add_span(0x207F, 0x207F);
+    property.add_span(0x2090, 0x209C);
+    property.add_span(0x2102, 0x2102);
+    property.add_span(0x2107, 0x2107);
+    property.add_span(0x210A, 0x2113);
+    property.add_span(0x2115, 0x2115);
+    property.add_span(0x2118, 0x211D);
+    property.add_span(0x2124, 0x2124);
+    property.add_span(0x2126, 0x2126);
+    property.add_span(0x2128, 0x2128);
+    property.add_span(0x212A, 0x2139);
+    property.add_span(0x213C, 0x213F);
+    property.add_span(0x2145, 0x2149);
+    property.add_span(0x214E, 0x214E);
+    property.add_span(0x2160, 0x2188);
+    property.add_span(0x2C00, 0x2CE4);
+    property.add_span(0x2CEB, 0x2CEE);
+    property.add_span(0x2CF2, 0x2CF3);
+    property.add_span(0x2D00, 0x2D25);
+    property.add_span(0x2D27, 0x2D27);
+    property.add_span(0x2D2D, 0x2D2D);
+    property.add_span(0x2D30, 0x2D67);
+    property.add_span(0x2D6F, 0x2D6F);
+    property.add_span(0x2D80, 0x2D96);
+    property.add_span(0x2DA0, 0x2DA6);
+    property.add_span(0x2DA8, 0x2DAE);
+    property.add_span(0x2DB0, 0x2DB6);
+    property.add_span(0x2DB8, 0x2DBE);
+    property.add_span(0x2DC0, 0x2DC6);
+    property.add_span(0x2DC8, 0x2DCE);
+    property.add_span(0x2DD0, 0x2DD6);
+    property.add_span(0x2DD8, 0x2DDE);
+    property.add_span(0x3005, 0x3007);
+    property.add_span(0x3021, 0x3029);
+    property.add_span(0x3031, 0x3035);
+    property.add_span(0x3038, 0x303C);
+    property.add_span(0x3041, 0x3096);
+    property.add_span(0x309D, 0x309F);
+    property.add_span(0x30A1, 0x30FA);
+    property.add_span(0x30FC, 0x30FF);
+    property.add_span(0x3105, 0x312F);
+    property.add_span(0x3131, 0x318E);
+    property.add_span(0x31A0, 0x31BF);
+    property.add_span(0x31F0, 0x31FF);
+    property.add_span(0x3400, 0x4DBF);
+    property.add_span(0x4E00, 0xA48C);
+    property.add_span(0xA4D0, 0xA4FD);
+    property.add_span(0xA500, 0xA60C);
+    property.add_span(0xA610, 0xA61F);
+    property.add_span(0xA62A, 0xA62B);
+    property.add_span(0xA640, 0xA66E);
+    property.add_span(0xA67F, 0xA69D);
+    property.add_span(0xA6A0, 0xA6EF);
+    property.add_span(0xA717, 0xA71F);
+    property.add_span(0xA722, 0xA788);
+    property.add_span(0xA78B, 0xA7CD);
+    property.add_span(0xA7D0, 0xA7D1);
+    property.add_span(0xA7D3, 0xA7D3);
+    property.add_span(0xA7D5, 0xA7DC);
+    property.add_span(0xA7F2, 0xA801);
+    property.add_span(0xA803, 0xA805);
+    property.add_span(0xA807, 0xA80A);
+    property.add_span(0xA80C, 0xA822);
+    property.add_span(0xA840, 0xA873);
+    property.add_span(0xA882, 0xA8B3);
+    property.add_span(0xA8F2, 0xA8F7);
+    property.add_span(0xA8FB, 0xA8FB);
+    property.add_span(0xA8FD, 0xA8FE);
+    property.add_span(0xA90A, 0xA925);
+    property.add_span(0xA930, 0xA946);
+    property.add_span(0xA960, 0xA97C);
+    property.add_span(0xA984, 0xA9B2);
+    property.add_span(0xA9CF, 0xA9CF);
+    property.add_span(0xA9E0, 0xA9E4);
+    property.add_span(0xA9E6, 0xA9EF);
+    property.add_span(0xA9FA, 0xA9FE);
+    property.add_span(0xAA00, 0xAA28);
+    property.add_span(0xAA40, 0xAA42);
+    property.add_span(0xAA44, 0xAA4B);
+    property.add_span(0xAA60, 0xAA76);
+    property.add_span(0xAA7A, 0xAA7A);
+    property.add_span(0xAA7E, 0xAAAF);
+    property.add_span(0xAAB1, 0xAAB1);
+    property.add_span(0xAAB5, 0xAAB6);
+    property.add_span(0xAAB9, 0xAABD);
+    property.add_span(0xAAC0, 0xAAC0);
+    property.add_span(0xAAC2, 0xAAC2);
+    property.add_span(0xAADB, 0xAADD);
+    property.add_span(0xAAE0, 0xAAEA);
+    property.add_span(0xAAF2, 0xAAF4);
+    property.add_span(0xAB01, 0xAB06);
+    property.add_span(0xAB09, 0xAB0E);
+    property.add_span(0xAB11, 0xAB16);
+    property.add_span(0xAB20, 0xAB26);
+    property.add_span(0xAB28, 0xAB2E);
+    property.add_span(0xAB30, 0xAB5A);
+    property.add_span(0xAB5C, 0xAB69);
+    property.add_span(0xAB70, 0xABE2);
+    property.add_span(0xAC00, 0xD7A3);
+    property.add_span(0xD7B0, 0xD7C6);
+    property.add_span(0xD7CB, 0xD7FB);
+    property.add_span(0xF900, 0xFA6D);
+    property.add_span(0xFA70, 0xFAD9);
+    property.add_span(0xFB00, 0xFB06);
+    property.add_span(0xFB13, 0xFB17);
+    property.add_span(0xFB1D, 0xFB1D);
+    property.add_span(0xFB1F, 0xFB28);
+    property.add_span(0xFB2A, 0xFB36);
+    property.add_span(0xFB38, 0xFB3C);
+    property.add_span(0xFB3E, 0xFB3E);
+    property.add_span(0xFB40, 0xFB41);
+    property.add_span(0xFB43, 0xFB44);
+    property.add_span(0xFB46, 0xFBB1);
+    property.add_span(0xFBD3, 0xFC5D);
+    property.add_span(0xFC64, 0xFD3D);
+    property.add_span(0xFD50, 0xFD8F);
+    property.add_span(0xFD92, 0xFDC7);
+    property.add_span(0xFDF0, 0xFDF9);
+    property.add_span(0xFE71, 0xFE71);
+    property.add_span(0xFE73, 0xFE73);
+    property.add_span(0xFE77, 0xFE77);
+    property.add_span(0xFE79, 0xFE79);
+    property.add_span(0xFE7B, 0xFE7B);
+    property.add_span(0xFE7D, 0xFE7D);
+    property.add_span(0xFE7F, 0xFEFC);
+    property.add_span(0xFF21, 0xFF3A);
+    property.add_span(0xFF41, 0xFF5A);
+    property.add_span(0xFF66, 0xFF9D);
+    property.add_span(0xFFA0, 0xFFBE);
+    property.add_span(0xFFC2, 0xFFC7);
+    property.add_span(0xFFCA, 0xFFCF);
+    property.add_span(0xFFD2, 0xFFD7);
+    property.add_span(0xFFDA, 0xFFDC);
+    property.add_span(0x10000, 0x1000B);
+    property.add_span(0x1000D, 0x10026);
+    property.add_span(0x10028, 0x1003A);
+    property.add_span(0x1003C, 0x1003D);
+    property.add_span(0x1003F, 0x1004D);
+    property.add_span(0x10050, 0x1005D);
+    property.add_span(0x10080, 0x100FA);
+    property.add_span(0x10140, 0x10174);
+    property.add_span(0x10280, 0x1029C);
+    property.add_span(0x102A0, 0x102D0);
+    property.add_span(0x10300, 0x1031F);
+    property.add_span(0x1032D, 0x1034A);
+    property.add_span(0x10350, 0x10375);
+    property.add_span(0x10380, 0x1039D);
+    property.add_span(0x103A0, 0x103C3);
+    property.add_span(0x103C8, 0x103CF);
+    property.add_span(0x103D1, 0x103D5);
+    property.add_span(0x10400, 0x1049D);
+    property.add_span(0x104B0, 0x104D3);
+    property.add_span(0x104D8, 0x104FB);
+    property.add_span(0x10500, 0x10527);
+    property.add_span(0x10530, 0x10563);
+    property.add_span(0x10570, 0x1057A);
+    property.add_span(0x1057C, 0x1058A);
+    property.add_span(0x1058C, 0x10592);
+    property.add_span(0x10594, 0x10595);
+    property.add_span(0x10597, 0x105A1);
+    property.add_span(0x105A3, 0x105B1);
+    property.add_span(0x105B3, 0x105B9);
+    property.add_span(0x105BB, 0x105BC);
+    property.add_span(0x105C0, 0x105F3);
+    property.add_span(0x10600, 0x10736);
+    property.add_span(0x10740, 0x10755);
+    property.add_span(0x10760, 0x10767);
+    property.add_span(0x10780, 0x10785);
+    property.add_span(0x10787, 0x107B0);
+    property.add_span(0x107B2, 0x107BA);
+    property.add_span(0x10800, 0x10805);
+    property.add_span(0x10808, 0x10808);
+    property.add_span(0x1080A, 0x10835);
+    property.add_span(0x10837, 0x10838);
+    property.add_span(0x1083C, 0x1083C);
+    property.add_span(0x1083F, 0x10855);
+    property.add_span(0x10860, 0x10876);
+    property.add_span(0x10880, 0x1089E);
+    property.add_span(0x108E0, 0x108F2);
+    property.add_span(0x108F4, 0x108F5);
+    property.add_span(0x10900, 0x10915);
+    property.add_span(0x10920, 0x10939);
+    property.add_span(0x10980, 0x109B7);
+    property.add_span(0x109BE, 0x109BF);
+    property.add_span(0x10A00, 0x10A00);
+    property.add_span(0x10A10, 0x10A13);
+    property.add_span(0x10A15, 0x10A17);
+    property.add_span(0x10A19, 0x10A35);
+    property.add_span(0x10A60, 0x10A7C);
+    property.add_span(0x10A80, 0x10A9C);
+    property.add_span(0x10AC0, 0x10AC7);
+    property.add_span(0x10AC9, 0x10AE4);
+    property.add_span(0x10B00, 0x10B35);
+    property.add_span(0x10B40, 0x10B55);
+    property.add_span(0x10B60, 0x10B72);
+    property.add_span(0x10B80, 0x10B91);
+    property.add_span(0x10C00, 0x10C48);
+    property.add_span(0x10C80, 0x10CB2);
+    property.add_span(0x10CC0, 0x10CF2);
+    property.add_span(0x10D00, 0x10D23);
+    property.add_span(0x10D4A, 0x10D65);
+    property.add_span(0x10D6F, 0x10D85);
+    property.add_span(0x10E80, 0x10EA9);
+    property.add_span(0x10EB0, 0x10EB1);
+    property.add_span(0x10EC2, 0x10EC4);
+    property.add_span(0x10F00, 0x10F1C);
+    property.add_span(0x10F27, 0x10F27);
+    property.add_span(0x10F30, 0x10F45);
+    property.add_span(0x10F70, 0x10F81);
+    property.add_span(0x10FB0, 0x10FC4);
+    property.add_span(0x10FE0, 0x10FF6);
+    property.add_span(0x11003, 0x11037);
+    property.add_span(0x11071, 0x11072);
+    property.add_span(0x11075, 0x11075);
+    property.add_span(0x11083, 0x110AF);
+    property.add_span(0x110D0, 0x110E8);
+    property.add_span(0x11103, 0x11126);
+    property.add_span(0x11144, 0x11144);
+    property.add_span(0x11147, 0x11147);
+    property.add_span(0x11150, 0x11172);
+    property.add_span(0x11176, 0x11176);
+    property.add_span(0x11183, 0x111B2);
+    property.add_span(0x111C1, 0x111C4);
+    property.add_span(0x111DA, 0x111DA);
+    property.add_span(0x111DC, 0x111DC);
+    property.add_span(0x11200, 0x11211);
+    property.add_span(0x11213, 0x1122B);
+    property.add_span(0x1123F, 0x11240);
+    property.add_span(0x11280, 0x11286);
+    property.add_span(0x11288, 0x11288);
+    property.add_span(0x1128A, 0x1128D);
+    property.add_span(0x1128F, 0x1129D);
+    property.add_span(0x1129F, 0x112A8);
+    property.add_span(0x112B0, 0x112DE);
+    property.add_span(0x11305, 0x1130C);
+    property.add_span(0x1130F, 0x11310);
+    property.add_span(0x11313, 0x11328);
+    property.add_span(0x1132A, 0x11330);
+    property.add_span(0x11332, 0x11333);
+    property.add_span(0x11335, 0x11339);
+    property.add_span(0x1133D, 0x1133D);
+    property.add_span(0x11350, 0x11350);
+    property.add_span(0x1135D, 0x11361);
+    property.add_span(0x11380, 0x11389);
+    property.add_span(0x1138B, 0x1138B);
+    property.add_span(0x1138E, 0x1138E);
+    property.add_span(0x11390, 0x113B5);
+    property.add_span(0x113B7, 0x113B7);
+    property.add_span(0x113D1, 0x113D1);
+    property.add_span(0x113D3, 0x113D3);
+    property.add_span(0x11400, 0x11434);
+    property.add_span(0x11447, 0x1144A);
+    property.add_span(0x1145F, 0x11461);
+    property.add_span(0x11480, 0x114AF);
+    property.add_span(0x114C4, 0x114C5);
+    property.add_span(0x114C7, 0x114C7);
+    property.add_span(0x11580, 0x115AE);
+    property.add_span(0x115D8, 0x115DB);
+    property.add_span(0x11600, 0x1162F);
+    property.add_span(0x11644, 0x11644);
+    property.add_span(0x11680, 0x116AA);
+    property.add_span(0x116B8, 0x116B8);
+    property.add_span(0x11700, 0x1171A);
+    property.add_span(0x11740, 0x11746);
+    property.add_span(0x11800, 0x1182B);
+    property.add_span(0x118A0, 0x118DF);
+    property.add_span(0x118FF, 0x11906);
+    property.add_span(0x11909, 0x11909);
+    property.add_span(0x1190C, 0x11913);
+    property.add_span(0x11915, 0x11916);
+    property.add_span(0x11918, 0x1192F);
+    property.add_span(0x1193F, 0x1193F);
+    property.add_span(0x11941, 0x11941);
+    property.add_span(0x119A0, 0x119A7);
+    property.add_span(0x119AA, 0x119D0);
+    property.add_span(0x119E1, 0x119E1);
+    property.add_span(0x119E3, 0x119E3);
+    property.add_span(0x11A00, 0x11A00);
+    property.add_span(0x11A0B, 0x11A32);
+    property.add_span(0x11A3A, 0x11A3A);
+    property.add_span(0x11A50, 0x11A50);
+    property.add_span(0x11A5C, 0x11A89);
+    property.add_span(0x11A9D, 0x11A9D);
+    property.add_span(0x11AB0, 0x11AF8);
+    property.add_span(0x11BC0, 0x11BE0);
+    property.add_span(0x11C00, 0x11C08);
+    property.add_span(0x11C0A, 0x11C2E);
+    property.add_span(0x11C40, 0x11C40);
+    property.add_span(0x11C72, 0x11C8F);
+    property.add_span(0x11D00, 0x11D06);
+    property.add_span(0x11D08, 0x11D09);
+    property.add_span(0x11D0B, 0x11D30);
+    property.add_span(0x11D46, 0x11D46);
+    property.add_span(0x11D60, 0x11D65);
+    property.add_span(0x11D67, 0x11D68);
+    property.add_span(0x11D6A, 0x11D89);
+    property.add_span(0x11D98, 0x11D98);
+    property.add_span(0x11EE0, 0x11EF2);
+    property.add_span(0x11F02, 0x11F02);
+    property.add_span(0x11F04, 0x11F10);
+    property.add_span(0x11F12, 0x11F33);
+    property.add_span(0x11FB0, 0x11FB0);
+    property.add_span(0x12000, 0x12399);
+    property.add_span(0x12400, 0x1246E);
+    property.add_span(0x12480, 0x12543);
+    property.add_span(0x12F90, 0x12FF0);
+    property.add_span(0x13000, 0x1342F);
+    property.add_span(0x13441, 0x13446);
+    property.add_span(0x13460, 0x143FA);
+    property.add_span(0x14400, 0x14646);
+    property.add_span(0x16100, 0x1611D);
+    property.add_span(0x16800, 0x16A38);
+    property.add_span(0x16A40, 0x16A5E);
+    property.add_span(0x16A70, 0x16ABE);
+    property.add_span(0x16AD0, 0x16AED);
+    property.add_span(0x16B00, 0x16B2F);
+    property.add_span(0x16B40, 0x16B43);
+    property.add_span(0x16B63, 0x16B77);
+    property.add_span(0x16B7D, 0x16B8F);
+    property.add_span(0x16D40, 0x16D6C);
+    property.add_span(0x16E40, 0x16E7F);
+    property.add_span(0x16F00, 0x16F4A);
+    property.add_span(0x16F50, 0x16F50);
+    property.add_span(0x16F93, 0x16F9F);
+    property.add_span(0x16FE0, 0x16FE1);
+    property.add_span(0x16FE3, 0x16FE3);
+    property.add_span(0x17000, 0x187F7);
+    property.add_span(0x18800, 0x18CD5);
+    property.add_span(0x18CFF, 0x18D08);
+    property.add_span(0x1AFF0, 0x1AFF3);
+    property.add_span(0x1AFF5, 0x1AFFB);
+    property.add_span(0x1AFFD, 0x1AFFE);
+    property.add_span(0x1B000, 0x1B122);
+    property.add_span(0x1B132, 0x1B132);
+    property.add_span(0x1B150, 0x1B152);
+    property.add_span(0x1B155, 0x1B155);
+    property.add_span(0x1B164, 0x1B167);
+    property.add_span(0x1B170, 0x1B2FB);
+    property.add_span(0x1BC00, 0x1BC6A);
+    property.add_span(0x1BC70, 0x1BC7C);
+    property.add_span(0x1BC80, 0x1BC88);
+    property.add_span(0x1BC90, 0x1BC99);
+    property.add_span(0x1D400, 0x1D454);
+    property.add_span(0x1D456, 0x1D49C);
+    property.add_span(0x1D49E, 0x1D49F);
+    property.add_span(0x1D4A2, 0x1D4A2);
+    property.add_span(0x1D4A5, 0x1D4A6);
+    property.add_span(0x1D4A9, 0x1D4AC);
+    property.add_span(0x1D4AE, 0x1D4B9);
+    property.add_span(0x1D4BB, 0x1D4BB);
+    property.add_span(0x1D4BD, 0x1D4C3);
+    property.add_span(0x1D4C5, 0x1D505);
+    property.add_span(0x1D507, 0x1D50A);
+    property.add_span(0x1D50D, 0x1D514);
+    property.add_span(0x1D516, 0x1D51C);
+    property.add_span(0x1D51E, 0x1D539);
+    property.add_span(0x1D53B, 0x1D53E);
+    property.add_span(0x1D540, 0x1D544);
+    property.add_span(0x1D546, 0x1D546);
+    property.add_span(0x1D54A, 0x1D550);
+    property.add_span(0x1D552, 0x1D6A5);
+    property.add_span(0x1D6A8, 0x1D6C0);
+    property.add_span(0x1D6C2, 0x1D6DA);
+    property.add_span(0x1D6DC, 0x1D6FA);
+    property.add_span(0x1D6FC, 0x1D714);
+    property.add_span(0x1D716, 0x1D734);
+    property.add_span(0x1D736, 0x1D74E);
+    property.add_span(0x1D750, 0x1D76E);
+    property.add_span(0x1D770, 0x1D788);
+    property.add_span(0x1D78A, 0x1D7A8);
+    property.add_span(0x1D7AA, 0x1D7C2);
+    property.add_span(0x1D7C4, 0x1D7CB);
+    property.add_span(0x1DF00, 0x1DF1E);
+    property.add_span(0x1DF25, 0x1DF2A);
+    property.add_span(0x1E030, 0x1E06D);
+    property.add_span(0x1E100, 0x1E12C);
+    property.add_span(0x1E137, 0x1E13D);
+    property.add_span(0x1E14E, 0x1E14E);
+    property.add_span(0x1E290, 0x1E2AD);
+    property.add_span(0x1E2C0, 0x1E2EB);
+    property.add_span(0x1E4D0, 0x1E4EB);
+    property.add_span(0x1E5D0, 0x1E5ED);
+    property.add_span(0x1E5F0, 0x1E5F0);
+    property.add_span(0x1E7E0, 0x1E7E6);
+    property.add_span(0x1E7E8, 0x1E7EB);
+    property.add_span(0x1E7ED, 0x1E7EE);
+    property.add_span(0x1E7F0, 0x1E7FE);
+    property.add_span(0x1E800, 0x1E8C4);
+    property.add_span(0x1E900, 0x1E943);
+    property.add_span(0x1E94B, 0x1E94B);
+    property.add_span(0x1EE00, 0x1EE03);
+    property.add_span(0x1EE05, 0x1EE1F);
+    property.add_span(0x1EE21, 0x1EE22);
+    property.add_span(0x1EE24, 0x1EE24);
+    property.add_span(0x1EE27, 0x1EE27);
+    property.add_span(0x1EE29, 0x1EE32);
+    property.add_span(0x1EE34, 0x1EE37);
+    property.add_span(0x1EE39, 0x1EE39);
+    property.add_span(0x1EE3B, 0x1EE3B);
+    property.add_span(0x1EE42, 0x1EE42);
+    property.add_span(0x1EE47, 0x1EE47);
+    property.add_span(0x1EE49, 0x1EE49);
+    property.add_span(0x1EE4B, 0x1EE4B);
+    property.add_span(0x1EE4D, 0x1EE4F);
+    property.add_span(0x1EE51, 0x1EE52);
+    property.add_span(0x1EE54, 0x1EE54);
+    property.add_span(0x1EE57, 0x1EE57);
+    property.add_span(0x1EE59, 0x1EE59);
+    property.add_span(0x1EE5B, 0x1EE5B);
+    property.add_span(0x1EE5D, 0x1EE5D);
+    property.add_span(0x1EE5F, 0x1EE5F);
+    property.add_span(0x1EE61, 0x1EE62);
+    property.add_span(0x1EE64, 0x1EE64);
+    property.add_span(0x1EE67, 0x1EE6A);
+    property.add_span(0x1EE6C, 0x1EE72);
+    property.add_span(0x1EE74, 0x1EE77);
+    property.add_span(0x1EE79, 0x1EE7C);
+    property.add_span(0x1EE7E, 0x1EE7E);
+    property.add_span(0x1EE80, 0x1EE89);
+    property.add_span(0x1EE8B, 0x1EE9B);
+    property.add_span(0x1EEA1, 0x1EEA3);
+    property.add_span(0x1EEA5, 0x1EEA9);
+    property.add_span(0x1EEAB, 0x1EEBB);
+    property.add_span(0x20000, 0x2A6DF);
+    property.add_span(0x2A700, 0x2B739);
+    property.add_span(0x2B740, 0x2B81D);
+    property.add_span(0x2B820, 0x2CEA1);
+    property.add_span(0x2CEB0, 0x2EBE0);
+    property.add_span(0x2EBF0, 0x2EE5D);
+    property.add_span(0x2F800, 0x2FA1D);
+    property.add_span(0x30000, 0x3134A);
+    property.add_span(0x31350, 0x323AF);
+    db.insert("XID_Start", property);
+    db
+}
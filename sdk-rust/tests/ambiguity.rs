@@ -0,0 +1,177 @@
+use hime_sdk::ambiguity::{find_ambiguities, Ambiguity, AmbiguityKind};
+use hime_sdk::errors::Error;
+use hime_sdk::grammars::Grammar;
+use hime_sdk::{CompilationTask, Input, ParsingMethod};
+
+mod common;
+
+/// An ambiguous expression grammar
+const EXPRESSIONS: &str = r#"
+grammar Test
+{
+    options { Axiom = "s"; }
+    terminals
+    {
+        NUM -> [0-9]+ ;
+    }
+    rules
+    {
+        s -> e ;
+        e -> e '+' e | NUM ;
+    }
+}
+"#;
+
+/// A grammar that is not ambiguous but requires two tokens of lookahead
+const LOOKAHEAD: &str = r#"
+grammar Test
+{
+    options { Axiom = "s"; }
+    terminals
+    {
+        A -> 'a' ;
+        X -> 'x' ;
+        Y -> 'y' ;
+        Z -> 'z' ;
+    }
+    rules
+    {
+        s -> a X Y | b X Z ;
+        a -> A ;
+        b -> A ;
+    }
+}
+"#;
+
+/// A grammar for palindromes that is not ambiguous, but not LR(k) either
+const PALINDROMES: &str = r#"
+grammar Test
+{
+    options { Axiom = "s"; }
+    terminals
+    {
+        A -> 'a' ;
+        B -> 'b' ;
+    }
+    rules
+    {
+        s -> p ;
+        p -> A p A | B p B | ;
+    }
+}
+"#;
+
+/// An ambiguous grammar where the conflict is not at the point of the ambiguity
+const PARENT: &str = r#"
+grammar Test
+{
+    options { Axiom = "s"; }
+    terminals
+    {
+        A -> 'a' ;
+    }
+    rules
+    {
+        s -> x A A | y A ;
+        x -> A | ;
+        y -> A A | A A A ;
+    }
+}
+"#;
+
+/// Builds the grammar and finds its ambiguities, with their descriptions
+fn analyze(grammar: &str, test: impl FnOnce(&Grammar, Vec<(Ambiguity, String)>)) {
+    let mut data = common::new_task(grammar, ParsingMethod::RNGLALR1)
+        .load()
+        .unwrap();
+    data.grammars[0]
+        .build(Some(ParsingMethod::RNGLALR1), None, 0)
+        .unwrap();
+    let grammar = &data.grammars[0];
+    let ambiguities = find_ambiguities(grammar)
+        .into_iter()
+        .map(|ambiguity| {
+            let description = ambiguity.get_description(grammar);
+            (ambiguity, description)
+        })
+        .collect();
+    test(grammar, ambiguities);
+}
+
+#[test]
+fn test_ambiguity_unifying() {
+    analyze(EXPRESSIONS, |_, ambiguities| {
+        assert_eq!(ambiguities.len(), 1);
+        let (ambiguity, description) = &ambiguities[0];
+        assert_eq!(ambiguity.kind, AmbiguityKind::Ambiguous);
+        assert_eq!(
+            description,
+            "Ambiguous: Shift/Reduce conflict on `+` in state 6, between e -> e + e
+  Example of input that is ambiguous: e + e • + e
+  First derivation: [s -> [e -> e + [e -> e • + e]]]
+  Second derivation: [s -> [e -> [e -> e + e •] + e]]"
+        );
+    });
+}
+
+#[test]
+fn test_ambiguity_lookahead() {
+    analyze(LOOKAHEAD, |_, ambiguities| {
+        assert!(ambiguities.is_empty());
+    });
+}
+
+#[test]
+fn test_ambiguity_undecided() {
+    analyze(PALINDROMES, |_, ambiguities| {
+        assert_eq!(ambiguities.len(), 2);
+        assert!(ambiguities
+            .iter()
+            .all(|(ambiguity, _)| ambiguity.kind == AmbiguityKind::PossiblyAmbiguous));
+    });
+}
+
+#[test]
+fn test_ambiguity_bounded_search() {
+    analyze(PARENT, |grammar, ambiguities| {
+        assert_eq!(ambiguities.len(), 1);
+        let (ambiguity, _) = &ambiguities[0];
+        assert_eq!(ambiguity.kind, AmbiguityKind::Ambiguous);
+        let counterexample = ambiguity.counterexample.as_ref().unwrap();
+        assert_eq!(counterexample.first.get_example(grammar), "A A A");
+        assert_eq!(counterexample.second.get_example(grammar), "A A A");
+        let mut derivations = [
+            counterexample.first.get_description(grammar),
+            counterexample.second.get_description(grammar),
+        ];
+        derivations.sort();
+        assert_eq!(derivations, ["[s -> [x -> A] A A]", "[s -> [y -> A A] A]"]);
+    });
+}
+
+#[test]
+fn test_ambiguity_after_failed_build() {
+    // the analysis is also available when the grammar has unresolved conflicts
+    let mut task = common::new_task(EXPRESSIONS, ParsingMethod::LALR1);
+    task.print_ambiguities = Some(true);
+    let errors = task.execute().err().unwrap();
+    assert!(errors
+        .errors
+        .iter()
+        .all(|error| matches!(error, Error::LrConflict(0, _))));
+    let ambiguities = find_ambiguities(&errors.context.grammars[0]);
+    assert_eq!(ambiguities.len(), 1);
+    assert_eq!(ambiguities[0].kind, AmbiguityKind::Ambiguous);
+    // the grammars that cannot be prepared are not analyzed
+    let grammar = EXPRESSIONS.replace("Axiom = \"s\"", "Axiom = \"x\"");
+    let task = CompilationTask {
+        inputs: vec![Input::Raw(&grammar)],
+        print_ambiguities: Some(true),
+        ..Default::default()
+    };
+    let errors = task.execute().err().unwrap();
+    assert!(errors
+        .errors
+        .iter()
+        .any(|error| matches!(error, Error::AxiomNotDefined(0))));
+}
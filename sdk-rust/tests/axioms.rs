@@ -0,0 +1,44 @@
+use hime_redist::symbols::SemanticElementTrait;
+
+mod common;
+
+const GRAMMAR: &str = r#"
+grammar Calc
+{
+    options
+    {
+        Axiom = "stmt, expr";
+        Separator = "WS";
+    }
+    terminals
+    {
+        WS -> U+0020+ ;
+        ID -> [a-z]+ ;
+        NUM -> [0-9]+ ;
+    }
+    rules
+    {
+        stmt -> ID '='! expr ';'! ;
+        expr -> expr '+'^ term | term ;
+        term -> ID | NUM ;
+    }
+}
+"#;
+
+/// Parses inputs as each of the axioms of a grammar
+#[test]
+fn test_parse_as_axioms() {
+    common::compare(GRAMMAR, |parser| {
+        let result = parser.parse("x = 1 + y;");
+        assert!(result.is_success());
+        assert_eq!(result.get_ast().get_root().get_symbol().name, "stmt");
+
+        let result = parser.parse_as("expr", "1 + y").unwrap();
+        assert!(result.is_success());
+        assert_eq!(result.get_ast().get_root().get_symbol().name, "+");
+
+        let result = parser.parse_as("expr", "x = 1;").unwrap();
+        assert!(!result.is_success());
+        assert!(parser.parse_as("term", "x").is_none());
+    });
+}
@@ -0,0 +1,91 @@
+use hime_redist::errors::ParseErrorDataTrait;
+use hime_redist::symbols::SemanticElementTrait;
+use hime_sdk::errors::Error;
+use hime_sdk::ParsingMethod;
+
+mod common;
+
+const GRAMMAR: &str = r#"
+grammar Packets
+{
+    options
+    {
+        Axiom = "packets";
+        Input = "bytes";
+    }
+    terminals
+    {
+        MAGIC -> 0xCA 0xFE ;
+        PING -> 0x01 ;
+        DATA -> 0x02 (0x20 .. 0x7E)+ 0x00 ;
+        HEADER -> ~'hdr' [^a-z]{2} ;
+        END -> 0xFF ;
+    }
+    rules
+    {
+        packets -> MAGIC packet* END ;
+        packet -> PING | DATA | HEADER ;
+    }
+}
+"#;
+
+/// Parses a binary input with a grammar over bytes
+#[test]
+fn test_byte_grammar() {
+    let task = common::new_task(GRAMMAR, ParsingMethod::LALR1);
+    common::with_parser(&task, |parser| {
+        let result = parser.parse_bytes(b"\xCA\xFE\x01\x02abc\x00HdR\xFF\x80\xFF");
+        assert!(result.errors.errors.is_empty());
+        let tokens = result.get_tokens();
+        let names = tokens
+            .iter()
+            .map(|token| token.get_symbol().name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["MAGIC", "PING", "DATA", "HEADER", "END", "$"]);
+        let data = tokens.get_token(2);
+        assert_eq!(data.get_span().unwrap().index, 3);
+        assert_eq!(data.get_span().unwrap().length, 5);
+        assert_eq!(data.get_bytes(), b"\x02abc\x00");
+        assert_eq!(tokens.get_token(3).get_bytes(), b"HdR\xFF\x80");
+
+        let result = parser.parse_bytes(b"\xCA\xFE\x03\xFF");
+        assert_eq!(result.errors.errors.len(), 1);
+        assert_eq!(result.errors.errors[0].get_position().column, 3);
+    })
+    .unwrap();
+}
+
+/// Reports Unicode constructs in grammars over bytes and bytes in grammars over text
+#[test]
+fn test_byte_grammar_errors() {
+    let errors = common::load_errors(
+        "grammar G { options { Axiom = \"e\"; Input = \"bytes\"; } terminals { X -> uc{Lu} | [é] | U+0041; } rules { e -> X; } }",
+    );
+    assert_eq!(errors.len(), 3);
+    assert!(errors
+        .iter()
+        .all(|error| matches!(error, Error::UnicodeInByteGrammar(_))));
+
+    let errors = common::load_errors(
+        "grammar G { options { Axiom = \"e\"; } terminals { X -> 0x41 | 0x00 .. 0x20; } rules { e -> X; } }",
+    );
+    assert_eq!(errors.len(), 2);
+    assert!(errors
+        .iter()
+        .all(|error| matches!(error, Error::ByteInTextGrammar(_))));
+}
+
+/// Reports and skips unexpected bytes one at a time
+#[test]
+fn test_byte_unexpected() {
+    let (tokens, errors) = common::compare(GRAMMAR, |parser| {
+        let result = parser.parse_bytes(b"\xCA\xFE\x83\x01\xFF");
+        let error = &result.errors.errors[0];
+        assert_eq!(error.get_position().column, 3);
+        assert_eq!(error.get_length(), 1);
+        (common::get_tokens(&result), common::get_errors(&result))
+    });
+    assert_eq!(errors, ["Unexpected byte 0x83"]);
+    let names: Vec<&str> = tokens.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["MAGIC", "PING", "END", "$"]);
+}
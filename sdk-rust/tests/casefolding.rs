@@ -0,0 +1,46 @@
+use hime_sdk::ParsingMethod;
+
+mod common;
+
+const GRAMMAR: &str = r#"
+grammar Words
+{
+    options
+    {
+        Axiom = "words";
+        Separator = "WS";
+        CaseInsensitiveContexts = "upper";
+    }
+    terminals
+    {
+        WS -> U+0020+ ;
+        ACCENTED -> ~[é]+ ;
+        DESERET -> ~'𐐨' ;
+        context upper
+        {
+            KW -> 'kelvin' ;
+        }
+    }
+    rules
+    {
+        words -> word+ ;
+        word -> ~'été' | ACCENTED | DESERET | #upper{ KW } ;
+    }
+}
+"#;
+
+/// Parses inputs with case-insensitive literals, classes and contexts
+#[test]
+fn test_case_insensitive_terminals() {
+    let task = common::new_task(GRAMMAR, ParsingMethod::LALR1);
+    common::with_parser(&task, |parser| {
+        for input in ["été ÉTÉ Été", "éÉé", "𐐀 𐐨", "kelvin KELVIN \u{212A}elvin"] {
+            let result = parser.parse(input);
+            assert!(result.errors.errors.is_empty(), "{input}");
+            assert!(result.is_success());
+        }
+        let result = parser.parse("ete");
+        assert!(!result.errors.errors.is_empty());
+    })
+    .unwrap();
+}
@@ -0,0 +1,72 @@
+use hime_redist::errors::ParseErrorDataTrait;
+use hime_sdk::ParsingMethod;
+
+mod common;
+
+const GRAMMAR: &str = r#"
+grammar Symbols
+{
+    options
+    {
+        Axiom = "items";
+        Separator = "WS";
+    }
+    terminals
+    {
+        WS -> U+0020+ ;
+        EMOJI -> (U+1F300 .. U+1F64F)+ ;
+        WIDE -> (U+FF00 .. U+10010)+ ;
+        GREETING -> 'hi👋' ;
+        OTHER -> [a-z]+ ;
+    }
+    rules
+    {
+        items -> item+ ;
+        item -> EMOJI | WIDE | GREETING | OTHER ;
+    }
+}
+"#;
+
+/// Gets the names and values of the tokens produced by a parser for an input
+fn get_tokens(char_lexer: bool, input: &str) -> Vec<(String, String)> {
+    let mut task = common::new_task(GRAMMAR, ParsingMethod::LALR1);
+    task.rust_char_lexer = Some(char_lexer);
+    common::with_parser(&task, |parser| {
+        let result = parser.parse(input);
+        assert!(result.errors.errors.is_empty(), "{input}");
+        common::get_tokens(&result)
+    })
+    .unwrap()
+}
+
+/// Lexes astral characters with the lexer on Unicode scalar values
+#[test]
+fn test_char_lexer() {
+    let input = "🌀🙂🙏 ＡＢ\u{FFFF}𐀀 hi👋 hi abc";
+    let tokens = get_tokens(true, input);
+    let names = tokens
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec!["EMOJI", "WIDE", "GREETING", "OTHER", "OTHER", "$"]
+    );
+    assert_eq!(tokens[1].1, "ＡＢ\u{FFFF}𐀀");
+    assert_eq!(tokens, get_tokens(false, input));
+}
+
+/// Reports the same errors with the lexer on Unicode scalar values
+#[test]
+fn test_char_lexer_errors() {
+    for char_lexer in [false, true] {
+        let mut task = common::new_task(GRAMMAR, ParsingMethod::LALR1);
+        task.rust_char_lexer = Some(char_lexer);
+        common::with_parser(&task, |parser| {
+            let result = parser.parse("hi👋 hi🌀✋");
+            assert_eq!(result.errors.errors.len(), 1);
+            assert_eq!(result.errors.errors[0].get_position().column, 8);
+        })
+        .unwrap();
+    }
+}
@@ -0,0 +1,54 @@
+use hime_sdk::errors::codes::{get_explanation, ERROR_CODES};
+
+mod common;
+
+/// Compiles a grammar and gets the codes of the reported errors and warnings
+fn compile(grammar: &str, output: &str) -> Vec<&'static str> {
+    match common::execute(&format!("codes_{output}"), grammar, |_| {}) {
+        Ok(data) => data.warnings.iter().map(|error| error.get_code()).collect(),
+        Err(errors) => errors.errors.iter().map(|error| error.get_code()).collect(),
+    }
+}
+
+/// Gets the grammars in the code blocks of an explanation, with whether they are erroneous
+fn get_examples(explanation: &str) -> Vec<(bool, String)> {
+    let mut examples = Vec::new();
+    let mut current: Option<(bool, String)> = None;
+    for line in explanation.lines() {
+        match current.as_mut() {
+            Some(_) if line == "```" => examples.push(current.take().unwrap()),
+            Some((_, content)) => {
+                content.push_str(line);
+                content.push('\n');
+            }
+            None if line == "```hime" => current = Some((false, String::new())),
+            None if line == "```hime,error" => current = Some((true, String::new())),
+            None => {}
+        }
+    }
+    examples
+}
+
+#[test]
+fn test_codes_are_stable() {
+    for (index, (code, explanation)) in ERROR_CODES.iter().enumerate() {
+        assert_eq!(*code, format!("H{:04}", index + 1));
+        assert!(!explanation.is_empty());
+    }
+    assert_eq!(get_explanation("h0008"), Some(ERROR_CODES[7].1));
+    assert_eq!(get_explanation("H9999"), None);
+}
+
+#[test]
+fn test_codes_examples() {
+    let mut failures = Vec::new();
+    for (code, explanation) in ERROR_CODES {
+        for (index, (erroneous, grammar)) in get_examples(explanation).into_iter().enumerate() {
+            let codes = compile(&grammar, &format!("{code}_{index}"));
+            if codes.contains(&code) != erroneous {
+                failures.push(format!("example {index} of {code} reports {codes:?}"));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{failures:#?}");
+}
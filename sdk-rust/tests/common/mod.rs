@@ -0,0 +1,168 @@
+//! Fixtures shared by the integration tests
+//! Each test crate uses a part of them only.
+
+#![allow(dead_code)]
+
+use std::fmt::Debug;
+
+use hime_redist::ast::{AstImpl, AstNode};
+use hime_redist::errors::ParseError;
+use hime_redist::result::ParseResult;
+use hime_redist::symbols::SemanticElementTrait;
+use hime_sdk::errors::{Error, Errors};
+use hime_sdk::sdk::InMemoryParser;
+use hime_sdk::{CompilationTask, Input, LoadedData, ParsingMethod, Runtime};
+
+/// The parsing methods for the LR(1) and GLR parsers, which must produce the same results
+pub const METHODS: [ParsingMethod; 2] = [ParsingMethod::LALR1, ParsingMethod::RNGLALR1];
+
+/// Gets a compilation task for a grammar with the specified parsing method
+pub fn new_task(grammar: &str, method: ParsingMethod) -> CompilationTask<'_> {
+    CompilationTask {
+        inputs: vec![Input::Raw(grammar)],
+        method: Some(method),
+        ..Default::default()
+    }
+}
+
+/// Builds the in-memory parser for the first grammar of a task, then runs a test on it
+pub fn with_parser<R>(
+    task: &CompilationTask,
+    test: impl FnOnce(&InMemoryParser) -> R,
+) -> Result<R, Vec<Error>> {
+    let mut data = task.load().unwrap();
+    let parser = task.generate_in_memory(&mut data.grammars[0], 0)?;
+    Ok(test(&parser))
+}
+
+/// Runs a test on the LR(1) and GLR parsers for a grammar
+/// Gets the result, which must be the same for both parsers
+pub fn compare<R: Debug + PartialEq>(grammar: &str, test: impl Fn(&InMemoryParser) -> R) -> R {
+    let mut results: Vec<R> = METHODS
+        .iter()
+        .map(|method| with_parser(&new_task(grammar, *method), &test).unwrap())
+        .collect();
+    assert_eq!(results[0], results[1]);
+    results.pop().unwrap()
+}
+
+/// Builds the parser for a grammar with the specified method and parses an input
+/// Gets the AST rendered as a string, or `None` when the input is rejected
+pub fn parse(
+    grammar: &str,
+    method: ParsingMethod,
+    input: &str,
+) -> Result<Option<String>, Vec<Error>> {
+    with_parser(&new_task(grammar, method), |parser| {
+        let result = parser.parse(input);
+        if result.is_success() {
+            Some(print(result.get_ast().get_root()))
+        } else {
+            None
+        }
+    })
+}
+
+/// Loads a grammar that is expected to be erroneous and gets the errors
+pub fn load_errors(grammar: &str) -> Vec<Error> {
+    let task = CompilationTask {
+        inputs: vec![Input::Raw(grammar)],
+        ..Default::default()
+    };
+    task.load().err().unwrap().errors
+}
+
+/// Builds the first grammar in an input and outputs its artifacts for a runtime
+/// Gets the errors, the output is expected to fail
+pub fn output_errors(grammar: &str, runtime: Runtime) -> Vec<Error> {
+    let task = CompilationTask {
+        inputs: vec![Input::Raw(grammar)],
+        output_target: Some(runtime),
+        ..Default::default()
+    };
+    let mut data = task.load().unwrap();
+    let build = data.grammars[0].build(None, None, 0).unwrap();
+    hime_sdk::output::output_grammar_artifacts(&task, &data.grammars[0], 0, &build)
+        .err()
+        .unwrap()
+}
+
+/// Executes a compilation task for a grammar in a temporary output directory
+pub fn execute(
+    name: &str,
+    grammar: &str,
+    setup: impl FnOnce(&mut CompilationTask),
+) -> Result<LoadedData<'static>, Errors<'static>> {
+    let output = std::env::temp_dir().join(format!("hime_test_{name}"));
+    std::fs::create_dir_all(&output).unwrap();
+    let mut task = CompilationTask {
+        inputs: vec![Input::Raw(grammar)],
+        output_target: Some(Runtime::Rust),
+        output_path: Some(output.to_string_lossy().to_string()),
+        ..Default::default()
+    };
+    setup(&mut task);
+    let result = task
+        .execute()
+        .map(LoadedData::into_static)
+        .map_err(Errors::into_static);
+    std::fs::remove_dir_all(&output).unwrap();
+    result
+}
+
+/// Gets the identifier of a terminal
+pub fn get_id(parser: &InMemoryParser, name: &str) -> u32 {
+    parser
+        .terminals
+        .iter()
+        .find(|terminal| terminal.name == name)
+        .unwrap()
+        .id
+}
+
+/// Gets the names of the terminals and the values of the tokens in a result
+pub fn get_tokens(result: &ParseResult<'_, '_, '_, AstImpl>) -> Vec<(String, String)> {
+    result
+        .get_tokens()
+        .iter()
+        .map(|token| {
+            (
+                token.get_symbol().name.to_string(),
+                token.get_value().unwrap_or_default().to_string(),
+            )
+        })
+        .collect()
+}
+
+/// Gets the names of the terminals and the values of expected tokens
+pub fn tokens<'a>(expected: &[(&'a str, &'a str)]) -> Vec<(String, String)> {
+    expected
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+/// Gets the messages of the errors in a result
+pub fn get_errors(result: &ParseResult<'_, '_, '_, AstImpl>) -> Vec<String> {
+    result
+        .errors
+        .errors
+        .iter()
+        .map(ParseError::to_string)
+        .collect()
+}
+
+/// Gets a textual representation of a node and its children
+pub fn print(node: AstNode) -> String {
+    let children = node.children();
+    let name = match node.get_value() {
+        Some(value) if children.is_empty() => format!("{}:{value}", node.get_symbol().name),
+        _ => node.get_symbol().name.to_string(),
+    };
+    if children.is_empty() {
+        name
+    } else {
+        let children: Vec<String> = children.iter().map(print).collect();
+        format!("{name}({})", children.join(" "))
+    }
+}
@@ -0,0 +1,124 @@
+use hime_redist::ast::AstNode;
+use hime_redist::lexers::{ParseHooks, UserContextProvider};
+use hime_redist::symbols::SemanticElementTrait;
+use hime_redist::text::Text;
+use hime_sdk::loaders::hime_grammar::{parse_str, parse_str_with_contexts, parse_text_with_hooks};
+
+mod common;
+
+/// A grammar with the lexer hack, a statement being either a declaration or a call
+const GRAMMAR: &str = r#"
+grammar LexerHack
+{
+    options { Axiom = "unit"; Separator = "WS"; }
+    terminals
+    {
+        WS -> ' '+ ;
+        ID -> [a-z]+ ;
+        context types
+        {
+            TYPENAME -> [a-z]+ ;
+        }
+    }
+    rules
+    {
+        unit -> stmt^ ;
+        stmt -> decl^ | call^ ;
+        decl -> #types{ TYPENAME } ID ;
+        call -> ID ID ;
+    }
+}
+"#;
+
+/// A provider of the known type names
+struct TypeNames<'a> {
+    names: &'a [&'a str],
+}
+
+impl UserContextProvider for TypeNames<'_> {
+    fn get_context_priority(
+        &self,
+        value: &str,
+        context: u16,
+        _terminal_id: u32,
+        parser_priority: Option<usize>,
+    ) -> Option<usize> {
+        if context == 0 || self.names.contains(&value) {
+            parser_priority
+        } else {
+            // the context for type names is closed for this value
+            None
+        }
+    }
+}
+
+/// Gets the symbols of a node and its children
+fn get_symbols(node: AstNode) -> Vec<String> {
+    let mut symbols = vec![node.get_symbol().name.to_string()];
+    symbols.extend(
+        node.children()
+            .iter()
+            .map(|child| child.get_symbol().name.to_string()),
+    );
+    symbols
+}
+
+/// Parses an input with the LR(1) and GLR parsers, with a provider of type names if any
+/// Gets the symbols of the root and its children, which must be the same for both parsers
+fn parse(input: &str, names: Option<&[&str]>) -> Vec<String> {
+    common::compare(GRAMMAR, |parser| {
+        let provider = names.map(|names| TypeNames { names });
+        let hooks = match &provider {
+            Some(provider) => ParseHooks::new().with_contexts(provider),
+            None => ParseHooks::new(),
+        };
+        let result = parser.parse_with_hooks(Text::from_str(input), hooks);
+        assert!(result.is_success(), "{:?}", result.errors.errors);
+        get_symbols(result.get_ast().get_root())
+    })
+}
+
+#[test]
+fn test_user_context_provider_open() {
+    assert_eq!(parse("int x", Some(&["int"])), ["decl", "TYPENAME", "ID"]);
+}
+
+#[test]
+fn test_user_context_provider_closed() {
+    assert_eq!(parse("print x", Some(&["int"])), ["call", "ID", "ID"]);
+}
+
+#[test]
+fn test_user_context_provider_absent() {
+    // without provider, the context opened by the parser has the priority
+    assert_eq!(parse("print x", None), ["decl", "TYPENAME", "ID"]);
+}
+
+/// A provider that closes all the contexts but the default one
+struct DefaultOnly;
+
+impl UserContextProvider for DefaultOnly {
+    fn get_context_priority(
+        &self,
+        _value: &str,
+        context: u16,
+        _terminal_id: u32,
+        parser_priority: Option<usize>,
+    ) -> Option<usize> {
+        if context == 0 {
+            parser_priority
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn test_generated_parse_with_contexts() {
+    const INPUT: &str = "grammar G { options { } terminals { external X; } rules { e -> X ; } }";
+    assert!(parse_str(INPUT).is_success());
+    // `external` is lexed as a name when the context for the keyword is closed
+    assert!(!parse_str_with_contexts(INPUT, &DefaultOnly).is_success());
+    let hooks = ParseHooks::new().with_contexts(&DefaultOnly);
+    assert!(!parse_text_with_hooks(Text::from_str(INPUT), hooks).is_success());
+}
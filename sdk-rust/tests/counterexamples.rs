@@ -0,0 +1,115 @@
+use hime_sdk::errors::Error;
+use hime_sdk::lr::{ConflictKind, Counterexample};
+use hime_sdk::ParsingMethod;
+
+mod common;
+
+/// An ambiguous grammar
+const AMBIGUOUS: &str = r#"
+grammar Test
+{
+    options { Axiom = "s"; }
+    terminals
+    {
+        NUM -> [0-9]+ ;
+        ID -> [a-z]+ ;
+        IF -> 'if' ;
+        THEN -> 'then' ;
+        ELSE -> 'else' ;
+    }
+    rules
+    {
+        s -> e | st ;
+        e -> e '+' e | NUM ;
+        st -> IF e THEN st | IF e THEN st ELSE st | ID ;
+    }
+}
+"#;
+
+/// A grammar that is not ambiguous but requires two tokens of lookahead
+const LOOKAHEAD: &str = r#"
+grammar Test
+{
+    options { Axiom = "s"; }
+    terminals
+    {
+        A -> 'a' ;
+        X -> 'x' ;
+        Y -> 'y' ;
+        Z -> 'z' ;
+    }
+    rules
+    {
+        s -> a X Y | b X Z ;
+        a -> A ;
+        b -> A ;
+    }
+}
+"#;
+
+/// Builds the grammar and gets the conflicts with their counterexamples, rendered as text
+fn get_counterexamples(grammar: &str) -> Vec<(ConflictKind, String, Counterexample, [String; 4])> {
+    let task = common::new_task(grammar, ParsingMethod::LALR1);
+    let mut data = task.load().unwrap();
+    let errors = data.grammars[0].build(task.method, None, 0).err().unwrap();
+    let grammar = &data.grammars[0];
+    errors
+        .into_iter()
+        .filter_map(|error| match error {
+            Error::LrConflict(_, conflict) => {
+                let counterexample = conflict.counterexample.unwrap();
+                let texts = [
+                    counterexample.first.get_example(grammar),
+                    counterexample.first.get_description(grammar),
+                    counterexample.second.get_example(grammar),
+                    counterexample.second.get_description(grammar),
+                ];
+                Some((
+                    conflict.kind,
+                    conflict.lookahead.get_value(grammar),
+                    counterexample,
+                    texts,
+                ))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_counterexample_unifying() {
+    let conflicts = get_counterexamples(AMBIGUOUS);
+    assert_eq!(conflicts.len(), 2);
+    for (kind, lookahead, counterexample, texts) in conflicts {
+        assert_eq!(kind, ConflictKind::ShiftReduce);
+        assert!(counterexample.unifying);
+        assert_eq!(texts[0], texts[2]);
+        if lookahead == "+" {
+            assert_eq!(texts[0], "e + e • + e");
+            assert_eq!(texts[1], "[s -> [e -> e + [e -> e • + e]]]");
+            assert_eq!(texts[3], "[s -> [e -> [e -> e + e •] + e]]");
+        } else {
+            assert_eq!(texts[0], "IF e THEN IF e THEN st • ELSE st");
+            assert_eq!(
+                texts[1],
+                "[s -> [st -> IF e THEN [st -> IF e THEN st • ELSE st]]]"
+            );
+            assert_eq!(
+                texts[3],
+                "[s -> [st -> IF e THEN [st -> IF e THEN st •] ELSE st]]"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_counterexample_non_unifying() {
+    let conflicts = get_counterexamples(LOOKAHEAD);
+    assert_eq!(conflicts.len(), 1);
+    let (kind, _, counterexample, texts) = &conflicts[0];
+    assert_eq!(*kind, ConflictKind::ReduceReduce);
+    assert!(!counterexample.unifying);
+    let mut texts = [&texts[0], &texts[2]];
+    texts.sort();
+    assert_eq!(texts, [&"A • X Y", &"A • X Z"]);
+}
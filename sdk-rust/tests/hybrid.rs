@@ -0,0 +1,97 @@
+use hime_sdk::{CompilationTask, Input, ParsingMethod};
+
+mod common;
+
+/// An ambiguous expression grammar with a deterministic part
+const AMBIGUOUS: &str = r#"
+grammar Test
+{
+    options { Axiom = "s"; Separator = "WS"; }
+    terminals
+    {
+        WS -> ' '+ ;
+        NUM -> [0-9]+ ;
+        ID -> [a-z]+ ;
+    }
+    rules
+    {
+        s -> decl* ;
+        decl -> ID '=' e ';' ;
+        e -> e '+' e | e '*' e | '(' e ')' | NUM | ID ;
+    }
+}
+"#;
+
+/// A grammar with nullable variables and a conflict requiring more than one token of lookahead
+const NULLABLE: &str = r#"
+grammar Test
+{
+    options { Axiom = "s"; }
+    terminals
+    {
+        A -> 'a' ;
+        X -> 'x' ;
+        Y -> 'y' ;
+        Z -> 'z' ;
+    }
+    rules
+    {
+        s -> item* ;
+        item -> opt a X Y | opt b X Z ;
+        opt -> Z? ;
+        a -> A ;
+        b -> A ;
+    }
+}
+"#;
+
+/// Checks that the hybrid methods produce the same results as the RNGLR methods
+fn check_same(grammar: &str, input: &str) -> Option<String> {
+    let expected = common::parse(grammar, ParsingMethod::RNGLR1, input).unwrap();
+    for method in [
+        ParsingMethod::RNGLALR1,
+        ParsingMethod::HLR1,
+        ParsingMethod::HLALR1,
+    ] {
+        assert_eq!(common::parse(grammar, method, input).unwrap(), expected);
+    }
+    expected
+}
+
+#[test]
+fn test_hybrid_ambiguous() {
+    assert!(check_same(AMBIGUOUS, "x = 1;").is_some());
+    assert!(check_same(AMBIGUOUS, "x = 1 + 2 * 3; y = (x + 1) * 2;").is_some());
+    assert!(check_same(AMBIGUOUS, "x = 1 + 2 + 3 + 4; y = x;").is_some());
+    assert!(check_same(AMBIGUOUS, "").is_some());
+}
+
+#[test]
+fn test_hybrid_nullable() {
+    assert!(check_same(NULLABLE, "axy").is_some());
+    assert!(check_same(NULLABLE, "zaxzaxyzaxy").is_some());
+}
+
+#[test]
+fn test_hybrid_errors() {
+    assert!(check_same(AMBIGUOUS, "x = 1 +;").is_none());
+    assert!(check_same(AMBIGUOUS, "x = (1 + 2;").is_none());
+    assert!(check_same(NULLABLE, "axz axa").is_none());
+}
+
+#[test]
+fn test_hybrid_option() {
+    let grammar = AMBIGUOUS.replace(
+        "Separator = \"WS\";",
+        "Separator = \"WS\"; Method = \"hlalr1\";",
+    );
+    let task = CompilationTask {
+        inputs: vec![Input::Raw(&grammar)],
+        ..Default::default()
+    };
+    common::with_parser(&task, |parser| {
+        assert!(parser.parser_is_hybrid);
+        assert!(parser.parse("x = 1 + 2 * 3;").is_success());
+    })
+    .unwrap();
+}
@@ -0,0 +1,106 @@
+use hime_sdk::errors::Error;
+use hime_sdk::lr::{build_graph_ielr1, build_graph_lalr1, build_graph_lr1};
+use hime_sdk::ParsingMethod;
+
+mod common;
+
+/// A grammar that is LR(1) but not LALR(1)
+const GRAMMAR: &str = r#"
+grammar Test
+{
+    options { Axiom = "s"; }
+    terminals
+    {
+        A -> 'a' ;
+        B -> 'b' ;
+        C -> 'c' ;
+        D -> 'd' ;
+        E -> 'e' ;
+    }
+    rules
+    {
+        s -> A x D | B y D | A y E | B x E | C z D | D z E ;
+        x -> C ;
+        y -> C ;
+        z -> C C ;
+    }
+}
+"#;
+
+/// The minimal LR(1) graph has no LALR(1) conflicts and is smaller than the LR(1) graph
+#[test]
+fn test_ielr1_without_lalr_conflicts() {
+    let task = common::new_task(GRAMMAR, ParsingMethod::LALR1);
+    let mut data = task.load().unwrap();
+    let errors = task
+        .generate_in_memory(&mut data.grammars[0], 0)
+        .err()
+        .unwrap();
+    assert!(errors
+        .iter()
+        .all(|error| matches!(error, Error::LrConflict(_, _))));
+
+    let grammar = &data.grammars[0];
+    let (lalr1, _) = build_graph_lalr1(grammar);
+    let (lr1, _) = build_graph_lr1(grammar);
+    let (ielr1, conflicts) = build_graph_ielr1(grammar);
+    assert!(conflicts.is_empty());
+    assert!(ielr1.states.len() > lalr1.states.len());
+    assert!(ielr1.states.len() < lr1.states.len());
+
+    let task = common::new_task(GRAMMAR, ParsingMethod::IELR1);
+    common::with_parser(&task, |parser| {
+        for input in ["acd", "bcd", "ace", "bce", "cccd", "dcce"] {
+            assert!(parser.parse(input).is_success());
+        }
+        assert!(!parser.parse("acc").is_success());
+    })
+    .unwrap();
+}
+
+/// The conflicts that are absent from the LR(1) graph are explained by the LALR(1) merge
+#[test]
+fn test_lalr1_merge_conflicts() {
+    let task = common::new_task(GRAMMAR, ParsingMethod::LALR1);
+    let mut data = task.load().unwrap();
+    let errors = data.grammars[0].build(task.method, None, 0).err().unwrap();
+    let grammar = &data.grammars[0];
+    assert!(!errors.is_empty());
+    for error in errors {
+        let Error::LrConflict(_, conflict) = error else {
+            panic!("expected a LR conflict");
+        };
+        let merge = conflict.lalr_merge.unwrap();
+        assert_eq!(merge.states.len(), 2);
+        let mut rules: Vec<&str> = merge
+            .leaks
+            .iter()
+            .map(|leak| {
+                grammar
+                    .get_variable(leak.rule.variable)
+                    .unwrap()
+                    .name
+                    .as_str()
+            })
+            .collect();
+        rules.sort_unstable();
+        assert_eq!(rules, ["x", "y"]);
+        assert!(merge
+            .leaks
+            .iter()
+            .all(|leak| merge.states.contains(&leak.state) && leak.phrase.0.len() == 2));
+    }
+
+    // a conflict that is also in the LR(1) graph
+    let task = common::new_task(
+        r#"grammar Test { options { Axiom = "e"; } rules { e -> e '+' e | 'x' ; } }"#,
+        ParsingMethod::LALR1,
+    );
+    let mut data = task.load().unwrap();
+    let errors = data.grammars[0].build(task.method, None, 0).err().unwrap();
+    assert!(!errors.is_empty());
+    assert!(errors.iter().all(|error| matches!(
+        error,
+        Error::LrConflict(_, conflict) if conflict.lalr_merge.is_none()
+    )));
+}
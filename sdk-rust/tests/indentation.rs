@@ -0,0 +1,105 @@
+use hime_sdk::errors::Error;
+use hime_sdk::{ParsingMethod, Runtime};
+
+mod common;
+
+/// An indentation-sensitive grammar with blocks of statements
+const GRAMMAR: &str = r#"
+grammar Blocks
+{
+    options { Axiom = "unit"; Indentation = "true"; }
+    terminals
+    {
+        ID -> [a-z]+ ;
+    }
+    rules
+    {
+        unit -> stmt* ;
+        stmt -> simple^ | compound^ ;
+        simple -> ID args? NEWLINE! ;
+        args -> '(' ID* ')' ;
+        compound -> ID ':' NEWLINE! INDENT! stmt+ DEDENT! ;
+    }
+}
+"#;
+
+/// Parses an input with the LR(1) and GLR parsers
+/// Gets the names of the matched terminals and the errors, which must be the same for both parsers
+fn parse(grammar: &str, input: &str) -> (Vec<String>, Vec<String>) {
+    let task = common::new_task(grammar, ParsingMethod::LALR1);
+    assert!(task.load().unwrap().warnings.is_empty());
+    common::compare(grammar, |parser| {
+        let result = parser.parse(input);
+        let terminals = common::get_tokens(&result)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        (terminals, common::get_errors(&result))
+    })
+}
+
+#[test]
+fn test_indentation_blocks() {
+    let (terminals, errors) = parse(GRAMMAR, "if:\n  a\n  b\n\nc\n");
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(
+        terminals,
+        [
+            "ID", ":", "NEWLINE", "INDENT", "ID", "NEWLINE", "ID", "NEWLINE", "DEDENT", "ID",
+            "NEWLINE", "$"
+        ]
+    );
+}
+
+#[test]
+fn test_indentation_nested_blocks_at_end() {
+    let (terminals, errors) = parse(GRAMMAR, "if:\n  for:\n    a");
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(
+        terminals,
+        [
+            "ID", ":", "NEWLINE", "INDENT", "ID", ":", "NEWLINE", "INDENT", "ID", "NEWLINE",
+            "DEDENT", "DEDENT", "$"
+        ]
+    );
+}
+
+#[test]
+fn test_indentation_in_brackets() {
+    // line breaks and indentation within brackets are ignored
+    let (terminals, errors) = parse(GRAMMAR, "f(a\n      b\n c)\nd\n");
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(
+        terminals,
+        ["ID", "(", "ID", "ID", "ID", ")", "NEWLINE", "ID", "NEWLINE", "$"]
+    );
+}
+
+#[test]
+fn test_indentation_inconsistent_dedent() {
+    let (_, errors) = parse(GRAMMAR, "if:\n    a\n  b\n");
+    assert_eq!(
+        errors[0],
+        "Inconsistent indentation: column 2 does not match any enclosing level"
+    );
+}
+
+#[test]
+fn test_indentation_user_defined_terminal() {
+    // a statement may also be terminated by a semicolon
+    let grammar = GRAMMAR.replace("ID -> [a-z]+ ;", "ID -> [a-z]+ ; NEWLINE -> ';' ;");
+    let (terminals, errors) = parse(&grammar, "a; b\n");
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(terminals, ["ID", "NEWLINE", "ID", "NEWLINE", "$"]);
+}
+
+#[test]
+fn test_indentation_unsupported_runtimes() {
+    for runtime in [Runtime::Net, Runtime::Java] {
+        let errors = common::output_errors(GRAMMAR, runtime);
+        assert!(matches!(
+            &errors[..],
+            [Error::InvalidOption(0, option, _)] if option == "Indentation"
+        ));
+    }
+}
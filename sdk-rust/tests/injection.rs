@@ -0,0 +1,92 @@
+//! The parsers generated for `injection/Injection.gram`
+//! The parsers of the injected grammars are expected in sibling modules named after them.
+
+use hime_redist::errors::ParseErrorDataTrait;
+use hime_redist::symbols::SemanticElementTrait;
+use hime_redist::text::TextPosition;
+use hime_sdk::errors::Error;
+use hime_sdk::{CompilationTask, Input, Runtime};
+
+mod common;
+
+#[allow(dead_code)]
+#[path = "injection/expression.rs"]
+mod expression;
+#[allow(dead_code)]
+#[path = "injection/template.rs"]
+mod template;
+
+#[test]
+fn test_injection_grafted_trees() {
+    let result = template::parse_str("a {1 + x} b {y}");
+    assert!(result.is_success());
+    assert_eq!(
+        common::print(result.get_ast().get_root()),
+        "template(part(TEXT:a ) part(CODE(code(+(term(NUM:1) term(ID:x))))) part(TEXT: b ) part(CODE(code(term(ID:y)))))"
+    );
+}
+
+#[test]
+fn test_injection_positions() {
+    let result = template::parse_str("a {1 + x} b {y}");
+    let ast = result.get_ast();
+    let node = ast.find_node_at_index(7).unwrap();
+    assert_eq!(node.get_symbol().name, "ID");
+    assert_eq!(node.get_value(), Some("x"));
+    assert_eq!(
+        node.get_position(),
+        Some(TextPosition { line: 1, column: 8 })
+    );
+}
+
+#[test]
+fn test_injection_errors() {
+    // the errors in the injected language are reported in the host text
+    let result = template::parse_str("a {1 +} b");
+    let error = &result.errors.errors[0];
+    assert!(error.to_string().starts_with("Unexpected token \"}\""));
+    assert_eq!(error.get_position(), TextPosition { line: 1, column: 7 });
+}
+
+/// Loads the grammars for the test of injected languages, with the specified value for the `Inject` option
+fn load_with_injection(inject: &str) -> Vec<Error> {
+    let grammars = include_str!("injection/Injection.gram").replace(
+        "Inject = \"CODE: Expression.code\";",
+        &format!("Inject = \"{inject}\";"),
+    );
+    let task = CompilationTask {
+        inputs: vec![Input::Raw(&grammars)],
+        ..Default::default()
+    };
+    task.load()
+        .err()
+        .map(|errors| errors.errors)
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_injection_undefined_grammar() {
+    assert!(load_with_injection("CODE: Expression").is_empty());
+    let errors = load_with_injection("CODE: Expresion");
+    assert!(matches!(&errors[..], [Error::GrammarNotFound(name)] if name == "Expresion"));
+}
+
+#[test]
+fn test_injection_undefined_axiom() {
+    let errors = load_with_injection("CODE: Expression.expr");
+    assert!(matches!(
+        &errors[..],
+        [Error::InvalidOption(0, option, valid)] if option == "Inject" && valid == &["Expression.code"]
+    ));
+}
+
+#[test]
+fn test_injection_unsupported_runtimes() {
+    for runtime in [Runtime::Net, Runtime::Java] {
+        let errors = common::output_errors(include_str!("injection/Injection.gram"), runtime);
+        assert!(matches!(
+            &errors[..],
+            [Error::InvalidOption(0, option, _)] if option == "Inject"
+        ));
+    }
+}
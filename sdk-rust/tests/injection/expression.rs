@@ -0,0 +1,272 @@
+//! Module for the lexer and parser for `Expression`
+//! WARNING: this file has been generated by
+//! Hime Parser Generator 4.3.0
+
+use hime_redist::ast::{AstImpl, AstNode};
+use hime_redist::errors::ParseErrors;
+use hime_redist::lexers::automaton::Automaton;
+use hime_redist::lexers::filter::FilteredSource;
+use hime_redist::lexers::impls::ContextFreeLexer;
+use hime_redist::lexers::Lexer;
+use hime_redist::lexers::ParseHooks;
+use hime_redist::lexers::TokenSource;
+use hime_redist::parsers::lrk::LRkAutomaton;
+use hime_redist::parsers::lrk::LRkParser;
+use hime_redist::parsers::Parser;
+use hime_redist::result::{ParseResult, ParseResultAst};
+use hime_redist::symbols::SemanticBody;
+use hime_redist::symbols::SemanticElementTrait;
+use hime_redist::symbols::Symbol;
+use hime_redist::text::Text;
+use hime_redist::tokens::TokenRepository;
+
+/// Static resource for the serialized lexer automaton
+static LEXER_AUTOMATON: &[u8] = include_bytes!("expression_lexer.bin");
+
+/// The unique identifier for terminal `WS`
+pub const ID_TERMINAL_WS: u32 = 0x0003;
+/// The unique identifier for terminal `ID`
+pub const ID_TERMINAL_ID: u32 = 0x0004;
+/// The unique identifier for terminal `NUM`
+pub const ID_TERMINAL_NUM: u32 = 0x0005;
+
+/// The unique identifier for the default context
+pub const CONTEXT_DEFAULT: u16 = 0;
+
+/// The collection of terminals matched by this lexer
+/// The terminals are in an order consistent with the automaton,
+/// so that terminal indices in the automaton can be used to retrieve the terminals in this table
+pub const TERMINALS: &[Symbol] = &[
+    Symbol {
+        id: 0x0001,
+        name: "ε",
+    },
+    Symbol {
+        id: 0x0002,
+        name: "$",
+    },
+    Symbol {
+        id: 0x0003,
+        name: "WS",
+    },
+    Symbol {
+        id: 0x0004,
+        name: "ID",
+    },
+    Symbol {
+        id: 0x0005,
+        name: "NUM",
+    },
+    Symbol {
+        id: 0x0009,
+        name: "{",
+    },
+    Symbol {
+        id: 0x000A,
+        name: "}",
+    },
+    Symbol {
+        id: 0x000B,
+        name: "+",
+    },
+];
+
+/// Creates a new lexer
+fn new_lexer<'a: 'b, 'b, 'c>(
+    repository: TokenRepository<'a, 'b, 'c>,
+    errors: &'c mut ParseErrors<'a>,
+) -> Lexer<'a, 'b, 'c> {
+    let automaton = Automaton::new(LEXER_AUTOMATON);
+    Lexer::ContextFree(ContextFreeLexer::new(repository, errors, automaton, 0x0003))
+}
+
+/// Static resource for the serialized parser automaton
+static PARSER_AUTOMATON: &[u8] = include_bytes!("expression_parser.bin");
+
+/// The unique identifier for variable `code`
+pub const ID_VARIABLE_CODE: u32 = 0x0006;
+/// The unique identifier for variable `expr`
+pub const ID_VARIABLE_EXPR: u32 = 0x0007;
+/// The unique identifier for variable `term`
+pub const ID_VARIABLE_TERM: u32 = 0x0008;
+
+/// The collection of variables matched by this parser
+/// The variables are in an order consistent with the automaton,
+/// so that variable indices in the automaton can be used to retrieve the variables in this table
+pub const VARIABLES: &[Symbol] = &[
+    Symbol {
+        id: 0x0006,
+        name: "code",
+    },
+    Symbol {
+        id: 0x0007,
+        name: "expr",
+    },
+    Symbol {
+        id: 0x0008,
+        name: "term",
+    },
+    Symbol {
+        id: 0x000C,
+        name: "__VAxiom",
+    },
+];
+
+/// The collection of virtuals matched by this parser
+/// The virtuals are in an order consistent with the automaton,
+/// so that virtual indices in the automaton can be used to retrieve the virtuals in this table
+pub const VIRTUALS: &[Symbol] = &[];
+
+/// Parses the specified string with this parser
+#[must_use]
+pub fn parse_str(input: &str) -> ParseResult<'static, '_, 'static, AstImpl> {
+    let text = Text::from_str(input);
+    parse_text(text)
+}
+
+/// Parses the specified string with this parser
+#[must_use]
+pub fn parse_string(input: String) -> ParseResultAst {
+    let text = Text::from_string(input);
+    parse_text(text)
+}
+
+/// Parses the specified stream of UTF-8 with this parser
+///
+/// # Errors
+///
+/// Return an `std::io::Error` when reading the stream as UTF-8 fails
+pub fn parse_utf8_stream(input: &mut dyn std::io::Read) -> Result<ParseResultAst, std::io::Error> {
+    let text = Text::from_utf8_stream(input)?;
+    Ok(parse_text(text))
+}
+
+/// Parses the specified string as a `code` with this parser
+#[must_use]
+pub fn parse_axiom_code(input: &str) -> ParseResult<'static, '_, 'static, AstImpl> {
+    let text = Text::from_str(input);
+    parse_text_with(text, TERMINALS, VARIABLES, VIRTUALS, ParseHooks::new())
+}
+
+/// Parses the specified text with this parser
+/// The text may be configured for the computation of columns, see `Text::with_config`.
+#[must_use]
+pub fn parse_text<'t>(text: Text<'t>) -> ParseResult<'static, 't, 'static, AstImpl> {
+    parse_text_with(text, TERMINALS, VARIABLES, VIRTUALS, ParseHooks::new())
+}
+
+/// Parses the specified text with this parser, with hooks into the production of the tokens
+/// The hooks combine a user-defined context provider, an external scanner and a filter of the tokens.
+#[must_use]
+pub fn parse_text_with_hooks<'t>(
+    text: Text<'t>,
+    hooks: ParseHooks,
+) -> ParseResult<'static, 't, 'static, AstImpl> {
+    parse_text_with(text, TERMINALS, VARIABLES, VIRTUALS, hooks)
+}
+
+/// Parses the specified text with this parser
+fn parse_text_with<'s, 't, 'a>(
+    text: Text<'t>,
+    terminals: &'a [Symbol<'s>],
+    variables: &'a [Symbol<'s>],
+    virtuals: &'a [Symbol<'s>],
+    hooks: ParseHooks,
+) -> ParseResult<'s, 't, 'a, AstImpl> {
+    let mut my_actions = |_index: usize, _head: Symbol, _body: &dyn SemanticBody| {};
+    let mut result = ParseResult::<AstImpl>::new(terminals, variables, virtuals, text);
+    {
+        let data = result.get_parsing_data();
+        let mut lexer = new_lexer(data.0, data.1);
+        if let Some(contexts) = hooks.contexts {
+            lexer.set_user_context_provider(contexts);
+        }
+        if let Some(scanner) = hooks.scanner {
+            lexer.set_external_scanner(scanner);
+        }
+        let mut filtered;
+        let source: &mut dyn TokenSource = if let Some(filter) = hooks.filter {
+            filtered = FilteredSource::new(lexer, filter);
+            &mut filtered
+        } else {
+            &mut lexer
+        };
+        let automaton = LRkAutomaton::new(PARSER_AUTOMATON);
+        let mut parser = LRkParser::new(
+            source,
+            variables,
+            virtuals,
+            automaton,
+            data.2,
+            &mut my_actions,
+        );
+        parser.parse();
+    }
+    result
+}
+
+/// Parses the specified text with this parser, using the tokens produced by a custom source
+/// The source is created from the repository of tokens and the repository for errors of the result.
+pub fn parse_text_from<'t, F>(
+    text: Text<'t>,
+    new_source: F,
+) -> ParseResult<'static, 't, 'static, AstImpl>
+where
+    F: for<'a> FnOnce(
+        TokenRepository<'static, 't, 'a>,
+        &'a mut ParseErrors<'static>,
+    ) -> Box<dyn TokenSource<'static, 't, 'a> + 'a>,
+{
+    let mut my_actions = |_index: usize, _head: Symbol, _body: &dyn SemanticBody| {};
+    let mut result = ParseResult::<AstImpl>::new(TERMINALS, VARIABLES, VIRTUALS, text);
+    {
+        let data = result.get_parsing_data();
+        let mut source = new_source(data.0, data.1);
+        let automaton = LRkAutomaton::new(PARSER_AUTOMATON);
+        let mut parser = LRkParser::new(
+            source.as_mut(),
+            VARIABLES,
+            VIRTUALS,
+            automaton,
+            data.2,
+            &mut my_actions,
+        );
+        parser.parse();
+    }
+    result
+}
+
+/// Visitor interface
+#[allow(unused_variables)]
+pub trait Visitor {
+    fn on_terminal_ws(&self, node: &AstNode) {}
+    fn on_terminal_id(&self, node: &AstNode) {}
+    fn on_terminal_num(&self, node: &AstNode) {}
+    fn on_variable_code(&self, node: &AstNode) {}
+    fn on_variable_expr(&self, node: &AstNode) {}
+    fn on_variable_term(&self, node: &AstNode) {}
+}
+
+/// Walk the AST of a result using a visitor
+pub fn visit(result: &ParseResult<AstImpl>, visitor: &dyn Visitor) {
+    let ast = result.get_ast();
+    let root = ast.get_root();
+    visit_ast_node(root, visitor);
+}
+
+/// Walk the sub-AST from the specified node using a visitor
+pub fn visit_ast_node(node: AstNode, visitor: &dyn Visitor) {
+    let children = node.children();
+    for child in children.iter() {
+        visit_ast_node(child, visitor);
+    }
+    match node.get_symbol().id {
+        0x0003 => visitor.on_terminal_ws(&node),
+        0x0004 => visitor.on_terminal_id(&node),
+        0x0005 => visitor.on_terminal_num(&node),
+        0x0006 => visitor.on_variable_code(&node),
+        0x0007 => visitor.on_variable_expr(&node),
+        0x0008 => visitor.on_variable_term(&node),
+        _ => (),
+    };
+}
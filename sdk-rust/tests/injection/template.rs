@@ -0,0 +1,298 @@
+//! Module for the lexer and parser for `Template`
+//! WARNING: this file has been generated by
+//! Hime Parser Generator 4.3.0
+
+use hime_redist::ast::{AstImpl, AstNode};
+use hime_redist::errors::ParseErrors;
+use hime_redist::lexers::automaton::Automaton;
+use hime_redist::lexers::filter::FilteredSource;
+use hime_redist::lexers::impls::ContextFreeLexer;
+use hime_redist::lexers::Lexer;
+use hime_redist::lexers::ParseHooks;
+use hime_redist::lexers::TokenSource;
+use hime_redist::parsers::lrk::LRkAutomaton;
+use hime_redist::parsers::lrk::LRkParser;
+use hime_redist::parsers::Parser;
+use hime_redist::result::{InjectionOffsets, ParseResult, ParseResultAst};
+use hime_redist::symbols::concat_symbols;
+use hime_redist::symbols::SemanticBody;
+use hime_redist::symbols::SemanticElementTrait;
+use hime_redist::symbols::Symbol;
+use hime_redist::text::Text;
+use hime_redist::tokens::TokenRepository;
+
+/// Static resource for the serialized lexer automaton
+static LEXER_AUTOMATON: &[u8] = include_bytes!("template_lexer.bin");
+
+/// The unique identifier for terminal `TEXT`
+pub const ID_TERMINAL_TEXT: u32 = 0x0003;
+/// The unique identifier for terminal `CODE`
+pub const ID_TERMINAL_CODE: u32 = 0x0004;
+
+/// The unique identifier for the default context
+pub const CONTEXT_DEFAULT: u16 = 0;
+
+/// The collection of terminals matched by this lexer
+/// The terminals are in an order consistent with the automaton,
+/// so that terminal indices in the automaton can be used to retrieve the terminals in this table
+pub const TERMINALS: &[Symbol] = &[
+    Symbol {
+        id: 0x0001,
+        name: "ε",
+    },
+    Symbol {
+        id: 0x0002,
+        name: "$",
+    },
+    Symbol {
+        id: 0x0003,
+        name: "TEXT",
+    },
+    Symbol {
+        id: 0x0004,
+        name: "CODE",
+    },
+];
+
+/// Creates a new lexer
+fn new_lexer<'a: 'b, 'b, 'c>(
+    repository: TokenRepository<'a, 'b, 'c>,
+    errors: &'c mut ParseErrors<'a>,
+) -> Lexer<'a, 'b, 'c> {
+    let automaton = Automaton::new(LEXER_AUTOMATON);
+    Lexer::ContextFree(ContextFreeLexer::new(repository, errors, automaton, 0xFFFF))
+}
+
+/// Static resource for the serialized parser automaton
+static PARSER_AUTOMATON: &[u8] = include_bytes!("template_parser.bin");
+
+/// The unique identifier for variable `template`
+pub const ID_VARIABLE_TEMPLATE: u32 = 0x0005;
+/// The unique identifier for variable `part`
+pub const ID_VARIABLE_PART: u32 = 0x0006;
+
+/// The collection of variables matched by this parser
+/// The variables are in an order consistent with the automaton,
+/// so that variable indices in the automaton can be used to retrieve the variables in this table
+pub const VARIABLES: &[Symbol] = &[
+    Symbol {
+        id: 0x0005,
+        name: "template",
+    },
+    Symbol {
+        id: 0x0006,
+        name: "part",
+    },
+    Symbol {
+        id: 0x0007,
+        name: "__V7",
+    },
+    Symbol {
+        id: 0x0008,
+        name: "__VAxiom",
+    },
+];
+
+/// The collection of virtuals matched by this parser
+/// The virtuals are in an order consistent with the automaton,
+/// so that virtual indices in the automaton can be used to retrieve the virtuals in this table
+pub const VIRTUALS: &[Symbol] = &[];
+
+/// The offsets of the symbols of the injected grammar `Expression` in the symbol tables
+const INJECTION_EXPRESSION: InjectionOffsets = InjectionOffsets {
+    terminals: TERMINALS.len(),
+    variables: VARIABLES.len(),
+    virtuals: VIRTUALS.len(),
+};
+
+/// The collection of terminals, including the ones of the injected grammars
+pub const ALL_TERMINALS: &[Symbol] = &concat_symbols::<
+    { TERMINALS.len() + super::expression::TERMINALS.len() },
+>(&[TERMINALS, super::expression::TERMINALS]);
+
+/// The collection of variables, including the ones of the injected grammars
+pub const ALL_VARIABLES: &[Symbol] = &concat_symbols::<
+    { VARIABLES.len() + super::expression::VARIABLES.len() },
+>(&[VARIABLES, super::expression::VARIABLES]);
+
+/// The collection of virtuals, including the ones of the injected grammars
+pub const ALL_VIRTUALS: &[Symbol] = &concat_symbols::<
+    { VIRTUALS.len() + super::expression::VIRTUALS.len() },
+>(&[VIRTUALS, super::expression::VIRTUALS]);
+
+/// Parses the values of the tokens in the injected languages and grafts the results
+/// The parsers of the injected languages are expected in sibling modules, e.g. `super::expression`.
+fn inject(result: &mut ParseResult<'_, '_, '_, AstImpl>) {
+    result.inject(
+        0x0004,
+        &INJECTION_EXPRESSION,
+        super::expression::parse_axiom_code,
+    );
+}
+
+/// Parses the specified string with this parser
+#[must_use]
+pub fn parse_str(input: &str) -> ParseResult<'static, '_, 'static, AstImpl> {
+    let text = Text::from_str(input);
+    parse_text(text)
+}
+
+/// Parses the specified string with this parser
+#[must_use]
+pub fn parse_string(input: String) -> ParseResultAst {
+    let text = Text::from_string(input);
+    parse_text(text)
+}
+
+/// Parses the specified stream of UTF-8 with this parser
+///
+/// # Errors
+///
+/// Return an `std::io::Error` when reading the stream as UTF-8 fails
+pub fn parse_utf8_stream(input: &mut dyn std::io::Read) -> Result<ParseResultAst, std::io::Error> {
+    let text = Text::from_utf8_stream(input)?;
+    Ok(parse_text(text))
+}
+
+/// Parses the specified string as a `template` with this parser
+#[must_use]
+pub fn parse_axiom_template(input: &str) -> ParseResult<'static, '_, 'static, AstImpl> {
+    let text = Text::from_str(input);
+    parse_text_with(
+        text,
+        ALL_TERMINALS,
+        ALL_VARIABLES,
+        ALL_VIRTUALS,
+        ParseHooks::new(),
+    )
+}
+
+/// Parses the specified text with this parser
+/// The text may be configured for the computation of columns, see `Text::with_config`.
+#[must_use]
+pub fn parse_text<'t>(text: Text<'t>) -> ParseResult<'static, 't, 'static, AstImpl> {
+    parse_text_with(
+        text,
+        ALL_TERMINALS,
+        ALL_VARIABLES,
+        ALL_VIRTUALS,
+        ParseHooks::new(),
+    )
+}
+
+/// Parses the specified text with this parser, with hooks into the production of the tokens
+/// The hooks combine a user-defined context provider, an external scanner and a filter of the tokens.
+#[must_use]
+pub fn parse_text_with_hooks<'t>(
+    text: Text<'t>,
+    hooks: ParseHooks,
+) -> ParseResult<'static, 't, 'static, AstImpl> {
+    parse_text_with(text, ALL_TERMINALS, ALL_VARIABLES, ALL_VIRTUALS, hooks)
+}
+
+/// Parses the specified text with this parser
+fn parse_text_with<'s, 't, 'a>(
+    text: Text<'t>,
+    terminals: &'a [Symbol<'s>],
+    variables: &'a [Symbol<'s>],
+    virtuals: &'a [Symbol<'s>],
+    hooks: ParseHooks,
+) -> ParseResult<'s, 't, 'a, AstImpl> {
+    let mut my_actions = |_index: usize, _head: Symbol, _body: &dyn SemanticBody| {};
+    let mut result = ParseResult::<AstImpl>::new(terminals, variables, virtuals, text);
+    {
+        let data = result.get_parsing_data();
+        let mut lexer = new_lexer(data.0, data.1);
+        if let Some(contexts) = hooks.contexts {
+            lexer.set_user_context_provider(contexts);
+        }
+        if let Some(scanner) = hooks.scanner {
+            lexer.set_external_scanner(scanner);
+        }
+        let mut filtered;
+        let source: &mut dyn TokenSource = if let Some(filter) = hooks.filter {
+            filtered = FilteredSource::new(lexer, filter);
+            &mut filtered
+        } else {
+            &mut lexer
+        };
+        let automaton = LRkAutomaton::new(PARSER_AUTOMATON);
+        let mut parser = LRkParser::new(
+            source,
+            variables,
+            virtuals,
+            automaton,
+            data.2,
+            &mut my_actions,
+        );
+        parser.parse();
+    }
+    inject(&mut result);
+    result
+}
+
+/// Parses the specified text with this parser, using the tokens produced by a custom source
+/// The source is created from the repository of tokens and the repository for errors of the result.
+pub fn parse_text_from<'t, F>(
+    text: Text<'t>,
+    new_source: F,
+) -> ParseResult<'static, 't, 'static, AstImpl>
+where
+    F: for<'a> FnOnce(
+        TokenRepository<'static, 't, 'a>,
+        &'a mut ParseErrors<'static>,
+    ) -> Box<dyn TokenSource<'static, 't, 'a> + 'a>,
+{
+    let mut my_actions = |_index: usize, _head: Symbol, _body: &dyn SemanticBody| {};
+    let mut result = ParseResult::<AstImpl>::new(ALL_TERMINALS, ALL_VARIABLES, ALL_VIRTUALS, text);
+    {
+        let data = result.get_parsing_data();
+        let mut source = new_source(data.0, data.1);
+        let automaton = LRkAutomaton::new(PARSER_AUTOMATON);
+        let mut parser = LRkParser::new(
+            source.as_mut(),
+            VARIABLES,
+            VIRTUALS,
+            automaton,
+            data.2,
+            &mut my_actions,
+        );
+        parser.parse();
+    }
+    inject(&mut result);
+    result
+}
+
+/// Visitor interface
+#[allow(unused_variables)]
+pub trait Visitor {
+    fn on_terminal_text(&self, node: &AstNode) {}
+    fn on_terminal_code(&self, node: &AstNode) {}
+    fn on_variable_template(&self, node: &AstNode) {}
+    fn on_variable_part(&self, node: &AstNode) {}
+}
+
+/// Walk the AST of a result using a visitor
+pub fn visit(result: &ParseResult<AstImpl>, visitor: &dyn Visitor) {
+    let ast = result.get_ast();
+    let root = ast.get_root();
+    visit_ast_node(root, visitor);
+}
+
+/// Walk the sub-AST from the specified node using a visitor
+pub fn visit_ast_node(node: AstNode, visitor: &dyn Visitor) {
+    // the trees of the injected languages are not visited
+    if !matches!(node.get_symbol().id, 0x0004) {
+        let children = node.children();
+        for child in children.iter() {
+            visit_ast_node(child, visitor);
+        }
+    }
+    match node.get_symbol().id {
+        0x0003 => visitor.on_terminal_text(&node),
+        0x0004 => visitor.on_terminal_code(&node),
+        0x0005 => visitor.on_variable_template(&node),
+        0x0006 => visitor.on_variable_part(&node),
+        _ => (),
+    };
+}
@@ -0,0 +1,140 @@
+use hime_sdk::errors::Error;
+use hime_sdk::lints::lint;
+use hime_sdk::ParsingMethod;
+
+mod common;
+
+/// A grammar without any problem
+const CLEAN: &str = r#"
+grammar Test
+{
+    options { Axiom = "s"; Separator = "WS"; }
+    terminals
+    {
+        WS -> ' '+ ;
+        fragment DIGIT -> [0-9] ;
+        NUM -> DIGIT+ ;
+        ID -> [a-z]+ ;
+        context inner
+        {
+            KW -> 'kw' ;
+        }
+    }
+    rules
+    {
+        s -> e* ;
+        e -> NUM | ID | #inner{ KW } | '(' e ')' ;
+    }
+}
+"#;
+
+/// Loads a grammar and runs the lint pass on it
+fn lint_grammar(grammar: &str) -> Vec<String> {
+    let data = common::new_task(grammar, ParsingMethod::RNGLALR1)
+        .load()
+        .unwrap();
+    let warnings = lint(&data.grammars[0], 0);
+    assert!(warnings.iter().all(Error::is_warning));
+    warnings
+        .iter()
+        .map(|warning| warning.with_context(&data).to_string())
+        .collect()
+}
+
+#[test]
+fn test_lint_clean() {
+    assert!(lint_grammar(CLEAN).is_empty());
+}
+
+#[test]
+fn test_lint_clean_after_build() {
+    let mut data = common::new_task(CLEAN, ParsingMethod::RNGLALR1)
+        .load()
+        .unwrap();
+    data.grammars[0]
+        .build(Some(ParsingMethod::RNGLALR1), None, 0)
+        .unwrap();
+    assert!(lint(&data.grammars[0], 0).is_empty());
+}
+
+#[test]
+fn test_lint_unreachable_variable() {
+    let grammar = CLEAN.replace("'(' e ')' ;", "'(' e ')' ; orphan -> ID NUM ;");
+    assert_eq!(
+        lint_grammar(&grammar),
+        ["Variable `orphan` is not reachable from the axiom"]
+    );
+}
+
+#[test]
+fn test_lint_unproductive_variable() {
+    let grammar = CLEAN.replace("'(' e ')' ;", "'(' e ')' | loop ; loop -> '[' loop ']' ;");
+    assert_eq!(
+        lint_grammar(&grammar),
+        ["Variable `loop` cannot produce any finite sequence of terminals"]
+    );
+}
+
+#[test]
+fn test_lint_unused_terminal() {
+    let grammar = CLEAN.replace("ID -> [a-z]+ ;", "ID -> [a-z]+ ; HEX -> '0x' [0-9a-f]+ ;");
+    assert_eq!(
+        lint_grammar(&grammar),
+        ["Terminal `HEX` is never used in the rules"]
+    );
+}
+
+#[test]
+fn test_lint_unused_fragment() {
+    let grammar = CLEAN.replace(
+        "NUM -> DIGIT+ ;",
+        "fragment ALPHA -> [a-z] ; NUM -> DIGIT+ ;",
+    );
+    assert_eq!(lint_grammar(&grammar), ["Fragment `ALPHA` is never used"]);
+}
+
+#[test]
+fn test_lint_empty_context() {
+    let grammar = CLEAN
+        .replace("KW -> 'kw' ;", "")
+        .replace("#inner{ KW }", "#inner{ ID }");
+    assert_eq!(
+        lint_grammar(&grammar),
+        ["Lexical context `inner` has no terminal"]
+    );
+}
+
+#[test]
+fn test_lint_duplicate_rule_body() {
+    let grammar = CLEAN.replace("'(' e ')' ;", "'(' e ')' | NUM ;");
+    assert_eq!(lint_grammar(&grammar), ["Duplicate rule for variable `e`"]);
+}
+
+#[test]
+fn test_lint_shadowed_terminal() {
+    let grammar = CLEAN.replace("ID -> [a-z]+ ;", "KEY -> 'key' ; ID -> [a-z]+ ;");
+    assert_eq!(
+        lint_grammar(&grammar),
+        ["Terminal `KEY` is never used and can never be matched"]
+    );
+}
+
+#[test]
+fn test_lint_warnings_on_execute() {
+    let grammar = CLEAN.replace("'(' e ')' ;", "'(' e ')' ; orphan -> ID NUM ;");
+    let data = common::execute("lint_warnings_on_execute", &grammar, |task| {
+        task.method = Some(ParsingMethod::RNGLALR1);
+    })
+    .unwrap();
+    assert_eq!(data.warnings.len(), 1);
+    assert!(matches!(
+        &data.warnings[0],
+        Error::UnreachableVariable(_, name) if name == "orphan"
+    ));
+    // the loaded data is cloned with its warnings
+    let copy = data.clone();
+    assert!(matches!(
+        &copy.warnings[0],
+        Error::UnreachableVariable(_, name) if name == "orphan"
+    ));
+}
@@ -0,0 +1,125 @@
+use hime_sdk::errors::Error;
+use hime_sdk::ParsingMethod;
+
+mod common;
+
+/// A grammar that requires two tokens of lookahead
+const GRAMMAR: &str = r#"
+grammar Test
+{
+    options { Axiom = "s"; }
+    terminals
+    {
+        A -> 'a' ;
+        X -> 'x' ;
+        Y -> 'y' ;
+        Z -> 'z' ;
+    }
+    rules
+    {
+        s -> a X Y | b X Z | A X X | c ;
+        a -> A ;
+        b -> A ;
+        c -> A Y Y | d Y Z ;
+        d -> A ;
+    }
+}
+"#;
+
+/// A grammar that remains ambiguous with two tokens of lookahead
+const AMBIGUOUS: &str = r#"
+grammar Test
+{
+    options { Axiom = "s"; }
+    terminals
+    {
+        A -> 'a' ;
+        X -> 'x' ;
+        Y -> 'y' ;
+    }
+    rules
+    {
+        s -> a X X Y | b X X ;
+        a -> A ;
+        b -> A ;
+    }
+}
+"#;
+
+/// Builds the grammar with the specified method and lookahead, then checks the parsed inputs
+fn check(
+    grammar: &str,
+    method: ParsingMethod,
+    lookahead: Option<usize>,
+    accepted: &[&str],
+    rejected: &[&str],
+) -> Result<(), Vec<Error>> {
+    let mut task = common::new_task(grammar, method);
+    task.lookahead = lookahead;
+    common::with_parser(&task, |parser| {
+        for input in accepted {
+            assert!(parser.parse(input).is_success(), "{input}");
+        }
+        for input in rejected {
+            assert!(!parser.parse(input).is_success(), "{input}");
+        }
+    })
+}
+
+#[test]
+fn test_lookahead_1_conflicts() {
+    let errors = check(GRAMMAR, ParsingMethod::LALR1, None, &[], &[])
+        .err()
+        .unwrap();
+    assert!(!errors.is_empty());
+    assert!(errors
+        .iter()
+        .all(|error| matches!(error, Error::LrConflict(_, _))));
+}
+
+#[test]
+fn test_lookahead_2_parses() {
+    for method in [
+        ParsingMethod::LR1,
+        ParsingMethod::LALR1,
+        ParsingMethod::IELR1,
+    ] {
+        assert!(check(
+            GRAMMAR,
+            method,
+            Some(2),
+            &["axy", "axz", "axx", "ayy", "ayz"],
+            &["ax", "axyy", "ay", "azz"],
+        )
+        .is_ok());
+    }
+}
+
+#[test]
+fn test_lookahead_2_conflicts() {
+    let errors = check(AMBIGUOUS, ParsingMethod::LALR1, Some(2), &[], &[])
+        .err()
+        .unwrap();
+    assert!(!errors.is_empty());
+    assert!(errors
+        .iter()
+        .all(|error| matches!(error, Error::LrConflict(_, _))));
+    assert!(check(
+        AMBIGUOUS,
+        ParsingMethod::LALR1,
+        Some(3),
+        &["axxy", "axx"],
+        &["axxx"],
+    )
+    .is_ok());
+}
+
+#[test]
+fn test_lookahead_invalid_method() {
+    let errors = check(GRAMMAR, ParsingMethod::RNGLALR1, Some(2), &[], &[])
+        .err()
+        .unwrap();
+    assert!(errors
+        .iter()
+        .all(|error| matches!(error, Error::InvalidOption(_, _, _))));
+}
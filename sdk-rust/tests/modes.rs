@@ -0,0 +1,90 @@
+use hime_sdk::errors::Error;
+use hime_sdk::Runtime;
+
+mod common;
+
+/// A grammar with strings lexed in their own mode
+const GRAMMAR: &str = r#"
+grammar Modes
+{
+    options { Axiom = "unit"; Separator = "WS"; }
+    terminals
+    {
+        WS -> ' '+ ;
+        ID -> [a-z]+ ;
+        QUOTE -> '"' => push str ;
+        CLOSE -> ')' => pop ;
+        context str
+        {
+            CHARS -> [a-z ]+ ;
+            END -> '"' => pop ;
+        }
+    }
+    rules
+    {
+        unit -> item* ;
+        item -> ID | CLOSE | QUOTE #str{ CHARS? END } ;
+    }
+}
+"#;
+
+/// Parses an input with the LR(1) and GLR parsers
+/// Gets the matched tokens, which must be the same for both parsers
+fn parse(input: &str) -> Vec<(String, String)> {
+    common::compare(GRAMMAR, |parser| {
+        let result = parser.parse(input);
+        assert!(result.is_success(), "{:?}", result.errors.errors);
+        common::get_tokens(&result)
+    })
+}
+
+#[test]
+fn test_mode_push_pop() {
+    assert_eq!(
+        parse(r#"ab "cd ef" gh"#),
+        common::tokens(&[
+            ("ID", "ab"),
+            ("QUOTE", "\""),
+            ("CHARS", "cd ef"),
+            ("END", "\""),
+            ("ID", "gh"),
+            ("$", ""),
+        ])
+    );
+}
+
+#[test]
+fn test_mode_empty() {
+    assert_eq!(
+        parse(r#""" ab"#),
+        common::tokens(&[("QUOTE", "\""), ("END", "\""), ("ID", "ab"), ("$", "")])
+    );
+}
+
+#[test]
+fn test_mode_pop_on_empty_stack() {
+    // popping the empty stack of modes leaves the lexer in the default context
+    assert_eq!(
+        parse(r#"ab ) "cd" ef"#),
+        common::tokens(&[
+            ("ID", "ab"),
+            ("CLOSE", ")"),
+            ("QUOTE", "\""),
+            ("CHARS", "cd"),
+            ("END", "\""),
+            ("ID", "ef"),
+            ("$", ""),
+        ])
+    );
+}
+
+#[test]
+fn test_mode_unsupported_runtimes() {
+    for runtime in [Runtime::Net, Runtime::Java] {
+        let errors = common::output_errors(GRAMMAR, runtime);
+        assert!(matches!(
+            &errors[..],
+            [Error::InvalidOption(0, option, _)] if option == "Runtime"
+        ));
+    }
+}
@@ -0,0 +1,107 @@
+use hime_sdk::errors::Error;
+use hime_sdk::ParsingMethod;
+
+mod common;
+
+/// An ambiguous expression grammar, disambiguated by precedence declarations
+const GRAMMAR: &str = r#"
+grammar Test
+{
+    options { Axiom = "s"; }
+    terminals
+    {
+        NUM -> [0-9]+ ;
+        fragment UMINUS -> '-' ;
+    }
+    precedence
+    {
+        nonassoc '<' ;
+        left '+' ;
+        left '*' ;
+        right '^' ;
+        right UMINUS ;
+    }
+    rules
+    {
+        s -> e ;
+        e -> e '+' e
+           | e '*' e
+           | e '^' e
+           | e '<' e
+           | '-' e %prec UMINUS
+           | NUM ;
+    }
+}
+"#;
+
+#[test]
+fn test_precedence_resolves_conflicts() {
+    let grammar = r#"
+        grammar Test
+        {
+            options { Axiom = "e"; }
+            terminals { NUM -> [0-9]+ ; }
+            precedence
+            {
+                left '+' ;
+                right '^' ;
+            }
+            rules { e -> e '+' e | e '^' e | NUM ; }
+        }
+    "#;
+    let mut data = common::new_task(grammar, ParsingMethod::LALR1)
+        .load()
+        .unwrap();
+    let build = data.grammars[0]
+        .build(Some(ParsingMethod::LALR1), None, 0)
+        .unwrap();
+    let mut resolved: Vec<String> = build
+        .resolved
+        .iter()
+        .map(|resolved| resolved.get_description(&data.grammars[0]))
+        .collect();
+    resolved.sort();
+    // the higher level decides, the associativity breaks the ties within a level
+    assert_eq!(
+        resolved,
+        [
+            "state 6: shift/reduce conflict on `+` with `e -> e + e` resolved as reduce `e -> e + e` by the left precedence level 0",
+            "state 6: shift/reduce conflict on `^` with `e -> e + e` resolved as shift by the right precedence level 1",
+            "state 7: shift/reduce conflict on `+` with `e -> e ^ e` resolved as reduce `e -> e ^ e` by the right precedence level 1",
+            "state 7: shift/reduce conflict on `^` with `e -> e ^ e` resolved as shift by the right precedence level 1",
+        ]
+    );
+}
+
+#[test]
+fn test_precedence_required() {
+    let grammar = GRAMMAR.replace("%prec UMINUS", "");
+    let start = grammar.find("precedence").unwrap();
+    let end = grammar.find("rules").unwrap();
+    let grammar = format!("{}{}", &grammar[..start], &grammar[end..]);
+    let errors = common::parse(&grammar, ParsingMethod::LALR1, "1")
+        .err()
+        .unwrap();
+    assert!(!errors.is_empty());
+    assert!(errors
+        .iter()
+        .all(|error| matches!(error, Error::LrConflict(_, _))));
+}
+
+#[test]
+fn test_precedence_redefined() {
+    let grammar = GRAMMAR.replace("left '*' ;", "left '*' '+' ;");
+    let errors = common::load_errors(&grammar);
+    assert!(errors
+        .iter()
+        .any(|error| matches!(error, Error::PrecedenceRedefined(_, _, _))));
+}
+
+#[test]
+fn test_precedence_unknown_symbol() {
+    let grammar = GRAMMAR.replace("right UMINUS ;", "right UMINUS '%' ;");
+    let errors = common::load_errors(&grammar);
+    assert!(errors
+        .iter()
+        .any(|error| matches!(error, Error::SymbolNotFound(_, _))));
+}
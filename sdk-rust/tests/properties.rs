@@ -0,0 +1,79 @@
+use hime_sdk::errors::Error;
+use hime_sdk::{CompilationTask, Input, ParsingMethod};
+
+mod common;
+
+const GRAMMAR: &str = r#"
+grammar Identifiers
+{
+    options
+    {
+        Axiom = "items";
+        Separator = "WS";
+    }
+    terminals
+    {
+        WS -> up{White_Space}+ ;
+        ID -> up{XID_Start} up{XID_Continue}* ;
+        GREEK -> up{Script=Greek}+ ;
+    }
+    rules
+    {
+        items -> item+ ;
+        item -> GREEK | ID ;
+    }
+}
+"#;
+
+/// Parses identifiers defined with Unicode scripts and binary properties
+#[test]
+fn test_unicode_properties() {
+    let task = common::new_task(GRAMMAR, ParsingMethod::LALR1);
+    common::with_parser(&task, |parser| {
+        let result = parser.parse("αβγ x_1\u{3000}名前 𐐀𐐨");
+        assert!(result.errors.errors.is_empty());
+        let names: Vec<String> = common::get_tokens(&result)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, ["GREEK", "ID", "ID", "ID", "$"]);
+
+        let result = parser.parse("1x");
+        assert!(!result.errors.errors.is_empty());
+    })
+    .unwrap();
+}
+
+/// Reports unknown Unicode scripts and properties
+#[test]
+fn test_unknown_unicode_property() {
+    let errors = common::load_errors(
+        "grammar G { options { Axiom = \"e\"; } terminals { X -> up{Script=Klingon}; } rules { e -> X; } }",
+    );
+    assert!(matches!(
+        &errors[0],
+        Error::UnknownUnicodeProperty(_, name) if name == "Script=Klingon"
+    ));
+}
+
+/// Resolves the Unicode classes against a pinned version of the Unicode Character Database
+#[test]
+fn test_pinned_unicode_version() {
+    let grammar = |version: &str| {
+        format!("grammar G {{ options {{ Axiom = \"e\"; UnicodeVersion = \"{version}\"; }} terminals {{ X -> up{{Script=Greek}}+; }} rules {{ e -> X; }} }}")
+    };
+    let source = grammar("16.0");
+    let task = CompilationTask {
+        inputs: vec![Input::Raw(&source)],
+        ..Default::default()
+    };
+    let data = task.load().unwrap();
+    assert_eq!(data.grammars[0].get_unicode_database().version, "16.0.0");
+
+    let errors = common::load_errors(&grammar("3.2"));
+    assert!(matches!(
+        &errors[0],
+        Error::UnsupportedUnicodeVersion(_, version, supported)
+            if version == "3.2" && supported == &["16.0.0"]
+    ));
+}
@@ -1,4 +1,7 @@
 use hime_sdk::output::helper::{get_namespace_java, get_namespace_net, get_namespace_rust};
+use hime_sdk::ParsingMethod;
+
+mod common;
 
 /// [Github issue #79](https://github.com/cenotelie/hime/issues/79)
 #[test]
@@ -10,3 +13,15 @@ fn test_namespace_transformation() {
     assert_eq!(get_namespace_rust("a.b.c"), String::from("a::b::c"));
     assert_eq!(get_namespace_rust("a::b::c"), String::from("a::b::c"));
 }
+
+/// The LR(1) graphs have accepting states, so that the RNGLR(1) parsers accept their input
+#[test]
+fn test_lr1_accepting_states() {
+    let grammar = r#"grammar G { options { Axiom = "e"; } terminals { X -> 'x'; } rules { e -> X e | X ; } }"#;
+    for method in [ParsingMethod::LR1, ParsingMethod::RNGLR1] {
+        assert_eq!(
+            common::parse(grammar, method, "xxx").unwrap().as_deref(),
+            Some("e(X:x e(X:x e(X:x)))")
+        );
+    }
+}
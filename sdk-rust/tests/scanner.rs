@@ -0,0 +1,215 @@
+use hime_redist::lexers::{ExternalMatch, ExternalScanner, ParseHooks};
+use hime_redist::text::Text;
+use hime_sdk::errors::Error;
+use hime_sdk::{ParsingMethod, Runtime};
+
+mod common;
+
+/// A grammar with terminals matched by an external scanner
+const GRAMMAR: &str = r#"
+grammar Scanner
+{
+    options { Axiom = "unit"; Separator = "WS"; }
+    terminals
+    {
+        WS -> ' '+ ;
+        ID -> [a-z]+ ;
+        external RAW;
+        external AT;
+        external END;
+    }
+    rules
+    {
+        unit -> item* END ;
+        item -> ID | RAW | AT ;
+    }
+}
+"#;
+
+/// A scanner for raw strings between `<<` and `>>`, the `@` sign and the end marker
+struct Scanner {
+    /// The identifier of the raw string terminal
+    raw: u32,
+    /// The identifier of the `@` terminal
+    at: u32,
+    /// The identifier of the end marker terminal
+    end: u32,
+    /// Whether the end marker has been produced
+    ended: bool,
+    /// The match for a `#`
+    sharp: ExternalMatch,
+}
+
+impl ExternalScanner for Scanner {
+    fn scan_before(&mut self, text: &Text, index: usize) -> Option<ExternalMatch> {
+        if text.is_end(index) {
+            if self.ended {
+                return None;
+            }
+            self.ended = true;
+            return Some(ExternalMatch {
+                terminal_id: self.end,
+                length: 0,
+            });
+        }
+        let rest = text.get_value(index, text.len() - index);
+        if let Some(raw) = rest.strip_prefix("<<") {
+            let length = raw.find(">>")? + 4;
+            return Some(ExternalMatch {
+                terminal_id: self.raw,
+                length,
+            });
+        }
+        if rest.starts_with('#') {
+            return Some(self.sharp);
+        }
+        None
+    }
+
+    fn scan_after(
+        &mut self,
+        text: &Text,
+        index: usize,
+        matched: Option<ExternalMatch>,
+    ) -> Option<ExternalMatch> {
+        if matched.is_none() && text.at(index) == '@' {
+            return Some(ExternalMatch {
+                terminal_id: self.at,
+                length: 1,
+            });
+        }
+        matched
+    }
+}
+
+/// Parses an input with the LR(1) and GLR parsers and the external scanner
+/// A `#` is matched as the specified terminal, or an unknown one, with the specified length.
+/// Gets the matched tokens and the errors, which must be the same for both parsers
+fn parse(input: &str, sharp: Option<&str>, length: usize) -> (Vec<(String, String)>, Vec<String>) {
+    common::compare(GRAMMAR, |parser| {
+        let mut scanner = Scanner {
+            raw: common::get_id(parser, "RAW"),
+            at: common::get_id(parser, "AT"),
+            end: common::get_id(parser, "END"),
+            ended: false,
+            sharp: ExternalMatch {
+                terminal_id: sharp.map_or(0xFFFF, |name| common::get_id(parser, name)),
+                length,
+            },
+        };
+        let hooks = ParseHooks::new().with_scanner(&mut scanner);
+        let result = parser.parse_with_hooks(Text::from_str(input), hooks);
+        (common::get_tokens(&result), common::get_errors(&result))
+    })
+}
+
+#[test]
+fn test_scan_before() {
+    let (tokens_found, errors) = parse("ab <<c d>> ef", Some("ID"), 1);
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(
+        tokens_found,
+        common::tokens(&[
+            ("ID", "ab"),
+            ("RAW", "<<c d>>"),
+            ("ID", "ef"),
+            ("END", ""),
+            ("$", ""),
+        ])
+    );
+}
+
+#[test]
+fn test_scan_after_fallback() {
+    let (tokens_found, errors) = parse("ab @ ef", Some("ID"), 1);
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(
+        tokens_found,
+        common::tokens(&[
+            ("ID", "ab"),
+            ("AT", "@"),
+            ("ID", "ef"),
+            ("END", ""),
+            ("$", ""),
+        ])
+    );
+}
+
+#[test]
+fn test_scan_end_of_input() {
+    let (tokens_found, errors) = parse("", Some("ID"), 1);
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(tokens_found, common::tokens(&[("END", ""), ("$", "")]));
+}
+
+#[test]
+fn test_scan_known_terminal() {
+    // the scanner may also produce terminals that are matched by the automaton
+    let (tokens_found, errors) = parse("ab # ef", Some("ID"), 1);
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(
+        tokens_found,
+        common::tokens(&[
+            ("ID", "ab"),
+            ("ID", "#"),
+            ("ID", "ef"),
+            ("END", ""),
+            ("$", ""),
+        ])
+    );
+}
+
+#[test]
+fn test_scan_unknown_terminal() {
+    // the unknown terminal is reported and the lexer recovers from the `#`
+    let (tokens_found, errors) = parse("ab # ef", None, 1);
+    assert_eq!(errors[0], "Unknown terminal 0xFFFF");
+    assert_eq!(tokens_found[0], (String::from("ID"), String::from("ab")));
+}
+
+#[test]
+fn test_scan_empty_match() {
+    // the empty match is ignored and the automaton fails to match the `#`
+    let (tokens_found, errors) = parse("ab # ef", Some("ID"), 0);
+    assert_eq!(errors[0], "Unexpected character '#' (U+23)");
+    assert_eq!(tokens_found[0], (String::from("ID"), String::from("ab")));
+    assert!(tokens_found.iter().all(|(_, value)| value != "#"));
+}
+
+#[test]
+fn test_external_as_name() {
+    // the keyword is contextual, a terminal or a rule may be named `external`
+    let terminal = GRAMMAR
+        .replace(
+            "ID -> [a-z]+ ;",
+            "ID -> [a-z]+ ;\n        external -> '!' ;",
+        )
+        .replace("item -> ID", "item -> external | ID");
+    let rule = GRAMMAR.replace(
+        "unit -> item* END ;",
+        "unit -> external END ;\n        external -> item* ;",
+    );
+    for grammar in [terminal, rule] {
+        let task = common::new_task(&grammar, ParsingMethod::LALR1);
+        let data = task.load().unwrap();
+        assert!(data.grammars[0].get_symbol("external").is_some());
+        assert!(
+            data.grammars[0]
+                .get_terminal_for_name("RAW")
+                .unwrap()
+                .is_external
+        );
+        common::with_parser(&task, |_| ()).unwrap();
+    }
+}
+
+#[test]
+fn test_external_unsupported_runtimes() {
+    for runtime in [Runtime::Net, Runtime::Java] {
+        let errors = common::output_errors(GRAMMAR, runtime);
+        assert!(matches!(
+            &errors[..],
+            [Error::InvalidOption(0, option, _)] if option == "Runtime"
+        ));
+    }
+}
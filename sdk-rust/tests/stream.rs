@@ -0,0 +1,106 @@
+use hime_redist::ast::AstImpl;
+use hime_redist::lexers::stream::TokenStreamSource;
+use hime_redist::parsers::lrk::LRkParser;
+use hime_redist::parsers::rnglr::RNGLRParser;
+use hime_redist::parsers::Parser;
+use hime_redist::result::ParseResult;
+use hime_redist::symbols::{SemanticBody, Symbol};
+use hime_redist::text::{Text, TextSpan};
+use hime_sdk::sdk::ParserAutomaton;
+
+mod common;
+
+const GRAMMAR: &str = r#"
+grammar Calc
+{
+    options { Axiom = "expr"; Separator = "WS"; }
+    terminals
+    {
+        WS -> U+0020+ ;
+        ID -> [a-z]+ ;
+        NUM -> [0-9]+ ;
+    }
+    rules
+    {
+        expr -> expr '+'^ term | term^ ;
+        term -> ID | NUM ;
+    }
+}
+"#;
+
+/// Parses the tokens in a text with the LR(1) and GLR parsers fed by a stream of tokens
+/// The tokens are given by the name of their terminal, or an unknown identifier, and their span.
+/// Gets the AST and the errors, which must be the same for both parsers
+fn parse(input: &str, tokens: &[(Option<&str>, usize, usize)]) -> (String, Vec<String>) {
+    common::compare(GRAMMAR, |parser| {
+        let stream: Vec<(u32, TextSpan)> = tokens
+            .iter()
+            .map(|&(name, index, length)| {
+                let id = name.map_or(0xFFFF, |name| common::get_id(parser, name));
+                (id, TextSpan { index, length })
+            })
+            .collect();
+        let mut result = ParseResult::<AstImpl>::new(
+            &parser.terminals,
+            &parser.variables,
+            &parser.virtuals,
+            Text::from_str(input),
+        );
+        {
+            let (repository, errors, ast) = result.get_parsing_data();
+            let mut source = TokenStreamSource::new(repository, errors, stream.into_iter());
+            let mut actions = |_index: usize, _head: Symbol, _body: &dyn SemanticBody| ();
+            match &parser.parser_automaton {
+                ParserAutomaton::Lrk(automaton) => LRkParser::new(
+                    &mut source,
+                    &parser.variables,
+                    &parser.virtuals,
+                    automaton.clone(),
+                    ast,
+                    &mut actions,
+                )
+                .parse(),
+                ParserAutomaton::Rnglr(automaton) => RNGLRParser::new_with_ast(
+                    &mut source,
+                    &parser.variables,
+                    &parser.virtuals,
+                    automaton.clone(),
+                    ast,
+                    &mut actions,
+                )
+                .parse(),
+            }
+        }
+        let ast = if result.is_success() {
+            common::print(result.get_ast().get_root())
+        } else {
+            String::new()
+        };
+        (ast, common::get_errors(&result))
+    })
+}
+
+#[test]
+fn test_token_stream() {
+    let (ast, errors) = parse(
+        "x + 12 + y",
+        &[
+            (Some("ID"), 0, 1),
+            (Some("+"), 2, 1),
+            (Some("NUM"), 4, 2),
+            (Some("+"), 7, 1),
+            (Some("ID"), 9, 1),
+        ],
+    );
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(ast, "+(+(term(ID:x) term(NUM:12)) term(ID:y))");
+}
+
+#[test]
+fn test_token_stream_unknown_terminal() {
+    let (_, errors) = parse(
+        "x # 12",
+        &[(Some("ID"), 0, 1), (None, 2, 1), (Some("NUM"), 4, 2)],
+    );
+    assert_eq!(errors[0], "Unknown terminal 0xFFFF");
+}
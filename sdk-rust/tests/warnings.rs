@@ -0,0 +1,89 @@
+use hime_sdk::errors::Error;
+use hime_sdk::{CompilationTask, Input};
+
+mod common;
+
+/// A grammar with a redefined terminal and a conflict resolved by precedence
+const GRAMMAR: &str = r#"
+grammar Test
+{
+    options { Axiom = "s"; }
+    terminals
+    {
+        NUM -> [0-9]+ ;
+        NUM -> [0-9]+ '.' ;
+    }
+    precedence
+    {
+        left '+' ;
+    }
+    rules
+    {
+        s -> e ;
+        e -> e '+' e | NUM ;
+    }
+}
+"#;
+
+/// Gets the names of the warnings
+fn names(warnings: &[Error]) -> Vec<&'static str> {
+    warnings
+        .iter()
+        .map(|warning| warning.get_warning_name().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_warnings_do_not_fail_load() {
+    let task = CompilationTask {
+        inputs: vec![Input::Raw(GRAMMAR)],
+        ..Default::default()
+    };
+    let data = task.load().unwrap();
+    assert_eq!(names(&data.warnings), ["overriding-terminal"]);
+}
+
+#[test]
+fn test_warnings_reported() {
+    let data = common::execute("warnings_reported", GRAMMAR, |_| {}).unwrap();
+    assert_eq!(
+        names(&data.warnings),
+        ["overriding-terminal", "resolved-conflict"]
+    );
+    assert_eq!(
+        data.warnings[1].with_context(&data).to_string(),
+        "Conflict resolved by the precedence declarations, state 6: shift/reduce conflict on `+` with `e -> e + e` resolved as reduce `e -> e + e` by the left precedence level 0"
+    );
+}
+
+#[test]
+fn test_warnings_allowed() {
+    let data = common::execute("warnings_allowed", GRAMMAR, |task| {
+        task.allowed_warnings = vec![String::from("resolved-conflict")];
+    })
+    .unwrap();
+    assert_eq!(names(&data.warnings), ["overriding-terminal"]);
+}
+
+#[test]
+fn test_warnings_denied() {
+    let errors = common::execute("warnings_denied", GRAMMAR, |task| {
+        task.deny_warnings = Some(true);
+        task.allowed_warnings = vec![String::from("overriding-terminal")];
+    })
+    .unwrap_err();
+    assert_eq!(names(&errors.errors), ["resolved-conflict"]);
+}
+
+#[test]
+fn test_warnings_denied_all_allowed() {
+    let data = common::execute("warnings_denied_all_allowed", GRAMMAR, |task| {
+        task.deny_warnings = Some(true);
+        task.allowed_warnings = vec![
+            String::from("overriding-terminal"),
+            String::from("resolved-conflict"),
+        ];
+    })
+    .unwrap();
+    assert!(data.warnings.is_empty());
+}
@@ -15,10 +15,11 @@
  * If not, see <http://www.gnu.org/licenses/>.
  ******************************************************************************/
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::error::Error;
 use std::fs;
 use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 
 use hime_sdk::unicode::{Block, Category};
 use regex::Regex;
@@ -32,14 +33,24 @@ pub const GIT_HASH: &str = env!("GIT_HASH");
 /// The git tag that was used to build the application
 pub const GIT_TAG: &str = env!("GIT_TAG");
 
-/// The URL of the latest specification of Unicode blocks
-const URL_UNICODE_BLOCKS: &str = "http://www.unicode.org/Public/UCD/latest/ucd/Blocks.txt";
-/// The URL of the latest specification of Unicode code points
-const URL_UNICODE_DATA: &str = "http://www.unicode.org/Public/UCD/latest/ucd/UnicodeData.txt";
+/// The binary properties to extract from the UCD, with the file that defines each of them
+const BINARY_PROPERTIES: &[(&str, &str)] = &[
+    ("PropList.txt", "White_Space"),
+    ("PropList.txt", "Pattern_Syntax"),
+    ("PropList.txt", "Pattern_White_Space"),
+    ("DerivedCoreProperties.txt", "Alphabetic"),
+    ("DerivedCoreProperties.txt", "Lowercase"),
+    ("DerivedCoreProperties.txt", "Uppercase"),
+    ("DerivedCoreProperties.txt", "ID_Start"),
+    ("DerivedCoreProperties.txt", "ID_Continue"),
+    ("DerivedCoreProperties.txt", "XID_Start"),
+    ("DerivedCoreProperties.txt", "XID_Continue"),
+    ("emoji/emoji-data.txt", "Emoji"),
+];
 
-/// Gets the latest unicode blocks from the Unicode web site
-fn get_latet_blocks() -> Result<Vec<Block>, Box<dyn Error>> {
-    let content = reqwest::blocking::get(URL_UNICODE_BLOCKS)?.text()?;
+/// Reads the unicode blocks from a local UCD
+fn read_blocks(ucd: &Path) -> Result<Vec<Block>, Box<dyn Error>> {
+    let content = fs::read_to_string(ucd.join("Blocks.txt"))?;
     let mut blocks = Vec::new();
     let re = Regex::new(r"([0-9A-F]+)\.\.([0-9A-F]+); (([a-zA-Z0-9 ]|-)+)")?;
     for line in content.split('\n') {
@@ -61,9 +72,53 @@ fn get_latet_blocks() -> Result<Vec<Block>, Box<dyn Error>> {
     Ok(blocks)
 }
 
-/// Gets the latest unicode categories from the Unicode web site
-fn get_latet_categories() -> Result<Vec<Category>, Box<dyn Error>> {
-    let content = reqwest::blocking::get(URL_UNICODE_DATA)?.text()?;
+/// Reads the unicode general categories from a local UCD
+/// The derived file `extracted/DerivedGeneralCategory.txt` is used when present,
+/// `UnicodeData.txt` otherwise.
+fn read_categories(ucd: &Path) -> Result<Vec<Category>, Box<dyn Error>> {
+    let derived = ucd.join("extracted").join("DerivedGeneralCategory.txt");
+    if !derived.exists() {
+        return read_categories_from_data(ucd);
+    }
+    // unassigned code points are not a matchable category
+    let mut runs: Vec<(u32, u32, String)> = read_ucd_property_file(&derived)?
+        .into_iter()
+        .filter(|(name, _)| name != "Cn")
+        .flat_map(|(name, spans)| {
+            spans
+                .into_iter()
+                .map(move |(begin, end)| (begin, end, name.clone()))
+        })
+        .collect();
+    runs.sort_unstable();
+    // as in UnicodeData.txt, consecutive assigned code points with the same category
+    // are merged across the unassigned ones between them
+    let mut merged: Vec<(u32, u32, String)> = Vec::new();
+    for (begin, end, name) in runs {
+        match merged.last_mut() {
+            Some(last) if last.2 == name => last.1 = end,
+            _ => merged.push((begin, end, name)),
+        }
+    }
+    let mut spans: HashMap<String, Vec<(u32, u32)>> = HashMap::new();
+    for (begin, end, name) in merged {
+        let category = spans.entry(name).or_default();
+        // surrogates cannot be matched on their own, their category is empty
+        if !(0xD800..0xE000).contains(&begin) && !(0xD800..0xE000).contains(&end) {
+            category.push((begin, end));
+        }
+    }
+    let mut categories = spans
+        .into_iter()
+        .map(|(name, spans)| build_property(name, spans))
+        .collect::<Vec<_>>();
+    categories.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    Ok(categories)
+}
+
+/// Reads the unicode general categories from the `UnicodeData.txt` file of a local UCD
+fn read_categories_from_data(ucd: &Path) -> Result<Vec<Category>, Box<dyn Error>> {
+    let content = fs::read_to_string(ucd.join("UnicodeData.txt"))?;
     let mut categories = HashMap::new();
     let re = Regex::new(r"([0-9A-F]+);([^;]+);([^;]+);.*")?;
     let mut current_name: Option<String> = None;
@@ -106,9 +161,99 @@ fn get_latet_categories() -> Result<Vec<Category>, Box<dyn Error>> {
     Ok(categories)
 }
 
+/// Reads the classes of case-equivalent code points from a local UCD
+/// Only the common and simple foldings are used (statuses C and S)
+fn read_case_folding(ucd: &Path) -> Result<Vec<Vec<u32>>, Box<dyn Error>> {
+    let content = fs::read_to_string(ucd.join("CaseFolding.txt"))?;
+    let mut classes: HashMap<u32, BTreeSet<u32>> = HashMap::new();
+    let re = Regex::new(r"([0-9A-F]+); ([CS]); ([0-9A-F]+);")?;
+    for line in content.split('\n') {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(m) = re.captures(line) {
+            let cp = u32::from_str_radix(&m[1], 16)?;
+            let folded = u32::from_str_radix(&m[3], 16)?;
+            let class = classes.entry(folded).or_default();
+            class.insert(folded);
+            class.insert(cp);
+        }
+    }
+    let mut classes = classes
+        .into_values()
+        .map(|class| class.into_iter().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    classes.sort_unstable();
+    Ok(classes)
+}
+
+/// The spans of code points for each value of a property
+type PropertyValues = HashMap<String, Vec<(u32, u32)>>;
+
+/// Reads the spans of code points for each value in a property file of a local UCD
+fn read_ucd_property_file(path: &Path) -> Result<PropertyValues, Box<dyn Error>> {
+    let content = fs::read_to_string(path)?;
+    let mut values = PropertyValues::new();
+    let re = Regex::new(r"^([0-9A-F]+)(\.\.([0-9A-F]+))?\s*;\s*([_a-zA-Z0-9]+)")?;
+    for line in content.lines() {
+        if let Some(m) = re.captures(line) {
+            let begin = u32::from_str_radix(&m[1], 16)?;
+            let end = match m.get(3) {
+                Some(end) => u32::from_str_radix(end.as_str(), 16)?,
+                None => begin,
+            };
+            values
+                .entry(m[4].to_string())
+                .or_default()
+                .push((begin, end));
+        }
+    }
+    Ok(values)
+}
+
+/// Builds a named set of code points from spans, merging the adjacent ones
+fn build_property(name: String, mut spans: Vec<(u32, u32)>) -> Category {
+    spans.sort_unstable();
+    let mut property = Category::new_owned(name);
+    let mut current: Option<(u32, u32)> = None;
+    for (begin, end) in spans {
+        current = match current {
+            Some((b, e)) if begin <= e + 1 => Some((b, e.max(end))),
+            Some((b, e)) => {
+                property.add_span(b, e);
+                Some((begin, end))
+            }
+            None => Some((begin, end)),
+        };
+    }
+    if let Some((b, e)) = current {
+        property.add_span(b, e);
+    }
+    property
+}
+
+/// Reads the unicode scripts and binary properties from a local UCD
+fn read_properties(ucd: &Path) -> Result<Vec<Category>, Box<dyn Error>> {
+    let mut properties = Vec::new();
+    for (script, spans) in read_ucd_property_file(&ucd.join("Scripts.txt"))? {
+        properties.push(build_property(format!("Script={script}"), spans));
+    }
+    let mut files = HashMap::new();
+    for (file, name) in BINARY_PROPERTIES {
+        if !files.contains_key(file) {
+            files.insert(*file, read_ucd_property_file(&ucd.join(file))?);
+        }
+        if let Some(spans) = files[file].get(*name) {
+            properties.push(build_property((*name).to_string(), spans.clone()));
+        }
+    }
+    properties.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    Ok(properties)
+}
+
 /// Generates the code for the Unicode blocks data
-fn generate_blocks_db(blocks: &[Block]) -> Result<(), Box<dyn Error>> {
-    let mut writer = io::BufWriter::new(fs::File::create("blocks.rs")?);
+fn generate_blocks_db(output: &Path, blocks: &[Block]) -> Result<(), Box<dyn Error>> {
+    let mut writer = io::BufWriter::new(fs::File::create(output.join("blocks.rs"))?);
     writeln!(writer, "/*")?;
     writeln!(writer, " * WARNING: this file has been generated by")?;
     writeln!(writer, " * Hime Parser Generator")?;
@@ -120,7 +265,7 @@ fn generate_blocks_db(blocks: &[Block]) -> Result<(), Box<dyn Error>> {
     writeln!(writer, "/// Gets all blocks")?;
     writeln!(
         writer,
-        "#[allow(clippy::similar_names, clippy::too_many_lines)]"
+        "#[allow(\n    clippy::similar_names,\n    clippy::too_many_lines,\n    clippy::unreadable_literal\n)]"
     )?;
     writeln!(writer, "#[must_use]")?;
     writeln!(
@@ -144,8 +289,8 @@ fn generate_blocks_db(blocks: &[Block]) -> Result<(), Box<dyn Error>> {
 }
 
 /// Generates the code for the Unicode blocks tests
-fn generate_blocks_tests(blocks: &[Block]) -> Result<(), Box<dyn Error>> {
-    let mut writer = io::BufWriter::new(fs::File::create("UnicodeBlocks.suite")?);
+fn generate_blocks_tests(output: &Path, blocks: &[Block]) -> Result<(), Box<dyn Error>> {
+    let mut writer = io::BufWriter::new(fs::File::create(output.join("UnicodeBlocks.suite"))?);
     writeln!(writer, "fixture UnicodeBlocks")?;
     for block in blocks {
         let cs_name = block.name.replace('-', "");
@@ -178,8 +323,8 @@ fn generate_blocks_tests(blocks: &[Block]) -> Result<(), Box<dyn Error>> {
 }
 
 /// Generates the code for the Unicode categories data
-fn generate_categories_db(categories: &[Category]) -> Result<(), Box<dyn Error>> {
-    let mut aggragated = HashMap::new();
+fn generate_categories_db(output: &Path, categories: &[Category]) -> Result<(), Box<dyn Error>> {
+    let mut aggragated = BTreeMap::new();
     for category in categories {
         let first = category.name[0..1].to_string();
         aggragated
@@ -187,7 +332,7 @@ fn generate_categories_db(categories: &[Category]) -> Result<(), Box<dyn Error>>
             .or_insert_with(Vec::new)
             .push(category);
     }
-    let mut writer = io::BufWriter::new(fs::File::create("categories.rs")?);
+    let mut writer = io::BufWriter::new(fs::File::create(output.join("categories.rs"))?);
     writeln!(writer, "/*")?;
     writeln!(writer, " * WARNING: this file has been generated by")?;
     writeln!(writer, " * Hime Parser Generator")?;
@@ -199,7 +344,7 @@ fn generate_categories_db(categories: &[Category]) -> Result<(), Box<dyn Error>>
     writeln!(writer, "/// Gets all categories")?;
     writeln!(
         writer,
-        "#[allow(clippy::similar_names, clippy::too_many_lines)]"
+        "#[allow(\n    clippy::similar_names,\n    clippy::too_many_lines,\n    clippy::unreadable_literal\n)]"
     )?;
     writeln!(writer, "#[must_use]")?;
     writeln!(
@@ -263,16 +408,139 @@ fn generate_categories_db(categories: &[Category]) -> Result<(), Box<dyn Error>>
     Ok(())
 }
 
+/// Generates the code for the Unicode scripts and binary properties data
+fn generate_properties_db(output: &Path, properties: &[Category]) -> Result<(), Box<dyn Error>> {
+    let mut writer = io::BufWriter::new(fs::File::create(output.join("properties.rs"))?);
+    writeln!(writer, "/*")?;
+    writeln!(writer, " * WARNING: this file has been generated by")?;
+    writeln!(writer, " * Hime Parser Generator")?;
+    writeln!(writer, " */")?;
+    writeln!(writer)?;
+    writeln!(writer, "use crate::unicode::Category;")?;
+    writeln!(writer, "use std::collections::HashMap;")?;
+    writeln!(writer)?;
+    writeln!(writer, "/// Gets all scripts and binary properties")?;
+    writeln!(
+        writer,
+        "#[allow(clippy::too_many_lines, clippy::unreadable_literal)]"
+    )?;
+    writeln!(writer, "#[must_use]")?;
+    writeln!(
+        writer,
+        "pub fn get_properties() -> HashMap<&'static str, Category> {{"
+    )?;
+    writeln!(writer, "    let mut db = HashMap::new();")?;
+    for property in properties {
+        writeln!(
+            writer,
+            "    let mut property = Category::new(\"{}\");",
+            &property.name
+        )?;
+        for span in &property.spans {
+            writeln!(
+                writer,
+                "    property.add_span(0x{:X}, 0x{:X});",
+                span.begin.value(),
+                span.end.value()
+            )?;
+        }
+        writeln!(writer, "    db.insert(\"{}\", property);", &property.name)?;
+    }
+    writeln!(writer, "    db")?;
+    writeln!(writer, "}}")?;
+    Ok(())
+}
+
+/// Generates the code for the Unicode case folding data
+fn generate_case_folding_db(output: &Path, classes: &[Vec<u32>]) -> Result<(), Box<dyn Error>> {
+    let mut writer = io::BufWriter::new(fs::File::create(output.join("casefolding.rs"))?);
+    writeln!(writer, "/*")?;
+    writeln!(writer, " * WARNING: this file has been generated by")?;
+    writeln!(writer, " * Hime Parser Generator")?;
+    writeln!(writer, " */")?;
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "/// The classes of case-equivalent code points, according to the simple case folding"
+    )?;
+    writeln!(writer, "pub const CASE_FOLDING_CLASSES: &[&[u32]] = &[")?;
+    for class in classes {
+        let values = class
+            .iter()
+            .map(|cp| format!("0x{cp:X}"))
+            .collect::<Vec<_>>();
+        writeln!(writer, "    &[{}],", values.join(", "))?;
+    }
+    writeln!(writer, "];")?;
+    Ok(())
+}
+
+/// Generates the module for a version of the Unicode Character Database
+fn generate_version_module(output: &Path, version: &str) -> Result<(), Box<dyn Error>> {
+    let mut writer = io::BufWriter::new(fs::File::create(output.join("mod.rs"))?);
+    writeln!(writer, "/*")?;
+    writeln!(writer, " * WARNING: this file has been generated by")?;
+    writeln!(writer, " * Hime Parser Generator")?;
+    writeln!(writer, " */")?;
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "//! Tables for version {version} of the Unicode Character Database"
+    )?;
+    writeln!(writer)?;
+    writeln!(writer, "mod blocks;")?;
+    writeln!(writer, "mod casefolding;")?;
+    writeln!(writer, "mod categories;")?;
+    writeln!(writer, "mod properties;")?;
+    writeln!(writer)?;
+    writeln!(writer, "pub use blocks::get_blocks;")?;
+    writeln!(writer, "pub use casefolding::CASE_FOLDING_CLASSES;")?;
+    writeln!(writer, "pub use categories::get_categories;")?;
+    writeln!(writer, "pub use properties::get_properties;")?;
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "/// The version of the Unicode Character Database for these tables"
+    )?;
+    writeln!(writer, "pub const VERSION: &str = \"{version}\";")?;
+    Ok(())
+}
+
+/// Gets the name of the module for a version of the Unicode Character Database
+fn get_version_module_name(version: &str) -> String {
+    format!("v{}", version.replace('.', "_"))
+}
+
 fn main() {
     println!("{CRATE_NAME} {CRATE_VERSION} tag={GIT_TAG} hash={GIT_HASH}");
-    println!("Retrieving and building blocks db ...");
-    let blocks = get_latet_blocks().unwrap();
+    let args = std::env::args().collect::<Vec<_>>();
+    if args.len() < 3 {
+        println!("usage: {CRATE_NAME} <ucd-directory> <version> [output-directory]");
+        std::process::exit(1);
+    }
+    let ucd = PathBuf::from(&args[1]);
+    let version = &args[2];
+    let output = args.get(3).map_or_else(PathBuf::new, PathBuf::from);
+    let module = output.join(get_version_module_name(version));
+    fs::create_dir_all(&module).unwrap();
+    println!("Reading blocks from {} ...", ucd.display());
+    let blocks = read_blocks(&ucd).unwrap();
     println!("Generating blocks db ...");
-    generate_blocks_db(&blocks).unwrap();
+    generate_blocks_db(&module, &blocks).unwrap();
     println!("Generating blocks tests ...");
-    generate_blocks_tests(&blocks).unwrap();
-    println!("Retrieving and building categories db ...");
-    let categories = get_latet_categories().unwrap();
+    generate_blocks_tests(&output, &blocks).unwrap();
+    println!("Reading categories ...");
+    let categories = read_categories(&ucd).unwrap();
     println!("Generating categories db ...");
-    generate_categories_db(&categories).unwrap();
+    generate_categories_db(&module, &categories).unwrap();
+    println!("Reading scripts and properties ...");
+    let properties = read_properties(&ucd).unwrap();
+    println!("Generating properties db ...");
+    generate_properties_db(&module, &properties).unwrap();
+    println!("Reading case folding ...");
+    let classes = read_case_folding(&ucd).unwrap();
+    println!("Generating case folding db ...");
+    generate_case_folding_db(&module, &classes).unwrap();
+    println!("Generating the module for Unicode {version} ...");
+    generate_version_module(&module, version).unwrap();
 }
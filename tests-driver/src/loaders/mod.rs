@@ -40,7 +40,7 @@ pub fn parse_fixtures(fixtures: &[FixtureDef]) -> Result<Fixtures, Errors> {
         .iter()
         .map(|FixtureDef(_, content)| {
             let mut reader = BufReader::new(*content);
-            fixture::parse_utf8_stream(&mut reader)
+            fixture::parse_utf8_stream(&mut reader).expect("the fixtures are valid UTF-8")
         })
         .collect();
     let is_ok = results.iter().all(ParseResult::<AstImpl>::is_success);
@@ -77,6 +77,7 @@ pub fn parse_fixtures(fixtures: &[FixtureDef]) -> Result<Fixtures, Errors> {
                     })
                     .collect(),
                 grammars: Vec::new(),
+                warnings: Vec::new(),
             },
             errors,
         })
@@ -35,7 +35,8 @@ pub const GIT_HASH: &str = env!("GIT_HASH");
 /// The git tag that was used to build the application
 pub const GIT_TAG: &str = env!("GIT_TAG");
 
-const FIXTURES: [FixtureDef; 8] = [
+const FIXTURES: [FixtureDef; 10] = [
+    FixtureDef("Axioms", include_bytes!("fixtures/Axioms.suite")),
     FixtureDef(
         "ContextSensitive",
         include_bytes!("fixtures/ContextSensitive.suite"),
@@ -49,6 +50,7 @@ const FIXTURES: [FixtureDef; 8] = [
         "LexicalRules",
         include_bytes!("fixtures/LexicalRules.suite"),
     ),
+    FixtureDef("Precedence", include_bytes!("fixtures/Precedence.suite")),
     FixtureDef("Regressions", include_bytes!("fixtures/Regressions.suite")),
     FixtureDef(
         "SyntacticRules",
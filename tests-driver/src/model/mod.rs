@@ -59,7 +59,7 @@ impl Fixtures {
         // Build all parser data
         let mut grammars_data = Vec::new();
         for (index, grammar) in grammars.iter_mut().enumerate() {
-            match grammar.build(None, index) {
+            match grammar.build(None, None, index) {
                 Ok(data) => grammars_data.push(data),
                 Err(mut errs) => errors.append(&mut errs),
             }
@@ -251,6 +251,7 @@ impl Fixtures {
             context: LoadedData {
                 inputs: self.get_loaded_inputs(),
                 grammars,
+                warnings: Vec::new(),
             },
             errors,
         }